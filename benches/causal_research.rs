@@ -124,12 +124,12 @@ fn bench_observed_remove_processing(c: &mut Criterion) {
                         let op = match i % 3 {
                             0 => DsonOperation::FieldAdd {
                                 path: format!("field_{}", i % 10),
-                                value: OperationValue::NumberRef(i.to_string()),
+                                value: OperationValue::NumberRef(i.to_string().into()),
                             },
                             1 => DsonOperation::FieldModify {
                                 path: format!("field_{}", i % 10),
-                                old_value: Some(OperationValue::NumberRef(i.to_string())),
-                                new_value: OperationValue::NumberRef((i * 2).to_string()),
+                                old_value: Some(OperationValue::NumberRef(i.to_string().into())),
+                                new_value: OperationValue::NumberRef((i * 2).to_string().into()),
                             },
                             _ => DsonOperation::FieldDelete {
                                 path: format!("field_{}", i % 10),
@@ -362,12 +362,12 @@ fn bench_memory_usage_observed_remove(c: &mut Criterion) {
                         let op = match i % 4 {
                             0 => DsonOperation::FieldAdd {
                                 path: format!("field_{}", i % 50),
-                                value: OperationValue::NumberRef(i.to_string()),
+                                value: OperationValue::NumberRef(i.to_string().into()),
                             },
                             1 => DsonOperation::FieldModify {
                                 path: format!("field_{}", i % 50),
-                                old_value: Some(OperationValue::NumberRef(i.to_string())),
-                                new_value: OperationValue::NumberRef((i * 2).to_string()),
+                                old_value: Some(OperationValue::NumberRef(i.to_string().into())),
+                                new_value: OperationValue::NumberRef((i * 2).to_string().into()),
                             },
                             2 => DsonOperation::FieldDelete {
                                 path: format!("field_{}", i % 50),
@@ -444,7 +444,7 @@ fn bench_concurrent_readers_writers(c: &mut Criterion) {
                                     let mut processor = processor_clone.lock().unwrap();
                                     let op = DsonOperation::FieldAdd {
                                         path: format!("field_{}_{}", thread_id, i),
-                                        value: OperationValue::NumberRef(dot_id.to_string()),
+                                        value: OperationValue::NumberRef(dot_id.to_string().into()),
                                     };
                                     let _result = processor.process_operation(&op);
                                 }
@@ -469,7 +469,7 @@ fn bench_crdt_merge_table(c: &mut Criterion) {
             table.add_entry(
                 &format!("path.to.field_{i}"),
                 MergeStrategy::LastWriteWins,
-                &OperationValue::NumberRef(i.to_string()),
+                &OperationValue::NumberRef(i.to_string().into()),
                 i as u64,
             );
         }
@@ -514,7 +514,7 @@ fn bench_crdt_optimized_processor(c: &mut Criterion) {
             .map(|i| {
                 (
                     format!("field_{i}"),
-                    OperationValue::NumberRef(i.to_string()),
+                    OperationValue::NumberRef(i.to_string().into()),
                     100u64,
                 )
             })
@@ -530,7 +530,7 @@ fn bench_crdt_optimized_processor(c: &mut Criterion) {
                 b.iter(|| {
                     black_box(processor.merge_value(
                         "field_0",
-                        &OperationValue::NumberRef("999".to_string()),
+                        &OperationValue::NumberRef("999".into()),
                         200,
                     ))
                 })
@@ -542,7 +542,7 @@ fn bench_crdt_optimized_processor(c: &mut Criterion) {
             .map(|i| {
                 (
                     format!("field_{i}"),
-                    OperationValue::NumberRef((i * 2).to_string()),
+                    OperationValue::NumberRef((i * 2).to_string().into()),
                     200u64,
                 )
             })
@@ -576,13 +576,13 @@ fn bench_preparsed_value(c: &mut Criterion) {
 
     // Integer parsing
     group.bench_function("parse_integer", |b| {
-        let op = OperationValue::NumberRef("12345".to_string());
+        let op = OperationValue::NumberRef("12345".into());
         b.iter(|| black_box(PreParsedValue::from_operation_value(&op)))
     });
 
     // Float parsing
     group.bench_function("parse_float", |b| {
-        let op = OperationValue::NumberRef("123.456".to_string());
+        let op = OperationValue::NumberRef("123.456".into());
         b.iter(|| black_box(PreParsedValue::from_operation_value(&op)))
     });
 
@@ -291,7 +291,7 @@ mod operations {
     pub fn field_add() -> Vec<DsonOperation> {
         vec![DsonOperation::FieldAdd {
             path: "timestamp".to_string(),
-            value: OperationValue::NumberRef("1234567890".to_string()),
+            value: OperationValue::NumberRef("1234567890".into()),
         }]
     }
 
@@ -299,7 +299,7 @@ mod operations {
     pub fn field_modify() -> Vec<DsonOperation> {
         vec![DsonOperation::FieldModify {
             path: "score".to_string(),
-            value: OperationValue::NumberRef("999".to_string()),
+            value: OperationValue::NumberRef("999".into()),
         }]
     }
 
@@ -319,7 +319,7 @@ mod operations {
             },
             DsonOperation::FieldModify {
                 path: "score".to_string(),
-                value: OperationValue::NumberRef("100".to_string()),
+                value: OperationValue::NumberRef("100".into()),
             },
             DsonOperation::FieldDelete {
                 path: "email".to_string(),
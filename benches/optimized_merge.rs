@@ -100,7 +100,7 @@ fn bench_processor_merge_value(c: &mut Criterion) {
             let local_entries = paths.iter().enumerate().map(|(i, path)| {
                 (
                     path.clone(),
-                    OperationValue::NumberRef(i.to_string()),
+                    OperationValue::NumberRef(i.to_string().into()),
                     i as u64,
                 )
             });
@@ -112,7 +112,7 @@ fn bench_processor_merge_value(c: &mut Criterion) {
                 .map(|(i, path)| {
                     (
                         path.clone(),
-                        OperationValue::NumberRef((i + 100).to_string()),
+                        OperationValue::NumberRef((i + 100).to_string().into()),
                         (i + 1) as u64,
                     )
                 })
@@ -142,7 +142,7 @@ fn bench_processor_merge_batch(c: &mut Criterion) {
             let local_entries: Vec<_> = (0..count)
                 .map(|i| {
                     let path = format!("field_{}", i);
-                    let value = OperationValue::NumberRef(i.to_string());
+                    let value = OperationValue::NumberRef(i.to_string().into());
                     (path, value, i as u64)
                 })
                 .collect();
@@ -152,7 +152,7 @@ fn bench_processor_merge_batch(c: &mut Criterion) {
             let remote_entries: Vec<_> = (0..count)
                 .map(|i| {
                     let path = format!("field_{}", i);
-                    let value = OperationValue::NumberRef((i + 100).to_string());
+                    let value = OperationValue::NumberRef((i + 100).to_string().into());
                     (path, value, (i + 1) as u64)
                 })
                 .collect();
@@ -176,7 +176,7 @@ fn bench_preparsed_conversion(c: &mut Criterion) {
         group.throughput(Throughput::Elements(*count as u64));
         group.bench_with_input(BenchmarkId::from_parameter(count), count, |b, &count| {
             let values: Vec<_> = (0..count)
-                .map(|i| OperationValue::NumberRef(i.to_string()))
+                .map(|i| OperationValue::NumberRef(i.to_string().into()))
                 .collect();
 
             b.iter(|| {
@@ -202,7 +202,7 @@ fn bench_merge_table_lookup(c: &mut Criterion) {
                 table.add_entry(
                     path,
                     MergeStrategy::LastWriteWins,
-                    &OperationValue::NumberRef(i.to_string()),
+                    &OperationValue::NumberRef(i.to_string().into()),
                     i as u64,
                 );
             }
@@ -244,7 +244,7 @@ fn bench_comparison_lww_1000(c: &mut Criterion) {
         let local_entries = paths.iter().enumerate().map(|(i, path)| {
             (
                 path.clone(),
-                OperationValue::NumberRef(i.to_string()),
+                OperationValue::NumberRef(i.to_string().into()),
                 i as u64,
             )
         });
@@ -256,7 +256,7 @@ fn bench_comparison_lww_1000(c: &mut Criterion) {
             .map(|(i, path)| {
                 (
                     path.clone(),
-                    OperationValue::NumberRef((i + 100).to_string()),
+                    OperationValue::NumberRef((i + 100).to_string().into()),
                     (i + 1) as u64,
                 )
             })
@@ -297,7 +297,7 @@ fn bench_comparison_max_1000(c: &mut Criterion) {
         let local_entries = paths.iter().enumerate().map(|(i, path)| {
             (
                 path.clone(),
-                OperationValue::NumberRef((i * 2).to_string()),
+                OperationValue::NumberRef((i * 2).to_string().into()),
                 i as u64,
             )
         });
@@ -309,7 +309,7 @@ fn bench_comparison_max_1000(c: &mut Criterion) {
             .map(|(i, path)| {
                 (
                     path.clone(),
-                    OperationValue::NumberRef((i * 3).to_string()),
+                    OperationValue::NumberRef((i * 3).to_string().into()),
                     1_u64,
                 )
             })
@@ -359,7 +359,7 @@ fn bench_scaling(c: &mut Criterion) {
                 let local_entries = paths.iter().enumerate().map(|(i, path)| {
                     (
                         path.clone(),
-                        OperationValue::NumberRef((i * 2).to_string()),
+                        OperationValue::NumberRef((i * 2).to_string().into()),
                         i as u64,
                     )
                 });
@@ -371,7 +371,7 @@ fn bench_scaling(c: &mut Criterion) {
                     .map(|(i, path)| {
                         (
                             path.clone(),
-                            OperationValue::NumberRef((i * 3).to_string()),
+                            OperationValue::NumberRef((i * 3).to_string().into()),
                             1_u64,
                         )
                     })
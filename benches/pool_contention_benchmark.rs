@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Benchmarks: missing_docs - criterion_group! macro generates undocumentable code
+#![allow(missing_docs)]
+// Benchmarks: clippy lints relaxed for benchmark code (not production)
+#![allow(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
+//! Benchmarks for `SharedPool` under multi-threaded contention.
+//!
+//! Compares the sharded pool against a single-shard pool (one lock, the
+//! previous layout) with threads hammering acquire/release, both with a
+//! single buffer size and a mix of size classes.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use fionn_pool::{PoolStrategy, SharedPool, TapePool, TapePoolExt};
+use std::hint::black_box;
+use std::sync::Barrier;
+use std::time::{Duration, Instant};
+
+const OPS_PER_THREAD: u64 = 10_000;
+const THREAD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+const MIXED_SIZES: [usize; 4] = [256, 1024, 4096, 65536];
+
+/// Run `ops` on `threads` threads at once, `iters` times each, and time it.
+fn run_contended(
+    pool: &SharedPool,
+    threads: usize,
+    iters: u64,
+    ops: impl Fn(&SharedPool, u64) + Sync,
+) -> Duration {
+    let barrier = Barrier::new(threads + 1);
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                barrier.wait();
+                for i in 0..iters * OPS_PER_THREAD {
+                    ops(pool, i);
+                }
+                barrier.wait();
+            });
+        }
+        barrier.wait();
+        let start = Instant::now();
+        barrier.wait();
+        start.elapsed()
+    })
+}
+
+fn pools(threads: usize) -> [(&'static str, SharedPool); 2] {
+    let strategy = PoolStrategy::SizeLimited {
+        max_tapes: 16 * threads,
+    };
+    [
+        ("single_shard", SharedPool::with_shards(strategy.clone(), 1)),
+        ("sharded", SharedPool::with_shards(strategy, threads)),
+    ]
+}
+
+fn bench_uniform_contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pool_contention_uniform");
+
+    for threads in THREAD_COUNTS {
+        group.throughput(Throughput::Elements(OPS_PER_THREAD * threads as u64));
+        for (name, pool) in pools(threads) {
+            group.bench_with_input(BenchmarkId::new(name, threads), &threads, |b, &threads| {
+                b.iter_custom(|iters| {
+                    run_contended(&pool, threads, iters, |pool, _| {
+                        let buf = pool.acquire(1024);
+                        pool.release(black_box(buf));
+                    })
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_mixed_size_contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pool_contention_mixed_sizes");
+
+    for threads in THREAD_COUNTS {
+        group.throughput(Throughput::Elements(OPS_PER_THREAD * threads as u64));
+        for (name, pool) in pools(threads) {
+            group.bench_with_input(BenchmarkId::new(name, threads), &threads, |b, &threads| {
+                b.iter_custom(|iters| {
+                    run_contended(&pool, threads, iters, |pool, i| {
+                        let size = MIXED_SIZES[(i % MIXED_SIZES.len() as u64) as usize];
+                        let buf = pool.acquire(size);
+                        pool.release(black_box(buf));
+                    })
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_guard_contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pool_contention_guard");
+
+    for threads in THREAD_COUNTS {
+        group.throughput(Throughput::Elements(OPS_PER_THREAD * threads as u64));
+        let pool = SharedPool::with_shards(PoolStrategy::Unbounded, threads);
+        group.bench_with_input(
+            BenchmarkId::new("acquire_guard", threads),
+            &threads,
+            |b, &threads| {
+                b.iter_custom(|iters| {
+                    run_contended(&pool, threads, iters, |pool, _| {
+                        let mut buf = pool.acquire_guard(1024);
+                        buf.extend_from_slice(b"{}");
+                        black_box(&buf);
+                    })
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_uniform_contention,
+    bench_mixed_size_contention,
+    bench_guard_contention
+);
+criterion_main!(benches);
@@ -73,7 +73,7 @@ fn tape_serialization_benchmarks(c: &mut Criterion) {
             let mut modifications = AHashMap::new();
             modifications.insert(
                 "root.a.b.c.d.e".to_string(),
-                OperationValue::NumberRef("42".to_string()),
+                OperationValue::NumberRef("42".into()),
             );
 
             b.iter(|| {
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 //! fionn CLI binary - A Swiss Army knife for structured data with SIMD acceleration
 //!
-//! Supports multiple formats: JSON, YAML, TOML, CSV, ISON, TOON
+//! Supports multiple formats: JSON, YAML, TOML, CSV, ISON, TOON, HCL, INI, .properties, .env
 //!
 //! This CLI uses the tape-based architecture throughout for maximum performance:
 //! - `UnifiedTape::parse()` for parsing all formats
@@ -12,18 +12,36 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use fionn_core::FormatKind;
+use fionn_core::limits::{BigIntegers, DuplicateKeys, ParseLimits, check_json, locate_keys};
+use fionn_core::path::{ParsePathError, PathComponent, PathStyle};
+use fionn_core::simd::{SimdDispatch, SimdLevel};
+#[cfg(feature = "toml")]
+use fionn_core::value_builder::TomlBuilder;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use fionn_core::value_builder::ValueBuilder;
+#[cfg(feature = "yaml")]
+use fionn_core::value_builder::YamlBuilder;
+#[cfg(feature = "csv")]
+use fionn_diff::{CsvDiff, CsvDiffOp, CsvDiffOptions, csv_diff};
 use fionn_diff::{
     apply_patch, deep_merge_tapes, diff_tapes, json_diff, json_merge_patch, merge_tapes,
     tape_to_value,
 };
 use fionn_gron::{
-    GronOptions, GronQueryOptions, Query, gron, gron_from_tape, gron_query, ungron_to_value,
+    GronJsonlOptions, GronOptions, GronQueryOptions, Query, StreamRoot, UngronJsonlOptions, gron,
+    gron_from_tape, gron_jsonl_streaming, gron_query, gron_query_streaming, gron_streaming,
+    ungron_jsonl_streaming, ungron_to_value_with_root, ungron_to_value_with_style,
 };
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use fionn_gron::{gron_typed, ungron_with_builder_and_root};
+use fionn_simd::sidecar::{IndexData, JsonIndex, SidecarIndex, key_hash, read_range};
+use fionn_simd::{FilterExpr, JsonType, Prefilter, Verdict};
+use fionn_stream::compression::{self, CompressedWriter, Compression};
 use fionn_tape::DsonTape;
 use serde::Serialize;
 use serde_json::Value;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, BufRead, Read, Write};
 use std::path::{Path, PathBuf};
 
 // Tape-based transform is available when any format feature is enabled
@@ -32,10 +50,17 @@ use std::path::{Path, PathBuf};
     feature = "toml",
     feature = "csv",
     feature = "ison",
-    feature = "toon"
+    feature = "toon",
+    feature = "hcl",
+    feature = "ini",
+    feature = "properties",
+    feature = "env"
 ))]
 use fionn_simd::transform::{TransformOptions, transform};
 
+#[cfg(feature = "csv")]
+use fionn_simd::formats::{CsvCell, CsvDialect, CsvEscape, CsvParser, infer_column_types};
+
 // ============================================================================
 // CLI Format Enum (mirrors FormatKind but for clap)
 // ============================================================================
@@ -65,6 +90,18 @@ pub enum Format {
     /// TOON (Token-Oriented Object Notation)
     #[cfg(feature = "toon")]
     Toon,
+    /// HCL (Terraform-style configuration language)
+    #[cfg(feature = "hcl")]
+    Hcl,
+    /// INI (sectioned key/value configuration)
+    #[cfg(feature = "ini")]
+    Ini,
+    /// Java .properties
+    #[cfg(feature = "properties")]
+    Properties,
+    /// Dotenv (.env) files
+    #[cfg(feature = "env")]
+    Env,
     /// Gron (greppable object notation) - output only
     Gron,
     /// Auto-detect format from content/extension
@@ -88,6 +125,14 @@ impl Format {
             Self::Ison | Self::Isonl => Some(FormatKind::Ison),
             #[cfg(feature = "toon")]
             Self::Toon => Some(FormatKind::Toon),
+            #[cfg(feature = "hcl")]
+            Self::Hcl => Some(FormatKind::Hcl),
+            #[cfg(feature = "ini")]
+            Self::Ini => Some(FormatKind::Ini),
+            #[cfg(feature = "properties")]
+            Self::Properties => Some(FormatKind::Properties),
+            #[cfg(feature = "env")]
+            Self::Env => Some(FormatKind::Env),
             Self::Gron | Self::Auto => None,
         }
     }
@@ -123,7 +168,7 @@ impl Format {
     about = "A Swiss Army knife for structured data with SIMD acceleration"
 )]
 #[command(long_about = "fionn - Multi-format data processing tool\n\n\
-    Supports: JSON, YAML, TOML, CSV, ISON, TOON\n\
+    Supports: JSON, YAML, TOML, CSV, ISON, TOON, HCL, INI, .properties, .env\n\
     Operations: gron, diff, patch, merge, query, format, validate, convert")]
 #[allow(clippy::struct_excessive_bools)] // CLI args naturally have many boolean flags
 struct Args {
@@ -155,10 +200,214 @@ struct Args {
     #[arg(short = 'q', long = "quiet", global = true)]
     quiet: bool,
 
+    /// Cap SIMD kernels at a level: scalar, sse2, avx2, avx512, neon
+    /// (overrides the `FIONN_SIMD` environment variable)
+    #[arg(long = "simd-level", global = true, value_name = "LEVEL")]
+    simd: Option<SimdLevel>,
+
+    /// Path syntax: js, pointer, jsonpath, jq or dotted (default: each
+    /// command's own; gron uses js, diff and patch pointer, ops dotted, and
+    /// query its JSONPath-like syntax)
+    #[arg(long = "path-style", global = true, value_name = "STYLE")]
+    path_style: Option<PathStyle>,
+
+    #[command(flatten)]
+    limits: LimitArgs,
+
+    #[cfg(feature = "csv")]
+    #[command(flatten)]
+    csv: CsvArgs,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+/// Parse limits, applied whenever a document is read
+#[derive(clap::Args)]
+struct LimitArgs {
+    /// Maximum nesting depth
+    #[arg(long = "max-depth", global = true, value_name = "N")]
+    max_depth: Option<usize>,
+
+    /// Maximum string or key length in bytes
+    #[arg(long = "max-string-length", global = true, value_name = "BYTES")]
+    max_string_length: Option<usize>,
+
+    /// Maximum document size in bytes (per record when streaming)
+    #[arg(long = "max-size", global = true, value_name = "BYTES")]
+    max_size: Option<usize>,
+
+    /// Duplicate object keys: allow, report or reject
+    #[arg(long = "duplicate-keys", global = true, value_name = "POLICY")]
+    duplicate_keys: Option<DuplicateKeys>,
+
+    /// Integers beyond i64/u64: lossy or reject (preserve is unsupported,
+    /// as documents are parsed into values that hold them as f64)
+    #[arg(long = "big-integers", global = true, value_name = "POLICY")]
+    big_integers: Option<BigIntegers>,
+}
+
+impl LimitArgs {
+    /// Override `base` with the limits given on the command line
+    fn apply(&self, mut base: ParseLimits) -> Result<ParseLimits, Box<dyn std::error::Error>> {
+        if self.big_integers == Some(BigIntegers::Preserve) {
+            return Err(
+                "--big-integers preserve is unsupported: documents are parsed into \
+                        values that round big integers to f64; use reject to refuse them"
+                    .into(),
+            );
+        }
+        base.max_depth = self.max_depth.or(base.max_depth);
+        base.max_string_len = self.max_string_length.or(base.max_string_len);
+        base.max_document_size = self.max_size.or(base.max_document_size);
+        base.duplicate_keys = self.duplicate_keys.unwrap_or(base.duplicate_keys);
+        base.big_integers = self.big_integers.unwrap_or(base.big_integers);
+        Ok(base)
+    }
+}
+
+/// CSV dialect options, applied whenever CSV input is read
+#[cfg(feature = "csv")]
+#[derive(clap::Args)]
+#[allow(clippy::struct_excessive_bools)] // Independent CLI flags
+struct CsvArgs {
+    /// CSV field delimiter (default: auto-detect)
+    #[arg(long = "csv-delimiter", global = true, value_name = "CHAR")]
+    delimiter: Option<char>,
+
+    /// CSV quote character
+    #[arg(
+        long = "csv-quote",
+        global = true,
+        value_name = "CHAR",
+        default_value_t = '"'
+    )]
+    quote: char,
+
+    /// Quotes inside quoted CSV fields are backslash-escaped instead of doubled
+    #[arg(long = "csv-backslash-escape", global = true)]
+    backslash_escape: bool,
+
+    /// CSV input has no header row (columns are named column1, column2, ...)
+    #[arg(long = "csv-no-header", global = true)]
+    no_header: bool,
+
+    /// Skip CSV lines starting with this character
+    #[arg(long = "csv-comment", global = true, value_name = "CHAR")]
+    comment: Option<char>,
+
+    /// Trim whitespace around unquoted CSV fields
+    #[arg(long = "csv-trim", global = true)]
+    trim: bool,
+
+    /// Read this unquoted CSV value as null (repeatable)
+    #[arg(long = "csv-null", global = true, value_name = "TOKEN")]
+    null: Vec<String>,
+
+    /// Infer a type per CSV column (integers, floats, booleans) instead of strings
+    #[arg(long = "infer-types", global = true)]
+    infer_types: bool,
+
+    /// Number of CSV rows sampled by --infer-types
+    #[arg(
+        long = "infer-sample",
+        global = true,
+        value_name = "ROWS",
+        default_value_t = 1000
+    )]
+    infer_sample: usize,
+}
+
+/// Resolved CSV input options
+#[cfg(feature = "csv")]
+#[derive(Debug, Clone)]
+struct CsvInputOptions {
+    dialect: CsvDialect,
+    detect_delimiter: bool,
+    infer_sample: Option<usize>,
+}
+
+#[cfg(feature = "csv")]
+impl Default for CsvInputOptions {
+    fn default() -> Self {
+        Self {
+            dialect: CsvDialect::new(),
+            detect_delimiter: true,
+            infer_sample: None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl CsvInputOptions {
+    /// Diff options comparing cells the way this dialect reads them
+    fn diff_options(&self) -> CsvDiffOptions {
+        CsvDiffOptions {
+            trim_values: self.dialect.trim,
+            numeric_compare: self.infer_sample.is_some(),
+            ..CsvDiffOptions::default()
+        }
+        .with_null_tokens(self.dialect.null_tokens.iter().cloned())
+    }
+}
+
+#[cfg(feature = "csv")]
+impl CsvArgs {
+    /// Validate the flags and build the CSV input options
+    fn to_options(&self) -> Result<CsvInputOptions, Box<dyn std::error::Error>> {
+        fn ascii(flag: &str, c: char) -> Result<u8, Box<dyn std::error::Error>> {
+            u8::try_from(c)
+                .ok()
+                .filter(u8::is_ascii)
+                .ok_or_else(|| format!("--{flag} must be a single ASCII character").into())
+        }
+
+        let mut dialect = CsvDialect::new()
+            .with_quote(ascii("csv-quote", self.quote)?)
+            .with_header(!self.no_header)
+            .with_trim(self.trim)
+            .with_null_tokens(self.null.iter().cloned());
+        if let Some(delimiter) = self.delimiter {
+            dialect = dialect.with_delimiter(ascii("csv-delimiter", delimiter)?);
+        }
+        if let Some(comment) = self.comment {
+            dialect = dialect.with_comment_prefix(ascii("csv-comment", comment)?);
+        }
+        if self.backslash_escape {
+            dialect = dialect.with_escape(CsvEscape::Backslash);
+        }
+
+        Ok(CsvInputOptions {
+            dialect,
+            detect_delimiter: self.delimiter.is_none(),
+            infer_sample: self.infer_types.then_some(self.infer_sample),
+        })
+    }
+}
+
+/// Options for reading and writing documents, resolved from the command line
+#[derive(Debug, Clone, Default)]
+struct DocumentOptions {
+    /// Path syntax of gron read or written as a data format
+    path_style: PathStyle,
+    /// Limits every parsed document is checked against
+    limits: ParseLimits,
+    /// Dialect and type inference for CSV input
+    #[cfg(feature = "csv")]
+    csv: CsvInputOptions,
+}
+
+impl DocumentOptions {
+    fn from_args(args: &Args) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            path_style: args.path_style.unwrap_or_default(),
+            limits: args.limits.apply(ParseLimits::permissive())?,
+            #[cfg(feature = "csv")]
+            csv: args.csv.to_options()?,
+        })
+    }
+}
+
 /// Subcommands for fionn CLI
 #[derive(Subcommand)]
 enum Commands {
@@ -169,6 +418,7 @@ enum Commands {
         input: Option<PathBuf>,
 
         /// Reverse mode: convert gron back to structured format
+        /// (with `--to jsonl`, one line per `json[N]` record)
         #[arg(short = 'u', long = "ungron")]
         ungron: bool,
 
@@ -195,6 +445,26 @@ enum Commands {
         /// Sort output alphabetically
         #[arg(long = "sort")]
         sort: bool,
+
+        /// JSON stream format, one `[["a",0],1]` array per line
+        /// (ungron reads it either way)
+        #[arg(long = "json", conflicts_with = "query")]
+        json: bool,
+
+        /// Open JSON stream paths with the root prefix (`[["json","a"],1]`);
+        /// with -u, strip it from stream lines
+        #[arg(long = "root")]
+        root: bool,
+
+        /// Stream JSON input without building a tape, in constant memory
+        /// (automatic for JSON files of 64 MiB or more)
+        #[arg(long = "stream", conflicts_with_all = ["ungron", "sort"])]
+        stream: bool,
+
+        /// Annotate assignments with their types (`# int`, `# datetime`,
+        /// `# !tag`); ungron to TOML/YAML restores them
+        #[arg(long = "types", conflicts_with = "json")]
+        types: bool,
     },
 
     /// Compute diff between two files
@@ -204,7 +474,8 @@ enum Commands {
         /// Second file (target)
         file2: PathBuf,
 
-        /// Output format for diff (json-patch, merge-patch, or unified)
+        /// Output format for diff (json-patch, merge-patch, gron, tape, or csv
+        /// for row and cell changes compared under the CSV dialect)
         #[arg(long = "diff-format", default_value = "json-patch")]
         diff_format: String,
 
@@ -227,6 +498,11 @@ enum Commands {
         /// Dry run - show result without modifying
         #[arg(long = "dry-run")]
         dry_run: bool,
+
+        /// Edit the source text in place, keeping comments, key order and
+        /// indentation (JSON/JSONC, YAML, TOML)
+        #[arg(long = "preserve-format")]
+        preserve_format: bool,
     },
 
     /// Merge multiple files
@@ -257,6 +533,15 @@ enum Commands {
         /// Return only first match
         #[arg(long = "first")]
         first: bool,
+
+        /// Seek through the sidecar index from `fionn index build`; JSONL
+        /// records are addressed as `[N]` or `[*]`
+        #[arg(long = "index")]
+        index: bool,
+
+        /// Sidecar index to use instead of FILE.fidx (implies --index)
+        #[arg(long = "index-file", value_name = "INDEX")]
+        index_file: Option<PathBuf>,
     },
 
     /// Convert between formats
@@ -288,7 +573,7 @@ enum Commands {
         /// Input file
         file: Option<PathBuf>,
 
-        /// Strict validation mode
+        /// Strict validation mode: RFC 8259 limits plus lint warnings
         #[arg(long = "strict")]
         strict: bool,
     },
@@ -298,7 +583,7 @@ enum Commands {
         /// Input file
         file: Option<PathBuf>,
 
-        /// Filter records by query
+        /// Filter records by query (`path`, `path OP literal` or `path in [..]`)
         #[arg(long = "filter")]
         filter: Option<String>,
 
@@ -351,6 +636,12 @@ enum Commands {
         file: Option<PathBuf>,
     },
 
+    /// Manage sidecar indexes for random access into large files
+    Index {
+        #[command(subcommand)]
+        action: IndexAction,
+    },
+
     /// Run benchmarks
     Bench {
         /// Benchmark type (parse, gron, diff, all)
@@ -359,13 +650,38 @@ enum Commands {
     },
 }
 
+/// Sidecar index actions
+#[derive(Subcommand)]
+enum IndexAction {
+    /// Index an uncompressed JSON or JSONL file (written to FILE.fidx or -o)
+    Build {
+        /// Input file
+        file: PathBuf,
+
+        /// Container nesting depth to index for JSON documents
+        #[arg(long = "depth", default_value = "2")]
+        depth: u32,
+    },
+}
+
 // ============================================================================
 // Format Detection and Parsing
 // ============================================================================
 
-/// Detect format from file extension
+/// Detect format from file extension, looking through a compression suffix
+/// (`events.jsonl.gz` is JSONL)
 fn detect_format_from_extension(path: &Path) -> Option<Format> {
-    let ext = path.extension()?.to_str()?.to_lowercase();
+    // Dotenv files are named rather than extended (`.env`, `.env.local`)
+    #[cfg(feature = "env")]
+    if path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(FormatKind::detect_from_file_name)
+        .is_some_and(|result| result.format == FormatKind::Env)
+    {
+        return Some(Format::Env);
+    }
+    let ext = compression::split_extension(path).1?;
     match ext.as_str() {
         "json" | "geojson" => Some(Format::Json),
         "jsonl" | "ndjson" => Some(Format::Jsonl),
@@ -381,6 +697,14 @@ fn detect_format_from_extension(path: &Path) -> Option<Format> {
         "isonl" => Some(Format::Isonl),
         #[cfg(feature = "toon")]
         "toon" => Some(Format::Toon),
+        #[cfg(feature = "hcl")]
+        "hcl" | "tf" | "tfvars" => Some(Format::Hcl),
+        #[cfg(feature = "ini")]
+        "ini" | "cfg" => Some(Format::Ini),
+        #[cfg(feature = "properties")]
+        "properties" => Some(Format::Properties),
+        #[cfg(feature = "env")]
+        "env" => Some(Format::Env),
         "gron" => Some(Format::Gron),
         _ => None,
     }
@@ -401,6 +725,14 @@ fn detect_format_from_content(content: &[u8]) -> Format {
         FormatKind::Ison => Format::Ison,
         #[cfg(feature = "toon")]
         FormatKind::Toon => Format::Toon,
+        #[cfg(feature = "hcl")]
+        FormatKind::Hcl => Format::Hcl,
+        #[cfg(feature = "ini")]
+        FormatKind::Ini => Format::Ini,
+        #[cfg(feature = "properties")]
+        FormatKind::Properties => Format::Properties,
+        #[cfg(feature = "env")]
+        FormatKind::Env => Format::Env,
         #[allow(unreachable_patterns)] // Matches feature-gated variants
         _ => Format::Json,
     }
@@ -445,8 +777,125 @@ fn resolve_output_format(explicit: Option<Format>, input_format: Format) -> Form
 // Parsing Functions
 // ============================================================================
 
+/// Parse CSV to an array of objects using the configured dialect
+///
+/// Cells are strings unless type inference is enabled; null tokens always
+/// read as null.
+#[cfg(feature = "csv")]
+fn parse_csv_to_value(content: &str, options: &CsvInputOptions) -> Value {
+    let mut dialect = options.dialect.clone();
+    if options.detect_delimiter {
+        let lines: Vec<&[u8]> = content.as_bytes().split(|&b| b == b'\n').take(5).collect();
+        dialect.delimiter = CsvParser::detect_delimiter(&lines);
+    }
+
+    let mut rows: Vec<Vec<CsvCell>> = dialect
+        .split_records(content.as_bytes())
+        .into_iter()
+        .map(|record| dialect.parse_record(record))
+        .collect();
+    let headers: Vec<String> = if dialect.has_header && !rows.is_empty() {
+        rows.remove(0).into_iter().map(|cell| cell.value).collect()
+    } else {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        (1..=width).map(|i| format!("column{i}")).collect()
+    };
+    let types = options
+        .infer_sample
+        .map(|sample| infer_column_types(&rows, &dialect, sample));
+
+    let records = rows
+        .iter()
+        .map(|row| {
+            let obj = headers
+                .iter()
+                .zip(row)
+                .enumerate()
+                .map(|(i, (header, cell))| {
+                    let value = match &types {
+                        Some(types) => types
+                            .get(i)
+                            .copied()
+                            .unwrap_or_default()
+                            .to_value(cell, &dialect),
+                        None if !cell.quoted && dialect.null_tokens.contains(&cell.value) => {
+                            Value::Null
+                        }
+                        None => Value::String(cell.value.clone()),
+                    };
+                    (header.clone(), value)
+                })
+                .collect();
+            Value::Object(obj)
+        })
+        .collect();
+    Value::Array(records)
+}
+
 /// Parse content to [`serde_json::Value`] based on format
-fn parse_to_value(content: &str, format: Format) -> Result<Value, Box<dyn std::error::Error>> {
+///
+/// The document is checked against the command line limits; warnings the
+/// limit policy reports are printed to stderr.
+fn parse_to_value(
+    content: &str,
+    format: Format,
+    options: &DocumentOptions,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let (value, warnings) = parse_with_limits(content, format, options, &options.limits)?;
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+    Ok(value)
+}
+
+/// Parse content and check it against `limits`, returning the warnings the
+/// limit policy reports
+fn parse_with_limits(
+    content: &str,
+    format: Format,
+    options: &DocumentOptions,
+    limits: &ParseLimits,
+) -> Result<(Value, Vec<String>), Box<dyn std::error::Error>> {
+    if limits.is_permissive() {
+        return Ok((parse_unchecked(content, format, options)?, Vec::new()));
+    }
+
+    // JSON is checked on the raw bytes, before parsing can hide duplicates
+    let json_input = matches!(format, Format::Json | Format::Jsonl | Format::Auto);
+    let mut warnings = Vec::new();
+    if json_input {
+        let report =
+            check_json(content.as_bytes(), limits).map_err(|e| format!("Limit exceeded: {e}"))?;
+        warnings.extend(report.warnings.iter().map(ToString::to_string));
+    }
+
+    let value = parse_unchecked(content, format, options)?;
+    if !json_input {
+        let (depth, longest) = value_shape(&value);
+        limits
+            .check_shape(content.len(), depth, |limit| {
+                let keys = keys_to_depth(&value, limit + 1).unwrap_or_default();
+                locate_keys(content.as_bytes(), keys)
+            })
+            .map_err(|e| format!("Limit exceeded: {e}"))?;
+        if let Some(limit) = limits.max_string_len
+            && longest > limit
+        {
+            return Err(format!(
+                "Limit exceeded: string of {longest} bytes exceeds limit of {limit}"
+            )
+            .into());
+        }
+    }
+    Ok((value, warnings))
+}
+
+/// Parse content to [`serde_json::Value`] without checking limits
+fn parse_unchecked(
+    content: &str,
+    format: Format,
+    options: &DocumentOptions,
+) -> Result<Value, Box<dyn std::error::Error>> {
     match format {
         Format::Json | Format::Jsonl | Format::Auto => Ok(serde_json::from_str(content)?),
         #[cfg(feature = "yaml")]
@@ -459,26 +908,10 @@ fn parse_to_value(content: &str, format: Format) -> Result<Value, Box<dyn std::e
             Ok(serde_json::from_str(&json_str)?)
         }
         #[cfg(feature = "csv")]
-        Format::Csv => {
-            // Parse CSV to array of objects
-            let mut reader = csv::Reader::from_reader(content.as_bytes());
-            let headers: Vec<String> = reader.headers()?.iter().map(String::from).collect();
-            let mut records = Vec::new();
-            for result in reader.records() {
-                let record = result?;
-                let mut obj = serde_json::Map::new();
-                for (i, field) in record.iter().enumerate() {
-                    if i < headers.len() {
-                        obj.insert(headers[i].clone(), Value::String(field.to_string()));
-                    }
-                }
-                records.push(Value::Object(obj));
-            }
-            Ok(Value::Array(records))
-        }
+        Format::Csv => Ok(parse_csv_to_value(content, &options.csv)),
         Format::Gron => {
             // Parse gron format back to JSON
-            let value = ungron_to_value(content)?;
+            let value = ungron_to_value_with_style(content, options.path_style)?;
             Ok(value)
         }
         #[cfg(feature = "ison")]
@@ -511,19 +944,64 @@ fn parse_to_value(content: &str, format: Format) -> Result<Value, Box<dyn std::e
                 .map_err(|e| format!("TOON output encoding error: {e}"))?;
             Ok(serde_json::from_str(&json_str)?)
         }
+        #[cfg(feature = "hcl")]
+        Format::Hcl => parse_via_transform(content, FormatKind::Hcl),
+        #[cfg(feature = "ini")]
+        Format::Ini => parse_via_transform(content, FormatKind::Ini),
+        #[cfg(feature = "properties")]
+        Format::Properties => parse_via_transform(content, FormatKind::Properties),
+        #[cfg(feature = "env")]
+        Format::Env => parse_via_transform(content, FormatKind::Env),
         #[allow(unreachable_patterns)] // Matches feature-gated variants
         _ => Err("Unsupported input format".into()),
     }
 }
 
+/// Parse a configuration format to a value via its tape parser and the JSON emitter
+#[cfg(any(
+    feature = "hcl",
+    feature = "ini",
+    feature = "properties",
+    feature = "env"
+))]
+fn parse_via_transform(
+    content: &str,
+    kind: FormatKind,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let opts = TransformOptions::new();
+    let (json_bytes, _metrics) = transform(content.as_bytes(), kind, FormatKind::Json, &opts)
+        .map_err(|e| format!("{} parse error: {e}", kind.name()))?;
+    Ok(serde_json::from_slice(&json_bytes)?)
+}
+
 // ============================================================================
 // Output Functions
 // ============================================================================
 
+/// Serialize a value to a configuration format via the format's emitter
+#[cfg(any(
+    feature = "hcl",
+    feature = "ini",
+    feature = "properties",
+    feature = "env"
+))]
+fn emit_via_transform(
+    value: &Value,
+    kind: FormatKind,
+    pretty: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let json_bytes = serde_json::to_vec(value)?;
+    let opts = TransformOptions::new().with_pretty(pretty);
+    let (output, _metrics) = transform(&json_bytes, FormatKind::Json, kind, &opts)
+        .map_err(|e| format!("{} transform error: {e}", kind.name()))?;
+    String::from_utf8(output).map_err(Into::into)
+}
+
 /// Serialize value to string based on format
 fn value_to_string(
     value: &Value,
     format: Format,
+    options: &DocumentOptions,
     pretty: bool,
     compact: bool,
     indent: usize,
@@ -587,11 +1065,10 @@ fn value_to_string(
         Format::Gron => {
             // Output as gron format
             let json_str = serde_json::to_string(value)?;
-            let opts = if compact {
-                GronOptions::default().compact()
-            } else {
-                GronOptions::default()
-            };
+            let mut opts = GronOptions::default().path_style(options.path_style);
+            if compact {
+                opts = opts.compact();
+            }
             Ok(gron(&json_str, &opts)?)
         }
         #[cfg(feature = "ison")]
@@ -614,6 +1091,14 @@ fn value_to_string(
                     .map_err(|e| format!("TOON transform error: {e}"))?;
             String::from_utf8(output).map_err(Into::into)
         }
+        #[cfg(feature = "hcl")]
+        Format::Hcl => emit_via_transform(value, FormatKind::Hcl, pretty),
+        #[cfg(feature = "ini")]
+        Format::Ini => emit_via_transform(value, FormatKind::Ini, pretty),
+        #[cfg(feature = "properties")]
+        Format::Properties => emit_via_transform(value, FormatKind::Properties, pretty),
+        #[cfg(feature = "env")]
+        Format::Env => emit_via_transform(value, FormatKind::Env, pretty),
         #[allow(unreachable_patterns)] // Matches feature-gated variants
         _ => Err("Unsupported output format".into()),
     }
@@ -623,29 +1108,65 @@ fn value_to_string(
 // I/O Helpers
 // ============================================================================
 
+/// Batch size for feeding streamed input to the batch processors
+#[cfg(feature = "ison")]
+const STREAM_BATCH_BYTES: usize = 4 * 1024 * 1024;
+
+/// JSON files at least this large are gronned without building a tape
+const GRON_STREAM_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// Open a file or stdin, transparently decompressing gzip/zstd/bzip2 input
+fn open_input(path: Option<&Path>) -> Result<Box<dyn BufRead + Send>, Box<dyn std::error::Error>> {
+    let reader: Box<dyn BufRead + Send> = match path {
+        Some(p) => Box::new(io::BufReader::new(fs::File::open(p)?)),
+        None => Box::new(io::BufReader::new(io::stdin())),
+    };
+    Ok(compression::auto_decoder(reader)?.1)
+}
+
+/// Read a whole, possibly compressed, file
+fn read_file(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let mut content = String::new();
+    open_input(Some(path))?.read_to_string(&mut content)?;
+    Ok(content)
+}
+
 /// Read input from file or stdin
 fn read_input(path: Option<&PathBuf>) -> Result<String, Box<dyn std::error::Error>> {
-    if let Some(p) = path {
-        Ok(fs::read_to_string(p)?)
-    } else {
-        let mut input = String::new();
-        io::stdin().read_to_string(&mut input)?;
-        Ok(input)
-    }
+    let mut input = String::new();
+    open_input(path.map(PathBuf::as_path))?.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Open the output file or stdout, compressing by the file's extension
+/// (`-o out.jsonl.zst`)
+fn open_output(
+    path: Option<&PathBuf>,
+) -> Result<CompressedWriter<Box<dyn Write>>, Box<dyn std::error::Error>> {
+    let Some(p) = path else {
+        return Ok(CompressedWriter::Plain(Box::new(io::stdout().lock())));
+    };
+    let compression = Compression::from_path(p);
+    if !compression.is_available() {
+        return Err(format!(
+            "{} output requires building with the `{}` feature",
+            compression.name(),
+            compression.name()
+        )
+        .into());
+    }
+    let file: Box<dyn Write> = Box::new(io::BufWriter::new(fs::File::create(p)?));
+    Ok(CompressedWriter::new(file, compression)?)
 }
 
 /// Write output to file or stdout
 fn write_output(output: &str, path: Option<&PathBuf>) -> Result<(), Box<dyn std::error::Error>> {
-    if let Some(p) = path {
-        fs::write(p, output)?;
-    } else {
-        let stdout = io::stdout();
-        let mut handle = stdout.lock();
-        handle.write_all(output.as_bytes())?;
-        if !output.ends_with('\n') {
-            handle.write_all(b"\n")?;
-        }
+    let mut writer = open_output(path)?;
+    writer.write_all(output.as_bytes())?;
+    if path.is_none() && !output.ends_with('\n') {
+        writer.write_all(b"\n")?;
     }
+    writer.finish()?;
     Ok(())
 }
 
@@ -656,6 +1177,24 @@ fn write_output(output: &str, path: Option<&PathBuf>) -> Result<(), Box<dyn std:
 fn main() {
     let args = Args::parse();
 
+    // Pin the SIMD kernel table before any kernel runs
+    let simd = if let Some(level) = args.simd {
+        fionn_core::simd::configure(level)
+    } else {
+        if let Err(e) = SimdDispatch::from_env()
+            && !args.quiet
+        {
+            eprintln!("Warning: {e}; using detected SIMD level");
+        }
+        fionn_core::simd::dispatch()
+    };
+    if simd.level != simd.requested && !args.quiet {
+        eprintln!(
+            "Warning: SIMD level {} is not supported on this CPU, using {}",
+            simd.requested, simd.level
+        );
+    }
+
     let result = match &args.command {
         Commands::Gron { .. } => handle_gron(&args),
         Commands::Diff { .. } => handle_diff(&args),
@@ -669,6 +1208,7 @@ fn main() {
         Commands::Schema { .. } => handle_schema(&args),
         Commands::Ops { .. } => handle_ops(&args),
         Commands::Stats { .. } => handle_stats(&args),
+        Commands::Index { .. } => handle_index(&args),
         Commands::Bench { .. } => {
             handle_bench(&args);
             Ok(())
@@ -681,6 +1221,7 @@ fn main() {
     }
 }
 
+#[allow(clippy::too_many_lines)] // Dispatches JSONL streaming, ungron, tape and value-based gron
 fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Gron {
         input,
@@ -691,22 +1232,18 @@ fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
         prefix,
         query,
         sort,
+        json,
+        root,
+        stream,
+        types,
     } = &args.command
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
-    let content = read_input(input.as_ref())?;
-    let input_format = resolve_input_format(args.from, input.as_deref(), content.as_bytes());
-
-    if *ungron {
-        // Convert gron back to structured format
-        let value = ungron_to_value(&content)?;
-        let output_format = resolve_output_format(args.to, Format::Json);
-        let output = value_to_string(&value, output_format, args.pretty, args.compact, 2)?;
-        write_output(&output, args.output.as_ref())?;
-        return Ok(());
-    }
+    let mut reader = open_input(input.as_deref())?;
+    let input_format = resolve_input_format(args.from, input.as_deref(), reader.fill_buf()?);
 
     // Build gron options
     let mut opts = GronOptions::with_prefix(prefix);
@@ -719,9 +1256,89 @@ fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     if *values_only {
         opts = opts.values_only();
     }
-    if args.color {
+    if args.color && !*json {
         opts = opts.color();
     }
+    if *json {
+        opts = opts.json();
+    }
+    if *root {
+        opts = opts.stream_root(StreamRoot::Prefixed);
+    }
+    if *types {
+        opts = opts.show_types();
+    }
+    if let Some(style) = args.path_style {
+        opts = opts.path_style(style);
+    }
+
+    // JSONL streams record by record, so compressed archives never sit in memory
+    if input_format == Format::Jsonl && !*ungron && query.is_none() && !*sort {
+        let jsonl_opts = GronJsonlOptions {
+            gron: opts,
+            ..GronJsonlOptions::default()
+        };
+        let mut writer = open_output(args.output.as_ref())?;
+        gron_jsonl_streaming(reader, &jsonl_opts, &mut writer)?;
+        writer.finish()?;
+        return Ok(());
+    }
+
+    // Large single documents stream straight from the reader; queries skip
+    // non-matching subtrees unparsed
+    let large_input = input
+        .as_deref()
+        .and_then(|p| fs::metadata(p).ok())
+        .is_some_and(|m| m.len() >= GRON_STREAM_THRESHOLD);
+    if input_format == Format::Json && (*stream || (large_input && !*ungron && !*sort)) {
+        let mut writer = open_output(args.output.as_ref())?;
+        if let Some(query_str) = query {
+            let query_opts = GronQueryOptions {
+                gron: opts,
+                max_matches: 0,
+                include_containers: false,
+            };
+            gron_query_streaming(reader, &Query::parse(query_str)?, &query_opts, &mut writer)?;
+        } else {
+            gron_streaming(reader, &opts, &mut writer)?;
+        }
+        writer.finish()?;
+        return Ok(());
+    }
+
+    // Indexed JSONL gron ungrons record by record, one document per line
+    if *ungron && resolve_output_format(args.to, Format::Json) == Format::Jsonl {
+        let mut writer = open_output(args.output.as_ref())?;
+        let ungron_opts = UngronJsonlOptions::default()
+            .path_style(opts.path_style)
+            .stream_root(opts.stream_root);
+        ungron_jsonl_streaming(reader, &ungron_opts, &mut writer)?;
+        writer.finish()?;
+        return Ok(());
+    }
+
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+
+    if *ungron {
+        // Convert gron back to structured format
+        let output_format = resolve_output_format(args.to, Format::Json);
+        if let Some(output) = ungron_native(&content, output_format, &opts)? {
+            write_output(&output, args.output.as_ref())?;
+            return Ok(());
+        }
+        let value = ungron_to_value_with_root(&content, opts.path_style, opts.stream_root)?;
+        let output = value_to_string(
+            &value,
+            output_format,
+            &documents,
+            args.pretty,
+            args.compact,
+            2,
+        )?;
+        write_output(&output, args.output.as_ref())?;
+        return Ok(());
+    }
 
     // For JSON input, use tape-based gron for maximum performance
     // For other formats, parse to value first then use value-based gron
@@ -750,7 +1367,7 @@ fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             }
         } else {
             // Fall back to value-based gron
-            let value = parse_to_value(&content, input_format)?;
+            let value = parse_to_value(&content, input_format, &documents)?;
             let json_str = serde_json::to_string(&value)?;
             let mut result = gron(&json_str, &opts)?;
             if *sort {
@@ -760,9 +1377,19 @@ fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             }
             result
         }
+    } else if query.is_none()
+        && let Some(mut result) = gron_native(&content, input_format, &opts)?
+    {
+        // TOML/YAML gron from native values keeps dates and tags
+        if *sort {
+            let mut lines: Vec<&str> = result.lines().collect();
+            lines.sort_unstable();
+            result = lines.join("\n");
+        }
+        result
     } else {
         // Non-JSON input: parse to value first
-        let value = parse_to_value(&content, input_format)?;
+        let value = parse_to_value(&content, input_format, &documents)?;
         let json_str = serde_json::to_string(&value)?;
 
         if let Some(query_str) = query {
@@ -788,6 +1415,57 @@ fn handle_gron(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Gron TOML or YAML from its native value, so dates and tags keep their types
+#[allow(unused_variables, clippy::unnecessary_wraps)] // Formats are feature-gated
+fn gron_native(
+    content: &str,
+    format: Format,
+    opts: &GronOptions,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    match format {
+        #[cfg(feature = "toml")]
+        Format::Toml => {
+            let value: toml_crate::Value = toml_crate::from_str(content)?;
+            Ok(Some(gron_typed(&value, opts)?))
+        }
+        #[cfg(feature = "yaml")]
+        Format::Yaml => {
+            let value: serde_yaml::Value = serde_yaml::from_str(content)?;
+            Ok(Some(gron_typed(&value, opts)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Ungron into TOML or YAML through the format's builder, honoring type
+/// annotations
+#[allow(unused_variables, clippy::unnecessary_wraps)] // Formats are feature-gated
+fn ungron_native(
+    content: &str,
+    format: Format,
+    options: &GronOptions,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    if options.path_style != PathStyle::Js {
+        return Ok(None);
+    }
+    match format {
+        #[cfg(feature = "toml")]
+        Format::Toml => {
+            let mut builder = TomlBuilder::new();
+            let value = ungron_with_builder_and_root(content, &mut builder, options.stream_root)?;
+            Ok(Some(builder.serialize_pretty(&value)?))
+        }
+        #[cfg(feature = "yaml")]
+        Format::Yaml => {
+            let mut builder = YamlBuilder::new();
+            let value = ungron_with_builder_and_root(content, &mut builder, options.stream_root)?;
+            Ok(Some(builder.serialize(&value)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[allow(clippy::too_many_lines)] // Dispatches json-patch, merge-patch, gron and tape diff formats
 fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Diff {
         file1,
@@ -798,16 +1476,17 @@ fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
-    let content1 = fs::read_to_string(file1)?;
-    let content2 = fs::read_to_string(file2)?;
+    let content1 = read_file(file1)?;
+    let content2 = read_file(file2)?;
 
     let format1 = resolve_input_format(args.from, Some(file1), content1.as_bytes());
     let format2 = resolve_input_format(args.from, Some(file2), content2.as_bytes());
 
     // Parse both files to values (works with all formats)
-    let mut value1 = parse_to_value(&content1, format1)?;
-    let mut value2 = parse_to_value(&content2, format2)?;
+    let mut value1 = parse_to_value(&content1, format1, &documents)?;
+    let mut value2 = parse_to_value(&content2, format2, &documents)?;
 
     // If ignore-order is set, sort all arrays recursively
     if *ignore_order {
@@ -827,20 +1506,18 @@ fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             // Diff as gron (show changed lines)
             let json1 = serde_json::to_string(&value1)?;
             let json2 = serde_json::to_string(&value2)?;
+            let gron_opts = GronOptions::default().path_style(args.path_style.unwrap_or_default());
 
             // Use tape-based gron for JSON, value-based for others
             let (gron1, gron2) = if both_json {
                 let tape1 = DsonTape::parse(&content1).map_err(|e| format!("Parse error: {e}"))?;
                 let tape2 = DsonTape::parse(&content2).map_err(|e| format!("Parse error: {e}"))?;
                 (
-                    gron_from_tape(&tape1, &GronOptions::default())?,
-                    gron_from_tape(&tape2, &GronOptions::default())?,
+                    gron_from_tape(&tape1, &gron_opts)?,
+                    gron_from_tape(&tape2, &gron_opts)?,
                 )
             } else {
-                (
-                    gron(&json1, &GronOptions::default())?,
-                    gron(&json2, &GronOptions::default())?,
-                )
+                (gron(&json1, &gron_opts)?, gron(&json2, &gron_opts)?)
             };
 
             let lines1: std::collections::HashSet<&str> = gron1.lines().collect();
@@ -860,6 +1537,11 @@ fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             diff_lines.sort_unstable();
             diff_lines.join("\n")
         }
+        #[cfg(feature = "csv")]
+        "csv" => {
+            let diff = csv_diff(&value1, &value2, &documents.csv.diff_options());
+            serde_json::to_string_pretty(&csv_diff_to_value(&diff))?
+        }
         "tape" => {
             // Native tape diff - only works for JSON inputs
             if !both_json {
@@ -869,7 +1551,7 @@ fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             let tape2 = DsonTape::parse(&content2).map_err(|e| format!("Parse error: {e}"))?;
             let tape_diff = diff_tapes(&tape1, &tape2)?;
             // Convert TapeDiff operations to JSON array for output
-            let ops: Vec<serde_json::Value> = tape_diff
+            let mut ops: serde_json::Value = tape_diff
                 .operations
                 .iter()
                 .map(|op| match op {
@@ -891,12 +1573,21 @@ fn handle_diff(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
                     _ => serde_json::json!({"op": "unknown"}),
                 })
                 .collect();
+            if let Some(style) = args.path_style {
+                restyle_patch_paths(&mut ops, PathStyle::Pointer, style)?;
+            }
             serde_json::to_string_pretty(&ops)?
         }
         _ => {
             // Default: JSON Patch (RFC 6902) - works with all formats
             let patch = json_diff(&value1, &value2);
-            serde_json::to_string_pretty(&patch)?
+            if let Some(style) = args.path_style {
+                let mut ops = serde_json::to_value(&patch)?;
+                restyle_patch_paths(&mut ops, PathStyle::Pointer, style)?;
+                serde_json::to_string_pretty(&ops)?
+            } else {
+                serde_json::to_string_pretty(&patch)?
+            }
         }
     };
 
@@ -910,28 +1601,67 @@ fn handle_patch(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
         patch,
         patch_format,
         dry_run,
+        preserve_format,
     } = &args.command
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
-    let content = fs::read_to_string(file)?;
-    let patch_content = fs::read_to_string(patch)?;
+    let content = read_file(file)?;
+    let patch_content = read_file(patch)?;
 
     let input_format = resolve_input_format(args.from, Some(file), content.as_bytes());
-    let value = parse_to_value(&content, input_format)?;
+
+    if *preserve_format {
+        if args.to.is_some_and(|to| to != input_format) {
+            return Err("--preserve-format cannot convert between formats".into());
+        }
+        let format = match input_format {
+            Format::Json => fionn_diff::PreserveFormat::Json,
+            #[cfg(feature = "yaml")]
+            Format::Yaml => fionn_diff::PreserveFormat::Yaml,
+            #[cfg(feature = "toml")]
+            Format::Toml => fionn_diff::PreserveFormat::Toml,
+            #[allow(unreachable_patterns)]
+            other => {
+                return Err(format!("--preserve-format does not support {other:?} input").into());
+            }
+        };
+        let output = if patch_format.as_str() == "merge-patch" {
+            let patch_value: Value = serde_json::from_str(&patch_content)?;
+            fionn_diff::merge_patch_preserving(&content, format, &patch_value)?
+        } else {
+            let patch_ops = read_json_patch(&patch_content, args.path_style)?;
+            fionn_diff::apply_patch_preserving(&content, format, &patch_ops)?
+        };
+        if *dry_run && !args.quiet {
+            eprintln!("Dry run - would produce:");
+        }
+        write_output(&output, args.output.as_ref())?;
+        return Ok(());
+    }
+
+    let value = parse_to_value(&content, input_format, &documents)?;
 
     let patched = if patch_format.as_str() == "merge-patch" {
         let patch_value: Value = serde_json::from_str(&patch_content)?;
         json_merge_patch(&value, &patch_value)
     } else {
         // JSON Patch (RFC 6902)
-        let patch_ops: fionn_diff::JsonPatch = serde_json::from_str(&patch_content)?;
+        let patch_ops = read_json_patch(&patch_content, args.path_style)?;
         apply_patch(&value, &patch_ops)?
     };
 
     let output_format = resolve_output_format(args.to, input_format);
-    let output = value_to_string(&patched, output_format, args.pretty, args.compact, 2)?;
+    let output = value_to_string(
+        &patched,
+        output_format,
+        &documents,
+        args.pretty,
+        args.compact,
+        2,
+    )?;
 
     if *dry_run && !args.quiet {
         eprintln!("Dry run - would produce:");
@@ -941,6 +1671,41 @@ fn handle_patch(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Parse a JSON Patch whose `path`/`from` members are written in `style`
+fn read_json_patch(
+    content: &str,
+    style: Option<PathStyle>,
+) -> Result<fionn_diff::JsonPatch, Box<dyn std::error::Error>> {
+    let Some(style) = style.filter(|style| *style != PathStyle::Pointer) else {
+        return Ok(serde_json::from_str(content)?);
+    };
+    let mut ops: Value = serde_json::from_str(content)?;
+    restyle_patch_paths(&mut ops, style, PathStyle::Pointer)?;
+    Ok(serde_json::from_value(ops)?)
+}
+
+/// Rewrite the `path` and `from` members of patch operations between styles
+fn restyle_patch_paths(
+    ops: &mut Value,
+    from: PathStyle,
+    to: PathStyle,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Value::Array(ops) = ops else {
+        return Ok(());
+    };
+    for op in ops {
+        let Value::Object(fields) = op else {
+            continue;
+        };
+        for key in ["path", "from"] {
+            if let Some(Value::String(path)) = fields.get_mut(key) {
+                *path = from.convert(path, to)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn handle_merge(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Merge {
         files,
@@ -950,22 +1715,23 @@ fn handle_merge(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     if files.is_empty() {
         return Err("No files provided for merge".into());
     }
 
     // Parse first file as base (works with all formats)
-    let content = fs::read_to_string(&files[0])?;
+    let content = read_file(&files[0])?;
     let input_format = resolve_input_format(args.from, Some(&files[0]), content.as_bytes());
-    let mut result = parse_to_value(&content, input_format)?;
+    let mut result = parse_to_value(&content, input_format, &documents)?;
 
     // Check if all files are JSON for potential tape-based optimization
     let all_json = input_format == Format::Json
         && files[1..].iter().all(|f| {
-            fs::read(f)
-                .map(|c| resolve_input_format(args.from, Some(f), &c) == Format::Json)
-                .unwrap_or(false)
+            read_file(f).is_ok_and(|c| {
+                resolve_input_format(args.from, Some(f), c.as_bytes()) == Format::Json
+            })
         });
 
     // Only use tape-based merge for simple replace strategy (default behavior)
@@ -973,7 +1739,7 @@ fn handle_merge(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
 
     if use_tape_optimization {
         // Optimize: use tape-based merge for two JSON files
-        let overlay_content = fs::read_to_string(&files[1])?;
+        let overlay_content = read_file(&files[1])?;
         let base_tape = DsonTape::parse(&content).map_err(|e| format!("Parse error: {e}"))?;
         let overlay_tape =
             DsonTape::parse(&overlay_content).map_err(|e| format!("Parse error: {e}"))?;
@@ -986,9 +1752,9 @@ fn handle_merge(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     } else {
         // General case: value-based merge with array strategy support
         for file in &files[1..] {
-            let file_content = fs::read_to_string(file)?;
+            let file_content = read_file(file)?;
             let file_format = resolve_input_format(args.from, Some(file), file_content.as_bytes());
-            let overlay_value = parse_to_value(&file_content, file_format)?;
+            let overlay_value = parse_to_value(&file_content, file_format, &documents)?;
 
             result = if *deep {
                 deep_merge_with_array_strategy(&result, &overlay_value, array_strategy)
@@ -999,7 +1765,14 @@ fn handle_merge(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let output_format = resolve_output_format(args.to, input_format);
-    let output = value_to_string(&result, output_format, args.pretty, args.compact, 2)?;
+    let output = value_to_string(
+        &result,
+        output_format,
+        &documents,
+        args.pretty,
+        args.compact,
+        2,
+    )?;
     write_output(&output, args.output.as_ref())?;
     Ok(())
 }
@@ -1010,17 +1783,33 @@ fn handle_query(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
         file,
         raw,
         first,
+        index,
+        index_file,
     } = &args.command
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
+    let segments = query_segments(query, args.path_style)?;
 
-    let content = read_input(file.as_ref())?;
-    let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let value = parse_to_value(&content, input_format)?;
-
-    // Execute query and collect matching values
-    let matches = execute_query(&value, query);
+    let indexed = if *index || index_file.is_some() {
+        let file = file.as_deref().ok_or("--index requires an input file")?;
+        query_with_index(file, index_file.as_deref(), &segments, args.quiet)?
+    } else {
+        None
+    };
+    let matches = if let Some(matches) = indexed {
+        matches
+    } else {
+        let content = read_input(file.as_ref())?;
+        let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
+        let value = parse_to_value(&content, input_format, &documents)?;
+
+        // Execute query and collect matching values
+        let mut results = Vec::new();
+        collect_query_matches(&value, &segments, 0, &mut results);
+        results
+    };
 
     // Apply --first flag
     let matches = if *first && !matches.is_empty() {
@@ -1046,11 +1835,25 @@ fn handle_query(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             .join("\n")
     } else if matches.len() == 1 {
         // Single result: output directly
-        value_to_string(&matches[0], output_format, args.pretty, args.compact, 2)?
+        value_to_string(
+            &matches[0],
+            output_format,
+            &documents,
+            args.pretty,
+            args.compact,
+            2,
+        )?
     } else {
         // Multiple results: output as array
         let arr = Value::Array(matches);
-        value_to_string(&arr, output_format, args.pretty, args.compact, 2)?
+        value_to_string(
+            &arr,
+            output_format,
+            &documents,
+            args.pretty,
+            args.compact,
+            2,
+        )?
     };
 
     write_output(&output, args.output.as_ref())?;
@@ -1074,6 +1877,28 @@ fn execute_query(value: &Value, query: &str) -> Vec<Value> {
     results
 }
 
+/// Parse a query into segments, as a path in `style` when one is given
+fn query_segments(
+    query: &str,
+    style: Option<PathStyle>,
+) -> Result<Vec<QuerySegmentParsed>, ParsePathError> {
+    let Some(style) = style else {
+        let query = query.trim();
+        if query == "." || query.is_empty() {
+            return Ok(Vec::new());
+        }
+        return Ok(parse_query_path(query));
+    };
+    Ok(style
+        .parse(query)?
+        .into_iter()
+        .map(|component| match component {
+            PathComponent::Field(name) => QuerySegmentParsed::Field(name),
+            PathComponent::ArrayIndex(index) => QuerySegmentParsed::Index(index),
+        })
+        .collect())
+}
+
 /// Parse query path into segments
 fn parse_query_path(query: &str) -> Vec<QuerySegmentParsed> {
     let mut segments = Vec::new();
@@ -1250,14 +2075,155 @@ fn recursive_field_search(
     }
 }
 
+/// Answer a query by seeking through a sidecar index
+///
+/// Returns `None` when the index is missing or stale, or cannot narrow the
+/// query, so the caller falls back to reading the whole file.
+fn query_with_index(
+    file: &Path,
+    index_path: Option<&Path>,
+    segments: &[QuerySegmentParsed],
+    quiet: bool,
+) -> Result<Option<Vec<Value>>, Box<dyn std::error::Error>> {
+    let index_path = index_path.map_or_else(|| SidecarIndex::default_path(file), Path::to_path_buf);
+    let sidecar = match SidecarIndex::load(&index_path) {
+        Ok(sidecar) => sidecar,
+        Err(e) => {
+            if !quiet {
+                eprintln!("Warning: cannot use index {}: {e}", index_path.display());
+            }
+            return Ok(None);
+        }
+    };
+    if !sidecar.is_fresh(file)? {
+        if !quiet {
+            eprintln!(
+                "Warning: index {} is stale; rebuild it with `fionn index build`",
+                index_path.display()
+            );
+        }
+        return Ok(None);
+    }
+
+    if segments.is_empty() {
+        return Ok(None);
+    }
+    let mut source = io::BufReader::new(fs::File::open(file)?);
+    let mut results = Vec::new();
+    match &sidecar.data {
+        IndexData::Json(index) => {
+            collect_indexed_matches(index, &mut source, 0, segments, 0, &mut results)?;
+        }
+        IndexData::Jsonl(index) => {
+            // The records form the document's top-level array
+            let records: Vec<usize> = match segments.first() {
+                Some(QuerySegmentParsed::Index(i)) => {
+                    (*i < index.len()).then_some(*i).into_iter().collect()
+                }
+                Some(QuerySegmentParsed::Wildcard) => (0..index.len()).collect(),
+                _ => return Ok(None),
+            };
+            for record in records {
+                let range = index.record(record).unwrap_or_default();
+                let value = serde_json::from_slice(&read_range(&mut source, range)?)?;
+                collect_query_matches(&value, segments, 1, &mut results);
+            }
+        }
+    }
+    Ok(Some(results))
+}
+
+/// [`collect_query_matches`] over index entries, parsing only the byte
+/// ranges the index cannot resolve further
+fn collect_indexed_matches(
+    index: &JsonIndex,
+    source: &mut io::BufReader<fs::File>,
+    entry: usize,
+    segments: &[QuerySegmentParsed],
+    segment_idx: usize,
+    results: &mut Vec<Value>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(e) = index.entry(entry) else {
+        return Ok(());
+    };
+    let value_type = e.value_type();
+    let segment = segments.get(segment_idx);
+    let next = match segment {
+        Some(QuerySegmentParsed::Field(name))
+            if e.is_expanded() && value_type == JsonType::Object =>
+        {
+            // Last duplicate wins, as when the whole document is parsed
+            let hash = key_hash(name);
+            let mut found = None;
+            for child in index.children(entry) {
+                if index.entries()[child].key_hash() == Some(hash)
+                    && index.key(source, child)?.as_deref() == Some(name)
+                {
+                    found = Some(child);
+                }
+            }
+            found.into_iter().collect()
+        }
+        Some(QuerySegmentParsed::Index(i)) if e.is_expanded() && value_type == JsonType::Array => {
+            index.element(entry, *i).into_iter().collect()
+        }
+        Some(QuerySegmentParsed::Wildcard) if e.is_expanded() && value_type == JsonType::Array => {
+            index.children(entry).collect()
+        }
+        Some(QuerySegmentParsed::Field(_) | QuerySegmentParsed::Index(_)) if e.is_expanded() => {
+            Vec::new()
+        }
+        _ => {
+            let value = serde_json::from_slice(&index.read_raw(source, entry)?)?;
+            collect_query_matches(&value, segments, segment_idx, results);
+            return Ok(());
+        }
+    };
+    for child in next {
+        collect_indexed_matches(index, source, child, segments, segment_idx + 1, results)?;
+    }
+    Ok(())
+}
+
+fn handle_index(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let Commands::Index { action } = &args.command else {
+        unreachable!()
+    };
+    let IndexAction::Build { file, depth } = action;
+
+    if Compression::from_path(file) != Compression::None {
+        return Err("indexes need an uncompressed file to seek into".into());
+    }
+    let sidecar = match args.from.or_else(|| detect_format_from_extension(file)) {
+        Some(Format::Jsonl) => SidecarIndex::build_jsonl(file)?,
+        Some(Format::Json | Format::Auto) | None => SidecarIndex::build_json(file, *depth)?,
+        Some(_) => return Err("only JSON and JSONL files can be indexed".into()),
+    };
+    let index_path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| SidecarIndex::default_path(file));
+    sidecar.save(&index_path)?;
+
+    if !args.quiet {
+        let summary = match &sidecar.data {
+            IndexData::Json(index) => format!("{} values", index.entries().len()),
+            IndexData::Jsonl(index) => format!("{} records", index.len()),
+        };
+        eprintln!("Indexed {summary} into {}", index_path.display());
+    }
+    Ok(())
+}
+
 fn handle_convert(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Convert { file, sort_keys } = &args.command else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let mut value = parse_to_value(&content, input_format)?;
+    let mut value = parse_to_value(&content, input_format, &documents)?;
 
     if *sort_keys {
         value = sort_json_keys(&value);
@@ -1268,7 +2234,14 @@ fn handle_convert(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
         return Err("Output format must be specified with --to for convert command".into());
     }
 
-    let output = value_to_string(&value, output_format, args.pretty, args.compact, 2)?;
+    let output = value_to_string(
+        &value,
+        output_format,
+        &documents,
+        args.pretty,
+        args.compact,
+        2,
+    )?;
     write_output(&output, args.output.as_ref())?;
     Ok(())
 }
@@ -1282,17 +2255,25 @@ fn handle_format(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let mut value = parse_to_value(&content, input_format)?;
+    let mut value = parse_to_value(&content, input_format, &documents)?;
 
     if *sort_keys {
         value = sort_json_keys(&value);
     }
 
     let output_format = resolve_output_format(args.to, input_format);
-    let output = value_to_string(&value, output_format, true, args.compact, *indent)?;
+    let output = value_to_string(
+        &value,
+        output_format,
+        &documents,
+        true,
+        args.compact,
+        *indent,
+    )?;
     write_output(&output, args.output.as_ref())?;
     Ok(())
 }
@@ -1301,28 +2282,32 @@ fn handle_validate(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Validate { file, strict } = &args.command else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
+    let limits = if *strict {
+        args.limits.apply(ParseLimits::strict())?
+    } else {
+        documents.limits
+    };
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
 
     // Try to parse - will error if invalid
-    let value = parse_to_value(&content, input_format)?;
+    let (value, mut warnings) = parse_with_limits(&content, input_format, &documents, &limits)?;
 
     // Strict mode: additional validation checks
     if *strict {
-        let mut warnings = Vec::new();
         validate_strict(&value, "", &mut warnings);
-
-        if !warnings.is_empty() {
-            for warning in &warnings {
-                eprintln!("Warning: {warning}");
-            }
-            return Err(format!(
-                "Strict validation failed with {} warning(s)",
-                warnings.len()
-            )
-            .into());
-        }
+    }
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+    if *strict && !warnings.is_empty() {
+        return Err(format!(
+            "Strict validation failed with {} warning(s)",
+            warnings.len()
+        )
+        .into());
     }
 
     if !args.quiet {
@@ -1341,6 +2326,14 @@ fn handle_validate(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             Format::Isonl => "ISONL",
             #[cfg(feature = "toon")]
             Format::Toon => "TOON",
+            #[cfg(feature = "hcl")]
+            Format::Hcl => "HCL",
+            #[cfg(feature = "ini")]
+            Format::Ini => "INI",
+            #[cfg(feature = "properties")]
+            Format::Properties => "Properties",
+            #[cfg(feature = "env")]
+            Format::Env => "Env",
             Format::Gron => "Gron",
             Format::Auto => "Auto-detected",
         };
@@ -1350,6 +2343,36 @@ fn handle_validate(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Nesting depth and longest string or key of a parsed value
+fn value_shape(value: &Value) -> (usize, usize) {
+    match value {
+        Value::String(s) => (0, s.len()),
+        Value::Array(arr) => arr
+            .iter()
+            .map(value_shape)
+            .fold((1, 0), |(d, l), (cd, cl)| (d.max(cd + 1), l.max(cl))),
+        Value::Object(obj) => obj.iter().fold((1, 0), |(d, l), (key, val)| {
+            let (cd, cl) = value_shape(val);
+            (d.max(cd + 1), l.max(cl).max(key.len()))
+        }),
+        _ => (0, 0),
+    }
+}
+
+/// Keys on the way to the first container nested `depth` levels deep
+fn keys_to_depth(value: &Value, depth: usize) -> Option<Vec<&str>> {
+    match value {
+        Value::Object(_) | Value::Array(_) if depth <= 1 => Some(Vec::new()),
+        Value::Object(obj) => obj.iter().find_map(|(key, child)| {
+            let mut keys = keys_to_depth(child, depth - 1)?;
+            keys.insert(0, key.as_str());
+            Some(keys)
+        }),
+        Value::Array(arr) => arr.iter().find_map(|child| keys_to_depth(child, depth - 1)),
+        _ => None,
+    }
+}
+
 /// Perform strict validation checks on a value
 fn validate_strict(value: &Value, path: &str, warnings: &mut Vec<String>) {
     match value {
@@ -1406,14 +2429,12 @@ fn validate_strict(value: &Value, path: &str, warnings: &mut Vec<String>) {
                 validate_strict(item, &new_path, warnings);
             }
         }
-        Value::String(s) => {
-            // Check for suspicious string patterns
-            if s.trim().is_empty() && !s.is_empty() {
-                warnings.push(format!(
-                    "Whitespace-only string at {}",
-                    if path.is_empty() { "root" } else { path }
-                ));
-            }
+        // Check for suspicious string patterns
+        Value::String(s) if s.trim().is_empty() && !s.is_empty() => {
+            warnings.push(format!(
+                "Whitespace-only string at {}",
+                if path.is_empty() { "root" } else { path }
+            ));
         }
         Value::Number(n) => {
             // Check for NaN-like or problematic numbers
@@ -1456,21 +2477,29 @@ fn handle_stream(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
         unreachable!()
     };
 
-    let content = read_input(file.as_ref())?;
-    let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
+    if args.path_style.is_some() && (filter.is_some() || fields.is_some()) {
+        return Err("--path-style does not apply to --filter or --fields".into());
+    }
+
+    let mut reader = open_input(file.as_deref())?;
+    let input_format = resolve_input_format(args.from, file.as_deref(), reader.fill_buf()?);
+    let mut writer = open_output(args.output.as_ref())?;
 
     // Check if we should use SIMD-accelerated ISONL processing
     #[cfg(feature = "ison")]
     if *use_simd && matches!(input_format, Format::Isonl) {
-        return handle_stream_isonl_simd(
+        handle_stream_isonl_simd(
             args,
-            content.as_bytes(),
+            reader,
+            &mut writer,
             filter.as_deref(),
             *limit,
             skip.unwrap_or(0),
             fields.as_deref(),
             ops.as_deref(),
-        );
+        )?;
+        writer.finish()?;
+        return Ok(());
     }
 
     // Suppress warning when ison feature is not enabled
@@ -1479,14 +2508,17 @@ fn handle_stream(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     // Fall back to standard line-by-line processing
     handle_stream_generic(
         args,
-        &content,
+        reader,
+        &mut writer,
         input_format,
         filter,
         limit,
         skip,
         fields,
         ops,
-    )
+    )?;
+    writer.finish()?;
+    Ok(())
 }
 
 /// Generic streaming handler for JSONL and other line-delimited formats
@@ -1494,7 +2526,8 @@ fn handle_stream(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
 #[allow(clippy::ref_option)] // Pattern matches CLI argument types
 fn handle_stream_generic(
     args: &Args,
-    content: &str,
+    reader: impl BufRead,
+    writer: &mut impl Write,
     input_format: Format,
     filter: &Option<String>,
     limit: &Option<usize>,
@@ -1502,9 +2535,11 @@ fn handle_stream_generic(
     fields: &Option<String>,
     ops: &Option<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut results = Vec::new();
+    let documents = DocumentOptions::from_args(args)?;
+    let mut output_count = 0;
     let mut count = 0;
     let skip_count = skip.unwrap_or(0);
+    let output_format = resolve_output_format(args.to, Format::Json);
 
     // Parse field list for selective extraction
     let field_list: Vec<&str> = fields
@@ -1519,7 +2554,17 @@ fn handle_stream_generic(
         .transpose()?
         .unwrap_or_default();
 
-    for line in content.lines() {
+    let filter = filter.as_deref().map(FilterExpr::parse);
+    // Records can be tested before parsing when the filter sees them unchanged
+    let prefilter = filter
+        .as_ref()
+        .filter(|_| field_list.is_empty() && dson_ops.is_empty())
+        .filter(|_| matches!(input_format, Format::Json | Format::Jsonl))
+        .and_then(Prefilter::compile);
+    let (mut prefilter_hits, mut prefilter_misses) = (0, 0);
+
+    for line in reader.lines() {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
@@ -1530,8 +2575,26 @@ fn handle_stream_generic(
             continue;
         }
 
+        // Reject non-matching records from their raw bytes; decided records
+        // are still validated so malformed lines fail as they would on parse
+        let mut known_match = false;
+        if let Some(prefilter) = &prefilter {
+            match prefilter.check_validated(line.as_bytes())? {
+                Verdict::Reject => {
+                    prefilter_hits += 1;
+                    count += 1;
+                    continue;
+                }
+                Verdict::Match => {
+                    prefilter_hits += 1;
+                    known_match = true;
+                }
+                Verdict::Inconclusive => prefilter_misses += 1,
+            }
+        }
+
         // Parse line
-        let mut value = parse_to_value(line, input_format)?;
+        let mut value = parse_to_value(&line, input_format, &documents)?;
 
         // Apply field filtering if specified
         if !field_list.is_empty() {
@@ -1544,18 +2607,21 @@ fn handle_stream_generic(
         }
 
         // Apply filter if specified
-        if let Some(filter_expr) = filter
-            && !evaluate_filter(&value, filter_expr)
+        if let Some(filter) = &filter
+            && !known_match
+            && !evaluate_filter(&value, filter)
         {
             count += 1;
             continue; // Skip non-matching records
         }
 
-        results.push(value);
+        let output = value_to_string(&value, output_format, &documents, false, true, 0)?;
+        writeln!(writer, "{output}")?;
+        output_count += 1;
 
         // Check limit
         if let Some(lim) = limit
-            && results.len() >= *lim
+            && output_count >= *lim
         {
             break;
         }
@@ -1563,24 +2629,28 @@ fn handle_stream_generic(
         count += 1;
     }
 
-    // Output results
-    let output_format = resolve_output_format(args.to, Format::Json);
-    for value in &results {
-        let output = value_to_string(value, output_format, false, true, 0)?;
-        println!("{output}");
-    }
-
     if !args.quiet {
-        eprintln!("Processed {} records", results.len());
+        eprintln!("Processed {output_count} records");
+        if prefilter.is_some() {
+            eprintln!(
+                "Prefilter decided {prefilter_hits} of {} records from raw bytes",
+                prefilter_hits + prefilter_misses
+            );
+        }
     }
     Ok(())
 }
 
 /// SIMD-accelerated ISONL streaming handler
+///
+/// Input is fed to the batch processor in line-aligned batches, so large or
+/// compressed files are never held in memory at once.
 #[cfg(feature = "ison")]
+#[allow(clippy::too_many_arguments)] // Stream handler needs multiple configuration parameters
 fn handle_stream_isonl_simd(
     args: &Args,
-    data: &[u8],
+    reader: impl BufRead,
+    writer: &mut impl Write,
     filter: Option<&str>,
     limit: Option<usize>,
     skip: usize,
@@ -1589,12 +2659,9 @@ fn handle_stream_isonl_simd(
 ) -> Result<(), Box<dyn std::error::Error>> {
     use fionn_stream::skiptape::isonl::{IsonlBatchResult, SimdIsonlBatchProcessor};
 
-    let mut processor = SimdIsonlBatchProcessor::new();
-
-    // Process entire batch with SIMD acceleration
-    let batch_result: IsonlBatchResult = processor
-        .process_batch_unfiltered(data)
-        .map_err(|e| format!("ISONL processing error: {e}"))?;
+    let documents = DocumentOptions::from_args(args)?;
+    let mut processor = SimdIsonlBatchProcessor::new().with_limits(documents.limits);
+    let filter = filter.map(FilterExpr::parse);
 
     // Parse field list for selective extraction
     let field_list: Vec<&str> = fields
@@ -1609,57 +2676,65 @@ fn handle_stream_isonl_simd(
 
     let mut count = 0;
     let mut output_count = 0;
+    let (mut total_lines, mut successful_lines, mut failed_lines) = (0, 0, 0);
     let output_format = resolve_output_format(args.to, Format::Json);
 
-    for doc in &batch_result.documents {
-        // Skip initial records if requested
-        if count < skip {
-            count += 1;
-            continue;
-        }
+    'batches: for batch in compression::line_batches(reader, STREAM_BATCH_BYTES) {
+        // Process each batch with SIMD acceleration
+        let batch_result: IsonlBatchResult = processor
+            .process_batch_unfiltered(&batch?)
+            .map_err(|e| format!("ISONL processing error: {e}"))?;
+        total_lines += batch_result.statistics.total_lines;
+        successful_lines += batch_result.statistics.successful_lines;
+        failed_lines += batch_result.statistics.failed_lines;
+
+        for doc in &batch_result.documents {
+            // Skip initial records if requested
+            if count < skip {
+                count += 1;
+                continue;
+            }
 
-        // Parse the JSON document
-        let mut value: Value = serde_json::from_str(doc)?;
+            // Parse the JSON document
+            let mut value: Value = serde_json::from_str(doc)?;
 
-        // Apply field filtering if specified
-        if !field_list.is_empty() {
-            value = extract_fields(&value, &field_list);
-        }
+            // Apply field filtering if specified
+            if !field_list.is_empty() {
+                value = extract_fields(&value, &field_list);
+            }
 
-        // Apply DSON operations if specified
-        if !dson_ops.is_empty() {
-            value = apply_dson_ops(value, &dson_ops)?;
-        }
+            // Apply DSON operations if specified
+            if !dson_ops.is_empty() {
+                value = apply_dson_ops(value, &dson_ops)?;
+            }
 
-        // Apply filter if specified
-        if let Some(filter_expr) = filter
-            && !evaluate_filter(&value, filter_expr)
-        {
-            count += 1;
-            continue;
-        }
+            // Apply filter if specified
+            if let Some(filter) = &filter
+                && !evaluate_filter(&value, filter)
+            {
+                count += 1;
+                continue;
+            }
 
-        // Output the result
-        let output = value_to_string(&value, output_format, false, true, 0)?;
-        println!("{output}");
-        output_count += 1;
+            // Output the result
+            let output = value_to_string(&value, output_format, &documents, false, true, 0)?;
+            writeln!(writer, "{output}")?;
+            output_count += 1;
 
-        // Check limit
-        if let Some(lim) = limit
-            && output_count >= lim
-        {
-            break;
-        }
+            // Check limit
+            if let Some(lim) = limit
+                && output_count >= lim
+            {
+                break 'batches;
+            }
 
-        count += 1;
+            count += 1;
+        }
     }
 
     if !args.quiet {
         eprintln!(
-            "Processed {} records ({} successful, {} errors) via SIMD",
-            batch_result.statistics.total_lines,
-            batch_result.statistics.successful_lines,
-            batch_result.statistics.failed_lines
+            "Processed {total_lines} records ({successful_lines} successful, {failed_lines} errors) via SIMD"
         );
     }
     Ok(())
@@ -1737,95 +2812,9 @@ fn apply_dson_ops(
 }
 
 /// Evaluate a filter expression against a value
-/// Supports: path queries (existence check), comparisons (>, <, >=, <=, ==, !=)
-fn evaluate_filter(value: &Value, filter: &str) -> bool {
-    let filter = filter.trim();
-
-    // Try to parse as comparison expression
-    for op in &[">=", "<=", "!=", "==", ">", "<"] {
-        if let Some(pos) = filter.find(op) {
-            let path = filter[..pos].trim();
-            let rhs = filter[pos + op.len()..].trim();
-
-            // Get value at path
-            let matches = execute_query(value, path);
-            if matches.is_empty() {
-                return false;
-            }
-
-            let lhs = &matches[0];
-
-            // Parse right-hand side
-            let rhs_value: Value = if rhs == "null" {
-                Value::Null
-            } else if rhs == "true" {
-                Value::Bool(true)
-            } else if rhs == "false" {
-                Value::Bool(false)
-            } else if let Ok(n) = rhs.parse::<i64>() {
-                Value::Number(n.into())
-            } else if let Ok(n) = rhs.parse::<f64>() {
-                serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number)
-            } else {
-                // String (remove quotes if present)
-                let s = rhs.trim_matches('"').trim_matches('\'');
-                Value::String(s.to_string())
-            };
-
-            return compare_values(lhs, &rhs_value, op);
-        }
-    }
-
-    // No comparison operator - treat as existence check
-    let matches = execute_query(value, filter);
-    !matches.is_empty()
-}
-
-/// Compare two JSON values with an operator
-fn compare_values(lhs: &Value, rhs: &Value, op: &str) -> bool {
-    match op {
-        "==" => lhs == rhs,
-        "!=" => lhs != rhs,
-        ">" | ">=" | "<" | "<=" => {
-            // Numeric comparison
-            let lhs_num = match lhs {
-                Value::Number(n) => n.as_f64(),
-                _ => None,
-            };
-            let rhs_num = match rhs {
-                Value::Number(n) => n.as_f64(),
-                _ => None,
-            };
-
-            if let (Some(l), Some(r)) = (lhs_num, rhs_num) {
-                match op {
-                    ">" => l > r,
-                    ">=" => l >= r,
-                    "<" => l < r,
-                    "<=" => l <= r,
-                    _ => false,
-                }
-            } else {
-                // String comparison fallback
-                let lhs_str = match lhs {
-                    Value::String(s) => s.as_str(),
-                    _ => return false,
-                };
-                let rhs_str = match rhs {
-                    Value::String(s) => s.as_str(),
-                    _ => return false,
-                };
-                match op {
-                    ">" => lhs_str > rhs_str,
-                    ">=" => lhs_str >= rhs_str,
-                    "<" => lhs_str < rhs_str,
-                    "<=" => lhs_str <= rhs_str,
-                    _ => false,
-                }
-            }
-        }
-        _ => false,
-    }
+/// Supports: path queries (existence check), comparisons (>, <, >=, <=, ==, !=), `in [..]`
+fn evaluate_filter(value: &Value, filter: &FilterExpr) -> bool {
+    filter.test(execute_query(value, &filter.path).first())
 }
 
 fn handle_schema(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
@@ -1836,10 +2825,11 @@ fn handle_schema(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let value = parse_to_value(&content, input_format)?;
+    let value = parse_to_value(&content, input_format, &documents)?;
 
     let schema = infer_schema(&value);
 
@@ -1858,14 +2848,17 @@ fn handle_ops(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Ops { op, file, path } = &args.command else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let value = parse_to_value(&content, input_format)?;
+    let value = parse_to_value(&content, input_format, &documents)?;
+
+    let style = args.path_style.unwrap_or(PathStyle::Dotted);
 
     // Navigate to path if specified
     let target = if let Some(p) = path {
-        get_at_path(&value, p).ok_or_else(|| format!("Path not found: {p}"))?
+        get_at_path(&value, &style.parse(p)?).ok_or_else(|| format!("Path not found: {p}"))?
     } else {
         &value
     };
@@ -1909,9 +2902,10 @@ fn handle_ops(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
             };
             Value::String(type_name.to_string())
         }
-        "flatten" => flatten_value(target, ""),
+        "flatten" => flatten_value(target, style),
         "paths" => {
-            let paths = collect_paths(target, "");
+            let mut paths = Vec::new();
+            collect_paths(target, style, &mut Vec::new(), &mut paths);
             Value::Array(paths.into_iter().map(Value::String).collect())
         }
         "first" => {
@@ -1970,7 +2964,14 @@ fn handle_ops(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let output_format = resolve_output_format(args.to, Format::Json);
-    let output = value_to_string(&result, output_format, args.pretty, args.compact, 2)?;
+    let output = value_to_string(
+        &result,
+        output_format,
+        &documents,
+        args.pretty,
+        args.compact,
+        2,
+    )?;
     write_output(&output, args.output.as_ref())?;
     Ok(())
 }
@@ -1979,10 +2980,11 @@ fn handle_stats(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let Commands::Stats { file } = &args.command else {
         unreachable!()
     };
+    let documents = DocumentOptions::from_args(args)?;
 
     let content = read_input(file.as_ref())?;
     let input_format = resolve_input_format(args.from, file.as_deref(), content.as_bytes());
-    let value = parse_to_value(&content, input_format)?;
+    let value = parse_to_value(&content, input_format, &documents)?;
 
     let stats = compute_stats(&value);
     let output = serde_json::to_string_pretty(&stats)?;
@@ -1997,6 +2999,9 @@ fn handle_bench(args: &Args) {
         unreachable!()
     };
 
+    let simd = fionn_core::simd::dispatch();
+    println!("SIMD kernels: {} ({})", simd.level, simd.source);
+
     let data = r#"{"test": "data", "number": 42, "array": [1,2,3], "nested": {"a": 1, "b": 2}}"#
         .repeat(1000);
 
@@ -2051,6 +3056,55 @@ fn sort_json_keys(value: &Value) -> Value {
     }
 }
 
+/// Render CSV diff operations as a JSON array
+#[cfg(feature = "csv")]
+fn csv_diff_to_value(diff: &CsvDiff) -> Value {
+    diff.operations
+        .iter()
+        .map(|op| match op {
+            CsvDiffOp::AddRow { position, values } => {
+                serde_json::json!({"op": "add_row", "position": position, "values": values})
+            }
+            CsvDiffOp::RemoveRow { position, key } => {
+                serde_json::json!({"op": "remove_row", "position": position, "key": key})
+            }
+            CsvDiffOp::ModifyRow {
+                position,
+                key,
+                changes,
+            } => {
+                let changes: Vec<Value> = changes
+                    .iter()
+                    .map(|c| {
+                        serde_json::json!({"column": c.column, "old": c.old_value, "new": c.new_value})
+                    })
+                    .collect();
+                serde_json::json!({"op": "modify_row", "position": position, "key": key, "changes": changes})
+            }
+            CsvDiffOp::MoveRow {
+                from_position,
+                to_position,
+                key,
+            } => serde_json::json!({
+                "op": "move_row", "from": from_position, "position": to_position, "key": key
+            }),
+            CsvDiffOp::AddColumn { name, position } => {
+                serde_json::json!({"op": "add_column", "name": name, "position": position})
+            }
+            CsvDiffOp::RemoveColumn { name, position } => {
+                serde_json::json!({"op": "remove_column", "name": name, "position": position})
+            }
+            CsvDiffOp::RenameColumn {
+                old_name,
+                new_name,
+                position,
+            } => serde_json::json!({
+                "op": "rename_column", "from": old_name, "name": new_name, "position": position
+            }),
+        })
+        .collect()
+}
+
 /// Sort all arrays recursively (for ignore-order diff)
 fn sort_arrays_recursive(value: &Value) -> Value {
     match value {
@@ -2117,84 +3171,83 @@ fn deep_merge_with_array_strategy(base: &Value, overlay: &Value, array_strategy:
     }
 }
 
-/// Get value at JSON path
-fn get_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
-    let mut current = value;
-    for segment in path.split('.').filter(|s| !s.is_empty()) {
-        match current {
-            Value::Object(obj) => {
-                current = obj.get(segment)?;
-            }
-            Value::Array(arr) => {
-                let idx: usize = segment.parse().ok()?;
-                current = arr.get(idx)?;
-            }
-            _ => return None,
-        }
-    }
-    Some(current)
+/// Navigate to a path; numeric names index arrays and numeric indices
+/// name object fields, since dotted and pointer paths cannot tell them apart
+fn get_at_path<'a>(value: &'a Value, path: &[PathComponent]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(value, |current, component| match (current, component) {
+            (Value::Object(obj), PathComponent::Field(key)) => obj.get(key),
+            (Value::Object(obj), PathComponent::ArrayIndex(index)) => obj.get(&index.to_string()),
+            (Value::Array(arr), PathComponent::ArrayIndex(index)) => arr.get(*index),
+            (Value::Array(arr), PathComponent::Field(key)) => arr.get(key.parse::<usize>().ok()?),
+            _ => None,
+        })
 }
 
-/// Flatten nested object to dotted keys
-fn flatten_value(value: &Value, prefix: &str) -> Value {
+/// Flatten nested values to one entry per leaf, keyed by path
+fn flatten_value(value: &Value, style: PathStyle) -> Value {
     let mut result = serde_json::Map::new();
-    flatten_recursive(value, prefix, &mut result);
+    flatten_recursive(value, style, &mut Vec::new(), &mut result);
     Value::Object(result)
 }
 
-fn flatten_recursive(value: &Value, prefix: &str, result: &mut serde_json::Map<String, Value>) {
+fn flatten_recursive(
+    value: &Value,
+    style: PathStyle,
+    path: &mut Vec<PathComponent>,
+    result: &mut serde_json::Map<String, Value>,
+) {
     match value {
         Value::Object(obj) => {
             for (k, v) in obj {
-                let new_prefix = if prefix.is_empty() {
-                    k.clone()
-                } else {
-                    format!("{prefix}.{k}")
-                };
-                flatten_recursive(v, &new_prefix, result);
+                path.push(PathComponent::Field(k.clone()));
+                flatten_recursive(v, style, path, result);
+                path.pop();
             }
         }
         Value::Array(arr) => {
             for (i, v) in arr.iter().enumerate() {
-                let new_prefix = format!("{prefix}[{i}]");
-                flatten_recursive(v, &new_prefix, result);
+                path.push(PathComponent::ArrayIndex(i));
+                flatten_recursive(v, style, path, result);
+                path.pop();
             }
         }
         _ => {
-            if !prefix.is_empty() {
-                result.insert(prefix.to_string(), value.clone());
+            if !path.is_empty() {
+                result.insert(style.format(path), value.clone());
             }
         }
     }
 }
 
 /// Collect all paths in a value
-fn collect_paths(value: &Value, prefix: &str) -> Vec<String> {
-    let mut paths = Vec::new();
-    if !prefix.is_empty() {
-        paths.push(prefix.to_string());
+fn collect_paths(
+    value: &Value,
+    style: PathStyle,
+    path: &mut Vec<PathComponent>,
+    paths: &mut Vec<String>,
+) {
+    if !path.is_empty() {
+        paths.push(style.format(path));
     }
 
     match value {
         Value::Object(obj) => {
             for (k, v) in obj {
-                let new_prefix = if prefix.is_empty() {
-                    k.clone()
-                } else {
-                    format!("{prefix}.{k}")
-                };
-                paths.extend(collect_paths(v, &new_prefix));
+                path.push(PathComponent::Field(k.clone()));
+                collect_paths(v, style, path, paths);
+                path.pop();
             }
         }
         Value::Array(arr) => {
             for (i, v) in arr.iter().enumerate() {
-                let new_prefix = format!("{prefix}[{i}]");
-                paths.extend(collect_paths(v, &new_prefix));
+                path.push(PathComponent::ArrayIndex(i));
+                collect_paths(v, style, path, paths);
+                path.pop();
             }
         }
         _ => {}
     }
-    paths
 }
 
 /// Infer JSON Schema from value
@@ -2388,3 +3441,97 @@ fn compute_stats(value: &Value) -> Value {
 
     Value::Object(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition_is_consistent() {
+        Args::command().debug_assert();
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_dialect_threads_into_parse_and_diff() {
+        let args = Args::parse_from([
+            "fionn",
+            "--csv-null",
+            "NA",
+            "--csv-trim",
+            "--infer-types",
+            "diff",
+            "a.csv",
+            "b.csv",
+        ]);
+        let documents = DocumentOptions::from_args(&args).unwrap();
+        let source = parse_to_value("id,qty\n1, 2 \n2,NA\n", Format::Csv, &documents).unwrap();
+        let target = parse_to_value("id,qty\n1,2.0\n2, NA \n", Format::Csv, &documents).unwrap();
+        assert_eq!(source[1]["qty"], Value::Null);
+        assert!(csv_diff(&source, &target, &documents.csv.diff_options()).is_empty());
+
+        let plain = DocumentOptions::from_args(&Args::parse_from(["fionn", "stats"])).unwrap();
+        let source = parse_to_value("id,qty\n1, 2 \n", Format::Csv, &plain).unwrap();
+        assert_eq!(source[0]["qty"], " 2 ");
+    }
+
+    #[test]
+    fn test_query_segments_follow_path_style() {
+        let value = serde_json::json!({"a": {"b": [5, 6]}});
+        for (style, query) in [
+            (None, ".a.b[1]"),
+            (Some(PathStyle::Pointer), "/a/b/1"),
+            (Some(PathStyle::JsonPath), "$['a']['b'][1]"),
+            (Some(PathStyle::Dotted), "a.b[1]"),
+        ] {
+            let segments = query_segments(query, style).unwrap();
+            let mut results = Vec::new();
+            collect_query_matches(&value, &segments, 0, &mut results);
+            assert_eq!(results, vec![serde_json::json!(6)], "{query}");
+        }
+        assert!(query_segments("a/b", Some(PathStyle::Pointer)).is_err());
+    }
+
+    #[test]
+    fn test_limits_apply_to_every_command() {
+        let args = Args::parse_from([
+            "fionn",
+            "format",
+            "--max-depth",
+            "2",
+            "--duplicate-keys",
+            "reject",
+        ]);
+        let documents = DocumentOptions::from_args(&args).unwrap();
+        assert!(parse_to_value("[[1]]", Format::Json, &documents).is_ok());
+        assert!(parse_to_value("[[[1]]]", Format::Json, &documents).is_err());
+        assert!(parse_to_value(r#"{"a":1,"a":2}"#, Format::Json, &documents).is_err());
+
+        let plain = DocumentOptions::from_args(&Args::parse_from(["fionn", "format"])).unwrap();
+        assert!(parse_to_value(r#"{"a":1,"a":2}"#, Format::Json, &plain).is_ok());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_depth_limit_reports_offset() {
+        let args = Args::parse_from(["fionn", "format", "--max-depth", "2"]);
+        let documents = DocumentOptions::from_args(&args).unwrap();
+        let yaml = "top: 1\nouter:\n  inner:\n    x: 1\n";
+        let err = parse_to_value(yaml, Format::Yaml, &documents).unwrap_err();
+        assert!(err.to_string().ends_with("at byte 16"), "{err}");
+    }
+
+    #[test]
+    fn test_big_integers_are_rejected_not_rounded() {
+        let big = r#"{"n":123456789012345678901234567890}"#;
+        let preserve = Args::parse_from(["fionn", "format", "--big-integers", "preserve"]);
+        let err = DocumentOptions::from_args(&preserve).unwrap_err();
+        assert!(err.to_string().contains("unsupported"));
+
+        let reject = Args::parse_from(["fionn", "format", "--big-integers", "reject"]);
+        let documents = DocumentOptions::from_args(&reject).unwrap();
+        assert!(parse_to_value(big, Format::Json, &documents).is_err());
+        assert!(parse_to_value(r#"{"n":1}"#, Format::Json, &documents).is_ok());
+    }
+}
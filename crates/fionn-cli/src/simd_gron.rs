@@ -8,8 +8,9 @@ use fionn_gron::{
     ErrorMode, GronJsonlOptions, GronOptions, GronQueryOptions, IndexFormat, Query, gron,
     gron_jsonl, gron_query, ungron_to_value,
 };
+use fionn_stream::compression;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, BufRead, Read, Write};
 use std::path::PathBuf;
 
 /// SIMD-accelerated gron - make JSON greppable
@@ -127,13 +128,17 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
 
 #[allow(clippy::ref_option)] // Pattern matches CLI argument types
 fn read_input(path: &Option<PathBuf>) -> Result<String, Box<dyn std::error::Error>> {
-    if let Some(p) = path {
-        Ok(fs::read_to_string(p)?)
+    // gzip/zstd/bzip2 input is detected by magic bytes and decoded on the fly
+    let reader: Box<dyn BufRead + Send> = if let Some(p) = path {
+        Box::new(io::BufReader::new(fs::File::open(p)?))
     } else {
-        let mut input = String::new();
-        io::stdin().read_to_string(&mut input)?;
-        Ok(input)
-    }
+        Box::new(io::BufReader::new(io::stdin()))
+    };
+    let mut input = String::new();
+    compression::auto_decoder(reader)?
+        .1
+        .read_to_string(&mut input)?;
+    Ok(input)
 }
 
 fn write_output(output: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -37,6 +37,22 @@ pub enum FormatKind {
     /// TOON (Token-Oriented Object Notation) - LLM-optimized
     #[cfg(feature = "toon")]
     Toon = 5,
+
+    /// HCL (`HashiCorp` Configuration Language, Terraform)
+    #[cfg(feature = "hcl")]
+    Hcl = 6,
+
+    /// INI (sections of `key = value` pairs)
+    #[cfg(feature = "ini")]
+    Ini = 7,
+
+    /// Java `.properties`
+    #[cfg(feature = "properties")]
+    Properties = 8,
+
+    /// Dotenv (`.env`) files
+    #[cfg(feature = "env")]
+    Env = 9,
 }
 
 impl FormatKind {
@@ -55,6 +71,14 @@ impl FormatKind {
             Self::Ison => "ison",
             #[cfg(feature = "toon")]
             Self::Toon => "toon",
+            #[cfg(feature = "hcl")]
+            Self::Hcl => "hcl",
+            #[cfg(feature = "ini")]
+            Self::Ini => "ini",
+            #[cfg(feature = "properties")]
+            Self::Properties => "properties",
+            #[cfg(feature = "env")]
+            Self::Env => "env",
         }
     }
 
@@ -73,6 +97,14 @@ impl FormatKind {
             "ison" | "isonl" => Some(Self::Ison),
             #[cfg(feature = "toon")]
             "toon" => Some(Self::Toon),
+            #[cfg(feature = "hcl")]
+            "hcl" | "tf" | "terraform" => Some(Self::Hcl),
+            #[cfg(feature = "ini")]
+            "ini" | "cfg" => Some(Self::Ini),
+            #[cfg(feature = "properties")]
+            "properties" => Some(Self::Properties),
+            #[cfg(feature = "env")]
+            "env" | "dotenv" => Some(Self::Env),
             _ => None,
         }
     }
@@ -92,6 +124,14 @@ impl FormatKind {
             Self::Ison => true,
             #[cfg(feature = "toon")]
             Self::Toon => false,
+            #[cfg(feature = "hcl")]
+            Self::Hcl => true,
+            #[cfg(feature = "ini")]
+            Self::Ini => true,
+            #[cfg(feature = "properties")]
+            Self::Properties => true,
+            #[cfg(feature = "env")]
+            Self::Env => true,
         }
     }
 
@@ -110,8 +150,57 @@ impl FormatKind {
             Self::Ison => true, // :type:id references
             #[cfg(feature = "toon")]
             Self::Toon => false,
+            #[cfg(feature = "hcl")]
+            Self::Hcl => false,
+            #[cfg(feature = "ini")]
+            Self::Ini => false,
+            #[cfg(feature = "properties")]
+            Self::Properties => false,
+            #[cfg(feature = "env")]
+            Self::Env => true, // ${VAR} interpolation
+        }
+    }
+
+    /// Check if scalar values keep their type (number, boolean, null)
+    ///
+    /// INI, `.properties` and dotenv values are untyped strings.
+    #[must_use]
+    pub const fn supports_typed_scalars(self) -> bool {
+        match self {
+            #[cfg(feature = "ini")]
+            Self::Ini => false,
+            #[cfg(feature = "properties")]
+            Self::Properties => false,
+            #[cfg(feature = "env")]
+            Self::Env => false,
+            #[allow(unreachable_patterns)] // Matches feature-gated variants
+            _ => true,
+        }
+    }
+
+    /// Maximum container nesting the format can represent, `None` if unbounded
+    ///
+    /// Dotenv is a flat list of variables, INI adds one level of sections.
+    /// `.properties` keys are flattened with dots, so nesting is unbounded.
+    #[must_use]
+    pub const fn max_nesting(self) -> Option<usize> {
+        match self {
+            #[cfg(feature = "ini")]
+            Self::Ini => Some(2),
+            #[cfg(feature = "env")]
+            Self::Env => Some(1),
+            #[allow(unreachable_patterns)] // Matches feature-gated variants
+            _ => None,
         }
     }
+
+    /// Check if the format has a native array/sequence syntax
+    ///
+    /// INI, `.properties` and dotenv can only spell arrays as indexed keys.
+    #[must_use]
+    pub const fn supports_arrays(self) -> bool {
+        self.supports_typed_scalars()
+    }
 }
 
 impl fmt::Display for FormatKind {
@@ -174,6 +263,14 @@ impl FormatKind {
             "ison" | "isonl" => Some(Self::Ison),
             #[cfg(feature = "toon")]
             "toon" => Some(Self::Toon),
+            #[cfg(feature = "hcl")]
+            "hcl" | "tf" | "tfvars" => Some(Self::Hcl),
+            #[cfg(feature = "ini")]
+            "ini" | "cfg" => Some(Self::Ini),
+            #[cfg(feature = "properties")]
+            "properties" => Some(Self::Properties),
+            #[cfg(feature = "env")]
+            "env" => Some(Self::Env),
             _ => None,
         };
         format.map(|f| DetectionResult {
@@ -182,6 +279,24 @@ impl FormatKind {
         })
     }
 
+    /// Detect format from a file name
+    ///
+    /// Handles names without a usable extension, such as `.env` and
+    /// `.env.production`, before falling back to
+    /// [`detect_from_extension`](Self::detect_from_extension).
+    #[must_use]
+    pub fn detect_from_file_name(name: &str) -> Option<DetectionResult> {
+        #[cfg(feature = "env")]
+        if name == ".env" || name.starts_with(".env.") {
+            return Some(DetectionResult {
+                format: Self::Env,
+                confidence: Confidence::Exact,
+            });
+        }
+        let (_, ext) = name.rsplit_once('.')?;
+        Self::detect_from_extension(ext)
+    }
+
     /// Detect format from MIME type
     ///
     /// Returns `Some(result)` with `Confidence::Exact` for known MIME types.
@@ -206,6 +321,10 @@ impl FormatKind {
             "application/toml" | "text/x-toml" => Some(Self::Toml),
             #[cfg(feature = "csv")]
             "text/csv" | "text/tab-separated-values" => Some(Self::Csv),
+            #[cfg(feature = "hcl")]
+            "application/hcl" | "text/x-hcl" => Some(Self::Hcl),
+            #[cfg(feature = "properties")]
+            "text/x-java-properties" => Some(Self::Properties),
             _ => None,
         };
         format.map(|f| DetectionResult {
@@ -229,6 +348,10 @@ impl FormatKind {
     /// | CSV    | Contains `,` with consistent structure per line |
     /// | ISON   | Contains `:type:` reference patterns |
     /// | TOON   | Contains indented tabular data with array headers |
+    /// | HCL    | Contains `name "label" {` block headers |
+    /// | INI    | `[section]` headers with `;` comments or bare string values |
+    /// | ENV    | Every line is `NAME=value` or `export NAME=value` |
+    /// | Properties | Dotted keys with `=`, `:` separators or `!` comments |
     ///
     /// # Examples
     ///
@@ -240,6 +363,7 @@ impl FormatKind {
     /// assert_eq!(result.format, FormatKind::Json);
     /// ```
     #[must_use]
+    #[allow(clippy::too_many_lines)] // One heuristic per format, checked in priority order
     pub fn detect_from_content(content: &[u8]) -> DetectionResult {
         // Empty content defaults to JSON
         if content.is_empty() {
@@ -280,6 +404,14 @@ impl FormatKind {
                     .chars()
                     .all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.' || c == '[');
                 if looks_like_toml_section && !inside.contains(',') && !inside.contains(':') {
+                    // INI and TOML share section headers; INI values are bare strings
+                    #[cfg(feature = "ini")]
+                    if Self::looks_like_ini(trimmed) {
+                        return DetectionResult {
+                            format: Self::Ini,
+                            confidence: Confidence::Medium,
+                        };
+                    }
                     // This is likely a TOML section header, check TOML first
                     #[cfg(feature = "toml")]
                     if Self::looks_like_toml(trimmed) {
@@ -297,6 +429,33 @@ impl FormatKind {
             };
         }
 
+        // Check for HCL - block headers would otherwise read as TOML key = value
+        #[cfg(feature = "hcl")]
+        if Self::looks_like_hcl(trimmed) {
+            return DetectionResult {
+                format: Self::Hcl,
+                confidence: Confidence::Medium,
+            };
+        }
+
+        // Check for dotenv - every line is NAME=value
+        #[cfg(feature = "env")]
+        if Self::looks_like_env(trimmed) {
+            return DetectionResult {
+                format: Self::Env,
+                confidence: Confidence::Medium,
+            };
+        }
+
+        // Check for properties - dotted keys with bare values
+        #[cfg(feature = "properties")]
+        if Self::looks_like_properties(trimmed) {
+            return DetectionResult {
+                format: Self::Properties,
+                confidence: Confidence::Medium,
+            };
+        }
+
         // Check for YAML - document marker or characteristic patterns
         #[cfg(feature = "yaml")]
         if Self::looks_like_yaml(trimmed) {
@@ -306,6 +465,15 @@ impl FormatKind {
             };
         }
 
+        // Check for INI - `;` comments or bare string values
+        #[cfg(feature = "ini")]
+        if Self::looks_like_ini(trimmed) {
+            return DetectionResult {
+                format: Self::Ini,
+                confidence: Confidence::Medium,
+            };
+        }
+
         // Check for TOML - section headers or key=value
         #[cfg(feature = "toml")]
         if Self::looks_like_toml(trimmed) {
@@ -522,6 +690,115 @@ impl FormatKind {
         false
     }
 
+    /// Check if content looks like HCL
+    #[cfg(feature = "hcl")]
+    fn looks_like_hcl(content: &str) -> bool {
+        content.lines().take(20).any(|line| {
+            let line = line.trim();
+            // Block header: `type "label" ... {` with no assignment
+            line.ends_with('{')
+                && !line.contains('=')
+                && !line.contains(':')
+                && line.split_whitespace().next().is_some_and(|ident| {
+                    ident.len() > 1
+                        && ident.starts_with(|c: char| c.is_ascii_alphabetic())
+                        && ident
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                })
+        })
+    }
+
+    /// Check if content looks like INI
+    #[cfg(feature = "ini")]
+    fn looks_like_ini(content: &str) -> bool {
+        for line in content.lines().take(20) {
+            let line = line.trim();
+            if line.starts_with(';') {
+                return true;
+            }
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return false;
+            };
+            let key = key.trim();
+            if key.is_empty() {
+                return false;
+            }
+            // A bare key with inner whitespace is not valid TOML
+            if !key.starts_with(['"', '\'']) && key.contains(char::is_whitespace) {
+                return true;
+            }
+            // A bare multi-word or non-literal value is not valid TOML
+            let value = value.trim();
+            let toml_literal = value.is_empty()
+                || value.starts_with(['"', '\'', '[', '{'])
+                || value == "true"
+                || value == "false"
+                || value.parse::<f64>().is_ok();
+            if !toml_literal {
+                return true;
+            }
+        }
+        // Every pair is also valid TOML, so leave it to the TOML check
+        false
+    }
+
+    /// Check if content looks like a dotenv file
+    #[cfg(feature = "env")]
+    fn looks_like_env(content: &str) -> bool {
+        let mut vars = 0;
+        for line in content.lines().take(20) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let Some((name, _)) = line.split_once('=') else {
+                return false;
+            };
+            let is_var = name.starts_with(|c: char| c.is_ascii_uppercase() || c == '_')
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+            if !is_var {
+                return false;
+            }
+            vars += 1;
+        }
+        vars > 0
+    }
+
+    /// Check if content looks like Java properties
+    #[cfg(feature = "properties")]
+    fn looks_like_properties(content: &str) -> bool {
+        let mut dotted = false;
+        for line in content.lines().take(20) {
+            let line = line.trim();
+            if line.starts_with('!') {
+                return true;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some(sep) = line.find(['=', ':']) else {
+                return false;
+            };
+            let key = line[..sep].trim_end();
+            if key.is_empty() || key.contains(char::is_whitespace) || key.starts_with('[') {
+                return false;
+            }
+            let value = line[sep + 1..].trim_start();
+            if value.starts_with(['"', '[', '{']) {
+                return false;
+            }
+            dotted |= key.contains('.');
+        }
+        dotted
+    }
+
     /// Detect format using all available methods
     ///
     /// Tries detection in order of reliability:
@@ -623,6 +900,9 @@ pub enum FormatSpecificKind {
     #[cfg(feature = "toml")]
     /// TOML section header `[name]`
     TomlSectionHeader,
+    #[cfg(feature = "toml")]
+    /// TOML offset/local date-time, date or time
+    TomlDatetime,
 
     // === YAML (feature = "yaml") ===
     #[cfg(feature = "yaml")]
@@ -673,6 +953,41 @@ pub enum FormatSpecificKind {
     #[cfg(feature = "toon")]
     /// TOON list item (- prefix)
     ToonListItem,
+
+    // === HCL (feature = "hcl") ===
+    #[cfg(feature = "hcl")]
+    /// HCL block `type "label" { ... }`
+    HclBlock,
+    #[cfg(feature = "hcl")]
+    /// HCL attribute `name = expr`
+    HclAttribute,
+    #[cfg(feature = "hcl")]
+    /// HCL heredoc `<<EOT`
+    HclHeredoc,
+    #[cfg(feature = "hcl")]
+    /// HCL expression or `${...}` interpolation
+    HclExpression,
+
+    // === INI (feature = "ini") ===
+    #[cfg(feature = "ini")]
+    /// INI section header `[name]`
+    IniSection,
+    #[cfg(feature = "ini")]
+    /// INI key before the first section
+    IniGlobal,
+
+    // === Properties (feature = "properties") ===
+    #[cfg(feature = "properties")]
+    /// Properties value continued with a trailing backslash
+    PropertiesContinuation,
+
+    // === Dotenv (feature = "env") ===
+    #[cfg(feature = "env")]
+    /// Dotenv `export NAME=value`
+    EnvExport,
+    #[cfg(feature = "env")]
+    /// Dotenv `${NAME}` interpolation
+    EnvInterpolation,
 }
 
 impl NodeKind {
@@ -784,7 +1099,8 @@ impl FormatSpecificKind {
             Self::TomlDottedKey
             | Self::TomlInlineTable
             | Self::TomlArrayTable
-            | Self::TomlSectionHeader => FormatKind::Toml,
+            | Self::TomlSectionHeader
+            | Self::TomlDatetime => FormatKind::Toml,
 
             #[cfg(feature = "yaml")]
             Self::YamlAnchor
@@ -803,6 +1119,20 @@ impl FormatSpecificKind {
 
             #[cfg(feature = "toon")]
             Self::ToonTabular | Self::ToonFolded | Self::ToonListItem => FormatKind::Toon,
+
+            #[cfg(feature = "hcl")]
+            Self::HclBlock | Self::HclAttribute | Self::HclHeredoc | Self::HclExpression => {
+                FormatKind::Hcl
+            }
+
+            #[cfg(feature = "ini")]
+            Self::IniSection | Self::IniGlobal => FormatKind::Ini,
+
+            #[cfg(feature = "properties")]
+            Self::PropertiesContinuation => FormatKind::Properties,
+
+            #[cfg(feature = "env")]
+            Self::EnvExport | Self::EnvInterpolation => FormatKind::Env,
         }
     }
 
@@ -818,6 +1148,8 @@ impl FormatSpecificKind {
             Self::TomlArrayTable => "array-table",
             #[cfg(feature = "toml")]
             Self::TomlSectionHeader => "section-header",
+            #[cfg(feature = "toml")]
+            Self::TomlDatetime => "datetime",
 
             #[cfg(feature = "yaml")]
             Self::YamlAnchor => "anchor",
@@ -850,6 +1182,28 @@ impl FormatSpecificKind {
             Self::ToonFolded => "folded",
             #[cfg(feature = "toon")]
             Self::ToonListItem => "list-item",
+
+            #[cfg(feature = "hcl")]
+            Self::HclBlock => "block",
+            #[cfg(feature = "hcl")]
+            Self::HclAttribute => "attribute",
+            #[cfg(feature = "hcl")]
+            Self::HclHeredoc => "heredoc",
+            #[cfg(feature = "hcl")]
+            Self::HclExpression => "expression",
+
+            #[cfg(feature = "ini")]
+            Self::IniSection => "section",
+            #[cfg(feature = "ini")]
+            Self::IniGlobal => "global",
+
+            #[cfg(feature = "properties")]
+            Self::PropertiesContinuation => "continuation",
+
+            #[cfg(feature = "env")]
+            Self::EnvExport => "export",
+            #[cfg(feature = "env")]
+            Self::EnvInterpolation => "interpolation",
         }
     }
 
@@ -861,6 +1215,10 @@ impl FormatSpecificKind {
             Self::YamlAlias => true,
             #[cfg(feature = "ison")]
             Self::IsonRef => true,
+            #[cfg(feature = "hcl")]
+            Self::HclExpression => true,
+            #[cfg(feature = "env")]
+            Self::EnvInterpolation => true,
             #[allow(unreachable_patterns)] // Matches feature-gated variants
             _ => false,
         }
@@ -889,6 +1247,10 @@ impl FormatSpecificKind {
             Self::IsonFieldDecl => true,
             #[cfg(feature = "toon")]
             Self::ToonTabular => true,
+            #[cfg(feature = "hcl")]
+            Self::HclBlock => true,
+            #[cfg(feature = "ini")]
+            Self::IniSection => true,
             #[allow(unreachable_patterns)] // Matches feature-gated variants
             _ => false,
         }
@@ -906,6 +1268,7 @@ impl FormatSpecificKind {
                 "inline-table" => Some(Self::TomlInlineTable),
                 "array-table" => Some(Self::TomlArrayTable),
                 "section-header" => Some(Self::TomlSectionHeader),
+                "datetime" => Some(Self::TomlDatetime),
                 _ => None,
             },
             #[cfg(feature = "yaml")]
@@ -938,6 +1301,31 @@ impl FormatSpecificKind {
                 "list-item" => Some(Self::ToonListItem),
                 _ => None,
             },
+            #[cfg(feature = "hcl")]
+            "hcl" => match predicate {
+                "block" => Some(Self::HclBlock),
+                "attribute" => Some(Self::HclAttribute),
+                "heredoc" => Some(Self::HclHeredoc),
+                "expression" => Some(Self::HclExpression),
+                _ => None,
+            },
+            #[cfg(feature = "ini")]
+            "ini" => match predicate {
+                "section" => Some(Self::IniSection),
+                "global" => Some(Self::IniGlobal),
+                _ => None,
+            },
+            #[cfg(feature = "properties")]
+            "properties" => match predicate {
+                "continuation" => Some(Self::PropertiesContinuation),
+                _ => None,
+            },
+            #[cfg(feature = "env")]
+            "env" => match predicate {
+                "export" => Some(Self::EnvExport),
+                "interpolation" => Some(Self::EnvInterpolation),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -1135,6 +1523,81 @@ mod tests {
         assert_eq!(result.format, FormatKind::Csv);
     }
 
+    #[cfg(feature = "hcl")]
+    #[test]
+    fn test_detect_hcl() {
+        let hcl = "resource \"aws_instance\" \"web\" {\n  ami = \"ami-123\"\n}\n";
+        let result = FormatKind::detect_from_content(hcl.as_bytes());
+        assert_eq!(result.format, FormatKind::Hcl);
+        assert_eq!(
+            FormatKind::detect_from_extension("tf").unwrap().format,
+            FormatKind::Hcl
+        );
+    }
+
+    #[cfg(feature = "ini")]
+    #[test]
+    fn test_detect_ini() {
+        let ini = "; settings\n[server]\nhost = example.com\nport = 8080\n";
+        let result = FormatKind::detect_from_content(ini.as_bytes());
+        assert_eq!(result.format, FormatKind::Ini);
+        let ini_bare = "[server]\nname = my server\n";
+        let result = FormatKind::detect_from_content(ini_bare.as_bytes());
+        assert_eq!(result.format, FormatKind::Ini);
+        let ini_key = "[server]\nmax connections = 10\n";
+        let result = FormatKind::detect_from_content(ini_key.as_bytes());
+        assert_eq!(result.format, FormatKind::Ini);
+        // Content that is also valid TOML is never claimed as INI
+        let ambiguous = "[server]\nport = 8080\n";
+        let result = FormatKind::detect_from_content(ambiguous.as_bytes());
+        assert_ne!(result.format, FormatKind::Ini);
+    }
+
+    #[cfg(all(feature = "ini", feature = "toml"))]
+    #[test]
+    fn test_detect_toml_not_ini() {
+        let toml_section = "[package]\nname = \"myapp\"\nversion = 1";
+        let result = FormatKind::detect_from_content(toml_section.as_bytes());
+        assert_eq!(result.format, FormatKind::Toml);
+    }
+
+    #[cfg(feature = "env")]
+    #[test]
+    fn test_detect_env() {
+        let env = "# db\nexport DATABASE_URL=postgres://localhost/db\nDEBUG=1\n";
+        let result = FormatKind::detect_from_content(env.as_bytes());
+        assert_eq!(result.format, FormatKind::Env);
+        assert_eq!(
+            FormatKind::detect_from_file_name(".env.production")
+                .unwrap()
+                .format,
+            FormatKind::Env
+        );
+    }
+
+    #[cfg(feature = "properties")]
+    #[test]
+    fn test_detect_properties() {
+        let props = "! app\nserver.port=8080\nspring.datasource.url: jdbc:h2:mem\n";
+        let result = FormatKind::detect_from_content(props.as_bytes());
+        assert_eq!(result.format, FormatKind::Properties);
+        assert_eq!(
+            FormatKind::detect_from_file_name("app.properties")
+                .unwrap()
+                .format,
+            FormatKind::Properties
+        );
+    }
+
+    #[cfg(feature = "ini")]
+    #[test]
+    fn test_ini_section_predicate() {
+        let kind = FormatSpecificKind::from_namespaced("ini", "section").unwrap();
+        assert_eq!(kind.format(), FormatKind::Ini);
+        assert_eq!(kind.predicate_name(), "section");
+        assert!(NodeKind::Header.matches(NodeKind::FormatSpecific(kind)));
+    }
+
     #[test]
     fn test_detect_from_content_empty() {
         let result = FormatKind::detect_from_content(&[]);
@@ -5,8 +5,11 @@
 //!
 //! - [`error`] - Error types and Result alias
 //! - [`format`](mod@format) - Format types and node kind classification
+//! - [`limits`] - Parse limits and RFC 8259 strictness policy
+//! - [`number`] - Typed JSON numbers, parsed once
 //! - [`path`] - JSON path parsing utilities
 //! - [`schema`] - Schema-based filtering
+//! - [`simd`] - Runtime SIMD kernel dispatch
 //! - [`value`] - Operation value types
 //! - [`operations`] - DSON operation types
 //! - [`tape_source`] - Format-agnostic tape traversal abstraction
@@ -28,6 +31,10 @@ pub mod diffable;
 pub mod error;
 /// Format types and node kind classification for multi-format support
 pub mod format;
+/// Parse limits and RFC 8259 strictness policy
+pub mod limits;
+/// Typed JSON numbers with their lexemes
+pub mod number;
 /// Core operation types
 pub mod operations;
 /// Format-agnostic patch application traits
@@ -38,6 +45,8 @@ pub mod path;
 pub mod predicate;
 /// Schema-based filtering for DOMless processing
 pub mod schema;
+/// Runtime SIMD kernel dispatch shared by every accelerated routine
+pub mod simd;
 /// Format-agnostic tape traversal abstraction for multi-format support
 pub mod tape_source;
 /// Operation value types for DSON operations
@@ -50,20 +59,26 @@ pub use error::{DsonError, Result};
 pub use format::{
     Confidence, DetectionResult, FormatKind, FormatSpecificKind, NodeKind, ParsingContext,
 };
+pub use limits::{LimitViolation, ParseLimits};
+pub use number::{JsonNumber, NumberValue};
 pub use operations::{DsonOperation, MergeStrategy};
 pub use path::{
-    ParsedPath, PathCache, PathComponent, PathComponentRange, PathComponentRef, parse_simd,
-    parse_simd_ref_into,
+    ParsedPath, PathCache, PathComponent, PathComponentRange, PathComponentRef, PathStyle,
+    parse_simd, parse_simd_ref_into,
 };
 pub use predicate::{
     ContextPredicate, FidelityAnnotation, KindPredicate, LossCategory, ParsedPredicate,
 };
 pub use schema::{CompiledSchema, MatchType, SchemaFilter, SchemaPattern};
+pub use simd::{SimdDispatch, SimdLevel};
 pub use value::OperationValue;
 
 // Tape abstraction re-exports
 pub use tape_source::{TapeIterator, TapeNodeKind, TapeNodeRef, TapeSource, TapeValue};
-pub use value_builder::{JsonBuilder, PathSegment, ValueBuilder, set_at_path_json};
+pub use value_builder::{
+    JsonBuilder, ParseTypeAnnotationError, PathSegment, TypeAnnotation, ValueBuilder,
+    set_at_path_json,
+};
 
 // Format-specific builder re-exports
 #[cfg(feature = "toml")]
@@ -0,0 +1,903 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Parse limits and RFC 8259 strictness policy
+//!
+//! The parsers accept anything their backends accept: duplicate object
+//! keys, arbitrarily deep nesting, integers no 64-bit type can hold and
+//! unpaired UTF-16 surrogate escapes. [`ParseLimits`] describes what an
+//! untrusted document may contain, and [`check_json`] enforces it in a
+//! single pass over the raw bytes before the real parser runs.
+//!
+//! ```
+//! use fionn_core::limits::{DuplicateKeys, LimitViolation, ParseLimits, check_json};
+//!
+//! let limits = ParseLimits::strict();
+//! assert!(check_json(br#"{"a":1}"#, &limits).is_ok());
+//!
+//! let err = check_json(br#"{"a":1,"a":2}"#, &limits).unwrap_err();
+//! assert!(matches!(err, LimitViolation::DuplicateKey { .. }));
+//!
+//! // Report duplicates instead of rejecting them
+//! let limits = limits.with_duplicate_keys(DuplicateKeys::Report);
+//! let report = check_json(br#"{"a":1,"a":2}"#, &limits).unwrap();
+//! assert_eq!(report.warnings.len(), 1);
+//! ```
+//!
+//! Syntax errors are left to the parser: the checker never rejects a
+//! document for being malformed, only for breaking a limit.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::error::DsonError;
+use crate::value_builder::PathSegment;
+
+/// Nesting depth allowed by [`ParseLimits::strict`]
+pub const STRICT_MAX_DEPTH: usize = 512;
+
+/// What to do with an object key that appears twice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateKeys {
+    /// Accept the document and let the parser pick a value
+    #[default]
+    Allow,
+    /// Accept the document and list each duplicate in the report
+    Report,
+    /// Reject the document
+    Reject,
+}
+
+/// What to do with an integer outside the `i64`/`u64` range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BigIntegers {
+    /// Leave it to the parser, which may round it to `f64` or reject it
+    #[default]
+    Lossy,
+    /// Reject the document
+    Reject,
+    /// Keep the lexeme so the parser can store a raw number
+    Preserve,
+}
+
+/// Error returned when a policy name is not recognised
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown policy '{0}'")]
+pub struct ParsePolicyError(pub String);
+
+impl DuplicateKeys {
+    /// Lowercase name, as accepted by [`FromStr`]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Report => "report",
+            Self::Reject => "reject",
+        }
+    }
+}
+
+impl BigIntegers {
+    /// Lowercase name, as accepted by [`FromStr`]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Lossy => "lossy",
+            Self::Reject => "reject",
+            Self::Preserve => "preserve",
+        }
+    }
+}
+
+impl fmt::Display for DuplicateKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for BigIntegers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DuplicateKeys {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "allow" => Ok(Self::Allow),
+            "report" => Ok(Self::Report),
+            "reject" => Ok(Self::Reject),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for BigIntegers {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "lossy" => Ok(Self::Lossy),
+            "reject" => Ok(Self::Reject),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+/// Limits and strictness policy applied while parsing
+///
+/// The default is permissive and matches the behaviour of the plain
+/// `parse` functions. [`ParseLimits::strict`] rejects everything RFC 8259
+/// leaves to implementations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseLimits {
+    /// Deepest container nesting allowed, 1 for the root container
+    pub max_depth: Option<usize>,
+    /// Longest string or key allowed, in encoded bytes between the quotes
+    pub max_string_len: Option<usize>,
+    /// Largest document allowed, in bytes
+    pub max_document_size: Option<usize>,
+    /// Duplicate key policy
+    pub duplicate_keys: DuplicateKeys,
+    /// Out-of-range integer policy
+    pub big_integers: BigIntegers,
+    /// Reject `\u` escapes that are not part of a surrogate pair
+    pub reject_lone_surrogates: bool,
+}
+
+impl ParseLimits {
+    /// No limits, the behaviour of the plain parsers
+    #[must_use]
+    pub const fn permissive() -> Self {
+        Self {
+            max_depth: None,
+            max_string_len: None,
+            max_document_size: None,
+            duplicate_keys: DuplicateKeys::Allow,
+            big_integers: BigIntegers::Lossy,
+            reject_lone_surrogates: false,
+        }
+    }
+
+    /// Reject duplicate keys, out-of-range integers, lone surrogates and
+    /// nesting deeper than [`STRICT_MAX_DEPTH`]
+    ///
+    /// String and document sizes stay unlimited; set them for the
+    /// deployment with [`with_max_string_len`](Self::with_max_string_len)
+    /// and [`with_max_document_size`](Self::with_max_document_size).
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            max_depth: Some(STRICT_MAX_DEPTH),
+            max_string_len: None,
+            max_document_size: None,
+            duplicate_keys: DuplicateKeys::Reject,
+            big_integers: BigIntegers::Reject,
+            reject_lone_surrogates: true,
+        }
+    }
+
+    /// Set the maximum nesting depth
+    #[must_use]
+    pub const fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Set the maximum string length in bytes
+    #[must_use]
+    pub const fn with_max_string_len(mut self, len: usize) -> Self {
+        self.max_string_len = Some(len);
+        self
+    }
+
+    /// Set the maximum document size in bytes
+    #[must_use]
+    pub const fn with_max_document_size(mut self, size: usize) -> Self {
+        self.max_document_size = Some(size);
+        self
+    }
+
+    /// Set the duplicate key policy
+    #[must_use]
+    pub const fn with_duplicate_keys(mut self, policy: DuplicateKeys) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Set the out-of-range integer policy
+    #[must_use]
+    pub const fn with_big_integers(mut self, policy: BigIntegers) -> Self {
+        self.big_integers = policy;
+        self
+    }
+
+    /// Set whether lone surrogate escapes are rejected
+    #[must_use]
+    pub const fn with_reject_lone_surrogates(mut self, reject: bool) -> Self {
+        self.reject_lone_surrogates = reject;
+        self
+    }
+
+    /// Whether no check is enabled, so [`check_json`] can be skipped
+    #[must_use]
+    pub const fn is_permissive(&self) -> bool {
+        self.max_depth.is_none()
+            && self.max_string_len.is_none()
+            && self.max_document_size.is_none()
+            && matches!(self.duplicate_keys, DuplicateKeys::Allow)
+            && matches!(self.big_integers, BigIntegers::Lossy)
+            && !self.reject_lone_surrogates
+    }
+
+    /// Check a size and a depth reported by a non-JSON parser
+    ///
+    /// `locate` is called with the depth limit when it is broken, and returns
+    /// the byte offset of the first container nested past it (see
+    /// [`locate_keys`]).
+    ///
+    /// # Errors
+    /// Returns the first limit the document breaks
+    pub fn check_shape(
+        &self,
+        size: usize,
+        depth: usize,
+        locate: impl FnOnce(usize) -> usize,
+    ) -> Result<(), LimitViolation> {
+        if let Some(limit) = self.max_document_size
+            && size > limit
+        {
+            return Err(LimitViolation::DocumentTooLarge { size, limit });
+        }
+        if let Some(limit) = self.max_depth
+            && depth > limit
+        {
+            return Err(LimitViolation::DepthExceeded {
+                offset: locate(limit),
+                limit,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Byte offset of a value in a document whose parser keeps no positions
+///
+/// `keys` are the field names on the way to the value. Each is searched for
+/// as a whole word after the previous one, and the offset of the last one
+/// found is returned, so the result points at the key the value is written
+/// under.
+#[must_use]
+pub fn locate_keys<'k>(input: &[u8], keys: impl IntoIterator<Item = &'k str>) -> usize {
+    let is_word = |byte: Option<&u8>| byte.is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_');
+    let (mut found, mut from) = (0, 0);
+    for key in keys
+        .into_iter()
+        .map(str::as_bytes)
+        .filter(|key| !key.is_empty())
+    {
+        let Some(at) = (from..input.len().saturating_sub(key.len() - 1)).find(|&at| {
+            input[at..].starts_with(key)
+                && !is_word(at.checked_sub(1).and_then(|before| input.get(before)))
+                && !is_word(input.get(at + key.len()))
+        }) else {
+            break;
+        };
+        found = at;
+        from = at + key.len();
+    }
+    found
+}
+
+/// A limit broken by a document
+///
+/// Offsets are byte positions in the checked input.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LimitViolation {
+    /// The document is larger than `max_document_size`
+    #[error("document is {size} bytes, limit is {limit}")]
+    DocumentTooLarge {
+        /// Document size in bytes
+        size: usize,
+        /// Configured limit
+        limit: usize,
+    },
+    /// A container opens deeper than `max_depth`
+    #[error("nesting deeper than {limit} at byte {offset}")]
+    DepthExceeded {
+        /// Offset of the opening bracket
+        offset: usize,
+        /// Configured limit
+        limit: usize,
+    },
+    /// A string or key is longer than `max_string_len`
+    #[error("string of {len} bytes at byte {offset} exceeds limit of {limit}")]
+    StringTooLong {
+        /// Offset of the opening quote
+        offset: usize,
+        /// Encoded length in bytes
+        len: usize,
+        /// Configured limit
+        limit: usize,
+    },
+    /// An object repeats a key
+    #[error("duplicate key \"{key}\" at byte {offset}")]
+    DuplicateKey {
+        /// Offset of the repeated key
+        offset: usize,
+        /// Decoded key
+        key: String,
+    },
+    /// An integer does not fit in `i64` or `u64`
+    #[error("integer {lexeme} at byte {offset} is outside the i64/u64 range")]
+    IntegerOutOfRange {
+        /// Offset of the first digit or sign
+        offset: usize,
+        /// The integer as written
+        lexeme: String,
+    },
+    /// A `\u` escape names half of a surrogate pair on its own
+    #[error("lone surrogate escape at byte {offset}")]
+    LoneSurrogate {
+        /// Offset of the backslash
+        offset: usize,
+    },
+}
+
+impl From<LimitViolation> for DsonError {
+    fn from(violation: LimitViolation) -> Self {
+        Self::ValidationError(violation.to_string())
+    }
+}
+
+/// An out-of-range integer kept under [`BigIntegers::Preserve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawNumber {
+    /// Offset of the first digit or sign
+    pub offset: usize,
+    /// The integer as written
+    pub lexeme: String,
+    /// Location of the number in the document
+    pub path: Vec<PathSegment>,
+}
+
+/// What [`check_json`] found in a document that passed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LimitReport {
+    /// Problems the policy reports instead of rejecting
+    pub warnings: Vec<LimitViolation>,
+    /// Integers kept under [`BigIntegers::Preserve`], in document order
+    pub raw_numbers: Vec<RawNumber>,
+    /// Deepest container nesting seen
+    pub max_depth: usize,
+}
+
+/// Open container while scanning
+enum Frame {
+    Object {
+        /// Keys seen so far, when duplicates are checked
+        keys: Option<HashSet<String>>,
+        /// Most recent key, when paths are tracked
+        key: Option<String>,
+        expect_key: bool,
+    },
+    Array {
+        index: usize,
+    },
+}
+
+impl Frame {
+    /// Note the key at `offset` in this object
+    fn record_key(
+        &mut self,
+        offset: usize,
+        decoded: Option<String>,
+        limits: &ParseLimits,
+        report: &mut LimitReport,
+    ) -> Result<(), LimitViolation> {
+        let Self::Object {
+            keys,
+            key,
+            expect_key,
+        } = self
+        else {
+            return Ok(());
+        };
+        *expect_key = false;
+        if let (Some(keys), Some(name)) = (keys, &decoded)
+            && !keys.insert(name.clone())
+        {
+            let violation = LimitViolation::DuplicateKey {
+                offset,
+                key: name.clone(),
+            };
+            if limits.duplicate_keys == DuplicateKeys::Reject {
+                return Err(violation);
+            }
+            report.warnings.push(violation);
+        }
+        if limits.big_integers == BigIntegers::Preserve {
+            *key = decoded;
+        }
+        Ok(())
+    }
+}
+
+/// Check a JSON document against `limits` without building anything
+///
+/// # Errors
+/// Returns the first limit the document breaks
+pub fn check_json(input: &[u8], limits: &ParseLimits) -> Result<LimitReport, LimitViolation> {
+    let mut report = LimitReport::default();
+    if let Some(limit) = limits.max_document_size
+        && input.len() > limit
+    {
+        return Err(LimitViolation::DocumentTooLarge {
+            size: input.len(),
+            limit,
+        });
+    }
+    if limits.is_permissive() {
+        return Ok(report);
+    }
+
+    let check_keys = limits.duplicate_keys != DuplicateKeys::Allow;
+    let track_paths = limits.big_integers == BigIntegers::Preserve;
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut i = 0;
+
+    while i < input.len() {
+        match input[i] {
+            open @ (b'{' | b'[') => {
+                stack.push(if open == b'{' {
+                    Frame::Object {
+                        keys: check_keys.then(HashSet::new),
+                        key: None,
+                        expect_key: true,
+                    }
+                } else {
+                    Frame::Array { index: 0 }
+                });
+                if let Some(limit) = limits.max_depth
+                    && stack.len() > limit
+                {
+                    return Err(LimitViolation::DepthExceeded { offset: i, limit });
+                }
+                report.max_depth = report.max_depth.max(stack.len());
+                i += 1;
+            }
+            b'}' | b']' => {
+                stack.pop();
+                i += 1;
+            }
+            b',' => {
+                match stack.last_mut() {
+                    Some(Frame::Object { expect_key, .. }) => *expect_key = true,
+                    Some(Frame::Array { index }) => *index += 1,
+                    None => {}
+                }
+                i += 1;
+            }
+            b'"' => {
+                let is_key = matches!(
+                    stack.last(),
+                    Some(Frame::Object {
+                        expect_key: true,
+                        ..
+                    })
+                );
+                let decode = is_key && (check_keys || track_paths);
+                let (end, decoded) = scan_string(input, i, decode, limits)?;
+                if is_key && let Some(frame) = stack.last_mut() {
+                    frame.record_key(i, decoded, limits, &mut report)?;
+                }
+                i = end;
+            }
+            b'-' | b'0'..=b'9' => {
+                let end = number_end(input, i);
+                let lexeme = &input[i..end];
+                if limits.big_integers != BigIntegers::Lossy && integer_out_of_range(lexeme) {
+                    let lexeme = String::from_utf8_lossy(lexeme).into_owned();
+                    if limits.big_integers == BigIntegers::Reject {
+                        return Err(LimitViolation::IntegerOutOfRange { offset: i, lexeme });
+                    }
+                    report.raw_numbers.push(RawNumber {
+                        offset: i,
+                        lexeme,
+                        path: current_path(&stack),
+                    });
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    Ok(report)
+}
+
+/// Scan the string whose opening quote is at `start`
+///
+/// Returns the offset just past the closing quote and, when `decode` is
+/// set, the unescaped text. Unterminated strings run to the end of input.
+fn scan_string(
+    input: &[u8],
+    start: usize,
+    decode: bool,
+    limits: &ParseLimits,
+) -> Result<(usize, Option<String>), LimitViolation> {
+    let mut text = Vec::new();
+    let mut i = start + 1;
+    while i < input.len() {
+        match input[i] {
+            b'"' => break,
+            b'\\' => {
+                let Some(&escape) = input.get(i + 1) else {
+                    i = input.len();
+                    break;
+                };
+                if escape == b'u' {
+                    let width = scan_unicode_escape(input, i, decode.then_some(&mut text), limits)?;
+                    i += width;
+                    continue;
+                }
+                if decode {
+                    text.push(match escape {
+                        b'n' => b'\n',
+                        b't' => b'\t',
+                        b'r' => b'\r',
+                        b'b' => 0x08,
+                        b'f' => 0x0c,
+                        other => other,
+                    });
+                }
+                i += 2;
+            }
+            byte => {
+                if decode {
+                    text.push(byte);
+                }
+                i += 1;
+            }
+        }
+    }
+
+    let len = i.min(input.len()) - (start + 1);
+    if let Some(limit) = limits.max_string_len
+        && len > limit
+    {
+        return Err(LimitViolation::StringTooLong {
+            offset: start,
+            len,
+            limit,
+        });
+    }
+    let decoded = decode.then(|| String::from_utf8_lossy(&text).into_owned());
+    Ok(((i + 1).min(input.len()), decoded))
+}
+
+/// Check the `\u` escape at `at`, returning how many bytes it spans
+fn scan_unicode_escape(
+    input: &[u8],
+    at: usize,
+    text: Option<&mut Vec<u8>>,
+    limits: &ParseLimits,
+) -> Result<usize, LimitViolation> {
+    let Some(unit) = hex4(input, at + 2) else {
+        // Malformed escape, left for the parser to report
+        return Ok(2);
+    };
+    let (width, ch) = match unit {
+        0xD800..=0xDBFF => {
+            let low = (input.get(at + 6) == Some(&b'\\') && input.get(at + 7) == Some(&b'u'))
+                .then(|| hex4(input, at + 8))
+                .flatten()
+                .filter(|low| (0xDC00..=0xDFFF).contains(low));
+            low.map_or((6, None), |low| {
+                let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                (12, char::from_u32(code))
+            })
+        }
+        0xDC00..=0xDFFF => (6, None),
+        _ => (6, char::from_u32(unit)),
+    };
+    if ch.is_none() && limits.reject_lone_surrogates {
+        return Err(LimitViolation::LoneSurrogate { offset: at });
+    }
+    if let Some(text) = text {
+        let mut buf = [0u8; 4];
+        text.extend_from_slice(
+            ch.unwrap_or(char::REPLACEMENT_CHARACTER)
+                .encode_utf8(&mut buf)
+                .as_bytes(),
+        );
+    }
+    Ok(width)
+}
+
+/// Four hex digits at `at` as a UTF-16 code unit
+fn hex4(input: &[u8], at: usize) -> Option<u32> {
+    let digits = input.get(at..at + 4)?;
+    let digits = std::str::from_utf8(digits).ok()?;
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Offset just past the number starting at `start`
+fn number_end(input: &[u8], start: usize) -> usize {
+    input[start + 1..]
+        .iter()
+        .position(|b| !matches!(b, b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-'))
+        .map_or(input.len(), |n| start + 1 + n)
+}
+
+/// Whether an integer lexeme fits neither `i64` nor `u64`
+///
+/// Lexemes with a fraction or exponent are floats and always fit.
+fn integer_out_of_range(lexeme: &[u8]) -> bool {
+    if lexeme.iter().any(|b| matches!(b, b'.' | b'e' | b'E')) {
+        return false;
+    }
+    let digits = lexeme.iter().filter(|b| b.is_ascii_digit()).count();
+    if digits < 19 {
+        return false;
+    }
+    let Ok(text) = std::str::from_utf8(lexeme) else {
+        return false;
+    };
+    text.parse::<i64>().is_err() && text.parse::<u64>().is_err()
+}
+
+/// Path to the value being scanned
+fn current_path(stack: &[Frame]) -> Vec<PathSegment> {
+    stack
+        .iter()
+        .filter_map(|frame| match frame {
+            Frame::Object { key, .. } => key.clone().map(PathSegment::Field),
+            Frame::Array { index } => Some(PathSegment::Index(*index)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =========================================================================
+    // Policy Tests
+    // =========================================================================
+
+    #[test]
+    fn test_default_is_permissive() {
+        assert_eq!(ParseLimits::default(), ParseLimits::permissive());
+        assert!(ParseLimits::default().is_permissive());
+        assert!(!ParseLimits::strict().is_permissive());
+    }
+
+    #[test]
+    fn test_policy_names_roundtrip() {
+        for policy in [
+            DuplicateKeys::Allow,
+            DuplicateKeys::Report,
+            DuplicateKeys::Reject,
+        ] {
+            assert_eq!(policy.to_string().parse::<DuplicateKeys>(), Ok(policy));
+        }
+        for policy in [
+            BigIntegers::Lossy,
+            BigIntegers::Reject,
+            BigIntegers::Preserve,
+        ] {
+            assert_eq!(policy.to_string().parse::<BigIntegers>(), Ok(policy));
+        }
+        assert_eq!(
+            " REJECT ".parse::<DuplicateKeys>(),
+            Ok(DuplicateKeys::Reject)
+        );
+        assert!("first".parse::<DuplicateKeys>().is_err());
+    }
+
+    #[test]
+    fn test_permissive_accepts_everything() {
+        let input = br#"{"a":1,"a":99999999999999999999999,"s":"\ud800"}"#;
+        let report = check_json(input, &ParseLimits::permissive()).unwrap();
+        assert_eq!(report, LimitReport::default());
+    }
+
+    #[test]
+    fn test_violation_converts_to_dson_error() {
+        let error: DsonError = LimitViolation::LoneSurrogate { offset: 3 }.into();
+        assert!(matches!(error, DsonError::ValidationError(_)));
+        assert!(error.to_string().contains("byte 3"));
+    }
+
+    // =========================================================================
+    // Duplicate Keys
+    // =========================================================================
+
+    #[test]
+    fn test_duplicate_key_rejected() {
+        let err = check_json(br#"{"a":1,"b":{"a":2},"a":3}"#, &ParseLimits::strict()).unwrap_err();
+        assert_eq!(
+            err,
+            LimitViolation::DuplicateKey {
+                offset: 19,
+                key: "a".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_duplicate_key_decoded_before_comparing() {
+        let err = check_json(br#"{"\u0061":1,"a":2}"#, &ParseLimits::strict()).unwrap_err();
+        assert!(matches!(err, LimitViolation::DuplicateKey { key, .. } if key == "a"));
+    }
+
+    #[test]
+    fn test_same_key_in_sibling_objects_allowed() {
+        let input = br#"[{"a":1},{"a":2}]"#;
+        assert!(check_json(input, &ParseLimits::strict()).is_ok());
+    }
+
+    #[test]
+    fn test_string_values_are_not_keys() {
+        let input = br#"{"a":"a","b":"a"}"#;
+        assert!(check_json(input, &ParseLimits::strict()).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_key_reported() {
+        let limits = ParseLimits::strict().with_duplicate_keys(DuplicateKeys::Report);
+        let report = check_json(br#"{"x":1,"x":2,"x":3}"#, &limits).unwrap();
+        assert_eq!(report.warnings.len(), 2);
+    }
+
+    // =========================================================================
+    // Depth, Size and Length
+    // =========================================================================
+
+    #[test]
+    fn test_depth_limit() {
+        let limits = ParseLimits::permissive().with_max_depth(2);
+        assert_eq!(check_json(b"[[1]]", &limits).unwrap().max_depth, 2);
+        assert_eq!(
+            check_json(br#"[{"a":[1]}]"#, &limits),
+            Err(LimitViolation::DepthExceeded {
+                offset: 6,
+                limit: 2
+            })
+        );
+        // Brackets inside strings do not count
+        assert!(check_json(br#"["[[[["]"#, &limits).is_ok());
+    }
+
+    #[test]
+    fn test_document_size_limit() {
+        let limits = ParseLimits::permissive().with_max_document_size(4);
+        assert!(check_json(b"[1]", &limits).is_ok());
+        assert_eq!(
+            check_json(b"[1,2]", &limits),
+            Err(LimitViolation::DocumentTooLarge { size: 5, limit: 4 })
+        );
+    }
+
+    #[test]
+    fn test_string_length_limit() {
+        let limits = ParseLimits::permissive().with_max_string_len(3);
+        assert!(check_json(br#"{"abc":"\n"}"#, &limits).is_ok());
+        assert!(matches!(
+            check_json(br#"{"abcd":1}"#, &limits),
+            Err(LimitViolation::StringTooLong { len: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_shape() {
+        let limits = ParseLimits::permissive()
+            .with_max_depth(3)
+            .with_max_document_size(10);
+        assert!(limits.check_shape(10, 3, |_| 0).is_ok());
+        assert!(limits.check_shape(11, 1, |_| 0).is_err());
+        assert_eq!(
+            limits.check_shape(1, 4, |limit| limit * 10),
+            Err(LimitViolation::DepthExceeded {
+                offset: 30,
+                limit: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_locate_keys() {
+        let input = b"a:\n  b:\n    a:\n      c: 1\n";
+        assert_eq!(locate_keys(input, ["a", "b", "a"]), 12);
+        assert_eq!(locate_keys(input, ["a", "missing", "c"]), 0);
+        assert_eq!(locate_keys(b"name = 1\na = 2\n", ["a"]), 9);
+        assert_eq!(locate_keys(input, []), 0);
+    }
+
+    // =========================================================================
+    // Numbers
+    // =========================================================================
+
+    #[test]
+    fn test_integer_range_edges() {
+        let limits = ParseLimits::strict();
+        for ok in [
+            "18446744073709551615",
+            "-9223372036854775808",
+            "123456789012345678901234.5",
+            "1e400",
+        ] {
+            assert!(check_json(ok.as_bytes(), &limits).is_ok(), "{ok}");
+        }
+        for bad in ["18446744073709551616", "-9223372036854775809"] {
+            assert_eq!(
+                check_json(bad.as_bytes(), &limits),
+                Err(LimitViolation::IntegerOutOfRange {
+                    offset: 0,
+                    lexeme: bad.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_big_integers_preserved_with_path() {
+        let limits = ParseLimits::permissive().with_big_integers(BigIntegers::Preserve);
+        let input = br#"{"a":[1,{"b":123456789012345678901234}],"c":-99999999999999999999}"#;
+        let report = check_json(input, &limits).unwrap();
+        assert_eq!(report.raw_numbers.len(), 2);
+        assert_eq!(report.raw_numbers[0].lexeme, "123456789012345678901234");
+        assert_eq!(
+            report.raw_numbers[0].path,
+            vec![
+                PathSegment::Field("a".to_string()),
+                PathSegment::Index(1),
+                PathSegment::Field("b".to_string()),
+            ]
+        );
+        assert_eq!(
+            report.raw_numbers[1].path,
+            vec![PathSegment::Field("c".to_string())]
+        );
+    }
+
+    // =========================================================================
+    // Surrogates
+    // =========================================================================
+
+    #[test]
+    fn test_surrogate_pairs_accepted() {
+        let input = br#"["\ud83d\ude00","\uD83D\uDE00","\u00e9"]"#;
+        assert!(check_json(input, &ParseLimits::strict()).is_ok());
+    }
+
+    #[test]
+    fn test_lone_surrogates_rejected() {
+        let limits = ParseLimits::strict();
+        for (input, offset) in [
+            (&br#"["\ud800"]"#[..], 2),
+            (br#"["x\udc00"]"#, 3),
+            (br#"["\ud800A"]"#, 2),
+            (br#"["\ud83d\ud83d"]"#, 2),
+        ] {
+            assert_eq!(
+                check_json(input, &limits),
+                Err(LimitViolation::LoneSurrogate { offset }),
+                "{}",
+                String::from_utf8_lossy(input)
+            );
+        }
+    }
+
+    #[test]
+    fn test_malformed_input_is_left_to_parser() {
+        let limits = ParseLimits::strict();
+        for input in [&b"{\"a"[..], b"]]]", b"\"\\u12", b"[\"\\", b"-"] {
+            assert!(check_json(input, &limits).is_ok());
+        }
+    }
+}
@@ -0,0 +1,607 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Typed JSON numbers, parsed once
+//!
+//! Tape builders parse each number as they meet it and keep both the
+//! typed value and the lexeme, so arithmetic, comparisons and CRDT merges
+//! read a machine number while emitters still write the text as it was.
+//!
+//! Integer digits are consumed eight at a time with SWAR arithmetic on a
+//! single `u64`. Numbers with a fraction or exponent go through an
+//! Eisel-Lemire parser, which is correctly rounded like `str::parse`.
+//!
+//! Non-negative integers become [`NumberValue::U64`] and negative ones
+//! [`NumberValue::I64`], as in simd-json and `serde_json`. Everything
+//! else, including integers too large for either, is [`NumberValue::F64`].
+//!
+//! ```
+//! use fionn_core::number::{JsonNumber, NumberValue, parse_number};
+//!
+//! assert_eq!(parse_number(b"-42"), Some(NumberValue::I64(-42)));
+//! assert_eq!(parse_number(b"2.5e3"), Some(NumberValue::F64(2500.0)));
+//! assert_eq!(parse_number(b"01"), None);
+//!
+//! let n = JsonNumber::parse("1.50");
+//! assert_eq!(n.as_f64(), Some(1.5));
+//! assert_eq!(n.lexeme(), "1.50");
+//! ```
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A number as a machine value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberValue {
+    /// Negative integer
+    I64(i64),
+    /// Non-negative integer
+    U64(u64),
+    /// Anything with a fraction or exponent, or too large for 64 bits
+    F64(f64),
+}
+
+impl NumberValue {
+    /// The value as `f64`, rounding integers beyond 2^53
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Rounding large integers is the documented behaviour
+    pub const fn as_f64(self) -> f64 {
+        match self {
+            Self::I64(i) => i as f64,
+            Self::U64(u) => u as f64,
+            Self::F64(f) => f,
+        }
+    }
+
+    /// The value as `i64`, if it is an integer in range
+    #[must_use]
+    pub const fn as_i64(self) -> Option<i64> {
+        match self {
+            Self::I64(i) => Some(i),
+            Self::U64(u) if u <= i64::MAX as u64 => Some(u.cast_signed()),
+            _ => None,
+        }
+    }
+
+    /// The value as `u64`, if it is a non-negative integer
+    #[must_use]
+    pub const fn as_u64(self) -> Option<u64> {
+        match self {
+            Self::U64(u) => Some(u),
+            Self::I64(i) if i >= 0 => Some(i.cast_unsigned()),
+            _ => None,
+        }
+    }
+
+    /// Whether the value is an integer
+    #[must_use]
+    pub const fn is_integer(self) -> bool {
+        matches!(self, Self::I64(_) | Self::U64(_))
+    }
+}
+
+impl fmt::Display for NumberValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I64(i) => write!(f, "{i}"),
+            Self::U64(u) => write!(f, "{u}"),
+            Self::F64(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+/// A number's lexeme together with its parsed value
+///
+/// Equality and hashing use the lexeme, so `1.0` and `1` differ, exactly
+/// as they did when numbers were stored as text. The lexeme is kept even
+/// when it does not parse; such numbers have no [`value`](Self::value).
+#[derive(Debug, Clone)]
+pub struct JsonNumber {
+    lexeme: String,
+    value: Option<NumberValue>,
+}
+
+impl JsonNumber {
+    /// Parse a lexeme
+    ///
+    /// JSON grammar takes the fast path. Anything else `str::parse`
+    /// accepts as `f64`, such as `NaN` or `+1`, is still given a value.
+    #[must_use]
+    pub fn parse(lexeme: impl Into<String>) -> Self {
+        let lexeme = lexeme.into();
+        let value = parse_number(lexeme.as_bytes())
+            .or_else(|| lexeme.parse::<f64>().ok().map(NumberValue::F64));
+        Self { lexeme, value }
+    }
+
+    /// Build from a value already parsed by a tape builder
+    #[must_use]
+    pub fn from_parts(lexeme: impl Into<String>, value: NumberValue) -> Self {
+        Self {
+            lexeme: lexeme.into(),
+            value: Some(value),
+        }
+    }
+
+    /// The number as written
+    #[must_use]
+    pub fn lexeme(&self) -> &str {
+        &self.lexeme
+    }
+
+    /// The parsed value, if the lexeme is a number
+    #[must_use]
+    pub const fn value(&self) -> Option<NumberValue> {
+        self.value
+    }
+
+    /// The value as `i64`, if it is an integer in range
+    #[must_use]
+    pub const fn as_i64(&self) -> Option<i64> {
+        match self.value {
+            Some(value) => value.as_i64(),
+            None => None,
+        }
+    }
+
+    /// The value as `u64`, if it is a non-negative integer
+    #[must_use]
+    pub const fn as_u64(&self) -> Option<u64> {
+        match self.value {
+            Some(value) => value.as_u64(),
+            None => None,
+        }
+    }
+
+    /// The value as `f64`
+    #[must_use]
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self.value {
+            Some(value) => Some(value.as_f64()),
+            None => None,
+        }
+    }
+
+    /// Convert to a `serde_json` value, falling back to a string for
+    /// lexemes that are not finite numbers
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        match self.value {
+            Some(NumberValue::I64(i)) => serde_json::Value::Number(i.into()),
+            Some(NumberValue::U64(u)) => serde_json::Value::Number(u.into()),
+            Some(NumberValue::F64(f)) => serde_json::Number::from_f64(f).map_or_else(
+                || serde_json::Value::String(self.lexeme.clone()),
+                serde_json::Value::Number,
+            ),
+            None => serde_json::Value::String(self.lexeme.clone()),
+        }
+    }
+
+    /// Take the lexeme
+    #[must_use]
+    pub fn into_lexeme(self) -> String {
+        self.lexeme
+    }
+}
+
+impl From<NumberValue> for JsonNumber {
+    fn from(value: NumberValue) -> Self {
+        Self::from_parts(value.to_string(), value)
+    }
+}
+
+impl From<i64> for JsonNumber {
+    fn from(value: i64) -> Self {
+        let value = if value < 0 {
+            NumberValue::I64(value)
+        } else {
+            NumberValue::U64(value.cast_unsigned())
+        };
+        value.into()
+    }
+}
+
+impl From<u64> for JsonNumber {
+    fn from(value: u64) -> Self {
+        NumberValue::U64(value).into()
+    }
+}
+
+impl From<f64> for JsonNumber {
+    fn from(value: f64) -> Self {
+        NumberValue::F64(value).into()
+    }
+}
+
+impl From<&serde_json::Number> for JsonNumber {
+    fn from(number: &serde_json::Number) -> Self {
+        let value = number.as_u64().map_or_else(
+            || {
+                number.as_i64().map_or_else(
+                    || NumberValue::F64(number.as_f64().unwrap_or(f64::NAN)),
+                    NumberValue::I64,
+                )
+            },
+            NumberValue::U64,
+        );
+        Self::from_parts(number.to_string(), value)
+    }
+}
+
+impl From<String> for JsonNumber {
+    fn from(lexeme: String) -> Self {
+        Self::parse(lexeme)
+    }
+}
+
+impl From<&str> for JsonNumber {
+    fn from(lexeme: &str) -> Self {
+        Self::parse(lexeme)
+    }
+}
+
+impl Deref for JsonNumber {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.lexeme
+    }
+}
+
+impl AsRef<str> for JsonNumber {
+    fn as_ref(&self) -> &str {
+        &self.lexeme
+    }
+}
+
+impl fmt::Display for JsonNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.lexeme)
+    }
+}
+
+impl PartialEq for JsonNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.lexeme == other.lexeme
+    }
+}
+
+impl Eq for JsonNumber {}
+
+impl Hash for JsonNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lexeme.hash(state);
+    }
+}
+
+impl PartialEq<str> for JsonNumber {
+    fn eq(&self, other: &str) -> bool {
+        self.lexeme == other
+    }
+}
+
+impl PartialEq<&str> for JsonNumber {
+    fn eq(&self, other: &&str) -> bool {
+        self.lexeme == *other
+    }
+}
+
+impl PartialEq<String> for JsonNumber {
+    fn eq(&self, other: &String) -> bool {
+        &self.lexeme == other
+    }
+}
+
+// =============================================================================
+// Parsing
+// =============================================================================
+
+/// Parse a complete JSON number
+///
+/// Returns `None` unless all of `bytes` is one number in RFC 8259
+/// grammar, or if a float overflows to infinity.
+#[must_use]
+pub fn parse_number(bytes: &[u8]) -> Option<NumberValue> {
+    let negative = bytes.first() == Some(&b'-');
+    let int_start = usize::from(negative);
+    let int_len = count_digits(bytes, int_start);
+    if int_len == 0 || (int_len > 1 && bytes[int_start] == b'0') {
+        return None;
+    }
+    let mut end = int_start + int_len;
+    let has_fraction = bytes.get(end) == Some(&b'.');
+    if has_fraction {
+        let frac_len = count_digits(bytes, end + 1);
+        if frac_len == 0 {
+            return None;
+        }
+        end += 1 + frac_len;
+    }
+    let has_exponent = matches!(bytes.get(end), Some(b'e' | b'E'));
+    if has_exponent {
+        end += 1;
+        if matches!(bytes.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        let exp_len = count_digits(bytes, end);
+        if exp_len == 0 {
+            return None;
+        }
+        end += exp_len;
+    }
+    if end != bytes.len() {
+        return None;
+    }
+
+    if !has_fraction
+        && !has_exponent
+        && let Some(magnitude) = parse_digits(&bytes[int_start..end])
+    {
+        if !negative {
+            return Some(NumberValue::U64(magnitude));
+        }
+        if let Some(value) = 0i64.checked_sub_unsigned(magnitude) {
+            return Some(NumberValue::I64(value));
+        }
+    }
+    let value: f64 = lexical_core::parse(bytes).ok()?;
+    value.is_finite().then_some(NumberValue::F64(value))
+}
+
+/// Length of the digit run at `start`
+fn count_digits(bytes: &[u8], start: usize) -> usize {
+    bytes.get(start..).map_or(0, |rest| {
+        rest.iter().take_while(|b| b.is_ascii_digit()).count()
+    })
+}
+
+/// Value of a run of ASCII digits, if it fits in `u64`
+fn parse_digits(digits: &[u8]) -> Option<u64> {
+    // 19 digits always fit; a 20th may overflow
+    if digits.len() > 20 {
+        return None;
+    }
+    let (fast, tail) = digits.split_at(digits.len().min(19));
+    let mut value = 0u64;
+    let mut chunks = fast.chunks_exact(8);
+    for chunk in &mut chunks {
+        let chunk: [u8; 8] = chunk.try_into().ok()?;
+        value = value * 100_000_000 + u64::from(parse_eight_digits(chunk));
+    }
+    for &digit in chunks.remainder() {
+        value = value * 10 + u64::from(digit - b'0');
+    }
+    for &digit in tail {
+        value = value
+            .checked_mul(10)?
+            .checked_add(u64::from(digit - b'0'))?;
+    }
+    Some(value)
+}
+
+/// Convert eight ASCII digits with three multiplies (SWAR)
+///
+/// The caller guarantees every byte is a digit.
+const fn parse_eight_digits(chunk: [u8; 8]) -> u32 {
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 100 + (1_000_000 << 32);
+    const MUL2: u64 = 1 + (10_000 << 32);
+
+    let mut val = u64::from_le_bytes(chunk).wrapping_sub(0x3030_3030_3030_3030);
+    // Pairs of digits
+    val = val.wrapping_mul(10).wrapping_add(val >> 8);
+    // Pairs of pairs, then the two halves, landing in the top 32 bits
+    val = (val & MASK)
+        .wrapping_mul(MUL1)
+        .wrapping_add(((val >> 16) & MASK).wrapping_mul(MUL2))
+        >> 32;
+    #[allow(clippy::cast_possible_truncation)] // Eight digits are below 10^8
+    {
+        val as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =========================================================================
+    // Parser Tests
+    // =========================================================================
+
+    #[test]
+    fn test_parse_integers() {
+        assert_eq!(parse_number(b"0"), Some(NumberValue::U64(0)));
+        assert_eq!(parse_number(b"-0"), Some(NumberValue::I64(0)));
+        assert_eq!(parse_number(b"7"), Some(NumberValue::U64(7)));
+        assert_eq!(parse_number(b"-12"), Some(NumberValue::I64(-12)));
+        assert_eq!(
+            parse_number(b"12345678901234567"),
+            Some(NumberValue::U64(12_345_678_901_234_567))
+        );
+    }
+
+    #[test]
+    fn test_parse_integer_edges() {
+        assert_eq!(
+            parse_number(b"18446744073709551615"),
+            Some(NumberValue::U64(u64::MAX))
+        );
+        assert_eq!(
+            parse_number(b"-9223372036854775808"),
+            Some(NumberValue::I64(i64::MIN))
+        );
+        assert_eq!(
+            parse_number(b"18446744073709551616"),
+            Some(NumberValue::F64(18_446_744_073_709_551_616.0))
+        );
+        assert_eq!(
+            parse_number(b"-9223372036854775809"),
+            Some(NumberValue::F64(-9_223_372_036_854_775_809.0))
+        );
+        assert!(matches!(
+            parse_number(b"123456789012345678901234567890"),
+            Some(NumberValue::F64(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_floats() {
+        assert_eq!(parse_number(b"1.5"), Some(NumberValue::F64(1.5)));
+        assert_eq!(parse_number(b"-0.25"), Some(NumberValue::F64(-0.25)));
+        assert_eq!(parse_number(b"1e3"), Some(NumberValue::F64(1000.0)));
+        assert_eq!(parse_number(b"1E-2"), Some(NumberValue::F64(0.01)));
+        assert_eq!(parse_number(b"2.5e+2"), Some(NumberValue::F64(250.0)));
+        assert_eq!(parse_number(b"1e400"), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_grammar() {
+        for bad in [
+            &b""[..],
+            b"-",
+            b"01",
+            b"-01",
+            b"1.",
+            b".5",
+            b"+1",
+            b"1e",
+            b"1e+",
+            b"1.5.2",
+            b"1 ",
+            b"NaN",
+            b"0x10",
+        ] {
+            assert_eq!(parse_number(bad), None, "{}", String::from_utf8_lossy(bad));
+        }
+    }
+
+    #[test]
+    fn test_eight_digit_chunks() {
+        assert_eq!(parse_eight_digits(*b"00000000"), 0);
+        assert_eq!(parse_eight_digits(*b"12345678"), 12_345_678);
+        assert_eq!(parse_eight_digits(*b"99999999"), 99_999_999);
+        for len in 1..=20 {
+            let digits: Vec<u8> = (0..len).map(|i| b'1' + (i % 9)).collect();
+            let expected = std::str::from_utf8(&digits).unwrap().parse::<u64>().ok();
+            assert_eq!(parse_digits(&digits), expected, "{len} digits");
+        }
+    }
+
+    #[test]
+    fn test_floats_match_std() {
+        for text in [
+            "0.1",
+            "3.141592653589793",
+            "2.2250738585072014e-308",
+            "1.7976931348623157e308",
+            "5e-324",
+            "9007199254740993.0",
+            "123456.789e-10",
+        ] {
+            let expected: f64 = text.parse().unwrap();
+            assert_eq!(
+                parse_number(text.as_bytes()),
+                Some(NumberValue::F64(expected))
+            );
+        }
+    }
+
+    // =========================================================================
+    // JsonNumber Tests
+    // =========================================================================
+
+    #[test]
+    fn test_value_conversions() {
+        assert_eq!(NumberValue::U64(5).as_i64(), Some(5));
+        assert_eq!(NumberValue::U64(u64::MAX).as_i64(), None);
+        assert_eq!(NumberValue::I64(-5).as_u64(), None);
+        assert_eq!(NumberValue::F64(1.5).as_i64(), None);
+        assert!((NumberValue::I64(-3).as_f64() + 3.0).abs() < f64::EPSILON);
+        assert!(NumberValue::U64(1).is_integer());
+        assert!(!NumberValue::F64(1.0).is_integer());
+    }
+
+    #[test]
+    fn test_json_number_keeps_lexeme() {
+        let n = JsonNumber::parse("1.0");
+        assert_eq!(n.lexeme(), "1.0");
+        assert_eq!(n.to_string(), "1.0");
+        assert_eq!(n.as_f64(), Some(1.0));
+        assert_eq!(n.as_i64(), None);
+        assert_ne!(n, JsonNumber::parse("1"));
+        assert_eq!(n, "1.0");
+    }
+
+    #[test]
+    fn test_json_number_lenient_fallback() {
+        assert!(JsonNumber::parse("NaN").as_f64().unwrap().is_nan());
+        assert_eq!(JsonNumber::parse("+1").as_f64(), Some(1.0));
+        let not_a_number = JsonNumber::parse("*2");
+        assert_eq!(not_a_number.value(), None);
+        assert_eq!(&*not_a_number, "*2");
+    }
+
+    #[test]
+    fn test_json_number_from_values() {
+        assert_eq!(JsonNumber::from(42i64).value(), Some(NumberValue::U64(42)));
+        assert_eq!(JsonNumber::from(-42i64), "-42");
+        assert_eq!(JsonNumber::from(2.5f64), "2.5");
+        assert_eq!(JsonNumber::from(7u64).as_i64(), Some(7));
+        assert_eq!(JsonNumber::from("12".to_string()).as_u64(), Some(12));
+    }
+
+    #[test]
+    fn test_json_number_serde_json_roundtrip() {
+        let value: serde_json::Value =
+            serde_json::from_str("[-1, 18446744073709551615, 0.25]").unwrap();
+        let numbers: Vec<JsonNumber> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(serde_json::Value::as_number)
+            .map(JsonNumber::from)
+            .collect();
+        assert_eq!(numbers[0].value(), Some(NumberValue::I64(-1)));
+        assert_eq!(numbers[1].as_u64(), Some(u64::MAX));
+        assert_eq!(numbers[2].as_f64(), Some(0.25));
+        let back: Vec<serde_json::Value> = numbers.iter().map(JsonNumber::to_json_value).collect();
+        assert_eq!(serde_json::Value::Array(back), value);
+        assert_eq!(
+            JsonNumber::parse("*2").to_json_value(),
+            serde_json::Value::String("*2".to_string())
+        );
+    }
+
+    // =========================================================================
+    // Property Tests
+    // =========================================================================
+
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn prop_integers_roundtrip(n in any::<i64>()) {
+                let expected = if n < 0 {
+                    NumberValue::I64(n)
+                } else {
+                    NumberValue::U64(n.cast_unsigned())
+                };
+                prop_assert_eq!(parse_number(n.to_string().as_bytes()), Some(expected));
+            }
+
+            #[test]
+            fn prop_unsigned_roundtrip(n in any::<u64>()) {
+                prop_assert_eq!(parse_number(n.to_string().as_bytes()), Some(NumberValue::U64(n)));
+            }
+
+            #[test]
+            fn prop_floats_match_std(x in any::<f64>().prop_filter("finite", |x| x.is_finite())) {
+                let text = format!("{x:e}");
+                let expected: f64 = text.parse().unwrap();
+                prop_assert_eq!(parse_number(text.as_bytes()), Some(NumberValue::F64(expected)));
+            }
+        }
+    }
+}
@@ -2,7 +2,13 @@
 use dashmap::DashMap;
 use memchr::{memchr, memchr_iter, memchr2};
 use std::ops::Range;
-use std::sync::{Arc, OnceLock};
+use std::sync::Arc;
+
+use crate::simd::{SimdDispatch, SimdLevel};
+
+mod style;
+
+pub use style::{ParsePathError, ParsePathStyleError, PathStyle};
 
 /// Component of a JSON path.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -278,14 +284,14 @@ pub fn parse_simd_ref_into<'a>(path: &'a str, components: &mut Vec<PathComponent
     let mut current_start = 0;
     let mut i = 0;
     components.reserve(estimate_components(bytes));
-    let dispatch = DISPATCH.get_or_init(init_dispatch);
+    let level = crate::simd::dispatch().path;
 
     while i < bytes.len() {
         let remaining = bytes.len().saturating_sub(i);
         let Some(pos) = (if remaining < SIMD_CUTOFF_DEFAULT {
             memchr2(b'.', b'[', &bytes[i..]).map(|pos| i + pos)
         } else {
-            find_delim_dynamic(bytes, i, remaining, *dispatch)
+            find_delim_dynamic(bytes, i, remaining, level)
         }) else {
             break;
         };
@@ -310,7 +316,7 @@ pub fn parse_simd_ref_into<'a>(path: &'a str, components: &mut Vec<PathComponent
                 let end = if remaining < SIMD_CUTOFF_DEFAULT {
                     memchr(b']', &bytes[start..]).map(|pos| start + pos)
                 } else {
-                    find_byte_dynamic(bytes, start, remaining, b']', *dispatch)
+                    find_byte_dynamic(bytes, start, remaining, b']', level)
                 };
                 if let Some(end) = end {
                     let index_str = &bytes[start..end];
@@ -341,14 +347,14 @@ fn parse_simd_ranges(path: &str, components: &mut Vec<PathComponentRange>) {
     let mut current_start = 0;
     let mut i = 0;
     components.reserve(estimate_components(bytes));
-    let dispatch = DISPATCH.get_or_init(init_dispatch);
+    let level = crate::simd::dispatch().path;
 
     while i < bytes.len() {
         let remaining = bytes.len().saturating_sub(i);
         let Some(pos) = (if remaining < SIMD_CUTOFF_DEFAULT {
             memchr2(b'.', b'[', &bytes[i..]).map(|pos| i + pos)
         } else {
-            find_delim_dynamic(bytes, i, remaining, *dispatch)
+            find_delim_dynamic(bytes, i, remaining, level)
         }) else {
             break;
         };
@@ -371,7 +377,7 @@ fn parse_simd_ranges(path: &str, components: &mut Vec<PathComponentRange>) {
                 let end = if remaining < SIMD_CUTOFF_DEFAULT {
                     memchr(b']', &bytes[start..]).map(|pos| start + pos)
                 } else {
-                    find_byte_dynamic(bytes, start, remaining, b']', *dispatch)
+                    find_byte_dynamic(bytes, start, remaining, b']', level)
                 };
                 if let Some(end) = end {
                     let index_str = &bytes[start..end];
@@ -394,32 +400,53 @@ fn parse_simd_ranges(path: &str, components: &mut Vec<PathComponentRange>) {
     }
 }
 
+/// Parse path with the delimiter search pinned to one SIMD level.
+///
+/// The level is lowered to what the CPU supports, so any level is safe to
+/// request. The chosen kernel scans every span, with no short-input cutoff,
+/// which makes this the entry point for comparing kernels against each other.
+#[must_use]
+pub fn parse_simd_with_level(path: &str, level: SimdLevel) -> Vec<PathComponent> {
+    let finders: (DelimFinder, ByteFinder) = match SimdDispatch::for_level(level).path {
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx512 => (find_delim_avx512_wrapper, find_byte_avx512_wrapper),
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2 => (find_delim_avx2_wrapper, find_byte_avx2_wrapper),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        SimdLevel::Sse2 => (find_delim_sse2, find_byte_sse2),
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon => (find_delim_neon, find_byte_neon),
+        _ => (find_delim_scalar, find_byte_scalar),
+    };
+    parse_simd_with(path, 0, Some(finders))
+}
+
 /// Parse path using forced AVX2 instructions.
+///
+/// Falls back to a narrower kernel on CPUs without AVX2.
 #[cfg(target_arch = "x86_64")]
 #[inline]
+#[must_use]
 pub fn parse_simd_forced_avx2(path: &str) -> Vec<PathComponent> {
-    parse_simd_with_forced(path, find_delim_avx2_wrapper, find_byte_avx2_wrapper)
+    parse_simd_with_level(path, SimdLevel::Avx2)
 }
 
 /// Parse path using forced AVX-512 instructions.
+///
+/// Falls back to a narrower kernel on CPUs without AVX-512.
 #[cfg(target_arch = "x86_64")]
 #[inline]
+#[must_use]
 pub fn parse_simd_forced_avx512(path: &str) -> Vec<PathComponent> {
-    parse_simd_with_forced(path, find_delim_avx512_wrapper, find_byte_avx512_wrapper)
+    parse_simd_with_level(path, SimdLevel::Avx512)
 }
 
 /// Parse path using forced SSE2 instructions.
-#[cfg(target_arch = "x86_64")]
-#[inline]
-pub fn parse_simd_forced_sse2(path: &str) -> Vec<PathComponent> {
-    parse_simd_with_forced(path, find_delim_sse2, find_byte_sse2)
-}
-
-/// Parse path using forced SSE2 instructions.
-#[cfg(target_arch = "x86")]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline]
+#[must_use]
 pub fn parse_simd_forced_sse2(path: &str) -> Vec<PathComponent> {
-    parse_simd_with_forced(path, find_delim_sse2, find_byte_sse2)
+    parse_simd_with_level(path, SimdLevel::Sse2)
 }
 
 #[inline]
@@ -427,17 +454,6 @@ fn parse_simd_with_cutoff(path: &str, cutoff: usize) -> Vec<PathComponent> {
     parse_simd_with(path, cutoff, None)
 }
 
-/// Helper for x86 forced SIMD instruction parsing
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[inline]
-fn parse_simd_with_forced(
-    path: &str,
-    find_delim: DelimFinder,
-    find_byte: ByteFinder,
-) -> Vec<PathComponent> {
-    parse_simd_with(path, 0, Some((find_delim, find_byte)))
-}
-
 #[inline]
 fn parse_simd_with(
     path: &str,
@@ -449,7 +465,7 @@ fn parse_simd_with(
     let mut current_start = 0;
     let mut i = 0;
     components.reserve(estimate_components(bytes));
-    let dispatch = DISPATCH.get_or_init(init_dispatch);
+    let level = crate::simd::dispatch().path;
 
     while i < bytes.len() {
         let remaining = bytes.len().saturating_sub(i);
@@ -458,7 +474,7 @@ fn parse_simd_with(
         } else if let Some((find_delim, _)) = forced {
             find_delim(bytes, i)
         } else {
-            find_delim_dynamic(bytes, i, remaining, *dispatch)
+            find_delim_dynamic(bytes, i, remaining, level)
         }) else {
             break;
         };
@@ -485,7 +501,7 @@ fn parse_simd_with(
                 } else if let Some((_, find_byte)) = forced {
                     find_byte(bytes, start, b']')
                 } else {
-                    find_byte_dynamic(bytes, start, remaining, b']', *dispatch)
+                    find_byte_dynamic(bytes, start, remaining, b']', level)
                 };
                 if let Some(end) = end {
                     let index_str = &bytes[start..end];
@@ -561,59 +577,6 @@ fn estimate_components(bytes: &[u8]) -> usize {
 type DelimFinder = fn(&[u8], usize) -> Option<usize>;
 type ByteFinder = fn(&[u8], usize, u8) -> Option<usize>;
 
-#[derive(Clone, Copy)]
-#[allow(clippy::struct_excessive_bools)] // These are CPU feature flags, bools are appropriate
-#[allow(dead_code)] // Fields used conditionally based on target_arch
-struct SimdDispatch {
-    has_sse2: bool,
-    has_avx2: bool,
-    has_avx512: bool,
-    has_neon: bool,
-}
-
-static DISPATCH: OnceLock<SimdDispatch> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
-fn init_dispatch() -> SimdDispatch {
-    SimdDispatch {
-        has_sse2: std::is_x86_feature_detected!("sse2"),
-        has_avx2: std::is_x86_feature_detected!("avx2"),
-        has_avx512: std::is_x86_feature_detected!("avx512bw")
-            && std::is_x86_feature_detected!("avx512f"),
-        has_neon: false,
-    }
-}
-
-#[cfg(target_arch = "x86")]
-fn init_dispatch() -> SimdDispatch {
-    SimdDispatch {
-        has_sse2: std::is_x86_feature_detected!("sse2"),
-        has_avx2: false,
-        has_avx512: false,
-        has_neon: false,
-    }
-}
-
-#[cfg(target_arch = "aarch64")]
-const fn init_dispatch() -> SimdDispatch {
-    SimdDispatch {
-        has_sse2: false,
-        has_avx2: false,
-        has_avx512: false,
-        has_neon: true, // NEON is mandatory on aarch64
-    }
-}
-
-#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
-fn init_dispatch() -> SimdDispatch {
-    SimdDispatch {
-        has_sse2: false,
-        has_avx2: false,
-        has_avx512: false,
-        has_neon: false,
-    }
-}
-
 /// NEON threshold for using SIMD path finding (16 bytes = 128-bit vector)
 #[cfg(target_arch = "aarch64")]
 const SIMD_NEON_THRESHOLD: usize = 16;
@@ -623,31 +586,31 @@ fn find_delim_dynamic(
     bytes: &[u8],
     start: usize,
     remaining: usize,
-    dispatch: SimdDispatch,
+    level: SimdLevel,
 ) -> Option<usize> {
     #[cfg(target_arch = "x86_64")]
     {
-        if dispatch.has_avx512 && remaining >= SIMD_AVX512_THRESHOLD {
+        if level.includes(SimdLevel::Avx512) && remaining >= SIMD_AVX512_THRESHOLD {
             return find_delim_avx512_wrapper(bytes, start);
         }
-        if dispatch.has_avx2 && remaining >= SIMD_AVX2_THRESHOLD {
+        if level.includes(SimdLevel::Avx2) && remaining >= SIMD_AVX2_THRESHOLD {
             return find_delim_avx2_wrapper(bytes, start);
         }
-        if dispatch.has_sse2 && remaining >= SIMD_SSE2_THRESHOLD {
+        if level.includes(SimdLevel::Sse2) && remaining >= SIMD_SSE2_THRESHOLD {
             return find_delim_sse2(bytes, start);
         }
     }
 
     #[cfg(target_arch = "x86")]
     {
-        if dispatch.has_sse2 && remaining >= SIMD_SSE2_THRESHOLD {
+        if level.includes(SimdLevel::Sse2) && remaining >= SIMD_SSE2_THRESHOLD {
             return find_delim_sse2(bytes, start);
         }
     }
 
     #[cfg(target_arch = "aarch64")]
     {
-        if dispatch.has_neon && remaining >= SIMD_NEON_THRESHOLD {
+        if level == SimdLevel::Neon && remaining >= SIMD_NEON_THRESHOLD {
             return find_delim_neon(bytes, start);
         }
     }
@@ -661,31 +624,31 @@ fn find_byte_dynamic(
     start: usize,
     remaining: usize,
     needle: u8,
-    dispatch: SimdDispatch,
+    level: SimdLevel,
 ) -> Option<usize> {
     #[cfg(target_arch = "x86_64")]
     {
-        if dispatch.has_avx512 && remaining >= SIMD_AVX512_THRESHOLD {
+        if level.includes(SimdLevel::Avx512) && remaining >= SIMD_AVX512_THRESHOLD {
             return find_byte_avx512_wrapper(bytes, start, needle);
         }
-        if dispatch.has_avx2 && remaining >= SIMD_AVX2_THRESHOLD {
+        if level.includes(SimdLevel::Avx2) && remaining >= SIMD_AVX2_THRESHOLD {
             return find_byte_avx2_wrapper(bytes, start, needle);
         }
-        if dispatch.has_sse2 && remaining >= SIMD_SSE2_THRESHOLD {
+        if level.includes(SimdLevel::Sse2) && remaining >= SIMD_SSE2_THRESHOLD {
             return find_byte_sse2(bytes, start, needle);
         }
     }
 
     #[cfg(target_arch = "x86")]
     {
-        if dispatch.has_sse2 && remaining >= SIMD_SSE2_THRESHOLD {
+        if level.includes(SimdLevel::Sse2) && remaining >= SIMD_SSE2_THRESHOLD {
             return find_byte_sse2(bytes, start, needle);
         }
     }
 
     #[cfg(target_arch = "aarch64")]
     {
-        if dispatch.has_neon && remaining >= SIMD_NEON_THRESHOLD {
+        if level == SimdLevel::Neon && remaining >= SIMD_NEON_THRESHOLD {
             return find_byte_neon(bytes, start, needle);
         }
     }
@@ -693,6 +656,16 @@ fn find_byte_dynamic(
     memchr(needle, &bytes[start..]).map(|pos| start + pos)
 }
 
+#[inline]
+fn find_delim_scalar(bytes: &[u8], start: usize) -> Option<usize> {
+    memchr2(b'.', b'[', &bytes[start..]).map(|pos| start + pos)
+}
+
+#[inline]
+fn find_byte_scalar(bytes: &[u8], start: usize, needle: u8) -> Option<usize> {
+    memchr(needle, &bytes[start..]).map(|pos| start + pos)
+}
+
 #[cfg(target_arch = "x86_64")]
 #[inline]
 fn find_delim_sse2(bytes: &[u8], start: usize) -> Option<usize> {
@@ -1523,12 +1496,18 @@ mod tests {
 
     // SIMD dispatch tests
     #[test]
-    fn test_simd_dispatch_init() {
-        let dispatch = init_dispatch();
-        // Just check that it doesn't panic
-        let _ = dispatch.has_sse2;
-        let _ = dispatch.has_avx2;
-        let _ = dispatch.has_avx512;
+    fn test_parse_simd_with_level_matches_baseline() {
+        let long = format!("{}.tail[12].{}", "segment".repeat(20), "x".repeat(70));
+        for path in ["user.name", "users[0].name", "a..b", long.as_str()] {
+            let expected = parse_baseline(path);
+            for level in crate::simd::available_levels() {
+                assert_eq!(
+                    parse_simd_with_level(path, level),
+                    expected,
+                    "{level}: {path}"
+                );
+            }
+        }
     }
 
     // Edge case tests
@@ -1648,33 +1627,33 @@ mod tests {
     // Dynamic dispatch tests
     #[test]
     fn test_find_delim_dynamic_short_path() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"a.b";
-        let result = find_delim_dynamic(bytes, 0, bytes.len(), dispatch);
+        let result = find_delim_dynamic(bytes, 0, bytes.len(), level);
         assert_eq!(result, Some(1));
     }
 
     #[test]
     fn test_find_delim_dynamic_no_delim() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"abcdefgh";
-        let result = find_delim_dynamic(bytes, 0, bytes.len(), dispatch);
+        let result = find_delim_dynamic(bytes, 0, bytes.len(), level);
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_find_byte_dynamic_short() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"a]b";
-        let result = find_byte_dynamic(bytes, 0, bytes.len(), b']', dispatch);
+        let result = find_byte_dynamic(bytes, 0, bytes.len(), b']', level);
         assert_eq!(result, Some(1));
     }
 
     #[test]
     fn test_find_byte_dynamic_not_found() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"abcdefgh";
-        let result = find_byte_dynamic(bytes, 0, bytes.len(), b']', dispatch);
+        let result = find_byte_dynamic(bytes, 0, bytes.len(), b']', level);
         assert_eq!(result, None);
     }
 
@@ -1883,17 +1862,17 @@ mod tests {
 
     #[test]
     fn test_find_delim_dynamic_with_start() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"aaa.bbb";
-        let result = find_delim_dynamic(bytes, 2, bytes.len(), dispatch);
+        let result = find_delim_dynamic(bytes, 2, bytes.len(), level);
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_find_byte_dynamic_with_start() {
-        let dispatch = init_dispatch();
+        let level = crate::simd::dispatch().path;
         let bytes = b"aa]bb]cc";
-        let result = find_byte_dynamic(bytes, 3, bytes.len(), b']', dispatch);
+        let result = find_byte_dynamic(bytes, 3, bytes.len(), b']', level);
         assert_eq!(result, Some(5));
     }
 
@@ -1923,21 +1902,11 @@ mod tests {
         assert_eq!(components.len(), 60);
     }
 
-    #[test]
-    fn test_simd_dispatch_fields() {
-        let dispatch = init_dispatch();
-        // Test all fields are accessible
-        let _ = dispatch.has_sse2;
-        let _ = dispatch.has_avx2;
-        let _ = dispatch.has_avx512;
-        let _ = dispatch.has_neon;
-    }
-
     #[test]
     fn test_global_dispatch() {
-        let dispatch = DISPATCH.get_or_init(init_dispatch);
-        // Just verify it doesn't panic and returns valid dispatch
-        let _ = dispatch.has_sse2;
+        let level = crate::simd::dispatch().path;
+        // The path kernel never exceeds the CPU
+        assert!(level.is_supported());
     }
 
     #[test]
@@ -0,0 +1,735 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Path dialects and lossless conversion between them
+//!
+//! Each subsystem speaks its own path syntax: gron writes JavaScript-style
+//! `json.a["b c"][0]`, JSON Patch uses JSON Pointer `/a/b c/0`, and
+//! operations and schemas use dotted `a.b`. [`PathStyle`] renders and parses
+//! all of them through one component list, so a path can move between
+//! dialects without losing field names that contain dots, brackets, quotes,
+//! `~` or `/`.
+//!
+//! | Style      | Example                  | Root    |
+//! |------------|--------------------------|---------|
+//! | `js`       | `json.a["b c"][0]`       | `json`  |
+//! | `pointer`  | `/a/b c/0`               | (empty) |
+//! | `jsonpath` | `$['a']['b c'][0]`       | `$`     |
+//! | `jq`       | `.a["b c"][0]`           | `.`     |
+//! | `dotted`   | `a.b c[0]`               | (empty) |
+//!
+//! JSON Pointer does not distinguish array indices from numeric field names;
+//! tokens such as `0` parse back as indices, and callers resolve them against
+//! the value they index (ungron treats an index into an object as a field).
+//! Every other style round-trips exactly.
+//!
+//! ```
+//! use fionn_core::path::PathStyle;
+//!
+//! let path = PathStyle::Js.convert(r#"json.a["b.c"][0]"#, PathStyle::Pointer)?;
+//! assert_eq!(path, "/a/b.c/0");
+//! let path = PathStyle::Pointer.convert("/a~1b/0", PathStyle::JsonPath)?;
+//! assert_eq!(path, "$['a/b'][0]");
+//! # Ok::<(), fionn_core::path::ParsePathError>(())
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::PathComponent;
+
+/// Syntax used to render and parse paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PathStyle {
+    /// JavaScript member access as written by gron: `json.a["b c"][0]`
+    #[default]
+    Js,
+    /// RFC 6901 JSON Pointer: `/a/b c/0`
+    Pointer,
+    /// RFC 9535 normalized `JSONPath`: `$['a']['b c'][0]`
+    JsonPath,
+    /// jq path expression: `.a["b c"][0]`
+    Jq,
+    /// Dotted field names with bracketed indices: `a.b c[0]`
+    ///
+    /// `.`, `[`, `]` and `\` inside names are escaped with a backslash.
+    /// Names that are empty, start or end with whitespace, or contain control
+    /// characters are quoted instead (`[""]`, `[" a"]`, `["a\nb"]`) so the
+    /// path stays on one line and survives trimming.
+    Dotted,
+}
+
+impl PathStyle {
+    /// Every style
+    pub const ALL: [Self; 5] = [
+        Self::Js,
+        Self::Pointer,
+        Self::JsonPath,
+        Self::Jq,
+        Self::Dotted,
+    ];
+
+    /// Name used on the command line
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Js => "js",
+            Self::Pointer => "pointer",
+            Self::JsonPath => "jsonpath",
+            Self::Jq => "jq",
+            Self::Dotted => "dotted",
+        }
+    }
+
+    /// Rendering of the empty path
+    #[must_use]
+    pub const fn root(self) -> &'static str {
+        match self {
+            Self::Js => "json",
+            Self::Pointer | Self::Dotted => "",
+            Self::JsonPath => "$",
+            Self::Jq => ".",
+        }
+    }
+
+    /// Append a field component to a rendered path
+    pub fn push_field(self, out: &mut Vec<u8>, name: &str) {
+        match self {
+            Self::Js if is_js_identifier(name) => {
+                out.push(b'.');
+                out.extend_from_slice(name.as_bytes());
+            }
+            Self::Jq if is_jq_identifier(name) => {
+                if out.as_slice() != b"." {
+                    out.push(b'.');
+                }
+                out.extend_from_slice(name.as_bytes());
+            }
+            Self::Js | Self::Jq => {
+                if out.is_empty() && self == Self::Jq {
+                    out.push(b'.');
+                }
+                push_quoted(out, name, b'"');
+            }
+            Self::Pointer => {
+                out.push(b'/');
+                for byte in name.bytes() {
+                    match byte {
+                        b'~' => out.extend_from_slice(b"~0"),
+                        b'/' => out.extend_from_slice(b"~1"),
+                        _ => out.push(byte),
+                    }
+                }
+            }
+            Self::JsonPath => push_quoted(out, name, b'\''),
+            Self::Dotted if needs_dotted_quoting(name) => push_quoted(out, name, b'"'),
+            Self::Dotted => {
+                if !out.is_empty() {
+                    out.push(b'.');
+                }
+                for byte in name.bytes() {
+                    if matches!(byte, b'.' | b'[' | b']' | b'\\') {
+                        out.push(b'\\');
+                    }
+                    out.push(byte);
+                }
+            }
+        }
+    }
+
+    /// Append an array index component to a rendered path
+    pub fn push_index(self, out: &mut Vec<u8>, index: usize) {
+        let digits = index.to_string();
+        if self == Self::Pointer {
+            out.push(b'/');
+            out.extend_from_slice(digits.as_bytes());
+            return;
+        }
+        if out.is_empty() && self == Self::Jq {
+            out.push(b'.');
+        }
+        out.push(b'[');
+        out.extend_from_slice(digits.as_bytes());
+        out.push(b']');
+    }
+
+    /// Render components as a path in this style
+    #[must_use]
+    pub fn format(self, components: &[PathComponent]) -> String {
+        let mut out = self.root().as_bytes().to_vec();
+        for component in components {
+            match component {
+                PathComponent::Field(name) => self.push_field(&mut out, name),
+                PathComponent::ArrayIndex(index) => self.push_index(&mut out, *index),
+            }
+        }
+        // Safety: roots and escapes are ASCII and names are valid UTF-8
+        unsafe { String::from_utf8_unchecked(out) }
+    }
+
+    /// Parse a path in this style into components
+    ///
+    /// A `js` path may start with any root identifier (the gron prefix);
+    /// it is not part of the components.
+    ///
+    /// # Errors
+    /// Returns an error if the path is not valid in this style.
+    pub fn parse(self, path: &str) -> Result<Vec<PathComponent>, ParsePathError> {
+        let mut cursor = Cursor {
+            style: self,
+            path,
+            pos: 0,
+        };
+        match self {
+            Self::Js => cursor.parse_js(),
+            Self::Pointer => cursor.parse_pointer(),
+            Self::JsonPath => cursor.parse_jsonpath(),
+            Self::Jq => cursor.parse_jq(),
+            Self::Dotted => cursor.parse_dotted(),
+        }
+    }
+
+    /// Rewrite a path in this style into another style
+    ///
+    /// # Errors
+    /// Returns an error if the path is not valid in this style.
+    pub fn convert(self, path: &str, to: Self) -> Result<String, ParsePathError> {
+        Ok(to.format(&self.parse(path)?))
+    }
+}
+
+impl fmt::Display for PathStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Unknown path style name
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown path style '{0}' (expected js, pointer, jsonpath, jq or dotted)")]
+pub struct ParsePathStyleError(pub String);
+
+impl FromStr for PathStyle {
+    type Err = ParsePathStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|style| style.name() == name)
+            .ok_or_else(|| ParsePathStyleError(s.to_string()))
+    }
+}
+
+/// Path that is not valid in its style
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid {style} path '{path}' at byte {position}: {reason}")]
+pub struct ParsePathError {
+    /// Style the path was parsed as
+    pub style: PathStyle,
+    /// The rejected path
+    pub path: String,
+    /// Byte offset of the problem
+    pub position: usize,
+    /// What was wrong
+    pub reason: &'static str,
+}
+
+/// Whether a name can follow `.` in a `js` path
+fn is_js_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.as_bytes()[0].is_ascii_digit()
+        && !name.bytes().any(|byte| {
+            matches!(byte, b'.' | b'[' | b']' | b'"' | b'\\' | b' ' | 0..=0x1f | 0x80..=0xff)
+        })
+}
+
+/// Whether a name can follow `.` in a `jq` path
+fn is_jq_identifier(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    !bytes.is_empty()
+        && (bytes[0].is_ascii_alphabetic() || bytes[0] == b'_')
+        && bytes
+            .iter()
+            .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+}
+
+/// Whether a name has to be bracket-quoted in a `dotted` path
+fn needs_dotted_quoting(name: &str) -> bool {
+    name.is_empty()
+        || name.starts_with(char::is_whitespace)
+        || name.ends_with(char::is_whitespace)
+        || name.chars().any(char::is_control)
+}
+
+/// Append `[<quote>name<quote>]` with JSON-style escapes
+fn push_quoted(out: &mut Vec<u8>, name: &str, quote: u8) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    out.push(b'[');
+    out.push(quote);
+    for byte in name.bytes() {
+        match byte {
+            b'\\' => out.extend_from_slice(b"\\\\"),
+            b'\x08' => out.extend_from_slice(b"\\b"),
+            b'\x0c' => out.extend_from_slice(b"\\f"),
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\r' => out.extend_from_slice(b"\\r"),
+            b'\t' => out.extend_from_slice(b"\\t"),
+            0..=0x1f => out.extend_from_slice(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX[usize::from(byte >> 4)],
+                HEX[usize::from(byte & 0xf)],
+            ]),
+            _ if byte == quote => out.extend_from_slice(&[b'\\', quote]),
+            _ => out.push(byte),
+        }
+    }
+    out.push(quote);
+    out.push(b']');
+}
+
+/// Position in a path being parsed
+struct Cursor<'a> {
+    style: PathStyle,
+    path: &'a str,
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn error(&self, reason: &'static str) -> ParsePathError {
+        ParsePathError {
+            style: self.style,
+            path: self.path.to_string(),
+            position: self.pos,
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.path.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, byte: u8, reason: &'static str) -> Result<(), ParsePathError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Take bytes up to (not including) the next `.` or `[`
+    fn name(&mut self) -> &str {
+        let rest = &self.path[self.pos..];
+        let len = rest.find(['.', '[']).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Parse `[N]`, `["name"]` or `['name']` after the opening bracket
+    fn bracket(&mut self, quotes: &[u8]) -> Result<PathComponent, ParsePathError> {
+        let component = match self.peek() {
+            Some(quote) if quotes.contains(&quote) => {
+                self.pos += 1;
+                PathComponent::Field(self.quoted(quote)?)
+            }
+            Some(b'0'..=b'9') => {
+                let rest = &self.path[self.pos..];
+                let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+                let index = rest[..len]
+                    .parse()
+                    .map_err(|_| self.error("array index out of range"))?;
+                self.pos += len;
+                PathComponent::ArrayIndex(index)
+            }
+            _ => return Err(self.error("expected an array index or quoted name")),
+        };
+        self.expect(b']', "expected ']'")?;
+        Ok(component)
+    }
+
+    /// Parse a quoted name after its opening quote, consuming the closing one
+    fn quoted(&mut self, quote: u8) -> Result<String, ParsePathError> {
+        let mut name = String::new();
+        loop {
+            let rest = &self.path[self.pos..];
+            let len = rest
+                .find(|c: char| c == char::from(quote) || c == '\\')
+                .ok_or_else(|| self.error("unterminated quoted name"))?;
+            name.push_str(&rest[..len]);
+            self.pos += len;
+            if self.eat(quote) {
+                return Ok(name);
+            }
+            self.pos += 1;
+            let escaped = self
+                .peek()
+                .ok_or_else(|| self.error("unterminated escape"))?;
+            self.pos += 1;
+            match escaped {
+                b'b' => name.push('\x08'),
+                b'f' => name.push('\x0c'),
+                b'n' => name.push('\n'),
+                b'r' => name.push('\r'),
+                b't' => name.push('\t'),
+                b'u' => name.push(self.unicode_escape()?),
+                b'\\' | b'/' | b'"' | b'\'' => name.push(char::from(escaped)),
+                _ => return Err(self.error("invalid escape")),
+            }
+        }
+    }
+
+    /// Decode the `XXXX` of `\uXXXX`, joining surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, ParsePathError> {
+        let high = self.hex4()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            if !self.path[self.pos..].starts_with("\\u") {
+                return Err(self.error("unpaired surrogate"));
+            }
+            self.pos += 2;
+            let low = self.hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("unpaired surrogate"));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, ParsePathError> {
+        let digits = self
+            .path
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        let code =
+            u32::from_str_radix(digits, 16).map_err(|_| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    /// `root(.name | [N] | ["name"])*`
+    fn parse_js(&mut self) -> Result<Vec<PathComponent>, ParsePathError> {
+        let mut components = Vec::new();
+        self.name();
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            if byte == b'.' {
+                components.push(PathComponent::Field(self.name().to_string()));
+            } else {
+                components.push(self.bracket(b"\"")?);
+            }
+        }
+        Ok(components)
+    }
+
+    /// `(/token)*` with `~0` for `~` and `~1` for `/`
+    fn parse_pointer(&mut self) -> Result<Vec<PathComponent>, ParsePathError> {
+        if self.path.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !self.path.starts_with('/') {
+            return Err(self.error("expected '/'"));
+        }
+        let mut components = Vec::new();
+        for token in self.path[1..].split('/') {
+            self.pos += 1;
+            let is_index = !token.is_empty()
+                && token.bytes().all(|byte| byte.is_ascii_digit())
+                && (token == "0" || !token.starts_with('0'));
+            if let Some(index) = is_index.then(|| token.parse().ok()).flatten() {
+                components.push(PathComponent::ArrayIndex(index));
+            } else {
+                let mut name = String::with_capacity(token.len());
+                let mut chars = token.chars();
+                while let Some(c) = chars.next() {
+                    if c != '~' {
+                        name.push(c);
+                        continue;
+                    }
+                    match chars.next() {
+                        Some('0') => name.push('~'),
+                        Some('1') => name.push('/'),
+                        _ => return Err(self.error("'~' must be followed by 0 or 1")),
+                    }
+                }
+                components.push(PathComponent::Field(name));
+            }
+            self.pos += token.len();
+        }
+        Ok(components)
+    }
+
+    /// `$(['name'] | [N] | .name)*`
+    fn parse_jsonpath(&mut self) -> Result<Vec<PathComponent>, ParsePathError> {
+        self.expect(b'$', "expected '$'")?;
+        let mut components = Vec::new();
+        while let Some(byte) = self.peek() {
+            self.pos += 1;
+            match byte {
+                b'[' => components.push(self.bracket(b"'\"")?),
+                b'.' => match self.name() {
+                    "" => return Err(self.error("expected a field name")),
+                    name => components.push(PathComponent::Field(name.to_string())),
+                },
+                _ => return Err(self.error("expected '[' or '.'")),
+            }
+        }
+        Ok(components)
+    }
+
+    /// `.` or `(.name | [N] | ["name"] | .[N] | .["name"])+`
+    fn parse_jq(&mut self) -> Result<Vec<PathComponent>, ParsePathError> {
+        self.expect(b'.', "expected '.'")?;
+        let mut components = Vec::new();
+        let mut after_dot = true;
+        while let Some(byte) = self.peek() {
+            if byte == b'[' {
+                self.pos += 1;
+                components.push(self.bracket(b"\"")?);
+                after_dot = false;
+            } else if after_dot {
+                match self.name() {
+                    "" => return Err(self.error("expected a field name")),
+                    name => components.push(PathComponent::Field(name.to_string())),
+                }
+                after_dot = false;
+            } else {
+                self.expect(b'.', "expected '.' or '['")?;
+                after_dot = true;
+            }
+        }
+        if after_dot && !components.is_empty() {
+            return Err(self.error("expected a field name"));
+        }
+        Ok(components)
+    }
+
+    /// `name(.name | [N] | [""])*` with backslash escapes in names
+    fn parse_dotted(&mut self) -> Result<Vec<PathComponent>, ParsePathError> {
+        let mut components = Vec::new();
+        if self.path.is_empty() {
+            return Ok(components);
+        }
+        let mut expect_name = true;
+        loop {
+            if self.eat(b'[') {
+                components.push(self.bracket(b"\"")?);
+            } else if expect_name {
+                components.push(PathComponent::Field(self.escaped_name()?));
+            } else {
+                return Err(self.error("expected '.' or '['"));
+            }
+            match self.peek() {
+                None => return Ok(components),
+                Some(b'.') => {
+                    self.pos += 1;
+                    expect_name = true;
+                }
+                _ => expect_name = false,
+            }
+        }
+    }
+
+    /// Take a dotted name up to the next unescaped `.` or `[`
+    fn escaped_name(&mut self) -> Result<String, ParsePathError> {
+        let mut name = String::new();
+        loop {
+            let rest = &self.path[self.pos..];
+            let len = rest.find(['.', '[', ']', '\\']).unwrap_or(rest.len());
+            name.push_str(&rest[..len]);
+            self.pos += len;
+            match self.peek() {
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = self.path[self.pos..]
+                        .chars()
+                        .next()
+                        .ok_or_else(|| self.error("unterminated escape"))?;
+                    name.push(escaped);
+                    self.pos += escaped.len_utf8();
+                }
+                Some(b']') => return Err(self.error("unescaped ']'")),
+                _ if name.is_empty() => return Err(self.error("expected a field name")),
+                _ => return Ok(name),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str) -> PathComponent {
+        PathComponent::Field(name.to_string())
+    }
+
+    /// Components exercising every escape rule
+    fn awkward() -> Vec<PathComponent> {
+        vec![
+            field("a"),
+            field("b c"),
+            PathComponent::ArrayIndex(0),
+            field("d.e"),
+            field("[x]"),
+            field("~/"),
+            field("q\"'\\"),
+            field(""),
+            field("tab\there"),
+            field("héllo"),
+            field("12"),
+            PathComponent::ArrayIndex(12),
+        ]
+    }
+
+    // =========================================================================
+    // Formatting
+    // =========================================================================
+
+    #[test]
+    fn test_format_each_style() {
+        let components = vec![field("a"), field("b c"), PathComponent::ArrayIndex(0)];
+        assert_eq!(PathStyle::Js.format(&components), r#"json.a["b c"][0]"#);
+        assert_eq!(PathStyle::Pointer.format(&components), "/a/b c/0");
+        assert_eq!(PathStyle::JsonPath.format(&components), "$['a']['b c'][0]");
+        assert_eq!(PathStyle::Jq.format(&components), r#".a["b c"][0]"#);
+        assert_eq!(PathStyle::Dotted.format(&components), "a.b c[0]");
+    }
+
+    #[test]
+    fn test_format_root() {
+        for style in PathStyle::ALL {
+            assert_eq!(style.format(&[]), style.root());
+            assert_eq!(style.parse(style.root()).unwrap(), Vec::new());
+        }
+    }
+
+    #[test]
+    fn test_format_escapes() {
+        assert_eq!(PathStyle::Pointer.format(&[field("a/b~c")]), "/a~1b~0c");
+        assert_eq!(PathStyle::JsonPath.format(&[field("it's")]), r"$['it\'s']");
+        assert_eq!(
+            PathStyle::JsonPath.format(&[field("\u{1}")]),
+            r"$['\u0001']"
+        );
+        assert_eq!(
+            PathStyle::Dotted.format(&[field("a.b"), field("c")]),
+            r"a\.b.c"
+        );
+        assert_eq!(PathStyle::Dotted.format(&[field("")]), r#"[""]"#);
+        assert_eq!(
+            PathStyle::Dotted.format(&[field(" a"), field("b\n"), field("c d")]),
+            r#"[" a"]["b\n"].c d"#
+        );
+        assert_eq!(PathStyle::Jq.format(&[field("0a")]), r#".["0a"]"#);
+        assert_eq!(
+            PathStyle::Jq.format(&[PathComponent::ArrayIndex(3)]),
+            ".[3]"
+        );
+    }
+
+    // =========================================================================
+    // Parsing
+    // =========================================================================
+
+    #[test]
+    fn test_roundtrip_every_style() {
+        let components = awkward();
+        for style in PathStyle::ALL {
+            let rendered = style.format(&components);
+            let parsed = style.parse(&rendered).unwrap();
+            if style == PathStyle::Pointer {
+                // Numeric names read back as indices
+                assert_eq!(parsed[10], PathComponent::ArrayIndex(12));
+                assert_eq!(parsed[..10], components[..10]);
+            } else {
+                assert_eq!(parsed, components, "{style}: {rendered}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_between_styles() {
+        for from in PathStyle::ALL {
+            for to in PathStyle::ALL {
+                let components = vec![field("a.b"), PathComponent::ArrayIndex(2), field("c/d")];
+                let path = from.format(&components);
+                let converted = from.convert(&path, to).unwrap();
+                assert_eq!(to.parse(&converted).unwrap(), components, "{from} -> {to}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_js_custom_root() {
+        assert_eq!(
+            PathStyle::Js.parse(r#"data.a["x"][1]"#).unwrap(),
+            vec![field("a"), field("x"), PathComponent::ArrayIndex(1)]
+        );
+        assert_eq!(PathStyle::Js.parse(".a").unwrap(), vec![field("a")]);
+    }
+
+    #[test]
+    fn test_parse_lenient_forms() {
+        assert_eq!(
+            PathStyle::JsonPath.parse(r#"$.a["b"]"#).unwrap(),
+            vec![field("a"), field("b")]
+        );
+        assert_eq!(
+            PathStyle::Jq.parse(r#".a.["b"].[0]"#).unwrap(),
+            vec![field("a"), field("b"), PathComponent::ArrayIndex(0)]
+        );
+        assert_eq!(
+            PathStyle::Pointer.parse("/01/0").unwrap(),
+            vec![field("01"), PathComponent::ArrayIndex(0)]
+        );
+        assert_eq!(
+            PathStyle::Js.parse(r#"json["😀"]"#).unwrap(),
+            vec![field("\u{1f600}")]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(PathStyle::Pointer.parse("a/b").is_err());
+        assert!(PathStyle::Pointer.parse("/a~2").is_err());
+        assert!(PathStyle::JsonPath.parse("a").is_err());
+        assert!(PathStyle::JsonPath.parse("$['a'").is_err());
+        assert!(PathStyle::Jq.parse("a").is_err());
+        assert!(PathStyle::Jq.parse(".a.").is_err());
+        assert!(PathStyle::Dotted.parse("a..b").is_err());
+        assert!(PathStyle::Dotted.parse("a]").is_err());
+        assert!(PathStyle::Js.parse("json[x]").is_err());
+        assert!(PathStyle::Js.parse(r#"json["\q"]"#).is_err());
+
+        let err = PathStyle::Jq.parse("a").unwrap_err();
+        assert_eq!(err.position, 0);
+        assert!(err.to_string().contains("invalid jq path"));
+    }
+
+    // =========================================================================
+    // Names
+    // =========================================================================
+
+    #[test]
+    fn test_from_str() {
+        for style in PathStyle::ALL {
+            assert_eq!(style.name().parse::<PathStyle>().unwrap(), style);
+            assert_eq!(style.to_string(), style.name());
+        }
+        assert_eq!("JQ".parse::<PathStyle>().unwrap(), PathStyle::Jq);
+        assert!("xpath".parse::<PathStyle>().is_err());
+    }
+}
@@ -100,7 +100,7 @@ impl BlackBoxSerde {
                         serde_json::Value::Number(n) => {
                             operations.push(DsonOperation::FieldAdd {
                                 path: field_path,
-                                value: OperationValue::NumberRef(n.to_string()),
+                                value: OperationValue::NumberRef(n.into()),
                             });
                         }
                         serde_json::Value::Bool(b) => {
@@ -141,7 +141,7 @@ impl BlackBoxSerde {
                     serde_json::Value::Number(n) => {
                         operations.push(DsonOperation::FieldAdd {
                             path: path.to_string(),
-                            value: OperationValue::NumberRef(n.to_string()),
+                            value: OperationValue::NumberRef(n.into()),
                         });
                     }
                     serde_json::Value::Bool(b) => {
@@ -218,19 +218,7 @@ impl BlackBoxSerde {
     fn operation_value_to_json(value: &OperationValue) -> serde_json::Value {
         match value {
             OperationValue::StringRef(s) => serde_json::Value::String(s.clone()),
-            OperationValue::NumberRef(n) => n.parse::<i64>().map_or_else(
-                |_| {
-                    n.parse::<f64>().map_or_else(
-                        |_| serde_json::Value::String(n.clone()),
-                        |num| {
-                            serde_json::Number::from_f64(num).map_or_else(
-                                || serde_json::Value::String(n.clone()),
-                                serde_json::Value::Number,
-                            )
-                        },
-                    )
-                },
-                |num| serde_json::Value::Number(num.into()),
+            OperationValue::NumberRef(n) => n.to_json_value(),
             ),
             OperationValue::BoolRef(b) => serde_json::Value::Bool(*b),
             // Handle Null and all other variants (ObjectRef, ArrayRef, etc.)
@@ -344,7 +332,7 @@ mod tests {
         let serde = BlackBoxSerde::new_unfiltered();
         let ops = vec![DsonOperation::FieldAdd {
             path: "age".to_string(),
-            value: OperationValue::NumberRef("30".to_string()),
+            value: OperationValue::NumberRef("30".into()),
         }];
         let result = serde.serialize_operations(&ops);
         assert!(result.is_ok());
@@ -355,7 +343,7 @@ mod tests {
         let serde = BlackBoxSerde::new_unfiltered();
         let ops = vec![DsonOperation::FieldAdd {
             path: "score".to_string(),
-            value: OperationValue::NumberRef("3.14".to_string()),
+            value: OperationValue::NumberRef("3.14".into()),
         }];
         let result = serde.serialize_operations(&ops);
         assert!(result.is_ok());
@@ -399,7 +387,7 @@ mod tests {
         let serde = BlackBoxSerde::new_unfiltered();
         let ops = vec![DsonOperation::FieldAdd {
             path: "value".to_string(),
-            value: OperationValue::NumberRef("not_a_number".to_string()),
+            value: OperationValue::NumberRef("not_a_number".into()),
         }];
         let result = serde.serialize_operations(&ops);
         assert!(result.is_ok());
@@ -528,7 +516,7 @@ mod tests {
         // NaN and Infinity are not valid JSON numbers, should fall back to string
         let ops = vec![DsonOperation::FieldAdd {
             path: "value".to_string(),
-            value: OperationValue::NumberRef("NaN".to_string()),
+            value: OperationValue::NumberRef("NaN".into()),
         }];
         let result = serde.serialize_operations(&ops);
         assert!(result.is_ok());
@@ -539,7 +527,7 @@ mod tests {
         let serde = BlackBoxSerde::new_unfiltered();
         let ops = vec![DsonOperation::FieldAdd {
             path: "value".to_string(),
-            value: OperationValue::NumberRef("Infinity".to_string()),
+            value: OperationValue::NumberRef("Infinity".into()),
         }];
         let result = serde.serialize_operations(&ops);
         assert!(result.is_ok());
@@ -131,9 +131,9 @@ impl SimdJsonSerdeProcessor {
                     let value = match static_val {
                         simd_json::StaticNode::Null => OperationValue::Null,
                         simd_json::StaticNode::Bool(b) => OperationValue::BoolRef(*b),
-                        simd_json::StaticNode::I64(n) => OperationValue::NumberRef(n.to_string()),
-                        simd_json::StaticNode::U64(n) => OperationValue::NumberRef(n.to_string()),
-                        simd_json::StaticNode::F64(n) => OperationValue::NumberRef(n.to_string()),
+                        simd_json::StaticNode::I64(n) => OperationValue::NumberRef((*n).into()),
+                        simd_json::StaticNode::U64(n) => OperationValue::NumberRef((*n).into()),
+                        simd_json::StaticNode::F64(n) => OperationValue::NumberRef((*n).into()),
                     };
                     self.operations.push(DsonOperation::FieldAdd {
                         path: current_path,
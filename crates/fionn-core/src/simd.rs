@@ -0,0 +1,498 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Runtime SIMD kernel dispatch
+//!
+//! Every SIMD-accelerated routine in fionn picks its kernel from one
+//! [`SimdDispatch`] table, resolved the first time any of them runs.
+//! The table records which instruction set each kernel family uses:
+//! container skipping, character classification, line splitting, string
+//! escaping and unescaping, path parsing, byte comparison and UTF-8
+//! validation.
+//!
+//! # Overriding the detected level
+//!
+//! Set `FIONN_SIMD` to `scalar`, `sse2`, `avx2`, `avx512` or `neon` to
+//! cap the instruction set, for example to reproduce a report from a
+//! machine without AVX-512. Programs can call [`configure`] instead,
+//! before the first SIMD routine runs. A level the CPU lacks is lowered
+//! to the widest supported level that does not exceed it, so an
+//! override is always safe to set.
+//!
+//! ```
+//! use fionn_core::simd::{SimdDispatch, SimdLevel};
+//!
+//! // Tables for explicit levels are clamped to the hardware
+//! let scalar = SimdDispatch::for_level(SimdLevel::Scalar);
+//! assert_eq!(scalar.skip, SimdLevel::Scalar);
+//! assert!(!scalar.pclmulqdq);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use thiserror::Error;
+
+/// Environment variable that overrides the detected SIMD level
+pub const SIMD_ENV: &str = "FIONN_SIMD";
+
+/// Instruction set a kernel is compiled for
+///
+/// `x86`/`x86_64` levels form a ladder (`Scalar` < `Sse2` < `Avx2` <
+/// `Avx512`); `Neon` is the only level above `Scalar` on `aarch64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SimdLevel {
+    /// Portable byte-at-a-time code
+    #[default]
+    Scalar,
+    /// `x86` SSE2 (128-bit)
+    Sse2,
+    /// `x86` AVX2 (256-bit)
+    Avx2,
+    /// `x86` AVX-512 with byte/word instructions (512-bit)
+    Avx512,
+    /// ARM NEON (128-bit)
+    Neon,
+}
+
+impl SimdLevel {
+    /// Every level, narrowest first
+    pub const ALL: [Self; 5] = [
+        Self::Scalar,
+        Self::Sse2,
+        Self::Avx2,
+        Self::Avx512,
+        Self::Neon,
+    ];
+
+    /// Lowercase name, as accepted by `FIONN_SIMD`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::Sse2 => "sse2",
+            Self::Avx2 => "avx2",
+            Self::Avx512 => "avx512",
+            Self::Neon => "neon",
+        }
+    }
+
+    /// Vector width in bytes
+    #[must_use]
+    pub const fn vector_width(self) -> usize {
+        match self {
+            Self::Scalar => 1,
+            Self::Sse2 | Self::Neon => 16,
+            Self::Avx2 => 32,
+            Self::Avx512 => 64,
+        }
+    }
+
+    /// Whether code for `other` may run wherever `self` is allowed
+    ///
+    /// True when both are on the same ladder and `other` is no wider.
+    #[must_use]
+    pub const fn includes(self, other: Self) -> bool {
+        match (self, other) {
+            (_, Self::Scalar) | (Self::Neon, Self::Neon) => true,
+            (Self::Neon, _) | (_, Self::Neon) => false,
+            _ => self.vector_width() >= other.vector_width(),
+        }
+    }
+
+    /// Whether the running CPU supports this level
+    #[must_use]
+    pub fn is_supported(self) -> bool {
+        cpu().supports(self)
+    }
+
+    /// Widest level the running CPU supports
+    #[must_use]
+    pub fn detect() -> Self {
+        available_levels().last().copied().unwrap_or_default()
+    }
+
+    /// Widest supported level that is no wider than `self`
+    #[must_use]
+    pub fn clamp_to_cpu(self) -> Self {
+        if self.is_supported() {
+            return self;
+        }
+        available_levels()
+            .into_iter()
+            .rev()
+            .find(|level| level.vector_width() <= self.vector_width())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for SimdLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Unknown `FIONN_SIMD` value
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown SIMD level '{0}' (expected scalar, sse2, avx2, avx512 or neon)")]
+pub struct ParseSimdLevelError(pub String);
+
+impl FromStr for SimdLevel {
+    type Err = ParseSimdLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|level| level.name() == name)
+            .ok_or_else(|| ParseSimdLevelError(s.to_string()))
+    }
+}
+
+/// Where the active level came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelSource {
+    /// CPU feature detection
+    Detected,
+    /// The `FIONN_SIMD` environment variable
+    Env,
+    /// A call to [`configure`]
+    Config,
+}
+
+impl fmt::Display for LevelSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Detected => "detected",
+            Self::Env => SIMD_ENV,
+            Self::Config => "configured",
+        })
+    }
+}
+
+/// Kernel selection for every SIMD family
+///
+/// Each family field names the kernel that family runs. A family without
+/// a kernel at the chosen level falls back to the next narrower one it has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // Auxiliary CPU feature flags
+pub struct SimdDispatch {
+    /// Level the table was built for, after clamping to the CPU
+    pub level: SimdLevel,
+    /// Level that was asked for, before clamping
+    pub requested: SimdLevel,
+    /// Where the requested level came from
+    pub source: LevelSource,
+    /// Container and string skipping (`Avx2Skip`)
+    pub skip: SimdLevel,
+    /// 64-byte character classification
+    pub classify: SimdLevel,
+    /// JSONL line splitting; any level above scalar uses `memchr`
+    pub line_split: SimdLevel,
+    /// JSON string escaping
+    pub escape: SimdLevel,
+    /// JSON string unescaping
+    pub unescape: SimdLevel,
+    /// Path delimiter search
+    pub path: SimdLevel,
+    /// Byte slice comparison
+    pub compare: SimdLevel,
+    /// UTF-8 validation of string contents
+    pub utf8: SimdLevel,
+    /// BMI2 `bzhi` for bracket counting
+    pub bmi2: bool,
+    /// Carry-less multiply for the in-string prefix XOR
+    pub pclmulqdq: bool,
+}
+
+impl SimdDispatch {
+    /// Table for `level`, lowered to what the CPU supports
+    #[must_use]
+    pub fn for_level(level: SimdLevel) -> Self {
+        Self::resolve(level, LevelSource::Config)
+    }
+
+    /// Table for the widest level the CPU supports
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::resolve(SimdLevel::detect(), LevelSource::Detected)
+    }
+
+    /// Table for `FIONN_SIMD`, or the detected level when unset
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable holds an unknown level.
+    pub fn from_env() -> Result<Self, ParseSimdLevelError> {
+        match std::env::var(SIMD_ENV) {
+            Ok(value) if !value.trim().is_empty() => {
+                Ok(Self::resolve(value.parse()?, LevelSource::Env))
+            }
+            _ => Ok(Self::detect()),
+        }
+    }
+
+    fn resolve(requested: SimdLevel, source: LevelSource) -> Self {
+        let level = requested.clamp_to_cpu();
+        let cpu = cpu();
+        let vector = level != SimdLevel::Scalar;
+        // Each family maps onto the widest kernel it actually has
+        let cap = |widest: SimdLevel| {
+            if level == SimdLevel::Neon || widest.includes(level) {
+                level
+            } else {
+                widest
+            }
+        };
+        let x86_only = |widest: SimdLevel| {
+            if level == SimdLevel::Neon {
+                SimdLevel::Scalar
+            } else {
+                cap(widest)
+            }
+        };
+        // Families with no SSE2 kernel go straight from AVX2 to scalar
+        let no_sse2 = |kernel: SimdLevel| {
+            if kernel == SimdLevel::Sse2 {
+                SimdLevel::Scalar
+            } else {
+                kernel
+            }
+        };
+
+        Self {
+            level,
+            requested,
+            source,
+            skip: no_sse2(x86_only(SimdLevel::Avx512)),
+            classify: no_sse2(cap(SimdLevel::Avx2)),
+            line_split: level,
+            escape: cap(SimdLevel::Avx2),
+            unescape: cap(SimdLevel::Avx2),
+            path: level,
+            compare: cap(SimdLevel::Avx2),
+            utf8: no_sse2(x86_only(SimdLevel::Avx2)),
+            bmi2: vector && cpu.bmi2,
+            pclmulqdq: vector && cpu.pclmulqdq,
+        }
+    }
+}
+
+impl Default for SimdDispatch {
+    fn default() -> Self {
+        Self::detect()
+    }
+}
+
+static DISPATCH: OnceLock<SimdDispatch> = OnceLock::new();
+
+/// The process-wide kernel table
+///
+/// Resolved on first use from `FIONN_SIMD` (an unknown value is ignored)
+/// or, failing that, CPU detection.
+#[must_use]
+pub fn dispatch() -> &'static SimdDispatch {
+    DISPATCH.get_or_init(|| SimdDispatch::from_env().unwrap_or_else(|_| SimdDispatch::detect()))
+}
+
+/// Pin the process-wide kernel table to `level`
+///
+/// Must run before any SIMD routine. Returns the table now in effect,
+/// which is the earlier one if the table was already resolved.
+pub fn configure(level: SimdLevel) -> &'static SimdDispatch {
+    DISPATCH.get_or_init(|| SimdDispatch::for_level(level))
+}
+
+/// Every level the CPU supports, narrowest first
+///
+/// Always starts with [`SimdLevel::Scalar`]. Tests use this to run each
+/// kernel over the same input.
+#[must_use]
+pub fn available_levels() -> Vec<SimdLevel> {
+    SimdLevel::ALL
+        .into_iter()
+        .filter(|&level| cpu().supports(level))
+        .collect()
+}
+
+/// Raw CPU feature flags, detected once
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)] // These are CPU feature flags
+struct CpuFeatures {
+    sse2: bool,
+    avx2: bool,
+    avx512: bool,
+    neon: bool,
+    bmi2: bool,
+    pclmulqdq: bool,
+}
+
+impl CpuFeatures {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn detect() -> Self {
+        Self {
+            sse2: std::is_x86_feature_detected!("sse2"),
+            avx2: std::is_x86_feature_detected!("avx2"),
+            avx512: std::is_x86_feature_detected!("avx512f")
+                && std::is_x86_feature_detected!("avx512bw"),
+            neon: false,
+            bmi2: std::is_x86_feature_detected!("bmi2"),
+            pclmulqdq: std::is_x86_feature_detected!("pclmulqdq"),
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    const fn detect() -> Self {
+        Self {
+            sse2: false,
+            avx2: false,
+            avx512: false,
+            neon: true, // NEON is mandatory on aarch64
+            bmi2: false,
+            pclmulqdq: false,
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    const fn detect() -> Self {
+        Self {
+            sse2: false,
+            avx2: false,
+            avx512: false,
+            neon: false,
+            bmi2: false,
+            pclmulqdq: false,
+        }
+    }
+
+    const fn supports(self, level: SimdLevel) -> bool {
+        match level {
+            SimdLevel::Scalar => true,
+            SimdLevel::Sse2 => self.sse2,
+            SimdLevel::Avx2 => self.avx2,
+            SimdLevel::Avx512 => self.avx512,
+            SimdLevel::Neon => self.neon,
+        }
+    }
+}
+
+fn cpu() -> &'static CpuFeatures {
+    static CPU: OnceLock<CpuFeatures> = OnceLock::new();
+    CPU.get_or_init(CpuFeatures::detect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =========================================================================
+    // SimdLevel Tests
+    // =========================================================================
+
+    #[test]
+    fn test_level_parse_roundtrip() {
+        for level in SimdLevel::ALL {
+            assert_eq!(level.name().parse::<SimdLevel>(), Ok(level));
+            assert_eq!(level.to_string(), level.name());
+        }
+        assert_eq!(" AVX2 ".parse::<SimdLevel>(), Ok(SimdLevel::Avx2));
+        assert!("avx3".parse::<SimdLevel>().is_err());
+    }
+
+    #[test]
+    fn test_level_includes() {
+        assert!(SimdLevel::Avx512.includes(SimdLevel::Sse2));
+        assert!(SimdLevel::Avx2.includes(SimdLevel::Scalar));
+        assert!(!SimdLevel::Sse2.includes(SimdLevel::Avx2));
+        assert!(SimdLevel::Neon.includes(SimdLevel::Scalar));
+        assert!(!SimdLevel::Neon.includes(SimdLevel::Sse2));
+        assert!(!SimdLevel::Avx512.includes(SimdLevel::Neon));
+    }
+
+    #[test]
+    fn test_available_levels_start_with_scalar() {
+        let levels = available_levels();
+        assert_eq!(levels.first(), Some(&SimdLevel::Scalar));
+        assert!(levels.iter().all(|level| level.is_supported()));
+        assert_eq!(levels.last().copied(), Some(SimdLevel::detect()));
+    }
+
+    #[test]
+    fn test_clamp_to_cpu() {
+        for level in SimdLevel::ALL {
+            let clamped = level.clamp_to_cpu();
+            assert!(clamped.is_supported());
+            assert!(clamped.vector_width() <= level.vector_width());
+        }
+        assert_eq!(SimdLevel::Scalar.clamp_to_cpu(), SimdLevel::Scalar);
+    }
+
+    // =========================================================================
+    // SimdDispatch Tests
+    // =========================================================================
+
+    #[test]
+    fn test_scalar_table_disables_everything() {
+        let table = SimdDispatch::for_level(SimdLevel::Scalar);
+        assert_eq!(table.level, SimdLevel::Scalar);
+        for family in [
+            table.skip,
+            table.classify,
+            table.line_split,
+            table.escape,
+            table.unescape,
+            table.path,
+            table.compare,
+            table.utf8,
+        ] {
+            assert_eq!(family, SimdLevel::Scalar);
+        }
+        assert!(!table.bmi2);
+        assert!(!table.pclmulqdq);
+    }
+
+    #[test]
+    fn test_tables_only_use_supported_kernels() {
+        for requested in SimdLevel::ALL {
+            let table = SimdDispatch::for_level(requested);
+            assert_eq!(table.requested, requested);
+            for family in [
+                table.skip,
+                table.classify,
+                table.line_split,
+                table.escape,
+                table.unescape,
+                table.path,
+                table.compare,
+                table.utf8,
+            ] {
+                assert!(family.is_supported());
+                assert!(table.level.includes(family));
+            }
+        }
+    }
+
+    #[test]
+    fn test_family_caps() {
+        if SimdLevel::Avx512.is_supported() {
+            let table = SimdDispatch::for_level(SimdLevel::Avx512);
+            assert_eq!(table.skip, SimdLevel::Avx512);
+            assert_eq!(table.classify, SimdLevel::Avx2);
+            assert_eq!(table.escape, SimdLevel::Avx2);
+            assert_eq!(table.path, SimdLevel::Avx512);
+        }
+        if SimdLevel::Sse2.is_supported() {
+            let table = SimdDispatch::for_level(SimdLevel::Sse2);
+            assert_eq!(table.skip, SimdLevel::Scalar);
+            assert_eq!(table.classify, SimdLevel::Scalar);
+            assert_eq!(table.escape, SimdLevel::Sse2);
+            assert_eq!(table.compare, SimdLevel::Sse2);
+        }
+    }
+
+    #[test]
+    fn test_dispatch_is_resolved_once() {
+        let first = dispatch();
+        let second = configure(SimdLevel::Scalar);
+        assert!(std::ptr::eq(first, second));
+    }
+}
@@ -127,7 +127,7 @@ fn generate_medium_scale_datasets() -> Vec<CanonicalDataset> {
             json_data: r#"{"numbers": [0,1,2,3,4]}"#.to_string(),
             operations: vec![DsonOperation::ArrayReduce {
                 path: "numbers".to_string(),
-                initial: OperationValue::NumberRef("0".to_string()),
+                initial: OperationValue::NumberRef("0".into()),
                 reducer: ReduceFunction::Sum,
             }],
             expected_result: Some(r#"{"sum": 4950}"#.to_string()),
@@ -190,7 +190,7 @@ fn generate_large_scale_datasets() -> Vec<CanonicalDataset> {
                 },
                 DsonOperation::ArrayReduce {
                     path: "data".to_string(),
-                    initial: OperationValue::NumberRef("0".to_string()),
+                    initial: OperationValue::NumberRef("0".into()),
                     reducer: ReduceFunction::Sum,
                 },
             ],
@@ -213,7 +213,7 @@ fn generate_batch_datasets() -> Vec<CanonicalDataset> {
                 },
                 DsonOperation::FieldModify {
                     path: "user.age".to_string(),
-                    value: OperationValue::NumberRef("26".to_string()),
+                    value: OperationValue::NumberRef("26".into()),
                 },
                 DsonOperation::FieldAdd {
                     path: "user.verified".to_string(),
@@ -269,7 +269,7 @@ pub fn generate_array_build_operations(n: usize) -> Vec<DsonOperation> {
 #[must_use]
 pub fn generate_number_elements(n: usize) -> Vec<OperationValue> {
     (0..n)
-        .map(|i| OperationValue::NumberRef(i.to_string()))
+        .map(|i| OperationValue::NumberRef(i.to_string().into()))
         .collect()
 }
 
@@ -293,7 +293,7 @@ pub fn generate_string_elements(n: usize) -> Vec<OperationValue> {
 pub fn generate_mixed_elements(n: usize) -> Vec<OperationValue> {
     (0..n)
         .map(|i| match i % 4 {
-            0 => OperationValue::NumberRef(i.to_string()),
+            0 => OperationValue::NumberRef(i.to_string().into()),
             1 => OperationValue::StringRef(format!("string_{i}")),
             2 => OperationValue::BoolRef(i % 2 == 0),
             _ => OperationValue::Null,
@@ -361,7 +361,7 @@ pub fn generate_performance_comparisons() -> Vec<PerformanceComparison> {
                 },
                 DsonOperation::ArrayReduce {
                     path: "temp".to_string(),
-                    initial: OperationValue::NumberRef("0".to_string()),
+                    initial: OperationValue::NumberRef("0".into()),
                     reducer: ReduceFunction::Sum,
                 },
             ],
@@ -381,7 +381,7 @@ pub fn generate_performance_comparisons() -> Vec<PerformanceComparison> {
                     },
                     DsonOperation::ArrayReduce {
                         path: "temp".to_string(),
-                        initial: OperationValue::NumberRef("0".to_string()),
+                        initial: OperationValue::NumberRef("0".into()),
                         reducer: ReduceFunction::Sum,
                     },
                 ],
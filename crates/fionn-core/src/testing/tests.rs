@@ -41,7 +41,7 @@ fn test_basic_canonical_operations() {
     });
     processor.add_operation(DsonOperation::FieldAdd {
         path: "user.age".to_string(),
-        value: OperationValue::NumberRef("30".to_string()),
+        value: OperationValue::NumberRef("30".into()),
     });
 
     let canonical = processor.compute_canonical().unwrap();
@@ -102,7 +102,7 @@ fn test_operation_optimization() {
         },
         DsonOperation::FieldAdd {
             path: "user.age".to_string(),
-            value: OperationValue::NumberRef("25".to_string()),
+            value: OperationValue::NumberRef("25".into()),
         },
         // Redundant delete
         DsonOperation::FieldDelete {
@@ -133,12 +133,12 @@ fn test_crdt_operations() {
     // Simulate concurrent updates
     processor.add_operation(DsonOperation::MergeField {
         path: "counter".to_string(),
-        value: OperationValue::NumberRef("10".to_string()),
+        value: OperationValue::NumberRef("10".into()),
         timestamp: 100,
     });
     processor.add_operation(DsonOperation::MergeField {
         path: "counter".to_string(),
-        value: OperationValue::NumberRef("15".to_string()),
+        value: OperationValue::NumberRef("15".into()),
         timestamp: 200,
     });
     processor.add_operation(DsonOperation::ConflictResolve {
@@ -174,7 +174,7 @@ fn test_complex_scenarios() {
     });
     processor.add_operation(DsonOperation::FieldAdd {
         path: "user.age".to_string(),
-        value: OperationValue::NumberRef("30".to_string()),
+        value: OperationValue::NumberRef("30".into()),
     }); // This should be filtered out in output
 
     let canonical = processor.compute_canonical().unwrap();
@@ -270,7 +270,7 @@ mod integration_tests {
         });
         processor.add_operation(DsonOperation::FieldAdd {
             path: "user.age".to_string(),
-            value: OperationValue::NumberRef("30".to_string()),
+            value: OperationValue::NumberRef("30".into()),
         });
 
         let canonical = processor.compute_canonical().unwrap();
@@ -317,7 +317,7 @@ mod integration_tests {
             },
             DsonOperation::FieldAdd {
                 path: "user.age".to_string(),
-                value: OperationValue::NumberRef("25".to_string()),
+                value: OperationValue::NumberRef("25".into()),
             },
             DsonOperation::FieldDelete {
                 path: "user.temp".to_string(),
@@ -340,12 +340,12 @@ mod integration_tests {
 
         processor.add_operation(DsonOperation::MergeField {
             path: "counter".to_string(),
-            value: OperationValue::NumberRef("10".to_string()),
+            value: OperationValue::NumberRef("10".into()),
             timestamp: 100,
         });
         processor.add_operation(DsonOperation::MergeField {
             path: "counter".to_string(),
-            value: OperationValue::NumberRef("15".to_string()),
+            value: OperationValue::NumberRef("15".into()),
             timestamp: 200,
         });
         processor.add_operation(DsonOperation::ConflictResolve {
@@ -378,7 +378,7 @@ mod integration_tests {
         });
         processor.add_operation(DsonOperation::FieldAdd {
             path: "user.age".to_string(),
-            value: OperationValue::NumberRef("30".to_string()),
+            value: OperationValue::NumberRef("30".into()),
         });
 
         let canonical = processor.compute_canonical().unwrap();
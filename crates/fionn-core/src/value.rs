@@ -4,13 +4,15 @@
 //! This module defines the value types that can be used in DSON operations.
 //! Values are designed to be zero-copy references where possible.
 
+use crate::number::JsonNumber;
+
 /// Values that can be operated on (references to avoid allocation)
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OperationValue {
     /// String value
     StringRef(String),
-    /// Number value (stored as string for precision)
-    NumberRef(String),
+    /// Number value, parsed once and kept with its lexeme
+    NumberRef(JsonNumber),
     /// Boolean value
     BoolRef(bool),
     /// Null value
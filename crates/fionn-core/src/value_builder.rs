@@ -22,9 +22,15 @@
 //! let json = builder.serialize(&obj)?;
 //! ```
 
-use crate::format::FormatKind;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use crate::format::FormatSpecificKind;
+use crate::format::{FormatKind, NodeKind};
 use crate::tape_source::TapeValue;
 use crate::{DsonError, Result};
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
 // ============================================================================
 // ValueBuilder Trait
@@ -114,6 +120,15 @@ pub trait ValueBuilder {
         self.serialize(value)
     }
 
+    /// Apply a gron type annotation to a built value
+    ///
+    /// Builders override this to restore types their format has and JSON
+    /// lacks; the default keeps the value unchanged.
+    fn annotated(&mut self, value: Self::Output, annotation: &TypeAnnotation) -> Self::Output {
+        let _ = annotation;
+        value
+    }
+
     /// Build a value from a [`TapeValue`]
     fn build_from_tape_value(&mut self, value: &TapeValue<'_>) -> Self::Output {
         match value {
@@ -235,12 +250,325 @@ impl ValueBuilder for JsonBuilder {
     }
 }
 
+// ============================================================================
+// Type Annotations
+// ============================================================================
+
+/// Value type carried alongside a gron assignment
+///
+/// Annotated gron appends the type as a trailing comment
+/// (`json.created = "1979-05-27T07:32:00Z"; # datetime`), keeping
+/// distinctions that JSON literals cannot express: TOML date/time values,
+/// floats with integral values, and YAML tags. Annotations are derived from
+/// a value's [`NodeKind`] ([`TypeAnnotation::from_node_kind`]) and map back
+/// onto it ([`TypeAnnotation::node_kind`]). Builders turn annotated values
+/// back into native types through [`ValueBuilder::annotated`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeAnnotation {
+    /// Null
+    Null,
+    /// Boolean
+    Bool,
+    /// Integer
+    Int,
+    /// Floating-point number
+    Float,
+    /// String
+    String,
+    /// Object/mapping
+    Object,
+    /// Array/sequence
+    Array,
+    /// Date-time with offset (`1979-05-27T07:32:00Z`)
+    Datetime,
+    /// Date-time without offset (`1979-05-27T07:32:00`)
+    LocalDatetime,
+    /// Date without time (`1979-05-27`)
+    LocalDate,
+    /// Time without date (`07:32:00`)
+    LocalTime,
+    /// YAML tag, written as `!name`
+    Tag(String),
+}
+
+impl TypeAnnotation {
+    /// Annotation names other than tags, in declaration order
+    pub const NAMES: [&'static str; 11] = [
+        "null",
+        "bool",
+        "int",
+        "float",
+        "string",
+        "object",
+        "array",
+        "datetime",
+        "local-datetime",
+        "local-date",
+        "local-time",
+    ];
+
+    /// Annotation name (`datetime`, `int`, `!tag`, ...)
+    #[must_use]
+    pub fn name(&self) -> Cow<'static, str> {
+        let name = match self {
+            Self::Null => "null",
+            Self::Bool => "bool",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::String => "string",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Datetime => "datetime",
+            Self::LocalDatetime => "local-datetime",
+            Self::LocalDate => "local-date",
+            Self::LocalTime => "local-time",
+            Self::Tag(tag) => return Cow::Owned(format!("!{tag}")),
+        };
+        Cow::Borrowed(name)
+    }
+
+    /// Base type of a JSON literal as written in gron output
+    ///
+    /// Numbers with a fraction or exponent are floats.
+    #[must_use]
+    pub fn of_literal(literal: &[u8]) -> Option<Self> {
+        Some(match literal.first()? {
+            b'"' => Self::String,
+            b'{' => Self::Object,
+            b'[' => Self::Array,
+            b't' | b'f' => Self::Bool,
+            b'n' => Self::Null,
+            _ if literal.iter().any(|b| matches!(b, b'.' | b'e' | b'E')) => Self::Float,
+            _ => Self::Int,
+        })
+    }
+
+    /// Annotation for a value of `kind` written as the gron `literal`
+    ///
+    /// Numbers are ints or floats by their literal; non-finite floats are
+    /// written as strings. [`FormatSpecificKind::TomlDatetime`] values get
+    /// the date/time annotation matching their text. Kinds that need more
+    /// than the literal, such as YAML tags, have no annotation here.
+    #[must_use]
+    pub fn from_node_kind(kind: NodeKind, literal: &[u8]) -> Option<Self> {
+        match kind {
+            NodeKind::Null => Some(Self::Null),
+            NodeKind::Boolean => Some(Self::Bool),
+            NodeKind::String => Some(Self::String),
+            NodeKind::Object => Some(Self::Object),
+            NodeKind::Array => Some(Self::Array),
+            NodeKind::Number => match Self::of_literal(literal)? {
+                Self::Int => Some(Self::Int),
+                _ => Some(Self::Float),
+            },
+            #[cfg(feature = "toml")]
+            NodeKind::FormatSpecific(FormatSpecificKind::TomlDatetime) => {
+                Self::of_datetime(unquote(literal)?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Date/time annotation matching the shape of `text`
+    ///
+    /// Recognizes the TOML forms: `1979-05-27T07:32:00Z` (the `T` may also
+    /// be a space), `1979-05-27T07:32:00`, `1979-05-27` and `07:32:00`,
+    /// with optional fractional seconds.
+    #[must_use]
+    pub fn of_datetime(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        match bytes.get(10) {
+            None if is_date(bytes) => Some(Self::LocalDate),
+            Some(b'T' | b't' | b' ') if is_date(&bytes[..10]) => {
+                Some(if time_has_offset(&bytes[11..])? {
+                    Self::Datetime
+                } else {
+                    Self::LocalDatetime
+                })
+            }
+            _ => (!time_has_offset(bytes)?).then_some(Self::LocalTime),
+        }
+    }
+
+    /// Check that a gron literal is a value of this type
+    ///
+    /// Floats may also be written as integers, or as strings when not
+    /// finite; date/time values must be strings of the matching shape. Tags
+    /// accept any value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the literal contradicts the annotation.
+    pub fn check_literal(&self, literal: &[u8]) -> Result<()> {
+        let base = Self::of_literal(literal);
+        let matches = match self {
+            Self::Tag(_) => true,
+            Self::Float => match base {
+                Some(Self::Int | Self::Float) => true,
+                Some(Self::String) => unquote(literal)
+                    .and_then(|text| text.parse::<f64>().ok())
+                    .is_some_and(|f| !f.is_finite()),
+                _ => false,
+            },
+            Self::Datetime | Self::LocalDatetime | Self::LocalDate | Self::LocalTime => {
+                unquote(literal).and_then(Self::of_datetime).as_ref() == Some(self)
+            }
+            _ => base.as_ref() == Some(self),
+        };
+        if matches {
+            Ok(())
+        } else {
+            Err(DsonError::ParseError(format!(
+                "type annotation '{self}' does not match value {}",
+                String::from_utf8_lossy(literal)
+            )))
+        }
+    }
+
+    /// Whether values of this type are written as JSON strings
+    #[must_use]
+    pub const fn is_string_like(&self) -> bool {
+        matches!(
+            self,
+            Self::String | Self::Datetime | Self::LocalDatetime | Self::LocalDate | Self::LocalTime
+        )
+    }
+
+    /// Universal node kind of annotated values
+    ///
+    /// Date/time values map to [`FormatSpecificKind::TomlDatetime`] and tags
+    /// to [`FormatSpecificKind::YamlTag`] when those formats are enabled.
+    #[must_use]
+    pub const fn node_kind(&self) -> NodeKind {
+        match self {
+            Self::Null => NodeKind::Null,
+            Self::Bool => NodeKind::Boolean,
+            Self::Int | Self::Float => NodeKind::Number,
+            Self::String => NodeKind::String,
+            Self::Object => NodeKind::Object,
+            Self::Array => NodeKind::Array,
+            #[cfg(feature = "toml")]
+            Self::Datetime | Self::LocalDatetime | Self::LocalDate | Self::LocalTime => {
+                NodeKind::FormatSpecific(FormatSpecificKind::TomlDatetime)
+            }
+            #[cfg(not(feature = "toml"))]
+            Self::Datetime | Self::LocalDatetime | Self::LocalDate | Self::LocalTime => {
+                NodeKind::String
+            }
+            #[cfg(feature = "yaml")]
+            Self::Tag(_) => NodeKind::FormatSpecific(FormatSpecificKind::YamlTag),
+            #[cfg(not(feature = "yaml"))]
+            Self::Tag(_) => NodeKind::Scalar,
+        }
+    }
+}
+
+/// Contents of a JSON string literal without escapes
+fn unquote(literal: &[u8]) -> Option<&str> {
+    let inner = literal.strip_prefix(b"\"")?.strip_suffix(b"\"")?;
+    std::str::from_utf8(inner)
+        .ok()
+        .filter(|text| !text.contains('\\'))
+}
+
+/// Whether `field` is a decimal number in `min..=max`
+fn in_range(field: &[u8], min: u32, max: u32) -> bool {
+    !field.is_empty()
+        && field.iter().all(u8::is_ascii_digit)
+        && (min..=max).contains(
+            &field
+                .iter()
+                .fold(0, |n: u32, digit| n * 10 + u32::from(digit - b'0')),
+        )
+}
+
+/// Whether `bytes` is a `YYYY-MM-DD` date
+fn is_date(bytes: &[u8]) -> bool {
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && in_range(&bytes[..4], 0, 9999)
+        && in_range(&bytes[5..7], 1, 12)
+        && in_range(&bytes[8..], 1, 31)
+}
+
+/// Whether `bytes`, an `HH:MM:SS[.frac]` time, carries a UTC offset
+///
+/// Returns `None` if `bytes` is not a time.
+fn time_has_offset(bytes: &[u8]) -> Option<bool> {
+    let valid = bytes.len() >= 8
+        && bytes[2] == b':'
+        && bytes[5] == b':'
+        && in_range(&bytes[..2], 0, 23)
+        && in_range(&bytes[3..5], 0, 59)
+        && in_range(&bytes[6..8], 0, 60);
+    if !valid {
+        return None;
+    }
+    let mut rest = &bytes[8..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        rest = &fraction[digits..];
+    }
+    match rest {
+        [] => Some(false),
+        [b'Z' | b'z'] => Some(true),
+        [b'+' | b'-', hours @ .., b':', m1, m2] if hours.len() == 2 => {
+            (in_range(hours, 0, 23) && in_range(&[*m1, *m2], 0, 59)).then_some(true)
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for TypeAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+/// Unknown type annotation
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown type annotation '{0}'")]
+pub struct ParseTypeAnnotationError(pub String);
+
+impl FromStr for TypeAnnotation {
+    type Err = ParseTypeAnnotationError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let name = s.trim();
+        if let Some(tag) = name.strip_prefix('!') {
+            return if tag.is_empty() || tag.contains(char::is_whitespace) {
+                Err(ParseTypeAnnotationError(s.to_string()))
+            } else {
+                Ok(Self::Tag(tag.to_string()))
+            };
+        }
+        Ok(match name {
+            "null" => Self::Null,
+            "bool" => Self::Bool,
+            "int" => Self::Int,
+            "float" => Self::Float,
+            "string" => Self::String,
+            "object" => Self::Object,
+            "array" => Self::Array,
+            "datetime" => Self::Datetime,
+            "local-datetime" => Self::LocalDatetime,
+            "local-date" => Self::LocalDate,
+            "local-time" => Self::LocalTime,
+            _ => return Err(ParseTypeAnnotationError(s.to_string())),
+        })
+    }
+}
+
 // ============================================================================
 // Path Navigation Helper
 // ============================================================================
 
 /// Path segment for navigation during ungron
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PathSegment {
     /// Object field access
     Field(String),
@@ -676,6 +1004,171 @@ mod tests {
         let builder = JsonBuilder::new();
         assert_eq!(builder.target_format(), FormatKind::Json);
     }
+
+    // =========================================================================
+    // Type Annotation Tests
+    // =========================================================================
+
+    #[test]
+    fn test_type_annotation_name_roundtrip() {
+        for name in TypeAnnotation::NAMES {
+            let annotation: TypeAnnotation = name.parse().unwrap();
+            assert_eq!(annotation.name(), name);
+        }
+        let tag: TypeAnnotation = "!color".parse().unwrap();
+        assert_eq!(tag, TypeAnnotation::Tag("color".to_string()));
+        assert_eq!(tag.to_string(), "!color");
+    }
+
+    #[test]
+    fn test_type_annotation_parse_error() {
+        assert!("decimal".parse::<TypeAnnotation>().is_err());
+        assert!("!".parse::<TypeAnnotation>().is_err());
+        assert!("!a b".parse::<TypeAnnotation>().is_err());
+    }
+
+    #[test]
+    fn test_type_annotation_of_literal() {
+        let cases: [(&[u8], TypeAnnotation); 8] = [
+            (b"\"x\"", TypeAnnotation::String),
+            (b"{}", TypeAnnotation::Object),
+            (b"[]", TypeAnnotation::Array),
+            (b"true", TypeAnnotation::Bool),
+            (b"null", TypeAnnotation::Null),
+            (b"-12", TypeAnnotation::Int),
+            (b"1.0", TypeAnnotation::Float),
+            (b"1e300", TypeAnnotation::Float),
+        ];
+        for (literal, expected) in cases {
+            assert_eq!(TypeAnnotation::of_literal(literal), Some(expected));
+        }
+        assert_eq!(TypeAnnotation::of_literal(b""), None);
+    }
+
+    #[test]
+    fn test_type_annotation_node_kind() {
+        assert_eq!(TypeAnnotation::Float.node_kind(), NodeKind::Number);
+        assert_eq!(TypeAnnotation::Array.node_kind(), NodeKind::Array);
+        assert!(TypeAnnotation::LocalDate.is_string_like());
+        assert!(!TypeAnnotation::Int.is_string_like());
+    }
+
+    #[test]
+    fn test_type_annotation_of_datetime() {
+        let cases = [
+            ("1979-05-27T07:32:00Z", Some(TypeAnnotation::Datetime)),
+            (
+                "1979-05-27 07:32:00.999-08:00",
+                Some(TypeAnnotation::Datetime),
+            ),
+            ("1979-05-27T07:32:00", Some(TypeAnnotation::LocalDatetime)),
+            ("1979-05-27", Some(TypeAnnotation::LocalDate)),
+            ("07:32:00.5", Some(TypeAnnotation::LocalTime)),
+            ("07:32:00Z", None),
+            ("1979-13-27", None),
+            ("1979-05-27T", None),
+            ("not a date", None),
+            ("", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(TypeAnnotation::of_datetime(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_type_annotation_check_literal() {
+        let valid: [(TypeAnnotation, &[u8]); 6] = [
+            (TypeAnnotation::Int, b"-3"),
+            (TypeAnnotation::Float, b"2"),
+            (TypeAnnotation::Float, b"\"-inf\""),
+            (TypeAnnotation::LocalDate, b"\"1979-05-27\""),
+            (TypeAnnotation::Object, b"{}"),
+            (TypeAnnotation::Tag("color".to_string()), b"\"red\""),
+        ];
+        for (annotation, literal) in valid {
+            assert!(annotation.check_literal(literal).is_ok(), "{annotation}");
+        }
+
+        let invalid: [(TypeAnnotation, &[u8]); 6] = [
+            (TypeAnnotation::Datetime, b"\"not a date\""),
+            (TypeAnnotation::Datetime, b"\"1979-05-27\""),
+            (TypeAnnotation::Int, b"1.5"),
+            (TypeAnnotation::Float, b"\"1.5\""),
+            (TypeAnnotation::String, b"1"),
+            (TypeAnnotation::Array, b"{}"),
+        ];
+        for (annotation, literal) in invalid {
+            assert!(annotation.check_literal(literal).is_err(), "{annotation}");
+        }
+    }
+
+    #[test]
+    fn test_type_annotation_from_node_kind() {
+        assert_eq!(
+            TypeAnnotation::from_node_kind(NodeKind::Number, b"1"),
+            Some(TypeAnnotation::Int)
+        );
+        assert_eq!(
+            TypeAnnotation::from_node_kind(NodeKind::Number, b"\"nan\""),
+            Some(TypeAnnotation::Float)
+        );
+        assert_eq!(
+            TypeAnnotation::from_node_kind(NodeKind::String, b"\"x\""),
+            Some(TypeAnnotation::String)
+        );
+        assert_eq!(
+            TypeAnnotation::from_node_kind(NodeKind::Comment, b"1"),
+            None
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_type_annotation_from_toml_datetime_kind() {
+        let kind = NodeKind::FormatSpecific(FormatSpecificKind::TomlDatetime);
+        assert_eq!(
+            TypeAnnotation::from_node_kind(kind, b"\"07:32:00\""),
+            Some(TypeAnnotation::LocalTime)
+        );
+        assert_eq!(TypeAnnotation::from_node_kind(kind, b"\"x\""), None);
+        assert_eq!(
+            TypeAnnotation::LocalTime.node_kind(),
+            NodeKind::FormatSpecific(FormatSpecificKind::TomlDatetime)
+        );
+    }
+
+    #[test]
+    fn test_json_builder_ignores_annotations() {
+        let mut builder = JsonBuilder::new();
+        let value = builder.string("1979-05-27");
+        assert_eq!(
+            builder.annotated(value, &TypeAnnotation::LocalDate),
+            serde_json::Value::String("1979-05-27".to_string())
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_builder_restores_datetime() {
+        let mut builder = TomlBuilder::new();
+        let value = builder.string("1979-05-27T07:32:00Z");
+        let value = builder.annotated(value, &TypeAnnotation::Datetime);
+        assert!(matches!(value, toml_crate::Value::Datetime(_)));
+
+        // Unparseable values stay strings
+        let value = builder.string("yesterday");
+        let value = builder.annotated(value, &TypeAnnotation::LocalDate);
+        assert_eq!(value, toml_crate::Value::String("yesterday".to_string()));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_builder_restores_tag() {
+        let mut builder = YamlBuilder::new();
+        let value = builder.string("red");
+        let value = builder.annotated(value, &TypeAnnotation::Tag("color".to_string()));
+        assert_eq!(builder.serialize(&value).unwrap(), "!color red\n");
+    }
 }
 
 // ============================================================================
@@ -767,6 +1260,18 @@ impl ValueBuilder for YamlBuilder {
         matches!(value, serde_yaml::Value::Sequence(_))
     }
 
+    fn annotated(&mut self, value: Self::Output, annotation: &TypeAnnotation) -> Self::Output {
+        match annotation {
+            TypeAnnotation::Tag(tag) => {
+                serde_yaml::Value::Tagged(Box::new(serde_yaml::value::TaggedValue {
+                    tag: serde_yaml::value::Tag::new(tag),
+                    value,
+                }))
+            }
+            _ => value,
+        }
+    }
+
     fn serialize(&self, value: &Self::Output) -> Result<String> {
         serde_yaml::to_string(value).map_err(|e| DsonError::SerializationError(e.to_string()))
     }
@@ -864,6 +1369,21 @@ impl ValueBuilder for TomlBuilder {
         matches!(value, toml_crate::Value::Array(_))
     }
 
+    fn annotated(&mut self, value: Self::Output, annotation: &TypeAnnotation) -> Self::Output {
+        match (annotation, value) {
+            (
+                TypeAnnotation::Datetime
+                | TypeAnnotation::LocalDatetime
+                | TypeAnnotation::LocalDate
+                | TypeAnnotation::LocalTime,
+                toml_crate::Value::String(s),
+            ) => s
+                .parse()
+                .map_or(toml_crate::Value::String(s), toml_crate::Value::Datetime),
+            (_, value) => value,
+        }
+    }
+
     fn serialize(&self, value: &Self::Output) -> Result<String> {
         toml_crate::to_string(value).map_err(|e| DsonError::SerializationError(e.to_string()))
     }
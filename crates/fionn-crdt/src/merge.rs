@@ -11,7 +11,7 @@
 //! Enable with `--features optimized_merge`
 
 use ahash::AHashMap;
-use fionn_core::{MergeStrategy, OperationValue};
+use fionn_core::{MergeStrategy, NumberValue, OperationValue};
 use smallvec::SmallVec;
 use std::hash::{Hash, Hasher};
 
@@ -50,16 +50,15 @@ impl PreParsedValue {
     #[must_use]
     pub fn from_operation_value(value: &OperationValue) -> Self {
         match value {
-            OperationValue::NumberRef(s) => {
-                // Try parsing as integer first (faster), then float
-                s.parse::<i64>().map_or_else(
-                    |_| {
-                        s.parse::<f64>()
-                            .map_or_else(|_| Self::String(s.clone()), Self::Float)
-                    },
-                    Self::Integer,
-                )
-            }
+            // Numbers arrive parsed; only the lexeme of a non-number is copied
+            OperationValue::NumberRef(n) => n.value().map_or_else(
+                || Self::String(n.to_string()),
+                |value| {
+                    value
+                        .as_i64()
+                        .map_or_else(|| Self::Float(value.as_f64()), Self::Integer)
+                },
+            ),
             OperationValue::StringRef(s) => Self::String(s.clone()),
             OperationValue::BoolRef(b) => Self::Boolean(*b),
             OperationValue::Null => Self::Null,
@@ -85,9 +84,9 @@ impl PreParsedValue {
     #[must_use]
     pub fn to_operation_value(&self) -> OperationValue {
         match self {
-            Self::Integer(i) => OperationValue::NumberRef(i.to_string()),
-            Self::Float(f) => OperationValue::NumberRef(f.to_string()),
-            Self::Timestamp(t) => OperationValue::NumberRef(t.to_string()),
+            Self::Integer(i) => OperationValue::NumberRef((*i).into()),
+            Self::Float(f) => OperationValue::NumberRef((*f).into()),
+            Self::Timestamp(t) => OperationValue::NumberRef((*t).into()),
             Self::String(s) => OperationValue::StringRef(s.clone()),
             Self::Boolean(b) => OperationValue::BoolRef(*b),
             Self::Null => OperationValue::Null,
@@ -107,29 +106,11 @@ impl PreParsedValue {
     /// since f64 only has 52 bits of mantissa precision.
     #[inline]
     #[must_use]
-    pub fn as_f64(&self) -> Option<f64> {
+    pub const fn as_f64(&self) -> Option<f64> {
         match self {
-            Self::Integer(i) => {
-                // Use i32 conversion when possible for lossless conversion
-                i32::try_from(*i).map_or_else(
-                    |_| {
-                        // Large integer: use string parsing for explicit conversion
-                        i.to_string().parse::<f64>().ok()
-                    },
-                    |small| Some(f64::from(small)),
-                )
-            }
+            Self::Integer(i) => Some(NumberValue::I64(*i).as_f64()),
             Self::Float(f) => Some(*f),
-            Self::Timestamp(t) => {
-                // Use u32 conversion when possible for lossless conversion
-                u32::try_from(*t).map_or_else(
-                    |_| {
-                        // Large timestamp: use string parsing for explicit conversion
-                        t.to_string().parse::<f64>().ok()
-                    },
-                    |small| Some(f64::from(small)),
-                )
-            }
+            Self::Timestamp(t) => Some(NumberValue::U64(*t).as_f64()),
             _ => None,
         }
     }
@@ -724,7 +705,7 @@ mod tests {
 
     #[test]
     fn test_pre_parsed_value_integer() {
-        let op_val = OperationValue::NumberRef("42".to_string());
+        let op_val = OperationValue::NumberRef("42".into());
         let pre = PreParsedValue::from_operation_value(&op_val);
 
         assert!(matches!(pre, PreParsedValue::Integer(42)));
@@ -734,7 +715,7 @@ mod tests {
 
     #[test]
     fn test_pre_parsed_value_float() {
-        let op_val = OperationValue::NumberRef("3.25".to_string());
+        let op_val = OperationValue::NumberRef("3.25".into());
         let pre = PreParsedValue::from_operation_value(&op_val);
 
         assert!(matches!(pre, PreParsedValue::Float(f) if (f - 3.25).abs() < 0.001));
@@ -755,7 +736,7 @@ mod tests {
         table.add_entry(
             "user.score",
             MergeStrategy::Max,
-            &OperationValue::NumberRef("50".to_string()),
+            &OperationValue::NumberRef("50".into()),
             100,
         );
 
@@ -809,12 +790,12 @@ mod tests {
                 ),
                 (
                     "score".to_string(),
-                    OperationValue::NumberRef("50".to_string()),
+                    OperationValue::NumberRef("50".into()),
                     100,
                 ),
                 (
                     "count".to_string(),
-                    OperationValue::NumberRef("10".to_string()),
+                    OperationValue::NumberRef("10".into()),
                     100,
                 ),
             ]
@@ -827,13 +808,11 @@ mod tests {
         assert_eq!(result.winner, Winner::Remote);
 
         // Test Max merge - remote wins with higher value
-        let result =
-            processor.merge_value("score", &OperationValue::NumberRef("75".to_string()), 100);
+        let result = processor.merge_value("score", &OperationValue::NumberRef("75".into()), 100);
         assert_eq!(result.winner, Winner::Remote);
 
         // Test Additive merge - values are summed
-        let result =
-            processor.merge_value("count", &OperationValue::NumberRef("5".to_string()), 100);
+        let result = processor.merge_value("count", &OperationValue::NumberRef("5".into()), 100);
         assert_eq!(result.winner, Winner::Merged);
         assert!(result.merged_value.is_some());
         if let Some(PreParsedValue::Integer(sum)) = result.merged_value {
@@ -1120,7 +1099,7 @@ mod tests {
         table.add_entry(
             "path",
             MergeStrategy::Max,
-            &OperationValue::NumberRef("10".to_string()),
+            &OperationValue::NumberRef("10".into()),
             200,
         );
         // Duplicate should overwrite
@@ -1224,11 +1203,7 @@ mod tests {
         let mut processor = OptimizedMergeProcessor::new();
         processor.init_local(
             vec![
-                (
-                    "a".to_string(),
-                    OperationValue::NumberRef("10".to_string()),
-                    100,
-                ),
+                ("a".to_string(), OperationValue::NumberRef("10".into()), 100),
                 (
                     "b".to_string(),
                     OperationValue::StringRef("hello".to_string()),
@@ -1240,11 +1215,7 @@ mod tests {
 
         let results = processor.merge_batch(
             vec![
-                (
-                    "a".to_string(),
-                    OperationValue::NumberRef("20".to_string()),
-                    200,
-                ),
+                ("a".to_string(), OperationValue::NumberRef("20".into()), 200),
                 (
                     "b".to_string(),
                     OperationValue::StringRef("world".to_string()),
@@ -1262,20 +1233,11 @@ mod tests {
     fn test_optimized_merge_processor_merge_batch_parallel() {
         let mut processor = OptimizedMergeProcessor::new();
         processor.init_local(
-            vec![(
-                "x".to_string(),
-                OperationValue::NumberRef("5".to_string()),
-                100,
-            )]
-            .into_iter(),
+            vec![("x".to_string(), OperationValue::NumberRef("5".into()), 100)].into_iter(),
         );
 
         let entries = vec![
-            (
-                "x".to_string(),
-                OperationValue::NumberRef("10".to_string()),
-                200,
-            ),
+            ("x".to_string(), OperationValue::NumberRef("10".into()), 200),
             (
                 "y".to_string(),
                 OperationValue::StringRef("test".to_string()),
@@ -1294,15 +1256,14 @@ mod tests {
         processor.init_local(
             vec![(
                 "val".to_string(),
-                OperationValue::NumberRef("100".to_string()),
+                OperationValue::NumberRef("100".into()),
                 100,
             )]
             .into_iter(),
         );
 
         // Remote has smaller value
-        let result =
-            processor.merge_value("val", &OperationValue::NumberRef("50".to_string()), 100);
+        let result = processor.merge_value("val", &OperationValue::NumberRef("50".into()), 100);
         assert_eq!(result.winner, Winner::Remote);
 
         // Remote has larger value
@@ -1311,14 +1272,13 @@ mod tests {
         processor2.init_local(
             vec![(
                 "val".to_string(),
-                OperationValue::NumberRef("10".to_string()),
+                OperationValue::NumberRef("10".into()),
                 100,
             )]
             .into_iter(),
         );
 
-        let result =
-            processor2.merge_value("val", &OperationValue::NumberRef("50".to_string()), 100);
+        let result = processor2.merge_value("val", &OperationValue::NumberRef("50".into()), 100);
         assert_eq!(result.winner, Winner::Local);
     }
 
@@ -1329,14 +1289,13 @@ mod tests {
         processor.init_local(
             vec![(
                 "val".to_string(),
-                OperationValue::NumberRef("3.25".to_string()),
+                OperationValue::NumberRef("3.25".into()),
                 100,
             )]
             .into_iter(),
         );
 
-        let result =
-            processor.merge_value("val", &OperationValue::NumberRef("1.5".to_string()), 100);
+        let result = processor.merge_value("val", &OperationValue::NumberRef("1.5".into()), 100);
         assert_eq!(result.winner, Winner::Remote);
     }
 
@@ -1347,14 +1306,13 @@ mod tests {
         processor.init_local(
             vec![(
                 "val".to_string(),
-                OperationValue::NumberRef("3.25".to_string()),
+                OperationValue::NumberRef("3.25".into()),
                 100,
             )]
             .into_iter(),
         );
 
-        let result =
-            processor.merge_value("val", &OperationValue::NumberRef("5.5".to_string()), 100);
+        let result = processor.merge_value("val", &OperationValue::NumberRef("5.5".into()), 100);
         assert_eq!(result.winner, Winner::Remote);
     }
 
@@ -1365,14 +1323,13 @@ mod tests {
         processor.init_local(
             vec![(
                 "val".to_string(),
-                OperationValue::NumberRef("1.5".to_string()),
+                OperationValue::NumberRef("1.5".into()),
                 100,
             )]
             .into_iter(),
         );
 
-        let result =
-            processor.merge_value("val", &OperationValue::NumberRef("2.5".to_string()), 100);
+        let result = processor.merge_value("val", &OperationValue::NumberRef("2.5".into()), 100);
         assert_eq!(result.winner, Winner::Merged);
         if let Some(PreParsedValue::Float(sum)) = result.merged_value {
             assert!((sum - 4.0).abs() < 0.001);
@@ -1407,8 +1364,7 @@ mod tests {
         processor
             .init_local(vec![("val".to_string(), OperationValue::BoolRef(true), 100)].into_iter());
 
-        let result =
-            processor.merge_value("val", &OperationValue::NumberRef("10".to_string()), 200);
+        let result = processor.merge_value("val", &OperationValue::NumberRef("10".into()), 200);
         // Falls back to LWW for non-matching types
         assert_eq!(result.winner, Winner::Remote);
     }
@@ -1595,7 +1551,7 @@ mod tests {
 
     #[test]
     fn test_pre_parsed_value_invalid_number() {
-        let op_val = OperationValue::NumberRef("not_a_number".to_string());
+        let op_val = OperationValue::NumberRef("not_a_number".into());
         let pre = PreParsedValue::from_operation_value(&op_val);
         // Should fall back to String
         assert!(matches!(pre, PreParsedValue::String(_)));
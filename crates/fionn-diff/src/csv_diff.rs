@@ -33,6 +33,7 @@ use serde_json::{Map, Value};
 use std::collections::{HashMap, HashSet};
 
 /// CSV diff options
+#[allow(clippy::struct_excessive_bools)] // Independent comparison toggles
 #[derive(Debug, Clone)]
 pub struct CsvDiffOptions {
     /// How to identify rows for comparison
@@ -45,6 +46,12 @@ pub struct CsvDiffOptions {
     pub empty_is_null: bool,
     /// Key column for key-based identity
     pub key_column: Option<String>,
+    /// Cell values treated as null (e.g. `NULL`, `\N`), matching the CSV dialect
+    pub null_tokens: HashSet<String>,
+    /// Ignore leading and trailing whitespace in cell values
+    pub trim_values: bool,
+    /// Compare numeric cells by value, so `2`, `2.0` and `2e0` are equal
+    pub numeric_compare: bool,
 }
 
 impl Default for CsvDiffOptions {
@@ -55,6 +62,9 @@ impl Default for CsvDiffOptions {
             ignore_columns: HashSet::new(),
             empty_is_null: false,
             key_column: None,
+            null_tokens: HashSet::new(),
+            trim_values: false,
+            numeric_compare: false,
         }
     }
 }
@@ -69,6 +79,40 @@ impl CsvDiffOptions {
         }
     }
 
+    /// Treat the given tokens as null
+    #[must_use]
+    pub fn with_null_tokens<I, S>(mut self, tokens: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_tokens = tokens.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Normalize a cell value for comparison
+    ///
+    /// Returns `None` for cells that read as null under these options.
+    fn normalize(&self, value: Option<&str>) -> Option<String> {
+        let value = value?;
+        let value = if self.trim_values {
+            value.trim()
+        } else {
+            value
+        };
+        if (self.empty_is_null && value.is_empty()) || self.null_tokens.contains(value) {
+            return None;
+        }
+        if self.numeric_compare
+            && let Ok(number) = value.parse::<f64>()
+            && number.is_finite()
+        {
+            // Canonical form: -0.0 and 0.0 compare equal
+            return Some((number + 0.0).to_string());
+        }
+        Some(value.to_string())
+    }
+
     /// Create options for content-addressed diff
     #[must_use]
     pub fn content_addressed() -> Self {
@@ -222,6 +266,10 @@ pub fn csv_diff(source: &Value, target: &Value, options: &CsvDiffOptions) -> Csv
 }
 
 /// Compute diff between two `TapeSource` CSVs
+///
+/// Cells are compared as in [`csv_diff`]: set `null_tokens`, `trim_values`
+/// and `numeric_compare` from the dialect the tapes were read with, so typed
+/// or null cells in one tape match their text form in the other.
 pub fn csv_diff_tapes<S: TapeSource, T: TapeSource>(
     source: &S,
     target: &T,
@@ -247,18 +295,7 @@ fn extract_csv_rows(value: &Value) -> Vec<HashMap<String, String>> {
         if let Some(rows_arr) = csv.get("rows").and_then(|r| r.as_array()) {
             for row in rows_arr {
                 if let Some(obj) = row.as_object() {
-                    let row_map: HashMap<String, String> = obj
-                        .iter()
-                        .map(|(k, v)| {
-                            let val = match v {
-                                Value::String(s) => s.clone(),
-                                Value::Null => String::new(),
-                                other => other.to_string(),
-                            };
-                            (k.clone(), val)
-                        })
-                        .collect();
-                    rows.push(row_map);
+                    rows.push(row_cells(obj));
                 }
             }
         }
@@ -267,18 +304,7 @@ fn extract_csv_rows(value: &Value) -> Vec<HashMap<String, String>> {
     else if let Some(arr) = value.as_array() {
         for row in arr {
             if let Some(obj) = row.as_object() {
-                let row_map: HashMap<String, String> = obj
-                    .iter()
-                    .map(|(k, v)| {
-                        let val = match v {
-                            Value::String(s) => s.clone(),
-                            Value::Null => String::new(),
-                            other => other.to_string(),
-                        };
-                        (k.clone(), val)
-                    })
-                    .collect();
-                rows.push(row_map);
+                rows.push(row_cells(obj));
             }
         }
     }
@@ -286,6 +312,23 @@ fn extract_csv_rows(value: &Value) -> Vec<HashMap<String, String>> {
     rows
 }
 
+/// Cell text of a row
+///
+/// Null cells (a dialect's null tokens once parsed) are left out, so they
+/// compare like missing cells and like null tokens still in text form.
+fn row_cells(obj: &Map<String, Value>) -> HashMap<String, String> {
+    obj.iter()
+        .filter_map(|(k, v)| {
+            let val = match v {
+                Value::String(s) => s.clone(),
+                Value::Null => return None,
+                other => other.to_string(),
+            };
+            Some((k.clone(), val))
+        })
+        .collect()
+}
+
 fn tape_to_csv_value<T: TapeSource>(tape: &T) -> Value {
     // Extract CSV structure from tape
     // This handles the csv.rows[N].col format
@@ -565,19 +608,7 @@ fn diff_row(
         let src_val = source.get(col).map(String::as_str);
         let tgt_val = target.get(col).map(String::as_str);
 
-        // Normalize empty strings if option set
-        let src_normalized = if options.empty_is_null {
-            src_val.filter(|s| !s.is_empty())
-        } else {
-            src_val
-        };
-        let tgt_normalized = if options.empty_is_null {
-            tgt_val.filter(|s| !s.is_empty())
-        } else {
-            tgt_val
-        };
-
-        if src_normalized != tgt_normalized {
+        if options.normalize(src_val) != options.normalize(tgt_val) {
             changes.push(CellChange {
                 column: col.to_string(),
                 old_value: src_val.map(std::borrow::ToOwned::to_owned),
@@ -604,14 +635,10 @@ fn hash_row(row: &HashMap<String, String>, options: &CsvDiffOptions) -> u64 {
     keys.sort_unstable();
 
     for key in keys {
-        key.hash(&mut hasher);
-        if let Some(val) = row.get(key) {
-            let normalized = if options.empty_is_null && val.is_empty() {
-                ""
-            } else {
-                val.as_str()
-            };
-            normalized.hash(&mut hasher);
+        // Null cells hash like missing ones
+        if let Some(value) = options.normalize(row.get(key).map(String::as_str)) {
+            key.hash(&mut hasher);
+            value.hash(&mut hasher);
         }
     }
 
@@ -724,6 +751,53 @@ mod tests {
         assert!(diff2.is_empty());
     }
 
+    #[test]
+    fn test_csv_diff_dialect_normalization() {
+        let source = json!([{"id": "1", "qty": "2", "note": "NULL", "name": " Alice "}]);
+        let target = json!([{"id": 1, "qty": 2.0, "note": null, "name": "Alice"}]);
+
+        assert!(!csv_diff(&source, &target, &CsvDiffOptions::default()).is_empty());
+
+        let options = CsvDiffOptions {
+            empty_is_null: true,
+            trim_values: true,
+            numeric_compare: true,
+            ..Default::default()
+        }
+        .with_null_tokens(["NULL"]);
+        assert!(csv_diff(&source, &target, &options).is_empty());
+        assert!(
+            csv_diff(
+                &source,
+                &target,
+                &CsvDiffOptions {
+                    row_identity: RowIdentityMode::ContentAddressed,
+                    ..options
+                }
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_csv_diff_tapes_dialect_normalization() {
+        use fionn_tape::DsonTape;
+
+        let source = DsonTape::parse(r#"[{"id":1,"qty":2,"note":null,"name":"Alice"}]"#).unwrap();
+        let target =
+            DsonTape::parse(r#"[{"id":"1","qty":"2.0","note":"NULL","name":" Alice "}]"#).unwrap();
+
+        assert!(!csv_diff_tapes(&source, &target, &CsvDiffOptions::default()).is_empty());
+
+        let options = CsvDiffOptions {
+            trim_values: true,
+            numeric_compare: true,
+            ..Default::default()
+        }
+        .with_null_tokens(["NULL"]);
+        assert!(csv_diff_tapes(&source, &target, &options).is_empty());
+    }
+
     #[test]
     fn test_csv_diff_ignore_columns() {
         let source = json!([{"id": "1", "name": "Alice", "updated": "2024-01-01"}]);
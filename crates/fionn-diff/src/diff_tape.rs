@@ -294,15 +294,15 @@ fn diff_at_path<S: TapeSource, T: TapeSource>(
                         )?;
                     }
                 }
-                TapeNodeKind::Value | TapeNodeKind::Key => {
+                TapeNodeKind::Value | TapeNodeKind::Key
+                    if !values_equal(source, src_idx, target, tgt_idx) =>
+                {
                     // Compare scalar values
-                    if !values_equal(source, src_idx, target, tgt_idx) {
-                        let value = extract_value(target, tgt_idx)?;
-                        diff.push(TapeDiffOp::Replace {
-                            path: path.to_string(),
-                            value,
-                        });
-                    }
+                    let value = extract_value(target, tgt_idx)?;
+                    diff.push(TapeDiffOp::Replace {
+                        path: path.to_string(),
+                        value,
+                    });
                 }
                 _ => {
                     // ObjectEnd, ArrayEnd - should not be diffed directly
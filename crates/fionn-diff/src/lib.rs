@@ -24,6 +24,10 @@
 //! - `null` values indicate deletion
 //! - Other values replace existing ones
 //!
+//! ## Format-Preserving Patching
+//! Apply either patch kind, or DSON operations, to JSONC, YAML or TOML source
+//! text, rewriting only the touched spans so comments and layout survive.
+//!
 //! ## Performance
 //!
 //! Uses SIMD acceleration for:
@@ -37,6 +41,7 @@ mod diff_tape;
 mod diff_zerocopy;
 mod merge;
 mod patch;
+mod preserve;
 mod simd_compare;
 mod tape_merge;
 mod tape_patch;
@@ -52,8 +57,13 @@ pub use diff_tape::{
 pub use diff_zerocopy::{JsonPatchRef, PatchOperationRef, json_diff_zerocopy};
 pub use merge::{deep_merge, json_merge_patch, merge_many, merge_patch_to_value};
 pub use patch::{JsonPatch, PatchError, PatchOperation, apply_patch, apply_patch_mut};
+pub use preserve::{
+    FormatPreservingDocument, PreserveError, PreserveFormat, apply_patch_preserving,
+    merge_patch_preserving,
+};
 pub use simd_compare::{
-    json_numbers_equal, json_strings_equal, simd_bytes_equal, simd_find_first_difference,
+    json_numbers_equal, json_strings_equal, simd_bytes_equal, simd_bytes_equal_with_level,
+    simd_find_first_difference, simd_find_first_difference_with_level,
 };
 pub use tape_merge::{
     StreamingMergeOptions, deep_merge_tape_into_value, deep_merge_tapes, merge_many_tapes,
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! JSON / JSONC span index.
+//!
+//! Accepts `//` and `/* */` comments and trailing commas.
+
+use super::{Entry, Layout, Node, NodeKind, PreserveError};
+use serde_json::Value;
+
+/// Parse JSONC source to a value
+pub fn parse_value(source: &str) -> Result<Value, PreserveError> {
+    serde_json::from_str(&strip(source)).map_err(|e| PreserveError::Syntax(e.to_string()))
+}
+
+/// Render a value as compact JSON
+pub fn render(value: &Value) -> String {
+    value.to_string()
+}
+
+/// Render an object key
+pub fn render_key(key: &str) -> String {
+    Value::String(key.to_string()).to_string()
+}
+
+/// Build the span index for a JSONC document
+pub fn index(source: &str) -> Result<Node, PreserveError> {
+    let mut parser = Parser {
+        bytes: source.as_bytes(),
+        pos: 0,
+    };
+    parser.skip_trivia();
+    let node = parser.value()?;
+    parser.skip_trivia();
+    if parser.pos < parser.bytes.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(node)
+}
+
+/// Blank out comments and drop trailing commas, keeping byte offsets of
+/// everything else
+fn strip(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let end = string_end(bytes, i).unwrap_or(bytes.len());
+                out.extend_from_slice(&bytes[i..end]);
+                i = end;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    out.push(b' ');
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let end = source[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |p| i + 2 + p + 2);
+                out.extend(
+                    bytes[i..end]
+                        .iter()
+                        .map(|&b| if b == b'\n' { b'\n' } else { b' ' }),
+                );
+                i = end;
+            }
+            b',' if next_significant(bytes, i + 1).is_some_and(|b| b == b'}' || b == b']') => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// Next byte that is not whitespace or inside a comment
+fn next_significant(bytes: &[u8], mut i: usize) -> Option<u8> {
+    loop {
+        match bytes.get(i)? {
+            b if b.is_ascii_whitespace() => i += 1,
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            &b => return Some(b),
+        }
+    }
+}
+
+/// Offset just past the closing quote of the string starting at `start`
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: &str) -> PreserveError {
+        PreserveError::Syntax(format!("{msg} at byte {}", self.pos))
+    }
+
+    fn skip_trivia(&mut self) {
+        while self.pos < self.bytes.len() {
+            match self.bytes[self.pos] {
+                b if b.is_ascii_whitespace() => self.pos += 1,
+                b'/' if self.bytes.get(self.pos + 1) == Some(&b'/') => {
+                    while self.pos < self.bytes.len() && self.bytes[self.pos] != b'\n' {
+                        self.pos += 1;
+                    }
+                }
+                b'/' if self.bytes.get(self.pos + 1) == Some(&b'*') => {
+                    self.pos += 2;
+                    while self.pos + 1 < self.bytes.len()
+                        && !(self.bytes[self.pos] == b'*' && self.bytes[self.pos + 1] == b'/')
+                    {
+                        self.pos += 1;
+                    }
+                    self.pos = (self.pos + 2).min(self.bytes.len());
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Node, PreserveError> {
+        match self.bytes.get(self.pos) {
+            Some(b'{') => self.container(b'}', NodeKind::Map),
+            Some(b'[') => self.container(b']', NodeKind::Seq),
+            Some(b'"') => {
+                let start = self.pos;
+                self.pos = string_end(self.bytes, start)
+                    .ok_or_else(|| self.error("unterminated string"))?;
+                Ok(Node::leaf(start..self.pos))
+            }
+            Some(_) => {
+                let start = self.pos;
+                while self.pos < self.bytes.len()
+                    && !matches!(self.bytes[self.pos], b',' | b'}' | b']' | b'/' | b':')
+                    && !self.bytes[self.pos].is_ascii_whitespace()
+                {
+                    self.pos += 1;
+                }
+                if start == self.pos {
+                    return Err(self.error("expected value"));
+                }
+                Ok(Node::leaf(start..self.pos))
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn container(&mut self, close: u8, kind: NodeKind) -> Result<Node, PreserveError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut entries = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.bytes.get(self.pos) {
+                Some(&b) if b == close => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b',') if !entries.is_empty() => {
+                    self.pos += 1;
+                    continue;
+                }
+                None => return Err(self.error("unterminated container")),
+                _ => {}
+            }
+
+            let entry_start = self.pos;
+            let key = if kind == NodeKind::Map {
+                let key_node = self.value()?;
+                let raw = &self.bytes[key_node.span];
+                let key: String =
+                    serde_json::from_slice(raw).map_err(|_| self.error("expected string key"))?;
+                self.skip_trivia();
+                if self.bytes.get(self.pos) != Some(&b':') {
+                    return Err(self.error("expected ':'"));
+                }
+                self.pos += 1;
+                self.skip_trivia();
+                Some(key)
+            } else {
+                None
+            };
+            let node = self.value()?;
+            entries.push(Entry {
+                key,
+                range: entry_start..node.span.end,
+                node,
+            });
+        }
+        Ok(Node {
+            span: start..self.pos,
+            kind,
+            layout: Layout::Flow,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_spans() {
+        let source = "{ \"a\": [1, /* two */ 2,], // tail\n \"b\": {} }";
+        let node = index(source).unwrap();
+        assert_eq!(node.kind, NodeKind::Map);
+        assert_eq!(node.entries.len(), 2);
+        let array = &node.entries[0].node;
+        assert_eq!(array.entries.len(), 2);
+        assert_eq!(&source[array.entries[1].node.span.clone()], "2");
+        assert_eq!(&source[node.entries[1].range.clone()], "\"b\": {}");
+    }
+
+    #[test]
+    fn test_parse_value_strips_comments_and_trailing_commas() {
+        let value = parse_value("{\"a\": \"// not a comment\", /* c */ \"b\": [1,],}").unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"a": "// not a comment", "b": [1]})
+        );
+    }
+}
@@ -0,0 +1,960 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Format-preserving patching.
+//!
+//! Applies JSON Patch (RFC 6902) and JSON Merge Patch (RFC 7396) to JSONC,
+//! YAML and TOML source text by rewriting only the byte spans an operation
+//! touches. Comments, key order, quoting style and blank lines elsewhere in
+//! the file stay byte-identical.
+//!
+//! Each operation is first applied to the parsed value to validate it and to
+//! obtain the expected result. The text is then edited through a lightweight
+//! span index, re-parsed and compared against that result. Edits the span
+//! editor cannot express without reformatting surrounding content are
+//! rejected with [`PreserveError::Unsupported`] instead of silently rewriting
+//! the file.
+
+mod jsonc;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+use crate::patch::{JsonPatch, PatchError, PatchOperation, apply_patch};
+use fionn_core::{DsonOperation, OperationValue, PathStyle};
+use serde_json::Value;
+use std::fmt;
+use std::ops::Range;
+
+/// Error type for format-preserving patching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreserveError {
+    /// The operation is invalid for the document.
+    Patch(PatchError),
+    /// Source text could not be parsed.
+    Syntax(String),
+    /// Edit cannot be applied without reformatting.
+    Unsupported(String),
+}
+
+impl fmt::Display for PreserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Patch(err) => err.fmt(f),
+            Self::Syntax(msg) => write!(f, "syntax error: {msg}"),
+            Self::Unsupported(msg) => write!(f, "unsupported edit: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for PreserveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Patch(err) => Some(err),
+            Self::Syntax(_) | Self::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<PatchError> for PreserveError {
+    fn from(err: PatchError) -> Self {
+        Self::Patch(err)
+    }
+}
+
+/// Source format for format-preserving patching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreserveFormat {
+    /// JSON, including JSONC comments and trailing commas
+    Json,
+    /// YAML block and flow documents
+    #[cfg(feature = "yaml")]
+    Yaml,
+    /// TOML documents
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+/// A source document that can be patched without reformatting
+///
+/// # Example
+///
+/// ```
+/// use fionn_diff::{FormatPreservingDocument, JsonPatch, PatchOperation, PreserveFormat};
+///
+/// let source = "{\n  // release version\n  \"version\": \"1.2.0\"\n}\n";
+/// let mut doc = FormatPreservingDocument::parse(source, PreserveFormat::Json).unwrap();
+/// let patch = JsonPatch::from_operations(vec![PatchOperation::Replace {
+///     path: "/version".to_string(),
+///     value: "1.3.0".into(),
+/// }]);
+/// doc.apply_patch(&patch).unwrap();
+/// assert_eq!(doc.as_str(), "{\n  // release version\n  \"version\": \"1.3.0\"\n}\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormatPreservingDocument {
+    source: String,
+    format: PreserveFormat,
+    value: Value,
+}
+
+impl FormatPreservingDocument {
+    /// Parse a source document
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreserveError::Syntax`] if the source is not valid in `format`.
+    pub fn parse(source: impl Into<String>, format: PreserveFormat) -> Result<Self, PreserveError> {
+        let source = source.into();
+        let value = format.parse_value(&source)?;
+        // Fail early on constructs the span index does not understand
+        format.index(&source)?;
+        Ok(Self {
+            source,
+            format,
+            value,
+        })
+    }
+
+    /// Source format of this document
+    #[must_use]
+    pub const fn format(&self) -> PreserveFormat {
+        self.format
+    }
+
+    /// Current source text
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Consume the document, returning the source text
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.source
+    }
+
+    /// Current parsed value
+    #[must_use]
+    pub const fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Apply a JSON Patch, editing only the affected spans
+    ///
+    /// Operations are applied in order. On error the document keeps the
+    /// edits of all operations before the failing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the patch error for invalid operations, or
+    /// [`PreserveError::Unsupported`] if an edit would need reformatting.
+    pub fn apply_patch(&mut self, patch: &JsonPatch) -> Result<(), PreserveError> {
+        for op in patch {
+            self.apply_operation(op)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a JSON Merge Patch, editing only the affected spans
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreserveError::Unsupported`] if an edit would need reformatting.
+    pub fn apply_merge_patch(&mut self, patch: &Value) -> Result<(), PreserveError> {
+        let mut operations = Vec::new();
+        merge_patch_operations(&self.value, patch, String::new(), &mut operations);
+        self.apply_patch(&JsonPatch::from_operations(operations))
+    }
+
+    /// Apply DSON operations, editing only the affected spans
+    ///
+    /// Paths use the dotted operation syntax (`a.b[0]`). `ObjectStart` and
+    /// `ArrayStart` create an empty container only if the path is missing;
+    /// `ObjectEnd` and `ArrayEnd` are no-ops. On error the document keeps the
+    /// edits of all operations before the failing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreserveError::Unsupported`] for tape-reference values or
+    /// edits that would need reformatting, and the patch error for invalid
+    /// operations.
+    pub fn apply_operations(&mut self, operations: &[DsonOperation]) -> Result<(), PreserveError> {
+        for operation in operations {
+            match operation {
+                DsonOperation::ObjectStart { path } | DsonOperation::ArrayStart { path } => {
+                    let pointer = operation_pointer(path)?;
+                    if self.value.pointer(&pointer).is_none() {
+                        let empty = if matches!(operation, DsonOperation::ObjectStart { .. }) {
+                            Value::Object(serde_json::Map::new())
+                        } else {
+                            Value::Array(Vec::new())
+                        };
+                        self.set(&pointer, empty)?;
+                    }
+                }
+                DsonOperation::ObjectEnd { .. } | DsonOperation::ArrayEnd { .. } => {}
+                DsonOperation::FieldAdd { path, value } => {
+                    self.set(&operation_pointer(path)?, operation_value(value)?)?;
+                }
+                DsonOperation::FieldModify {
+                    path, new_value, ..
+                } => self.apply_operation(&PatchOperation::Replace {
+                    path: operation_pointer(path)?,
+                    value: operation_value(new_value)?,
+                })?,
+                DsonOperation::FieldDelete { path } => self.remove(&operation_pointer(path)?)?,
+                DsonOperation::BatchExecute { operations } => self.apply_operations(operations)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the value at a JSON Pointer, adding the member if it is missing
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parent does not exist or the edit is unsupported.
+    pub fn set(&mut self, path: &str, value: Value) -> Result<(), PreserveError> {
+        self.apply_operation(&PatchOperation::Add {
+            path: path.to_string(),
+            value,
+        })
+    }
+
+    /// Remove the value at a JSON Pointer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path does not exist or the edit is unsupported.
+    pub fn remove(&mut self, path: &str) -> Result<(), PreserveError> {
+        self.apply_operation(&PatchOperation::Remove {
+            path: path.to_string(),
+        })
+    }
+
+    fn apply_operation(&mut self, op: &PatchOperation) -> Result<(), PreserveError> {
+        let expected = apply_patch(&self.value, &JsonPatch::from_operations(vec![op.clone()]))?;
+        let mut editor = Editor {
+            format: self.format,
+            source: self.source.clone(),
+            expected: &expected,
+        };
+
+        match op {
+            PatchOperation::Add { path, .. } | PatchOperation::Copy { path, .. } => {
+                editor.add(&parse_pointer(path)?, &self.value)?;
+            }
+            PatchOperation::Replace { path, value } => {
+                let segments = parse_pointer(path)?;
+                editor.replace(&segments, pointer_get(&self.value, &segments), value)?;
+            }
+            PatchOperation::Remove { path } => editor.remove(&parse_pointer(path)?)?,
+            PatchOperation::Move { from, path } => {
+                if from != path {
+                    editor.remove(&parse_pointer(from)?)?;
+                    let mut after_remove = self.value.clone();
+                    crate::patch::apply_patch_mut(
+                        &mut after_remove,
+                        &JsonPatch::from_operations(vec![PatchOperation::Remove {
+                            path: from.clone(),
+                        }]),
+                    )?;
+                    editor.add(&parse_pointer(path)?, &after_remove)?;
+                }
+            }
+            PatchOperation::Test { .. } => {}
+        }
+
+        let actual = self.format.parse_value(&editor.source)?;
+        if actual != expected {
+            return Err(PreserveError::Unsupported(format!(
+                "{} cannot be applied without reformatting",
+                operation_path(op)
+            )));
+        }
+        self.source = editor.source;
+        self.value = expected;
+        Ok(())
+    }
+}
+
+/// Apply a JSON Patch to source text, preserving formatting
+///
+/// # Errors
+///
+/// See [`FormatPreservingDocument::apply_patch`].
+pub fn apply_patch_preserving(
+    source: &str,
+    format: PreserveFormat,
+    patch: &JsonPatch,
+) -> Result<String, PreserveError> {
+    let mut doc = FormatPreservingDocument::parse(source, format)?;
+    doc.apply_patch(patch)?;
+    Ok(doc.into_string())
+}
+
+/// Apply a JSON Merge Patch to source text, preserving formatting
+///
+/// # Errors
+///
+/// See [`FormatPreservingDocument::apply_merge_patch`].
+pub fn merge_patch_preserving(
+    source: &str,
+    format: PreserveFormat,
+    patch: &Value,
+) -> Result<String, PreserveError> {
+    let mut doc = FormatPreservingDocument::parse(source, format)?;
+    doc.apply_merge_patch(patch)?;
+    Ok(doc.into_string())
+}
+
+// =============================================================================
+// Span Index
+// =============================================================================
+
+/// Indexed value in the source text
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// Byte range of the value text
+    pub span: Range<usize>,
+    /// Container kind
+    pub kind: NodeKind,
+    /// How members are laid out
+    pub layout: Layout,
+    /// Members (empty for leaves)
+    pub entries: Vec<Entry>,
+}
+
+impl Node {
+    pub const fn leaf(span: Range<usize>) -> Self {
+        Self {
+            span,
+            kind: NodeKind::Leaf,
+            layout: Layout::Leaf,
+            entries: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    /// Scalar, or a value edited only as a whole
+    Leaf,
+    /// Mapping / object / table
+    Map,
+    /// Sequence / array
+    Seq,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Edited only as a whole
+    Leaf,
+    /// Bracketed with comma separators
+    Flow,
+    /// One member per line
+    #[cfg_attr(not(any(feature = "yaml", feature = "toml")), allow(dead_code))]
+    Block {
+        /// Byte offset where a new member is appended
+        insert_at: usize,
+        /// Indentation of member lines
+        indent: String,
+        /// Key prefix for members (TOML dotted keys)
+        prefix: String,
+    },
+}
+
+/// Member of a container
+#[derive(Debug, Clone)]
+pub struct Entry {
+    /// Key for map members
+    pub key: Option<String>,
+    /// Byte range removed when the member is deleted
+    pub range: Range<usize>,
+    /// Member value
+    pub node: Node,
+}
+
+/// Format-specific indexing and rendering
+impl PreserveFormat {
+    fn parse_value(self, source: &str) -> Result<Value, PreserveError> {
+        match self {
+            Self::Json => jsonc::parse_value(source),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => yaml::parse_value(source),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::parse_value(source),
+        }
+    }
+
+    fn index(self, source: &str) -> Result<Node, PreserveError> {
+        match self {
+            Self::Json => jsonc::index(source),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => yaml::index(source),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::index(source),
+        }
+    }
+
+    /// Render a value inline, keeping the quoting style of `original`
+    #[cfg_attr(not(any(feature = "yaml", feature = "toml")), allow(unused_variables))]
+    #[cfg_attr(not(feature = "toml"), allow(clippy::unnecessary_wraps))]
+    fn render(self, value: &Value, original: Option<&str>) -> Result<String, PreserveError> {
+        match self {
+            Self::Json => Ok(jsonc::render(value)),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => Ok(yaml::render(value, original)),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::render(value, original),
+        }
+    }
+
+    /// Render a member of a bracketed container
+    fn flow_member(self, key: Option<&str>, rendered: &str) -> String {
+        let Some(key) = key else {
+            return rendered.to_string();
+        };
+        match self {
+            Self::Json => format!("{}: {rendered}", jsonc::render_key(key)),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => format!("{}: {rendered}", yaml::render_key(key)),
+            #[cfg(feature = "toml")]
+            Self::Toml => format!("{} = {rendered}", toml::render_key(key)),
+        }
+    }
+
+    /// Render a line-oriented member, without the trailing newline
+    #[cfg_attr(
+        not(any(feature = "yaml", feature = "toml")),
+        allow(clippy::unused_self, clippy::missing_const_for_fn)
+    )]
+    #[cfg_attr(not(feature = "toml"), allow(unused_variables))]
+    fn block_member(
+        self,
+        indent: &str,
+        prefix: &str,
+        key: Option<&str>,
+        rendered: &str,
+    ) -> Option<String> {
+        match (self, key) {
+            #[cfg(feature = "yaml")]
+            (Self::Yaml, Some(key)) => {
+                Some(format!("{indent}{}: {rendered}", yaml::render_key(key)))
+            }
+            #[cfg(feature = "yaml")]
+            (Self::Yaml, None) => Some(format!("{indent}- {rendered}")),
+            #[cfg(feature = "toml")]
+            (Self::Toml, Some(key)) => Some(format!(
+                "{indent}{prefix}{} = {rendered}",
+                toml::render_key(key)
+            )),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Editor
+// =============================================================================
+
+/// Applies the span edits for one patch operation
+struct Editor<'a> {
+    format: PreserveFormat,
+    source: String,
+    /// Document value after the operation
+    expected: &'a Value,
+}
+
+/// Result of walking a path through the span index
+struct Located {
+    node: Node,
+    /// Number of path segments matched
+    depth: usize,
+    /// Members of the parent container and the member index of `node`
+    parent: Option<(Node, usize)>,
+}
+
+impl Editor<'_> {
+    fn locate(&self, segments: &[String]) -> Result<Located, PreserveError> {
+        let mut node = self.format.index(&self.source)?;
+        let mut parent = None;
+        for (depth, segment) in segments.iter().enumerate() {
+            let position = match node.kind {
+                NodeKind::Map => node
+                    .entries
+                    .iter()
+                    .position(|e| e.key.as_deref() == Some(segment.as_str())),
+                NodeKind::Seq => segment
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&i| i < node.entries.len()),
+                NodeKind::Leaf => None,
+            };
+            let Some(position) = position else {
+                return Ok(Located {
+                    node,
+                    depth,
+                    parent,
+                });
+            };
+            let child = node.entries[position].node.clone();
+            parent = Some((node, position));
+            node = child;
+        }
+        Ok(Located {
+            node,
+            depth: segments.len(),
+            parent,
+        })
+    }
+
+    fn splice(&mut self, range: Range<usize>, text: &str) {
+        self.source.replace_range(range, text);
+    }
+
+    /// Replace the value at `segments`, descending into containers so only
+    /// changed members are rewritten
+    fn replace(
+        &mut self,
+        segments: &[String],
+        old: Option<&Value>,
+        new: &Value,
+    ) -> Result<(), PreserveError> {
+        match (old, new) {
+            (Some(old), new) if old == new => Ok(()),
+            (Some(Value::Object(old)), Value::Object(new)) => {
+                for key in old.keys().filter(|k| !new.contains_key(*k)) {
+                    self.remove(&child_path(segments, key))?;
+                }
+                for (key, value) in new {
+                    let path = child_path(segments, key);
+                    match old.get(key) {
+                        Some(previous) => self.replace(&path, Some(previous), value)?,
+                        None => self.insert(&path, value)?,
+                    }
+                }
+                Ok(())
+            }
+            (Some(Value::Array(old)), Value::Array(new)) if old.len() == new.len() => {
+                for (i, (previous, value)) in old.iter().zip(new).enumerate() {
+                    self.replace(&child_path(segments, &i.to_string()), Some(previous), value)?;
+                }
+                Ok(())
+            }
+            _ => {
+                let located = self.locate(segments)?;
+                if located.depth < segments.len() {
+                    return self.rerender(segments, &located);
+                }
+                let original = &self.source[located.node.span.clone()];
+                let rendered = self.format.render(new, Some(original))?;
+                self.splice(located.node.span, &rendered);
+                Ok(())
+            }
+        }
+    }
+
+    /// Handle an `add`: replace if the member exists in a map, else insert
+    fn add(&mut self, segments: &[String], before: &Value) -> Result<(), PreserveError> {
+        let mut segments = segments.to_vec();
+        if let Some((last, parent)) = segments.split_last_mut()
+            && last == "-"
+            && let Some(Value::Array(items)) = pointer_get(before, parent)
+        {
+            *last = items.len().to_string();
+        }
+        let segments = segments.as_slice();
+        let value = pointer_get(self.expected, segments)
+            .ok_or_else(|| PatchError::PathNotFound(pointer(segments)))?
+            .clone();
+        let parent_is_seq = segments
+            .split_last()
+            .and_then(|(_, parent)| pointer_get(before, parent))
+            .is_some_and(Value::is_array);
+        match pointer_get(before, segments) {
+            Some(old) if !parent_is_seq => self.replace(segments, Some(&old.clone()), &value),
+            _ if segments.is_empty() => self.replace(segments, Some(before), &value),
+            _ => self.insert(segments, &value),
+        }
+    }
+
+    fn insert(&mut self, segments: &[String], value: &Value) -> Result<(), PreserveError> {
+        let Some((last, parent_path)) = segments.split_last() else {
+            return Err(PatchError::CannotRemoveRoot.into());
+        };
+        let located = self.locate(parent_path)?;
+        if located.depth < parent_path.len() {
+            return self.rerender(parent_path, &located);
+        }
+        let node = &located.node;
+        let (key, index) = match node.kind {
+            NodeKind::Map => (Some(last.as_str()), node.entries.len()),
+            NodeKind::Seq if last == "-" => (None, node.entries.len()),
+            NodeKind::Seq => (
+                None,
+                last.parse::<usize>()
+                    .map_err(|_| PatchError::InvalidIndex(last.clone()))?,
+            ),
+            NodeKind::Leaf => return self.rerender(parent_path, &located),
+        };
+        let rendered = self.format.render(value, None)?;
+
+        let edit = match &node.layout {
+            Layout::Flow => Some(self.flow_insert(node, key, index, &rendered)),
+            Layout::Block {
+                insert_at,
+                indent,
+                prefix,
+            } => self
+                .format
+                .block_member(indent, prefix, key, &rendered)
+                .and_then(|member| self.block_insert(node, *insert_at, index, &member)),
+            Layout::Leaf => None,
+        };
+        match edit {
+            Some((at, text)) => {
+                self.splice(at..at, &text);
+                Ok(())
+            }
+            None => self.rerender(parent_path, &located),
+        }
+    }
+
+    fn remove(&mut self, segments: &[String]) -> Result<(), PreserveError> {
+        let located = self.locate(segments)?;
+        if located.depth < segments.len() {
+            // Already gone after an earlier re-render
+            return self.rerender(&segments[..located.depth], &located);
+        }
+        let Some((parent, index)) = &located.parent else {
+            return Err(PatchError::CannotRemoveRoot.into());
+        };
+        let range = match parent.layout {
+            Layout::Flow => Some(flow_remove_range(parent, *index)),
+            Layout::Block { .. } => {
+                let range = parent.entries[*index].range.clone();
+                let at_line_start =
+                    range.start == 0 || self.source.as_bytes()[range.start - 1] == b'\n';
+                at_line_start.then_some(range)
+            }
+            Layout::Leaf => None,
+        };
+        if let Some(range) = range {
+            self.splice(range, "");
+            return Ok(());
+        }
+        let parent_path = &segments[..segments.len() - 1];
+        let parent_located = Located {
+            node: parent.clone(),
+            depth: parent_path.len(),
+            parent: None,
+        };
+        self.rerender(parent_path, &parent_located)
+    }
+
+    /// Replace the deepest indexed node with the expected value, rendered inline
+    fn rerender(&mut self, segments: &[String], located: &Located) -> Result<(), PreserveError> {
+        let path = &segments[..located.depth.min(segments.len())];
+        if path.is_empty() || located.node.layout != Layout::Leaf && located.node.span.is_empty() {
+            return Err(PreserveError::Unsupported(format!(
+                "{} cannot be edited without reformatting",
+                pointer(path)
+            )));
+        }
+        let value = pointer_get(self.expected, path)
+            .ok_or_else(|| PatchError::PathNotFound(pointer(path)))?;
+        let original = &self.source[located.node.span.clone()];
+        let rendered = self.format.render(value, Some(original))?;
+        self.splice(located.node.span.clone(), &rendered);
+        Ok(())
+    }
+
+    /// Insertion point and text for a member of a bracketed container
+    fn flow_insert(
+        &self,
+        node: &Node,
+        key: Option<&str>,
+        index: usize,
+        rendered: &str,
+    ) -> (usize, String) {
+        let member = self.format.flow_member(key, rendered);
+        let entries = &node.entries;
+        if entries.is_empty() {
+            return (node.span.start + 1, member);
+        }
+        let separator = match entries.len() {
+            1 => match &self.source[node.span.start + 1..entries[0].range.start] {
+                "" => ", ".to_string(),
+                lead => format!(",{lead}"),
+            },
+            n => self.source[entries[n - 2].range.end..entries[n - 1].range.start].to_string(),
+        };
+        // Keep the comma and line layout of the existing separator, not its comments
+        let separator = separator.rfind('\n').map_or_else(
+            || ", ".to_string(),
+            |newline| format!(",{}", &separator[newline..]),
+        );
+        if index >= entries.len() {
+            (
+                entries[entries.len() - 1].range.end,
+                format!("{separator}{member}"),
+            )
+        } else {
+            let after_comma = separator.trim_start().trim_start_matches(',');
+            (
+                entries[index].range.start,
+                format!("{member},{after_comma}"),
+            )
+        }
+    }
+
+    /// Insertion point and text for a line-oriented member
+    fn block_insert(
+        &self,
+        node: &Node,
+        insert_at: usize,
+        index: usize,
+        member: &str,
+    ) -> Option<(usize, String)> {
+        let at = if index < node.entries.len() {
+            let start = node.entries[index].range.start;
+            let at_line_start = start == 0 || self.source.as_bytes()[start - 1] == b'\n';
+            if !at_line_start {
+                return None;
+            }
+            start
+        } else {
+            insert_at
+        };
+        let text = if at > 0 && self.source.as_bytes()[at - 1] != b'\n' {
+            format!("\n{member}")
+        } else {
+            format!("{member}\n")
+        };
+        Some((at, text))
+    }
+}
+
+/// Byte range to delete when removing a member of a bracketed container
+fn flow_remove_range(parent: &Node, index: usize) -> Range<usize> {
+    let entries = &parent.entries;
+    let entry = &entries[index].range;
+    if index + 1 < entries.len() {
+        entry.start..entries[index + 1].range.start
+    } else if index > 0 {
+        entries[index - 1].range.end..entry.end
+    } else {
+        entry.clone()
+    }
+}
+
+/// Translate a merge patch into JSON Patch operations against `target`
+fn merge_patch_operations(
+    target: &Value,
+    merge: &Value,
+    path: String,
+    operations: &mut Vec<PatchOperation>,
+) {
+    let (Value::Object(target_map), Value::Object(merge_map)) = (target, merge) else {
+        operations.push(PatchOperation::Add {
+            path,
+            value: crate::merge::json_merge_patch(target, merge),
+        });
+        return;
+    };
+    for (key, value) in merge_map {
+        let child = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+        match (target_map.get(key), value) {
+            (Some(_), Value::Null) => operations.push(PatchOperation::Remove { path: child }),
+            (None, Value::Null) => {}
+            (Some(existing @ Value::Object(_)), Value::Object(_)) => {
+                merge_patch_operations(existing, value, child, operations);
+            }
+            (_, value) => operations.push(PatchOperation::Add {
+                path: child,
+                value: crate::merge::json_merge_patch(&Value::Null, value),
+            }),
+        }
+    }
+}
+
+fn pointer_get<'v>(value: &'v Value, segments: &[String]) -> Option<&'v Value> {
+    segments
+        .iter()
+        .try_fold(value, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+fn child_path(segments: &[String], key: &str) -> Vec<String> {
+    let mut path = segments.to_vec();
+    path.push(key.to_string());
+    path
+}
+
+fn pointer(segments: &[String]) -> String {
+    segments.iter().fold(String::new(), |mut out, s| {
+        out.push('/');
+        out.push_str(&s.replace('~', "~0").replace('/', "~1"));
+        out
+    })
+}
+
+/// Split a JSON Pointer into unescaped segments
+fn parse_pointer(path: &str) -> Result<Vec<String>, PreserveError> {
+    fionn_core::patchable::parse_pointer(path)
+        .map_err(|_| PatchError::InvalidPath(path.to_string()).into())
+}
+
+/// Convert a dotted operation path to a JSON Pointer
+fn operation_pointer(path: &str) -> Result<String, PreserveError> {
+    PathStyle::Dotted
+        .convert(path, PathStyle::Pointer)
+        .map_err(|e| PatchError::InvalidPath(e.to_string()).into())
+}
+
+/// Operation values that carry their content; tape references do not
+fn operation_value(value: &OperationValue) -> Result<Value, PreserveError> {
+    match value {
+        OperationValue::StringRef(s) => Ok(Value::String(s.clone())),
+        OperationValue::NumberRef(n) => Ok(n.to_json_value()),
+        OperationValue::BoolRef(b) => Ok(Value::Bool(*b)),
+        OperationValue::Null => Ok(Value::Null),
+        OperationValue::ObjectRef { .. } | OperationValue::ArrayRef { .. } => Err(
+            PreserveError::Unsupported("tape references carry no value to write".to_string()),
+        ),
+    }
+}
+
+fn operation_path(op: &PatchOperation) -> &str {
+    match op {
+        PatchOperation::Add { path, .. }
+        | PatchOperation::Remove { path }
+        | PatchOperation::Replace { path, .. }
+        | PatchOperation::Move { path, .. }
+        | PatchOperation::Copy { path, .. }
+        | PatchOperation::Test { path, .. } => path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn patch(ops: Value) -> JsonPatch {
+        serde_json::from_value(ops).unwrap()
+    }
+
+    #[test]
+    fn test_jsonc_replace_keeps_comments() {
+        let source = "{\n  // build settings\n  \"name\": \"fionn\", /* pinned */\n  \"version\": \"0.1.0\",\n}\n";
+        let out = apply_patch_preserving(
+            source,
+            PreserveFormat::Json,
+            &patch(json!([{"op": "replace", "path": "/version", "value": "0.2.0"}])),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "{\n  // build settings\n  \"name\": \"fionn\", /* pinned */\n  \"version\": \"0.2.0\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_jsonc_add_and_remove() {
+        let source = "{\n  \"a\": 1,\n  \"b\": [1, 2]\n}";
+        let out = apply_patch_preserving(
+            source,
+            PreserveFormat::Json,
+            &patch(json!([
+                {"op": "add", "path": "/c", "value": true},
+                {"op": "add", "path": "/b/-", "value": 3},
+                {"op": "remove", "path": "/a"}
+            ])),
+        )
+        .unwrap();
+        assert_eq!(out, "{\n  \"b\": [1, 2, 3],\n  \"c\": true\n}");
+    }
+
+    #[test]
+    fn test_merge_patch_descends_into_objects() {
+        let source = "{\"pkg\": {\"version\": \"1\", \"keep\": 0}, \"drop\": null}";
+        let out = merge_patch_preserving(
+            source,
+            PreserveFormat::Json,
+            &json!({"pkg": {"version": "2"}, "drop": null}),
+        )
+        .unwrap();
+        assert_eq!(out, "{\"pkg\": {\"version\": \"2\", \"keep\": 0}}");
+    }
+
+    #[test]
+    fn test_failed_operation_keeps_source() {
+        let mut doc = FormatPreservingDocument::parse("{\"a\": 1}", PreserveFormat::Json).unwrap();
+        let err = doc.remove("/missing").unwrap_err();
+        assert!(matches!(
+            err,
+            PreserveError::Patch(PatchError::PathNotFound(_))
+        ));
+        assert_eq!(doc.as_str(), "{\"a\": 1}");
+    }
+
+    #[test]
+    fn test_move_and_copy() {
+        let mut doc =
+            FormatPreservingDocument::parse("{\"a\": 1, \"b\": {}}", PreserveFormat::Json).unwrap();
+        doc.apply_patch(&patch(json!([
+            {"op": "copy", "from": "/a", "path": "/b/x"},
+            {"op": "move", "from": "/a", "path": "/c"}
+        ])))
+        .unwrap();
+        assert_eq!(doc.value(), &json!({"b": {"x": 1}, "c": 1}));
+        assert_eq!(doc.as_str(), "{\"b\": {\"x\": 1}, \"c\": 1}");
+    }
+
+    #[test]
+    fn test_apply_dson_operations() {
+        let source = "{\n  // pinned\n  \"pkg\": {\"version\": \"1.0\"},\n  \"old\": true\n}\n";
+        let mut doc = FormatPreservingDocument::parse(source, PreserveFormat::Json).unwrap();
+        doc.apply_operations(&[DsonOperation::BatchExecute {
+            operations: vec![
+                DsonOperation::FieldModify {
+                    path: "pkg.version".to_string(),
+                    old_value: None,
+                    new_value: OperationValue::StringRef("1.1".to_string()),
+                },
+                DsonOperation::ObjectStart {
+                    path: "pkg".to_string(),
+                },
+                DsonOperation::ArrayStart {
+                    path: "pkg.tags".to_string(),
+                },
+                DsonOperation::FieldAdd {
+                    path: "pkg.tags[0]".to_string(),
+                    value: OperationValue::StringRef("stable".to_string()),
+                },
+                DsonOperation::FieldDelete {
+                    path: "old".to_string(),
+                },
+            ],
+        }])
+        .unwrap();
+        assert_eq!(
+            doc.value(),
+            &json!({"pkg": {"version": "1.1", "tags": ["stable"]}})
+        );
+        assert!(doc.as_str().contains("// pinned"));
+
+        let err = doc
+            .apply_operations(&[DsonOperation::FieldAdd {
+                path: "pkg.copy".to_string(),
+                value: OperationValue::ObjectRef { start: 0, end: 1 },
+            }])
+            .unwrap_err();
+        assert!(matches!(err, PreserveError::Unsupported(_)));
+    }
+}
@@ -0,0 +1,659 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! TOML span index.
+//!
+//! Indexes `[table]` and `[[array]]` sections and `key = value` lines,
+//! including dotted keys. Inline tables and arrays are indexed as bracketed
+//! containers.
+
+use super::{Entry, Layout, Node, NodeKind, PreserveError};
+use serde_json::Value;
+use std::ops::Range;
+
+/// Parse TOML source to a value
+pub fn parse_value(source: &str) -> Result<Value, PreserveError> {
+    let value: toml::Value =
+        toml::from_str(source).map_err(|e| PreserveError::Syntax(e.to_string()))?;
+    serde_json::to_value(value).map_err(|e| PreserveError::Syntax(e.to_string()))
+}
+
+/// Render a value inline, keeping the quote style of `original`
+pub fn render(value: &Value, original: Option<&str>) -> Result<String, PreserveError> {
+    let original = original.unwrap_or("");
+    Ok(match value {
+        Value::String(s) => {
+            let literal = original.starts_with('\'') && !original.starts_with("'''");
+            if literal && !s.contains(['\'', '\n', '\r']) {
+                format!("'{s}'")
+            } else {
+                Value::String(s.clone()).to_string()
+            }
+        }
+        Value::Null => {
+            return Err(PreserveError::Unsupported(
+                "TOML has no null value".to_string(),
+            ));
+        }
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|v| render(v, None))
+                .collect::<Result<Vec<_>, _>>()?;
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) if map.is_empty() => "{}".to_string(),
+        Value::Object(map) => {
+            let members = map
+                .iter()
+                .map(|(k, v)| Ok(format!("{} = {}", render_key(k), render(v, None)?)))
+                .collect::<Result<Vec<_>, PreserveError>>()?;
+            format!("{{ {} }}", members.join(", "))
+        }
+    })
+}
+
+/// Render a key, quoting it unless it is a bare key
+pub fn render_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    if bare {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Build the span index for a TOML document
+pub fn index(source: &str) -> Result<Node, PreserveError> {
+    let mut builder = Builder {
+        source,
+        tables: vec![Table::new(0)],
+    };
+    builder.scan()?;
+    Ok(builder.node(0, 0, String::new()))
+}
+
+/// Table under construction
+#[derive(Debug)]
+struct Table {
+    /// Offset of the header (or the first dotted key) defining the table
+    at: usize,
+    entries: Vec<(String, Range<usize>, Item)>,
+    /// Where new keys go; `None` for tables only implied by a sub-table
+    insert_at: Option<usize>,
+    indent: Option<String>,
+    /// Defined by dotted keys rather than a header
+    dotted: bool,
+}
+
+impl Table {
+    const fn new(at: usize) -> Self {
+        Self {
+            at,
+            entries: Vec::new(),
+            insert_at: None,
+            indent: None,
+            dotted: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Item {
+    Value(Range<usize>),
+    Table(usize),
+    Array(Vec<usize>),
+}
+
+struct Builder<'s> {
+    source: &'s str,
+    tables: Vec<Table>,
+}
+
+impl Builder<'_> {
+    fn scan(&mut self) -> Result<(), PreserveError> {
+        let bytes = self.source.as_bytes();
+        let mut current = 0;
+        self.tables[0].insert_at = Some(0);
+        // Open header section: (parent table, entry in parent, section start)
+        let mut section: Option<(usize, usize, usize)> = None;
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            let line_start = pos;
+            let content = skip_ws(bytes, pos);
+            let next_line = line_end(self.source, line_start);
+            match bytes.get(content) {
+                None | Some(b'\n' | b'#') => {}
+                Some(b'\r') if bytes.get(content + 1) == Some(&b'\n') => {}
+                Some(b'[') => {
+                    let array = bytes.get(content + 1) == Some(&b'[');
+                    let key_start = content + if array { 2 } else { 1 };
+                    let (keys, after) = parse_keys(self.source, key_start)?;
+                    let close: &[u8] = if array { b"]]" } else { b"]" };
+                    if !bytes[after..].starts_with(close) {
+                        return Err(syntax_error(after, "expected ']'"));
+                    }
+                    self.close_section(section.take(), line_start);
+                    let (parent, entry, table) = self.open_header(&keys, array, line_start)?;
+                    self.tables[table].insert_at = Some(next_line);
+                    section = Some((parent, entry, line_start));
+                    current = table;
+                }
+                Some(_) => {
+                    let (keys, after) = parse_keys(self.source, content)?;
+                    let eq = skip_ws(bytes, after);
+                    if bytes.get(eq) != Some(&b'=') {
+                        return Err(syntax_error(eq, "expected '='"));
+                    }
+                    let value_start = skip_ws(bytes, eq + 1);
+                    let value_end = value_end(self.source, value_start)
+                        .ok_or_else(|| syntax_error(value_start, "unterminated value"))?;
+                    let entry_end = line_end(self.source, value_end);
+                    let indent = &self.source[line_start..content];
+                    if self.tables[current].indent.is_none() {
+                        self.tables[current].indent = Some(indent.to_string());
+                    }
+
+                    let (last, parents) = keys
+                        .split_last()
+                        .ok_or_else(|| syntax_error(content, "empty key"))?;
+                    let mut table = current;
+                    for key in parents {
+                        table = self.child_table(table, key, content, true);
+                        let t = &mut self.tables[table];
+                        t.insert_at = Some(entry_end);
+                        t.indent.get_or_insert_with(|| indent.to_string());
+                    }
+                    self.tables[table].entries.push((
+                        last.clone(),
+                        line_start..entry_end,
+                        Item::Value(value_start..value_end),
+                    ));
+                    self.tables[current].insert_at = Some(entry_end);
+                    pos = entry_end;
+                    continue;
+                }
+            }
+            pos = next_line;
+        }
+        self.close_section(section, bytes.len());
+        Ok(())
+    }
+
+    /// Extend the entry range of a header section up to `end`
+    fn close_section(&mut self, section: Option<(usize, usize, usize)>, end: usize) {
+        if let Some((parent, entry, start)) = section {
+            let (_, range, item) = &mut self.tables[parent].entries[entry];
+            // An array of tables spans from its first element to its last
+            let start = if matches!(item, Item::Array(_)) {
+                range.start.min(start)
+            } else {
+                start
+            };
+            *range = start..end;
+        }
+    }
+
+    /// Resolve a `[a.b]` / `[[a.b]]` header, returning (parent, entry, table)
+    fn open_header(
+        &mut self,
+        keys: &[String],
+        array: bool,
+        at: usize,
+    ) -> Result<(usize, usize, usize), PreserveError> {
+        let (last, parents) = keys
+            .split_last()
+            .ok_or_else(|| syntax_error(at, "empty header"))?;
+        let mut parent = 0;
+        for key in parents {
+            parent = self.child_table(parent, key, at, false);
+        }
+        let existing = self.tables[parent]
+            .entries
+            .iter()
+            .position(|(k, ..)| k == last);
+        let table = self.tables.len();
+        self.tables.push(Table::new(at));
+        match existing {
+            Some(entry) if array => match &mut self.tables[parent].entries[entry].2 {
+                Item::Array(items) => {
+                    items.push(table);
+                    // Element sections are tracked through the array entry
+                    Ok((parent, entry, table))
+                }
+                _ => Err(syntax_error(at, "array of tables redefines a key")),
+            },
+            Some(entry) => match self.tables[parent].entries[entry].2 {
+                // A table implied earlier by a sub-table header
+                Item::Table(implied) => {
+                    self.tables.pop();
+                    self.tables[implied].at = at;
+                    Ok((parent, entry, implied))
+                }
+                _ => Err(syntax_error(at, "table redefines a key")),
+            },
+            None => {
+                let item = if array {
+                    Item::Array(vec![table])
+                } else {
+                    Item::Table(table)
+                };
+                self.tables[parent]
+                    .entries
+                    .push((last.clone(), at..at, item));
+                Ok((parent, self.tables[parent].entries.len() - 1, table))
+            }
+        }
+    }
+
+    /// Find or create the sub-table `key` of `table`
+    fn child_table(&mut self, table: usize, key: &str, at: usize, dotted: bool) -> usize {
+        let found = self.tables[table]
+            .entries
+            .iter()
+            .find_map(|(k, _, item)| match item {
+                Item::Table(t) if k == key => Some(*t),
+                Item::Array(items) if k == key => items.last().copied(),
+                _ => None,
+            });
+        if let Some(found) = found {
+            return found;
+        }
+        let child = self.tables.len();
+        let mut new = Table::new(at);
+        new.dotted = dotted;
+        self.tables.push(new);
+        self.tables[table]
+            .entries
+            .push((key.to_string(), at..at, Item::Table(child)));
+        child
+    }
+
+    /// Convert table `index` to a span node
+    ///
+    /// `fallback` and `prefix` give the insertion point and key prefix used
+    /// by tables that only exist implicitly.
+    fn node(&self, index: usize, fallback: usize, prefix: String) -> Node {
+        let table = &self.tables[index];
+        let (insert_at, prefix) = match table.insert_at {
+            Some(at) if !table.dotted => (at, String::new()),
+            Some(at) => (at, prefix),
+            None => (fallback, prefix),
+        };
+        let indent = table.indent.clone().unwrap_or_default();
+        let entries = table
+            .entries
+            .iter()
+            .map(|(key, range, item)| {
+                let child_prefix = format!("{prefix}{}.", render_key(key));
+                let node = match item {
+                    Item::Value(span) => inline_node(self.source, span.clone()),
+                    Item::Table(t) => self.node(*t, insert_at, child_prefix),
+                    Item::Array(items) => Node {
+                        span: range.start..range.start,
+                        kind: NodeKind::Seq,
+                        layout: Layout::Leaf,
+                        entries: items
+                            .iter()
+                            .map(|&t| Entry {
+                                key: None,
+                                range: range.start..range.start,
+                                node: self.node(t, insert_at, String::new()),
+                            })
+                            .collect(),
+                    },
+                };
+                Entry {
+                    key: Some(key.clone()),
+                    range: range.clone(),
+                    node,
+                }
+            })
+            .collect();
+        Node {
+            span: table.at..table.at,
+            kind: NodeKind::Map,
+            layout: Layout::Block {
+                insert_at,
+                indent,
+                prefix,
+            },
+            entries,
+        }
+    }
+}
+
+/// Parse a dotted key starting at `pos`, returning segments and the end offset
+fn parse_keys(source: &str, mut pos: usize) -> Result<(Vec<String>, usize), PreserveError> {
+    let bytes = source.as_bytes();
+    let mut keys = Vec::new();
+    loop {
+        pos = skip_ws(bytes, pos);
+        let (key, end) = match bytes.get(pos) {
+            Some(b'"') => {
+                let end =
+                    value_end(source, pos).ok_or_else(|| syntax_error(pos, "unterminated key"))?;
+                let key = serde_json::from_str(&source[pos..end])
+                    .map_err(|_| syntax_error(pos, "invalid quoted key"))?;
+                (key, end)
+            }
+            Some(b'\'') => {
+                let end =
+                    value_end(source, pos).ok_or_else(|| syntax_error(pos, "unterminated key"))?;
+                (source[pos + 1..end - 1].to_string(), end)
+            }
+            _ => {
+                let end = pos
+                    + bytes[pos..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-'))
+                        .count();
+                if end == pos {
+                    return Err(syntax_error(pos, "expected key"));
+                }
+                (source[pos..end].to_string(), end)
+            }
+        };
+        keys.push(key);
+        pos = skip_ws(bytes, end);
+        if bytes.get(pos) == Some(&b'.') {
+            pos += 1;
+        } else {
+            return Ok((keys, pos));
+        }
+    }
+}
+
+fn syntax_error(pos: usize, msg: &str) -> PreserveError {
+    PreserveError::Syntax(format!("{msg} at byte {pos}"))
+}
+
+/// Index an inline value; inline tables and arrays become bracketed containers
+fn inline_node(source: &str, span: Range<usize>) -> Node {
+    let bytes = source.as_bytes();
+    let kind = match bytes.get(span.start) {
+        Some(b'{') => NodeKind::Map,
+        Some(b'[') => NodeKind::Seq,
+        _ => return Node::leaf(span),
+    };
+    let mut entries = Vec::new();
+    let mut pos = span.start + 1;
+    loop {
+        pos = skip_trivia(bytes, pos);
+        match bytes.get(pos) {
+            Some(b',') => {
+                pos += 1;
+                continue;
+            }
+            Some(b'}' | b']') | None => break,
+            _ => {}
+        }
+        let entry_start = pos;
+        let key = if kind == NodeKind::Map {
+            let parsed = parse_keys(source, pos).ok().filter(|(keys, after)| {
+                keys.len() == 1 && bytes.get(skip_ws(bytes, *after)) == Some(&b'=')
+            });
+            // Dotted keys inside inline tables are edited as a whole
+            let Some((mut keys, after)) = parsed else {
+                return Node::leaf(span);
+            };
+            pos = skip_ws(bytes, skip_ws(bytes, after) + 1);
+            keys.pop()
+        } else {
+            None
+        };
+        let Some(end) = value_end(source, pos).filter(|&end| end <= span.end) else {
+            return Node::leaf(span);
+        };
+        entries.push(Entry {
+            key,
+            range: entry_start..end,
+            node: inline_node(source, pos..end),
+        });
+        pos = end;
+    }
+    Node {
+        span,
+        kind,
+        layout: Layout::Flow,
+        entries,
+    }
+}
+
+/// Skip whitespace, newlines and comments inside an inline array
+fn skip_trivia(bytes: &[u8], mut pos: usize) -> usize {
+    loop {
+        match bytes.get(pos) {
+            Some(b' ' | b'\t' | b'\r' | b'\n') => pos += 1,
+            Some(b'#') => {
+                while bytes.get(pos).is_some_and(|&b| b != b'\n') {
+                    pos += 1;
+                }
+            }
+            _ => return pos,
+        }
+    }
+}
+
+fn skip_ws(bytes: &[u8], mut pos: usize) -> usize {
+    while matches!(bytes.get(pos), Some(b' ' | b'\t')) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Offset just past the value starting at `start`
+fn value_end(source: &str, start: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let rest = &source[start..];
+    if let Some(body) = rest.strip_prefix("\"\"\"") {
+        let close = find_basic_close(body.as_bytes(), b"\"\"\"")?;
+        return Some(start + 3 + close + 3 + extra_quotes(&bytes[start + 6 + close..], b'"'));
+    }
+    if let Some(body) = rest.strip_prefix("'''") {
+        let close = body.find("'''")?;
+        return Some(start + 3 + close + 3 + extra_quotes(&bytes[start + 6 + close..], b'\''));
+    }
+    match bytes.get(start)? {
+        b'"' => find_basic_close(&bytes[start + 1..], b"\"").map(|i| start + 1 + i + 1),
+        b'\'' => rest[1..].find('\'').map(|i| start + 1 + i + 1),
+        b'[' | b'{' => {
+            let mut depth = 0usize;
+            let mut i = start;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'"' | b'\'' => {
+                        i = value_end(source, i)?;
+                        continue;
+                    }
+                    b'#' => {
+                        while i < bytes.len() && bytes[i] != b'\n' {
+                            i += 1;
+                        }
+                        continue;
+                    }
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            None
+        }
+        _ => {
+            let len = rest.find(['#', '\n']).unwrap_or(rest.len());
+            Some(start + rest[..len].trim_end().len())
+        }
+    }
+}
+
+/// Offset of the unescaped `close` sequence in a basic string body
+fn find_basic_close(body: &[u8], close: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < body.len() {
+        if body[i] == b'\\' {
+            i += 2;
+        } else if body[i..].starts_with(close) {
+            return Some(i);
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Up to two quotes may directly follow a multi-line string's delimiter
+fn extra_quotes(after: &[u8], quote: u8) -> usize {
+    after.iter().take(2).take_while(|&&b| b == quote).count()
+}
+
+/// Byte offset just past the newline ending the line containing `pos`
+fn line_end(source: &str, pos: usize) -> usize {
+    source[pos..]
+        .find('\n')
+        .map_or(source.len(), |i| pos + i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{PreserveFormat, apply_patch_preserving, merge_patch_preserving};
+    use super::*;
+    use serde_json::json;
+
+    const CARGO: &str = "# Package manifest
+[package]
+name = \"fionn\"
+version = \"0.2.0\"   # bumped by CI
+edition = '2021'
+
+[dependencies]
+serde = { version = \"1.0\", features = [\"derive\"] }
+toml.version = \"0.8\"
+toml.optional = true
+
+[[bin]]
+name = \"fionn\"
+
+[[bin]]
+name = \"simd-gron\"
+";
+
+    fn patch(source: &str, ops: Value) -> Result<String, PreserveError> {
+        apply_patch_preserving(
+            source,
+            PreserveFormat::Toml,
+            &serde_json::from_value(ops).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_index_tables() {
+        let node = index(CARGO).unwrap();
+        let keys: Vec<_> = node
+            .entries
+            .iter()
+            .map(|e| e.key.clone().unwrap())
+            .collect();
+        assert_eq!(keys, ["package", "dependencies", "bin"]);
+        let package = &node.entries[0].node;
+        assert_eq!(&CARGO[package.entries[1].node.span.clone()], "\"0.2.0\"");
+        let bins = &node.entries[2].node;
+        assert_eq!(bins.kind, NodeKind::Seq);
+        assert_eq!(bins.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_version_bump_keeps_layout() {
+        let out = merge_patch_preserving(
+            CARGO,
+            PreserveFormat::Toml,
+            &json!({"package": {"version": "0.3.0", "edition": "2024"}}),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            CARGO
+                .replace("\"0.2.0\"   # bumped", "\"0.3.0\"   # bumped")
+                .replace("'2021'", "'2024'")
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_keys() {
+        let out = patch(
+            CARGO,
+            json!([
+                {"op": "add", "path": "/package/license", "value": "MIT"},
+                {"op": "add", "path": "/dependencies/toml/features", "value": ["parse"]},
+                {"op": "remove", "path": "/dependencies/serde"},
+                {"op": "replace", "path": "/bin/1/name", "value": "gron"}
+            ]),
+        )
+        .unwrap();
+        let expected = CARGO
+            .replace(
+                "edition = '2021'\n",
+                "edition = '2021'\nlicense = \"MIT\"\n",
+            )
+            .replace(
+                "serde = { version = \"1.0\", features = [\"derive\"] }\n",
+                "",
+            )
+            .replace(
+                "toml.optional = true\n",
+                "toml.optional = true\ntoml.features = [\"parse\"]\n",
+            )
+            .replace("\"simd-gron\"", "\"gron\"");
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_inline_table_member_added() {
+        let out = patch(
+            CARGO,
+            json!([{"op": "add", "path": "/dependencies/serde/default-features", "value": false}]),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            CARGO.replace(
+                "features = [\"derive\"] }",
+                "features = [\"derive\"], default-features = false }"
+            )
+        );
+    }
+
+    #[test]
+    fn test_inline_array_edits() {
+        let source = "members = [\n  \"a\",  # first\n  \"b\",\n]\n";
+        let out = patch(
+            source,
+            json!([
+                {"op": "replace", "path": "/members/1", "value": "c"},
+                {"op": "add", "path": "/members/-", "value": "d"}
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "members = [\n  \"a\",  # first\n  \"c\",\n  \"d\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_null_rejected() {
+        let err = patch(
+            CARGO,
+            json!([{"op": "replace", "path": "/package/name", "value": null}]),
+        );
+        assert!(matches!(err, Err(PreserveError::Unsupported(_))));
+    }
+}
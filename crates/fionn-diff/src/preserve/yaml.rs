@@ -0,0 +1,590 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! YAML span index.
+//!
+//! Indexes block mappings and block sequences line by line. Flow
+//! collections, block scalars and multi-line plain scalars are indexed as
+//! leaves and only edited as a whole. Only the first document of a stream is
+//! indexed.
+
+use super::{Entry, Layout, Node, NodeKind, PreserveError};
+use serde_json::Value;
+
+/// Parse YAML source to a value
+pub fn parse_value(source: &str) -> Result<Value, PreserveError> {
+    if source.trim().is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_yaml::from_str(source).map_err(|e| PreserveError::Syntax(e.to_string()))
+}
+
+/// Render a value inline, keeping the quote style of `original`
+pub fn render(value: &Value, original: Option<&str>) -> String {
+    let original = original.unwrap_or("");
+    match value {
+        Value::String(s) => {
+            if original.starts_with('\'') && !s.contains('\n') {
+                format!("'{}'", s.replace('\'', "''"))
+            } else if original.starts_with('"') || !is_plain_safe(s) {
+                Value::String(s.clone()).to_string()
+            } else {
+                s.clone()
+            }
+        }
+        Value::Null if original == "~" => "~".to_string(),
+        Value::Null => "null".to_string(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(|v| render(v, None)).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let members: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", render_key(k), render(v, None)))
+                .collect();
+            format!("{{{}}}", members.join(", "))
+        }
+    }
+}
+
+/// Render a mapping key
+pub fn render_key(key: &str) -> String {
+    if is_plain_safe(key) {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Whether a string can be written as a plain scalar and read back as the
+/// same string, in both block and flow context
+fn is_plain_safe(s: &str) -> bool {
+    let Some(first) = s.chars().next() else {
+        return false;
+    };
+    let reserved = [
+        "true", "false", "yes", "no", "on", "off", "y", "n", "null", "~", ".inf", "-.inf", ".nan",
+    ];
+    s.trim() == s
+        && !"-?:,[]{}#&*!|>'\"%@`".contains(first)
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && !s.ends_with(':')
+        && !s.chars().any(|c| c.is_control() || "[]{},".contains(c))
+        && !reserved.contains(&s.to_ascii_lowercase().as_str())
+        && s.parse::<f64>().is_err()
+        && !s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Build the span index for a YAML document
+pub fn index(source: &str) -> Result<Node, PreserveError> {
+    let parser = Parser {
+        source,
+        lines: content_lines(source),
+    };
+    if parser.lines.is_empty() {
+        return Ok(Node::leaf(source.len()..source.len()));
+    }
+    let first = &parser.lines[0];
+    let (node, next) = parser.node(0, first.indent, None)?;
+    if next < parser.lines.len() {
+        let line = &parser.lines[next];
+        return Err(PreserveError::Syntax(format!(
+            "unexpected content at byte {}",
+            line.start + line.indent
+        )));
+    }
+    Ok(node)
+}
+
+/// A line holding YAML content
+#[derive(Debug)]
+struct Line {
+    /// Byte offset of the line start
+    start: usize,
+    /// Byte offset of the line end, excluding the newline
+    end: usize,
+    /// Leading spaces
+    indent: usize,
+    /// Byte offset of the content end, excluding comment and trailing spaces
+    content_end: usize,
+}
+
+impl Line {
+    fn text<'s>(&self, source: &'s str, col: usize) -> &'s str {
+        &source[(self.start + col).min(self.content_end)..self.content_end]
+    }
+}
+
+/// Lines with content, up to the end of the first document
+fn content_lines(source: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut seen_content = false;
+    while start < source.len() {
+        let end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let raw = &source[start..end];
+        let indent = raw.len() - raw.trim_start_matches(' ').len();
+        let content_end = start + strip_comment(raw);
+        let content = raw[indent.min(raw.len())..].trim_end();
+        let marker = content == "---" || content == "...";
+        if marker && seen_content {
+            break;
+        }
+        if content_end > start + indent && !marker && !content.starts_with('%') {
+            seen_content = true;
+            lines.push(Line {
+                start,
+                end,
+                indent,
+                content_end,
+            });
+        }
+        start = end + 1;
+    }
+    lines
+}
+
+/// Length of `line` without a trailing comment and trailing whitespace
+fn strip_comment(line: &str) -> usize {
+    let bytes = line.as_bytes();
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let token_start = i == 0
+            || matches!(
+                bytes[i - 1],
+                b' ' | b'\t' | b':' | b'[' | b'{' | b',' | b'-'
+            );
+        match quote {
+            Some(b'"') if b == b'\\' => i += 1,
+            Some(q) if b == q => {
+                if q == b'\'' && bytes.get(i + 1) == Some(&b'\'') {
+                    i += 1;
+                } else {
+                    quote = None;
+                }
+            }
+            None if (b == b'"' || b == b'\'') && token_start => quote = Some(b),
+            None if b == b'#' && (i == 0 || matches!(bytes[i - 1], b' ' | b'\t')) => {
+                return line[..i].trim_end().len();
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    line.trim_end().len()
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    lines: Vec<Line>,
+}
+
+impl Parser<'_> {
+    /// Parse the node starting at column `col` of line `li`
+    ///
+    /// `parent` is the indentation of the enclosing block, if any. Returns
+    /// the node and the index of the first line after it.
+    fn node(
+        &self,
+        li: usize,
+        col: usize,
+        parent: Option<usize>,
+    ) -> Result<(Node, usize), PreserveError> {
+        let text = self.lines[li].text(self.source, col);
+        if text == "-" || text.starts_with("- ") {
+            self.sequence(li, col)
+        } else if mapping_colon(text).is_some() {
+            self.mapping(li, col)
+        } else {
+            Ok(self.scalar(li, col, parent))
+        }
+    }
+
+    fn mapping(&self, li: usize, col: usize) -> Result<(Node, usize), PreserveError> {
+        let mut entries = Vec::new();
+        let mut cur = li;
+        while cur < self.lines.len() {
+            let line = &self.lines[cur];
+            let first = cur == li;
+            if !first && line.indent != col {
+                break;
+            }
+            let text = line.text(self.source, col);
+            let Some(colon) = mapping_colon(text) else {
+                if first {
+                    return Err(PreserveError::Syntax(format!(
+                        "expected mapping at byte {}",
+                        line.start
+                    )));
+                }
+                break;
+            };
+            let key = unquote_key(text[..colon].trim_end())?;
+            let value_col =
+                col + colon + 1 + text[colon + 1..].len() - text[colon + 1..].trim_start().len();
+            let (node, next) = if text[colon + 1..].trim().is_empty() {
+                self.block_child(cur, col, line.start + col + colon + 1)?
+            } else {
+                self.node_inline(cur, value_col, col)?
+            };
+            let entry_start = if first && col > line.indent {
+                line.start + col
+            } else {
+                line.start
+            };
+            entries.push(Entry {
+                key: Some(key),
+                range: entry_start..self.after_line(next - 1),
+                node,
+            });
+            cur = next;
+        }
+        Ok((self.block(NodeKind::Map, li, col, entries), cur))
+    }
+
+    fn sequence(&self, li: usize, col: usize) -> Result<(Node, usize), PreserveError> {
+        let mut entries = Vec::new();
+        let mut cur = li;
+        while cur < self.lines.len() {
+            let line = &self.lines[cur];
+            let first = cur == li;
+            if !first && line.indent != col {
+                break;
+            }
+            let text = line.text(self.source, col);
+            if !(text == "-" || text.starts_with("- ")) {
+                break;
+            }
+            let rest = &text[1..];
+            let item_col = col + 1 + rest.len() - rest.trim_start().len();
+            let (node, next) = if rest.trim().is_empty() {
+                self.block_child(cur, col, line.start + col + 1)?
+            } else {
+                self.node(cur, item_col, Some(col))?
+            };
+            let entry_start = if first && col > line.indent {
+                line.start + col
+            } else {
+                line.start
+            };
+            entries.push(Entry {
+                key: None,
+                range: entry_start..self.after_line(next - 1),
+                node,
+            });
+            cur = next;
+        }
+        Ok((self.block(NodeKind::Seq, li, col, entries), cur))
+    }
+
+    /// Value on the lines following a `key:` or `-` with nothing after it
+    fn block_child(
+        &self,
+        li: usize,
+        col: usize,
+        empty_at: usize,
+    ) -> Result<(Node, usize), PreserveError> {
+        match self.lines.get(li + 1) {
+            Some(next) if next.indent > col => self.node(li + 1, next.indent, Some(col)),
+            Some(next)
+                if next.indent == col && {
+                    let text = next.text(self.source, col);
+                    text == "-" || text.starts_with("- ")
+                } =>
+            {
+                self.sequence(li + 1, col)
+            }
+            _ => Ok((Node::leaf(empty_at..empty_at), li + 1)),
+        }
+    }
+
+    /// Value on the same line as its key
+    fn node_inline(
+        &self,
+        li: usize,
+        col: usize,
+        parent: usize,
+    ) -> Result<(Node, usize), PreserveError> {
+        let text = self.lines[li].text(self.source, col);
+        // `key: - x` and `key: a: b` are not valid block content
+        if text.starts_with("- ")
+            || mapping_colon(text).is_some() && !text.starts_with(['"', '\'', '[', '{'])
+        {
+            return Err(PreserveError::Syntax(format!(
+                "unexpected block content at byte {}",
+                self.lines[li].start + col
+            )));
+        }
+        Ok(self.scalar(li, col, Some(parent)))
+    }
+
+    /// Leaf value starting at `col` of line `li`
+    fn scalar(&self, li: usize, col: usize, parent: Option<usize>) -> (Node, usize) {
+        let line = &self.lines[li];
+        let start = line.start + col;
+        let text = line.text(self.source, col);
+        let end = match text.as_bytes().first() {
+            Some(b'|' | b'>') => {
+                // Block scalar: all following lines indented past the parent
+                let mut last = li;
+                while self
+                    .lines
+                    .get(last + 1)
+                    .is_some_and(|l| parent.is_none_or(|p| l.indent > p))
+                {
+                    last += 1;
+                }
+                return (Node::leaf(start..self.lines[last].end), last + 1);
+            }
+            Some(&open @ (b'[' | b'{' | b'"' | b'\'')) => {
+                closing(self.source, start, open).unwrap_or(line.content_end)
+            }
+            _ => {
+                // Plain scalar, possibly continued on more-indented lines
+                let mut last = li;
+                while self
+                    .lines
+                    .get(last + 1)
+                    .is_some_and(|l| parent.is_some_and(|p| l.indent > p))
+                {
+                    last += 1;
+                }
+                return (Node::leaf(start..self.lines[last].content_end), last + 1);
+            }
+        };
+        let next = self
+            .lines
+            .iter()
+            .position(|l| l.start > end)
+            .unwrap_or(self.lines.len());
+        (Node::leaf(start..end), next.max(li + 1))
+    }
+
+    fn block(&self, kind: NodeKind, li: usize, col: usize, entries: Vec<Entry>) -> Node {
+        let start = self.lines[li].start + col;
+        let end = entries.last().map_or(start, |e| e.node.span.end.max(start));
+        let insert_at = entries.last().map_or(start, |e| e.range.end);
+        Node {
+            span: start..end,
+            kind,
+            layout: Layout::Block {
+                insert_at,
+                indent: " ".repeat(col),
+                prefix: String::new(),
+            },
+            entries,
+        }
+    }
+
+    /// Byte offset just past the newline of line `li`
+    fn after_line(&self, li: usize) -> usize {
+        (self.lines[li].end + 1).min(self.source.len())
+    }
+}
+
+/// Offset of the `:` separating a mapping key from its value
+fn mapping_colon(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let search_from = match bytes.first()? {
+        q @ (b'"' | b'\'') => closing(text, 0, *q)?,
+        b'[' | b'{' | b'-' if bytes.get(1).is_none_or(|b| *b == b' ') || bytes[0] != b'-' => {
+            return None;
+        }
+        _ => 0,
+    };
+    text[search_from..]
+        .match_indices(':')
+        .map(|(i, _)| search_from + i)
+        .find(|&i| bytes.get(i + 1).is_none_or(|b| *b == b' ' || *b == b'\t'))
+}
+
+/// Offset just past the bracket or quote closing the one at `start`
+fn closing(source: &str, start: usize, open: u8) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut i = start + 1;
+    match open {
+        b'"' => {
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'\\' => i += 2,
+                    b'"' => return Some(i + 1),
+                    _ => i += 1,
+                }
+            }
+            None
+        }
+        b'\'' => {
+            while i < bytes.len() {
+                if bytes[i] == b'\'' {
+                    if bytes.get(i + 1) == Some(&b'\'') {
+                        i += 2;
+                        continue;
+                    }
+                    return Some(i + 1);
+                }
+                i += 1;
+            }
+            None
+        }
+        _ => {
+            let mut depth = 1;
+            while i < bytes.len() {
+                match bytes[i] {
+                    q @ (b'"' | b'\'') => {
+                        i = closing(source, i, q)?;
+                        continue;
+                    }
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            None
+        }
+    }
+}
+
+fn unquote_key(raw: &str) -> Result<String, PreserveError> {
+    if raw.starts_with(['"', '\'']) {
+        match serde_yaml::from_str::<Value>(raw) {
+            Ok(Value::String(key)) => Ok(key),
+            _ => Err(PreserveError::Syntax(format!("invalid key: {raw}"))),
+        }
+    } else {
+        Ok(raw.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{PreserveFormat, apply_patch_preserving, merge_patch_preserving};
+    use super::*;
+    use serde_json::json;
+
+    const CONFIG: &str = "# Service configuration
+name: fionn   # the crate
+version: '1.2.0'
+
+server:
+  host: \"localhost\"
+  ports:
+    - 8080  # http
+    - 8443
+  tls: {enabled: false}
+
+notes: |
+  keep this
+  block as is
+";
+
+    fn patch(source: &str, ops: Value) -> Result<String, PreserveError> {
+        apply_patch_preserving(
+            source,
+            PreserveFormat::Yaml,
+            &serde_json::from_value(ops).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_index_block_structure() {
+        let node = index(CONFIG).unwrap();
+        let keys: Vec<_> = node
+            .entries
+            .iter()
+            .map(|e| e.key.clone().unwrap())
+            .collect();
+        assert_eq!(keys, ["name", "version", "server", "notes"]);
+        let server = &node.entries[2].node;
+        assert_eq!(server.kind, NodeKind::Map);
+        let ports = &server.entries[1].node;
+        assert_eq!(ports.kind, NodeKind::Seq);
+        assert_eq!(&CONFIG[ports.entries[0].node.span.clone()], "8080");
+    }
+
+    #[test]
+    fn test_replace_keeps_quotes_and_comments() {
+        let out = patch(
+            CONFIG,
+            json!([
+                {"op": "replace", "path": "/version", "value": "1.3.0"},
+                {"op": "replace", "path": "/server/host", "value": "0.0.0.0"},
+                {"op": "replace", "path": "/server/ports/0", "value": 9090}
+            ]),
+        )
+        .unwrap();
+        let expected = CONFIG
+            .replace("'1.2.0'", "'1.3.0'")
+            .replace("\"localhost\"", "\"0.0.0.0\"")
+            .replace("8080  # http", "9090  # http");
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_add_and_remove_lines() {
+        let out = patch(
+            CONFIG,
+            json!([
+                {"op": "add", "path": "/server/workers", "value": 4},
+                {"op": "add", "path": "/server/ports/-", "value": 9000},
+                {"op": "remove", "path": "/name"}
+            ]),
+        )
+        .unwrap();
+        let expected = CONFIG
+            .replace("name: fionn   # the crate\n", "")
+            .replace("    - 8443\n", "    - 8443\n    - 9000\n")
+            .replace(
+                "  tls: {enabled: false}\n",
+                "  tls: {enabled: false}\n  workers: 4\n",
+            );
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_flow_value_rerendered_in_place() {
+        let out = merge_patch_preserving(
+            CONFIG,
+            PreserveFormat::Yaml,
+            &json!({"server": {"tls": {"enabled": true}}}),
+        )
+        .unwrap();
+        assert_eq!(out, CONFIG.replace("{enabled: false}", "{enabled: true}"));
+    }
+
+    #[test]
+    fn test_sequence_of_mappings() {
+        let source = "deps:\n  - name: a\n    version: 1\n  - name: b\n    version: 2\n";
+        let out = patch(
+            source,
+            json!([
+                {"op": "replace", "path": "/deps/1/version", "value": 3},
+                {"op": "add", "path": "/deps/0/optional", "value": true}
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "deps:\n  - name: a\n    version: 1\n    optional: true\n  - name: b\n    version: 3\n"
+        );
+    }
+
+    #[test]
+    fn test_render_strings() {
+        assert_eq!(render(&json!("plain"), None), "plain");
+        assert_eq!(render(&json!("yes"), None), "\"yes\"");
+        assert_eq!(render(&json!("1.0"), None), "\"1.0\"");
+        assert_eq!(render(&json!("a: b"), None), "\"a: b\"");
+        assert_eq!(render(&json!("it's"), Some("'x'")), "'it''s'");
+    }
+}
@@ -13,35 +13,29 @@ use std::arch::x86_64::{
 #[cfg(target_arch = "aarch64")]
 use std::arch::aarch64::{vceqq_u8, vld1q_u8, vminvq_u8};
 
-#[cfg(target_arch = "x86_64")]
-use std::sync::OnceLock;
-
-/// Cached SIMD feature detection.
-#[cfg(target_arch = "x86_64")]
-static HAS_SSE2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
-static HAS_AVX2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
-#[inline]
-fn has_sse2() -> bool {
-    *HAS_SSE2.get_or_init(|| is_x86_feature_detected!("sse2"))
-}
-
-#[cfg(target_arch = "x86_64")]
-#[inline]
-fn has_avx2() -> bool {
-    *HAS_AVX2.get_or_init(|| is_x86_feature_detected!("avx2"))
-}
+use fionn_core::simd::{SimdDispatch, SimdLevel};
 
 /// SIMD-accelerated byte slice equality check.
 ///
 /// Returns `true` if both slices are equal, `false` otherwise.
-/// Uses SIMD to compare 16-32 bytes at a time.
+/// Uses SIMD to compare 16-32 bytes at a time, with the kernel chosen by
+/// the `fionn_core::simd` table.
 #[inline]
 #[must_use]
 pub fn simd_bytes_equal(a: &[u8], b: &[u8]) -> bool {
+    bytes_equal_with(fionn_core::simd::dispatch().compare, a, b)
+}
+
+/// Byte slice equality with the kernel for `level`.
+///
+/// The level is lowered to what the CPU supports.
+#[must_use]
+pub fn simd_bytes_equal_with_level(level: SimdLevel, a: &[u8], b: &[u8]) -> bool {
+    bytes_equal_with(SimdDispatch::for_level(level).compare, a, b)
+}
+
+#[inline]
+fn bytes_equal_with(kernel: SimdLevel, a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -50,25 +44,19 @@ pub fn simd_bytes_equal(a: &[u8], b: &[u8]) -> bool {
         return true;
     }
 
-    #[cfg(target_arch = "x86_64")]
-    {
-        if a.len() >= 32 && has_avx2() {
-            return unsafe { simd_bytes_equal_avx2(a, b) };
-        }
-        if a.len() >= 16 && has_sse2() {
-            return unsafe { simd_bytes_equal_sse2(a, b) };
-        }
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    {
-        if a.len() >= 16 {
-            return unsafe { simd_bytes_equal_neon(a, b) };
-        }
+    // SAFETY: The kernel table only selects instruction sets the CPU has
+    match kernel {
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2 if a.len() >= 32 => unsafe { simd_bytes_equal_avx2(a, b) },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Sse2 | SimdLevel::Avx2 if a.len() >= 16 => unsafe {
+            simd_bytes_equal_sse2(a, b)
+        },
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon if a.len() >= 16 => unsafe { simd_bytes_equal_neon(a, b) },
+        // Scalar fallback
+        _ => a == b,
     }
-
-    // Scalar fallback
-    a == b
 }
 
 /// Find the first position where two byte slices differ.
@@ -79,61 +67,51 @@ pub fn simd_bytes_equal(a: &[u8], b: &[u8]) -> bool {
 #[inline]
 #[must_use]
 pub fn simd_find_first_difference(a: &[u8], b: &[u8]) -> Option<usize> {
+    first_difference_with(fionn_core::simd::dispatch().compare, a, b)
+}
+
+/// First differing position with the kernel for `level`.
+///
+/// The level is lowered to what the CPU supports.
+#[must_use]
+pub fn simd_find_first_difference_with_level(
+    level: SimdLevel,
+    a: &[u8],
+    b: &[u8],
+) -> Option<usize> {
+    first_difference_with(SimdDispatch::for_level(level).compare, a, b)
+}
+
+#[inline]
+fn first_difference_with(kernel: SimdLevel, a: &[u8], b: &[u8]) -> Option<usize> {
     let min_len = a.len().min(b.len());
 
     if min_len == 0 {
         return if a.len() == b.len() { None } else { Some(0) };
     }
 
-    #[cfg(target_arch = "x86_64")]
-    {
-        if min_len >= 32 && has_avx2() {
-            let diff = unsafe { find_first_difference_avx2(a, b, min_len) };
-            if let Some(pos) = diff {
-                return Some(pos);
-            }
-            // Check if lengths differ
-            return if a.len() == b.len() {
-                None
-            } else {
-                Some(min_len)
-            };
-        }
-        if min_len >= 16 && has_sse2() {
-            let diff = unsafe { find_first_difference_sse2(a, b, min_len) };
-            if let Some(pos) = diff {
-                return Some(pos);
-            }
-            return if a.len() == b.len() {
-                None
-            } else {
-                Some(min_len)
-            };
-        }
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    {
-        if min_len >= 16 {
-            let diff = unsafe { find_first_difference_neon(a, b, min_len) };
-            if let Some(pos) = diff {
-                return Some(pos);
-            }
-            return if a.len() == b.len() {
-                None
-            } else {
-                Some(min_len)
-            };
-        }
-    }
-
-    // Scalar fallback
-    for (i, (&byte_a, &byte_b)) in a.iter().zip(b.iter()).enumerate() {
-        if byte_a != byte_b {
-            return Some(i);
-        }
-    }
-
+    // SAFETY: The kernel table only selects instruction sets the CPU has
+    let diff = match kernel {
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2 if min_len >= 32 => unsafe { find_first_difference_avx2(a, b, min_len) },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Sse2 | SimdLevel::Avx2 if min_len >= 16 => unsafe {
+            find_first_difference_sse2(a, b, min_len)
+        },
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon if min_len >= 16 => unsafe { find_first_difference_neon(a, b, min_len) },
+        // Scalar fallback
+        _ => a
+            .iter()
+            .zip(b.iter())
+            .position(|(byte_a, byte_b)| byte_a != byte_b),
+    };
+
+    if diff.is_some() {
+        return diff;
+    }
+
+    // Check if lengths differ
     if a.len() == b.len() {
         None
     } else {
@@ -192,8 +170,8 @@ unsafe fn simd_bytes_equal_avx2(a: &[u8], b: &[u8]) -> bool {
             i += 32;
         }
 
-        // Handle remaining with SSE2 or scalar
-        if i + 16 <= len && has_sse2() {
+        // Handle remaining with SSE2 (implied by AVX2) or scalar
+        if i + 16 <= len {
             let chunk_a = _mm_loadu_si128(a[i..].as_ptr().cast());
             let chunk_b = _mm_loadu_si128(b[i..].as_ptr().cast());
             let cmp = _mm_cmpeq_epi8(chunk_a, chunk_b);
@@ -294,8 +272,8 @@ unsafe fn find_first_difference_avx2(a: &[u8], b: &[u8], min_len: usize) -> Opti
             i += 32;
         }
 
-        // Handle remaining with SSE2 or scalar
-        if i + 16 <= min_len && has_sse2() {
+        // Handle remaining with SSE2 (implied by AVX2) or scalar
+        if i + 16 <= min_len {
             let chunk_a = _mm_loadu_si128(a[i..].as_ptr().cast());
             let chunk_b = _mm_loadu_si128(b[i..].as_ptr().cast());
             let cmp = _mm_cmpeq_epi8(chunk_a, chunk_b);
@@ -741,21 +719,37 @@ mod tests {
     }
 
     // =========================================================================
-    // Feature Detection Tests
+    // Kernel Level Tests
     // =========================================================================
 
     #[cfg(target_arch = "x86_64")]
     #[test]
-    fn test_has_sse2() {
+    fn test_sse2_level_is_supported() {
         // SSE2 is baseline for x86_64
-        assert!(has_sse2());
+        assert!(SimdLevel::Sse2.is_supported());
+        assert_eq!(
+            SimdDispatch::for_level(SimdLevel::Sse2).compare,
+            SimdLevel::Sse2
+        );
     }
 
-    #[cfg(target_arch = "x86_64")]
     #[test]
-    fn test_has_avx2() {
-        // Just check it doesn't panic
-        let _ = has_avx2();
+    fn test_every_level_agrees() {
+        let a: Vec<u8> = (0..100u8).collect();
+        for len in [0usize, 1, 15, 16, 17, 31, 32, 33, 48, 64, 100] {
+            for diff_at in [None, Some(0), Some(len / 2), Some(len.saturating_sub(1))] {
+                let mut b = a[..len].to_vec();
+                if let Some(pos) = diff_at.filter(|_| len > 0) {
+                    b[pos] ^= 0xFF;
+                }
+                let equal = simd_bytes_equal_with_level(SimdLevel::Scalar, &a[..len], &b);
+                let first = simd_find_first_difference_with_level(SimdLevel::Scalar, &a, &b);
+                for level in fionn_core::simd::available_levels() {
+                    assert_eq!(simd_bytes_equal_with_level(level, &a[..len], &b), equal);
+                    assert_eq!(simd_find_first_difference_with_level(level, &a, &b), first);
+                }
+            }
+        }
     }
 
     // =========================================================================
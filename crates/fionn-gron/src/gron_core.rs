@@ -6,6 +6,8 @@
 
 use super::path_builder::PathBuilder;
 use super::simd_utils::escape_json_string;
+use fionn_core::path::PathStyle;
+use fionn_core::value_builder::TypeAnnotation;
 use fionn_core::{DsonError, Result};
 use simd_json::value::tape::Node;
 use std::io::Write;
@@ -24,10 +26,18 @@ pub struct GronOptions {
     pub paths_only: bool,
     /// Output values only (no paths)
     pub values_only: bool,
-    /// Include type annotations in output
+    /// Append each assignment's type as a trailing comment
+    /// (`json.a = 1.0; # float`); ungron reads annotations back
     pub show_types: bool,
     /// Colorized output for terminal
     pub color: bool,
+    /// Emit the JSON stream format (`[["a",0],1]`) instead of
+    /// assignments; colors and compact spacing do not apply
+    pub json: bool,
+    /// Whether JSON stream paths open with `prefix` (default: absent)
+    pub stream_root: StreamRoot,
+    /// Syntax of assignment paths (default: `js`, which uses `prefix` as root)
+    pub path_style: PathStyle,
 }
 
 impl Default for GronOptions {
@@ -40,6 +50,9 @@ impl Default for GronOptions {
             values_only: false,
             show_types: false,
             color: false,
+            json: false,
+            stream_root: StreamRoot::Absent,
+            path_style: PathStyle::Js,
         }
     }
 }
@@ -75,12 +88,129 @@ impl GronOptions {
         self
     }
 
+    /// Set type-annotated output.
+    #[must_use]
+    pub const fn show_types(mut self) -> Self {
+        self.show_types = true;
+        self
+    }
+
     /// Set color mode for terminal output.
     #[must_use]
     pub const fn color(mut self) -> Self {
         self.color = true;
         self
     }
+
+    /// Set JSON stream output (`[["a",0],1]` per line).
+    #[must_use]
+    pub const fn json(mut self) -> Self {
+        self.json = true;
+        self
+    }
+
+    /// Set whether JSON stream paths open with the root prefix.
+    #[must_use]
+    pub const fn stream_root(mut self, root: StreamRoot) -> Self {
+        self.stream_root = root;
+        self
+    }
+
+    /// Set the syntax of assignment paths.
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Path builder rendering paths in this output's syntax.
+    pub(crate) fn path_builder(&self) -> PathBuilder {
+        if self.json {
+            PathBuilder::stream(self.stream_root.prefix(&self.prefix))
+        } else {
+            PathBuilder::with_style(&self.prefix, self.path_style)
+        }
+    }
+}
+
+/// Whether JSON stream paths open with a root component.
+///
+/// Other gron implementations emit root-less paths (`[[],{}]`, `[["a"],1]`),
+/// which is the default. With [`StreamRoot::Prefixed`], gron writes the root
+/// prefix as the first component (`[["json","a"],1]`) and ungron strips it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StreamRoot {
+    /// Paths start below the root
+    #[default]
+    Absent,
+    /// Paths start with the root prefix
+    Prefixed,
+}
+
+impl StreamRoot {
+    /// The root component to write for `prefix`, empty when absent.
+    pub(crate) const fn prefix(self, prefix: &str) -> &str {
+        match self {
+            Self::Absent => "",
+            Self::Prefixed => prefix,
+        }
+    }
+
+    /// Drop the root component from a parsed stream path, if present.
+    pub(crate) fn strip<T>(self, mut components: Vec<T>) -> Vec<T> {
+        if self == Self::Prefixed && !components.is_empty() {
+            components.remove(0);
+        }
+        components
+    }
+}
+
+/// Append one JSON stream line: `[path,value]`, `[path]` or `value`.
+///
+/// `path` is the component list from a stream [`PathBuilder`].
+pub fn push_stream_line(buffer: &mut Vec<u8>, options: &GronOptions, path: &[u8], value: &[u8]) {
+    if options.values_only {
+        buffer.extend_from_slice(value);
+    } else if options.paths_only {
+        buffer.push(b'[');
+        buffer.extend_from_slice(path);
+        buffer.push(b']');
+    } else {
+        buffer.extend_from_slice(b"[[");
+        buffer.extend_from_slice(path);
+        buffer.extend_from_slice(b"],");
+        buffer.extend_from_slice(value);
+        buffer.push(b']');
+    }
+    buffer.push(b'\n');
+}
+
+/// Append an assignment's ` # type` comment when types are shown.
+///
+/// Without an explicit annotation the type is read off the JSON literal.
+pub fn push_type_annotation(
+    buffer: &mut Vec<u8>,
+    options: &GronOptions,
+    value: &[u8],
+    annotation: Option<&TypeAnnotation>,
+) {
+    if !options.show_types {
+        return;
+    }
+    let Some(annotation) = annotation
+        .cloned()
+        .or_else(|| TypeAnnotation::of_literal(value))
+    else {
+        return;
+    };
+    if options.color {
+        buffer.extend_from_slice(COLOR_EQUALS);
+    }
+    buffer.extend_from_slice(if options.compact { b"#" } else { b" # " });
+    buffer.extend_from_slice(annotation.name().as_bytes());
+    if options.color {
+        buffer.extend_from_slice(COLOR_RESET);
+    }
 }
 
 /// Output format for gron.
@@ -116,6 +246,19 @@ pub fn gron_to_writer<W: Write>(
     json: &str,
     options: &GronOptions,
     writer: &mut W,
+) -> Result<usize> {
+    gron_with_path(json, options, options.path_builder(), writer)
+}
+
+/// Convert JSON to gron format below an already-started path.
+///
+/// # Errors
+/// Returns an error if JSON parsing or writing fails.
+pub fn gron_with_path<W: Write>(
+    json: &str,
+    options: &GronOptions,
+    mut path_builder: PathBuilder,
+    writer: &mut W,
 ) -> Result<usize> {
     // Parse JSON using simd-json
     let mut bytes = json.as_bytes().to_vec();
@@ -127,7 +270,6 @@ pub fn gron_to_writer<W: Write>(
         return Ok(0);
     }
 
-    let mut path_builder = PathBuilder::new(&options.prefix);
     let mut output_buf = GronWriter::new(writer, options);
 
     // Traverse the tape
@@ -147,7 +289,7 @@ const COLOR_BRACKET: &[u8] = b"\x1b[90m"; // Gray for {} []
 const COLOR_RESET: &[u8] = b"\x1b[0m"; // Reset
 
 /// Buffered writer for gron output.
-struct GronWriter<'a, W: Write> {
+pub struct GronWriter<'a, W: Write> {
     writer: &'a mut W,
     buffer: Vec<u8>,
     bytes_written: usize,
@@ -155,7 +297,7 @@ struct GronWriter<'a, W: Write> {
 }
 
 impl<'a, W: Write> GronWriter<'a, W> {
-    fn new(writer: &'a mut W, options: &'a GronOptions) -> Self {
+    pub fn new(writer: &'a mut W, options: &'a GronOptions) -> Self {
         Self {
             writer,
             buffer: Vec::with_capacity(65536), // 64KB buffer
@@ -164,8 +306,20 @@ impl<'a, W: Write> GronWriter<'a, W> {
         }
     }
 
-    fn write_line(&mut self, path: &str, value: &[u8]) -> Result<()> {
-        if self.options.values_only {
+    pub fn write_line(&mut self, path: &str, value: &[u8]) -> Result<()> {
+        self.write_annotated_line(path, value, None)
+    }
+
+    /// Write one line, annotated with `annotation` when types are shown.
+    pub fn write_annotated_line(
+        &mut self,
+        path: &str,
+        value: &[u8],
+        annotation: Option<&TypeAnnotation>,
+    ) -> Result<()> {
+        if self.options.json {
+            push_stream_line(&mut self.buffer, self.options, path.as_bytes(), value);
+        } else if self.options.values_only {
             if self.options.color {
                 self.write_colored_value(value);
             } else {
@@ -198,6 +352,7 @@ impl<'a, W: Write> GronWriter<'a, W> {
                 self.buffer.extend_from_slice(value);
                 self.buffer.push(b';');
             }
+            push_type_annotation(&mut self.buffer, self.options, value, annotation);
             self.buffer.push(b'\n');
         } else if self.options.color {
             self.buffer.extend_from_slice(COLOR_PATH);
@@ -210,12 +365,15 @@ impl<'a, W: Write> GronWriter<'a, W> {
             self.buffer.extend_from_slice(COLOR_EQUALS);
             self.buffer.push(b';');
             self.buffer.extend_from_slice(COLOR_RESET);
+            push_type_annotation(&mut self.buffer, self.options, value, annotation);
             self.buffer.push(b'\n');
         } else {
             self.buffer.extend_from_slice(path.as_bytes());
             self.buffer.extend_from_slice(b" = ");
             self.buffer.extend_from_slice(value);
-            self.buffer.extend_from_slice(b";\n");
+            self.buffer.push(b';');
+            push_type_annotation(&mut self.buffer, self.options, value, annotation);
+            self.buffer.push(b'\n');
         }
 
         // Flush if buffer is getting full
@@ -257,7 +415,7 @@ impl<'a, W: Write> GronWriter<'a, W> {
         Ok(())
     }
 
-    fn bytes_written(&mut self) -> usize {
+    pub fn bytes_written(&mut self) -> usize {
         // Flush remaining
         let _ = self.flush();
         self.bytes_written
@@ -507,4 +665,33 @@ mod tests {
         let output = gron(json, &GronOptions::default()).unwrap();
         assert!(output.contains("json.temp = -10;"));
     }
+
+    #[test]
+    fn test_json_stream_output() {
+        let json = r#"{"a": [1, "x"]}"#;
+        let output = gron(json, &GronOptions::default().json()).unwrap();
+        assert_eq!(
+            output,
+            "[[],{}]\n[[\"a\"],[]]\n[[\"a\",0],1]\n[[\"a\",1],\"x\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_json_stream_prefixed_root() {
+        let json = r#"{"a": 1}"#;
+        let options = GronOptions::default()
+            .json()
+            .stream_root(StreamRoot::Prefixed);
+        let output = gron(json, &options).unwrap();
+        assert_eq!(output, "[[\"json\"],{}]\n[[\"json\",\"a\"],1]\n");
+    }
+
+    #[test]
+    fn test_json_stream_paths_and_values_only() {
+        let json = r#"{"a": 1}"#;
+        let paths = gron(json, &GronOptions::default().json().paths_only()).unwrap();
+        assert!(paths.contains("[\"a\"]\n"));
+        let values = gron(json, &GronOptions::default().json().values_only()).unwrap();
+        assert!(values.ends_with("1\n"));
+    }
 }
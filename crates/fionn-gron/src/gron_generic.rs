@@ -20,6 +20,7 @@
 //! ```
 
 use super::GronOptions;
+use super::gron_core::{push_stream_line, push_type_annotation};
 use super::path_builder::PathBuilder;
 use super::simd_utils::escape_json_string;
 use fionn_core::tape_source::{TapeNodeKind, TapeSource, TapeValue};
@@ -86,7 +87,7 @@ pub fn gron_from_tape_to_writer<T: TapeSource, W: Write>(
         return Ok(0);
     }
 
-    let mut path_builder = PathBuilder::new(&options.prefix);
+    let mut path_builder = options.path_builder();
     let mut output_buf = GenericGronWriter::new(writer, options);
 
     // Traverse the tape
@@ -124,7 +125,9 @@ impl<'a, W: Write> GenericGronWriter<'a, W> {
     }
 
     fn write_line(&mut self, path: &str, value: &[u8]) -> Result<()> {
-        if self.options.values_only {
+        if self.options.json {
+            push_stream_line(&mut self.buffer, self.options, path.as_bytes(), value);
+        } else if self.options.values_only {
             if self.options.color {
                 self.write_colored_value(value);
             } else {
@@ -157,6 +160,7 @@ impl<'a, W: Write> GenericGronWriter<'a, W> {
                 self.buffer.extend_from_slice(value);
                 self.buffer.push(b';');
             }
+            push_type_annotation(&mut self.buffer, self.options, value, None);
             self.buffer.push(b'\n');
         } else if self.options.color {
             self.buffer.extend_from_slice(COLOR_PATH);
@@ -169,12 +173,15 @@ impl<'a, W: Write> GenericGronWriter<'a, W> {
             self.buffer.extend_from_slice(COLOR_EQUALS);
             self.buffer.push(b';');
             self.buffer.extend_from_slice(COLOR_RESET);
+            push_type_annotation(&mut self.buffer, self.options, value, None);
             self.buffer.push(b'\n');
         } else {
             self.buffer.extend_from_slice(path.as_bytes());
             self.buffer.extend_from_slice(b" = ");
             self.buffer.extend_from_slice(value);
-            self.buffer.extend_from_slice(b";\n");
+            self.buffer.push(b';');
+            push_type_annotation(&mut self.buffer, self.options, value, None);
+            self.buffer.push(b'\n');
         }
 
         // Flush if buffer is getting full
@@ -873,4 +880,19 @@ mod tests {
         let result = String::from_utf8(output).unwrap();
         assert!(result.contains("json.key = 42;"));
     }
+
+    #[test]
+    fn test_json_stream() {
+        let mut tape = MockTape::new();
+        tape.push(TapeNodeKind::ObjectStart { count: 1 }, None);
+        tape.push(
+            TapeNodeKind::Key,
+            Some(TapeValue::String(Cow::Borrowed("key"))),
+        );
+        tape.push(TapeNodeKind::Value, Some(TapeValue::Int(42)));
+        tape.push(TapeNodeKind::ObjectEnd, None);
+
+        let output = gron_from_tape(&tape, &GronOptions::default().json()).unwrap();
+        assert!(output.contains("[[\"key\"],42]"));
+    }
 }
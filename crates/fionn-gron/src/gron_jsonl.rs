@@ -4,7 +4,9 @@
 //! Process newline-delimited JSON files, transforming each line to gron format
 //! with indexed prefixes.
 
-use super::gron_core::{GronOptions, gron};
+use super::gron_core::{GronOptions, gron_with_path};
+use super::path_builder::PathBuilder;
+use fionn_core::path::PathStyle;
 use fionn_core::{DsonError, Result};
 use fionn_simd::SimdLineSeparator;
 use std::io::{BufRead, Write};
@@ -83,6 +85,13 @@ impl GronJsonlOptions {
         self
     }
 
+    /// Set JSON stream output.
+    #[must_use]
+    pub fn json(mut self) -> Self {
+        self.gron = self.gron.json();
+        self
+    }
+
     /// Format prefix for a given line index.
     fn format_prefix(&self, line_num: usize) -> String {
         match &self.index_format {
@@ -91,6 +100,28 @@ impl GronJsonlOptions {
             IndexFormat::None => self.gron.prefix.clone(),
         }
     }
+
+    /// Path builder positioned at the root of a given line.
+    fn line_path(&self, line_num: usize) -> PathBuilder {
+        if !self.gron.json && self.gron.path_style == PathStyle::Js {
+            return PathBuilder::new(&self.format_prefix(line_num));
+        }
+        let mut path = self.gron.path_builder();
+        match &self.index_format {
+            IndexFormat::Bracketed => path.push_index_raw(line_num),
+            IndexFormat::Dotted => path.push_field(itoa::Buffer::new().format(line_num)),
+            IndexFormat::None => {}
+        }
+        path
+    }
+
+    /// Transform one line under its indexed root.
+    fn gron_line(&self, line: &str, line_num: usize) -> Result<String> {
+        let mut output = Vec::with_capacity(line.len() * 2);
+        gron_with_path(line, &self.gron, self.line_path(line_num), &mut output)?;
+        // Safety: we only write valid UTF-8
+        Ok(unsafe { String::from_utf8_unchecked(output) })
+    }
 }
 
 /// Processing statistics for JSONL.
@@ -166,13 +197,8 @@ pub fn gron_jsonl_to_writer<W: Write>(
 
         stats.lines_processed += 1;
 
-        // Create options with indexed prefix
-        let prefix = options.format_prefix(line_num);
-        let mut gron_opts = options.gron.clone();
-        gron_opts.prefix = prefix;
-
         // Transform line
-        match gron(line_str, &gron_opts) {
+        match options.gron_line(line_str, line_num) {
             Ok(gron_output) => {
                 writer
                     .write_all(gron_output.as_bytes())
@@ -207,11 +233,7 @@ pub fn gron_jsonl_to_writer<W: Write>(
             if !trimmed.is_empty() {
                 stats.lines_processed += 1;
 
-                let prefix = options.format_prefix(line_num);
-                let mut gron_opts = options.gron.clone();
-                gron_opts.prefix = prefix;
-
-                match gron(trimmed, &gron_opts) {
+                match options.gron_line(trimmed, line_num) {
                     Ok(gron_output) => {
                         writer
                             .write_all(gron_output.as_bytes())
@@ -265,11 +287,7 @@ pub fn gron_jsonl_streaming<R: BufRead, W: Write>(
 
         stats.lines_processed += 1;
 
-        let prefix = options.format_prefix(line_num);
-        let mut gron_opts = options.gron.clone();
-        gron_opts.prefix = prefix;
-
-        match gron(trimmed, &gron_opts) {
+        match options.gron_line(trimmed, line_num) {
             Ok(gron_output) => {
                 writer
                     .write_all(gron_output.as_bytes())
@@ -398,6 +416,21 @@ mod tests {
         assert!(output.contains("json[0].a=1;"));
     }
 
+    #[test]
+    fn test_json_stream_output() {
+        let input = b"{\"a\":1}\n{\"b\":2}";
+        let options = GronJsonlOptions::default().json();
+        let output = gron_jsonl(input, &options).unwrap();
+        assert!(output.contains("[[0,\"a\"],1]"));
+        assert!(output.contains("[[1,\"b\"],2]"));
+
+        let dotted = GronJsonlOptions::default()
+            .json()
+            .index_format(IndexFormat::Dotted);
+        let output = gron_jsonl(input, &dotted).unwrap();
+        assert!(output.contains("[[\"1\",\"b\"],2]"));
+    }
+
     #[test]
     fn test_stats() {
         let input = b"{\"a\":1}\n{\"b\":2}\ninvalid\n{\"c\":3}";
@@ -4,9 +4,12 @@
 //! This module provides a parallelized version of gron that processes
 //! large arrays concurrently, providing significant speedup on multi-core systems.
 
+use super::gron_core::StreamRoot;
 use super::path_builder::PathBuilder;
 use super::simd_escape::escape_json_string_simd;
-use fionn_core::{DsonError, Result};
+use fionn_core::Result;
+use fionn_core::path::PathStyle;
+use fionn_tape::DsonTape;
 use rayon::prelude::*;
 use simd_json::value::tape::Node;
 
@@ -19,6 +22,12 @@ pub struct GronParallelOptions {
     pub parallel_threshold: usize,
     /// Number of threads to use (default: rayon default)
     pub num_threads: Option<usize>,
+    /// Emit the JSON stream format (`[["a",0],1]`) instead of assignments
+    pub json: bool,
+    /// Whether JSON stream paths open with `prefix` (default: absent)
+    pub stream_root: StreamRoot,
+    /// Syntax of assignment paths (default: `js`, which uses `prefix` as root)
+    pub path_style: PathStyle,
 }
 
 impl Default for GronParallelOptions {
@@ -27,6 +36,9 @@ impl Default for GronParallelOptions {
             prefix: "json".to_string(),
             parallel_threshold: 1000,
             num_threads: None,
+            json: false,
+            stream_root: StreamRoot::Absent,
+            path_style: PathStyle::Js,
         }
     }
 }
@@ -48,22 +60,42 @@ impl GronParallelOptions {
         self.parallel_threshold = threshold;
         self
     }
+
+    /// Set JSON stream output (`[["a",0],1]` per line).
+    #[must_use]
+    pub const fn json(mut self) -> Self {
+        self.json = true;
+        self
+    }
+
+    /// Set whether JSON stream paths open with the root prefix.
+    #[must_use]
+    pub const fn stream_root(mut self, root: StreamRoot) -> Self {
+        self.stream_root = root;
+        self
+    }
+
+    /// Set the syntax of assignment paths.
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
 }
 
 /// Convert JSON to gron format using parallel processing for large arrays.
 ///
 /// This function uses rayon to parallelize processing of arrays that exceed
 /// the parallel threshold, providing significant speedup for large files.
+/// The tape itself is built with [`DsonTape::parse_parallel`], so a single
+/// huge document is also indexed on all cores.
 ///
 /// # Errors
 /// Returns an error if JSON parsing fails.
 pub fn gron_parallel(json: &str, options: &GronParallelOptions) -> Result<String> {
-    // Parse JSON using simd-json
-    let mut bytes = json.as_bytes().to_vec();
-    let tape = simd_json::to_tape(&mut bytes)
-        .map_err(|e| DsonError::ParseError(format!("JSON parse error: {e}")))?;
+    let tape = DsonTape::parse_parallel(json)?;
 
-    let nodes = &tape.0;
+    let nodes = tape.nodes();
     if nodes.is_empty() {
         return Ok(String::new());
     }
@@ -72,7 +104,11 @@ pub fn gron_parallel(json: &str, options: &GronParallelOptions) -> Result<String
     let estimated_size = json.len() * 3;
     let mut output = Vec::with_capacity(estimated_size);
 
-    let mut path_builder = PathBuilder::new(&options.prefix);
+    let mut path_builder = if options.json {
+        PathBuilder::stream(options.stream_root.prefix(&options.prefix))
+    } else {
+        PathBuilder::with_style(&options.prefix, options.path_style)
+    };
 
     // Traverse with parallel processing for large arrays
     traverse_parallel(nodes, 0, &mut path_builder, &mut output, options)?;
@@ -98,7 +134,7 @@ fn traverse_parallel(
     match node {
         Node::Object { len, count: _ } => {
             // Output object initialization
-            write_line(out, path.current_path(), b"{}");
+            write_line(out, path, b"{}");
 
             let mut idx = index + 1;
             for _ in 0..*len {
@@ -128,14 +164,14 @@ fn traverse_parallel(
 
         Node::Array { len, count: _ } => {
             // Output array initialization
-            write_line(out, path.current_path(), b"[]");
+            write_line(out, path, b"[]");
 
             let array_len = *len;
 
             // Check if array is large enough to parallelize
             if array_len >= options.parallel_threshold {
                 // Parallel processing for large arrays
-                let current_path = path.current_path().to_string();
+                let base_path = path.clone();
 
                 // Collect array element indices
                 let mut element_indices = Vec::with_capacity(array_len);
@@ -152,8 +188,8 @@ fn traverse_parallel(
                 let results: Vec<Vec<u8>> = element_indices
                     .par_iter()
                     .map(|(i, elem_idx)| {
-                        let mut local_path = PathBuilder::new(&current_path);
-                        // Don't add the root again, just the index
+                        // Continue from the array's path, adding just the index
+                        let mut local_path = base_path.clone();
                         local_path.push_index_raw(*i);
 
                         let mut local_out = Vec::with_capacity(1024);
@@ -188,7 +224,7 @@ fn traverse_parallel(
         Node::String(s) => {
             let mut value_buf = Vec::with_capacity(s.len() + 2);
             escape_json_string_simd(s, &mut value_buf);
-            write_line(out, path.current_path(), &value_buf);
+            write_line(out, path, &value_buf);
             Ok(index + 1)
         }
 
@@ -201,23 +237,23 @@ fn traverse_parallel(
                 StaticNode::I64(n) => {
                     let mut buf = itoa::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return Ok(index + 1);
                 }
                 StaticNode::U64(n) => {
                     let mut buf = itoa::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return Ok(index + 1);
                 }
                 StaticNode::F64(n) => {
                     let mut buf = ryu::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return Ok(index + 1);
                 }
             };
-            write_line(out, path.current_path(), value);
+            write_line(out, path, value);
             Ok(index + 1)
         }
     }
@@ -238,7 +274,7 @@ fn traverse_sequential(
 
     match node {
         Node::Object { len, count: _ } => {
-            write_line(out, path.current_path(), b"{}");
+            write_line(out, path, b"{}");
 
             let mut idx = index + 1;
             for _ in 0..*len {
@@ -261,7 +297,7 @@ fn traverse_sequential(
         }
 
         Node::Array { len, count: _ } => {
-            write_line(out, path.current_path(), b"[]");
+            write_line(out, path, b"[]");
 
             let mut idx = index + 1;
             for i in 0..*len {
@@ -280,7 +316,7 @@ fn traverse_sequential(
         Node::String(s) => {
             let mut value_buf = Vec::with_capacity(s.len() + 2);
             escape_json_string_simd(s, &mut value_buf);
-            write_line(out, path.current_path(), &value_buf);
+            write_line(out, path, &value_buf);
             index + 1
         }
 
@@ -293,23 +329,23 @@ fn traverse_sequential(
                 StaticNode::I64(n) => {
                     let mut buf = itoa::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return index + 1;
                 }
                 StaticNode::U64(n) => {
                     let mut buf = itoa::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return index + 1;
                 }
                 StaticNode::F64(n) => {
                     let mut buf = ryu::Buffer::new();
                     let s = buf.format(*n);
-                    write_line(out, path.current_path(), s.as_bytes());
+                    write_line(out, path, s.as_bytes());
                     return index + 1;
                 }
             };
-            write_line(out, path.current_path(), value);
+            write_line(out, path, value);
             index + 1
         }
     }
@@ -351,16 +387,25 @@ fn skip_value(nodes: &[Node<'_>], index: usize) -> usize {
 
 /// Write a gron line to the output buffer.
 #[inline]
-fn write_line(out: &mut Vec<u8>, path: &str, value: &[u8]) {
-    out.extend_from_slice(path.as_bytes());
-    out.extend_from_slice(b" = ");
-    out.extend_from_slice(value);
-    out.extend_from_slice(b";\n");
+fn write_line(out: &mut Vec<u8>, path: &PathBuilder, value: &[u8]) {
+    if path.is_stream() {
+        out.extend_from_slice(b"[[");
+        out.extend_from_slice(path.current_path_bytes());
+        out.extend_from_slice(b"],");
+        out.extend_from_slice(value);
+        out.extend_from_slice(b"]\n");
+    } else {
+        out.extend_from_slice(path.current_path_bytes());
+        out.extend_from_slice(b" = ");
+        out.extend_from_slice(value);
+        out.extend_from_slice(b";\n");
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{GronOptions, gron};
 
     // =========================================================================
     // GronParallelOptions Tests
@@ -704,4 +749,19 @@ mod tests {
             assert!(output.contains(&expected));
         }
     }
+
+    #[test]
+    fn test_parallel_json_stream_matches_sequential() {
+        let items: Vec<String> = (0..50).map(|i| format!(r#"{{"id":{i}}}"#)).collect();
+        let json = format!("[{}]", items.join(","));
+
+        let parallel = gron_parallel(
+            &json,
+            &GronParallelOptions::default().with_threshold(10).json(),
+        )
+        .unwrap();
+        let sequential = gron(&json, &GronOptions::default().json()).unwrap();
+        assert_eq!(parallel, sequential);
+        assert!(parallel.contains("[[49,\"id\"],49]"));
+    }
 }
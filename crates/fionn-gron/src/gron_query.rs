@@ -3,10 +3,11 @@
 //!
 //! Applies a query filter during gron traversal for efficient filtered output.
 
-use super::gron_core::GronOptions;
+use super::gron_core::{GronOptions, push_type_annotation};
 use super::path_builder::PathBuilder;
 use super::query::{MatchPotential, Query};
 use super::simd_utils::escape_json_string;
+use fionn_core::path::{PathComponent, PathStyle};
 use fionn_core::{DsonError, Result};
 use simd_json::value::tape::Node;
 use std::io::Write;
@@ -83,7 +84,7 @@ pub fn gron_query_to_writer<W: Write>(
 }
 
 /// Writer that filters output based on query.
-struct QueryGronWriter<'a, W: Write> {
+pub struct QueryGronWriter<'a, W: Write> {
     writer: &'a mut W,
     buffer: Vec<u8>,
     bytes_written: usize,
@@ -94,7 +95,7 @@ struct QueryGronWriter<'a, W: Write> {
 }
 
 impl<'a, W: Write> QueryGronWriter<'a, W> {
-    fn new(
+    pub fn new(
         writer: &'a mut W,
         options: &'a GronOptions,
         query: &'a Query,
@@ -111,18 +112,34 @@ impl<'a, W: Write> QueryGronWriter<'a, W> {
         }
     }
 
-    const fn should_stop(&self) -> bool {
+    pub const fn should_stop(&self) -> bool {
         self.max_matches > 0 && self.match_count >= self.max_matches
     }
 
-    fn write_if_matches(&mut self, path: &str, value: &[u8]) -> Result<bool> {
+    pub fn write_if_matches(&mut self, path: &str, value: &[u8]) -> Result<bool> {
         if self.should_stop() {
             return Ok(false);
         }
 
         if self.query.matches(path) {
             self.match_count += 1;
-            self.write_line(path, value)?;
+            // Queries match `js` paths; other styles are rewritten on output
+            if self.options.path_style == PathStyle::Js {
+                self.write_line(path, value)?;
+            } else {
+                let components = PathStyle::Js
+                    .parse(path)
+                    .map_err(|e| DsonError::InvalidField(e.to_string()))?;
+                // Rendered through a builder so pointer line breaks are quoted
+                let mut styled = PathBuilder::with_style("", self.options.path_style);
+                for component in &components {
+                    match component {
+                        PathComponent::Field(name) => styled.push_field(name),
+                        PathComponent::ArrayIndex(index) => styled.push_index(*index),
+                    }
+                }
+                self.write_line(styled.current_path(), value)?;
+            }
             Ok(true)
         } else {
             Ok(false)
@@ -132,21 +149,17 @@ impl<'a, W: Write> QueryGronWriter<'a, W> {
     fn write_line(&mut self, path: &str, value: &[u8]) -> Result<()> {
         if self.options.values_only {
             self.buffer.extend_from_slice(value);
-            self.buffer.push(b'\n');
         } else if self.options.paths_only {
             self.buffer.extend_from_slice(path.as_bytes());
-            self.buffer.push(b'\n');
-        } else if self.options.compact {
-            self.buffer.extend_from_slice(path.as_bytes());
-            self.buffer.push(b'=');
-            self.buffer.extend_from_slice(value);
-            self.buffer.extend_from_slice(b";\n");
         } else {
+            let equals: &[u8] = if self.options.compact { b"=" } else { b" = " };
             self.buffer.extend_from_slice(path.as_bytes());
-            self.buffer.extend_from_slice(b" = ");
+            self.buffer.extend_from_slice(equals);
             self.buffer.extend_from_slice(value);
-            self.buffer.extend_from_slice(b";\n");
+            self.buffer.push(b';');
+            push_type_annotation(&mut self.buffer, self.options, value, None);
         }
+        self.buffer.push(b'\n');
 
         if self.buffer.len() >= 60000 {
             self.flush()?;
@@ -166,7 +179,7 @@ impl<'a, W: Write> QueryGronWriter<'a, W> {
         Ok(())
     }
 
-    fn bytes_written(&mut self) -> usize {
+    pub fn bytes_written(&mut self) -> usize {
         let _ = self.flush();
         self.bytes_written
     }
@@ -0,0 +1,580 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Tape-free streaming gron for documents larger than memory.
+//!
+//! [`gron`](crate::gron) and the other entry points build a full tape before
+//! writing anything, so memory grows with the document. This module reads
+//! JSON incrementally from a [`BufRead`] instead, keeping only the current
+//! [`PathBuilder`] stack and the value being written: memory is bounded by
+//! nesting depth and the longest string, however large the document is.
+//!
+//! With a [`Query`], subtrees that cannot match are skipped with
+//! [`ResumableSkip`] without being tokenized, and reading stops once
+//! `max_matches` is reached.
+
+use super::gron_core::{GronOptions, GronWriter};
+use super::gron_query::{GronQueryOptions, QueryGronWriter};
+use super::path_builder::PathBuilder;
+use super::query::{MatchPotential, Query};
+use super::simd_utils::escape_json_string;
+use fionn_core::{DsonError, Result};
+use fionn_simd::ResumableSkip;
+use fionn_simd::unescape::unescape_json_string_simd;
+use std::io::{BufRead, Write};
+
+/// Destination for values found while streaming.
+trait Sink {
+    /// Write `value` at `path`; `container` marks `{}`/`[]` initializers.
+    fn write(&mut self, path: &str, value: &[u8], container: bool) -> Result<()>;
+
+    /// Whether nothing at or below `path` can be written.
+    fn prune(&self, _path: &str) -> bool {
+        false
+    }
+
+    /// Whether no further output is wanted.
+    fn done(&self) -> bool {
+        false
+    }
+}
+
+impl<W: Write> Sink for GronWriter<'_, W> {
+    fn write(&mut self, path: &str, value: &[u8], _container: bool) -> Result<()> {
+        self.write_line(path, value)
+    }
+}
+
+/// Query filter over a [`QueryGronWriter`].
+struct QuerySink<'a, W: Write> {
+    out: QueryGronWriter<'a, W>,
+    query: &'a Query,
+    include_containers: bool,
+}
+
+impl<W: Write> Sink for QuerySink<'_, W> {
+    fn write(&mut self, path: &str, value: &[u8], container: bool) -> Result<()> {
+        if !container || self.include_containers {
+            self.out.write_if_matches(path, value)?;
+        }
+        Ok(())
+    }
+
+    fn prune(&self, path: &str) -> bool {
+        !self.query.has_recursive() && self.query.match_potential(path) == MatchPotential::NoMatch
+    }
+
+    fn done(&self) -> bool {
+        self.out.should_stop()
+    }
+}
+
+/// Container being streamed.
+enum Frame {
+    Object,
+    /// Index of the current element
+    Array(usize),
+}
+
+/// Incremental JSON reader over a buffered source.
+struct StreamReader<R: BufRead> {
+    reader: R,
+    /// Bytes consumed so far, for error positions
+    offset: u64,
+    /// Raw bytes of the string or scalar being read
+    scratch: Vec<u8>,
+    /// The sink asked for no more output
+    stopped: bool,
+}
+
+impl<R: BufRead> StreamReader<R> {
+    const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            scratch: Vec::new(),
+            stopped: false,
+        }
+    }
+
+    fn error(&self, message: &str) -> DsonError {
+        DsonError::ParseError(format!(
+            "JSON parse error at byte {}: {message}",
+            self.offset
+        ))
+    }
+
+    fn fill(&mut self) -> Result<&[u8]> {
+        self.reader.fill_buf().map_err(DsonError::IoError)
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.reader.consume(len);
+        self.offset += len as u64;
+    }
+
+    /// Skip whitespace and return the next byte without consuming it.
+    fn peek(&mut self) -> Result<Option<u8>> {
+        loop {
+            let chunk = self.fill()?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let len = chunk.len();
+            if let Some(pos) = chunk
+                .iter()
+                .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+            {
+                let byte = chunk[pos];
+                self.consume(pos);
+                return Ok(Some(byte));
+            }
+            self.consume(len);
+        }
+    }
+
+    /// Consume `byte`, after optional whitespace.
+    fn expect(&mut self, byte: u8, message: &str) -> Result<()> {
+        if self.peek()? == Some(byte) {
+            self.consume(1);
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Skip one value with a resumable skipper.
+    fn skip(&mut self, mut skip: ResumableSkip) -> Result<()> {
+        let len = skip
+            .skip_reader(&mut self.reader)
+            .map_err(|e| DsonError::ParseError(format!("JSON parse error: {e}")))?;
+        self.offset += len;
+        Ok(())
+    }
+
+    /// Read a string body (opening quote consumed) and unescape it.
+    fn read_string(&mut self) -> Result<String> {
+        self.scratch.clear();
+        let mut escaped = false;
+        loop {
+            let chunk = self.reader.fill_buf().map_err(DsonError::IoError)?;
+            if chunk.is_empty() {
+                return Err(self.error("unterminated string"));
+            }
+            let mut pos = 0;
+            if escaped {
+                pos = 1;
+                escaped = false;
+            }
+            let end = loop {
+                match memchr::memchr2(b'"', b'\\', &chunk[pos..]) {
+                    Some(rel) if chunk[pos + rel] == b'"' => break Some(pos + rel),
+                    Some(rel) if pos + rel + 1 < chunk.len() => pos += rel + 2,
+                    Some(_) => {
+                        escaped = true;
+                        break None;
+                    }
+                    None => break None,
+                }
+            };
+            let len = chunk.len();
+            if let Some(end) = end {
+                self.scratch.extend_from_slice(&chunk[..end]);
+                self.consume(end + 1);
+                break;
+            }
+            self.scratch.extend_from_slice(chunk);
+            self.consume(len);
+        }
+
+        let bytes = if self.scratch.contains(&b'\\') {
+            unescape_json_string_simd(&self.scratch).map_err(|e| self.error(&e.to_string()))?
+        } else {
+            std::mem::take(&mut self.scratch)
+        };
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    /// Read a literal or number and render it as the tape-based gron would.
+    fn read_scalar(&mut self, out: &mut Vec<u8>) -> Result<()> {
+        self.scratch.clear();
+        loop {
+            let chunk = self.reader.fill_buf().map_err(DsonError::IoError)?;
+            if chunk.is_empty() {
+                break;
+            }
+            let len = chunk.len();
+            let end = chunk
+                .iter()
+                .position(|b| matches!(b, b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r'));
+            let take = end.unwrap_or(len);
+            self.scratch.extend_from_slice(&chunk[..take]);
+            self.consume(take);
+            if end.is_some() {
+                break;
+            }
+        }
+
+        match self.scratch.as_slice() {
+            token @ (b"true" | b"false" | b"null") => out.extend_from_slice(token),
+            token => {
+                let text = std::str::from_utf8(token).map_err(|_| self.error("invalid value"))?;
+                if !is_json_number(text) {
+                    return Err(self.error(&format!("invalid value '{text}'")));
+                }
+                if let Ok(n) = text.parse::<i64>() {
+                    out.extend_from_slice(itoa::Buffer::new().format(n).as_bytes());
+                } else if let Ok(n) = text.parse::<u64>() {
+                    out.extend_from_slice(itoa::Buffer::new().format(n).as_bytes());
+                } else {
+                    let n: f64 = text.parse().map_err(|_| self.error("invalid number"))?;
+                    out.extend_from_slice(ryu::Buffer::new().format(n).as_bytes());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a field name and its `:`, and push it onto `path`.
+    fn field(&mut self, path: &mut PathBuilder) -> Result<()> {
+        self.expect(b'"', "expected a field name")?;
+        let key = self.read_string()?;
+        self.expect(b':', "expected ':'")?;
+        path.push_field(&key);
+        Ok(())
+    }
+
+    /// Stream one value at `path` into `sink`.
+    ///
+    /// Open containers are kept on an explicit stack, so nesting depth is
+    /// limited by memory rather than by the call stack.
+    fn value<S: Sink>(&mut self, path: &mut PathBuilder, sink: &mut S) -> Result<()> {
+        let mut stack = Vec::new();
+        'values: loop {
+            if sink.done() {
+                self.stopped = true;
+                return Ok(());
+            }
+            let first = self
+                .peek()?
+                .ok_or_else(|| self.error("unexpected end of input"))?;
+            if sink.prune(path.current_path()) {
+                self.skip(ResumableSkip::value())?;
+            } else {
+                match first {
+                    b'{' => {
+                        self.consume(1);
+                        sink.write(path.current_path(), b"{}", true)?;
+                        if self.peek()? == Some(b'}') {
+                            self.consume(1);
+                        } else {
+                            self.field(path)?;
+                            stack.push(Frame::Object);
+                            continue;
+                        }
+                    }
+                    b'[' => {
+                        self.consume(1);
+                        sink.write(path.current_path(), b"[]", true)?;
+                        if self.peek()? == Some(b']') {
+                            self.consume(1);
+                        } else {
+                            path.push_index(0);
+                            stack.push(Frame::Array(0));
+                            continue;
+                        }
+                    }
+                    b'"' => {
+                        self.consume(1);
+                        let text = self.read_string()?;
+                        let mut value = Vec::with_capacity(text.len() + 2);
+                        escape_json_string(&text, &mut value);
+                        sink.write(path.current_path(), &value, false)?;
+                    }
+                    _ => {
+                        let mut value = Vec::with_capacity(24);
+                        self.read_scalar(&mut value)?;
+                        sink.write(path.current_path(), &value, false)?;
+                    }
+                }
+            }
+
+            // A value is complete: move to the next member or close containers
+            while let Some(frame) = stack.last_mut() {
+                path.pop();
+                match (frame, self.peek()?) {
+                    (Frame::Object, Some(b',')) => {
+                        self.consume(1);
+                        self.field(path)?;
+                        continue 'values;
+                    }
+                    (Frame::Array(index), Some(b',')) => {
+                        self.consume(1);
+                        *index += 1;
+                        path.push_index(*index);
+                        continue 'values;
+                    }
+                    (Frame::Object, Some(b'}')) | (Frame::Array(_), Some(b']')) => {
+                        self.consume(1);
+                        stack.pop();
+                    }
+                    (Frame::Object, _) => return Err(self.error("expected ',' or '}'")),
+                    (Frame::Array(_), _) => return Err(self.error("expected ',' or ']'")),
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    /// Stream the whole document, rejecting trailing content.
+    fn document<S: Sink>(&mut self, mut path: PathBuilder, sink: &mut S) -> Result<()> {
+        if self.peek()?.is_none() {
+            return Ok(());
+        }
+        self.value(&mut path, sink)?;
+        if !self.stopped && self.peek()?.is_some() {
+            return Err(self.error("trailing characters after document"));
+        }
+        Ok(())
+    }
+}
+
+/// Check the RFC 8259 number grammar.
+fn is_json_number(text: &str) -> bool {
+    let bytes = text.strip_prefix('-').unwrap_or(text).as_bytes();
+    let int_len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    if int_len == 0 || (int_len > 1 && bytes[0] == b'0') {
+        return false;
+    }
+    let mut rest = &bytes[int_len..];
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+    if let Some(exponent) = rest.strip_prefix(b"e").or_else(|| rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or_else(|| exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+        let len = exponent.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return false;
+        }
+        rest = &exponent[len..];
+    }
+    rest.is_empty()
+}
+
+/// Convert a JSON document read incrementally to gron format.
+///
+/// Output matches [`gron_to_writer`](crate::gron_to_writer), but the
+/// document is never held in memory.
+///
+/// # Errors
+/// Returns an error if reading fails, the JSON is malformed, or writing fails.
+///
+/// # Example
+/// ```rust,ignore
+/// let file = std::io::BufReader::new(std::fs::File::open("huge.json")?);
+/// gron_streaming(file, &GronOptions::default(), &mut std::io::stdout().lock())?;
+/// ```
+pub fn gron_streaming<R: BufRead, W: Write>(
+    reader: R,
+    options: &GronOptions,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut out = GronWriter::new(writer, options);
+    StreamReader::new(reader).document(options.path_builder(), &mut out)?;
+    Ok(out.bytes_written())
+}
+
+/// Convert a JSON document read incrementally to query-filtered gron format.
+///
+/// Subtrees that cannot match the query are skipped unparsed, so memory
+/// stays constant even when the matches are spread through a huge document.
+///
+/// # Errors
+/// Returns an error if reading fails, the JSON is malformed, or writing fails.
+pub fn gron_query_streaming<R: BufRead, W: Write>(
+    reader: R,
+    query: &Query,
+    options: &GronQueryOptions,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut sink = QuerySink {
+        out: QueryGronWriter::new(writer, &options.gron, query, options.max_matches),
+        query,
+        include_containers: options.include_containers,
+    };
+    StreamReader::new(reader).document(PathBuilder::new(&options.gron.prefix), &mut sink)?;
+    Ok(sink.out.bytes_written())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gron, gron_query};
+    use fionn_core::path::PathStyle;
+    use std::io::BufReader;
+
+    fn streamed(json: &str, options: &GronOptions) -> Result<String> {
+        let mut output = Vec::new();
+        gron_streaming(json.as_bytes(), options, &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    /// Reader handing out a few bytes at a time, to split every token
+    fn tiny_chunks(json: &str) -> BufReader<&[u8]> {
+        BufReader::with_capacity(3, json.as_bytes())
+    }
+
+    const SAMPLE: &str = r#" {"name": "Al\"iceé", "age": 30, "pi": 3.5, "big": 1e3,
+        "neg": -7, "huge": 18446744073709551615, "ok": true, "none": null,
+        "tags": ["a", [], {}, [1, [2]]], "a b": {"c.d": "\\"}} "#;
+
+    // =========================================================================
+    // Equivalence with tape-based gron
+    // =========================================================================
+
+    #[test]
+    fn test_matches_tape_gron() {
+        let options = GronOptions::default();
+        assert_eq!(
+            streamed(SAMPLE, &options).unwrap(),
+            gron(SAMPLE, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_matches_tape_gron_with_options() {
+        for options in [
+            GronOptions::default().compact(),
+            GronOptions::default().json(),
+            GronOptions::with_prefix("doc").paths_only(),
+            GronOptions::default().path_style(PathStyle::Pointer),
+        ] {
+            assert_eq!(
+                streamed(SAMPLE, &options).unwrap(),
+                gron(SAMPLE, &options).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_tiny_read_buffer() {
+        let options = GronOptions::default();
+        let mut output = Vec::new();
+        gron_streaming(tiny_chunks(SAMPLE), &options, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            gron(SAMPLE, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_scalar_root_and_empty_input() {
+        let options = GronOptions::default();
+        assert_eq!(streamed("42", &options).unwrap(), "json = 42;\n");
+        assert_eq!(streamed(" \n", &options).unwrap(), "");
+    }
+
+    #[test]
+    fn test_deep_nesting_does_not_grow_the_call_stack() {
+        const DEPTH: usize = 10_000;
+        // A small stack that recursing once per level would overflow
+        let handle = std::thread::Builder::new()
+            .stack_size(256 * 1024)
+            .spawn(|| {
+                let options = GronOptions::default();
+                let nested = format!("{}{}", "[".repeat(DEPTH), "]".repeat(DEPTH));
+                let mut sink = std::io::sink();
+                gron_streaming(nested.as_bytes(), &options, &mut sink).unwrap();
+
+                let unclosed = "[".repeat(DEPTH);
+                let err = gron_streaming(unclosed.as_bytes(), &options, &mut sink).unwrap_err();
+                assert!(err.to_string().contains("unexpected end of input"));
+            })
+            .unwrap();
+        handle.join().unwrap();
+    }
+
+    // =========================================================================
+    // Query pruning
+    // =========================================================================
+
+    #[test]
+    fn test_query_matches_tape_query() {
+        let json = r#"{"items": [{"id": 1, "skip": {"deep": [1, 2]}}, {"id": 2}], "other": [3]}"#;
+        let query = Query::parse(".items[*].id").unwrap();
+        let options = GronQueryOptions::default();
+
+        let mut output = Vec::new();
+        gron_query_streaming(tiny_chunks(json), &query, &options, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output, gron_query(json, &query, &options).unwrap());
+        assert_eq!(output, "json.items[0].id = 1;\njson.items[1].id = 2;\n");
+    }
+
+    #[test]
+    fn test_query_skips_pruned_subtrees_unparsed() {
+        // The pruned value is not valid gron input, only balanced JSON
+        let json = r#"{"other": {"x": [tru, 1.2.3]}, "id": 5}"#;
+        let query = Query::parse(".id").unwrap();
+        let mut output = Vec::new();
+        gron_query_streaming(
+            json.as_bytes(),
+            &query,
+            &GronQueryOptions::default(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "json.id = 5;\n");
+    }
+
+    #[test]
+    fn test_query_stops_at_max_matches() {
+        let json = r#"{"items": [{"id": 1}, {"id": 2}, {"id": 3}"#;
+        let query = Query::parse(".items[*].id").unwrap();
+        let options = GronQueryOptions::default().max_matches(2);
+        let mut output = Vec::new();
+        // The truncated tail is never read
+        gron_query_streaming(json.as_bytes(), &query, &options, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "json.items[0].id = 1;\njson.items[1].id = 2;\n"
+        );
+    }
+
+    // =========================================================================
+    // Errors
+    // =========================================================================
+
+    #[test]
+    fn test_malformed_input() {
+        let options = GronOptions::default();
+        for json in [
+            r#"{"a": 1"#,
+            r#"{"a" 1}"#,
+            "{a: 1}",
+            "[1 2]",
+            r#""unterminated"#,
+            "tru",
+            "01",
+            "1.",
+            "[1] [2]",
+        ] {
+            assert!(streamed(json, &options).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn test_number_grammar() {
+        for valid in ["0", "-0", "12", "1.5", "1e9", "-2.5E-3", "1E+2"] {
+            assert!(is_json_number(valid), "{valid}");
+        }
+        for invalid in ["", "-", "01", "1.", ".5", "1e", "+1", "0x10", "1e+"] {
+            assert!(!is_json_number(invalid), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,354 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Typed gron from format-native values.
+//!
+//! Converting TOML or YAML to JSON before gron loses what JSON literals
+//! cannot express: a TOML date-time becomes a plain string and a YAML tag
+//! disappears. [`gron_typed`] walks the native value instead, through
+//! [`TypedValue`], which reports each value's [`NodeKind`] (a TOML date-time
+//! is `FormatSpecificKind::TomlDatetime`, a YAML tag
+//! `FormatSpecificKind::YamlTag`). With [`GronOptions::show_types`] the
+//! annotation derived from that kind is written as a trailing comment:
+//!
+//! ```text
+//! json.created = "1979-05-27T07:32:00Z"; # datetime
+//! json.ratio = 1.0; # float
+//! json.color = "red"; # !rgb
+//! ```
+//!
+//! [`ungron_with_builder`](crate::ungron_with_builder) reads the annotations
+//! back, so gron → edit → ungron through a `TomlBuilder` or `YamlBuilder`
+//! restores the original types. An annotation that contradicts its value,
+//! such as `"not a date"` annotated `datetime`, is an error there.
+//!
+//! `FormatSpecificKind::YamlAnchor` and `FormatSpecificKind::YamlAlias`
+//! have no annotation: `serde_yaml` expands every alias while parsing, so
+//! typed values never contain them. The gron output repeats the anchored
+//! content at each alias and a `YamlBuilder` writes independent copies back.
+
+use super::gron_core::{GronOptions, GronWriter};
+use super::path_builder::PathBuilder;
+use super::simd_utils::escape_json_string;
+use fionn_core::Result;
+#[cfg(feature = "toml")]
+use fionn_core::format::FormatSpecificKind;
+use fionn_core::format::NodeKind;
+use fionn_core::value_builder::TypeAnnotation;
+use std::borrow::Cow;
+use std::io::Write;
+
+/// One level of a typed value, as seen by [`gron_typed`].
+#[derive(Debug)]
+pub enum TypedNode<'a, V> {
+    /// Scalar written as a JSON literal (`null`, `true`, `42`, `1.5`)
+    Literal(String, NodeKind),
+    /// Scalar written as a JSON string
+    String(Cow<'a, str>, NodeKind),
+    /// Object/mapping fields in document order
+    Object(Vec<(Cow<'a, str>, &'a V)>),
+    /// Array/sequence items
+    Array(Vec<&'a V>),
+    /// Value carrying a YAML tag (without the leading `!`)
+    Tagged(Cow<'a, str>, &'a V),
+}
+
+/// A value that can describe its own type for annotated gron.
+pub trait TypedValue: Sized {
+    /// Describe this value's top level.
+    fn typed_node(&self) -> TypedNode<'_, Self>;
+}
+
+/// Render a float as gron does, or as a string for non-finite values.
+fn float_node<'a, V>(value: f64) -> TypedNode<'a, V> {
+    if value.is_finite() {
+        TypedNode::Literal(
+            ryu::Buffer::new().format_finite(value).to_string(),
+            NodeKind::Number,
+        )
+    } else {
+        TypedNode::String(Cow::Owned(value.to_string()), NodeKind::Number)
+    }
+}
+
+impl TypedValue for serde_json::Value {
+    fn typed_node(&self) -> TypedNode<'_, Self> {
+        match self {
+            Self::Null => TypedNode::Literal("null".to_string(), NodeKind::Null),
+            Self::Bool(b) => TypedNode::Literal(b.to_string(), NodeKind::Boolean),
+            Self::Number(n) => match n.as_f64() {
+                Some(f) if n.is_f64() => float_node(f),
+                _ => TypedNode::Literal(n.to_string(), NodeKind::Number),
+            },
+            Self::String(s) => TypedNode::String(Cow::Borrowed(s), NodeKind::String),
+            Self::Array(items) => TypedNode::Array(items.iter().collect()),
+            Self::Object(map) => TypedNode::Object(
+                map.iter()
+                    .map(|(key, value)| (Cow::Borrowed(key.as_str()), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl TypedValue for toml::Value {
+    fn typed_node(&self) -> TypedNode<'_, Self> {
+        match self {
+            Self::String(s) => TypedNode::String(Cow::Borrowed(s), NodeKind::String),
+            Self::Integer(i) => {
+                TypedNode::Literal(itoa::Buffer::new().format(*i).to_string(), NodeKind::Number)
+            }
+            Self::Float(f) => float_node(*f),
+            Self::Boolean(b) => TypedNode::Literal(b.to_string(), NodeKind::Boolean),
+            Self::Datetime(dt) => TypedNode::String(
+                Cow::Owned(dt.to_string()),
+                NodeKind::FormatSpecific(FormatSpecificKind::TomlDatetime),
+            ),
+            Self::Array(items) => TypedNode::Array(items.iter().collect()),
+            Self::Table(table) => TypedNode::Object(
+                table
+                    .iter()
+                    .map(|(key, value)| (Cow::Borrowed(key.as_str()), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl TypedValue for serde_yaml::Value {
+    fn typed_node(&self) -> TypedNode<'_, Self> {
+        match self {
+            Self::Null => TypedNode::Literal("null".to_string(), NodeKind::Null),
+            Self::Bool(b) => TypedNode::Literal(b.to_string(), NodeKind::Boolean),
+            Self::Number(n) => match n.as_f64() {
+                Some(f) if n.is_f64() => float_node(f),
+                _ => TypedNode::Literal(n.to_string(), NodeKind::Number),
+            },
+            Self::String(s) => TypedNode::String(Cow::Borrowed(s), NodeKind::String),
+            Self::Sequence(items) => TypedNode::Array(items.iter().collect()),
+            Self::Mapping(map) => TypedNode::Object(
+                map.iter()
+                    .map(|(key, value)| (yaml_key(key), value))
+                    .collect(),
+            ),
+            Self::Tagged(tagged) => {
+                // `Tag` displays with exactly one leading `!`
+                let tag = tagged.tag.to_string();
+                TypedNode::Tagged(Cow::Owned(tag[1..].to_string()), &tagged.value)
+            }
+        }
+    }
+}
+
+/// Field name for a YAML mapping key, which need not be a string.
+#[cfg(feature = "yaml")]
+fn yaml_key(key: &serde_yaml::Value) -> Cow<'_, str> {
+    match key {
+        serde_yaml::Value::String(s) => Cow::Borrowed(s),
+        serde_yaml::Value::Null => Cow::Borrowed("null"),
+        serde_yaml::Value::Bool(b) => Cow::Owned(b.to_string()),
+        serde_yaml::Value::Number(n) => Cow::Owned(n.to_string()),
+        other => Cow::Owned(
+            serde_yaml::to_string(other)
+                .unwrap_or_default()
+                .trim_end()
+                .to_string(),
+        ),
+    }
+}
+
+/// Convert a typed value to gron format.
+///
+/// Without [`GronOptions::show_types`] the output matches [`gron`](crate::gron)
+/// of the value's JSON form.
+///
+/// # Errors
+/// Returns an error if writing fails.
+///
+/// # Example
+/// ```rust,ignore
+/// let config: toml::Value = toml::from_str("created = 1979-05-27T07:32:00Z")?;
+/// let output = gron_typed(&config, &GronOptions::default().show_types())?;
+/// // json = {}; # object
+/// // json.created = "1979-05-27T07:32:00Z"; # datetime
+/// ```
+pub fn gron_typed<V: TypedValue>(value: &V, options: &GronOptions) -> Result<String> {
+    let mut output = Vec::new();
+    gron_typed_to_writer(value, options, &mut output)?;
+    // Safety: we only write valid UTF-8
+    Ok(unsafe { String::from_utf8_unchecked(output) })
+}
+
+/// Convert a typed value to gron format, writing to a writer.
+///
+/// # Errors
+/// Returns an error if writing fails.
+pub fn gron_typed_to_writer<V: TypedValue, W: Write>(
+    value: &V,
+    options: &GronOptions,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut out = GronWriter::new(writer, options);
+    traverse_typed(value, None, &mut options.path_builder(), &mut out)?;
+    Ok(out.bytes_written())
+}
+
+fn traverse_typed<V: TypedValue, W: Write>(
+    value: &V,
+    tag: Option<&str>,
+    path: &mut PathBuilder,
+    out: &mut GronWriter<'_, W>,
+) -> Result<()> {
+    // A tag replaces the base type, which the literal already shows
+    let annotate = |kind, literal: &[u8]| {
+        tag.map_or_else(
+            || TypeAnnotation::from_node_kind(kind, literal),
+            |tag| Some(TypeAnnotation::Tag(tag.to_string())),
+        )
+    };
+
+    match value.typed_node() {
+        TypedNode::Literal(text, kind) => {
+            let annotation = annotate(kind, text.as_bytes());
+            out.write_annotated_line(path.current_path(), text.as_bytes(), annotation.as_ref())
+        }
+        TypedNode::String(text, kind) => {
+            let mut literal = Vec::with_capacity(text.len() + 2);
+            escape_json_string(&text, &mut literal);
+            let annotation = annotate(kind, &literal);
+            out.write_annotated_line(path.current_path(), &literal, annotation.as_ref())
+        }
+        TypedNode::Object(fields) => {
+            let annotation = annotate(NodeKind::Object, b"{}");
+            out.write_annotated_line(path.current_path(), b"{}", annotation.as_ref())?;
+            for (key, field) in fields {
+                path.push_field(&key);
+                traverse_typed(field, None, path, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        TypedNode::Array(items) => {
+            let annotation = annotate(NodeKind::Array, b"[]");
+            out.write_annotated_line(path.current_path(), b"[]", annotation.as_ref())?;
+            for (index, item) in items.into_iter().enumerate() {
+                path.push_index(index);
+                traverse_typed(item, None, path, out)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        TypedNode::Tagged(tag, inner) => traverse_typed(inner, Some(&tag), path, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gron;
+    use serde_json::json;
+
+    // =========================================================================
+    // JSON values
+    // =========================================================================
+
+    #[test]
+    fn test_plain_output_matches_gron() {
+        let value = json!({"name": "Al\"ice", "age": 30, "ratio": 1.0, "tags": ["a", null, true]});
+        let text = value.to_string();
+        for options in [
+            GronOptions::default(),
+            GronOptions::default().compact(),
+            GronOptions::default().json(),
+        ] {
+            assert_eq!(
+                gron_typed(&value, &options).unwrap(),
+                gron(&text, &options).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_show_types() {
+        let value = json!({"n": 1, "f": 1.0, "s": "x", "a": [null, false]});
+        let output = gron_typed(&value, &GronOptions::default().show_types()).unwrap();
+        assert!(output.contains("json = {}; # object\n"));
+        assert!(output.contains("json.n = 1; # int\n"));
+        assert!(output.contains("json.f = 1.0; # float\n"));
+        assert!(output.contains("json.s = \"x\"; # string\n"));
+        assert!(output.contains("json.a = []; # array\n"));
+        assert!(output.contains("json.a[0] = null; # null\n"));
+        assert!(output.contains("json.a[1] = false; # bool\n"));
+    }
+
+    #[test]
+    fn test_show_types_compact() {
+        let value = json!({"n": 1});
+        let output = gron_typed(&value, &GronOptions::default().show_types().compact()).unwrap();
+        assert_eq!(output, "json={};#object\njson.n=1;#int\n");
+    }
+
+    #[test]
+    fn test_show_types_matches_tape_gron() {
+        let text = r#"{"a": [1, 2.5, "x"], "b": {"c": null}}"#;
+        let value: serde_json::Value = serde_json::from_str(text).unwrap();
+        let options = GronOptions::default().show_types();
+        assert_eq!(
+            gron_typed(&value, &options).unwrap(),
+            gron(text, &options).unwrap()
+        );
+    }
+
+    // =========================================================================
+    // TOML values
+    // =========================================================================
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_datetimes() {
+        let value: toml::Value = toml::from_str(
+            "odt = 1979-05-27T07:32:00Z\nldt = 1979-05-27T07:32:00\nld = 1979-05-27\nlt = 07:32:00\n",
+        )
+        .unwrap();
+        let output = gron_typed(&value, &GronOptions::default().show_types()).unwrap();
+        assert!(output.contains("json.odt = \"1979-05-27T07:32:00Z\"; # datetime\n"));
+        assert!(output.contains("json.ldt = \"1979-05-27T07:32:00\"; # local-datetime\n"));
+        assert!(output.contains("json.ld = \"1979-05-27\"; # local-date\n"));
+        assert!(output.contains("json.lt = \"07:32:00\"; # local-time\n"));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_special_floats() {
+        let value: toml::Value = toml::from_str("a = inf\nb = 2.0").unwrap();
+        let output = gron_typed(&value, &GronOptions::default().show_types()).unwrap();
+        assert!(output.contains("json.a = \"inf\"; # float\n"));
+        assert!(output.contains("json.b = 2.0; # float\n"));
+    }
+
+    // =========================================================================
+    // YAML values
+    // =========================================================================
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_tags_and_keys() {
+        let value: serde_yaml::Value =
+            serde_yaml::from_str("color: !rgb red\nitems: !list [1]\n3: three\n").unwrap();
+        let output = gron_typed(&value, &GronOptions::default().show_types()).unwrap();
+        assert!(output.contains("json.color = \"red\"; # !rgb\n"));
+        assert!(output.contains("json.items = []; # !list\n"));
+        assert!(output.contains("json.items[0] = 1; # int\n"));
+        assert!(output.contains("json[\"3\"] = \"three\"; # string\n"));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_aliases_are_expanded() {
+        let value: serde_yaml::Value = serde_yaml::from_str("base: &b {x: 1}\ncopy: *b\n").unwrap();
+        let output = gron_typed(&value, &GronOptions::default().show_types()).unwrap();
+        assert!(output.contains("json.base.x = 1; # int\n"));
+        assert!(output.contains("json.copy.x = 1; # int\n"));
+        assert!(!output.contains('&') && !output.contains('*'));
+    }
+}
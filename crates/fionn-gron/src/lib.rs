@@ -12,6 +12,8 @@
 //! - **Ungron support**: Reconstruct JSON from gron output
 //! - **JSONL support**: Process newline-delimited JSON files
 //! - **Query mode**: Filter output with JSONPath-like queries
+//! - **Streaming mode**: Gron documents larger than memory straight from a reader
+//! - **Typed output**: Annotate assignments with types that survive ungron to TOML/YAML
 //!
 //! ## Example
 //!
@@ -29,12 +31,15 @@
 //! ## JSONL Processing
 //!
 //! ```rust,ignore
-//! use fionn::gron::{gron_jsonl, GronJsonlOptions};
+//! use fionn::gron::{gron_jsonl, ungron_jsonl, GronJsonlOptions, UngronJsonlOptions};
 //!
 //! let jsonl = b"{\"a\":1}\n{\"b\":2}";
 //! let output = gron_jsonl(jsonl, &GronJsonlOptions::default())?;
 //! // json[0].a = 1;
 //! // json[1].b = 2;
+//!
+//! // And back, one record per line
+//! let records = ungron_jsonl(&output, &UngronJsonlOptions::default())?;
 //! ```
 //!
 //! ## Query Mode
@@ -54,34 +59,45 @@ mod gron_generic;
 mod gron_jsonl;
 mod gron_parallel;
 mod gron_query;
+mod gron_stream;
+mod gron_typed;
 mod gron_zerocopy;
 mod path_builder;
 mod path_extended;
 mod query;
 mod simd_escape;
-mod simd_unescape;
 mod simd_utils;
 mod ungron;
 mod ungron_generic;
+mod ungron_jsonl;
 
-pub use gron_core::{GronOptions, GronOutput, gron, gron_to_writer};
+pub use fionn_core::value_builder::TypeAnnotation;
+pub use fionn_simd::unescape::{
+    UnescapeError, unescape_json_string_simd, unescape_json_string_with_level,
+    unescape_json_to_string,
+};
+pub use gron_core::{GronOptions, GronOutput, StreamRoot, gron, gron_to_writer};
 pub use gron_jsonl::{
     ErrorMode, GronJsonlOptions, IndexFormat, JsonlStats, gron_jsonl, gron_jsonl_streaming,
     gron_jsonl_to_writer,
 };
 pub use gron_parallel::{GronParallelOptions, gron_parallel};
 pub use gron_query::{GronQueryOptions, gron_query, gron_query_to_writer};
+pub use gron_stream::{gron_query_streaming, gron_streaming};
+pub use gron_typed::{TypedNode, TypedValue, gron_typed, gron_typed_to_writer};
 pub use gron_zerocopy::{GronLine, GronOutput as GronOutputZc, gron_zerocopy};
 pub use path_builder::PathBuilder;
 pub use path_extended::{
     ExtendedPathComponent, ParsedExtendedPath, parse_extended_path, parse_extended_path_ref,
 };
 pub use query::{MatchPotential, Query, QueryError, QuerySegment};
-pub use simd_escape::{escape_json_string_simd, escape_json_to_string};
-pub use simd_unescape::{UnescapeError, unescape_json_string_simd, unescape_json_to_string};
+pub use simd_escape::{
+    escape_json_string_simd, escape_json_string_with_level, escape_json_to_string,
+};
 pub use simd_utils::{escape_json_string, needs_escape, needs_quoting};
-pub use ungron::{ungron, ungron_to_value};
+pub use ungron::{ungron, ungron_to_value, ungron_to_value_with_root, ungron_to_value_with_style};
+pub use ungron_jsonl::{UngronJsonlOptions, ungron_jsonl, ungron_jsonl_streaming};
 
 // Generic (format-agnostic) gron operations
 pub use gron_generic::{gron_from_tape, gron_from_tape_to_writer};
-pub use ungron_generic::{ungron_to_json, ungron_with_builder};
+pub use ungron_generic::{ungron_to_json, ungron_with_builder, ungron_with_builder_and_root};
@@ -9,10 +9,13 @@
 //! - SIMD-friendly: Uses contiguous memory
 //! - Auto-quoting: Automatically quotes field names that need it
 //! - `SmallVec` optimization: Avoids heap allocation for typical nesting depths
+//! - Stream rendering: Paths as JSON array components (`"json","a",0`)
+//! - Path styles: JSON Pointer, `JSONPath`, jq or dotted paths via [`PathStyle`]
 
+use fionn_core::path::PathStyle;
 use smallvec::SmallVec;
 
-use super::simd_utils::needs_quoting;
+use super::simd_utils::{escape_json_string, needs_quoting};
 
 /// Stack-based path builder for efficient path construction.
 ///
@@ -30,6 +33,14 @@ pub struct PathBuilder {
     stack: SmallVec<[usize; 16]>,
     /// Root prefix (e.g., "json")
     root: String,
+    /// Render components as a JSON array body instead of an assignment path
+    stream: bool,
+    /// Syntax of assignment paths
+    style: PathStyle,
+    /// Number of pushed pointer tokens containing a line break
+    line_breaks: usize,
+    /// The path as a JSON string, while it contains a line break
+    quoted: Vec<u8>,
 }
 
 impl PathBuilder {
@@ -43,9 +54,56 @@ impl PathBuilder {
             buffer,
             stack: SmallVec::new(),
             root: root.to_string(),
+            stream: false,
+            style: PathStyle::Js,
+            line_breaks: 0,
+            quoted: Vec::new(),
         }
     }
 
+    /// Create a path builder rendering paths in the given style.
+    ///
+    /// The root prefix only applies to [`PathStyle::Js`]; other styles
+    /// start from their own root (`""`, `$` or `.`).
+    ///
+    /// JSON Pointer has no escape for line breaks, so a pointer path with a
+    /// line break in a field name is rendered as a JSON string
+    /// (`"/a\nb"`) to keep each assignment on one line.
+    #[must_use]
+    pub fn with_style(root: &str, style: PathStyle) -> Self {
+        let mut builder = Self {
+            buffer: Vec::with_capacity(256),
+            stack: SmallVec::new(),
+            root: root.to_string(),
+            stream: false,
+            style,
+            line_breaks: 0,
+            quoted: Vec::new(),
+        };
+        builder.reset();
+        builder
+    }
+
+    /// Create a path builder rendering JSON stream components.
+    ///
+    /// The path is the comma-separated body of a JSON array, such as
+    /// `"json","a",0`. An empty root adds no component, which gives the
+    /// root-less paths other gron implementations emit.
+    #[must_use]
+    pub fn stream(root: &str) -> Self {
+        let mut builder = Self {
+            buffer: Vec::with_capacity(256),
+            stack: SmallVec::new(),
+            root: root.to_string(),
+            stream: true,
+            style: PathStyle::Js,
+            line_breaks: 0,
+            quoted: Vec::new(),
+        };
+        builder.reset();
+        builder
+    }
+
     /// Create a new path builder with default root "json".
     #[must_use]
     pub fn default_root() -> Self {
@@ -59,7 +117,16 @@ impl PathBuilder {
     pub fn push_field(&mut self, name: &str) {
         self.stack.push(self.buffer.len());
 
-        if needs_quoting(name.as_bytes()) {
+        if self.stream {
+            self.push_separator();
+            escape_json_string(name, &mut self.buffer);
+        } else if self.style != PathStyle::Js {
+            self.style.push_field(&mut self.buffer, name);
+            if self.style == PathStyle::Pointer && has_line_break(name.as_bytes()) {
+                self.line_breaks += 1;
+            }
+            self.requote();
+        } else if needs_quoting(name.as_bytes()) {
             // Use bracket notation: ["field"]
             self.buffer.push(b'[');
             self.buffer.push(b'"');
@@ -76,9 +143,7 @@ impl PathBuilder {
     /// Push an array index onto the path.
     pub fn push_index(&mut self, index: usize) {
         self.stack.push(self.buffer.len());
-        self.buffer.push(b'[');
-        write_usize(&mut self.buffer, index);
-        self.buffer.push(b']');
+        self.push_index_raw(index);
     }
 
     /// Push an array index onto the path without recording on the stack.
@@ -86,15 +151,44 @@ impl PathBuilder {
     /// This is used for parallel processing where we don't need `pop()` support.
     /// The index is added directly to the current path.
     pub fn push_index_raw(&mut self, index: usize) {
-        self.buffer.push(b'[');
-        write_usize(&mut self.buffer, index);
-        self.buffer.push(b']');
+        if self.stream {
+            self.push_separator();
+            write_usize(&mut self.buffer, index);
+        } else if self.style != PathStyle::Js {
+            self.style.push_index(&mut self.buffer, index);
+            self.requote();
+        } else {
+            self.buffer.push(b'[');
+            write_usize(&mut self.buffer, index);
+            self.buffer.push(b']');
+        }
+    }
+
+    /// Separate a stream component from the one before it.
+    fn push_separator(&mut self) {
+        if !self.buffer.is_empty() {
+            self.buffer.push(b',');
+        }
     }
 
     /// Pop the last segment from the path.
     pub fn pop(&mut self) {
         if let Some(offset) = self.stack.pop() {
+            if self.line_breaks > 0 && has_line_break(&self.buffer[offset..]) {
+                self.line_breaks -= 1;
+            }
             self.buffer.truncate(offset);
+            self.requote();
+        }
+    }
+
+    /// Re-render the JSON string form of a path with line breaks.
+    fn requote(&mut self) {
+        if self.line_breaks > 0 {
+            // Safety: see `current_path`
+            let path = unsafe { std::str::from_utf8_unchecked(&self.buffer) };
+            self.quoted.clear();
+            escape_json_string(path, &mut self.quoted);
         }
     }
 
@@ -107,13 +201,17 @@ impl PathBuilder {
     /// - All added characters are ASCII
     #[must_use]
     pub fn current_path(&self) -> &str {
-        unsafe { std::str::from_utf8_unchecked(&self.buffer) }
+        unsafe { std::str::from_utf8_unchecked(self.current_path_bytes()) }
     }
 
     /// Get the current path as bytes.
     #[must_use]
     pub fn current_path_bytes(&self) -> &[u8] {
-        &self.buffer
+        if self.line_breaks > 0 {
+            &self.quoted
+        } else {
+            &self.buffer
+        }
     }
 
     /// Get the current depth (number of segments).
@@ -122,11 +220,32 @@ impl PathBuilder {
         self.stack.len()
     }
 
+    /// Check if paths render as JSON stream components.
+    #[must_use]
+    pub const fn is_stream(&self) -> bool {
+        self.stream
+    }
+
+    /// Get the style assignment paths are rendered in.
+    #[must_use]
+    pub const fn style(&self) -> PathStyle {
+        self.style
+    }
+
     /// Reset to root state.
     pub fn reset(&mut self) {
         self.buffer.clear();
-        self.buffer.extend_from_slice(self.root.as_bytes());
+        if self.stream {
+            if !self.root.is_empty() {
+                escape_json_string(&self.root, &mut self.buffer);
+            }
+        } else if self.style == PathStyle::Js {
+            self.buffer.extend_from_slice(self.root.as_bytes());
+        } else {
+            self.buffer.extend_from_slice(self.style.root().as_bytes());
+        }
         self.stack.clear();
+        self.line_breaks = 0;
     }
 
     /// Check if the path is at root level.
@@ -142,6 +261,12 @@ impl Default for PathBuilder {
     }
 }
 
+/// Check if a pointer token would break the assignment line.
+#[inline]
+fn has_line_break(bytes: &[u8]) -> bool {
+    memchr::memchr2(b'\n', b'\r', bytes).is_some()
+}
+
 /// Write a usize to a byte buffer as decimal digits.
 ///
 /// Uses a lookup table for small numbers (0-999) for efficiency.
@@ -197,6 +322,16 @@ fn escape_field_name(buffer: &mut Vec<u8>, name: &str) {
                 buffer.push(b'\\');
                 buffer.push(b'\\');
             }
+            b'\n' => buffer.extend_from_slice(b"\\n"),
+            b'\r' => buffer.extend_from_slice(b"\\r"),
+            b'\t' => buffer.extend_from_slice(b"\\t"),
+            // Other control characters would not survive line-based tools
+            0x00..=0x1f => {
+                const HEX: &[u8; 16] = b"0123456789abcdef";
+                buffer.extend_from_slice(b"\\u00");
+                buffer.push(HEX[usize::from(byte >> 4)]);
+                buffer.push(HEX[usize::from(byte & 0xf)]);
+            }
             _ => {
                 buffer.push(byte);
             }
@@ -241,6 +376,33 @@ mod tests {
         assert_eq!(builder.current_path(), "json");
     }
 
+    #[test]
+    fn test_stream_components() {
+        let mut builder = PathBuilder::stream("json");
+        assert_eq!(builder.current_path(), r#""json""#);
+        builder.push_field("a.b");
+        builder.push_index(3);
+        builder.push_field("say \"hi\"\n");
+        assert_eq!(builder.current_path(), r#""json","a.b",3,"say \"hi\"\n""#);
+
+        builder.pop();
+        builder.pop();
+        assert_eq!(builder.current_path(), r#""json","a.b""#);
+        assert!(builder.is_stream());
+    }
+
+    #[test]
+    fn test_stream_without_root() {
+        let mut builder = PathBuilder::stream("");
+        assert_eq!(builder.current_path(), "");
+        builder.push_index(0);
+        builder.push_field("x");
+        assert_eq!(builder.current_path(), r#"0,"x""#);
+        builder.reset();
+        assert!(builder.is_root());
+        assert_eq!(builder.current_path(), "");
+    }
+
     #[test]
     fn test_quoted_field() {
         let mut builder = PathBuilder::new("json");
@@ -407,6 +569,10 @@ mod tests {
         buffer.clear();
         escape_field_name(&mut buffer, r"back\slash");
         assert_eq!(&buffer, b"back\\\\slash");
+
+        buffer.clear();
+        escape_field_name(&mut buffer, "line\nbreak\u{1}");
+        assert_eq!(buffer, br"line\nbreak\u0001");
     }
 
     #[test]
@@ -477,4 +643,36 @@ mod tests {
         // Unicode fields need quoting
         assert!(builder.current_path().contains("日本語"));
     }
+
+    #[test]
+    fn test_styled_components() {
+        let expected = [
+            (PathStyle::Pointer, "", "/a~1b/0"),
+            (PathStyle::JsonPath, "$", "$['a/b'][0]"),
+            (PathStyle::Jq, ".", r#".["a/b"][0]"#),
+            (PathStyle::Dotted, "", "a/b[0]"),
+        ];
+        for (style, root, full) in expected {
+            let mut builder = PathBuilder::with_style("json", style);
+            assert_eq!(builder.current_path(), root);
+            builder.push_field("a/b");
+            builder.push_index(0);
+            assert_eq!(builder.current_path(), full);
+            builder.pop();
+            builder.pop();
+            assert_eq!(builder.current_path(), root);
+        }
+    }
+
+    #[test]
+    fn test_pointer_line_break_quoted() {
+        let mut builder = PathBuilder::with_style("json", PathStyle::Pointer);
+        builder.push_field("a");
+        builder.push_field("b\nc");
+        builder.push_index(1);
+        assert_eq!(builder.current_path(), r#""/a/b\nc/1""#);
+        builder.pop();
+        builder.pop();
+        assert_eq!(builder.current_path(), "/a");
+    }
 }
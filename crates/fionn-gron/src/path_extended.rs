@@ -20,6 +20,7 @@
 //! integer     ::= [0-9]+
 //! ```
 
+use fionn_core::path::PathComponent;
 use memchr::{memchr, memchr2};
 use std::ops::Range;
 
@@ -32,6 +33,15 @@ pub enum ExtendedPathComponent {
     ArrayIndex(usize),
 }
 
+impl From<PathComponent> for ExtendedPathComponent {
+    fn from(component: PathComponent) -> Self {
+        match component {
+            PathComponent::Field(name) => Self::Field(name),
+            PathComponent::ArrayIndex(index) => Self::ArrayIndex(index),
+        }
+    }
+}
+
 /// Borrowed extended path component.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExtendedPathComponentRef<'a> {
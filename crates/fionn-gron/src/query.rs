@@ -22,6 +22,10 @@
 
 use std::fmt;
 
+use fionn_simd::cursor::{PathComponent, PathSelector, Selection};
+
+use crate::simd_utils::needs_quoting;
+
 /// A compiled query for path matching.
 #[derive(Debug, Clone)]
 pub struct Query {
@@ -480,6 +484,31 @@ fn parse_quoted_string(bytes: &[u8]) -> Result<(String, usize), QueryError> {
     Err(QueryError::UnclosedQuote)
 }
 
+/// Drives single-pass extraction with [`fionn_simd::JsonCursor::extract`]
+impl PathSelector for Query {
+    fn select(&self, path: &[PathComponent<'_>]) -> Selection {
+        use fmt::Write;
+        let mut gron_path = String::from("json");
+        for component in path {
+            let _ = match component {
+                PathComponent::Field(name) if needs_quoting(name.as_bytes()) => {
+                    write!(gron_path, "[\"{name}\"]")
+                }
+                PathComponent::Field(name) => write!(gron_path, ".{name}"),
+                PathComponent::Index(index) => write!(gron_path, "[{index}]"),
+            };
+        }
+        // Recursive segments only ever report `Partial`, so test for a full match first
+        if self.matches(&gron_path) {
+            return Selection::Take;
+        }
+        match self.match_potential(&gron_path) {
+            MatchPotential::NoMatch => Selection::Skip,
+            MatchPotential::Matches | MatchPotential::Partial => Selection::Descend,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1095,4 +1124,33 @@ mod tests {
             QuerySegment::Field("field123".to_string())
         );
     }
+
+    // =========================================================================
+    // Cursor Extraction Tests
+    // =========================================================================
+
+    #[test]
+    fn test_query_drives_cursor_extraction() {
+        use fionn_simd::JsonCursor;
+
+        let line = br#"{"users": [{"name": "a", "id": 1}, {"name": "b"}], "meta": {"error": "x"}}"#;
+        let query = Query::parse(".users[*].name").unwrap();
+        let found = JsonCursor::new(line).extract(&query).unwrap();
+        let names: Vec<(&str, String)> = found
+            .iter()
+            .map(|(path, value)| (path.as_str(), value.as_str().unwrap().into_owned()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("users[0].name", "a".to_string()),
+                ("users[1].name", "b".to_string())
+            ]
+        );
+
+        let recursive = Query::parse("..error").unwrap();
+        let found = JsonCursor::new(line).extract(&recursive).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "meta.error");
+    }
 }
@@ -16,72 +16,48 @@ use std::arch::aarch64::{
     uint8x16_t, vceqq_u8, vcltq_u8, vdupq_n_u8, vld1q_u8, vmaxvq_u8, vorrq_u8,
 };
 
-#[allow(unused_imports)] // OnceLock used conditionally per target_arch
-use std::sync::OnceLock;
+use fionn_core::simd::{SimdDispatch, SimdLevel};
 
-/// Cached SIMD feature detection.
-#[cfg(target_arch = "x86_64")]
-static HAS_SSE2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
-static HAS_AVX2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
+/// SIMD-accelerated JSON string escaping.
+///
+/// Uses SIMD to find escape boundaries and bulk-copies clean segments,
+/// providing significant speedup over byte-by-byte escaping. The kernel
+/// comes from the `fionn_core::simd` table.
 #[inline]
-fn has_sse2() -> bool {
-    *HAS_SSE2.get_or_init(|| is_x86_feature_detected!("sse2"))
+pub fn escape_json_string_simd(s: &str, out: &mut Vec<u8>) {
+    escape_with(fionn_core::simd::dispatch().escape, s, out);
 }
 
-#[cfg(target_arch = "x86_64")]
-#[inline]
-fn has_avx2() -> bool {
-    *HAS_AVX2.get_or_init(|| is_x86_feature_detected!("avx2"))
+/// JSON string escaping with the kernel for `level`.
+///
+/// The level is lowered to what the CPU supports.
+pub fn escape_json_string_with_level(level: SimdLevel, s: &str, out: &mut Vec<u8>) {
+    escape_with(SimdDispatch::for_level(level).escape, s, out);
 }
 
-/// SIMD-accelerated JSON string escaping.
-///
-/// Uses SIMD to find escape boundaries and bulk-copies clean segments,
-/// providing significant speedup over byte-by-byte escaping.
 #[inline]
-pub fn escape_json_string_simd(s: &str, out: &mut Vec<u8>) {
+fn escape_with(kernel: SimdLevel, s: &str, out: &mut Vec<u8>) {
     let bytes = s.as_bytes();
 
     out.push(b'"');
 
-    if bytes.is_empty() {
-        out.push(b'"');
-        return;
-    }
-
-    #[cfg(target_arch = "x86_64")]
-    {
-        // Prefer AVX2 for strings >= 32 bytes
-        if bytes.len() >= 32 && has_avx2() {
-            unsafe { escape_json_string_avx2(bytes, out) };
-            out.push(b'"');
-            return;
+    if !bytes.is_empty() {
+        // SAFETY: The kernel table only selects instruction sets the CPU has
+        match kernel {
+            // Prefer AVX2 for strings >= 32 bytes
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Avx2 if bytes.len() >= 32 => unsafe {
+                escape_json_string_avx2(bytes, out);
+            },
+            #[cfg(target_arch = "x86_64")]
+            SimdLevel::Sse2 | SimdLevel::Avx2 => unsafe { escape_json_string_sse2(bytes, out) },
+            #[cfg(target_arch = "aarch64")]
+            SimdLevel::Neon => unsafe { escape_json_string_neon(bytes, out) },
+            _ => escape_json_string_scalar(bytes, out),
         }
-        if has_sse2() {
-            unsafe { escape_json_string_sse2(bytes, out) };
-            out.push(b'"');
-            return;
-        }
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    {
-        // NEON is always available on aarch64
-        unsafe { escape_json_string_neon(bytes, out) };
-        out.push(b'"');
-        return;
     }
 
-    // Fallback to scalar
-    #[allow(unreachable_code)] // Reachable only when no SIMD available (non-x86/aarch64)
-    {
-        escape_json_string_scalar(bytes, out);
-        out.push(b'"');
-    }
+    out.push(b'"');
 }
 
 /// SSE2-accelerated escape implementation.
@@ -261,8 +237,8 @@ unsafe fn find_next_escape_avx2(
             i += 32;
         }
 
-        // Handle remaining bytes with SSE2 or scalar
-        if i + 16 <= len && has_sse2() {
+        // Handle remaining bytes with SSE2 (implied by AVX2) or scalar
+        if i + 16 <= len {
             // Pre-compute SSE2 constants
             let sse_control = _mm_set1_epi8(0x20);
             #[allow(clippy::cast_possible_wrap)] // Safe: ASCII byte fits in i8
@@ -826,17 +802,15 @@ mod tests {
         assert_eq!(out, b"\"a\\\"b\\\\c\\nd\"");
     }
 
-    #[cfg(target_arch = "x86_64")]
     #[test]
-    fn test_has_sse2() {
-        // Just verify it doesn't panic
-        let _ = has_sse2();
-    }
-
-    #[cfg(target_arch = "x86_64")]
-    #[test]
-    fn test_has_avx2() {
-        // Just verify it doesn't panic
-        let _ = has_avx2();
+    fn test_escape_every_level_agrees() {
+        let input = format!("{}\"tab\there\u{1}{}", "clean ".repeat(10), "é".repeat(20));
+        let mut expected = Vec::new();
+        escape_json_string_with_level(SimdLevel::Scalar, &input, &mut expected);
+        for level in fionn_core::simd::available_levels() {
+            let mut out = Vec::new();
+            escape_json_string_with_level(level, &input, &mut out);
+            assert_eq!(out, expected, "{level}");
+        }
     }
 }
@@ -7,7 +7,7 @@
 //! - Escaping strings for JSON output
 //!
 //! ## Optimizations
-//! - Feature detection is cached at startup (avoids per-call CPUID)
+//! - Kernels follow the `fionn_core::simd` table, resolved once (avoids per-call CPUID)
 //! - SIMD constants are computed once and reused
 //! - Control character escape sequences use lookup tables
 
@@ -24,27 +24,32 @@ use std::arch::x86::*;
 #[cfg(target_arch = "aarch64")]
 use std::arch::aarch64::*;
 
-#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-use std::sync::OnceLock;
-
-/// Cached SIMD feature detection to avoid per-call CPUID overhead.
-/// This is critical for performance - CPUID can cost 100-200 cycles per check.
-#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-static HAS_SSE2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-static HAS_AVX2: OnceLock<bool> = OnceLock::new();
+use fionn_core::simd::SimdLevel;
 
+/// Whether the `fionn_core::simd` table enables SSE2 escape kernels.
+/// The table is resolved once, so this avoids per-call CPUID overhead.
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 #[inline]
 fn has_sse2() -> bool {
-    *HAS_SSE2.get_or_init(|| is_x86_feature_detected!("sse2"))
+    fionn_core::simd::dispatch()
+        .escape
+        .includes(SimdLevel::Sse2)
 }
 
+/// Whether the `fionn_core::simd` table enables AVX2 escape kernels.
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 #[inline]
 fn has_avx2() -> bool {
-    *HAS_AVX2.get_or_init(|| is_x86_feature_detected!("avx2"))
+    fionn_core::simd::dispatch()
+        .escape
+        .includes(SimdLevel::Avx2)
+}
+
+/// Whether the `fionn_core::simd` table enables NEON escape kernels.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn has_neon() -> bool {
+    fionn_core::simd::dispatch().escape == SimdLevel::Neon
 }
 
 /// Pre-computed control character escape sequences (0x00-0x1F).
@@ -108,7 +113,7 @@ pub fn needs_quoting(bytes: &[u8]) -> bool {
         return needs_quoting_scalar(bytes);
     }
 
-    // Use SIMD for longer strings (cached feature detection)
+    // Use SIMD for longer strings (kernel table lookup)
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     {
         // Prefer AVX2 for strings >= 32 bytes (processes 32 bytes at a time)
@@ -122,10 +127,11 @@ pub fn needs_quoting(bytes: &[u8]) -> bool {
 
     #[cfg(target_arch = "aarch64")]
     {
-        return unsafe { needs_quoting_neon(bytes) };
+        if has_neon() {
+            return unsafe { needs_quoting_neon(bytes) };
+        }
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
     needs_quoting_scalar(bytes)
 }
 
@@ -336,7 +342,7 @@ pub fn needs_escape(bytes: &[u8]) -> bool {
         return needs_escape_scalar(bytes);
     }
 
-    // Use cached feature detection to avoid per-call CPUID overhead
+    // Kernel table lookup avoids per-call CPUID overhead
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     {
         // Prefer AVX2 for strings >= 32 bytes (processes 32 bytes at a time)
@@ -350,10 +356,11 @@ pub fn needs_escape(bytes: &[u8]) -> bool {
 
     #[cfg(target_arch = "aarch64")]
     {
-        return unsafe { needs_escape_neon(bytes) };
+        if has_neon() {
+            return unsafe { needs_escape_neon(bytes) };
+        }
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
     needs_escape_scalar(bytes)
 }
 
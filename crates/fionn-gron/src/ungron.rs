@@ -3,8 +3,15 @@
 //!
 //! This module parses gron-format lines and reconstructs the original JSON
 //! document. This is the inverse of the gron transformation.
+//!
+//! Both assignment lines (`json.a[0] = 1;`) and JSON stream lines
+//! (`[["a",0],1]`) are accepted, and may be mixed. Type annotations
+//! (`json.a = 1.0; # float`) are read and applied where JSON can carry them.
 
+use super::gron_core::StreamRoot;
 use super::path_extended::{ExtendedPathComponent, parse_extended_path};
+use fionn_core::path::PathStyle;
+use fionn_core::value_builder::{ParseTypeAnnotationError, TypeAnnotation};
 use fionn_core::{DsonError, Result};
 use serde_json::{Map, Value};
 
@@ -36,6 +43,29 @@ pub fn ungron(input: &str) -> Result<String> {
 /// # Errors
 /// Returns an error if parsing fails.
 pub fn ungron_to_value(input: &str) -> Result<Value> {
+    ungron_to_value_with_style(input, PathStyle::Js)
+}
+
+/// Convert gron output whose assignment paths use `style` to a `serde_json` Value.
+///
+/// JSON stream lines are accepted whatever the style, with root-less paths.
+///
+/// # Errors
+/// Returns an error if parsing fails.
+pub fn ungron_to_value_with_style(input: &str, style: PathStyle) -> Result<Value> {
+    ungron_to_value_with_root(input, style, StreamRoot::Absent)
+}
+
+/// Convert gron output to a `serde_json` Value, stripping the root component
+/// of JSON stream paths when `stream_root` says they carry one.
+///
+/// # Errors
+/// Returns an error if parsing fails.
+pub fn ungron_to_value_with_root(
+    input: &str,
+    style: PathStyle,
+    stream_root: StreamRoot,
+) -> Result<Value> {
     let mut root = Value::Null;
 
     for line in input.lines() {
@@ -44,22 +74,7 @@ pub fn ungron_to_value(input: &str) -> Result<Value> {
             continue;
         }
 
-        // Parse gron line: path = value;
-        let (path_str, value_str) = parse_gron_line(line)?;
-
-        // Parse the path using extended parser
-        let components = parse_extended_path(path_str);
-
-        // Skip root component (e.g., "json")
-        let path_components: Vec<_> = if components.len() > 1 {
-            components.into_iter().skip(1).collect()
-        } else {
-            // Root assignment
-            vec![]
-        };
-
-        // Parse the value
-        let value = parse_json_value(value_str)?;
+        let (path_components, value) = parse_line(line, style, stream_root)?;
 
         // Set the value at the path
         set_path(&mut root, &path_components, value);
@@ -68,26 +83,166 @@ pub fn ungron_to_value(input: &str) -> Result<Value> {
     Ok(root)
 }
 
-/// Parse a gron line into path and value parts.
-fn parse_gron_line(line: &str) -> Result<(&str, &str)> {
-    // Handle both compact (=) and standard ( = ) formats
-    // Also handle trailing semicolon
+/// Parse one gron line, in either format, into its path below the root and value.
+///
+/// # Errors
+/// Returns an error if the line is neither a valid assignment nor a stream line.
+pub fn parse_line(
+    line: &str,
+    style: PathStyle,
+    stream_root: StreamRoot,
+) -> Result<(Vec<ExtendedPathComponent>, Value)> {
+    if is_stream_line(line) {
+        let (components, value) = parse_stream_line(line)?;
+        return Ok((stream_root.strip(components), value));
+    }
+
+    // Parse gron line: path = value; # type
+    let (line, annotation) = split_annotation(line)?;
+    let (components, value) = parse_gron_line(line, style)?;
+    Ok((components, apply_annotation(value, annotation.as_ref())?))
+}
 
-    let line = line.trim_end_matches(';').trim();
+/// Split a trailing ` # type` annotation off a gron assignment.
+///
+/// Annotations follow the terminating `;` and never contain quotes, so a `;`
+/// or `#` inside a string value is not mistaken for one.
+///
+/// # Errors
+/// Returns an error if the annotation names no known type.
+pub fn split_annotation(line: &str) -> Result<(&str, Option<TypeAnnotation>)> {
+    if let Some(pos) = line.rfind(';')
+        && let Some(name) = line[pos + 1..].trim().strip_prefix('#')
+        && !name.contains('"')
+    {
+        let annotation = name
+            .parse()
+            .map_err(|e: ParseTypeAnnotationError| DsonError::ParseError(e.to_string()))?;
+        return Ok((&line[..=pos], Some(annotation)));
+    }
+    Ok((line, None))
+}
 
-    // Find the = separator
-    let eq_pos = line
-        .find(" = ")
-        .map(|p| (p, p + 3))
-        .or_else(|| line.find('=').map(|p| (p, p + 1)));
+/// Restore the JSON-representable part of an annotation: integral floats.
+///
+/// # Errors
+/// Returns an error if the value contradicts the annotation.
+fn apply_annotation(value: Value, annotation: Option<&TypeAnnotation>) -> Result<Value> {
+    let Some(annotation) = annotation else {
+        return Ok(value);
+    };
+    annotation.check_literal(value.to_string().as_bytes())?;
+    Ok(match (annotation, &value) {
+        (TypeAnnotation::Float, Value::Number(n)) if !n.is_f64() => n
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(value, Value::Number),
+        _ => value,
+    })
+}
 
-    let (path_end, value_start) =
-        eq_pos.ok_or_else(|| DsonError::ParseError(format!("Invalid gron line: {line}")))?;
+/// Check if a line is in the JSON stream format.
+///
+/// Stream lines open with the path array (`[[`); a `dotted` assignment may
+/// start with a single bracket (`[0] = 1;`, `[""] = 1;`).
+pub fn is_stream_line(line: &str) -> bool {
+    line.strip_prefix('[')
+        .is_some_and(|rest| rest.trim_start().starts_with('['))
+}
 
-    let path = line[..path_end].trim();
-    let value = line[value_start..].trim();
+/// Parse a JSON stream line (`[["a",0],1]`) into path and value.
+pub fn parse_stream_line(line: &str) -> Result<(Vec<ExtendedPathComponent>, Value)> {
+    let invalid = || DsonError::ParseError(format!("Invalid gron stream line: {line}"));
+    let Ok(Value::Array(mut pair)) = serde_json::from_str::<Value>(line) else {
+        return Err(invalid());
+    };
+    if pair.len() != 2 {
+        return Err(invalid());
+    }
+    let value = pair.pop().unwrap_or(Value::Null);
+    let Some(Value::Array(path)) = pair.pop() else {
+        return Err(invalid());
+    };
+
+    let components = path
+        .into_iter()
+        .map(|component| match component {
+            Value::String(key) => Some(ExtendedPathComponent::Field(key)),
+            Value::Number(n) => n
+                .as_u64()
+                .and_then(|i| usize::try_from(i).ok())
+                .map(ExtendedPathComponent::ArrayIndex),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(invalid)?;
+    Ok((components, value))
+}
 
-    Ok((path, value))
+/// Parse a gron assignment (`path = value;`) into path components and value.
+///
+/// Field names and string values may both contain `=`, so each `=` is tried
+/// as the separator in turn and the first with a valid path before it and a
+/// complete JSON value after it wins. A value that swallowed a later `= value`
+/// would have to end inside a string, so no other split can be valid.
+fn parse_gron_line(line: &str, style: PathStyle) -> Result<(Vec<ExtendedPathComponent>, Value)> {
+    let line = line.trim_end_matches(';').trim();
+
+    let mut first_error = None;
+    for (path, value) in assignment_splits(line) {
+        match parse_json_value(value.trim()).and_then(|value| Ok((parse_path(path, style)?, value)))
+        {
+            Ok(assignment) => return Ok(assignment),
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    Err(first_error.unwrap_or_else(|| DsonError::ParseError(format!("Invalid gron line: {line}"))))
+}
+
+/// Candidate `(path, value)` splits of an assignment, one per `=`.
+///
+/// An `=` with a space on both sides is the standard ` = ` separator;
+/// otherwise it is the compact one.
+fn assignment_splits(line: &str) -> impl Iterator<Item = (&str, &str)> {
+    let bytes = line.as_bytes();
+    memchr::memchr_iter(b'=', bytes).map(move |pos| {
+        if pos > 0 && bytes[pos - 1] == b' ' && bytes.get(pos + 1) == Some(&b' ') {
+            (&line[..pos - 1], &line[pos + 2..])
+        } else {
+            (&line[..pos], &line[pos + 1..])
+        }
+    })
+}
+
+/// Parse an assignment path written in `style`.
+///
+/// `js` paths drop their root; pointer paths may be written as a JSON
+/// string when a field name contains a line break.
+fn parse_path(path: &str, style: PathStyle) -> Result<Vec<ExtendedPathComponent>> {
+    if style == PathStyle::Js {
+        // Skip root component (e.g., "json")
+        return Ok(parse_extended_path(path.trim())
+            .into_iter()
+            .skip(1)
+            .collect());
+    }
+
+    let unquoted: String;
+    let path = if style == PathStyle::Pointer && path.starts_with('"') {
+        unquoted = serde_json::from_str(path)
+            .map_err(|e| DsonError::ParseError(format!("Invalid quoted path '{path}': {e}")))?;
+        &unquoted
+    } else {
+        path
+    };
+    Ok(style
+        .parse(path)
+        .map_err(|e| DsonError::ParseError(e.to_string()))?
+        .into_iter()
+        .map(ExtendedPathComponent::from)
+        .collect())
 }
 
 /// Parse a JSON value string.
@@ -108,7 +263,10 @@ fn parse_json_value(s: &str) -> Result<Value> {
 }
 
 /// Set a value at the given path in the JSON tree.
-fn set_path(root: &mut Value, path: &[ExtendedPathComponent], value: Value) {
+///
+/// An index into an existing object addresses the field of that name, since
+/// JSON Pointer writes array indices and numeric field names alike (`/0`).
+pub fn set_path(root: &mut Value, path: &[ExtendedPathComponent], value: Value) {
     if path.is_empty() {
         *root = value;
         return;
@@ -127,34 +285,14 @@ fn set_path(root: &mut Value, path: &[ExtendedPathComponent], value: Value) {
     for (i, component) in path.iter().enumerate() {
         let is_last = i == path.len() - 1;
 
-        match component {
-            ExtendedPathComponent::Field(key) => {
-                // Ensure current is an object
-                if !current.is_object() {
-                    *current = Value::Object(Map::new());
-                }
-
-                let obj = current.as_object_mut().unwrap();
-
-                if is_last {
-                    obj.insert(key.clone(), value);
-                    return;
-                }
-
-                // Look ahead to determine what type the next level should be
-                let next_type = match &path[i + 1] {
-                    ExtendedPathComponent::Field(_) => Value::Object(Map::new()),
-                    ExtendedPathComponent::ArrayIndex(_) => Value::Array(Vec::new()),
-                };
-
-                // Navigate into or create the child
-                if !obj.contains_key(key) {
-                    obj.insert(key.clone(), next_type);
-                }
-                current = obj.get_mut(key).unwrap();
-            }
+        // Look ahead to determine what type the next level should be
+        let next_type = || match path.get(i + 1) {
+            Some(ExtendedPathComponent::ArrayIndex(_)) => Value::Array(Vec::new()),
+            _ => Value::Object(Map::new()),
+        };
 
-            ExtendedPathComponent::ArrayIndex(index) => {
+        let key = match component {
+            ExtendedPathComponent::ArrayIndex(index) if !current.is_object() => {
                 // Prevent OOM from excessively large array indices
                 if *index > MAX_ARRAY_INDEX {
                     return;
@@ -177,19 +315,31 @@ fn set_path(root: &mut Value, path: &[ExtendedPathComponent], value: Value) {
                     return;
                 }
 
-                // Look ahead to determine what type the next level should be
-                let next_type = match &path[i + 1] {
-                    ExtendedPathComponent::Field(_) => Value::Object(Map::new()),
-                    ExtendedPathComponent::ArrayIndex(_) => Value::Array(Vec::new()),
-                };
-
                 // Ensure the element has the right type
                 if arr[*index] == Value::Null {
-                    arr[*index] = next_type;
+                    arr[*index] = next_type();
                 }
                 current = &mut arr[*index];
+                continue;
             }
+            ExtendedPathComponent::ArrayIndex(index) => index.to_string(),
+            ExtendedPathComponent::Field(key) => key.clone(),
+        };
+
+        // Ensure current is an object
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
         }
+
+        let obj = current.as_object_mut().unwrap();
+
+        if is_last {
+            obj.insert(key, value);
+            return;
+        }
+
+        // Navigate into or create the child
+        current = obj.entry(key).or_insert_with(next_type);
     }
 }
 
@@ -402,26 +552,40 @@ json.a.b.c.d = "value";
 
     #[test]
     fn test_parse_gron_line_invalid() {
-        let result = parse_gron_line("no equals sign here");
+        let result = parse_gron_line("no equals sign here", PathStyle::Js);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_parse_gron_line_with_semicolon() {
-        let result = parse_gron_line("json.key = \"value\";");
+        let result = parse_gron_line("json.key = \"value\";", PathStyle::Js);
         assert!(result.is_ok());
         let (path, value) = result.unwrap();
-        assert_eq!(path, "json.key");
-        assert_eq!(value, "\"value\"");
+        assert_eq!(path, vec![ExtendedPathComponent::Field("key".to_string())]);
+        assert_eq!(value, "value");
     }
 
     #[test]
     fn test_parse_gron_line_compact() {
-        let result = parse_gron_line("json.key=\"value\"");
+        let result = parse_gron_line("json.key=\"value\"", PathStyle::Js);
         assert!(result.is_ok());
         let (path, value) = result.unwrap();
-        assert_eq!(path, "json.key");
-        assert_eq!(value, "\"value\"");
+        assert_eq!(path, vec![ExtendedPathComponent::Field("key".to_string())]);
+        assert_eq!(value, "value");
+    }
+
+    #[test]
+    fn test_parse_gron_line_separator_in_key() {
+        let field = |name: &str| vec![ExtendedPathComponent::Field(name.to_string())];
+        let (path, value) = parse_gron_line("/a = b = \"c = d\";", PathStyle::Pointer).unwrap();
+        assert_eq!(path, field("a = b"));
+        assert_eq!(value, "c = d");
+        let (path, value) = parse_gron_line("/a =;=1;", PathStyle::Pointer).unwrap();
+        assert_eq!(path, field("a =;"));
+        assert_eq!(value, 1);
+        let (path, value) = parse_gron_line(r#"json["x = 1"] = 2;"#, PathStyle::Js).unwrap();
+        assert_eq!(path, field("x = 1"));
+        assert_eq!(value, 2);
     }
 
     #[test]
@@ -618,4 +782,96 @@ json.formula = "a = b";"#;
         assert!(value.is_array());
         assert!(value.as_array().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_ungron_json_stream() {
+        let input = "[[],{}]\n[[\"a\"],{}]\n[[\"a\",\"b\"],true]\n";
+        let result = ungron_to_value(input).unwrap();
+        assert_eq!(result, serde_json::json!({"a": {"b": true}}));
+    }
+
+    #[test]
+    fn test_ungron_json_stream_keeps_first_component() {
+        // A top-level array whose first path component is a key, not a root
+        let input = "[[],[]]\n[[0],[]]\n[[0,0],\"a\"]\n[[0,1],\"b\"]\n[[1],true]\n";
+        let result = ungron_to_value(input).unwrap();
+        assert_eq!(result, serde_json::json!([["a", "b"], true]));
+        let result = ungron_to_value("[[\"a\",\"b\"],true]").unwrap();
+        assert_eq!(result, serde_json::json!({"a": {"b": true}}));
+    }
+
+    #[test]
+    fn test_ungron_json_stream_prefixed_root() {
+        let input = "[[\"json\"],{}]\n[[\"json\",\"a\"],[]]\n[[\"json\",\"a\",0],1]\n[[\"json\",\"a\",1],\"x\"]\n";
+        let result = ungron_to_value_with_root(input, PathStyle::Js, StreamRoot::Prefixed).unwrap();
+        assert_eq!(result, serde_json::json!({"a": [1, "x"]}));
+    }
+
+    #[test]
+    fn test_ungron_path_styles() {
+        let expected = serde_json::json!({"a": {"b.c": [null, 1]}, "d~/": true});
+        for style in PathStyle::ALL {
+            let options = crate::GronOptions::default().path_style(style);
+            let gron_output = crate::gron(&expected.to_string(), &options).unwrap();
+            let result = ungron_to_value_with_style(&gron_output, style).unwrap();
+            assert_eq!(result, expected, "{style}:\n{gron_output}");
+        }
+    }
+
+    #[test]
+    fn test_ungron_pointer_numeric_field_round_trip() {
+        let expected = serde_json::json!({"0": 1, "a.b": {"c": 2}, "d": [{"1": [3]}]});
+        let options = crate::GronOptions::default().path_style(PathStyle::Pointer);
+        let gron_output = crate::gron(&expected.to_string(), &options).unwrap();
+        let result = ungron_to_value_with_style(&gron_output, PathStyle::Pointer).unwrap();
+        assert_eq!(result, expected, "{gron_output}");
+    }
+
+    #[test]
+    fn test_ungron_json_stream_invalid_line() {
+        assert!(ungron_to_value("[[\"a\"]]").is_err());
+        assert!(ungron_to_value("[[\"a\",-1],1]").is_err());
+        assert!(ungron_to_value("[[\"a\",true],1]").is_err());
+    }
+
+    // =========================================================================
+    // Type annotations
+    // =========================================================================
+
+    #[test]
+    fn test_split_annotation() {
+        assert_eq!(
+            split_annotation("json.a = 1; # float").unwrap(),
+            ("json.a = 1;", Some(TypeAnnotation::Float))
+        );
+        assert_eq!(
+            split_annotation("json.a=1;#!tag").unwrap(),
+            ("json.a=1;", Some(TypeAnnotation::Tag("tag".to_string())))
+        );
+        assert_eq!(
+            split_annotation("json.a = 1;").unwrap(),
+            ("json.a = 1;", None)
+        );
+        assert_eq!(
+            split_annotation(r#"json.a = "b;#c""#).unwrap(),
+            (r#"json.a = "b;#c""#, None)
+        );
+        assert!(split_annotation("json.a = 1; # note").is_err());
+    }
+
+    #[test]
+    fn test_annotated_ungron() {
+        let input =
+            "json = {}; # object\njson.a = 2; # float\njson.d = \"1979-05-27\"; # local-date\n";
+        let value = ungron_to_value(input).unwrap();
+        assert_eq!(value["a"], serde_json::json!(2.0));
+        assert_eq!(value["d"], "1979-05-27");
+    }
+
+    #[test]
+    fn test_contradicting_annotation_is_an_error() {
+        assert!(ungron_to_value("json.a = \"not a date\"; # datetime").is_err());
+        assert!(ungron_to_value("json.a = 1.5; # int").is_err());
+        assert!(ungron_to_value("json.a = {}; # array").is_err());
+    }
 }
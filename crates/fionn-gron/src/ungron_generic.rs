@@ -20,8 +20,11 @@
 //! // value is now a serde_json::Value
 //! ```
 
+use super::gron_core::StreamRoot;
 use super::path_extended::{ExtendedPathComponent, parse_extended_path};
-use fionn_core::value_builder::ValueBuilder;
+use super::ungron::{is_stream_line, parse_stream_line, split_annotation};
+use ahash::AHashMap;
+use fionn_core::value_builder::{TypeAnnotation, ValueBuilder};
 use fionn_core::{DsonError, Result};
 
 /// Maximum array index allowed to prevent OOM from malicious input.
@@ -33,6 +36,10 @@ const MAX_ARRAY_INDEX: usize = 10_000_000;
 /// This is the generic version of [`ungron`](super::ungron) that works with any
 /// value builder implementation, enabling reconstruction to different formats.
 ///
+/// Type annotations (`json.created = "1979-05-27"; # local-date`) are passed
+/// to [`ValueBuilder::annotated`], so builders restore the types their format
+/// has; integral values annotated `float` are built as floats.
+///
 /// # Arguments
 ///
 /// * `input` - Gron-formatted text
@@ -57,7 +64,21 @@ const MAX_ARRAY_INDEX: usize = 10_000_000;
 /// let value = ungron_with_builder(gron_text, &mut builder)?;
 /// ```
 pub fn ungron_with_builder<B: ValueBuilder>(input: &str, builder: &mut B) -> Result<B::Output> {
-    let mut root: Option<B::Output> = None;
+    ungron_with_builder_and_root(input, builder, StreamRoot::Absent)
+}
+
+/// Ungron with a builder, stripping the root component of JSON stream paths
+/// when `stream_root` says they carry one.
+///
+/// # Errors
+///
+/// Returns an error if parsing fails.
+pub fn ungron_with_builder_and_root<B: ValueBuilder>(
+    input: &str,
+    builder: &mut B,
+    stream_root: StreamRoot,
+) -> Result<B::Output> {
+    let mut root: Option<Node<B::Output>> = None;
 
     for line in input.lines() {
         let line = line.trim();
@@ -65,28 +86,175 @@ pub fn ungron_with_builder<B: ValueBuilder>(input: &str, builder: &mut B) -> Res
             continue;
         }
 
-        // Parse gron line: path = value;
-        let (path_str, value_str) = parse_gron_line(line)?;
+        let (path_components, value) = if is_stream_line(line) {
+            let (components, value) = parse_stream_line(line)?;
+            let node = match &value {
+                serde_json::Value::Object(map) if map.is_empty() => Node::object(None),
+                serde_json::Value::Array(items) if items.is_empty() => Node::array(None),
+                _ => Node::Leaf(build_json_value(&value, builder)),
+            };
+            (stream_root.strip(components), node)
+        } else {
+            // Parse gron line: path = value; # type
+            let (line, annotation) = split_annotation(line)?;
+            let (path_str, value_str) = parse_gron_line(line)?;
 
-        // Parse the path using extended parser
-        let components = parse_extended_path(path_str);
+            // Parse the path using extended parser
+            let components = parse_extended_path(path_str);
 
-        // Skip root component (e.g., "json")
-        let path_components: Vec<_> = if components.len() > 1 {
-            components.into_iter().skip(1).collect()
-        } else {
-            // Root assignment
-            vec![]
-        };
+            // Skip root component (e.g., "json")
+            let path_components: Vec<_> = if components.len() > 1 {
+                components.into_iter().skip(1).collect()
+            } else {
+                // Root assignment
+                vec![]
+            };
 
-        // Parse the value
-        let value = parse_and_build_value(value_str, builder)?;
+            (path_components, parse_node(value_str, annotation, builder)?)
+        };
 
         // Set the value at the path
-        root = Some(set_path_generic(root, &path_components, value, builder));
+        root.get_or_insert(Node::Missing)
+            .set(&path_components, value);
     }
 
-    root.ok_or_else(|| DsonError::ParseError("Empty gron input".to_string()))
+    root.map(|node| node.build(builder))
+        .ok_or_else(|| DsonError::ParseError("Empty gron input".to_string()))
+}
+
+/// Value under construction.
+///
+/// Containers stay open while lines are read, so later lines can reach into
+/// them; the builder only sees each value once it is complete.
+enum Node<T> {
+    /// Finished scalar (or non-empty stream value)
+    Leaf(T),
+    /// Array slot or field that no line has assigned yet
+    Missing,
+    /// Object fields in first-assignment order, indexed by name
+    Object(
+        Vec<(String, Self)>,
+        AHashMap<String, usize>,
+        Option<TypeAnnotation>,
+    ),
+    /// Array items
+    Array(Vec<Self>, Option<TypeAnnotation>),
+}
+
+impl<T> Node<T> {
+    fn object(annotation: Option<TypeAnnotation>) -> Self {
+        Self::Object(Vec::new(), AHashMap::new(), annotation)
+    }
+
+    const fn array(annotation: Option<TypeAnnotation>) -> Self {
+        Self::Array(Vec::new(), annotation)
+    }
+
+    /// Set `value` at `path` below this node, replacing anything of the
+    /// wrong container type along the way.
+    fn set(&mut self, path: &[ExtendedPathComponent], value: Self) {
+        let Some((first, rest)) = path.split_first() else {
+            *self = value;
+            return;
+        };
+
+        let child = match first {
+            ExtendedPathComponent::Field(key) => {
+                if !matches!(self, Self::Object(..)) {
+                    *self = Self::object(None);
+                }
+                let Self::Object(fields, index, _) = self else {
+                    return;
+                };
+                let slot = *index.entry(key.clone()).or_insert_with(|| {
+                    fields.push((key.clone(), Self::Missing));
+                    fields.len() - 1
+                });
+                &mut fields[slot].1
+            }
+            ExtendedPathComponent::ArrayIndex(index) => {
+                // Prevent OOM from excessively large array indices
+                if *index > MAX_ARRAY_INDEX {
+                    return;
+                }
+                if !matches!(self, Self::Array(..)) {
+                    *self = Self::array(None);
+                }
+                let Self::Array(items, _) = self else {
+                    return;
+                };
+                if items.len() <= *index {
+                    items.resize_with(index + 1, || Self::Missing);
+                }
+                &mut items[*index]
+            }
+        };
+        child.set(rest, value);
+    }
+
+    /// Build the finished value, applying container annotations.
+    fn build<B: ValueBuilder<Output = T>>(self, builder: &mut B) -> T {
+        match self {
+            Self::Leaf(value) => value,
+            Self::Missing => builder.null(),
+            Self::Object(fields, _, annotation) => {
+                let mut object = builder.empty_object();
+                for (key, node) in fields {
+                    let value = node.build(builder);
+                    builder.insert_field(&mut object, &key, value);
+                }
+                annotate(builder, object, annotation.as_ref())
+            }
+            Self::Array(items, annotation) => {
+                let mut array = builder.empty_array();
+                for node in items {
+                    let value = node.build(builder);
+                    builder.push_element(&mut array, value);
+                }
+                annotate(builder, array, annotation.as_ref())
+            }
+        }
+    }
+}
+
+fn annotate<B: ValueBuilder>(
+    builder: &mut B,
+    value: B::Output,
+    annotation: Option<&TypeAnnotation>,
+) -> B::Output {
+    match annotation {
+        Some(annotation) => builder.annotated(value, annotation),
+        None => value,
+    }
+}
+
+/// Parse an assignment's value into a node, applying its annotation.
+///
+/// # Errors
+/// Returns an error if the value is invalid or contradicts its annotation.
+fn parse_node<B: ValueBuilder>(
+    s: &str,
+    annotation: Option<TypeAnnotation>,
+    builder: &mut B,
+) -> Result<Node<B::Output>> {
+    if let Some(annotation) = &annotation {
+        annotation.check_literal(s.as_bytes())?;
+    }
+    match s {
+        "{}" => return Ok(Node::object(annotation)),
+        "[]" => return Ok(Node::array(annotation)),
+        _ => {}
+    }
+
+    // Floats with integral values, and non-finite floats written as strings
+    let float = (annotation == Some(TypeAnnotation::Float))
+        .then(|| s.trim_matches('"').parse::<f64>().ok())
+        .flatten();
+    let value = match float {
+        Some(f) => builder.float(f),
+        None => parse_and_build_value(s, builder)?,
+    };
+    Ok(Node::Leaf(annotate(builder, value, annotation.as_ref())))
 }
 
 /// Parse a gron line into path and value parts.
@@ -141,114 +309,35 @@ fn parse_and_build_value<B: ValueBuilder>(s: &str, builder: &mut B) -> Result<B:
     Err(DsonError::ParseError(format!("Invalid JSON value: {s}")))
 }
 
-/// Set a value at the given path using the builder.
-fn set_path_generic<B: ValueBuilder>(
-    root: Option<B::Output>,
-    path: &[ExtendedPathComponent],
-    value: B::Output,
-    builder: &mut B,
-) -> B::Output {
-    if path.is_empty() {
-        return value;
-    }
-
-    // Ensure root exists with the right type
-    let mut root = root.unwrap_or_else(|| match &path[0] {
-        ExtendedPathComponent::Field(_) => builder.empty_object(),
-        ExtendedPathComponent::ArrayIndex(_) => builder.empty_array(),
-    });
-
-    // Build the path recursively
-    set_path_recursive(&mut root, path, 0, value, builder);
-
-    root
-}
-
-/// Recursively set a value at a path.
-fn set_path_recursive<B: ValueBuilder>(
-    current: &mut B::Output,
-    path: &[ExtendedPathComponent],
-    depth: usize,
-    value: B::Output,
-    builder: &mut B,
-) {
-    if depth >= path.len() {
-        return;
-    }
-
-    let is_last = depth == path.len() - 1;
-
-    match &path[depth] {
-        ExtendedPathComponent::Field(key) => {
-            if is_last {
-                builder.insert_field(current, key, value);
-            } else {
-                // Look ahead to determine what type the next level should be
-                let next_type = match &path[depth + 1] {
-                    ExtendedPathComponent::Field(_) => builder.empty_object(),
-                    ExtendedPathComponent::ArrayIndex(_) => builder.empty_array(),
-                };
-
-                // Get or create the child and recurse
-                let mut child = next_type;
-                set_path_recursive(&mut child, path, depth + 1, value, builder);
-                builder.insert_field(current, key, child);
+/// Build a parsed JSON stream value using the builder.
+fn build_json_value<B: ValueBuilder>(value: &serde_json::Value, builder: &mut B) -> B::Output {
+    match value {
+        serde_json::Value::Null => builder.null(),
+        serde_json::Value::Bool(b) => builder.bool(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => builder.int(i),
+            None => builder.float(n.as_f64().unwrap_or(f64::NAN)),
+        },
+        serde_json::Value::String(s) => builder.string(s),
+        serde_json::Value::Array(items) => {
+            let mut array = builder.empty_array();
+            for item in items {
+                let element = build_json_value(item, builder);
+                builder.push_element(&mut array, element);
             }
+            array
         }
-
-        ExtendedPathComponent::ArrayIndex(index) => {
-            if is_last {
-                // Extend array if needed and set value
-                extend_array_to_index(current, *index, builder);
-                set_array_element(current, *index, value, builder);
-            } else {
-                // Look ahead to determine what type the next level should be
-                let next_type = match &path[depth + 1] {
-                    ExtendedPathComponent::Field(_) => builder.empty_object(),
-                    ExtendedPathComponent::ArrayIndex(_) => builder.empty_array(),
-                };
-
-                // Extend array if needed
-                extend_array_to_index(current, *index, builder);
-
-                // Create child and recurse
-                let mut child = next_type;
-                set_path_recursive(&mut child, path, depth + 1, value, builder);
-                set_array_element(current, *index, child, builder);
+        serde_json::Value::Object(map) => {
+            let mut object = builder.empty_object();
+            for (key, item) in map {
+                let field = build_json_value(item, builder);
+                builder.insert_field(&mut object, key, field);
             }
+            object
         }
     }
 }
 
-/// Extend an array to have at least index + 1 elements.
-fn extend_array_to_index<B: ValueBuilder>(arr: &mut B::Output, index: usize, builder: &mut B) {
-    // Prevent OOM from excessively large array indices
-    if index > MAX_ARRAY_INDEX {
-        return;
-    }
-
-    // This is a simplified approach - we push nulls until we have enough elements
-    // Real implementations would track array length differently
-    for _ in 0..=index {
-        let null_val = builder.null();
-        builder.push_element(arr, null_val);
-    }
-}
-
-/// Set an element at index in an array.
-fn set_array_element<B: ValueBuilder>(
-    arr: &mut B::Output,
-    index: usize,
-    value: B::Output,
-    builder: &mut B,
-) {
-    // For generic builders, we use insert_field with string index as a workaround
-    // This works because push_element was already called to ensure the index exists
-    let _ = (arr, index, value, builder);
-    // Note: This is a limitation of the generic approach.
-    // Real implementations would need array index setter support in ValueBuilder.
-}
-
 /// Unescape a JSON string.
 fn unescape_json_string(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -502,4 +591,104 @@ json.string_val = "hello";
         let result = ungron_to_json(input).unwrap();
         assert_eq!(result, serde_json::json!(42));
     }
+
+    #[test]
+    fn test_ungron_json_stream() {
+        let input = "[[],{}]\n[[\"a\"],{\"b\":1.5}]\n[[\"c\"],\"x\"]\n";
+        let mut builder = JsonBuilder::new();
+        let result = ungron_with_builder(input, &mut builder).unwrap();
+        assert_eq!(result, serde_json::json!({"a": {"b": 1.5}, "c": "x"}));
+        let input = "[[\"json\"],{}]\n[[\"json\",\"c\"],\"x\"]\n";
+        let result =
+            ungron_with_builder_and_root(input, &mut builder, StreamRoot::Prefixed).unwrap();
+        assert_eq!(result, serde_json::json!({"c": "x"}));
+    }
+
+    #[test]
+    fn test_ungron_arrays_and_nesting() {
+        let input = "json.a.b = 1;\njson.a.c[1] = \"x\";\njson.a.c[0].d = true;\n";
+        let result = ungron_to_json(input).unwrap();
+        assert_eq!(
+            result,
+            serde_json::json!({"a": {"b": 1, "c": [{"d": true}, "x"]}})
+        );
+    }
+
+    #[test]
+    fn test_ungron_matches_json_ungron() {
+        let json =
+            r#"{"users": [{"name": "A", "tags": ["x", "y"]}, {"name": "B"}], "n": [[1], []]}"#;
+        let gron_output = crate::gron(json, &crate::GronOptions::default()).unwrap();
+        assert_eq!(
+            ungron_to_json(&gron_output).unwrap(),
+            crate::ungron_to_value(&gron_output).unwrap()
+        );
+    }
+
+    // =========================================================================
+    // Type annotations
+    // =========================================================================
+
+    #[test]
+    fn test_ungron_float_annotation() {
+        let input = "json = {}; # object\njson.a = 1; # float\njson.b = 1; # int\n";
+        let result = ungron_to_json(input).unwrap();
+        assert!(result["a"].is_f64());
+        assert!(result["b"].is_i64());
+    }
+
+    #[test]
+    fn test_ungron_annotation_inside_string() {
+        let result = ungron_to_json("json.a = \"x; # int\";").unwrap();
+        assert_eq!(result, serde_json::json!({"a": "x; # int"}));
+    }
+
+    #[test]
+    fn test_ungron_unknown_annotation() {
+        assert!(ungron_to_json("json.a = 1; # decimal").is_err());
+    }
+
+    #[test]
+    fn test_ungron_contradicting_annotation() {
+        assert!(ungron_to_json("json.a = \"not a date\"; # datetime").is_err());
+        assert!(ungron_to_json("json.a = \"1\"; # int").is_err());
+        assert!(ungron_to_json("json.a = []; # object").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_roundtrip() {
+        use fionn_core::value_builder::TomlBuilder;
+
+        let source = "title = \"x\"\nratio = 2.0\nnan = nan\n\n[owner]\ndob = 1979-05-27T07:32:00-08:00\nday = 1979-05-27\n\n[[points]]\nat = 07:32:00\n";
+        let value: toml::Value = toml::from_str(source).unwrap();
+        let options = crate::GronOptions::default().show_types();
+        let gron_output = crate::gron_typed(&value, &options).unwrap();
+
+        let rebuilt = ungron_with_builder(&gron_output, &mut TomlBuilder::new()).unwrap();
+        assert!(matches!(rebuilt["owner"]["dob"], toml::Value::Datetime(_)));
+        assert!(rebuilt["nan"].as_float().is_some_and(f64::is_nan));
+        assert_eq!(
+            toml::to_string(&rebuilt).unwrap(),
+            toml::to_string(&value).unwrap()
+        );
+
+        // An edit that breaks a date is rejected rather than kept as a string
+        let edited = gron_output.replace("\"1979-05-27\"", "\"not a date\"");
+        assert!(ungron_with_builder(&edited, &mut TomlBuilder::new()).is_err());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_tag_roundtrip() {
+        use fionn_core::value_builder::YamlBuilder;
+
+        let value: serde_yaml::Value =
+            serde_yaml::from_str("color: !rgb red\nitems: !list\n- 1\n- 2.0\n").unwrap();
+        let options = crate::GronOptions::default().show_types();
+        let gron_output = crate::gron_typed(&value, &options).unwrap();
+
+        let rebuilt = ungron_with_builder(&gron_output, &mut YamlBuilder::new()).unwrap();
+        assert_eq!(rebuilt, value);
+    }
 }
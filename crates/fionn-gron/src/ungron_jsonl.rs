@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Ungron of indexed JSONL gron back into line-delimited records.
+//!
+//! [`gron_jsonl`](crate::gron_jsonl) roots each record at `json[N]` (or
+//! `json.N`); this module reads the record index back off every line, groups
+//! the assignments per record and writes one JSON document per line, in index
+//! order. Records missing from the input (for example after `grep`) are
+//! skipped rather than filled with nulls.
+
+use super::gron_core::StreamRoot;
+use super::gron_jsonl::IndexFormat;
+use super::path_extended::ExtendedPathComponent;
+use super::ungron::{parse_line, set_path};
+use fionn_core::path::PathStyle;
+use fionn_core::{DsonError, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+
+/// Options for ungron of JSONL gron.
+#[derive(Debug, Clone)]
+pub struct UngronJsonlOptions {
+    /// How records were indexed when the gron was produced.
+    ///
+    /// `Bracketed` and `Dotted` both accept either index form; with `None`,
+    /// each root assignment (`json = {};`) starts a new record.
+    pub index_format: IndexFormat,
+    /// Syntax of assignment paths (default: `js`)
+    pub path_style: PathStyle,
+    /// Whether JSON stream paths open with a root component (default: absent)
+    pub stream_root: StreamRoot,
+}
+
+impl Default for UngronJsonlOptions {
+    fn default() -> Self {
+        Self {
+            index_format: IndexFormat::Bracketed,
+            path_style: PathStyle::Js,
+            stream_root: StreamRoot::Absent,
+        }
+    }
+}
+
+impl UngronJsonlOptions {
+    /// Set index format.
+    #[must_use]
+    pub const fn index_format(mut self, format: IndexFormat) -> Self {
+        self.index_format = format;
+        self
+    }
+
+    /// Set the syntax of assignment paths.
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Set whether JSON stream paths open with a root component.
+    #[must_use]
+    pub const fn stream_root(mut self, root: StreamRoot) -> Self {
+        self.stream_root = root;
+        self
+    }
+}
+
+/// Assigns each gron line to the record it belongs to.
+struct RecordSplitter {
+    indexed: bool,
+    style: PathStyle,
+    stream_root: StreamRoot,
+    /// Current record for unindexed input
+    record: usize,
+    /// Whether any line of the current unindexed record has been seen
+    started: bool,
+}
+
+impl RecordSplitter {
+    const fn new(options: &UngronJsonlOptions) -> Self {
+        Self {
+            indexed: !matches!(options.index_format, IndexFormat::None),
+            style: options.path_style,
+            stream_root: options.stream_root,
+            record: 0,
+            started: false,
+        }
+    }
+
+    /// Parse a line into its record index, path within the record and value.
+    ///
+    /// Returns `None` for blank lines and for the top-level container line
+    /// of indexed input.
+    fn split(&mut self, line: &str) -> Result<Option<(usize, Vec<ExtendedPathComponent>, Value)>> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        let (mut path, value) = parse_line(line, self.style, self.stream_root)?;
+
+        if !self.indexed {
+            if path.is_empty() && self.started {
+                self.record += 1;
+            }
+            self.started = true;
+            return Ok(Some((self.record, path, value)));
+        }
+
+        if path.is_empty() {
+            return Ok(None);
+        }
+        let index = match path.remove(0) {
+            ExtendedPathComponent::ArrayIndex(index) => Some(index),
+            ExtendedPathComponent::Field(key) => key.parse().ok(),
+        }
+        .ok_or_else(|| DsonError::ParseError(format!("Gron line has no record index: {line}")))?;
+        Ok(Some((index, path, value)))
+    }
+}
+
+/// Write one record as a line of JSON.
+fn write_record<W: Write>(writer: &mut W, record: &Value) -> Result<()> {
+    serde_json::to_writer(&mut *writer, record)
+        .map_err(|e| DsonError::SerializationError(e.to_string()))?;
+    writer.write_all(b"\n").map_err(DsonError::IoError)
+}
+
+/// Convert indexed JSONL gron back to JSONL.
+///
+/// Lines may arrive in any order; records are written in index order.
+///
+/// # Errors
+/// Returns an error if a line cannot be parsed or carries no record index.
+///
+/// # Example
+/// ```rust,ignore
+/// let gron_text = "json[0].a = 1;\njson[2].b = 2;\n";
+/// let jsonl = ungron_jsonl(gron_text, &UngronJsonlOptions::default())?;
+/// // {"a":1}
+/// // {"b":2}
+/// ```
+pub fn ungron_jsonl(input: &str, options: &UngronJsonlOptions) -> Result<String> {
+    let mut splitter = RecordSplitter::new(options);
+    let mut records: BTreeMap<usize, Value> = BTreeMap::new();
+
+    for line in input.lines() {
+        if let Some((index, path, value)) = splitter.split(line)? {
+            set_path(records.entry(index).or_default(), &path, value);
+        }
+    }
+
+    let mut output = Vec::with_capacity(input.len() / 2);
+    for record in records.values() {
+        write_record(&mut output, record)?;
+    }
+    // Safety: serde_json only writes valid UTF-8
+    Ok(unsafe { String::from_utf8_unchecked(output) })
+}
+
+/// Convert indexed JSONL gron from a buffered reader back to JSONL (streaming mode).
+///
+/// Only one record is held in memory at a time, so input must be sorted by
+/// record index, as [`gron_jsonl`](crate::gron_jsonl) writes it. Returns the
+/// number of records written.
+///
+/// # Errors
+/// Returns an error if a line cannot be parsed, carries no record index, or
+/// belongs to a record that has already been written.
+pub fn ungron_jsonl_streaming<R: BufRead, W: Write>(
+    reader: R,
+    options: &UngronJsonlOptions,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut splitter = RecordSplitter::new(options);
+    let mut current: Option<(usize, Value)> = None;
+    let mut written = 0;
+
+    for line_result in reader.lines() {
+        let line = line_result.map_err(DsonError::IoError)?;
+        let Some((index, path, value)) = splitter.split(&line)? else {
+            continue;
+        };
+
+        if let Some((open, record)) = &mut current {
+            if *open == index {
+                set_path(record, &path, value);
+                continue;
+            }
+            if index < *open {
+                return Err(DsonError::ParseError(format!(
+                    "Record {index} follows record {open}; sort input by record index"
+                )));
+            }
+            write_record(writer, record)?;
+            written += 1;
+        }
+
+        let mut record = Value::Null;
+        set_path(&mut record, &path, value);
+        current = Some((index, record));
+    }
+
+    if let Some((_, record)) = current {
+        write_record(writer, &record)?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GronJsonlOptions, gron_jsonl};
+
+    fn streaming(input: &str, options: &UngronJsonlOptions) -> Result<String> {
+        let mut output = Vec::new();
+        ungron_jsonl_streaming(input.as_bytes(), options, &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_roundtrip_bracketed() {
+        let input = b"{\"a\":1,\"b\":[true]}\n{\"c\":\"x\"}\n[1,2]";
+        let gron_output = gron_jsonl(input, &GronJsonlOptions::default()).unwrap();
+        let expected = "{\"a\":1,\"b\":[true]}\n{\"c\":\"x\"}\n[1,2]\n";
+
+        let options = UngronJsonlOptions::default();
+        assert_eq!(ungron_jsonl(&gron_output, &options).unwrap(), expected);
+        assert_eq!(streaming(&gron_output, &options).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_roundtrip_dotted() {
+        let input = b"{\"a\":1}\n{\"b\":2}";
+        let gron_options = GronJsonlOptions::default().index_format(IndexFormat::Dotted);
+        let gron_output = gron_jsonl(input, &gron_options).unwrap();
+
+        let options = UngronJsonlOptions::default().index_format(IndexFormat::Dotted);
+        assert_eq!(
+            streaming(&gron_output, &options).unwrap(),
+            "{\"a\":1}\n{\"b\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_unindexed() {
+        let input = b"{\"a\":1}\n{\"b\":2}";
+        let gron_options = GronJsonlOptions::default().index_format(IndexFormat::None);
+        let gron_output = gron_jsonl(input, &gron_options).unwrap();
+
+        let options = UngronJsonlOptions::default().index_format(IndexFormat::None);
+        assert_eq!(
+            ungron_jsonl(&gron_output, &options).unwrap(),
+            "{\"a\":1}\n{\"b\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_json_stream() {
+        let input = b"{\"a\":1}\n{\"b\":2}";
+        let gron_output = gron_jsonl(input, &GronJsonlOptions::default().json()).unwrap();
+        assert_eq!(
+            streaming(&gron_output, &UngronJsonlOptions::default()).unwrap(),
+            "{\"a\":1}\n{\"b\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_pointer_style() {
+        let input = b"{\"a/b\":1}\n{\"c\":[2]}";
+        let gron_options = GronJsonlOptions {
+            gron: crate::GronOptions::default().path_style(PathStyle::Pointer),
+            ..GronJsonlOptions::default()
+        };
+        let gron_output = gron_jsonl(input, &gron_options).unwrap();
+        assert!(gron_output.contains("/0/a~1b = 1;"));
+
+        let options = UngronJsonlOptions::default().path_style(PathStyle::Pointer);
+        assert_eq!(
+            streaming(&gron_output, &options).unwrap(),
+            "{\"a/b\":1}\n{\"c\":[2]}\n"
+        );
+    }
+
+    #[test]
+    fn test_sparse_indices() {
+        // As left by `grep`: no container lines, records 1 and 3 missing
+        let input = "json[0].name = \"a\";\njson[2].name = \"c\";\njson[4].tags[1] = \"x\";\n";
+        let expected = "{\"name\":\"a\"}\n{\"name\":\"c\"}\n{\"tags\":[null,\"x\"]}\n";
+
+        let options = UngronJsonlOptions::default();
+        assert_eq!(ungron_jsonl(input, &options).unwrap(), expected);
+        assert_eq!(streaming(input, &options).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_unsorted_input() {
+        let input = "json[1].b = 2;\njson[0].a = 1;\n";
+        let options = UngronJsonlOptions::default();
+        assert_eq!(
+            ungron_jsonl(input, &options).unwrap(),
+            "{\"a\":1}\n{\"b\":2}\n"
+        );
+        assert!(streaming(input, &options).is_err());
+    }
+
+    #[test]
+    fn test_missing_record_index() {
+        let options = UngronJsonlOptions::default();
+        assert!(ungron_jsonl("json.a = 1;", &options).is_err());
+        assert!(streaming("json.a = 1;", &options).is_err());
+    }
+
+    #[test]
+    fn test_streaming_record_count() {
+        let input = "json = [];\njson[0] = {};\njson[0].a = 1;\n\njson[1] = 2;\n";
+        let mut output = Vec::new();
+        let written = ungron_jsonl_streaming(
+            input.as_bytes(),
+            &UngronJsonlOptions::default(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(String::from_utf8(output).unwrap(), "{\"a\":1}\n2\n");
+    }
+}
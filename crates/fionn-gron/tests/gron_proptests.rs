@@ -6,7 +6,8 @@
 //! - `gron_from_tape` produces same output as gron
 //! - ungron handles various gron formats correctly
 
-use fionn_gron::{GronOptions, gron, ungron_to_value};
+use fionn_core::path::PathStyle;
+use fionn_gron::{GronOptions, gron, ungron_to_value, ungron_to_value_with_style};
 use proptest::prelude::*;
 use serde_json::{Value, json};
 
@@ -39,6 +40,61 @@ fn arb_json_value(depth: usize) -> impl Strategy<Value = Value> {
     })
 }
 
+/// Generate object keys built from characters that are significant in
+/// assignment lines or path syntax
+///
+/// Keys always contain a non-digit, since JSON Pointer reads all-digit
+/// tokens as array indices.
+fn arb_hostile_key() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        Just(" = "),
+        Just("="),
+        Just(";"),
+        Just(" "),
+        Just("\""),
+        Just("'"),
+        Just("\\"),
+        Just("."),
+        Just("/"),
+        Just("~"),
+        Just("["),
+        Just("]"),
+        Just("#"),
+        Just("$"),
+        Just("\n"),
+        Just("\t"),
+        Just("0"),
+        Just("a"),
+    ];
+    proptest::collection::vec(fragment, 0..6).prop_map(|fragments| {
+        let key = fragments.concat();
+        if !key.is_empty() && key.bytes().all(|b| b.is_ascii_digit()) {
+            key + "x"
+        } else {
+            key
+        }
+    })
+}
+
+/// Generate JSON values whose keys and strings are hostile to gron lines
+fn arb_hostile_json_value(depth: usize) -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<i64>().prop_map(|n| json!(n)),
+        arb_hostile_key().prop_map(Value::String),
+    ];
+
+    leaf.prop_recursive(u32::try_from(depth).unwrap_or(u32::MAX), 32, 4, |inner| {
+        prop_oneof![
+            proptest::collection::vec((arb_hostile_key(), inner.clone()), 1..4).prop_map(|pairs| {
+                let map: serde_json::Map<String, Value> = pairs.into_iter().collect();
+                Value::Object(map)
+            }),
+            proptest::collection::vec(inner, 1..4).prop_map(Value::Array),
+        ]
+    })
+}
+
 /// Normalize JSON for comparison (sort object keys, etc.)
 fn normalize_json(value: &Value) -> Value {
     match value {
@@ -93,6 +149,42 @@ proptest! {
         );
     }
 
+    /// Property: every path style roundtrips keys containing assignment and
+    /// path syntax (` = `, `;`, quotes, separators, newlines)
+    #[test]
+    fn prop_gron_path_styles_roundtrip_hostile_keys(
+        value in arb_hostile_json_value(3),
+        compact in any::<bool>(),
+    ) {
+        let json_str = serde_json::to_string(&value).unwrap();
+        for style in PathStyle::ALL {
+            let mut options = GronOptions::default().path_style(style);
+            if compact {
+                options = options.compact();
+            }
+            let gron_output = gron(&json_str, &options).unwrap();
+            let recovered = ungron_to_value_with_style(&gron_output, style);
+            prop_assert!(
+                recovered.as_ref().is_ok_and(|recovered| json_equivalent(&value, recovered)),
+                "{} roundtrip failed:\n{}\n{:?}",
+                style, gron_output, recovered
+            );
+        }
+    }
+
+    /// Property: JSON stream gron → ungron roundtrip produces equivalent JSON
+    #[test]
+    fn prop_gron_json_stream_roundtrip(value in arb_json_value(3)) {
+        let json_str = serde_json::to_string(&value).unwrap();
+        let gron_output = gron(&json_str, &GronOptions::default().json()).unwrap();
+        let recovered = ungron_to_value(&gron_output).unwrap();
+        prop_assert!(
+            json_equivalent(&value, &recovered),
+            "Stream roundtrip should produce equivalent JSON:\nOriginal: {:?}\nRecovered: {:?}",
+            value, recovered
+        );
+    }
+
     /// Property: gron with different prefix still roundtrips
     #[test]
     fn prop_gron_custom_prefix_roundtrip(value in arb_json_value(2), prefix in "[a-z]{1,5}") {
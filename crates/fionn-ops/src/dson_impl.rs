@@ -891,7 +891,7 @@ mod tests {
         let remote_op = CrdtOperation {
             operation: DsonOperation::MergeField {
                 path: "counter".to_string(),
-                value: OperationValue::NumberRef("20".to_string()),
+                value: OperationValue::NumberRef("20".into()),
                 timestamp: 5,
             },
             timestamp: 5,
@@ -954,12 +954,12 @@ mod tests {
         proc.process(r#"{"name":"Alice"}"#).unwrap();
 
         // Field add
-        proc.field_add("age", OperationValue::NumberRef("30".to_string()))
+        proc.field_add("age", OperationValue::NumberRef("30".into()))
             .unwrap();
         assert!(proc.field_exists("age"));
 
         // Field modify
-        proc.field_modify("age", OperationValue::NumberRef("31".to_string()))
+        proc.field_modify("age", OperationValue::NumberRef("31".into()))
             .unwrap();
 
         // Field delete
@@ -1027,7 +1027,7 @@ mod tests {
         let result = proc
             .array_reduce(
                 "items",
-                OperationValue::NumberRef("0".to_string()),
+                OperationValue::NumberRef("0".into()),
                 &ReduceFunction::Sum,
             )
             .unwrap();
@@ -1571,7 +1571,7 @@ mod tests {
             .map(|i| {
                 (
                     format!("field_{i}"),
-                    OperationValue::NumberRef(i.to_string()),
+                    OperationValue::NumberRef(i.to_string().into()),
                     i,
                 )
             })
@@ -1598,7 +1598,7 @@ mod tests {
         vc1.increment("replica_1");
         proc.operation_log.push((
             "field".to_string(),
-            OperationValue::NumberRef("1".to_string()),
+            OperationValue::NumberRef("1".into()),
             1,
             vc1.clone(),
         ));
@@ -1608,7 +1608,7 @@ mod tests {
         vc2.increment("replica_1");
         proc.operation_log.push((
             "field".to_string(),
-            OperationValue::NumberRef("2".to_string()),
+            OperationValue::NumberRef("2".into()),
             2,
             vc2,
         ));
@@ -1631,7 +1631,7 @@ mod tests {
         let ops: Vec<DsonOperation> = (0..20)
             .map(|i| DsonOperation::FieldAdd {
                 path: format!("field_{i}"),
-                value: OperationValue::NumberRef(i.to_string()),
+                value: OperationValue::NumberRef(i.to_string().into()),
             })
             .collect();
 
@@ -1648,7 +1648,7 @@ mod tests {
         let ops: Vec<DsonOperation> = (0..15)
             .map(|i| DsonOperation::FieldModify {
                 path: "shared".to_string(),
-                value: OperationValue::NumberRef(i.to_string()),
+                value: OperationValue::NumberRef(i.to_string().into()),
             })
             .collect();
 
@@ -174,6 +174,22 @@ impl VectorClock {
         }
     }
 
+    /// Rebuild a clock from `(replica_id, timestamp)` pairs, the inverse of [`Self::clocks`].
+    #[must_use]
+    pub fn from_clocks(clocks: impl IntoIterator<Item = (String, u64)>) -> Self {
+        let mut clock = Self::new();
+        for (replica_id, time) in clocks {
+            let hash = Self::hash_replica_id(&replica_id);
+            if let Some((_, ts)) = clock.inline.iter_mut().find(|(h, _)| *h == hash) {
+                *ts = (*ts).max(time);
+            } else {
+                clock.inline.push((hash, time));
+                clock.replica_ids.push(replica_id);
+            }
+        }
+        clock
+    }
+
     #[inline]
     fn hash_replica_id(replica_id: &str) -> u64 {
         use std::hash::{Hash, Hasher};
@@ -271,6 +287,14 @@ impl VectorClock {
         !self.happened_before(other) && !other.happened_before(self)
     }
 
+    /// Iterate `(replica_id, timestamp)` entries without allocating.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.replica_ids
+            .iter()
+            .zip(&self.inline)
+            .map(|(id, &(_, ts))| (id.as_str(), ts))
+    }
+
     /// For compatibility: get clocks as `BTreeMap` (for iteration in tests/benchmarks)
     #[must_use]
     pub fn clocks(&self) -> std::collections::BTreeMap<String, u64> {
@@ -562,8 +586,8 @@ impl MergeStrategy {
                 // For numeric values, take the max
                 match (local, remote) {
                     (OperationValue::NumberRef(a), OperationValue::NumberRef(b)) => {
-                        let a_val: f64 = a.parse().unwrap_or(0.0);
-                        let b_val: f64 = b.parse().unwrap_or(0.0);
+                        let a_val = a.as_f64().unwrap_or(0.0);
+                        let b_val = b.as_f64().unwrap_or(0.0);
                         if b_val > a_val {
                             remote.clone()
                         } else {
@@ -582,8 +606,8 @@ impl MergeStrategy {
             }
             Self::Min => match (local, remote) {
                 (OperationValue::NumberRef(a), OperationValue::NumberRef(b)) => {
-                    let a_val: f64 = a.parse().unwrap_or(0.0);
-                    let b_val: f64 = b.parse().unwrap_or(0.0);
+                    let a_val = a.as_f64().unwrap_or(0.0);
+                    let b_val = b.as_f64().unwrap_or(0.0);
                     if b_val < a_val {
                         remote.clone()
                     } else {
@@ -602,9 +626,9 @@ impl MergeStrategy {
                 // For numeric values, sum them
                 match (local, remote) {
                     (OperationValue::NumberRef(a), OperationValue::NumberRef(b)) => {
-                        let a_val: f64 = a.parse().unwrap_or(0.0);
-                        let b_val: f64 = b.parse().unwrap_or(0.0);
-                        OperationValue::NumberRef((a_val + b_val).to_string())
+                        let a_val = a.as_f64().unwrap_or(0.0);
+                        let b_val = b.as_f64().unwrap_or(0.0);
+                        OperationValue::NumberRef((a_val + b_val).into())
                     }
                     // For strings, concatenate
                     (OperationValue::StringRef(a), OperationValue::StringRef(b)) => {
@@ -691,8 +715,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_max() {
-        let local = OperationValue::NumberRef("10".to_string());
-        let remote = OperationValue::NumberRef("20".to_string());
+        let local = OperationValue::NumberRef("10".into());
+        let remote = OperationValue::NumberRef("20".into());
 
         let result = MergeStrategy::Max.resolve(&local, &remote, 1, 1);
         assert_eq!(result, remote);
@@ -700,8 +724,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_additive() {
-        let local = OperationValue::NumberRef("10".to_string());
-        let remote = OperationValue::NumberRef("20".to_string());
+        let local = OperationValue::NumberRef("10".into());
+        let remote = OperationValue::NumberRef("20".into());
 
         let result = MergeStrategy::Additive.resolve(&local, &remote, 1, 1);
         match result {
@@ -839,6 +863,22 @@ mod tests {
         let _ = vc1 == vc2;
     }
 
+    #[test]
+    fn test_vector_clock_from_clocks_roundtrip() {
+        let mut vc = VectorClock::new();
+        vc.increment("a");
+        vc.increment("a");
+        vc.increment("b");
+
+        let rebuilt = VectorClock::from_clocks(vc.clocks());
+        assert_eq!(rebuilt.get("a"), 2);
+        assert_eq!(rebuilt.get("b"), 1);
+        assert_eq!(rebuilt.clocks(), vc.clocks());
+        assert!(!rebuilt.happened_before(&vc));
+        assert!(!vc.happened_before(&rebuilt));
+        assert_eq!(rebuilt.iter().collect::<Vec<_>>(), vec![("a", 2), ("b", 1)]);
+    }
+
     #[test]
     fn test_vector_clock_debug() {
         let vc = VectorClock::new();
@@ -914,11 +954,11 @@ mod tests {
     fn test_merge_conflict_with_resolved() {
         let conflict = MergeConflict {
             path: "counter".to_string(),
-            local_value: OperationValue::NumberRef("10".to_string()),
-            remote_value: OperationValue::NumberRef("20".to_string()),
+            local_value: OperationValue::NumberRef("10".into()),
+            remote_value: OperationValue::NumberRef("20".into()),
             local_timestamp: 1,
             remote_timestamp: 2,
-            resolved_value: Some(OperationValue::NumberRef("20".to_string())),
+            resolved_value: Some(OperationValue::NumberRef("20".into())),
         };
 
         assert!(conflict.resolved_value.is_some());
@@ -956,8 +996,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_min() {
-        let local = OperationValue::NumberRef("10".to_string());
-        let remote = OperationValue::NumberRef("5".to_string());
+        let local = OperationValue::NumberRef("10".into());
+        let remote = OperationValue::NumberRef("5".into());
 
         let result = MergeStrategy::Min.resolve(&local, &remote, 1, 1);
         assert_eq!(result, remote); // 5 is smaller than 10
@@ -965,8 +1005,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_min_local_smaller() {
-        let local = OperationValue::NumberRef("3".to_string());
-        let remote = OperationValue::NumberRef("10".to_string());
+        let local = OperationValue::NumberRef("3".into());
+        let remote = OperationValue::NumberRef("10".into());
 
         let result = MergeStrategy::Min.resolve(&local, &remote, 1, 1);
         assert_eq!(result, local); // 3 is smaller than 10
@@ -984,8 +1024,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_max_local_larger() {
-        let local = OperationValue::NumberRef("100".to_string());
-        let remote = OperationValue::NumberRef("50".to_string());
+        let local = OperationValue::NumberRef("100".into());
+        let remote = OperationValue::NumberRef("50".into());
 
         let result = MergeStrategy::Max.resolve(&local, &remote, 1, 1);
         assert_eq!(result, local); // 100 is larger
@@ -1016,7 +1056,7 @@ mod tests {
     #[test]
     fn test_merge_strategy_additive_non_matching() {
         let local = OperationValue::BoolRef(true);
-        let remote = OperationValue::NumberRef("10".to_string());
+        let remote = OperationValue::NumberRef("10".into());
 
         // Falls back to LWW for non-matching types
         let result = MergeStrategy::Additive.resolve(&local, &remote, 1, 2);
@@ -1120,8 +1160,8 @@ mod tests {
     #[test]
     fn test_merge_strategy_max_invalid_parse() {
         // Test with non-parseable numbers
-        let local = OperationValue::NumberRef("invalid".to_string());
-        let remote = OperationValue::NumberRef("5".to_string());
+        let local = OperationValue::NumberRef("invalid".into());
+        let remote = OperationValue::NumberRef("5".into());
 
         let result = MergeStrategy::Max.resolve(&local, &remote, 1, 1);
         // "invalid" parses to 0.0, so 5 wins
@@ -1130,8 +1170,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_min_invalid_parse() {
-        let local = OperationValue::NumberRef("invalid".to_string());
-        let remote = OperationValue::NumberRef("5".to_string());
+        let local = OperationValue::NumberRef("invalid".into());
+        let remote = OperationValue::NumberRef("5".into());
 
         let result = MergeStrategy::Min.resolve(&local, &remote, 1, 1);
         // "invalid" parses to 0.0, so 0 wins (local)
@@ -1140,8 +1180,8 @@ mod tests {
 
     #[test]
     fn test_merge_strategy_additive_invalid_parse() {
-        let local = OperationValue::NumberRef("invalid".to_string());
-        let remote = OperationValue::NumberRef("10".to_string());
+        let local = OperationValue::NumberRef("invalid".into());
+        let remote = OperationValue::NumberRef("10".into());
 
         let result = MergeStrategy::Additive.resolve(&local, &remote, 1, 1);
         // "invalid" parses to 0.0, so result is 0 + 10 = 10
@@ -665,11 +665,11 @@ impl OperationOptimizer {
                 DsonOperation::FieldDelete { path: del_path } if del_path == path => {
                     saw_delete = true;
                 }
-                DsonOperation::FieldAdd { path: add_path, .. } if add_path == path => {
+                DsonOperation::FieldAdd { path: add_path, .. }
+                    if add_path == path && saw_delete =>
+                {
                     // If we see an add after a delete, it's not redundant
-                    if saw_delete {
-                        return false;
-                    }
+                    return false;
                 }
                 _ => {}
             }
@@ -889,8 +889,8 @@ mod tests {
             OperationValue::StringRef("test".to_string())
         );
         assert_eq!(
-            OperationValue::NumberRef("42".to_string()),
-            OperationValue::NumberRef("42".to_string())
+            OperationValue::NumberRef("42".into()),
+            OperationValue::NumberRef("42".into())
         );
         assert_eq!(
             OperationValue::ObjectRef { start: 0, end: 10 },
@@ -1182,7 +1182,7 @@ mod tests {
         });
         processor.add_operation(DsonOperation::ArrayReduce {
             path: "arr".to_string(),
-            initial: OperationValue::NumberRef("0".to_string()),
+            initial: OperationValue::NumberRef("0".into()),
             reducer: ReduceFunction::Sum,
         });
 
@@ -1610,7 +1610,7 @@ mod tests {
         assert!(format!("{:?}", OperationValue::Null).contains("Null"));
         assert!(format!("{:?}", OperationValue::BoolRef(true)).contains("BoolRef"));
         assert!(format!("{:?}", OperationValue::StringRef("s".to_string())).contains("StringRef"));
-        assert!(format!("{:?}", OperationValue::NumberRef("1".to_string())).contains("NumberRef"));
+        assert!(format!("{:?}", OperationValue::NumberRef("1".into())).contains("NumberRef"));
         assert!(
             format!("{:?}", OperationValue::ObjectRef { start: 0, end: 1 }).contains("ObjectRef")
         );
@@ -2148,7 +2148,7 @@ mod tests {
         );
         assert_ne!(
             OperationValue::StringRef("a".to_string()),
-            OperationValue::NumberRef("1".to_string())
+            OperationValue::NumberRef("1".into())
         );
         assert_ne!(
             OperationValue::ObjectRef { start: 0, end: 1 },
@@ -473,15 +473,9 @@ impl BlackBoxProcessor {
                 Node::String(s) => OperationValue::StringRef(s.to_string()),
                 Node::Static(simd_json::StaticNode::Bool(b)) => OperationValue::BoolRef(b),
                 Node::Static(simd_json::StaticNode::Null) => OperationValue::Null,
-                Node::Static(simd_json::StaticNode::I64(n)) => {
-                    OperationValue::NumberRef(n.to_string())
-                }
-                Node::Static(simd_json::StaticNode::U64(n)) => {
-                    OperationValue::NumberRef(n.to_string())
-                }
-                Node::Static(simd_json::StaticNode::F64(n)) => {
-                    OperationValue::NumberRef(n.to_string())
-                }
+                Node::Static(simd_json::StaticNode::I64(n)) => OperationValue::NumberRef(n.into()),
+                Node::Static(simd_json::StaticNode::U64(n)) => OperationValue::NumberRef(n.into()),
+                Node::Static(simd_json::StaticNode::F64(n)) => OperationValue::NumberRef(n.into()),
                 Node::Object { len, .. } => OperationValue::ObjectRef { start: 0, end: len },
                 Node::Array { len, .. } => OperationValue::ArrayRef { start: 0, end: len },
             };
@@ -499,9 +493,9 @@ impl BlackBoxProcessor {
             Node::String(s) => OperationValue::StringRef(s.to_string()),
             Node::Static(simd_json::StaticNode::Bool(b)) => OperationValue::BoolRef(b),
             Node::Static(simd_json::StaticNode::Null) => OperationValue::Null,
-            Node::Static(simd_json::StaticNode::I64(n)) => OperationValue::NumberRef(n.to_string()),
-            Node::Static(simd_json::StaticNode::U64(n)) => OperationValue::NumberRef(n.to_string()),
-            Node::Static(simd_json::StaticNode::F64(n)) => OperationValue::NumberRef(n.to_string()),
+            Node::Static(simd_json::StaticNode::I64(n)) => OperationValue::NumberRef(n.into()),
+            Node::Static(simd_json::StaticNode::U64(n)) => OperationValue::NumberRef(n.into()),
+            Node::Static(simd_json::StaticNode::F64(n)) => OperationValue::NumberRef(n.into()),
             Node::Object { .. } => OperationValue::ObjectRef { start: 0, end: 0 },
             Node::Array { .. } => OperationValue::ArrayRef { start: 0, end: 0 },
         };
@@ -533,9 +527,9 @@ impl BlackBoxProcessor {
         let _op_value = match record {
             Node::String(s) => OperationValue::StringRef(s.to_string()),
             Node::Static(simd_json::StaticNode::Bool(b)) => OperationValue::BoolRef(b),
-            Node::Static(simd_json::StaticNode::I64(n)) => OperationValue::NumberRef(n.to_string()),
-            Node::Static(simd_json::StaticNode::U64(n)) => OperationValue::NumberRef(n.to_string()),
-            Node::Static(simd_json::StaticNode::F64(n)) => OperationValue::NumberRef(n.to_string()),
+            Node::Static(simd_json::StaticNode::I64(n)) => OperationValue::NumberRef(n.into()),
+            Node::Static(simd_json::StaticNode::U64(n)) => OperationValue::NumberRef(n.into()),
+            Node::Static(simd_json::StaticNode::F64(n)) => OperationValue::NumberRef(n.into()),
             Node::Static(simd_json::StaticNode::Null)
             | Node::Object { .. }
             | Node::Array { .. } => OperationValue::Null,
@@ -703,7 +697,7 @@ impl BlackBoxProcessor {
         let filter_value = match predicate {
             crate::FilterPredicate::Even => OperationValue::StringRef("even".to_string()),
             crate::FilterPredicate::Odd => OperationValue::StringRef("odd".to_string()),
-            crate::FilterPredicate::EveryNth(n) => OperationValue::NumberRef(n.to_string()),
+            crate::FilterPredicate::EveryNth(n) => OperationValue::NumberRef(n.to_string().into()),
             _ => OperationValue::StringRef("custom_filter".to_string()),
         };
         self.modifications.insert(filter_path, filter_value);
@@ -714,8 +708,10 @@ impl BlackBoxProcessor {
         // Track the map operation
         let map_path = format!("{path}.map");
         let map_value = match transform {
-            crate::TransformFunction::Add(n) => OperationValue::NumberRef(n.to_string()),
-            crate::TransformFunction::Multiply(n) => OperationValue::NumberRef(format!("*{n}")),
+            crate::TransformFunction::Add(n) => OperationValue::NumberRef(n.to_string().into()),
+            crate::TransformFunction::Multiply(n) => {
+                OperationValue::NumberRef(format!("*{n}").into())
+            }
             crate::TransformFunction::ToUppercase => {
                 OperationValue::StringRef("uppercase".to_string())
             }
@@ -777,8 +773,10 @@ impl BlackBoxProcessor {
         let predicate_value = match predicate {
             crate::FilterPredicate::Even => OperationValue::StringRef("even".to_string()),
             crate::FilterPredicate::Odd => OperationValue::StringRef("odd".to_string()),
-            crate::FilterPredicate::EveryNth(n) => OperationValue::NumberRef(n.to_string()),
-            crate::FilterPredicate::GreaterThan(val) => OperationValue::NumberRef(val.to_string()),
+            crate::FilterPredicate::EveryNth(n) => OperationValue::NumberRef(n.to_string().into()),
+            crate::FilterPredicate::GreaterThan(val) => {
+                OperationValue::NumberRef(val.to_string().into())
+            }
             _ => OperationValue::StringRef("custom_filter".to_string()),
         };
         self.modifications.insert(filter_path, predicate_value);
@@ -789,8 +787,10 @@ impl BlackBoxProcessor {
         // Track stream transformation operation
         let map_path = format!("{path}.stream_map");
         let transform_value = match transform {
-            crate::TransformFunction::Add(n) => OperationValue::NumberRef(n.to_string()),
-            crate::TransformFunction::Multiply(n) => OperationValue::NumberRef(format!("*{n}")),
+            crate::TransformFunction::Add(n) => OperationValue::NumberRef(n.to_string().into()),
+            crate::TransformFunction::Multiply(n) => {
+                OperationValue::NumberRef(format!("*{n}").into())
+            }
             crate::TransformFunction::ToUppercase => {
                 OperationValue::StringRef("uppercase".to_string())
             }
@@ -803,7 +803,7 @@ impl BlackBoxProcessor {
     fn apply_stream_emit(&mut self, path: &str, batch_size: usize) {
         // Track stream emission operation
         let emit_path = format!("{path}.stream_emit");
-        let emit_value = OperationValue::NumberRef(batch_size.to_string());
+        let emit_value = OperationValue::NumberRef(batch_size.to_string().into());
         self.modifications.insert(emit_path, emit_value);
     }
 
@@ -1169,18 +1169,7 @@ impl BlackBoxProcessor {
     fn operation_value_to_json(op_value: &crate::OperationValue) -> serde_json::Value {
         match op_value {
             crate::OperationValue::StringRef(s) => serde_json::Value::String(s.clone()),
-            crate::OperationValue::NumberRef(n) => n.parse::<i64>().map_or_else(
-                |_| {
-                    n.parse::<f64>()
-                        .ok()
-                        .and_then(serde_json::Number::from_f64)
-                        .map_or_else(
-                            || serde_json::Value::String(n.clone()),
-                            serde_json::Value::Number,
-                        )
-                },
-                |num| serde_json::Value::Number(num.into()),
-            ),
+            crate::OperationValue::NumberRef(n) => n.to_json_value(),
             crate::OperationValue::BoolRef(b) => serde_json::Value::Bool(*b),
             crate::OperationValue::Null => serde_json::Value::Null,
             // ObjectRef and ArrayRef represent references to the tape
@@ -1256,11 +1245,11 @@ mod tests {
             },
             DsonOperation::FieldAdd {
                 path: "user.age".to_string(),
-                value: OperationValue::NumberRef("30".to_string()),
+                value: OperationValue::NumberRef("30".into()),
             },
             DsonOperation::FieldModify {
                 path: "user.age".to_string(),
-                value: OperationValue::NumberRef("31".to_string()),
+                value: OperationValue::NumberRef("31".into()),
             },
             DsonOperation::FieldDelete {
                 path: "user.temp".to_string(),
@@ -1367,11 +1356,11 @@ mod tests {
             DsonOperation::ArrayBuild {
                 path: "data".to_string(),
                 elements: vec![
-                    OperationValue::NumberRef("1".to_string()),
-                    OperationValue::NumberRef("2".to_string()),
-                    OperationValue::NumberRef("3".to_string()),
-                    OperationValue::NumberRef("4".to_string()),
-                    OperationValue::NumberRef("5".to_string()),
+                    OperationValue::NumberRef("1".into()),
+                    OperationValue::NumberRef("2".into()),
+                    OperationValue::NumberRef("3".into()),
+                    OperationValue::NumberRef("4".into()),
+                    OperationValue::NumberRef("5".into()),
                 ],
             },
             // Filter even numbers
@@ -1387,7 +1376,7 @@ mod tests {
             // Sum all results
             DsonOperation::ArrayReduce {
                 path: "data".to_string(),
-                initial: OperationValue::NumberRef("0".to_string()),
+                initial: OperationValue::NumberRef("0".into()),
                 reducer: ReduceFunction::Sum,
             },
         ];
@@ -1528,14 +1517,14 @@ mod tests {
             DsonOperation::ArrayBuild {
                 path: "nums".to_string(),
                 elements: vec![
-                    OperationValue::NumberRef("1".to_string()),
-                    OperationValue::NumberRef("2".to_string()),
-                    OperationValue::NumberRef("3".to_string()),
+                    OperationValue::NumberRef("1".into()),
+                    OperationValue::NumberRef("2".into()),
+                    OperationValue::NumberRef("3".into()),
                 ],
             },
             DsonOperation::ArrayReduce {
                 path: "nums".to_string(),
-                initial: OperationValue::NumberRef("0".to_string()),
+                initial: OperationValue::NumberRef("0".into()),
                 reducer: ReduceFunction::Sum,
             },
         ];
@@ -1550,13 +1539,13 @@ mod tests {
             DsonOperation::ArrayBuild {
                 path: "nums".to_string(),
                 elements: vec![
-                    OperationValue::NumberRef("2".to_string()),
-                    OperationValue::NumberRef("3".to_string()),
+                    OperationValue::NumberRef("2".into()),
+                    OperationValue::NumberRef("3".into()),
                 ],
             },
             DsonOperation::ArrayReduce {
                 path: "nums".to_string(),
-                initial: OperationValue::NumberRef("1".to_string()),
+                initial: OperationValue::NumberRef("1".into()),
                 reducer: ReduceFunction::Product,
             },
         ];
@@ -1569,7 +1558,7 @@ mod tests {
         let mut processor = BlackBoxProcessor::new_unfiltered();
         let operations = vec![DsonOperation::ArrayReduce {
             path: "nums".to_string(),
-            initial: OperationValue::NumberRef("999".to_string()),
+            initial: OperationValue::NumberRef("999".into()),
             reducer: ReduceFunction::Min,
         }];
         let result = processor.process_with_operations(r"{}", &operations);
@@ -1581,7 +1570,7 @@ mod tests {
         let mut processor = BlackBoxProcessor::new_unfiltered();
         let operations = vec![DsonOperation::ArrayReduce {
             path: "nums".to_string(),
-            initial: OperationValue::NumberRef("0".to_string()),
+            initial: OperationValue::NumberRef("0".into()),
             reducer: ReduceFunction::Max,
         }];
         let result = processor.process_with_operations(r"{}", &operations);
@@ -1593,7 +1582,7 @@ mod tests {
         let mut processor = BlackBoxProcessor::new_unfiltered();
         let operations = vec![DsonOperation::ArrayReduce {
             path: "nums".to_string(),
-            initial: OperationValue::NumberRef("0".to_string()),
+            initial: OperationValue::NumberRef("0".into()),
             reducer: ReduceFunction::Count,
         }];
         let result = processor.process_with_operations(r"{}", &operations);
@@ -1738,7 +1727,7 @@ mod tests {
         let mut processor = BlackBoxProcessor::new_unfiltered();
         let operations = vec![DsonOperation::StreamBuild {
             path: "repeated".to_string(),
-            generator: StreamGenerator::Repeat(OperationValue::NumberRef("42".to_string()), 3),
+            generator: StreamGenerator::Repeat(OperationValue::NumberRef("42".into()), 3),
         }];
         let result = processor.process_with_operations(r"{}", &operations);
         assert!(result.is_ok());
@@ -1991,7 +1980,7 @@ mod tests {
         let mut processor = BlackBoxProcessor::new_unfiltered();
         let operations = vec![DsonOperation::MergeField {
             path: "counter".to_string(),
-            value: OperationValue::NumberRef("10".to_string()),
+            value: OperationValue::NumberRef("10".into()),
             timestamp: 100,
         }];
         let result = processor.process_with_operations(r"{}", &operations);
@@ -2087,12 +2076,12 @@ mod tests {
             DsonOperation::ArrayInsert {
                 path: "items".to_string(),
                 index: 0,
-                value: OperationValue::NumberRef("1".to_string()),
+                value: OperationValue::NumberRef("1".into()),
             },
             DsonOperation::ArrayInsert {
                 path: "items".to_string(),
                 index: 1,
-                value: OperationValue::NumberRef("2".to_string()),
+                value: OperationValue::NumberRef("2".into()),
             },
         ];
         let result = processor.process_with_operations(r"{}", &operations);
@@ -2125,7 +2114,7 @@ mod tests {
             },
             DsonOperation::ArrayReduce {
                 path: "s".to_string(),
-                initial: OperationValue::NumberRef("0".to_string()),
+                initial: OperationValue::NumberRef("0".into()),
                 reducer: ReduceFunction::Sum,
             },
         ];
@@ -2727,7 +2716,7 @@ mod tests {
         for i in 0..150 {
             operations.push(DsonOperation::FieldAdd {
                 path: format!("field{i}"),
-                value: OperationValue::NumberRef(i.to_string()),
+                value: OperationValue::NumberRef(i.to_string().into()),
             });
         }
         let result = processor.process_adaptive(r#"{"base": "data"}"#, &operations);
@@ -91,7 +91,7 @@ impl StreamingProcessor {
         let mut current = start;
         while current < end {
             self.current_batch
-                .push(OperationValue::NumberRef(current.to_string()));
+                .push(OperationValue::NumberRef(current.into()));
             current += step;
         }
         // Don't flush - let streaming operations work on current_batch
@@ -114,8 +114,7 @@ impl StreamingProcessor {
         let mut b = 1i64;
 
         for _ in 0..count {
-            self.current_batch
-                .push(OperationValue::NumberRef(a.to_string()));
+            self.current_batch.push(OperationValue::NumberRef(a.into()));
             if self.current_batch.len() >= self.buffer_size {
                 self.flush_batch();
             }
@@ -190,14 +189,14 @@ impl StreamingProcessor {
         match predicate {
             FilterPredicate::Even => {
                 if let OperationValue::NumberRef(num_str) = value {
-                    num_str.parse::<i64>().is_ok_and(|num| num % 2 == 0)
+                    num_str.as_i64().is_some_and(|num| num % 2 == 0)
                 } else {
                     false
                 }
             }
             FilterPredicate::Odd => {
                 if let OperationValue::NumberRef(num_str) = value {
-                    num_str.parse::<i64>().is_ok_and(|num| num % 2 == 1)
+                    num_str.as_i64().is_some_and(|num| num % 2 == 1)
                 } else {
                     false
                 }
@@ -205,14 +204,14 @@ impl StreamingProcessor {
             FilterPredicate::EveryNth(n) => index.is_multiple_of(*n),
             FilterPredicate::GreaterThan(threshold) => {
                 if let OperationValue::NumberRef(num_str) = value {
-                    num_str.parse::<i64>().is_ok_and(|num| num > *threshold)
+                    num_str.as_i64().is_some_and(|num| num > *threshold)
                 } else {
                     false
                 }
             }
             FilterPredicate::LessThan(threshold) => {
                 if let OperationValue::NumberRef(num_str) = value {
-                    num_str.parse::<i64>().is_ok_and(|num| num < *threshold)
+                    num_str.as_i64().is_some_and(|num| num < *threshold)
                 } else {
                     false
                 }
@@ -221,11 +220,9 @@ impl StreamingProcessor {
                 match (value, compare_value) {
                     (OperationValue::NumberRef(num_str), OperationValue::NumberRef(cmp_str)) => {
                         // Compare as numbers
-                        if let (Ok(a), Ok(b)) = (num_str.parse::<i64>(), cmp_str.parse::<i64>()) {
+                        if let (Some(a), Some(b)) = (num_str.as_i64(), cmp_str.as_i64()) {
                             a == b
-                        } else if let (Ok(a), Ok(b)) =
-                            (num_str.parse::<f64>(), cmp_str.parse::<f64>())
-                        {
+                        } else if let (Some(a), Some(b)) = (num_str.as_f64(), cmp_str.as_f64()) {
                             (a - b).abs() < f64::EPSILON
                         } else {
                             num_str == cmp_str
@@ -261,14 +258,14 @@ impl StreamingProcessor {
             if let Some(threshold_str) = rest.strip_prefix(">") {
                 if let Ok(threshold) = threshold_str.trim().parse::<i64>()
                     && let OperationValue::NumberRef(num_str) = value
-                    && let Ok(num) = num_str.parse::<i64>()
+                    && let Some(num) = num_str.as_i64()
                 {
                     return num > threshold;
                 }
             } else if let Some(threshold_str) = rest.strip_prefix("<") {
                 if let Ok(threshold) = threshold_str.trim().parse::<i64>()
                     && let OperationValue::NumberRef(num_str) = value
-                    && let Ok(num) = num_str.parse::<i64>()
+                    && let Some(num) = num_str.as_i64()
                 {
                     return num < threshold;
                 }
@@ -297,15 +294,15 @@ impl StreamingProcessor {
     fn apply_transform(value: &OperationValue, transform: &TransformFunction) -> OperationValue {
         match (value, transform) {
             (OperationValue::NumberRef(num_str), TransformFunction::Add(delta)) => {
-                num_str.parse::<i64>().map_or_else(
-                    |_| value.clone(),
-                    |num| OperationValue::NumberRef((num + delta).to_string()),
+                num_str.as_i64().map_or_else(
+                    || value.clone(),
+                    |num| OperationValue::NumberRef((num + delta).into()),
                 )
             }
             (OperationValue::NumberRef(num_str), TransformFunction::Multiply(factor)) => {
-                num_str.parse::<i64>().map_or_else(
-                    |_| value.clone(),
-                    |num| OperationValue::NumberRef((num * factor).to_string()),
+                num_str.as_i64().map_or_else(
+                    || value.clone(),
+                    |num| OperationValue::NumberRef((num * factor).into()),
                 )
             }
             (OperationValue::StringRef(text), TransformFunction::ToUppercase) => {
@@ -549,7 +546,7 @@ mod tests {
             },
             DsonOperation::StreamFilter {
                 path: "numbers".to_string(),
-                predicate: FilterPredicate::Equals(OperationValue::NumberRef("5".to_string())),
+                predicate: FilterPredicate::Equals(OperationValue::NumberRef("5".into())),
             },
         ];
         processor.process_stream(&operations).unwrap();
@@ -753,7 +750,7 @@ mod tests {
         let mut processor = StreamingProcessor::new(3);
         let operations = vec![DsonOperation::StreamBuild {
             path: "rep".to_string(),
-            generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".to_string()), 10),
+            generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".into()), 10),
         }];
         processor.process_stream(&operations).unwrap();
         // Should have flushed multiple times
@@ -791,7 +788,7 @@ mod tests {
         let operations = vec![
             DsonOperation::StreamBuild {
                 path: "numbers".to_string(),
-                generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".to_string()), 10),
+                generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".into()), 10),
             },
             DsonOperation::StreamEmit {
                 path: "out".to_string(),
@@ -944,7 +941,7 @@ mod tests {
         let mut processor = StreamingProcessor::new(10);
         processor
             .current_batch
-            .push(OperationValue::NumberRef("5".to_string()));
+            .push(OperationValue::NumberRef("5".into()));
 
         let operations = vec![DsonOperation::StreamFilter {
             path: "test".to_string(),
@@ -963,7 +960,7 @@ mod tests {
             .push(OperationValue::StringRef("not a number".to_string()));
         processor
             .current_batch
-            .push(OperationValue::NumberRef("4".to_string()));
+            .push(OperationValue::NumberRef("4".into()));
         processor.current_batch.push(OperationValue::BoolRef(true));
 
         let operations = vec![DsonOperation::StreamFilter {
@@ -982,7 +979,7 @@ mod tests {
             .push(OperationValue::StringRef("not a number".to_string()));
         processor
             .current_batch
-            .push(OperationValue::NumberRef("3".to_string()));
+            .push(OperationValue::NumberRef("3".into()));
 
         let operations = vec![DsonOperation::StreamFilter {
             path: "test".to_string(),
@@ -1000,7 +997,7 @@ mod tests {
             .push(OperationValue::StringRef("text".to_string()));
         processor
             .current_batch
-            .push(OperationValue::NumberRef("10".to_string()));
+            .push(OperationValue::NumberRef("10".into()));
 
         let operations = vec![DsonOperation::StreamFilter {
             path: "test".to_string(),
@@ -1016,7 +1013,7 @@ mod tests {
         processor.current_batch.push(OperationValue::BoolRef(false));
         processor
             .current_batch
-            .push(OperationValue::NumberRef("3".to_string()));
+            .push(OperationValue::NumberRef("3".into()));
 
         let operations = vec![DsonOperation::StreamFilter {
             path: "test".to_string(),
@@ -1031,7 +1028,7 @@ mod tests {
         let mut processor = StreamingProcessor::new(10);
         processor
             .current_batch
-            .push(OperationValue::NumberRef("not_a_number".to_string()));
+            .push(OperationValue::NumberRef("not_a_number".into()));
 
         let operations = vec![DsonOperation::StreamMap {
             path: "test".to_string(),
@@ -1047,7 +1044,7 @@ mod tests {
         let mut processor = StreamingProcessor::new(10);
         processor
             .current_batch
-            .push(OperationValue::NumberRef("invalid".to_string()));
+            .push(OperationValue::NumberRef("invalid".into()));
 
         let operations = vec![DsonOperation::StreamMap {
             path: "test".to_string(),
@@ -1063,7 +1060,7 @@ mod tests {
         // Try to uppercase a number (unsupported)
         processor
             .current_batch
-            .push(OperationValue::NumberRef("5".to_string()));
+            .push(OperationValue::NumberRef("5".into()));
 
         let operations = vec![DsonOperation::StreamMap {
             path: "test".to_string(),
@@ -1079,14 +1076,14 @@ mod tests {
         let mut processor = StreamingProcessor::new(10);
         processor
             .current_batch
-            .push(OperationValue::NumberRef("3.14".to_string()));
+            .push(OperationValue::NumberRef("3.14".into()));
         processor
             .current_batch
-            .push(OperationValue::NumberRef("2.71".to_string()));
+            .push(OperationValue::NumberRef("2.71".into()));
 
         let operations = vec![DsonOperation::StreamFilter {
             path: "floats".to_string(),
-            predicate: FilterPredicate::Equals(OperationValue::NumberRef("3.14".to_string())),
+            predicate: FilterPredicate::Equals(OperationValue::NumberRef("3.14".into())),
         }];
         processor.process_stream(&operations).unwrap();
         assert_eq!(processor.total_items(), 1);
@@ -1133,7 +1130,7 @@ mod tests {
         let mut processor = StreamingProcessor::new(3);
         let operations = vec![DsonOperation::StreamBuild {
             path: "nums".to_string(),
-            generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".to_string()), 9),
+            generator: StreamGenerator::Repeat(OperationValue::NumberRef("1".into()), 9),
         }];
         processor.process_stream(&operations).unwrap();
         assert!(processor.batch_count() >= 3);
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! RAII guard for pooled buffers.
+
+use super::TapePool;
+use super::buffer::PooledBuffer;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+
+/// A pooled buffer that returns itself to its pool when dropped.
+///
+/// Obtained from [`TapePoolExt::acquire_guard`](super::TapePoolExt::acquire_guard).
+/// Dereferences to [`PooledBuffer`], so it can be used anywhere a buffer
+/// is expected; call [`PoolGuard::into_inner`] to keep the buffer instead.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fionn::pool::{SharedPool, PoolStrategy, TapePool, TapePoolExt};
+///
+/// let pool = SharedPool::new(PoolStrategy::default());
+/// {
+///     let mut buffer = pool.acquire_guard(1024);
+///     buffer.extend_from_slice(b"{}");
+/// } // buffer released here
+/// assert_eq!(pool.stats().releases, 1);
+/// ```
+pub struct PoolGuard<'a, P: TapePool + ?Sized> {
+    pool: &'a P,
+    buffer: PooledBuffer,
+}
+
+impl<'a, P: TapePool + ?Sized> PoolGuard<'a, P> {
+    /// Wrap a buffer so it is released to `pool` on drop.
+    #[must_use]
+    pub const fn new(pool: &'a P, buffer: PooledBuffer) -> Self {
+        Self { pool, buffer }
+    }
+
+    /// Take the buffer out of the guard without returning it to the pool.
+    #[must_use]
+    pub fn into_inner(self) -> PooledBuffer {
+        let mut guard = ManuallyDrop::new(self);
+        std::mem::take(&mut guard.buffer)
+    }
+
+    /// Get the pool this guard releases into.
+    #[must_use]
+    pub const fn pool(&self) -> &'a P {
+        self.pool
+    }
+}
+
+impl<P: TapePool + ?Sized> Deref for PoolGuard<'_, P> {
+    type Target = PooledBuffer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<P: TapePool + ?Sized> DerefMut for PoolGuard<'_, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl<P: TapePool + ?Sized> Drop for PoolGuard<'_, P> {
+    fn drop(&mut self) {
+        self.pool.release(std::mem::take(&mut self.buffer));
+    }
+}
+
+impl<P: TapePool + ?Sized> std::fmt::Debug for PoolGuard<'_, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoolGuard")
+            .field("buffer", &self.buffer)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PoolStrategy, SharedPool, TapePoolExt, ThreadLocalPool};
+
+    #[test]
+    fn test_guard_releases_on_drop() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        {
+            let mut buffer = pool.acquire_guard(100);
+            buffer.extend_from_slice(b"data");
+            assert_eq!(&buffer[..], b"data");
+            assert_eq!(pool.stats().buffers_in_pool, 0);
+        }
+
+        let stats = pool.stats();
+        assert_eq!(stats.releases, 1);
+        assert_eq!(stats.buffers_in_pool, 1);
+    }
+
+    #[test]
+    fn test_guard_into_inner_keeps_buffer() {
+        let pool = ThreadLocalPool::new(PoolStrategy::Unbounded);
+        let buffer = pool.acquire_guard(100).into_inner();
+        assert!(buffer.capacity() >= 100);
+        assert_eq!(pool.stats().releases, 0);
+
+        pool.release(buffer);
+        assert_eq!(pool.stats().buffers_in_pool, 1);
+    }
+
+    #[test]
+    fn test_guard_releases_on_panic() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _buffer = pool.acquire_guard(100);
+            panic!("unwinding with a guard");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(pool.stats().buffers_in_pool, 1);
+    }
+}
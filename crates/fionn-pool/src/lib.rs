@@ -5,7 +5,18 @@
 //! Two pool variants are available:
 //!
 //! - [`ThreadLocalPool`]: Fast, no synchronization, one pool per thread
-//! - [`SharedPool`]: Arc-based concurrent pool for multi-threaded access
+//! - [`SharedPool`]: Arc-based concurrent pool for multi-threaded access,
+//!   with size-class buckets and per-thread shards that steal from each other
+//!
+//! [`TapePoolExt::acquire_guard`] wraps a buffer in a [`PoolGuard`] that
+//! returns it to the pool when dropped.
+//!
+//! Beyond byte buffers, [`ObjectPool`] recycles any [`Reusable`] storage:
+//! simd-json tape nodes and scratch buffers ([`TapeStorage`]), bump arenas
+//! ([`ArenaPool`]) and builder storage defined by downstream crates. Every
+//! [`TapePool`] carries a tape storage pool, so [`TapePoolExt::parse`]
+//! reuses node vectors once the parsed tape is handed back with
+//! [`TapePoolExt::recycle`].
 //!
 //! ## Strategies
 //!
@@ -15,6 +26,7 @@
 //! - [`PoolStrategy::SizeLimited`]: Maximum number of tapes
 //! - [`PoolStrategy::MemoryLimited`]: Maximum total bytes
 //! - [`PoolStrategy::Lru`]: Least-recently-used eviction with size limit
+//! - [`PoolStrategy::IdleTimeout`]: LRU plus trimming of idle buffers
 //!
 //! ## Example
 //!
@@ -34,13 +46,19 @@
 //! ```
 
 mod buffer;
+mod guard;
+mod object;
 mod shared;
 mod strategy;
+mod tape;
 mod thread_local;
 
 pub use buffer::PooledBuffer;
+pub use guard::PoolGuard;
+pub use object::{ArenaPool, ObjectPool, Reusable};
 pub use shared::SharedPool;
-pub use strategy::{PoolStats, PoolStrategy};
+pub use strategy::{PoolStats, PoolStrategy, SizeClassStats};
+pub use tape::TapeStorage;
 pub use thread_local::ThreadLocalPool;
 
 /// Trait for tape buffer pools.
@@ -59,7 +77,7 @@ pub trait TapePool: Send {
     /// based on the pool's eviction strategy.
     fn release(&self, buffer: PooledBuffer);
 
-    /// Clear all buffers from the pool.
+    /// Clear all buffers (and pooled tape storage) from the pool.
     fn clear(&self);
 
     /// Get current pool statistics.
@@ -67,27 +85,51 @@ pub trait TapePool: Send {
 
     /// Get the pool's strategy.
     fn strategy(&self) -> &PoolStrategy;
+
+    /// Get the pool of recyclable simd-json tape storage.
+    fn tape_storage(&self) -> &ObjectPool<TapeStorage>;
 }
 
 /// Extension trait for pools with typed tape access.
 pub trait TapePoolExt: TapePool {
-    /// Parse JSON into a tape using a pooled buffer.
+    /// Acquire a buffer that is released back to the pool when dropped.
+    fn acquire_guard(&self, min_capacity: usize) -> PoolGuard<'_, Self> {
+        PoolGuard::new(self, self.acquire(min_capacity))
+    }
+
+    /// Parse JSON into a tape using pooled tape storage.
+    ///
+    /// The tape node vector and simd-json scratch buffers come from
+    /// [`TapePool::tape_storage`]; pass the result to
+    /// [`TapePoolExt::recycle`] to return them.
     ///
     /// # Errors
     /// Returns an error if JSON parsing fails.
     fn parse<'a>(&self, json: &'a mut [u8]) -> Result<ParsedTape<'a>, fionn_core::DsonError> {
-        let tape = simd_json::to_tape(json)
-            .map_err(|e| fionn_core::DsonError::ParseError(format!("{e}")))?;
-        Ok(ParsedTape { tape })
+        let (buffers, result) = self.tape_storage().acquire().fill(json);
+        match result {
+            Ok(tape) => Ok(ParsedTape { tape, buffers }),
+            Err(e) => {
+                self.tape_storage()
+                    .release(TapeStorage::from_buffers(buffers));
+                Err(fionn_core::DsonError::ParseError(format!("{e}")))
+            }
+        }
+    }
+
+    /// Return a parsed tape's storage to the pool.
+    fn recycle(&self, parsed: ParsedTape<'_>) {
+        self.tape_storage()
+            .release(TapeStorage::restore(parsed.tape, parsed.buffers));
     }
 }
 
 impl<T: TapePool> TapePoolExt for T {}
 
 /// A parsed tape with lifetime tied to input.
-#[derive(Debug)]
 pub struct ParsedTape<'a> {
     tape: simd_json::tape::Tape<'a>,
+    buffers: simd_json::Buffers,
 }
 
 impl<'a> ParsedTape<'a> {
@@ -104,6 +146,14 @@ impl<'a> ParsedTape<'a> {
     }
 }
 
+impl std::fmt::Debug for ParsedTape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParsedTape")
+            .field("tape", &self.tape)
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,12 +431,39 @@ mod tests {
         let parsed2 = pool.parse(&mut json2).unwrap();
         let parsed3 = pool.parse(&mut json3).unwrap();
 
-        // Verify parsing succeeded (parse() uses simd_json directly, not pool buffers)
+        // Verify parsing succeeded (buffers are untouched; tapes use tape storage)
         assert!(!parsed1.nodes().is_empty());
         assert!(!parsed2.nodes().is_empty());
         assert!(!parsed3.nodes().is_empty());
     }
 
+    #[test]
+    fn test_recycle_reuses_tape_storage() {
+        let pool = ThreadLocalPool::new(PoolStrategy::Unbounded);
+
+        let mut json = br#"{"a": [1, 2, 3]}"#.to_vec();
+        let parsed = pool.parse(&mut json).unwrap();
+        pool.recycle(parsed);
+        assert_eq!(pool.tape_storage().stats().buffers_in_pool, 1);
+
+        let mut json = br#"{"b": true}"#.to_vec();
+        let parsed = pool.parse(&mut json).unwrap();
+        assert_eq!(parsed.nodes().len(), 3);
+
+        let stats = pool.tape_storage().stats();
+        assert_eq!(stats.reuses, 1);
+        assert_eq!(stats.allocations, 1);
+    }
+
+    #[test]
+    fn test_parse_error_returns_storage() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+
+        let mut json = br"[1, 2".to_vec();
+        assert!(pool.parse(&mut json).is_err());
+        assert_eq!(pool.tape_storage().stats().buffers_in_pool, 1);
+    }
+
     #[test]
     fn test_shared_pool_parse() {
         let pool = SharedPool::new(PoolStrategy::SizeLimited { max_tapes: 8 });
@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Typed object pools for reusable parse storage.
+//!
+//! [`ObjectPool`] recycles any [`Reusable`] value: tape node vectors, bump
+//! arenas, builder storage. Values are reset on release, so an acquire in
+//! steady state hands back storage that already has the capacity the
+//! previous batch grew it to.
+
+use super::buffer::PooledBuffer;
+use super::strategy::{PoolStats, PoolStrategy};
+use bumpalo::Bump;
+use parking_lot::Mutex;
+
+/// Storage that can be cleared and handed out again.
+pub trait Reusable: Send {
+    /// Clear contents while keeping allocated capacity.
+    fn reset(&mut self);
+
+    /// Bytes of capacity retained by this value.
+    ///
+    /// Used by [`PoolStrategy::MemoryLimited`] accounting.
+    fn retained_bytes(&self) -> usize;
+}
+
+impl<T: Send> Reusable for Vec<T> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<T>()
+    }
+}
+
+impl Reusable for PooledBuffer {
+    fn reset(&mut self) {
+        self.clear();
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl Reusable for Bump {
+    fn reset(&mut self) {
+        // Keeps the largest chunk, so a steady workload stops allocating
+        Self::reset(self);
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.allocated_bytes()
+    }
+}
+
+/// Concurrent pool of reusable objects.
+///
+/// Objects are kept on a LIFO free list so the most recently released (and
+/// therefore cache-warm) value is handed out first. Eviction follows the
+/// pool's [`PoolStrategy`]: count limits drop the oldest entries, memory
+/// limits drop the largest.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fionn::pool::{ArenaPool, PoolStrategy};
+///
+/// let arenas = ArenaPool::new(PoolStrategy::size_limited(4));
+/// let arena = arenas.acquire();
+/// let s = arena.alloc_str("scratch");
+/// // ... build a tape in the arena ...
+/// arenas.release(arena);
+/// ```
+pub struct ObjectPool<T: Reusable> {
+    strategy: PoolStrategy,
+    state: Mutex<ObjectState<T>>,
+}
+
+struct ObjectState<T> {
+    free: Vec<T>,
+    stats: PoolStats,
+}
+
+/// Pool of reset-able bump arenas.
+pub type ArenaPool = ObjectPool<Bump>;
+
+impl<T: Reusable> ObjectPool<T> {
+    /// Create a new pool with the given strategy.
+    #[must_use]
+    pub fn new(strategy: PoolStrategy) -> Self {
+        Self {
+            strategy,
+            state: Mutex::new(ObjectState {
+                free: Vec::new(),
+                stats: PoolStats::default(),
+            }),
+        }
+    }
+
+    /// Take an object from the pool, or build one with `make` if empty.
+    pub fn acquire_with(&self, make: impl FnOnce() -> T) -> T {
+        let pooled = {
+            let mut state = self.state.lock();
+            state.stats.acquires += 1;
+            let pooled = state.free.pop();
+            if let Some(obj) = &pooled {
+                state.stats.reuses += 1;
+                state.stats.buffers_in_pool = state.free.len();
+                state.stats.total_bytes_in_pool -= obj.retained_bytes();
+            } else {
+                state.stats.allocations += 1;
+            }
+            pooled
+        };
+        pooled.unwrap_or_else(make)
+    }
+
+    /// Take an object from the pool, or a default one if empty.
+    pub fn acquire(&self) -> T
+    where
+        T: Default,
+    {
+        self.acquire_with(T::default)
+    }
+
+    /// Reset an object and return it to the pool.
+    pub fn release(&self, mut obj: T) {
+        obj.reset();
+        let bytes = obj.retained_bytes();
+
+        let mut state = self.state.lock();
+        state.stats.releases += 1;
+        state.free.push(obj);
+        state.stats.total_bytes_in_pool += bytes;
+        self.evict_if_needed(&mut state);
+
+        state.stats.buffers_in_pool = state.free.len();
+        if state.stats.buffers_in_pool > state.stats.peak_buffers {
+            state.stats.peak_buffers = state.stats.buffers_in_pool;
+        }
+        if state.stats.total_bytes_in_pool > state.stats.peak_bytes {
+            state.stats.peak_bytes = state.stats.total_bytes_in_pool;
+        }
+    }
+
+    /// Drop every pooled object.
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        state.free.clear();
+        state.stats.buffers_in_pool = 0;
+        state.stats.total_bytes_in_pool = 0;
+    }
+
+    /// Get current pool statistics.
+    #[must_use]
+    pub fn stats(&self) -> PoolStats {
+        self.state.lock().stats.clone()
+    }
+
+    /// Get the pool's strategy.
+    #[must_use]
+    pub const fn strategy(&self) -> &PoolStrategy {
+        &self.strategy
+    }
+
+    fn evict_if_needed(&self, state: &mut ObjectState<T>) {
+        match &self.strategy {
+            PoolStrategy::Unbounded => {}
+
+            PoolStrategy::SizeLimited { max_tapes }
+            | PoolStrategy::Lru { max_tapes }
+            | PoolStrategy::IdleTimeout { max_tapes, .. } => {
+                // The free list is LIFO, so the front holds the least recently used
+                while state.free.len() > *max_tapes {
+                    let obj = state.free.remove(0);
+                    state.stats.total_bytes_in_pool -= obj.retained_bytes();
+                    state.stats.evictions += 1;
+                }
+            }
+
+            PoolStrategy::MemoryLimited { max_bytes } => {
+                while state.stats.total_bytes_in_pool > *max_bytes && !state.free.is_empty() {
+                    let max_idx = state
+                        .free
+                        .iter()
+                        .enumerate()
+                        .max_by_key(|(_, o)| o.retained_bytes())
+                        .map(|(i, _)| i);
+
+                    if let Some(idx) = max_idx {
+                        let obj = state.free.remove(idx);
+                        state.stats.total_bytes_in_pool -= obj.retained_bytes();
+                        state.stats.evictions += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Reusable> Default for ObjectPool<T> {
+    fn default() -> Self {
+        Self::new(PoolStrategy::default())
+    }
+}
+
+impl<T: Reusable> std::fmt::Debug for ObjectPool<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectPool")
+            .field("strategy", &self.strategy)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_reuse_keeps_capacity() {
+        let pool: ObjectPool<Vec<u64>> = ObjectPool::new(PoolStrategy::Unbounded);
+
+        let mut v = pool.acquire();
+        v.extend(0..100);
+        let cap = v.capacity();
+        pool.release(v);
+
+        let v = pool.acquire();
+        assert!(v.is_empty());
+        assert_eq!(v.capacity(), cap);
+
+        let stats = pool.stats();
+        assert_eq!(stats.acquires, 2);
+        assert_eq!(stats.reuses, 1);
+        assert_eq!(stats.allocations, 1);
+    }
+
+    #[test]
+    fn test_acquire_with_only_builds_when_empty() {
+        let pool: ObjectPool<Vec<u8>> = ObjectPool::new(PoolStrategy::Unbounded);
+        pool.release(Vec::with_capacity(64));
+
+        let v = pool.acquire_with(|| panic!("pooled object should be reused"));
+        assert!(v.capacity() >= 64);
+    }
+
+    #[test]
+    fn test_arena_reset_on_release() {
+        let pool = ArenaPool::new(PoolStrategy::size_limited(2));
+
+        let arena = pool.acquire();
+        arena.alloc_str("hello world");
+        let before = arena.allocated_bytes();
+        pool.release(arena);
+
+        let arena = pool.acquire();
+        assert_eq!(arena.allocated_bytes(), before);
+        // A reset arena starts allocating from the beginning of its chunk
+        assert_eq!(arena.alloc_str("again"), "again");
+    }
+
+    #[test]
+    fn test_size_limited_evicts_oldest() {
+        let pool: ObjectPool<Vec<u8>> = ObjectPool::new(PoolStrategy::size_limited(2));
+
+        pool.release(Vec::with_capacity(1));
+        pool.release(Vec::with_capacity(2));
+        pool.release(Vec::with_capacity(3));
+
+        let stats = pool.stats();
+        assert_eq!(stats.buffers_in_pool, 2);
+        assert_eq!(stats.evictions, 1);
+
+        // Most recent first
+        assert!(pool.acquire().capacity() >= 3);
+        assert!(pool.acquire().capacity() >= 2);
+    }
+
+    #[test]
+    fn test_memory_limited_evicts_largest() {
+        let pool: ObjectPool<Vec<u8>> = ObjectPool::new(PoolStrategy::memory_limited(100));
+
+        pool.release(Vec::with_capacity(40));
+        pool.release(Vec::with_capacity(80));
+
+        let stats = pool.stats();
+        assert!(stats.total_bytes_in_pool <= 100);
+        assert_eq!(stats.evictions, 1);
+        assert!(pool.acquire().capacity() < 80);
+    }
+
+    #[test]
+    fn test_clear() {
+        let pool: ObjectPool<Vec<u8>> = ObjectPool::default();
+        pool.release(Vec::with_capacity(8));
+        pool.clear();
+
+        let stats = pool.stats();
+        assert_eq!(stats.buffers_in_pool, 0);
+        assert_eq!(stats.total_bytes_in_pool, 0);
+    }
+}
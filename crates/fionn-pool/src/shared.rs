@@ -1,18 +1,110 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 //! Shared concurrent tape pool implementation.
+//!
+//! Buffers are bucketed into power-of-two size classes, so `acquire` goes
+//! straight to a bucket instead of scanning every pooled buffer. Each
+//! thread has a home shard with its own lock; when the home shard has no
+//! buffer of the right class, `acquire` steals one from another shard
+//! before falling back to allocation.
 
 use super::TapePool;
 use super::buffer::PooledBuffer;
-use super::strategy::{PoolStats, PoolStrategy};
+use super::object::ObjectPool;
+use super::strategy::{PoolStats, PoolStrategy, SizeClassStats};
+use super::tape::TapeStorage;
 use parking_lot::Mutex;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Smallest buffer the pool hands out or keeps.
+const MIN_CAPACITY: usize = 256;
+
+/// Number of size classes: 256 B up to 16 MiB, the last class also holding
+/// anything larger.
+const SIZE_CLASSES: usize = 17;
+
+/// Upper bound on the number of shards.
+const MAX_SHARDS: usize = 64;
+
+/// Source of per-thread shard hints.
+static NEXT_SHARD_HINT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SHARD_HINT: usize = NEXT_SHARD_HINT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Smallest capacity held by `class`.
+const fn class_capacity(class: usize) -> usize {
+    MIN_CAPACITY << class
+}
+
+/// Class whose buffers all hold at least `min_capacity` bytes.
+fn acquire_class(min_capacity: usize) -> usize {
+    if min_capacity <= MIN_CAPACITY {
+        return 0;
+    }
+    min_capacity
+        .checked_next_power_of_two()
+        .map_or(SIZE_CLASSES - 1, release_class)
+}
+
+/// Class a buffer of `capacity` bytes is pooled in.
+fn release_class(capacity: usize) -> usize {
+    let class = capacity.ilog2() - MIN_CAPACITY.ilog2();
+    (class as usize).min(SIZE_CLASSES - 1)
+}
+
+/// A pooled buffer with the time it was released.
+struct IdleBuffer {
+    buffer: PooledBuffer,
+    released_at: Instant,
+}
+
+/// One shard's free lists, one per size class.
+///
+/// Each list is ordered by release time: acquires pop the most recently
+/// released buffer from the back, eviction and idle trimming take the
+/// oldest from the front.
+#[derive(Default)]
+struct Shard {
+    classes: [VecDeque<IdleBuffer>; SIZE_CLASSES],
+}
+
+#[derive(Default)]
+struct ClassCounters {
+    acquires: AtomicU64,
+    reuses: AtomicU64,
+    buffers: AtomicUsize,
+}
+
+#[derive(Default)]
+struct Counters {
+    acquires: AtomicU64,
+    releases: AtomicU64,
+    reuses: AtomicU64,
+    allocations: AtomicU64,
+    evictions: AtomicU64,
+    steals: AtomicU64,
+    idle_trims: AtomicU64,
+    buffers: AtomicUsize,
+    bytes: AtomicUsize,
+    peak_buffers: AtomicUsize,
+    peak_bytes: AtomicUsize,
+    classes: [ClassCounters; SIZE_CLASSES],
+}
 
 /// Shared concurrent buffer pool.
 ///
-/// Thread-safe pool using `parking_lot::Mutex` for synchronization.
+/// Thread-safe pool using sharded `parking_lot::Mutex` free lists.
 /// Best for multi-threaded workloads where buffers may be acquired
 /// on one thread and released on another.
 ///
+/// Strategy limits apply to the pool as a whole. Eviction prefers the
+/// releasing thread's shard, so LRU order is approximate across shards.
+/// With [`PoolStrategy::IdleTimeout`], releases trim idle buffers from the
+/// home shard and [`SharedPool::trim_idle`] sweeps every shard.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -32,25 +124,36 @@ use std::sync::atomic::{AtomicU64, Ordering};
 pub struct SharedPool {
     strategy: PoolStrategy,
     timestamp: AtomicU64,
-    state: Mutex<PoolState>,
-}
-
-struct PoolState {
-    buffers: Vec<PooledBuffer>,
-    stats: PoolStats,
+    tapes: ObjectPool<TapeStorage>,
+    shards: Box<[Mutex<Shard>]>,
+    counters: Counters,
 }
 
 impl SharedPool {
     /// Create a new shared pool with the given strategy.
+    ///
+    /// Uses one shard per available CPU, rounded up to a power of two.
     #[must_use]
     pub fn new(strategy: PoolStrategy) -> Self {
+        let shards = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .next_power_of_two();
+        Self::with_shards(strategy, shards)
+    }
+
+    /// Create a new shared pool with an explicit number of shards.
+    ///
+    /// The shard count is clamped to `1..=64`.
+    #[must_use]
+    pub fn with_shards(strategy: PoolStrategy, shards: usize) -> Self {
         Self {
+            tapes: ObjectPool::new(strategy.clone()),
             strategy,
             timestamp: AtomicU64::new(0),
-            state: Mutex::new(PoolState {
-                buffers: Vec::new(),
-                stats: PoolStats::default(),
-            }),
+            shards: (0..shards.clamp(1, MAX_SHARDS))
+                .map(|_| Mutex::new(Shard::default()))
+                .collect(),
+            counters: Counters::default(),
         }
     }
 
@@ -60,138 +163,296 @@ impl SharedPool {
         Self::new(PoolStrategy::default())
     }
 
+    /// Get the number of shards.
+    #[must_use]
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Drop buffers that have idled past the strategy's `max_idle`.
+    ///
+    /// Returns the number of buffers dropped. Does nothing unless the
+    /// strategy is [`PoolStrategy::IdleTimeout`].
+    pub fn trim_idle(&self) -> usize {
+        let Some(max_idle) = self.strategy.max_idle() else {
+            return 0;
+        };
+        let now = Instant::now();
+        self.shards
+            .iter()
+            .map(|shard| self.trim_shard(&mut shard.lock(), now, max_idle))
+            .sum()
+    }
+
     fn next_timestamp(&self) -> u64 {
         self.timestamp.fetch_add(1, Ordering::Relaxed)
     }
 
-    fn evict_if_needed(strategy: &PoolStrategy, state: &mut PoolState) {
-        match strategy {
-            PoolStrategy::Unbounded => {}
+    fn home_shard(&self) -> usize {
+        SHARD_HINT.with(|hint| *hint) % self.shards.len()
+    }
 
-            PoolStrategy::SizeLimited { max_tapes } => {
-                while state.buffers.len() >= *max_tapes {
-                    if let Some(buf) = state.buffers.pop() {
-                        state.stats.total_bytes_in_pool -= buf.capacity();
-                        state.stats.evictions += 1;
-                    }
-                }
-            }
+    /// Pop a buffer of `class` holding at least `min_capacity` bytes.
+    fn take(&self, shard: &mut Shard, class: usize, min_capacity: usize) -> Option<PooledBuffer> {
+        let free = &mut shard.classes[class];
+        let idle = if class == SIZE_CLASSES - 1 {
+            // The last class is open-ended, so capacities must be checked
+            let idx = free
+                .iter()
+                .rposition(|idle| idle.buffer.capacity() >= min_capacity)?;
+            free.remove(idx)?
+        } else {
+            free.pop_back()?
+        };
+        self.forget(class, &idle.buffer);
+        Some(idle.buffer)
+    }
 
-            PoolStrategy::MemoryLimited { max_bytes } => {
-                while state.stats.total_bytes_in_pool >= *max_bytes && !state.buffers.is_empty() {
-                    let max_idx = state
-                        .buffers
-                        .iter()
-                        .enumerate()
-                        .max_by_key(|(_, b)| b.capacity())
-                        .map(|(i, _)| i);
-
-                    if let Some(idx) = max_idx {
-                        let buf = state.buffers.remove(idx);
-                        state.stats.total_bytes_in_pool -= buf.capacity();
-                        state.stats.evictions += 1;
-                    }
+    /// Look for a buffer in shards other than `home`, skipping busy ones.
+    fn steal(&self, home: usize, class: usize, min_capacity: usize) -> Option<PooledBuffer> {
+        let shards = self.shards.len();
+        (1..shards).find_map(|offset| {
+            let mut shard = self.shards[(home + offset) % shards].try_lock()?;
+            self.take(&mut shard, class, min_capacity)
+        })
+    }
+
+    /// Add a buffer to a locked shard, updating counters under the lock.
+    fn store(&self, shard: &mut Shard, class: usize, idle: IdleBuffer) {
+        let capacity = idle.buffer.capacity();
+        shard.classes[class].push_back(idle);
+        self.counters.classes[class]
+            .buffers
+            .fetch_add(1, Ordering::Relaxed);
+        let buffers = self.counters.buffers.fetch_add(1, Ordering::Relaxed) + 1;
+        let bytes = self.counters.bytes.fetch_add(capacity, Ordering::Relaxed) + capacity;
+        self.counters
+            .peak_buffers
+            .fetch_max(buffers, Ordering::Relaxed);
+        self.counters.peak_bytes.fetch_max(bytes, Ordering::Relaxed);
+    }
+
+    /// Account for a buffer removed from a locked shard.
+    fn forget(&self, class: usize, buffer: &PooledBuffer) {
+        self.counters.classes[class]
+            .buffers
+            .fetch_sub(1, Ordering::Relaxed);
+        self.counters.buffers.fetch_sub(1, Ordering::Relaxed);
+        self.counters
+            .bytes
+            .fetch_sub(buffer.capacity(), Ordering::Relaxed);
+    }
+
+    fn trim_shard(&self, shard: &mut Shard, now: Instant, max_idle: Duration) -> usize {
+        let mut trimmed = 0;
+        for class in 0..SIZE_CLASSES {
+            while shard.classes[class]
+                .front()
+                .is_some_and(|idle| now.duration_since(idle.released_at) >= max_idle)
+            {
+                if let Some(idle) = shard.classes[class].pop_front() {
+                    self.forget(class, &idle.buffer);
+                    trimmed += 1;
                 }
             }
+        }
+        self.counters
+            .idle_trims
+            .fetch_add(trimmed as u64, Ordering::Relaxed);
+        trimmed
+    }
 
-            PoolStrategy::Lru { max_tapes } => {
-                while state.buffers.len() >= *max_tapes {
-                    let lru_idx = state
-                        .buffers
-                        .iter()
-                        .enumerate()
-                        .min_by_key(|(_, b)| b.last_used)
-                        .map(|(i, _)| i);
-
-                    if let Some(idx) = lru_idx {
-                        let buf = state.buffers.remove(idx);
-                        state.stats.total_bytes_in_pool -= buf.capacity();
-                        state.stats.evictions += 1;
-                    }
-                }
+    fn over_limit(&self) -> bool {
+        self.strategy
+            .max_tapes()
+            .is_some_and(|max| self.counters.buffers.load(Ordering::Relaxed) > max)
+            || self
+                .strategy
+                .max_bytes()
+                .is_some_and(|max| self.counters.bytes.load(Ordering::Relaxed) > max)
+    }
+
+    fn evict_if_needed(&self, home: usize) {
+        while self.over_limit() && self.evict_one(home) {}
+    }
+
+    /// Evict one buffer, preferring the `home` shard.
+    ///
+    /// Memory limits evict the largest buffer, count limits the oldest.
+    fn evict_one(&self, home: usize) -> bool {
+        let shards = self.shards.len();
+        for offset in 0..shards {
+            let mut shard = self.shards[(home + offset) % shards].lock();
+            if let Some((class, idle)) = self.pick_victim(&mut shard) {
+                self.forget(class, &idle.buffer);
+                drop(shard);
+                self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+                return true;
             }
         }
+        false
+    }
+
+    fn pick_victim(&self, shard: &mut Shard) -> Option<(usize, IdleBuffer)> {
+        if self.strategy.max_bytes().is_some() {
+            // Class ranges are disjoint, so the largest buffer is in the
+            // highest non-empty class
+            let class = (0..SIZE_CLASSES)
+                .rev()
+                .find(|&class| !shard.classes[class].is_empty())?;
+            let free = &mut shard.classes[class];
+            let idx = free
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, idle)| idle.buffer.capacity())
+                .map(|(i, _)| i)?;
+            return free.remove(idx).map(|idle| (class, idle));
+        }
+
+        let class = (0..SIZE_CLASSES)
+            .filter_map(|class| {
+                shard.classes[class]
+                    .front()
+                    .map(|idle| (class, idle.buffer.last_used))
+            })
+            .min_by_key(|&(_, last_used)| last_used)?
+            .0;
+        shard.classes[class].pop_front().map(|idle| (class, idle))
     }
 }
 
 impl TapePool for SharedPool {
     fn acquire(&self, min_capacity: usize) -> PooledBuffer {
-        let mut buffer = {
-            let mut state = self.state.lock();
-            state.stats.acquires += 1;
-
-            let suitable_idx = state
-                .buffers
-                .iter()
-                .enumerate()
-                .find(|(_, b)| b.capacity() >= min_capacity)
-                .map(|(i, _)| i);
-
-            let buf = if let Some(idx) = suitable_idx {
-                let mut buf = state.buffers.remove(idx);
-                state.stats.total_bytes_in_pool -= buf.capacity();
-                state.stats.buffers_in_pool = state.buffers.len();
-                state.stats.reuses += 1;
+        let class = acquire_class(min_capacity);
+        self.counters.acquires.fetch_add(1, Ordering::Relaxed);
+        self.counters.classes[class]
+            .acquires
+            .fetch_add(1, Ordering::Relaxed);
+
+        let home = self.home_shard();
+        let reused = self
+            .take(&mut self.shards[home].lock(), class, min_capacity)
+            .or_else(|| {
+                let stolen = self.steal(home, class, min_capacity)?;
+                self.counters.steals.fetch_add(1, Ordering::Relaxed);
+                Some(stolen)
+            });
+
+        let mut buffer = reused.map_or_else(
+            || {
+                self.counters.allocations.fetch_add(1, Ordering::Relaxed);
+                PooledBuffer::with_capacity(class_capacity(class).max(min_capacity))
+            },
+            |mut buf| {
+                self.counters.reuses.fetch_add(1, Ordering::Relaxed);
+                self.counters.classes[class]
+                    .reuses
+                    .fetch_add(1, Ordering::Relaxed);
                 buf.clear();
                 buf
-            } else {
-                state.stats.allocations += 1;
-                PooledBuffer::with_capacity(min_capacity.max(256))
-            };
-            drop(state);
-            buf
-        };
+            },
+        );
         buffer.touch(self.next_timestamp());
         buffer
     }
 
     fn release(&self, mut buffer: PooledBuffer) {
-        let mut state = self.state.lock();
-        state.stats.releases += 1;
+        self.counters.releases.fetch_add(1, Ordering::Relaxed);
+
+        if buffer.capacity() < MIN_CAPACITY {
+            // Too small for any size class
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
 
         buffer.clear();
         buffer.touch(self.next_timestamp());
 
-        Self::evict_if_needed(&self.strategy, &mut state);
-
-        let capacity = buffer.capacity();
-        state.buffers.push(buffer);
-        state.stats.buffers_in_pool = state.buffers.len();
-        state.stats.total_bytes_in_pool += capacity;
-
-        if state.stats.buffers_in_pool > state.stats.peak_buffers {
-            state.stats.peak_buffers = state.stats.buffers_in_pool;
-        }
-        if state.stats.total_bytes_in_pool > state.stats.peak_bytes {
-            state.stats.peak_bytes = state.stats.total_bytes_in_pool;
+        let class = release_class(buffer.capacity());
+        let home = self.home_shard();
+        let now = Instant::now();
+        {
+            let mut shard = self.shards[home].lock();
+            if let Some(max_idle) = self.strategy.max_idle() {
+                self.trim_shard(&mut shard, now, max_idle);
+            }
+            self.store(
+                &mut shard,
+                class,
+                IdleBuffer {
+                    buffer,
+                    released_at: now,
+                },
+            );
         }
+
+        self.evict_if_needed(home);
     }
 
     fn clear(&self) {
-        let mut state = self.state.lock();
-        state.buffers.clear();
-        state.stats.buffers_in_pool = 0;
-        state.stats.total_bytes_in_pool = 0;
+        for shard in &*self.shards {
+            let mut shard = shard.lock();
+            for class in 0..SIZE_CLASSES {
+                for idle in std::mem::take(&mut shard.classes[class]) {
+                    self.forget(class, &idle.buffer);
+                }
+            }
+        }
+        self.tapes.clear();
     }
 
     fn stats(&self) -> PoolStats {
-        self.state.lock().stats.clone()
+        let counters = &self.counters;
+        PoolStats {
+            buffers_in_pool: counters.buffers.load(Ordering::Relaxed),
+            total_bytes_in_pool: counters.bytes.load(Ordering::Relaxed),
+            acquires: counters.acquires.load(Ordering::Relaxed),
+            releases: counters.releases.load(Ordering::Relaxed),
+            reuses: counters.reuses.load(Ordering::Relaxed),
+            allocations: counters.allocations.load(Ordering::Relaxed),
+            evictions: counters.evictions.load(Ordering::Relaxed),
+            peak_buffers: counters.peak_buffers.load(Ordering::Relaxed),
+            peak_bytes: counters.peak_bytes.load(Ordering::Relaxed),
+            steals: counters.steals.load(Ordering::Relaxed),
+            idle_trims: counters.idle_trims.load(Ordering::Relaxed),
+            size_classes: counters
+                .classes
+                .iter()
+                .enumerate()
+                .map(|(class, counters)| SizeClassStats {
+                    capacity: class_capacity(class),
+                    acquires: counters.acquires.load(Ordering::Relaxed),
+                    reuses: counters.reuses.load(Ordering::Relaxed),
+                    buffers_in_pool: counters.buffers.load(Ordering::Relaxed),
+                })
+                .collect(),
+        }
     }
 
     fn strategy(&self) -> &PoolStrategy {
         &self.strategy
     }
+
+    fn tape_storage(&self) -> &ObjectPool<TapeStorage> {
+        &self.tapes
+    }
 }
 
-// SharedPool is both Send and Sync
-unsafe impl Send for SharedPool {}
-unsafe impl Sync for SharedPool {}
+impl std::fmt::Debug for SharedPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedPool")
+            .field("strategy", &self.strategy)
+            .field("shards", &self.shards.len())
+            .finish_non_exhaustive()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::sync::Arc;
     use std::thread;
+    use std::time::Duration;
 
     // =========================================================================
     // Constructor Tests
@@ -540,4 +801,225 @@ mod tests {
         assert!(large.capacity() >= 10000);
         assert_eq!(pool.stats().allocations, 2);
     }
+
+    // =========================================================================
+    // Size Class Tests
+    // =========================================================================
+
+    #[test]
+    fn test_size_class_boundaries() {
+        assert_eq!(acquire_class(0), 0);
+        assert_eq!(acquire_class(256), 0);
+        assert_eq!(acquire_class(257), 1);
+        assert_eq!(acquire_class(1024), 2);
+        assert_eq!(acquire_class(usize::MAX), SIZE_CLASSES - 1);
+        assert_eq!(release_class(256), 0);
+        assert_eq!(release_class(511), 0);
+        assert_eq!(release_class(512), 1);
+        assert_eq!(release_class(1 << 40), SIZE_CLASSES - 1);
+    }
+
+    #[test]
+    fn test_acquire_rounds_up_to_class() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        let buf = pool.acquire(600);
+        assert_eq!(buf.capacity(), 1024);
+        pool.release(buf);
+
+        // 1000 bytes maps to the same class and reuses the buffer
+        let buf = pool.acquire(1000);
+        assert_eq!(buf.capacity(), 1024);
+        assert_eq!(pool.stats().reuses, 1);
+    }
+
+    #[test]
+    fn test_smaller_class_does_not_take_larger_buffer() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        pool.release(pool.acquire(4096));
+
+        let small = pool.acquire(100);
+        assert_eq!(small.capacity(), 256);
+        assert_eq!(pool.stats().buffers_in_pool, 1);
+    }
+
+    #[test]
+    fn test_oversized_buffers_share_last_class() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        let huge = class_capacity(SIZE_CLASSES - 1) * 2;
+        pool.release(PooledBuffer::with_capacity(huge));
+
+        // Too big for what the pooled buffer holds
+        let bigger = pool.acquire(huge * 2);
+        assert!(bigger.capacity() >= huge * 2);
+        assert_eq!(pool.stats().reuses, 0);
+
+        let reused = pool.acquire(huge);
+        assert!(reused.capacity() >= huge);
+        assert_eq!(pool.stats().reuses, 1);
+    }
+
+    #[test]
+    fn test_undersized_release_is_dropped() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        pool.release(PooledBuffer::with_capacity(16));
+
+        let stats = pool.stats();
+        assert_eq!(stats.buffers_in_pool, 0);
+        assert_eq!(stats.evictions, 1);
+    }
+
+    #[test]
+    fn test_per_class_hit_rates() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        for _ in 0..4 {
+            pool.release(pool.acquire(100));
+        }
+        pool.release(pool.acquire(2048));
+
+        let stats = pool.stats();
+        assert_eq!(stats.size_classes.len(), SIZE_CLASSES);
+        assert_eq!(stats.size_classes[0].capacity, 256);
+        assert_eq!(stats.size_classes[0].acquires, 4);
+        assert_eq!(stats.size_classes[0].reuses, 3);
+        assert!((stats.size_classes[0].hit_rate() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(stats.size_classes[3].capacity, 2048);
+        assert_eq!(stats.size_classes[3].acquires, 1);
+        assert!(stats.size_classes[3].hit_rate().abs() < f64::EPSILON);
+        assert_eq!(stats.size_classes[3].buffers_in_pool, 1);
+    }
+
+    // =========================================================================
+    // Shard Tests
+    // =========================================================================
+
+    #[test]
+    fn test_shard_count_clamped() {
+        assert_eq!(
+            SharedPool::with_shards(PoolStrategy::Unbounded, 0).shard_count(),
+            1
+        );
+        assert_eq!(
+            SharedPool::with_shards(PoolStrategy::Unbounded, 4).shard_count(),
+            4
+        );
+        assert_eq!(
+            SharedPool::with_shards(PoolStrategy::Unbounded, 1000).shard_count(),
+            MAX_SHARDS
+        );
+    }
+
+    #[test]
+    fn test_steal_from_other_shard() {
+        let pool = SharedPool::with_shards(PoolStrategy::Unbounded, 2);
+        let home = pool.home_shard();
+        let other = (home + 1) % 2;
+
+        let mut buffer = PooledBuffer::with_capacity(256);
+        buffer.touch(pool.next_timestamp());
+        pool.store(
+            &mut pool.shards[other].lock(),
+            0,
+            IdleBuffer {
+                buffer,
+                released_at: Instant::now(),
+            },
+        );
+
+        let stolen = pool.acquire(100);
+        assert_eq!(stolen.capacity(), 256);
+        let stats = pool.stats();
+        assert_eq!(stats.steals, 1);
+        assert_eq!(stats.reuses, 1);
+        assert_eq!(stats.buffers_in_pool, 0);
+    }
+
+    #[test]
+    fn test_limit_applies_across_shards() {
+        let pool = Arc::new(SharedPool::with_shards(
+            PoolStrategy::SizeLimited { max_tapes: 3 },
+            8,
+        ));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let pool = Arc::clone(&pool);
+                thread::spawn(move || {
+                    let bufs: Vec<_> = (0..4).map(|_| pool.acquire(256)).collect();
+                    for buf in bufs {
+                        pool.release(buf);
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        let stats = pool.stats();
+        assert!(stats.buffers_in_pool <= 3);
+        let pooled: usize = stats.size_classes.iter().map(|c| c.buffers_in_pool).sum();
+        assert_eq!(pooled, stats.buffers_in_pool);
+    }
+
+    #[test]
+    fn test_lru_evicts_oldest_across_classes() {
+        let pool = SharedPool::with_shards(PoolStrategy::Lru { max_tapes: 2 }, 1);
+        let small = pool.acquire(256);
+        let medium = pool.acquire(1024);
+        let large = pool.acquire(4096);
+
+        pool.release(medium);
+        pool.release(small);
+        pool.release(large);
+
+        // The medium buffer was released first and goes first
+        let stats = pool.stats();
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.size_classes[0].buffers_in_pool, 1);
+        assert_eq!(stats.size_classes[2].buffers_in_pool, 0);
+        assert_eq!(stats.size_classes[4].buffers_in_pool, 1);
+    }
+
+    #[test]
+    fn test_shared_pool_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedPool>();
+    }
+
+    // =========================================================================
+    // Idle Trimming Tests
+    // =========================================================================
+
+    #[test]
+    fn test_trim_idle_drops_expired_buffers() {
+        let pool = SharedPool::with_shards(PoolStrategy::idle_timeout(8, Duration::ZERO), 1);
+        pool.release(pool.acquire(256));
+        pool.release(pool.acquire(4096));
+
+        // Each release trims what had already idled past the limit
+        assert_eq!(pool.stats().buffers_in_pool, 1);
+        assert_eq!(pool.trim_idle(), 1);
+
+        let stats = pool.stats();
+        assert_eq!(stats.buffers_in_pool, 0);
+        assert_eq!(stats.total_bytes_in_pool, 0);
+        assert_eq!(stats.idle_trims, 2);
+    }
+
+    #[test]
+    fn test_trim_idle_keeps_recent_buffers() {
+        let pool = SharedPool::new(PoolStrategy::idle_timeout(8, Duration::from_secs(3600)));
+        pool.release(pool.acquire(256));
+        pool.release(pool.acquire(4096));
+
+        assert_eq!(pool.trim_idle(), 0);
+        assert_eq!(pool.stats().buffers_in_pool, 2);
+    }
+
+    #[test]
+    fn test_trim_idle_ignored_without_timeout() {
+        let pool = SharedPool::new(PoolStrategy::Unbounded);
+        pool.release(pool.acquire(256));
+        assert_eq!(pool.trim_idle(), 0);
+        assert_eq!(pool.stats().buffers_in_pool, 1);
+    }
 }
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 //! Pool eviction strategies.
 
+use std::time::Duration;
+
 /// Pool eviction strategy configuration.
 #[derive(Debug, Clone)]
 pub enum PoolStrategy {
@@ -32,6 +34,18 @@ pub enum PoolStrategy {
         /// Maximum number of buffers to keep.
         max_tapes: usize,
     },
+
+    /// Least-recently-used eviction with size limit and idle trimming.
+    ///
+    /// Behaves like [`PoolStrategy::Lru`], and pools that track release
+    /// times also drop buffers that have sat unused for longer than
+    /// `max_idle`.
+    IdleTimeout {
+        /// Maximum number of buffers to keep.
+        max_tapes: usize,
+        /// How long a pooled buffer may stay idle before it is trimmed.
+        max_idle: Duration,
+    },
 }
 
 impl Default for PoolStrategy {
@@ -65,14 +79,23 @@ impl PoolStrategy {
         Self::Lru { max_tapes }
     }
 
+    /// Create an LRU strategy that also trims idle buffers.
+    #[must_use]
+    pub const fn idle_timeout(max_tapes: usize, max_idle: Duration) -> Self {
+        Self::IdleTimeout {
+            max_tapes,
+            max_idle,
+        }
+    }
+
     /// Check if a buffer should be evicted given current pool state.
     #[must_use]
     pub const fn should_evict(&self, current_count: usize, current_bytes: usize) -> bool {
         match self {
             Self::Unbounded => false,
-            Self::SizeLimited { max_tapes } | Self::Lru { max_tapes } => {
-                current_count >= *max_tapes
-            }
+            Self::SizeLimited { max_tapes }
+            | Self::Lru { max_tapes }
+            | Self::IdleTimeout { max_tapes, .. } => current_count >= *max_tapes,
             Self::MemoryLimited { max_bytes } => current_bytes >= *max_bytes,
         }
     }
@@ -81,7 +104,9 @@ impl PoolStrategy {
     #[must_use]
     pub const fn max_tapes(&self) -> Option<usize> {
         match self {
-            Self::SizeLimited { max_tapes } | Self::Lru { max_tapes } => Some(*max_tapes),
+            Self::SizeLimited { max_tapes }
+            | Self::Lru { max_tapes }
+            | Self::IdleTimeout { max_tapes, .. } => Some(*max_tapes),
             Self::Unbounded | Self::MemoryLimited { .. } => None,
         }
     }
@@ -91,9 +116,30 @@ impl PoolStrategy {
     pub const fn max_bytes(&self) -> Option<usize> {
         match self {
             Self::MemoryLimited { max_bytes } => Some(*max_bytes),
-            Self::Unbounded | Self::SizeLimited { .. } | Self::Lru { .. } => None,
+            Self::Unbounded
+            | Self::SizeLimited { .. }
+            | Self::Lru { .. }
+            | Self::IdleTimeout { .. } => None,
+        }
+    }
+
+    /// Get how long a buffer may stay idle before trimming (if applicable).
+    #[must_use]
+    pub const fn max_idle(&self) -> Option<Duration> {
+        match self {
+            Self::IdleTimeout { max_idle, .. } => Some(*max_idle),
+            Self::Unbounded
+            | Self::SizeLimited { .. }
+            | Self::MemoryLimited { .. }
+            | Self::Lru { .. } => None,
         }
     }
+
+    /// Check whether eviction should pick the least recently used buffer.
+    #[must_use]
+    pub const fn is_lru(&self) -> bool {
+        matches!(self, Self::Lru { .. } | Self::IdleTimeout { .. })
+    }
 }
 
 /// Pool statistics for monitoring and debugging.
@@ -125,6 +171,46 @@ pub struct PoolStats {
 
     /// Peak total bytes in pool.
     pub peak_bytes: usize,
+
+    /// Number of acquires served from another shard's free list.
+    pub steals: u64,
+
+    /// Number of buffers dropped after idling past the strategy's limit.
+    pub idle_trims: u64,
+
+    /// Per size-class counters, smallest class first.
+    ///
+    /// Empty for pools without size classes.
+    pub size_classes: Vec<SizeClassStats>,
+}
+
+/// Statistics for one size class of a pool.
+#[derive(Debug, Clone, Default)]
+pub struct SizeClassStats {
+    /// Smallest buffer capacity held by this class.
+    pub capacity: usize,
+
+    /// Number of acquire calls routed to this class.
+    pub acquires: u64,
+
+    /// Number of those acquires served by a pooled buffer.
+    pub reuses: u64,
+
+    /// Number of buffers currently pooled in this class.
+    pub buffers_in_pool: usize,
+}
+
+impl SizeClassStats {
+    /// Calculate hit rate (0.0 to 1.0).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Acceptable for statistical rate calculations
+    pub fn hit_rate(&self) -> f64 {
+        if self.acquires == 0 {
+            0.0
+        } else {
+            self.reuses as f64 / self.acquires as f64
+        }
+    }
 }
 
 impl PoolStats {
@@ -250,6 +336,17 @@ mod tests {
         assert!(strategy.should_evict(5, 10000));
     }
 
+    #[test]
+    fn test_idle_timeout_strategy() {
+        let strategy = PoolStrategy::idle_timeout(4, Duration::from_secs(30));
+        assert!(strategy.is_lru());
+        assert!(strategy.should_evict(4, 0));
+        assert_eq!(strategy.max_tapes(), Some(4));
+        assert_eq!(strategy.max_bytes(), None);
+        assert_eq!(strategy.max_idle(), Some(Duration::from_secs(30)));
+        assert_eq!(PoolStrategy::lru(4).max_idle(), None);
+    }
+
     #[test]
     fn test_max_tapes_unbounded() {
         let strategy = PoolStrategy::Unbounded;
@@ -328,6 +425,7 @@ mod tests {
             evictions: 2,
             peak_buffers: 6,
             peak_bytes: 1500,
+            ..Default::default()
         };
         let debug_str = format!("{stats:?}");
         assert!(debug_str.contains("buffers_in_pool"));
@@ -346,6 +444,7 @@ mod tests {
             evictions: 1,
             peak_buffers: 4,
             peak_bytes: 600,
+            ..Default::default()
         };
         let cloned = stats;
         assert_eq!(cloned.buffers_in_pool, 3);
@@ -388,6 +487,18 @@ mod tests {
         assert!((stats.reuse_rate() - 0.5).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_size_class_hit_rate() {
+        let class = SizeClassStats {
+            capacity: 256,
+            acquires: 4,
+            reuses: 3,
+            buffers_in_pool: 1,
+        };
+        assert!((class.hit_rate() - 0.75).abs() < f64::EPSILON);
+        assert!(SizeClassStats::default().hit_rate().abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_eviction_rate_zero_releases() {
         let stats = PoolStats::default();
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Reusable simd-json tape storage.
+
+use super::object::Reusable;
+use simd_json::Buffers;
+use simd_json::tape::Tape;
+
+/// Recyclable storage for one simd-json parse.
+///
+/// Holds the tape node vector together with simd-json's scratch buffers
+/// (structural indexes, string buffer, aligned input copy), so that
+/// repeated parses of similarly sized documents reuse all of it.
+pub struct TapeStorage {
+    tape: Tape<'static>,
+    buffers: Buffers,
+}
+
+impl TapeStorage {
+    /// Create empty storage.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tape: Tape(Vec::new()),
+            buffers: Buffers::default(),
+        }
+    }
+
+    /// Create storage sized for inputs of roughly `input_len` bytes.
+    #[must_use]
+    pub fn with_capacity(input_len: usize) -> Self {
+        Self {
+            tape: Tape(Vec::with_capacity(input_len / 2)),
+            buffers: Buffers::new(input_len),
+        }
+    }
+
+    /// Parse `json` into the pooled tape.
+    ///
+    /// The returned tape borrows `json`; hand it back with
+    /// [`TapeStorage::restore`] to keep its node capacity.
+    ///
+    /// The scratch buffers are always handed back, so a failed parse can
+    /// still return them via [`TapeStorage::from_buffers`].
+    pub fn fill(self, json: &mut [u8]) -> (Buffers, simd_json::Result<Tape<'_>>) {
+        let Self { tape, mut buffers } = self;
+        let mut tape = tape.reset();
+        let result = simd_json::fill_tape(json, &mut buffers, &mut tape).map(|()| tape);
+        (buffers, result)
+    }
+
+    /// Rebuild storage from a tape and the buffers used to fill it.
+    #[must_use]
+    pub fn restore(tape: Tape<'_>, buffers: Buffers) -> Self {
+        Self {
+            tape: tape.reset(),
+            buffers,
+        }
+    }
+
+    /// Rebuild storage from buffers alone (after a failed parse).
+    #[must_use]
+    pub const fn from_buffers(buffers: Buffers) -> Self {
+        Self {
+            tape: Tape(Vec::new()),
+            buffers,
+        }
+    }
+
+    /// Capacity of the tape node vector.
+    #[must_use]
+    pub const fn node_capacity(&self) -> usize {
+        self.tape.0.capacity()
+    }
+}
+
+impl Default for TapeStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for TapeStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TapeStorage")
+            .field("node_capacity", &self.node_capacity())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Reusable for TapeStorage {
+    fn reset(&mut self) {
+        self.tape.0.clear();
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.node_capacity() * std::mem::size_of::<simd_json::value::tape::Node<'static>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_and_restore_keeps_capacity() {
+        let mut json = br#"{"a": [1, 2, 3], "b": "x"}"#.to_vec();
+        let (buffers, tape) = TapeStorage::new().fill(&mut json);
+        let tape = tape.unwrap();
+        assert!(tape.0.len() > 4);
+
+        let storage = TapeStorage::restore(tape, buffers);
+        assert!(storage.node_capacity() > 4);
+    }
+
+    #[test]
+    fn test_fill_error_returns_buffers() {
+        let mut json = br#"{"a": }"#.to_vec();
+        let (buffers, result) = TapeStorage::new().fill(&mut json);
+        assert!(!result.unwrap_err().to_string().is_empty());
+
+        let mut json = br"[true]".to_vec();
+        assert!(TapeStorage::from_buffers(buffers).fill(&mut json).1.is_ok());
+    }
+}
@@ -3,7 +3,9 @@
 
 use super::TapePool;
 use super::buffer::PooledBuffer;
+use super::object::ObjectPool;
 use super::strategy::{PoolStats, PoolStrategy};
+use super::tape::TapeStorage;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -27,6 +29,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 pub struct ThreadLocalPool {
     strategy: PoolStrategy,
     timestamp: AtomicU64,
+    tapes: ObjectPool<TapeStorage>,
     state: RefCell<PoolState>,
 }
 
@@ -40,6 +43,7 @@ impl ThreadLocalPool {
     #[must_use]
     pub fn new(strategy: PoolStrategy) -> Self {
         Self {
+            tapes: ObjectPool::new(strategy.clone()),
             strategy,
             timestamp: AtomicU64::new(0),
             state: RefCell::new(PoolState {
@@ -91,7 +95,7 @@ impl ThreadLocalPool {
                 }
             }
 
-            PoolStrategy::Lru { max_tapes } => {
+            PoolStrategy::Lru { max_tapes } | PoolStrategy::IdleTimeout { max_tapes, .. } => {
                 // Evict LRU until at or under the limit (buffer already added)
                 while state.buffers.len() > *max_tapes {
                     let lru_idx = state
@@ -172,6 +176,8 @@ impl TapePool for ThreadLocalPool {
         state.buffers.clear();
         state.stats.buffers_in_pool = 0;
         state.stats.total_bytes_in_pool = 0;
+        drop(state);
+        self.tapes.clear();
     }
 
     fn stats(&self) -> PoolStats {
@@ -181,6 +187,10 @@ impl TapePool for ThreadLocalPool {
     fn strategy(&self) -> &PoolStrategy {
         &self.strategy
     }
+
+    fn tape_storage(&self) -> &ObjectPool<TapeStorage> {
+        &self.tapes
+    }
 }
 
 // ThreadLocalPool is !Sync due to RefCell - intentional for single-threaded use
@@ -4,9 +4,10 @@
 JSONL/ISONL streaming, multi-format parsing, gron, diff, CRDT, and tape API.
 """
 
-from collections.abc import Iterator
+from collections.abc import AsyncIterator, Iterator, Mapping, Sequence
 from enum import Enum
-from typing import Any
+from os import PathLike
+from typing import Any, Literal
 
 # =============================================================================
 # JSONL Streaming
@@ -251,6 +252,103 @@ def jsonl_to_isonl(
     """
     ...
 
+# =============================================================================
+# Incremental Streams
+# =============================================================================
+
+class RecordStream(Iterator[Any], AsyncIterator[Any]):
+    """Incremental reader returned by `open_stream`.
+
+    Use `for` over blocking sources and `async for` when the source's
+    read() is a coroutine. Yields records, or lists of up to `batch_size`
+    records.
+    """
+
+    @property
+    def errors(self) -> list[dict[str, Any]]:
+        """Records that failed to decode: dicts with "line", "message", "text"."""
+        ...
+
+    def __iter__(self) -> RecordStream: ...
+    def __next__(self) -> Any: ...
+    def __aiter__(self) -> RecordStream: ...
+    async def __anext__(self) -> Any: ...
+
+def open_stream(
+    source: str | PathLike[str] | Any,
+    format: Literal["jsonl", "ndjson", "isonl", "csv", "yaml-multi"] = "jsonl",
+    fields: list[str] | None = None,
+    batch_size: int | None = None,
+    strict: bool = False,
+) -> RecordStream:
+    """Stream records from a path or any object with read() (or recv()).
+
+    Decoding and field filtering run in Rust. Bad records are skipped and
+    listed in `RecordStream.errors` unless `strict` is set.
+
+    Args:
+        source: Path, file object, gzip file, socket, or asyncio.StreamReader
+        format: Record format; "csv" takes its header from the first line,
+            "yaml-multi" splits documents on "---"
+        fields: Field paths to keep, as in Pipeline.select (`user.name`, `user.*`)
+        batch_size: Yield lists of up to this many records
+        strict: Raise JSONDecodeError on the first bad record
+
+    Examples:
+        >>> with gzip.open("events.jsonl.gz", "rb") as f:
+        ...     for batch in fx.open_stream(f, fields=["id"], batch_size=1000):
+        ...         process(batch)
+        >>> async for record in fx.open_stream(asyncio_reader):
+        ...     handle(record)
+    """
+    ...
+
+# =============================================================================
+# Arrow Export (requires the `arrow` build feature)
+# =============================================================================
+
+class ArrowBatch:
+    """Arrow record batch exported through the PyCapsule stream interface.
+
+    Pass it to `pyarrow.table`, `polars.from_arrow` or any other consumer of
+    `__arrow_c_stream__`; the column buffers are not copied.
+    """
+
+    @property
+    def num_rows(self) -> int:
+        """Number of rows in the batch."""
+        ...
+
+    @property
+    def num_columns(self) -> int:
+        """Number of columns in the batch."""
+        ...
+
+    @property
+    def column_names(self) -> list[str]:
+        """Column names in schema order."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """Export as an `arrow_array_stream` capsule (requested_schema is ignored)."""
+        ...
+
+def jsonl_to_arrow(data: str | bytes) -> ArrowBatch:
+    """Build an Arrow batch from JSONL text, inferring the column schema.
+
+    Args:
+        data: JSONL text; every non-blank line must be a JSON object
+
+    Returns:
+        ArrowBatch with nested objects as struct columns and arrays as list columns
+    """
+    ...
+
+def records_to_arrow(records: list[dict[str, Any]]) -> ArrowBatch:
+    """Build an Arrow batch from a list of dicts, inferring the column schema."""
+    ...
+
 # =============================================================================
 # Multi-Format Parsing
 # =============================================================================
@@ -365,46 +463,75 @@ class MergeStrategy(Enum):
 class CrdtDocument:
     """A CRDT-enabled document for conflict-free merging.
 
+    Writes are stamped with Lamport timestamps and vector clocks, so merges
+    follow causality rather than wall clocks. Concurrent writes to a field are
+    resolved by that path's strategy (LWW by default, ties broken by replica
+    id). Paths are dotted; numeric segments index arrays.
+
     Examples:
         >>> doc = fx.CrdtDocument({"counter": 0}, replica_id="node-1")
         >>> doc.set("counter", 10)
+        >>> payload = doc.delta_since(peer.clock)
+        >>> peer.apply_delta(payload)
     """
 
-    def __init__(self, initial_data: Any, replica_id: str) -> None:
+    def __init__(self, initial_data: dict[str, Any], replica_id: str) -> None:
         """Create a new CRDT document.
 
         Args:
-            initial_data: Initial document data
+            initial_data: Initial document data, recorded as local writes
             replica_id: Unique identifier for this replica
         """
         ...
 
     def set(self, path: str, value: Any) -> None:
-        """Set a value at the given path."""
+        """Set a value at the given dotted path."""
         ...
 
     def delete(self, path: str) -> None:
-        """Delete a value at the given path."""
+        """Delete a value at the given dotted path."""
         ...
 
     def merge(self, other: CrdtDocument) -> list[dict[str, Any]]:
-        """Merge with another document.
+        """Apply every operation of another replica not seen yet.
+
+        Returns:
+            Conflicts (concurrent writes) as dicts with path, local, remote,
+            resolved, local_ts, remote_ts and winner
+        """
+        ...
+
+    def delta_since(self, clock: dict[str, int] | None = None) -> bytes:
+        """Encode the operations a peer at ``clock`` has not seen.
+
+        Args:
+            clock: The peer's ``clock``; None ships the full history
+        """
+        ...
+
+    def apply_delta(self, data: bytes) -> list[dict[str, Any]]:
+        """Apply a delta from another replica's ``delta_since``.
 
         Returns:
-            List of conflicts (if any)
+            Conflicts, in the same shape as ``merge``
         """
         ...
 
+    @property
+    def clock(self) -> dict[str, int]:
+        """Vector clock as {replica_id: operations seen}."""
+        ...
+
     def set_strategy(self, path_pattern: str, strategy: MergeStrategy) -> None:
-        """Set merge strategy for a path pattern."""
+        """Set the merge strategy for concurrent writes at a path and below it."""
         ...
 
     def export_state(self) -> dict[str, Any]:
-        """Export full state."""
+        """Export the document, replica id and vector clock."""
         ...
 
-    def export_delta(self, since_version: int) -> dict[str, Any]:
-        """Export delta since a version."""
+    def merge_jsonl(self, path: str) -> None:
+        """Merge JSONL records field by field through the path strategies."""
         ...
 
     @property
@@ -419,9 +546,11 @@ class CrdtDocument:
 class Pipeline:
     """Stream processing pipeline for JSONL/ISONL.
 
+    Native stages (select, where, apply, rename, drop, convert) run in
+    parallel batches with the GIL released; filter and map call Python.
+
     Examples:
-        >>> pipeline = fx.Pipeline()
-        >>> pipeline.filter(lambda x: x["active"])
+        >>> pipeline = fx.Pipeline().where("active == true").select(["id"])
         >>> pipeline.map(lambda x: {"id": x["id"]})
         >>> pipeline.process_isonl("input.isonl", "output.isonl")
     """
@@ -430,12 +559,36 @@ class Pipeline:
         """Create a new pipeline."""
         ...
 
-    def filter(self, predicate: Any) -> None:
-        """Add a filter stage."""
+    def filter(self, predicate: Any) -> Pipeline:
+        """Add a Python filter stage."""
+        ...
+
+    def map(self, transform: Any) -> Pipeline:
+        """Add a Python map stage."""
+        ...
+
+    def select(self, fields: list[str]) -> Pipeline:
+        """Keep only the given dotted field paths (wildcards allowed)."""
+        ...
+
+    def where(self, expr: str) -> Pipeline:
+        """Keep records matching a filter such as ``"latency > 500"``."""
         ...
 
-    def map(self, transform: Any) -> None:
-        """Add a map stage."""
+    def apply(self, operations: list[dict[str, Any]]) -> Pipeline:
+        """Apply DSON operations (``{"op": "field_add", "path": ..., "value": ...}``)."""
+        ...
+
+    def rename(self, mapping: dict[str, str]) -> Pipeline:
+        """Rename or move dotted field paths."""
+        ...
+
+    def drop(self, fields: list[str]) -> Pipeline:
+        """Remove dotted field paths."""
+        ...
+
+    def convert(self, to: str) -> Pipeline:
+        """Set the output format ("jsonl", "isonl", "json", "yaml")."""
         ...
 
     def process_jsonl(self, input_path: str, output_path: str) -> int:
@@ -459,7 +612,7 @@ class Pipeline:
         input_path: str,
         input_format: str,
         output_path: str,
-        output_format: str,
+        output_format: str | None = None,
         output_schema: list[str] | None = None,
     ) -> int:
         """Process with format conversion.
@@ -516,6 +669,52 @@ class Tape:
         """Convert to Python object (full materialization)."""
         ...
 
+    def to_python(self, path: str | None = None) -> Any:
+        """Materialize the subtree at path (whole document if None).
+
+        Raises:
+            KeyError: If the path does not exist
+        """
+        ...
+
+    def view(self) -> TapeMapping | TapeSequence | Any:
+        """Lazy view of the document.
+
+        Objects and arrays come back as read-only proxies that walk the
+        tape on access; scalars convert only when read.
+
+        Examples:
+            >>> root = tape.view()
+            >>> root["users"][0]["name"]
+            'Alice'
+        """
+        ...
+
+class TapeMapping(Mapping[str, Any]):
+    """Read-only lazy view of a JSON object in a Tape."""
+
+    def __getitem__(self, key: str) -> Any: ...
+    def __iter__(self) -> Iterator[str]: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, key: object) -> bool: ...
+    def get(self, key: str, default: Any = None) -> Any: ...
+    def keys(self) -> list[str]: ...  # type: ignore[override]
+    def values(self) -> list[Any]: ...  # type: ignore[override]
+    def items(self) -> list[tuple[str, Any]]: ...  # type: ignore[override]
+    def to_python(self) -> dict[str, Any]:
+        """Materialize this object as a dict."""
+        ...
+
+class TapeSequence(Sequence[Any]):
+    """Read-only lazy view of a JSON array in a Tape."""
+
+    def __getitem__(self, index: int | slice) -> Any: ...  # type: ignore[override]
+    def __iter__(self) -> Iterator[Any]: ...
+    def __len__(self) -> int: ...
+    def to_python(self) -> list[Any]:
+        """Materialize this array as a list."""
+        ...
+
 class TapePool:
     """Pool for reusing tape allocations.
 
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Arrow export (requires `arrow` feature)
+//!
+//! Turns JSONL text or lists of dicts into an Arrow record batch that any
+//! Arrow-aware library (pyarrow, Polars, `DuckDB`) imports without copying,
+//! through the Arrow `PyCapsule` stream interface (`__arrow_c_stream__`).
+//!
+//! - Column schemas are inferred as in `fionn_stream::arrow_bridge`
+//! - Nested objects become struct columns, arrays become list columns
+
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use fionn_stream::arrow_bridge::records_to_record_batch;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyCapsule, PyList, PyString};
+use serde_json::Value;
+
+use crate::compat::options::DumpOptions;
+use crate::exceptions::JSONDecodeError;
+use crate::types::py_to_json;
+
+/// Arrow record batch built by fionn.
+///
+/// Implements the Arrow `PyCapsule` stream interface, so it can be handed
+/// straight to Arrow consumers without copying the column buffers.
+///
+/// # Examples
+/// ```python
+/// import fionn.ext as fx
+/// import pyarrow as pa
+///
+/// batch = fx.jsonl_to_arrow(b'{"id": 1, "tags": ["a"]}\n{"id": 2, "tags": []}\n')
+/// table = pa.table(batch)
+/// ```
+#[pyclass(frozen)]
+pub struct ArrowBatch {
+    batch: RecordBatch,
+}
+
+#[pymethods]
+impl ArrowBatch {
+    /// Number of rows in the batch.
+    #[getter]
+    fn num_rows(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    /// Number of columns in the batch.
+    #[getter]
+    fn num_columns(&self) -> usize {
+        self.batch.num_columns()
+    }
+
+    /// Column names in schema order.
+    #[getter]
+    fn column_names(&self) -> Vec<String> {
+        self.batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    /// Export the batch as an `arrow_array_stream` capsule.
+    ///
+    /// `requested_schema` is accepted for protocol compatibility and ignored;
+    /// the stream always carries the inferred schema.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+        let reader =
+            RecordBatchIterator::new(std::iter::once(Ok(self.batch.clone())), self.batch.schema());
+        let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+        PyCapsule::new(py, stream, Some(c"arrow_array_stream".to_owned()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ArrowBatch(num_rows={}, columns={:?})",
+            self.batch.num_rows(),
+            self.column_names()
+        )
+    }
+}
+
+fn build_batch(records: &[Value]) -> PyResult<ArrowBatch> {
+    records_to_record_batch(records, None)
+        .map(|batch| ArrowBatch { batch })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Build an Arrow batch from JSONL text.
+///
+/// Blank lines are skipped; every other line must be a JSON object.
+///
+/// # Arguments
+/// * `data` - JSONL as `str` or `bytes`
+#[pyfunction]
+pub fn jsonl_to_arrow(data: &Bound<'_, PyAny>) -> PyResult<ArrowBatch> {
+    let text = if let Ok(bytes) = data.cast::<PyBytes>() {
+        std::str::from_utf8(bytes.as_bytes())
+            .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid UTF-8: {e}")))?
+            .to_owned()
+    } else {
+        data.cast::<PyString>()?.to_cow()?.into_owned()
+    };
+
+    let records = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))
+        })
+        .collect::<PyResult<Vec<Value>>>()?;
+    build_batch(&records)
+}
+
+/// Build an Arrow batch from a list of dicts.
+#[pyfunction]
+pub fn records_to_arrow(py: Python<'_>, records: &Bound<'_, PyList>) -> PyResult<ArrowBatch> {
+    let records = records
+        .iter()
+        .map(|item| py_to_json(py, &item, None, &DumpOptions::default()))
+        .collect::<PyResult<Vec<Value>>>()?;
+    build_batch(&records)
+}
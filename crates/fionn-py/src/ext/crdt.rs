@@ -9,16 +9,19 @@ use fionn_crdt::{
     OptimizedMergeProcessor, PreParsedValue, Winner, merge_additive_i64, merge_lww_fast,
     merge_max_i64, merge_min_i64,
 };
+use fionn_ops::dson_traits::{DeltaCrdt, MergeConflict, VectorClock};
+use fionn_ops::{DsonOperation, MergeStrategy as OpsMergeStrategy};
+use fionn_stream::format_crdt::{FormatCrdtProcessor, FormatDelta};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyBytes, PyDict, PyList};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::sync::RwLock;
-use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::pipeline::RecordLines;
 use crate::compat::options::DumpOptions;
-use crate::exceptions::JSONDecodeError;
+use crate::exceptions::{JSONDecodeError, JSONEncodeError};
 use crate::types::{json_to_py, py_to_json};
 
 /// Merge strategy for CRDT operations.
@@ -51,11 +54,16 @@ impl From<MergeStrategy> for CoreMergeStrategy {
     }
 }
 
-/// Get current timestamp in milliseconds.
-fn current_timestamp() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_or(0, |d| d.as_millis() as u64)
+impl From<MergeStrategy> for OpsMergeStrategy {
+    fn from(s: MergeStrategy) -> Self {
+        match s {
+            MergeStrategy::LastWriterWins => Self::LastWriteWins,
+            MergeStrategy::Additive => Self::Additive,
+            MergeStrategy::Max => Self::Max,
+            MergeStrategy::Min => Self::Min,
+            MergeStrategy::Concat | MergeStrategy::Union => Self::Union,
+        }
+    }
 }
 
 /// Convert Python value to `OperationValue`.
@@ -69,7 +77,7 @@ fn json_value_to_operation_value(value: &serde_json::Value) -> OperationValue {
     match value {
         serde_json::Value::Null => OperationValue::Null,
         serde_json::Value::Bool(b) => OperationValue::BoolRef(*b),
-        serde_json::Value::Number(n) => OperationValue::NumberRef(n.to_string()),
+        serde_json::Value::Number(n) => OperationValue::NumberRef(n.into()),
         serde_json::Value::String(s) => OperationValue::StringRef(s.clone()),
         serde_json::Value::Array(_) => OperationValue::ArrayRef { start: 0, end: 0 },
         serde_json::Value::Object(_) => OperationValue::ObjectRef { start: 0, end: 0 },
@@ -91,19 +99,171 @@ fn pre_parsed_to_json(value: &PreParsedValue) -> serde_json::Value {
     }
 }
 
-/// Convert `AHashMap` to `serde_json::Map` for serialization.
-fn timestamps_to_json(
-    timestamps: &ahash::AHashMap<String, u64>,
-) -> serde_json::Map<String, serde_json::Value> {
-    let mut map = serde_json::Map::new();
-    for (k, v) in timestamps {
-        map.insert(k.clone(), serde_json::Value::Number((*v).into()));
+/// Document id of the single document each replica tracks.
+const DOC_ID: &str = "doc";
+
+type Replica = FormatCrdtProcessor<RecordLines>;
+
+fn lock_error(e: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
+}
+
+fn crdt_error(e: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())
+}
+
+/// Expand a JSON value into the field operations that build it at `path`.
+///
+/// Containers become a marker followed by one operation per child, so
+/// replicas merge nested fields individually.
+fn value_to_operations(
+    path: &str,
+    value: &serde_json::Value,
+    ops: &mut Vec<DsonOperation>,
+) -> PyResult<()> {
+    let child_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    match value {
+        serde_json::Value::Object(map) => {
+            if !path.is_empty() {
+                ops.push(DsonOperation::FieldAdd {
+                    path: path.to_string(),
+                    value: OperationValue::ObjectRef { start: 0, end: 0 },
+                });
+            }
+            for (key, child) in map {
+                if key.is_empty() || key.contains('.') {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "CrdtDocument keys must be non-empty and free of '.': {key:?}"
+                    )));
+                }
+                value_to_operations(&child_path(key), child, ops)?;
+            }
+        }
+        serde_json::Value::Array(items) => {
+            ops.push(DsonOperation::FieldAdd {
+                path: path.to_string(),
+                value: OperationValue::ArrayRef { start: 0, end: 0 },
+            });
+            for (index, child) in items.iter().enumerate() {
+                value_to_operations(&child_path(&index.to_string()), child, ops)?;
+            }
+        }
+        scalar => ops.push(DsonOperation::FieldAdd {
+            path: path.to_string(),
+            value: json_value_to_operation_value(scalar),
+        }),
+    }
+    Ok(())
+}
+
+/// Convert an `OperationValue` from a merge conflict back to JSON.
+fn operation_value_to_json(value: &OperationValue) -> serde_json::Value {
+    match value {
+        OperationValue::Null => serde_json::Value::Null,
+        OperationValue::BoolRef(b) => serde_json::Value::Bool(*b),
+        OperationValue::NumberRef(n) => n.to_json_value(),
+        OperationValue::StringRef(s) => serde_json::Value::String(s.clone()),
+        OperationValue::ArrayRef { .. } => serde_json::Value::Array(Vec::new()),
+        OperationValue::ObjectRef { .. } => serde_json::Value::Object(serde_json::Map::new()),
+    }
+}
+
+/// Describe merge conflicts as `{path, local, remote, resolved, local_ts, remote_ts, winner}`.
+fn conflicts_to_json(conflicts: &[MergeConflict]) -> serde_json::Value {
+    conflicts
+        .iter()
+        .map(|conflict| {
+            let winner = if conflict.resolved_value.as_ref() == Some(&conflict.remote_value) {
+                "remote"
+            } else {
+                "local"
+            };
+            serde_json::json!({
+                "path": conflict.path,
+                "local": operation_value_to_json(&conflict.local_value),
+                "remote": operation_value_to_json(&conflict.remote_value),
+                "resolved": conflict.resolved_value.as_ref().map(operation_value_to_json),
+                "local_ts": conflict.local_timestamp,
+                "remote_ts": conflict.remote_timestamp,
+                "winner": winner,
+            })
+        })
+        .collect()
+}
+
+fn clock_to_json(clock: &VectorClock) -> serde_json::Value {
+    clock
+        .iter()
+        .map(|(replica, time)| (replica.to_string(), serde_json::Value::from(time)))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+const fn is_scalar(value: &serde_json::Value) -> bool {
+    !matches!(
+        value,
+        serde_json::Value::Object(_) | serde_json::Value::Array(_)
+    )
+}
+
+fn document_value(replica: &Replica) -> PyResult<serde_json::Value> {
+    let content = replica.get_document(DOC_ID).unwrap_or("{}");
+    serde_json::from_str(content)
+        .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Corrupt CRDT document: {e}")))
+}
+
+fn check_path(path: &str) -> PyResult<()> {
+    if path.split('.').any(str::is_empty) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid CrdtDocument path: {path:?}"
+        )));
     }
-    map
+    Ok(())
+}
+
+/// Apply `value` at `path` as local operations.
+///
+/// A container replaces whatever is at `path`: the old value is deleted
+/// first, otherwise its keys would survive the merge-friendly container marker.
+fn set_local(replica: &mut Replica, path: &str, value: &serde_json::Value) -> PyResult<()> {
+    check_path(path)?;
+    let mut ops = Vec::new();
+    if value.is_object() || value.is_array() {
+        let current = document_value(replica)?;
+        if path
+            .split('.')
+            .try_fold(&current, |node, part| match node {
+                serde_json::Value::Object(map) => map.get(part),
+                serde_json::Value::Array(items) => items.get(part.parse::<usize>().ok()?),
+                _ => None,
+            })
+            .is_some()
+        {
+            ops.push(DsonOperation::FieldDelete {
+                path: path.to_string(),
+            });
+        }
+    }
+    value_to_operations(path, value, &mut ops)?;
+    for op in &ops {
+        replica.apply_local(op).map_err(crdt_error)?;
+    }
+    Ok(())
 }
 
 /// A CRDT-enabled document for conflict-free merging.
 ///
+/// Every write is a field operation stamped with a Lamport timestamp and the
+/// replica's vector clock, so merges follow causality rather than wall clocks.
+/// Concurrent writes to a field go to that path's strategy (LWW by default,
+/// ties broken by replica id). Paths are dotted; numeric segments index arrays.
+///
 /// # Examples
 /// ```python
 /// import fionn.ext as fx
@@ -115,14 +275,26 @@ fn timestamps_to_json(
 /// remote_doc.set("counter", 5)
 ///
 /// conflicts = doc.merge(remote_doc)
-/// print(doc.value)  # Uses LWW resolution
+/// print(doc.value)  # Concurrent writes resolved by LWW
+///
+/// # Across processes, ship only what the peer lacks
+/// payload = remote_doc.delta_since(doc.clock)
+/// doc.apply_delta(payload)
 /// ```
 #[pyclass]
 pub struct CrdtDocument {
     replica_id: String,
-    processor: RwLock<OptimizedMergeProcessor>,
-    document: RwLock<serde_json::Value>,
-    timestamps: RwLock<ahash::AHashMap<String, u64>>,
+    replica: RwLock<Replica>,
+}
+
+impl CrdtDocument {
+    fn read(&self) -> PyResult<std::sync::RwLockReadGuard<'_, Replica>> {
+        self.replica.read().map_err(lock_error)
+    }
+
+    fn write(&self) -> PyResult<std::sync::RwLockWriteGuard<'_, Replica>> {
+        self.replica.write().map_err(lock_error)
+    }
 }
 
 #[pymethods]
@@ -131,196 +303,135 @@ impl CrdtDocument {
     #[pyo3(signature = (initial_data, replica_id))]
     fn new(py: Python<'_>, initial_data: &Bound<'_, PyAny>, replica_id: &str) -> PyResult<Self> {
         let doc = py_to_json(py, initial_data, None, &DumpOptions::default())?;
-        let processor = OptimizedMergeProcessor::new();
-
-        // Initialize timestamps for all fields
-        let mut timestamps = ahash::AHashMap::new();
-        let ts = current_timestamp();
-        if let serde_json::Value::Object(map) = &doc {
-            for key in map.keys() {
-                timestamps.insert(key.clone(), ts);
-            }
+        if !doc.is_object() {
+            return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "CrdtDocument initial_data must be a dict",
+            ));
+        }
+
+        // Initial fields are local writes, so they replicate like any other
+        let mut replica = Replica::new(RecordLines, replica_id);
+        replica.insert_document(DOC_ID, "{}".to_string());
+        let mut ops = Vec::new();
+        value_to_operations("", &doc, &mut ops)?;
+        for op in &ops {
+            replica.apply_local(op).map_err(crdt_error)?;
         }
 
         Ok(Self {
             replica_id: replica_id.to_string(),
-            processor: RwLock::new(processor),
-            document: RwLock::new(doc),
-            timestamps: RwLock::new(timestamps),
+            replica: RwLock::new(replica),
         })
     }
 
-    /// Set a value at the given path.
+    /// Set a value at the given dotted path.
     fn set(&self, py: Python<'_>, path: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
         let json_val = py_to_json(py, value, None, &DumpOptions::default())?;
-        let ts = current_timestamp();
-
-        // Update document
-        let mut doc = self.document.write().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-        if let serde_json::Value::Object(ref mut map) = *doc {
-            map.insert(path.to_string(), json_val);
-        }
-
-        // Update timestamp
-        self.timestamps
-            .write()
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?
-            .insert(path.to_string(), ts);
-
-        Ok(())
+        set_local(&mut *self.write()?, path, &json_val)
     }
 
-    /// Delete a value at the given path.
+    /// Delete a value at the given dotted path.
     fn delete(&self, path: &str) -> PyResult<()> {
-        let ts = current_timestamp();
-
-        // Remove from document
-        let mut doc = self.document.write().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-        if let serde_json::Value::Object(ref mut map) = *doc {
-            map.remove(path);
-        }
-
-        // Update timestamp (tombstone)
-        self.timestamps
-            .write()
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?
-            .insert(path.to_string(), ts);
-
+        check_path(path)?;
+        self.write()?
+            .apply_local(&DsonOperation::FieldDelete {
+                path: path.to_string(),
+            })
+            .map_err(crdt_error)?;
         Ok(())
     }
 
     /// Merge with another document.
     ///
-    /// Returns a list of conflicts (paths where both documents modified the same field).
+    /// Applies every operation of `other` this replica has not seen and
+    /// returns the conflicts: concurrent writes to the same field.
     fn merge(&self, py: Python<'_>, other: &Self) -> PyResult<Py<PyAny>> {
+        if std::ptr::eq(self, other) {
+            return json_to_py(py, &serde_json::Value::Array(Vec::new()));
+        }
+
         let conflicts = py.detach(|| {
-            let mut conflicts = Vec::new();
+            let since = self.read()?.vector_clock().clone();
+            let delta = other.read()?.generate_delta(&since);
+            let conflicts = self.write()?.apply_delta(delta).map_err(crdt_error)?;
+            Ok::<_, PyErr>(conflicts_to_json(&conflicts))
+        })?;
 
-            let other_doc = other.document.read().map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?;
-            let other_timestamps = other.timestamps.read().map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?;
+        json_to_py(py, &conflicts)
+    }
 
-            let mut my_doc = self.document.write().map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?;
-            let mut my_timestamps = self.timestamps.write().map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?;
+    /// Encode the operations a peer at `clock` has not seen.
+    ///
+    /// `clock` is the peer's `clock` property; `None` ships the full history.
+    #[pyo3(signature = (clock=None))]
+    fn delta_since(
+        &self,
+        py: Python<'_>,
+        clock: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let mut entries = Vec::new();
+        if let Some(clock) = clock {
+            for (replica, time) in clock.iter() {
+                entries.push((replica.extract::<String>()?, time.extract::<u64>()?));
+            }
+        }
+        let since = VectorClock::from_clocks(entries);
 
-            // For each field in other document
-            if let (serde_json::Value::Object(my_map), serde_json::Value::Object(other_map)) =
-                (&mut *my_doc, &*other_doc)
-            {
-                for (key, other_value) in other_map {
-                    let other_ts = other_timestamps.get(key).copied().unwrap_or(0);
-                    let my_ts = my_timestamps.get(key).copied().unwrap_or(0);
-
-                    // LWW resolution
-                    let winner = merge_lww_fast(my_ts, other_ts);
-
-                    // Track conflict if both modified
-                    if my_map.contains_key(key) && my_ts > 0 && other_ts > 0 && my_ts != other_ts {
-                        conflicts.push(serde_json::json!({
-                            "path": key,
-                            "local_ts": my_ts,
-                            "remote_ts": other_ts,
-                            "winner": if winner == Winner::Remote { "remote" } else { "local" }
-                        }));
-                    }
+        let bytes = py.detach(|| {
+            self.read()?
+                .generate_delta(&since)
+                .to_bytes()
+                .map_err(|e| PyErr::new::<JSONEncodeError, _>(e.to_string()))
+        })?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
 
-                    // Apply winner
-                    if winner == Winner::Remote {
-                        my_map.insert(key.clone(), other_value.clone());
-                        my_timestamps.insert(key.clone(), other_ts);
-                    }
-                }
-            }
-            Ok::<Vec<serde_json::Value>, PyErr>(conflicts)
+    /// Apply a delta produced by another replica's `delta_since`.
+    ///
+    /// Operations arriving ahead of their causal dependencies are held back
+    /// until the missing ones are applied. Returns the merge conflicts.
+    fn apply_delta(&self, py: Python<'_>, data: &[u8]) -> PyResult<Py<PyAny>> {
+        let conflicts = py.detach(|| {
+            let delta = FormatDelta::from_bytes(data)
+                .map_err(|e| PyErr::new::<JSONDecodeError, _>(e.to_string()))?;
+            let conflicts = self.write()?.apply_delta(delta).map_err(crdt_error)?;
+            Ok::<_, PyErr>(conflicts_to_json(&conflicts))
         })?;
 
-        json_to_py(py, &serde_json::Value::Array(conflicts))
+        json_to_py(py, &conflicts)
+    }
+
+    /// Vector clock as `{replica_id: operations seen}`.
+    #[getter]
+    fn clock(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let clock = clock_to_json(self.read()?.vector_clock());
+        json_to_py(py, &clock)
     }
 
-    /// Set merge strategy for a path pattern.
+    /// Set the merge strategy for concurrent writes at a path and below it.
     fn set_strategy(&self, path_pattern: &str, strategy: MergeStrategy) -> PyResult<()> {
-        let core_strategy: CoreMergeStrategy = strategy.into();
-        self.processor
-            .write()
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-            })?
-            .set_path_strategy(path_pattern, core_strategy);
+        self.write()?
+            .set_path_strategy(path_pattern, OpsMergeStrategy::from(strategy));
         Ok(())
     }
 
     /// Export full state.
     fn export_state(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let doc = self.document.read().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-        let timestamps = self.timestamps.read().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-
+        let replica = self.read()?;
         let state = serde_json::json!({
             "replica_id": self.replica_id,
-            "document": &*doc,
-            "timestamps": serde_json::Value::Object(timestamps_to_json(&timestamps)),
+            "document": document_value(&replica)?,
+            "clock": clock_to_json(replica.vector_clock()),
         });
+        drop(replica);
 
         json_to_py(py, &state)
     }
 
-    /// Export delta since a version.
-    fn export_delta(&self, py: Python<'_>, since_version: u64) -> PyResult<Py<PyAny>> {
-        let doc = self.document.read().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-        let timestamps = self.timestamps.read().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
-
-        // Collect fields modified since the given version
-        let mut delta = serde_json::Map::new();
-        let mut delta_timestamps = serde_json::Map::new();
-
-        if let serde_json::Value::Object(ref map) = *doc {
-            for (key, value) in map {
-                if let Some(&ts) = timestamps.get(key)
-                    && ts > since_version
-                {
-                    delta.insert(key.clone(), value.clone());
-                    delta_timestamps.insert(key.clone(), serde_json::Value::Number(ts.into()));
-                }
-            }
-        }
-
-        let result = serde_json::json!({
-            "replica_id": self.replica_id,
-            "delta": serde_json::Value::Object(delta),
-            "timestamps": serde_json::Value::Object(delta_timestamps),
-        });
-
-        json_to_py(py, &result)
-    }
-
     /// Get current value.
     #[getter]
     fn value(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let doc = self.document.read().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Lock error: {e}"))
-        })?;
+        let doc = document_value(&*self.read()?)?;
         json_to_py(py, &doc)
     }
 
@@ -332,8 +443,10 @@ impl CrdtDocument {
 
     /// Bulk merge from a JSONL file.
     ///
-    /// This is significantly faster than one-by-one Python merges as it
-    /// releases the GIL and uses native SIMD-accelerated parsing.
+    /// Lines are applied in file order, each field resolved against the
+    /// current value through its path strategy; a `ts` field orders lines for
+    /// LWW instead of being merged. Changed fields are recorded as local
+    /// writes, so they replicate. The GIL is released throughout.
     fn merge_jsonl(&self, py: Python<'_>, path: &str) -> PyResult<()> {
         let path_buf = PathBuf::from(path);
 
@@ -342,6 +455,8 @@ impl CrdtDocument {
                 PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to open {path}: {e}"))
             })?;
             let reader = BufReader::new(file);
+            let mut replica = self.write()?;
+            let mut line_timestamps: ahash::AHashMap<String, u64> = ahash::AHashMap::new();
 
             for (i, line) in reader.lines().enumerate() {
                 let line_str = line.map_err(|e| {
@@ -358,38 +473,41 @@ impl CrdtDocument {
                     PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON in line {i}: {e}"))
                 })?;
 
-                if let serde_json::Value::Object(other_map) = value {
-                    let mut my_doc = self.document.write().map_err(|e| {
-                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                            "Lock error: {e}"
-                        ))
-                    })?;
-                    let mut my_timestamps = self.timestamps.write().map_err(|e| {
-                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                            "Lock error: {e}"
-                        ))
-                    })?;
-
-                    if let serde_json::Value::Object(ref mut dest_map) = *my_doc {
-                        // Assuming each object might have a 'ts' field for LWW
-                        let remote_ts = other_map
-                            .get("ts")
-                            .and_then(serde_json::Value::as_u64)
-                            .unwrap_or(0);
-
-                        for (key, other_value) in other_map {
-                            if key == "ts" {
-                                continue;
-                            }
-
-                            let my_ts = my_timestamps.get(&key).copied().unwrap_or(0);
-                            let winner = merge_lww_fast(my_ts, remote_ts);
-
-                            if winner == Winner::Remote {
-                                dest_map.insert(key.clone(), other_value);
-                                my_timestamps.insert(key, remote_ts);
-                            }
+                let serde_json::Value::Object(other_map) = value else {
+                    continue;
+                };
+                let remote_ts = other_map
+                    .get("ts")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or(0);
+                let current = document_value(&replica)?;
+
+                for (key, other_value) in other_map {
+                    if key == "ts" {
+                        continue;
+                    }
+
+                    let newer = line_timestamps.get(&key).is_none_or(|&ts| remote_ts >= ts);
+                    let (local_rank, remote_rank) = if newer { (0, 1) } else { (1, 0) };
+                    let resolved = match current.get(&key) {
+                        Some(local) if is_scalar(local) && is_scalar(&other_value) => {
+                            let strategy = replica.strategy_for(&key);
+                            operation_value_to_json(&strategy.resolve(
+                                &json_value_to_operation_value(local),
+                                &json_value_to_operation_value(&other_value),
+                                local_rank,
+                                remote_rank,
+                            ))
                         }
+                        Some(local) if !newer => local.clone(),
+                        _ => other_value,
+                    };
+
+                    if newer {
+                        line_timestamps.insert(key.clone(), remote_ts);
+                    }
+                    if current.get(&key) != Some(&resolved) {
+                        set_local(&mut replica, &key, &resolved)?;
                     }
                 }
             }
@@ -4,6 +4,7 @@
 //! Parse and serialize YAML, TOML, CSV, ISON, ISONL, JSONL, and TOON formats.
 //! All formats are first-class citizens with full read/write support.
 
+use fionn_simd::formats::{CsvCell, CsvDialect};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 
@@ -196,65 +197,73 @@ pub fn parse_csv(
     let text = data.to_cow()?;
     let list = PyList::empty(py);
 
-    let mut lines = text.lines().peekable();
+    let dialect = csv_dialect().with_header(has_header);
+    let mut records = dialect
+        .split_records(text.as_bytes())
+        .into_iter()
+        .map(|record| dialect.parse_record(record))
+        .peekable();
 
     // Get headers
     let headers: Vec<String> = if has_header {
-        if let Some(header_line) = lines.next() {
-            header_line
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .collect()
+        if let Some(header) = records.next() {
+            header.into_iter().map(|cell| cell.value).collect()
         } else {
             return Ok(list.into());
         }
     } else {
         // Generate numeric headers
-        if let Some(first_line) = lines.peek() {
-            let count = first_line.split(',').count();
-            (0..count).map(|i| i.to_string()).collect()
+        if let Some(first) = records.peek() {
+            (0..first.len()).map(|i| i.to_string()).collect()
         } else {
             return Ok(list.into());
         }
     };
 
     // Parse data rows
-    for line in lines {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
+    for cells in records {
         let dict = PyDict::new(py);
-        let values: Vec<&str> = line.split(',').collect();
-
         for (i, header) in headers.iter().enumerate() {
-            let value = values.get(i).map_or("", |s| s.trim());
-
-            // Try to parse as number, bool, or keep as string
-            let py_value: Py<PyAny> = if let Ok(n) = value.parse::<i64>() {
-                n.into_pyobject(py)?.unbind().into_any()
-            } else if let Ok(f) = value.parse::<f64>() {
-                f.into_pyobject(py)?.unbind().into_any()
-            } else if value == "true" || value == "false" {
-                (value == "true")
-                    .into_pyobject(py)?
-                    .to_owned()
-                    .unbind()
-                    .into_any()
-            } else {
-                value.into_pyobject(py)?.unbind().into_any()
-            };
-
-            dict.set_item(header, py_value)?;
+            dict.set_item(header, json_to_py(py, &csv_row_cell(&cells, i))?)?;
         }
-
         list.append(dict)?;
     }
 
     Ok(list.into())
 }
 
+/// CSV dialect shared by `parse_csv` and CSV streams: RFC 4180 quoting with
+/// whitespace around unquoted cells trimmed.
+pub(super) const fn csv_dialect() -> CsvDialect {
+    CsvDialect::new().with_trim(true)
+}
+
+/// Infer the type of the `i`th cell of a row; missing cells read as `""`.
+///
+/// Quoted cells stay strings. Non-finite floats have no JSON form and stay
+/// strings too.
+pub(super) fn csv_row_cell(cells: &[CsvCell], i: usize) -> serde_json::Value {
+    let Some(cell) = cells.get(i) else {
+        return serde_json::Value::String(String::new());
+    };
+    let value = cell.value.as_str();
+    if cell.quoted {
+        serde_json::Value::String(value.to_string())
+    } else if let Ok(n) = value.parse::<i64>() {
+        n.into()
+    } else if let Some(n) = value
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+    {
+        serde_json::Value::Number(n)
+    } else if value == "true" || value == "false" {
+        serde_json::Value::Bool(value == "true")
+    } else {
+        serde_json::Value::String(value.to_string())
+    }
+}
+
 /// Parse ISON string to Python object.
 ///
 /// ISON (Indexed Structured Object Notation) is a compact format with
@@ -174,7 +174,7 @@ impl IsonlReader {
 
 /// Value types in ISONL
 #[derive(Debug)]
-enum IsonlValue {
+pub(super) enum IsonlValue {
     Int(i64),
     Float(f64),
     String(String),
@@ -182,7 +182,7 @@ enum IsonlValue {
 }
 
 /// Parse a single ISONL line using SIMD-friendly byte scanning
-fn parse_isonl_line(
+pub(super) fn parse_isonl_line(
     line: &[u8],
     requested_fields: Option<&Vec<String>>,
 ) -> Result<Vec<(String, IsonlValue)>, String> {
@@ -9,7 +9,11 @@
 //! - Diff/Patch/Merge operations
 //! - CRDT conflict-free merging
 //! - Zero-copy tape API
+//! - Incremental record streams over file-like objects
+//! - Arrow export for pyarrow/Polars (requires `arrow` feature)
 
+#[cfg(feature = "arrow")]
+mod arrow;
 mod crdt;
 mod diff;
 mod formats;
@@ -17,7 +21,9 @@ mod gron;
 mod isonl;
 mod jsonl;
 mod pipeline;
+mod stream;
 mod tape;
+mod tape_view;
 
 use pyo3::prelude::*;
 
@@ -46,6 +52,22 @@ pub fn register_ext_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(isonl::to_isonl, m)?)?;
     m.add_function(wrap_pyfunction!(isonl::jsonl_to_isonl, m)?)?;
 
+    // ==========================================================================
+    // Incremental Streams (paths, file-like objects, asyncio readers)
+    // ==========================================================================
+    m.add_class::<stream::RecordStream>()?;
+    m.add_function(wrap_pyfunction!(stream::open_stream, m)?)?;
+
+    // ==========================================================================
+    // Arrow Export (zero-copy hand-off to pyarrow, Polars, DuckDB)
+    // ==========================================================================
+    #[cfg(feature = "arrow")]
+    {
+        m.add_class::<arrow::ArrowBatch>()?;
+        m.add_function(wrap_pyfunction!(arrow::jsonl_to_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(arrow::records_to_arrow, m)?)?;
+    }
+
     // ==========================================================================
     // Multi-Format Parsing
     // ==========================================================================
@@ -102,6 +124,10 @@ pub fn register_ext_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<tape::Tape>()?;
     m.add_class::<tape::TapePool>()?;
     m.add_class::<tape::Schema>()?;
+    m.add_class::<tape_view::TapeMapping>()?;
+    m.add_class::<tape_view::TapeSequence>()?;
+    m.add_class::<tape_view::TapeIter>()?;
+    tape_view::register_abcs(m.py())?;
     m.add_function(wrap_pyfunction!(tape::parse_tape, m)?)?;
     m.add_function(wrap_pyfunction!(tape::batch_resolve, m)?)?;
     m.add_function(wrap_pyfunction!(tape::batch_query, m)?)?;
@@ -2,24 +2,53 @@
 //! Stream processing pipeline
 //!
 //! Build pipelines for processing JSONL and ISONL streams.
+//!
+//! Declarative stages (`select`, `where`, `apply`, `rename`, `drop`,
+//! `convert`) run in Rust on parallel batches with the GIL released. Python
+//! callables (`filter`, `map`) remain available as an escape hatch; records
+//! only cross into Python around those stages.
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rayon::prelude::*;
+use serde_json::{Map, Value};
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Write};
 
+use fionn_core::CompiledSchema;
+use fionn_core::format::FormatKind;
+use fionn_core::{DsonError, OperationValue};
+use fionn_ops::DsonOperation;
+use fionn_simd::{FilterExpr, Prefilter, Verdict};
+use fionn_stream::format_dson::{
+    BatchStatistics, FormatBatchProcessor, FormatBatchResult, FormatDsonProcessor,
+};
+
 use crate::compat::options::DumpOptions;
 use crate::exceptions::JSONDecodeError;
 use crate::types::{json_to_py, py_to_json};
 
+/// Non-empty lines read before a batch runs through the stages
+const BATCH_LINES: usize = 16 * 1024;
+
+/// Records handled by one parallel work unit
+const CHUNK_RECORDS: usize = 512;
+
 /// Stream processing pipeline for JSONL/ISONL.
 ///
 /// # Examples
 /// ```python
 /// import fionn.ext as fx
 ///
-/// pipeline = fx.Pipeline()
-/// pipeline.filter(lambda x: x["active"])
-/// pipeline.map(lambda x: {"id": x["id"], "score": x["score"] * 2})
+/// pipeline = (
+///     fx.Pipeline()
+///     .where("status == 'active'")
+///     .select(["id", "score", "user.name"])
+///     .rename({"user.name": "name"})
+/// )
+///
+/// # Python callables still work, at the cost of a round trip through dicts
+/// pipeline.map(lambda x: {**x, "score": x["score"] * 2})
 ///
 /// # Process JSONL
 /// pipeline.process_jsonl("input.jsonl", "output.jsonl")
@@ -33,10 +62,42 @@ pub struct Pipeline {
 }
 
 enum PipelineStage {
+    Select(CompiledSchema),
+    Where(WhereStage),
+    Apply(Vec<DsonOperation>),
+    Rename(Vec<(Vec<String>, Vec<String>)>),
+    Drop(Vec<Vec<String>>),
+    Convert(RecordFormat),
     Filter(Py<PyAny>),
     Map(Py<PyAny>),
 }
 
+impl PipelineStage {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Select(_) => "select",
+            Self::Where(_) => "where",
+            Self::Apply(_) => "apply",
+            Self::Rename(_) => "rename",
+            Self::Drop(_) => "drop",
+            Self::Convert(_) => "convert",
+            Self::Filter(_) => "filter",
+            Self::Map(_) => "map",
+        }
+    }
+
+    /// Whether the stage calls back into Python
+    const fn is_python(&self) -> bool {
+        matches!(self, Self::Filter(_) | Self::Map(_))
+    }
+}
+
+/// Consecutive stages that run on the same side of the GIL
+enum Segment<'a> {
+    Native(&'a [PipelineStage]),
+    Python(&'a [PipelineStage]),
+}
+
 #[pymethods]
 impl Pipeline {
     #[new]
@@ -47,6 +108,8 @@ impl Pipeline {
     /// Add a filter stage.
     ///
     /// The predicate function should return True to keep the record, False to drop it.
+    /// Runs under the GIL; prefer `where` when the condition can be expressed
+    /// declaratively.
     ///
     /// Args:
     ///     predicate: A callable that takes a record (dict) and returns bool
@@ -66,6 +129,7 @@ impl Pipeline {
     /// Add a map (transform) stage.
     ///
     /// The transform function takes a record and returns a new/modified record.
+    /// Runs under the GIL; prefer the native stages where they suffice.
     ///
     /// Args:
     ///     transform: A callable that takes a record (dict) and returns a dict
@@ -82,95 +146,185 @@ impl Pipeline {
         slf
     }
 
-    /// Process JSONL file through pipeline.
+    /// Keep only the given field paths (native stage).
+    ///
+    /// Paths are dotted (`user.name`) and may use `*` wildcards
+    /// (`user.*`). Arrays are transparent: `items.sku` keeps the `sku` of
+    /// every element of `items`.
     ///
     /// Args:
-    ///     `input_path`: Path to input JSONL file
-    ///     `output_path`: Path to output JSONL file
+    ///     fields: Field paths to keep
     ///
     /// Returns:
-    ///     Number of records processed
+    ///     self for method chaining
     ///
     /// Example:
     /// ```python
-    /// count = pipeline.process_jsonl("input.jsonl", "output.jsonl")
-    /// print(f"Processed {count} records")
+    /// pipeline.select(["id", "user.name"])
     /// ```
-    fn process_jsonl(&self, py: Python<'_>, input_path: &str, output_path: &str) -> PyResult<u64> {
-        let input_file = File::open(input_path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open input file: {e}"))
+    fn select(mut slf: PyRefMut<'_, Self>, fields: Vec<String>) -> PyResult<PyRefMut<'_, Self>> {
+        let schema = CompiledSchema::compile(&fields).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid field path: {e}"))
         })?;
-        let output_file = File::create(output_path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create output file: {e}"))
-        })?;
-
-        let reader = BufReader::with_capacity(64 * 1024, input_file);
-        let mut writer = BufWriter::with_capacity(64 * 1024, output_file);
-
-        let mut count = 0u64;
-        let mut line_buffer = String::with_capacity(4096);
-        let opts = DumpOptions::default();
-
-        for line_result in reader.lines() {
-            let line = line_result.map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Read error: {e}"))
-            })?;
-
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
+        slf.stages.push(PipelineStage::Select(schema));
+        Ok(slf)
+    }
 
-            // Parse JSON line
-            let mut bytes = trimmed.as_bytes().to_vec();
-            let value: serde_json::Value = simd_json::from_slice(&mut bytes)
-                .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))?;
-
-            // Convert to Python for pipeline stages
-            let mut py_obj = json_to_py(py, &value)?;
-
-            // Apply pipeline stages
-            let mut keep = true;
-            for stage in &self.stages {
-                match stage {
-                    PipelineStage::Filter(predicate) => {
-                        let result = predicate.call1(py, (&py_obj,))?;
-                        if !result.extract::<bool>(py)? {
-                            keep = false;
-                            break;
-                        }
-                    }
-                    PipelineStage::Map(transform) => {
-                        py_obj = transform.call1(py, (&py_obj,))?;
-                    }
-                }
-            }
+    /// Keep records matching a filter expression (native stage).
+    ///
+    /// Accepts `path`, `path OP literal` with `==`, `!=`, `>`, `>=`, `<`,
+    /// `<=`, or `path in [..]` with a JSON list. A bare path tests for existence. When this
+    /// is the first stage of a JSONL pipeline, simple field paths are
+    /// checked on the raw bytes so rejected lines are never parsed.
+    ///
+    /// Args:
+    ///     expr: Filter expression, e.g. `"latency > 500"`
+    ///
+    /// Returns:
+    ///     self for method chaining
+    ///
+    /// Example:
+    /// ```python
+    /// pipeline.where('user.role in ["admin", "owner"]')
+    /// ```
+    #[pyo3(name = "where")]
+    fn where_<'py>(mut slf: PyRefMut<'py, Self>, expr: &str) -> PyResult<PyRefMut<'py, Self>> {
+        let stage = WhereStage::parse(expr).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported where expression (paths may only use fields and indices): {expr}"
+            ))
+        })?;
+        slf.stages.push(PipelineStage::Where(stage));
+        Ok(slf)
+    }
 
-            if keep {
-                // Convert back to JSON and write
-                let result_value = py_to_json(py, py_obj.bind(py), None, &opts)?;
-                let json_str = serde_json::to_string(&result_value).map_err(|e| {
-                    PyErr::new::<crate::exceptions::JSONEncodeError, _>(format!(
-                        "Serialization error: {e}"
-                    ))
-                })?;
+    /// Apply DSON operations to every record (native stage).
+    ///
+    /// Each operation is a dict with an `op` of `field_add`, `field_modify`,
+    /// `field_delete`, `array_insert`, `array_remove` or `array_replace`, a
+    /// dotted `path`, and the `value` (a scalar) and `index` the operation
+    /// needs.
+    ///
+    /// Args:
+    ///     operations: List of operation dicts
+    ///
+    /// Returns:
+    ///     self for method chaining
+    ///
+    /// Example:
+    /// ```python
+    /// pipeline.apply([
+    ///     {"op": "field_add", "path": "source", "value": "import"},
+    ///     {"op": "field_delete", "path": "debug"},
+    /// ])
+    /// ```
+    fn apply<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        operations: Vec<Bound<'py, PyAny>>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let operations = operations
+            .iter()
+            .map(|op| parse_operation(py, op))
+            .collect::<PyResult<Vec<_>>>()?;
+        slf.stages.push(PipelineStage::Apply(operations));
+        Ok(slf)
+    }
 
-                line_buffer.clear();
-                line_buffer.push_str(&json_str);
-                line_buffer.push('\n');
-                writer.write_all(line_buffer.as_bytes()).map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
-                })?;
+    /// Rename fields (native stage).
+    ///
+    /// Both sides are dotted paths, so a rename can also move a field;
+    /// missing parents of the target are created as objects.
+    ///
+    /// Args:
+    ///     mapping: Dict of old path to new path
+    ///
+    /// Returns:
+    ///     self for method chaining
+    ///
+    /// Example:
+    /// ```python
+    /// pipeline.rename({"ts": "timestamp", "user.name": "name"})
+    /// ```
+    fn rename<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        mapping: &Bound<'py, PyDict>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let pairs = mapping
+            .iter()
+            .map(|(from, to)| {
+                Ok((
+                    split_path(&from.extract::<String>()?),
+                    split_path(&to.extract::<String>()?),
+                ))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        slf.stages.push(PipelineStage::Rename(pairs));
+        Ok(slf)
+    }
 
-                count += 1;
-            }
-        }
+    /// Remove fields (native stage).
+    ///
+    /// Args:
+    ///     fields: Dotted field paths to remove
+    ///
+    /// Returns:
+    ///     self for method chaining
+    ///
+    /// Example:
+    /// ```python
+    /// pipeline.drop(["debug", "user.password"])
+    /// ```
+    fn drop(mut slf: PyRefMut<'_, Self>, fields: Vec<String>) -> PyRefMut<'_, Self> {
+        let paths = fields.iter().map(|field| split_path(field)).collect();
+        slf.stages.push(PipelineStage::Drop(paths));
+        slf
+    }
 
-        writer.flush().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Flush error: {e}"))
+    /// Set the output format (native stage).
+    ///
+    /// Overrides the default output of `process_jsonl`/`process_isonl`, and
+    /// of `process` when no `output_format` is passed.
+    ///
+    /// Args:
+    ///     to: Output format ("jsonl", "isonl", "json", "yaml")
+    ///
+    /// Returns:
+    ///     self for method chaining
+    ///
+    /// Example:
+    /// ```python
+    /// pipeline.convert(to="isonl").process_jsonl("in.jsonl", "out.isonl")
+    /// ```
+    fn convert<'py>(mut slf: PyRefMut<'py, Self>, to: &str) -> PyResult<PyRefMut<'py, Self>> {
+        let format = RecordFormat::parse(to).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported output format: {to}"
+            ))
         })?;
+        slf.stages.push(PipelineStage::Convert(format));
+        Ok(slf)
+    }
 
-        Ok(count)
+    /// Process JSONL file through pipeline.
+    ///
+    /// Args:
+    ///     `input_path`: Path to input JSONL file
+    ///     `output_path`: Path to output JSONL file
+    ///
+    /// Returns:
+    ///     Number of records processed
+    ///
+    /// Example:
+    /// ```python
+    /// count = pipeline.process_jsonl("input.jsonl", "output.jsonl")
+    /// print(f"Processed {count} records")
+    /// ```
+    fn process_jsonl(&self, py: Python<'_>, input_path: &str, output_path: &str) -> PyResult<u64> {
+        let output_format = self.convert_format().unwrap_or(RecordFormat::Jsonl);
+        let mut writer = RecordWriter::create(output_path, output_format, None)?;
+        self.process_lines(py, input_path, RecordFormat::Jsonl, &mut writer)?;
+        writer.finish()
     }
 
     /// Process ISONL file through pipeline (11.9x faster than JSONL).
@@ -185,150 +339,10 @@ impl Pipeline {
     /// Returns:
     ///     Number of records processed
     fn process_isonl(&self, py: Python<'_>, input_path: &str, output_path: &str) -> PyResult<u64> {
-        // ISONL processing - parse the ISONL format and apply pipeline
-        let input_file = File::open(input_path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open input file: {e}"))
-        })?;
-        let output_file = File::create(output_path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create output file: {e}"))
-        })?;
-
-        let reader = BufReader::with_capacity(64 * 1024, input_file);
-        let mut writer = BufWriter::with_capacity(64 * 1024, output_file);
-
-        let mut count = 0u64;
-
-        for line_result in reader.lines() {
-            let line = line_result.map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Read error: {e}"))
-            })?;
-
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-
-            // Parse ISONL line: table.name|field1:type|field2:type|val1|val2
-            let parts: Vec<&str> = trimmed.split('|').collect();
-            if parts.len() < 3 {
-                continue;
-            }
-
-            // Find schema/data boundary (where type annotations end)
-            let mut schema_end = 0;
-            for (i, part) in parts.iter().enumerate() {
-                if i == 0 {
-                    schema_end = 1;
-                    continue;
-                }
-                if part.contains(':') {
-                    schema_end = i + 1;
-                } else {
-                    break;
-                }
-            }
-
-            // Build Python dict from ISONL
-            let dict = pyo3::types::PyDict::new(py);
-
-            // Schema fields (skip table name)
-            let fields: Vec<(&str, &str)> = parts[1..schema_end]
-                .iter()
-                .filter_map(|f| {
-                    let (name, typ) = f.split_once(':')?;
-
-                    Some((name, typ))
-                })
-                .collect();
-
-            // Values
-            let values = &parts[schema_end..];
-
-            for (i, (name, typ)) in fields.iter().enumerate() {
-                if i >= values.len() {
-                    break;
-                }
-                let val_str = values[i];
-
-                let py_val: Py<PyAny> = match *typ {
-                    "int" | "i64" | "i32" => val_str.parse::<i64>().ok().map_or_else(
-                        || py.None(),
-                        |v| v.into_pyobject(py).unwrap().unbind().into_any(),
-                    ),
-                    "float" | "f64" | "f32" => val_str.parse::<f64>().ok().map_or_else(
-                        || py.None(),
-                        |v| pyo3::types::PyFloat::new(py, v).unbind().into_any(),
-                    ),
-                    "bool" => {
-                        let b = val_str == "true" || val_str == "1";
-                        b.into_pyobject(py).unwrap().to_owned().unbind().into_any()
-                    }
-                    _ => {
-                        // Default to string
-                        pyo3::types::PyString::new(py, val_str).unbind().into_any()
-                    }
-                };
-
-                dict.set_item(*name, py_val)?;
-            }
-
-            // Apply pipeline stages
-            let mut py_obj: Py<PyAny> = dict.into_any().unbind();
-            let mut keep = true;
-
-            for stage in &self.stages {
-                match stage {
-                    PipelineStage::Filter(predicate) => {
-                        let result = predicate.call1(py, (&py_obj,))?;
-                        if !result.extract::<bool>(py)? {
-                            keep = false;
-                            break;
-                        }
-                    }
-                    PipelineStage::Map(transform) => {
-                        py_obj = transform.call1(py, (&py_obj,))?;
-                    }
-                }
-            }
-
-            if keep {
-                // Write back as ISONL
-                // Reconstruct ISONL line from dict
-                let result_dict = py_obj.bind(py);
-                let mut output_parts = vec![parts[0].to_string()];
-
-                // Add schema
-                for field_schema in &parts[1..schema_end] {
-                    output_parts.push(field_schema.to_string());
-                }
-
-                // Add values
-                for (name, _) in &fields {
-                    match result_dict.get_item(*name) {
-                        Ok(val) => {
-                            let s = val.str()?.to_string();
-                            output_parts.push(s);
-                        }
-                        Err(_) => {
-                            output_parts.push(String::new());
-                        }
-                    }
-                }
-
-                let line_out = output_parts.join("|");
-                writeln!(writer, "{line_out}").map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
-                })?;
-
-                count += 1;
-            }
-        }
-
-        writer.flush().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Flush error: {e}"))
-        })?;
-
-        Ok(count)
+        let output_format = self.convert_format().unwrap_or(RecordFormat::Isonl);
+        let mut writer = RecordWriter::create(output_path, output_format, None)?;
+        self.process_lines(py, input_path, RecordFormat::Isonl, &mut writer)?;
+        writer.finish()
     }
 
     /// Process with format conversion.
@@ -339,99 +353,54 @@ impl Pipeline {
     ///     `input_path`: Path to input file
     ///     `input_format`: Input format ("jsonl", "isonl", "json")
     ///     `output_path`: Path to output file
-    ///     `output_format`: Output format ("jsonl", "isonl", "json")
+    ///     `output_format`: Output format ("jsonl", "isonl", "json", "yaml");
+    ///         defaults to the `convert` stage, then to the input format
     ///     `output_schema`: Optional list of fields to include in output
     ///
     /// Returns:
     ///     Number of records processed
-    #[pyo3(signature = (input_path, input_format, output_path, output_format, output_schema=None))]
+    #[pyo3(signature = (input_path, input_format, output_path, output_format=None, output_schema=None))]
     fn process(
         &self,
         py: Python<'_>,
         input_path: &str,
         input_format: &str,
         output_path: &str,
-        output_format: &str,
+        output_format: Option<&str>,
         output_schema: Option<Vec<String>>,
     ) -> PyResult<u64> {
-        // Read input based on format
-        let records = match input_format.to_lowercase().as_str() {
-            "jsonl" => self.read_jsonl(py, input_path)?,
-            "isonl" => self.read_isonl(py, input_path)?,
-            "json" => self.read_json(py, input_path)?,
-            _ => {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+        let input = RecordFormat::parse(input_format)
+            .filter(|format| *format != RecordFormat::Yaml)
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                     "Unsupported input format: {input_format}"
-                )));
-            }
+                ))
+            })?;
+        let output = match output_format {
+            Some(name) => RecordFormat::parse(name).ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unsupported output format: {name}"
+                ))
+            })?,
+            None => self.convert_format().unwrap_or(input),
         };
 
-        // Apply pipeline stages to each record
-        let mut processed: Vec<Py<PyAny>> = Vec::with_capacity(records.len());
-
-        for record in records {
-            let mut py_obj = record;
-            let mut keep = true;
-
-            for stage in &self.stages {
-                match stage {
-                    PipelineStage::Filter(predicate) => {
-                        let result = predicate.call1(py, (&py_obj,))?;
-                        if !result.extract::<bool>(py)? {
-                            keep = false;
-                            break;
-                        }
-                    }
-                    PipelineStage::Map(transform) => {
-                        py_obj = transform.call1(py, (&py_obj,))?;
-                    }
-                }
-            }
-
-            if keep {
-                // Apply output schema if specified
-                if let Some(ref schema) = output_schema {
-                    let new_dict = pyo3::types::PyDict::new(py);
-                    let source_bound = py_obj.bind(py);
-                    // Try to downcast to PyDict for proper get_item semantics
-                    if let Ok(source_dict) = source_bound.cast::<pyo3::types::PyDict>() {
-                        for field in schema {
-                            if let Some(v) = source_dict.get_item(field)? {
-                                new_dict.set_item(field, v)?;
-                            }
-                        }
-                    }
-                    py_obj = new_dict.into_any().unbind();
-                }
-                processed.push(py_obj);
-            }
-        }
-
-        // Write output based on format
-        let count = processed.len() as u64;
-        match output_format.to_lowercase().as_str() {
-            "jsonl" => self.write_jsonl(py, output_path, &processed)?,
-            "isonl" => self.write_isonl(py, output_path, &processed, &output_schema)?,
-            "json" => self.write_json(py, output_path, &processed)?,
-            _ => {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                    "Unsupported output format: {output_format}"
-                )));
-            }
+        let mut writer = RecordWriter::create(output_path, output, output_schema)?;
+        if input == RecordFormat::Json {
+            let content = std::fs::read(input_path).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot read file: {e}"))
+            })?;
+            let records = py.detach(|| parse_json_records(content))?;
+            let records = self.run_segments(py, &self.segments(), records)?;
+            writer.write_all(&records)?;
+        } else {
+            self.process_lines(py, input_path, input, &mut writer)?;
         }
-
-        Ok(count)
+        writer.finish()
     }
 
     fn __repr__(&self) -> String {
-        let stage_desc: Vec<&str> = self
-            .stages
-            .iter()
-            .map(|s| match s {
-                PipelineStage::Filter(_) => "filter",
-                PipelineStage::Map(_) => "map",
-            })
-            .collect();
+        let stage_desc: Vec<&str> = self.stages.iter().map(PipelineStage::name).collect();
         format!("Pipeline(stages=[{}])", stage_desc.join(", "))
     }
 
@@ -442,263 +411,733 @@ impl Pipeline {
 
 // Helper methods for Pipeline (not exposed to Python)
 impl Pipeline {
-    fn read_jsonl(&self, py: Python<'_>, path: &str) -> PyResult<Vec<Py<PyAny>>> {
-        let file = File::open(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open file: {e}"))
+    /// Output format requested by the last `convert` stage
+    fn convert_format(&self) -> Option<RecordFormat> {
+        self.stages.iter().rev().find_map(|stage| match stage {
+            PipelineStage::Convert(format) => Some(*format),
+            _ => None,
+        })
+    }
+
+    /// Split the stages into native and Python runs
+    fn segments(&self) -> Vec<Segment<'_>> {
+        self.stages
+            .chunk_by(|a, b| a.is_python() == b.is_python())
+            .map(|run| {
+                if run[0].is_python() {
+                    Segment::Python(run)
+                } else {
+                    Segment::Native(run)
+                }
+            })
+            .collect()
+    }
+
+    /// Stream a line-oriented file through the stages in batches
+    fn process_lines(
+        &self,
+        py: Python<'_>,
+        input_path: &str,
+        format: RecordFormat,
+        writer: &mut RecordWriter,
+    ) -> PyResult<()> {
+        let input_file = File::open(input_path).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open input file: {e}"))
         })?;
-        let reader = BufReader::new(file);
-        let mut records = Vec::new();
+        let reader = BufReader::with_capacity(64 * 1024, input_file);
 
+        let mut batch = Vec::with_capacity(BATCH_LINES);
         for line_result in reader.lines() {
             let line = line_result.map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Read error: {e}"))
             })?;
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
+            if line.trim().is_empty() {
                 continue;
             }
+            if format == RecordFormat::Isonl {
+                writer.adopt_isonl_table(&line);
+            }
+            batch.push(line);
+            if batch.len() == BATCH_LINES {
+                let full = std::mem::replace(&mut batch, Vec::with_capacity(BATCH_LINES));
+                writer.write_all(&self.run_lines(py, full, format)?)?;
+            }
+        }
+        if !batch.is_empty() {
+            writer.write_all(&self.run_lines(py, batch, format)?)?;
+        }
+        Ok(())
+    }
 
-            let mut bytes = trimmed.as_bytes().to_vec();
-            let value: serde_json::Value = simd_json::from_slice(&mut bytes)
-                .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))?;
+    /// Decode a batch of lines and run it through every stage
+    ///
+    /// Decoding is fused with the leading native run, so a pipeline that
+    /// starts with native stages parses and transforms each chunk in one
+    /// parallel pass.
+    fn run_lines(
+        &self,
+        py: Python<'_>,
+        lines: Vec<String>,
+        format: RecordFormat,
+    ) -> PyResult<Vec<Value>> {
+        let segments = self.segments();
+        let (leading, rest): (&[PipelineStage], &[Segment<'_>]) = match segments.split_first() {
+            Some((Segment::Native(stages), rest)) => (stages, rest),
+            _ => (&[], &segments),
+        };
+        let prefilter = match leading.first() {
+            Some(PipelineStage::Where(stage)) if format == RecordFormat::Jsonl => {
+                stage.prefilter.as_ref()
+            }
+            _ => None,
+        };
 
-            records.push(json_to_py(py, &value)?);
-        }
+        let records = py.detach(|| {
+            lines
+                .into_par_iter()
+                .chunks(CHUNK_RECORDS)
+                .map(|chunk| {
+                    let records = chunk
+                        .into_iter()
+                        .filter_map(|line| decode_line(&line, format, prefilter).transpose())
+                        .collect::<PyResult<Vec<_>>>()?;
+                    run_native(leading, records)
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?;
 
+        self.run_segments(py, rest, records.into_iter().flatten().collect())
+    }
+
+    /// Run decoded records through the given segments
+    fn run_segments(
+        &self,
+        py: Python<'_>,
+        segments: &[Segment<'_>],
+        mut records: Vec<Value>,
+    ) -> PyResult<Vec<Value>> {
+        for segment in segments {
+            if records.is_empty() {
+                break;
+            }
+            records = match segment {
+                Segment::Native(stages) => py.detach(|| run_native_parallel(stages, records))?,
+                Segment::Python(stages) => run_python(py, stages, records)?,
+            };
+        }
         Ok(records)
     }
+}
 
-    fn read_isonl(&self, py: Python<'_>, path: &str) -> PyResult<Vec<Py<PyAny>>> {
-        let file = File::open(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open file: {e}"))
-        })?;
-        let reader = BufReader::new(file);
-        let mut records = Vec::new();
+// =============================================================================
+// Native Stages
+// =============================================================================
 
-        for line_result in reader.lines() {
-            let line = line_result.map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Read error: {e}"))
-            })?;
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
+/// Split a dotted field path into its keys
+fn split_path(path: &str) -> Vec<String> {
+    path.split('.').map(str::to_string).collect()
+}
 
-            // Parse ISONL format
-            let parts: Vec<&str> = trimmed.split('|').collect();
-            if parts.len() < 3 {
-                continue;
-            }
+/// A compiled `where` expression
+struct WhereStage {
+    expr: FilterExpr,
+    steps: Vec<Step>,
+    prefilter: Option<Prefilter>,
+}
 
-            let dict = pyo3::types::PyDict::new(py);
+/// One step of a `where` path
+enum Step {
+    Field(String),
+    Index(usize),
+}
 
-            // Find schema boundary
-            let mut schema_end = 1;
-            for (i, part) in parts.iter().enumerate().skip(1) {
-                if part.contains(':') {
-                    schema_end = i + 1;
-                } else {
-                    break;
-                }
-            }
+impl WhereStage {
+    /// Parse an expression whose path is a chain of fields and indices
+    fn parse(expr: &str) -> Option<Self> {
+        let expr = FilterExpr::parse(expr);
+        let steps = parse_steps(&expr.path)?;
+        let prefilter = Prefilter::compile(&expr);
+        Some(Self {
+            expr,
+            steps,
+            prefilter,
+        })
+    }
 
-            // Parse schema and values
-            let fields: Vec<(&str, &str)> = parts[1..schema_end]
-                .iter()
-                .filter_map(|f| {
-                    let mut split = f.splitn(2, ':');
-                    Some((split.next()?, split.next()?))
-                })
-                .collect();
+    fn test(&self, record: &Value) -> bool {
+        let found = self
+            .steps
+            .iter()
+            .try_fold(record, |value, step| match step {
+                Step::Field(name) => value.get(name.as_str()),
+                Step::Index(index) => value.get(*index),
+            });
+        self.expr.test(found)
+    }
+}
 
-            let values = &parts[schema_end..];
+/// Parse `$.a.b[0].c` style paths; `None` for wildcards or recursive descent
+fn parse_steps(path: &str) -> Option<Vec<Step>> {
+    let path = path.trim_start_matches('$');
+    let path = path.strip_prefix('.').unwrap_or(path);
+    if path.is_empty() || path.contains('*') {
+        return None;
+    }
 
-            for (i, (name, typ)) in fields.iter().enumerate() {
-                if i >= values.len() {
-                    break;
-                }
-                let val_str = values[i];
-                let py_val: Py<PyAny> = match *typ {
-                    "int" | "i64" | "i32" => val_str.parse::<i64>().ok().map_or_else(
-                        || py.None(),
-                        |v| v.into_pyobject(py).unwrap().unbind().into_any(),
-                    ),
-                    "float" | "f64" | "f32" => val_str.parse::<f64>().ok().map_or_else(
-                        || py.None(),
-                        |v| pyo3::types::PyFloat::new(py, v).unbind().into_any(),
-                    ),
-                    "bool" => {
-                        let b = val_str == "true" || val_str == "1";
-                        b.into_pyobject(py).unwrap().to_owned().unbind().into_any()
-                    }
-                    _ => pyo3::types::PyString::new(py, val_str).unbind().into_any(),
-                };
-                dict.set_item(*name, py_val)?;
+    let mut steps = Vec::new();
+    for part in path.split('.') {
+        let (name, mut indices) = part.find('[').map_or((part, ""), |at| part.split_at(at));
+        if !name.is_empty() {
+            steps.push(Step::Field(name.to_string()));
+        } else if indices.is_empty() {
+            return None;
+        }
+        while let Some(tail) = indices.strip_prefix('[') {
+            let (index, after) = tail.split_once(']')?;
+            steps.push(Step::Index(index.trim().parse().ok()?));
+            indices = after;
+        }
+        if !indices.is_empty() {
+            return None;
+        }
+    }
+    Some(steps)
+}
+
+/// Whether the schema keeps the whole value at `path`
+///
+/// Goes through the patterns rather than `CompiledSchema::matches_path`,
+/// whose hash fast path never matches wildcard patterns.
+fn selects(schema: &CompiledSchema, path: &str) -> bool {
+    !schema.is_excluded(path)
+        && schema
+            .include_patterns
+            .iter()
+            .any(|pattern| pattern.matches(path))
+}
+
+pub(super) fn select_fields(
+    fields: Map<String, Value>,
+    schema: &CompiledSchema,
+    prefix: &str,
+) -> Map<String, Value> {
+    fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            if selects(schema, &path) {
+                return Some((key, value));
+            }
+            if !schema.should_include_object(&path) {
+                return None;
             }
+            select_nested(value, schema, &path).map(|value| (key, value))
+        })
+        .collect()
+}
 
-            records.push(dict.into_any().unbind());
+fn select_nested(value: Value, schema: &CompiledSchema, path: &str) -> Option<Value> {
+    match value {
+        Value::Object(fields) => {
+            let fields = select_fields(fields, schema, path);
+            (!fields.is_empty()).then_some(Value::Object(fields))
         }
+        Value::Array(items) => {
+            let items: Vec<Value> = items
+                .into_iter()
+                .filter_map(|item| select_nested(item, schema, path))
+                .collect();
+            (!items.is_empty()).then_some(Value::Array(items))
+        }
+        _ => None,
+    }
+}
 
-        Ok(records)
+fn remove_path(record: &mut Value, path: &[String]) -> Option<Value> {
+    let (last, parents) = path.split_last()?;
+    let mut current = record;
+    for key in parents {
+        current = current.get_mut(key.as_str())?;
     }
+    current.as_object_mut()?.remove(last)
+}
 
-    fn read_json(&self, py: Python<'_>, path: &str) -> PyResult<Vec<Py<PyAny>>> {
-        let content = std::fs::read_to_string(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot read file: {e}"))
-        })?;
+fn insert_path(record: &mut Value, path: &[String], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = record;
+    for key in parents {
+        let Some(fields) = current.as_object_mut() else {
+            return;
+        };
+        current = fields
+            .entry(key.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+    if let Some(fields) = current.as_object_mut() {
+        fields.insert(last.clone(), value);
+    }
+}
 
-        let mut bytes = content.into_bytes();
-        let value: serde_json::Value = simd_json::from_slice(&mut bytes)
-            .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))?;
+/// Hands serialized records to [`FormatDsonProcessor`], one per line
+pub(super) struct RecordLines;
 
-        // If it's an array, return elements; otherwise wrap in vec
-        if let serde_json::Value::Array(arr) = value {
-            arr.iter().map(|v| json_to_py(py, v)).collect()
-        } else {
-            Ok(vec![json_to_py(py, &value)?])
-        }
+impl FormatBatchProcessor for RecordLines {
+    fn format_kind(&self) -> FormatKind {
+        FormatKind::Json
     }
 
-    fn write_jsonl(&self, py: Python<'_>, path: &str, records: &[Py<PyAny>]) -> PyResult<()> {
-        let file = File::create(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create file: {e}"))
-        })?;
-        let mut writer = BufWriter::new(file);
-        let opts = DumpOptions::default();
+    fn process_batch(
+        &mut self,
+        data: &[u8],
+        _schema: &fionn_stream::skiptape::CompiledSchema,
+    ) -> fionn_core::Result<FormatBatchResult> {
+        self.process_batch_unfiltered(data)
+    }
 
-        for record in records {
-            let value = py_to_json(py, record.bind(py), None, &opts)?;
-            let json_str = serde_json::to_string(&value).map_err(|e| {
-                PyErr::new::<crate::exceptions::JSONEncodeError, _>(format!(
-                    "Serialization error: {e}"
-                ))
-            })?;
-            writeln!(writer, "{json_str}").map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
-            })?;
-        }
+    fn process_batch_unfiltered(&mut self, data: &[u8]) -> fionn_core::Result<FormatBatchResult> {
+        let text = std::str::from_utf8(data)
+            .map_err(|e| DsonError::ParseError(format!("Invalid UTF-8: {e}")))?;
+        let documents: Vec<String> = text.lines().map(str::to_string).collect();
+        Ok(FormatBatchResult {
+            statistics: BatchStatistics {
+                total_lines: documents.len(),
+                successful_lines: documents.len(),
+                ..BatchStatistics::default()
+            },
+            documents,
+            errors: Vec::new(),
+        })
+    }
 
-        writer.flush().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Flush error: {e}"))
-        })?;
+    fn reset(&mut self) {}
+}
 
-        Ok(())
+fn apply_operations(records: Vec<Value>, operations: &[DsonOperation]) -> PyResult<Vec<Value>> {
+    let mut data = String::new();
+    for record in &records {
+        data.push_str(&record.to_string());
+        data.push('\n');
     }
 
-    fn write_isonl(
-        &self,
-        py: Python<'_>,
-        path: &str,
-        records: &[Py<PyAny>],
-        schema: &Option<Vec<String>>,
-    ) -> PyResult<()> {
-        let file = File::create(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create file: {e}"))
-        })?;
-        let mut writer = BufWriter::new(file);
-
-        // Infer schema from first record if not provided
-        let field_schema: Vec<(String, String)> = if let Some(fields) = schema {
-            fields
-                .iter()
-                .map(|f| (f.clone(), "string".to_string()))
-                .collect()
-        } else if let Some(first) = records.first() {
-            let first_bound = first.bind(py);
-            if let Ok(first_dict) = first_bound.cast::<pyo3::types::PyDict>() {
-                first_dict
-                    .keys()
-                    .iter()
-                    .filter_map(|k| {
-                        let key = k.str().ok()?.to_string();
-                        // Infer type from value
-                        let typ = if let Some(val) = first_dict.get_item(&key).ok().flatten() {
-                            if val.is_instance_of::<pyo3::types::PyBool>() {
-                                // Check bool before int since bool is subclass of int
-                                "bool"
-                            } else if val.is_instance_of::<pyo3::types::PyInt>() {
-                                "int"
-                            } else if val.is_instance_of::<pyo3::types::PyFloat>() {
-                                "float"
-                            } else {
-                                "string"
-                            }
-                        } else {
-                            "string"
-                        };
-                        Some((key, typ.to_string()))
-                    })
-                    .collect()
-            } else {
-                Vec::new()
-            }
-        } else {
-            Vec::new()
-        };
+    let mut processor = FormatDsonProcessor::new(RecordLines);
+    let result = processor
+        .process_unfiltered_with_operations(data.as_bytes(), operations)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+    if let Some(error) = result.errors.first() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "apply() failed on {}: {}",
+            error.raw_line, error.error_message
+        )));
+    }
 
-        for record in records {
-            let record_bound = record.bind(py);
-            let mut parts = vec!["table.data".to_string()];
+    result
+        .documents
+        .iter()
+        .map(|document| {
+            serde_json::from_str(document).map_err(|e| {
+                PyErr::new::<JSONDecodeError, _>(format!("Invalid apply() output: {e}"))
+            })
+        })
+        .collect()
+}
 
-            // Add schema fields
-            for (name, typ) in &field_schema {
-                parts.push(format!("{name}:{typ}"));
+/// Run native stages over one chunk of records
+fn run_native(stages: &[PipelineStage], mut records: Vec<Value>) -> PyResult<Vec<Value>> {
+    for stage in stages {
+        if records.is_empty() {
+            break;
+        }
+        match stage {
+            PipelineStage::Select(schema) => {
+                for record in &mut records {
+                    if let Value::Object(fields) = record {
+                        *fields = select_fields(std::mem::take(fields), schema, "");
+                    }
+                }
+            }
+            PipelineStage::Where(stage) => records.retain(|record| stage.test(record)),
+            PipelineStage::Apply(operations) => {
+                records = apply_operations(records, operations)?;
             }
+            PipelineStage::Rename(pairs) => {
+                for record in &mut records {
+                    for (from, to) in pairs {
+                        if let Some(value) = remove_path(record, from) {
+                            insert_path(record, to, value);
+                        }
+                    }
+                }
+            }
+            PipelineStage::Drop(paths) => {
+                for record in &mut records {
+                    for path in paths {
+                        remove_path(record, path);
+                    }
+                }
+            }
+            PipelineStage::Convert(_) => {}
+            PipelineStage::Filter(_) | PipelineStage::Map(_) => {
+                unreachable!("Python stages run in their own segment")
+            }
+        }
+    }
+    Ok(records)
+}
 
-            // Add values - try to downcast to PyDict
-            if let Ok(record_dict) = record_bound.cast::<pyo3::types::PyDict>() {
-                for (name, _) in &field_schema {
-                    if let Some(val) = record_dict.get_item(name.as_str())? {
-                        parts.push(val.str()?.to_string());
-                    } else {
-                        parts.push(String::new());
+/// Run native stages over records in parallel chunks
+fn run_native_parallel(stages: &[PipelineStage], records: Vec<Value>) -> PyResult<Vec<Value>> {
+    let chunks = records
+        .into_par_iter()
+        .chunks(CHUNK_RECORDS)
+        .map(|chunk| run_native(stages, chunk))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Run Python stages record by record under the GIL
+fn run_python(
+    py: Python<'_>,
+    stages: &[PipelineStage],
+    records: Vec<Value>,
+) -> PyResult<Vec<Value>> {
+    let opts = DumpOptions::default();
+    let mut kept = Vec::with_capacity(records.len());
+
+    'records: for record in records {
+        let mut py_obj = json_to_py(py, &record)?;
+        let mut mapped = false;
+        for stage in stages {
+            match stage {
+                PipelineStage::Filter(predicate) => {
+                    if !predicate.call1(py, (&py_obj,))?.bind(py).is_truthy()? {
+                        continue 'records;
                     }
                 }
-            } else {
-                // If not a dict, add empty values
-                for _ in &field_schema {
-                    parts.push(String::new());
+                PipelineStage::Map(transform) => {
+                    py_obj = transform.call1(py, (&py_obj,))?;
+                    mapped = true;
                 }
+                _ => unreachable!("native stages run in their own segment"),
             }
+        }
+        kept.push(if mapped {
+            py_to_json(py, py_obj.bind(py), None, &opts)?
+        } else {
+            record
+        });
+    }
 
-            writeln!(writer, "{}", parts.join("|")).map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
-            })?;
+    Ok(kept)
+}
+
+/// Build a DSON operation from an `apply()` dict
+fn parse_operation(py: Python<'_>, op: &Bound<'_, PyAny>) -> PyResult<DsonOperation> {
+    let dict = op.cast::<PyDict>().map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyTypeError, _>("apply() operations must be dicts")
+    })?;
+    let field = |name: &str| -> PyResult<Bound<'_, PyAny>> {
+        dict.get_item(name)?.ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "apply() operation is missing '{name}'"
+            ))
+        })
+    };
+    let value = || -> PyResult<OperationValue> {
+        let value = py_to_json(py, &field("value")?, None, &DumpOptions::default())?;
+        scalar_operation_value(value)
+    };
+    let index = || -> PyResult<usize> { field("index")?.extract() };
+
+    let kind: String = field("op")?.extract()?;
+    let path: String = field("path")?.extract()?;
+    Ok(match kind.as_str() {
+        "field_add" => DsonOperation::FieldAdd {
+            path,
+            value: value()?,
+        },
+        "field_modify" => DsonOperation::FieldModify {
+            path,
+            value: value()?,
+        },
+        "field_delete" => DsonOperation::FieldDelete { path },
+        "array_insert" => DsonOperation::ArrayInsert {
+            path,
+            index: index()?,
+            value: value()?,
+        },
+        "array_remove" => DsonOperation::ArrayRemove {
+            path,
+            index: index()?,
+        },
+        "array_replace" => DsonOperation::ArrayReplace {
+            path,
+            index: index()?,
+            value: value()?,
+        },
+        other => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown apply() operation: {other}"
+            )));
         }
+    })
+}
 
-        writer.flush().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Flush error: {e}"))
-        })?;
+/// Operation values are scalars; containers only exist as tape references
+fn scalar_operation_value(value: Value) -> PyResult<OperationValue> {
+    match value {
+        Value::Null => Ok(OperationValue::Null),
+        Value::Bool(b) => Ok(OperationValue::BoolRef(b)),
+        Value::Number(n) => Ok(OperationValue::NumberRef((&n).into())),
+        Value::String(s) => Ok(OperationValue::StringRef(s)),
+        Value::Array(_) | Value::Object(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "apply() values must be scalars; use map() for structured values",
+        )),
+    }
+}
 
-        Ok(())
+// =============================================================================
+// Record Formats
+// =============================================================================
+
+/// Record encodings a pipeline reads or writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecordFormat {
+    Jsonl,
+    Isonl,
+    Json,
+    Yaml,
+}
+
+impl RecordFormat {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "jsonl" => Some(Self::Jsonl),
+            "isonl" => Some(Self::Isonl),
+            "json" => Some(Self::Json),
+            "yaml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Decode one non-empty line; `None` if the line is skipped
+fn decode_line(
+    line: &str,
+    format: RecordFormat,
+    prefilter: Option<&Prefilter>,
+) -> PyResult<Option<Value>> {
+    let trimmed = line.trim();
+    if format == RecordFormat::Isonl {
+        return Ok(parse_isonl(trimmed));
+    }
+    if prefilter.is_some_and(|prefilter| prefilter.check(trimmed.as_bytes()) == Verdict::Reject) {
+        return Ok(None);
+    }
+
+    let mut bytes = trimmed.as_bytes().to_vec();
+    simd_json::from_slice(&mut bytes)
+        .map(Some)
+        .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))
+}
+
+/// Parse an ISONL line: `table.name|field1:type|field2:type|val1|val2`
+fn parse_isonl(line: &str) -> Option<Value> {
+    let parts: Vec<&str> = line.split('|').collect();
+    if parts.len() < 3 {
+        return None;
+    }
+
+    // Find schema/data boundary (where type annotations end)
+    let schema_end = parts
+        .iter()
+        .skip(1)
+        .position(|part| !part.contains(':'))
+        .map_or(parts.len(), |at| at + 1);
+    let values = &parts[schema_end..];
+
+    let mut record = Map::new();
+    for (field, raw) in parts[1..schema_end].iter().zip(values) {
+        let Some((name, typ)) = field.split_once(':') else {
+            continue;
+        };
+        let value = match typ {
+            "int" | "i64" | "i32" => raw.parse::<i64>().map_or(Value::Null, Value::from),
+            "float" | "f64" | "f32" => raw.parse::<f64>().map_or(Value::Null, Value::from),
+            "bool" => Value::Bool(*raw == "true" || *raw == "1"),
+            _ => Value::String((*raw).to_string()),
+        };
+        record.insert(name.to_string(), value);
+    }
+    Some(Value::Object(record))
+}
+
+fn parse_json_records(mut content: Vec<u8>) -> PyResult<Vec<Value>> {
+    let value: Value = simd_json::from_slice(&mut content)
+        .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Invalid JSON: {e}")))?;
+
+    // If it's an array, return elements; otherwise wrap in vec
+    Ok(match value {
+        Value::Array(items) => items,
+        other => vec![other],
+    })
+}
+
+/// ISONL type name of a value
+fn isonl_type(value: &Value) -> &'static str {
+    match value {
+        Value::Bool(_) => "bool",
+        Value::Number(n) if n.is_f64() => "float",
+        Value::Number(_) => "int",
+        _ => "string",
     }
+}
+
+/// Streaming writer for processed records
+struct RecordWriter {
+    format: RecordFormat,
+    writer: BufWriter<File>,
+    /// Fields kept in the output, if restricted
+    output_schema: Option<Vec<String>>,
+    /// ISONL table name, adopted from ISONL input
+    isonl_table: Option<String>,
+    /// ISONL field schema, fixed by the first record
+    isonl_fields: Option<Vec<(String, &'static str)>>,
+    /// JSON output is one document, written on finish
+    json_records: Vec<Value>,
+    count: u64,
+}
 
-    fn write_json(&self, py: Python<'_>, path: &str, records: &[Py<PyAny>]) -> PyResult<()> {
+impl RecordWriter {
+    fn create(
+        path: &str,
+        format: RecordFormat,
+        output_schema: Option<Vec<String>>,
+    ) -> PyResult<Self> {
         let file = File::create(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create file: {e}"))
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot create output file: {e}"))
         })?;
-        let mut writer = BufWriter::new(file);
-        let opts = DumpOptions::default();
+        Ok(Self {
+            format,
+            writer: BufWriter::with_capacity(64 * 1024, file),
+            output_schema,
+            isonl_table: None,
+            isonl_fields: None,
+            json_records: Vec::new(),
+            count: 0,
+        })
+    }
 
-        // Convert all records to JSON values
-        let values: Vec<serde_json::Value> = records
-            .iter()
-            .map(|r| py_to_json(py, r.bind(py), None, &opts))
-            .collect::<PyResult<Vec<_>>>()?;
+    /// Keep the table name of the first ISONL input line
+    fn adopt_isonl_table(&mut self, line: &str) {
+        if self.isonl_table.is_none() {
+            self.isonl_table = line.split('|').next().map(str::to_string);
+        }
+    }
 
-        // Write as JSON array
-        let json_str = serde_json::to_string_pretty(&values).map_err(|e| {
-            PyErr::new::<crate::exceptions::JSONEncodeError, _>(format!("Serialization error: {e}"))
-        })?;
+    fn write_all(&mut self, records: &[Value]) -> PyResult<()> {
+        for record in records {
+            let record = match &self.output_schema {
+                Some(fields) => project(record, fields),
+                None => record.clone(),
+            };
+            self.write(record)?;
+        }
+        Ok(())
+    }
 
-        writer.write_all(json_str.as_bytes()).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
-        })?;
+    fn write(&mut self, record: Value) -> PyResult<()> {
+        self.count += 1;
+        let mut line = match self.format {
+            RecordFormat::Json => {
+                self.json_records.push(record);
+                return Ok(());
+            }
+            RecordFormat::Jsonl => record.to_string(),
+            RecordFormat::Yaml => {
+                let yaml = serde_yaml::to_string(&record).map_err(|e| {
+                    PyErr::new::<crate::exceptions::JSONEncodeError, _>(format!(
+                        "Serialization error: {e}"
+                    ))
+                })?;
+                format!("---\n{}", yaml.trim_end())
+            }
+            RecordFormat::Isonl => self.isonl_line(&record),
+        };
+        line.push('\n');
+        self.writer
+            .write_all(line.as_bytes())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}")))
+    }
+
+    fn isonl_line(&mut self, record: &Value) -> String {
+        let fields = self.isonl_fields.get_or_insert_with(|| {
+            let names: Vec<String> = match (&self.output_schema, record.as_object()) {
+                (Some(fields), _) => fields.clone(),
+                (None, Some(object)) => object.keys().cloned().collect(),
+                (None, None) => Vec::new(),
+            };
+            names
+                .into_iter()
+                .map(|name| {
+                    let typ = record.get(name.as_str()).map_or("string", isonl_type);
+                    (name, typ)
+                })
+                .collect()
+        });
+
+        let mut parts = vec![
+            self.isonl_table
+                .clone()
+                .unwrap_or_else(|| "table.data".to_string()),
+        ];
+        for (name, typ) in fields.iter() {
+            parts.push(format!("{name}:{typ}"));
+        }
+        for (name, _) in fields.iter() {
+            parts.push(match record.get(name.as_str()) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+            });
+        }
+        parts.join("|")
+    }
+
+    fn finish(mut self) -> PyResult<u64> {
+        if self.format == RecordFormat::Json {
+            let json_str = serde_json::to_string_pretty(&self.json_records).map_err(|e| {
+                PyErr::new::<crate::exceptions::JSONEncodeError, _>(format!(
+                    "Serialization error: {e}"
+                ))
+            })?;
+            self.writer.write_all(json_str.as_bytes()).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Write error: {e}"))
+            })?;
+        }
 
-        writer.flush().map_err(|e| {
+        self.writer.flush().map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Flush error: {e}"))
         })?;
 
-        Ok(())
+        Ok(self.count)
+    }
+}
+
+/// Keep only the listed top-level fields
+fn project(record: &Value, fields: &[String]) -> Value {
+    let mut projected = Map::new();
+    if let Value::Object(source) = record {
+        for field in fields {
+            if let Some(value) = source.get(field) {
+                projected.insert(field.clone(), value.clone());
+            }
+        }
     }
+    Value::Object(projected)
 }
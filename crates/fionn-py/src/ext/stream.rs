@@ -0,0 +1,683 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Incremental record streams over files and file-like objects
+//!
+//! `open_stream` reads JSONL, ISONL, CSV or multi-document YAML in chunks from
+//! a path or any object with `.read()` (gzip files, `socket.makefile()`,
+//! `asyncio.StreamReader`, ...). Lines are decoded and field-filtered in Rust
+//! with the GIL released, and a bad record is reported in `errors` instead of
+//! ending the stream.
+
+use fionn_core::CompiledSchema;
+use fionn_simd::formats::{CsvDialect, CsvRecordScanner};
+use pyo3::exceptions::{PyStopAsyncIteration, PyStopIteration};
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyString};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use super::formats::{csv_dialect, csv_row_cell};
+use super::isonl::{IsonlValue, parse_isonl_line};
+use super::pipeline::select_fields;
+use crate::exceptions::JSONDecodeError;
+use crate::types::json_to_py;
+
+/// Bytes requested from the source per read.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Record formats `open_stream` understands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StreamFormat {
+    Jsonl,
+    Isonl,
+    Csv,
+    YamlMulti,
+}
+
+impl StreamFormat {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            "isonl" => Ok(Self::Isonl),
+            "csv" => Ok(Self::Csv),
+            "yaml-multi" => Ok(Self::YamlMulti),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown stream format: {name} (expected jsonl, isonl, csv or yaml-multi)"
+            ))),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Isonl => "isonl",
+            Self::Csv => "csv",
+            Self::YamlMulti => "yaml-multi",
+        }
+    }
+}
+
+/// A record that failed to decode.
+struct RecordError {
+    /// 1-based line the record starts on
+    line: usize,
+    message: String,
+    text: String,
+}
+
+enum Decoded {
+    Record(serde_json::Value),
+    Error(RecordError),
+}
+
+/// Push-based decoder: bytes in, records out.
+///
+/// Holds no Python state, so feeding runs with the GIL released.
+struct RecordDecoder {
+    format: StreamFormat,
+    fields: Option<CompiledSchema>,
+    /// Bytes of a line not yet terminated by `\n`
+    partial: Vec<u8>,
+    line: usize,
+    csv_dialect: CsvDialect,
+    /// Finds CSV record ends, keeping quoted newlines inside their record
+    csv_scanner: CsvRecordScanner,
+    /// Record ends found in the current chunk
+    csv_ends: Vec<usize>,
+    /// Absolute offset of the next byte fed to `csv_scanner`
+    csv_offset: usize,
+    csv_header: Option<Vec<String>>,
+    yaml_doc: String,
+    yaml_start: usize,
+    decoded: VecDeque<Decoded>,
+}
+
+impl RecordDecoder {
+    const fn new(format: StreamFormat, fields: Option<CompiledSchema>) -> Self {
+        let csv_dialect = csv_dialect();
+        Self {
+            format,
+            fields,
+            partial: Vec::new(),
+            line: 0,
+            csv_scanner: CsvRecordScanner::new(&csv_dialect),
+            csv_dialect,
+            csv_ends: Vec::new(),
+            csv_offset: 0,
+            csv_header: None,
+            yaml_doc: String::new(),
+            yaml_start: 0,
+            decoded: VecDeque::new(),
+        }
+    }
+
+    fn feed(&mut self, mut data: &[u8]) {
+        if self.format == StreamFormat::Csv {
+            self.feed_csv(data);
+            return;
+        }
+        while let Some(pos) = memchr::memchr(b'\n', data) {
+            self.complete_line(&data[..pos]);
+            data = &data[pos + 1..];
+        }
+        self.partial.extend_from_slice(data);
+    }
+
+    /// Split CSV on record ends rather than newlines, so a quoted field may
+    /// span lines and chunks.
+    fn feed_csv(&mut self, data: &[u8]) {
+        let mut ends = std::mem::take(&mut self.csv_ends);
+        ends.clear();
+        self.csv_scanner.scan(data, &mut ends);
+        let mut start = 0;
+        for &end in &ends {
+            // A `\r` ending the previous chunk ends a record at offset 0; it
+            // is still in `partial`, where `decode_line` strips it
+            let end = end - self.csv_offset;
+            self.complete_line(&data[start..end.saturating_sub(1)]);
+            start = end;
+        }
+        self.partial.extend_from_slice(&data[start..]);
+        self.csv_offset += data.len();
+        self.csv_ends = ends;
+    }
+
+    /// Decode the buffered partial line, ended by `tail`.
+    fn complete_line(&mut self, tail: &[u8]) {
+        if self.partial.is_empty() {
+            self.decode_line(tail);
+        } else {
+            let mut line = std::mem::take(&mut self.partial);
+            line.extend_from_slice(tail);
+            self.decode_line(&line);
+            line.clear();
+            self.partial = line;
+        }
+    }
+
+    /// Decode whatever is left once the source is exhausted.
+    fn finish(&mut self) {
+        if !self.partial.is_empty() {
+            let line = std::mem::take(&mut self.partial);
+            self.decode_line(&line);
+        }
+        if self.format == StreamFormat::YamlMulti {
+            self.flush_yaml();
+        }
+    }
+
+    fn decode_line(&mut self, raw: &[u8]) {
+        self.line += 1;
+        let raw = raw.strip_suffix(b"\r").unwrap_or(raw);
+        let text = match std::str::from_utf8(raw) {
+            Ok(text) => text,
+            Err(e) => {
+                let text = String::from_utf8_lossy(raw).into_owned();
+                self.error(self.line, format!("Invalid UTF-8: {e}"), text);
+                return;
+            }
+        };
+
+        if self.format == StreamFormat::YamlMulti {
+            self.decode_yaml_line(text);
+            return;
+        }
+
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return;
+        }
+
+        match self.format {
+            StreamFormat::Jsonl => {
+                let mut bytes = trimmed.as_bytes().to_vec();
+                match simd_json::from_slice::<serde_json::Value>(&mut bytes) {
+                    Ok(value) => self.push(value),
+                    Err(e) => self.error(self.line, format!("Invalid JSON: {e}"), text.into()),
+                }
+            }
+            StreamFormat::Isonl => match parse_isonl_line(trimmed.as_bytes(), None) {
+                Ok(record) => self.push(serde_json::Value::Object(
+                    record
+                        .into_iter()
+                        .map(|(key, value)| (key, isonl_to_json(value)))
+                        .collect(),
+                )),
+                Err(e) => self.error(self.line, format!("Invalid ISONL: {e}"), text.into()),
+            },
+            StreamFormat::Csv => {
+                let cells = self.csv_dialect.parse_record(trimmed.as_bytes());
+                if let Some(header) = &self.csv_header {
+                    let row = header
+                        .iter()
+                        .enumerate()
+                        .map(|(i, name)| (name.clone(), csv_row_cell(&cells, i)))
+                        .collect();
+                    self.push(serde_json::Value::Object(row));
+                } else {
+                    self.csv_header = Some(cells.into_iter().map(|cell| cell.value).collect());
+                }
+                // Later records start below any newlines quoted in this one
+                self.line += memchr::memchr_iter(b'\n', raw).count();
+            }
+            StreamFormat::YamlMulti => unreachable!("handled above"),
+        }
+    }
+
+    /// Accumulate YAML lines, splitting documents on `---` and `...`.
+    fn decode_yaml_line(&mut self, text: &str) {
+        let marker = text.trim_end();
+        if marker == "..." {
+            self.flush_yaml();
+        } else if marker == "---" || marker.starts_with("--- ") {
+            self.flush_yaml();
+            if let Some(rest) = marker.strip_prefix("--- ") {
+                self.yaml_start = self.line;
+                self.yaml_doc.push_str(rest);
+                self.yaml_doc.push('\n');
+            }
+        } else {
+            if self.yaml_doc.is_empty() {
+                self.yaml_start = self.line;
+            }
+            self.yaml_doc.push_str(text);
+            self.yaml_doc.push('\n');
+        }
+    }
+
+    fn flush_yaml(&mut self) {
+        let doc = std::mem::take(&mut self.yaml_doc);
+        let blank = doc.lines().all(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with('#')
+        });
+        if blank {
+            return;
+        }
+        match serde_yaml::from_str::<serde_json::Value>(&doc) {
+            Ok(value) => self.push(value),
+            Err(e) => self.error(self.yaml_start, format!("Invalid YAML: {e}"), doc),
+        }
+    }
+
+    fn push(&mut self, mut value: serde_json::Value) {
+        if let (Some(schema), serde_json::Value::Object(map)) = (&self.fields, &mut value) {
+            *map = select_fields(std::mem::take(map), schema, "");
+        }
+        self.decoded.push_back(Decoded::Record(value));
+    }
+
+    fn error(&mut self, line: usize, message: String, text: String) {
+        self.decoded.push_back(Decoded::Error(RecordError {
+            line,
+            message,
+            text,
+        }));
+    }
+}
+
+fn isonl_to_json(value: IsonlValue) -> serde_json::Value {
+    match value {
+        IsonlValue::Int(i) => i.into(),
+        IsonlValue::Float(f) => {
+            serde_json::Number::from_f64(f).map_or(serde_json::Value::Null, Into::into)
+        }
+        IsonlValue::String(s) => s.into(),
+        IsonlValue::Bool(b) => b.into(),
+    }
+}
+
+/// Where a stream's bytes come from.
+enum Source {
+    /// File opened from a path, read without the GIL
+    File(File),
+    /// Python object read through `method` (`read` or `recv`)
+    Object {
+        obj: Py<PyAny>,
+        method: &'static str,
+    },
+}
+
+/// Incremental reader of records from a file or file-like object.
+///
+/// Created by `open_stream`. Iterate it with `for` (or `async for` when the
+/// source's `read()` is a coroutine) to get one record at a time, or lists of
+/// up to `batch_size` records.
+///
+/// Records that fail to decode are skipped and described in `errors`
+/// unless the stream was opened with `strict=True`.
+#[pyclass(unsendable, module = "fionn.ext")]
+pub struct RecordStream {
+    source: Source,
+    decoder: RecordDecoder,
+    buffer: Vec<u8>,
+    batch_size: Option<usize>,
+    batch: Vec<serde_json::Value>,
+    strict: bool,
+    errors: Py<PyList>,
+    exhausted: bool,
+}
+
+impl RecordStream {
+    /// Read and decode one chunk.
+    ///
+    /// Returns the awaitable instead when the source's read is a coroutine;
+    /// its result goes to `feed_object` once awaited.
+    fn read_chunk<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        match &mut self.source {
+            Source::File(file) => {
+                let Self {
+                    decoder, buffer, ..
+                } = self;
+                buffer.resize(CHUNK_SIZE, 0);
+                let read = py
+                    .detach(|| {
+                        let n = file.read(buffer)?;
+                        if n == 0 {
+                            decoder.finish();
+                        } else {
+                            decoder.feed(&buffer[..n]);
+                        }
+                        Ok::<_, std::io::Error>(n)
+                    })
+                    .map_err(|e| {
+                        PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Read error: {e}"))
+                    })?;
+                self.exhausted = read == 0;
+                Ok(None)
+            }
+            Source::Object { obj, method } => {
+                let result = obj.bind(py).call_method1(*method, (CHUNK_SIZE,))?;
+                if result.hasattr("__await__")? {
+                    return Ok(Some(result));
+                }
+                self.feed_object(py, &result)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Decode a chunk returned by the source; an empty chunk means EOF.
+    fn feed_object(&mut self, py: Python<'_>, data: &Bound<'_, PyAny>) -> PyResult<()> {
+        let bytes = if let Ok(bytes) = data.cast::<PyBytes>() {
+            bytes.as_bytes().to_vec()
+        } else if let Ok(bytes) = data.cast::<PyByteArray>() {
+            bytes.to_vec()
+        } else if let Ok(text) = data.cast::<PyString>() {
+            text.to_cow()?.into_owned().into_bytes()
+        } else {
+            return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                "read() must return bytes or str, not {}",
+                data.get_type().name()?
+            )));
+        };
+
+        let decoder = &mut self.decoder;
+        if bytes.is_empty() {
+            decoder.finish();
+            self.exhausted = true;
+        } else {
+            py.detach(|| decoder.feed(&bytes));
+        }
+        Ok(())
+    }
+
+    fn next_record(&mut self, py: Python<'_>) -> PyResult<Option<serde_json::Value>> {
+        while let Some(decoded) = self.decoder.decoded.pop_front() {
+            match decoded {
+                Decoded::Record(value) => return Ok(Some(value)),
+                Decoded::Error(error) => self.report(py, error)?,
+            }
+        }
+        Ok(None)
+    }
+
+    fn report(&self, py: Python<'_>, error: RecordError) -> PyResult<()> {
+        if self.strict {
+            return Err(PyErr::new::<JSONDecodeError, _>(format!(
+                "line {}: {}",
+                error.line, error.message
+            )));
+        }
+        let entry = PyDict::new(py);
+        entry.set_item("line", error.line)?;
+        entry.set_item("message", error.message)?;
+        entry.set_item("text", error.text)?;
+        self.errors.bind(py).append(entry)
+    }
+
+    /// Next record or batch, if enough has been decoded to produce one.
+    fn take_ready(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let Some(size) = self.batch_size else {
+            return self
+                .next_record(py)?
+                .map(|value| json_to_py(py, &value))
+                .transpose();
+        };
+
+        while self.batch.len() < size {
+            match self.next_record(py)? {
+                Some(value) => self.batch.push(value),
+                None => break,
+            }
+        }
+        // A short batch only goes out once the source is exhausted
+        let full = self.batch.len() == size || self.exhausted;
+        if !full || self.batch.is_empty() {
+            return Ok(None);
+        }
+
+        let list = PyList::empty(py);
+        for value in self.batch.drain(..) {
+            list.append(json_to_py(py, &value)?)?;
+        }
+        Ok(Some(list.into_any().unbind()))
+    }
+}
+
+#[pymethods]
+impl RecordStream {
+    /// Records that failed to decode, as dicts with `line`, `message` and
+    /// `text` keys. Grows as the stream is consumed.
+    #[getter]
+    fn errors(&self, py: Python<'_>) -> Py<PyList> {
+        self.errors.clone_ref(py)
+    }
+
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        loop {
+            if let Some(item) = self.take_ready(py)? {
+                return Ok(Some(item));
+            }
+            if self.exhausted {
+                return Ok(None);
+            }
+            if let Some(pending) = self.read_chunk(py)? {
+                if pending.hasattr("close")? {
+                    pending.call_method0("close")?;
+                }
+                return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                    "read() returned an awaitable; iterate with `async for`",
+                ));
+            }
+        }
+    }
+
+    const fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(slf: PyRef<'_, Self>) -> NextRecord {
+        NextRecord {
+            stream: slf.into(),
+            awaiting: None,
+        }
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> String {
+        format!(
+            "RecordStream(format={}, errors={})",
+            self.decoder.format.name(),
+            self.errors.bind(py).len()
+        )
+    }
+}
+
+/// Awaitable returned by `RecordStream.__anext__`.
+///
+/// Behaves like a hand-written coroutine: it delegates to the source's pending
+/// `read()` awaitable, passing whatever that yields through to the event loop,
+/// and finishes with the next record or batch once one is decoded.
+#[pyclass(unsendable, module = "fionn.ext")]
+pub struct NextRecord {
+    stream: Py<RecordStream>,
+    /// Iterator of the `read()` awaitable currently in flight
+    awaiting: Option<Py<PyAny>>,
+}
+
+impl NextRecord {
+    fn drive<'py>(
+        &mut self,
+        py: Python<'py>,
+        mut resumed: Option<PyResult<Bound<'py, PyAny>>>,
+    ) -> PyResult<Py<PyAny>> {
+        loop {
+            if let Some(result) = resumed.take() {
+                match result {
+                    // The read is still waiting on the event loop
+                    Ok(yielded) => return Ok(yielded.unbind()),
+                    Err(err) => {
+                        self.awaiting = None;
+                        if !err.is_instance_of::<PyStopIteration>(py) {
+                            return Err(err);
+                        }
+                        let data = err.value(py).getattr("value")?;
+                        self.stream.borrow_mut(py).feed_object(py, &data)?;
+                    }
+                }
+            }
+
+            let mut stream = self.stream.borrow_mut(py);
+            if let Some(item) = stream.take_ready(py)? {
+                return Err(PyStopIteration::new_err((item,)));
+            }
+            if stream.exhausted {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            if let Some(pending) = stream.read_chunk(py)? {
+                drop(stream);
+                let iter = pending.call_method0("__await__")?;
+                resumed = Some(iter.call_method1("send", (py.None(),)));
+                self.awaiting = Some(iter.unbind());
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl NextRecord {
+    const fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.send(py, py.None())
+    }
+
+    fn send(&mut self, py: Python<'_>, value: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let resumed = self
+            .awaiting
+            .as_ref()
+            .map(|iter| iter.bind(py).call_method1("send", (value,)));
+        self.drive(py, resumed)
+    }
+
+    #[pyo3(signature = (exc, _value=None, _traceback=None))]
+    fn throw(
+        &mut self,
+        py: Python<'_>,
+        exc: Bound<'_, PyAny>,
+        _value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        match &self.awaiting {
+            Some(iter) => {
+                let resumed = iter.bind(py).call_method1("throw", (exc,));
+                self.drive(py, Some(resumed))
+            }
+            None => Err(PyErr::from_value(exc)),
+        }
+    }
+
+    fn close(&mut self, py: Python<'_>) -> PyResult<()> {
+        if let Some(iter) = self.awaiting.take() {
+            let iter = iter.bind(py);
+            if iter.hasattr("close")? {
+                iter.call_method0("close")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Open an incremental record stream over a path or file-like object.
+///
+/// Reads the source in chunks, so memory stays flat however large the input
+/// is. Decoding and `fields` filtering happen in Rust with the GIL released.
+///
+/// # Arguments
+/// * `source` - Path, or any object with `read(n)` (or `recv(n)`) returning
+///   bytes or str: open files, `gzip.open(...)`, `socket.makefile("rb")`,
+///   `asyncio.StreamReader` (iterate with `async for`)
+/// * `format` - "jsonl", "isonl", "csv" (first line is the header) or
+///   "yaml-multi" (documents separated by `---`)
+/// * `fields` - Optional field paths to keep, as in `Pipeline.select`
+///   (`id`, `user.name`, `user.*`)
+/// * `batch_size` - Yield lists of up to this many records instead of single records
+/// * `strict` - Raise `JSONDecodeError` on the first bad record instead of
+///   recording it in `errors`
+///
+/// # Examples
+/// ```python
+/// import gzip
+/// import fionn.ext as fx
+///
+/// with gzip.open("events.jsonl.gz", "rb") as f:
+///     stream = fx.open_stream(f, fields=["id", "type"], batch_size=1000)
+///     for batch in stream:
+///         process(batch)
+/// print(stream.errors)  # [{"line": 17, "message": "Invalid JSON: ...", "text": ...}]
+///
+/// reader, _ = await asyncio.open_connection(host, port)
+/// async for record in fx.open_stream(reader):
+///     handle(record)
+/// ```
+#[pyfunction]
+#[pyo3(signature = (source, format="jsonl", fields=None, batch_size=None, strict=false))]
+pub fn open_stream(
+    py: Python<'_>,
+    source: &Bound<'_, PyAny>,
+    format: &str,
+    fields: Option<Vec<String>>,
+    batch_size: Option<usize>,
+    strict: bool,
+) -> PyResult<RecordStream> {
+    let format = StreamFormat::parse(format)?;
+    let fields = fields
+        .map(|fields| CompiledSchema::compile(&fields))
+        .transpose()
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid field path: {e}"))
+        })?;
+    if batch_size == Some(0) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "batch_size must be at least 1",
+        ));
+    }
+
+    let source = if source.hasattr("read")? {
+        Source::Object {
+            obj: source.clone().unbind(),
+            method: "read",
+        }
+    } else if source.hasattr("recv")? {
+        Source::Object {
+            obj: source.clone().unbind(),
+            method: "recv",
+        }
+    } else if let Ok(path) = source.extract::<PathBuf>() {
+        let file = File::open(&path).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Cannot open file: {e}"))
+        })?;
+        Source::File(file)
+    } else {
+        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "source must be a path or an object with read()",
+        ));
+    };
+
+    Ok(RecordStream {
+        source,
+        decoder: RecordDecoder::new(format, fields),
+        buffer: Vec::new(),
+        batch_size,
+        batch: Vec::new(),
+        strict,
+        errors: PyList::empty(py).unbind(),
+        exhausted: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    // Python-side tests in tests/test_stream.py
+}
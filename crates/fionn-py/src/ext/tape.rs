@@ -12,6 +12,7 @@ use fionn_tape::{DsonTape, SimdValue};
 use simd_json::value::tape::Node;
 use std::sync::Arc;
 
+use super::tape_view;
 use crate::exceptions::JSONDecodeError;
 
 /// Schema for filtered parsing.
@@ -55,7 +56,7 @@ pub enum TapeInner {
 }
 
 impl TapeInner {
-    fn nodes(&self) -> &[Node<'static>] {
+    pub(super) fn nodes(&self) -> &[Node<'static>] {
         match self {
             Self::Owned(inner) => inner.nodes(),
             Self::Pooled(inner, _) => inner.nodes(),
@@ -83,7 +84,7 @@ impl TapeInner {
         }
     }
 
-    fn skip_value(&self, index: usize) -> fionn_core::Result<usize> {
+    pub(super) fn skip_value(&self, index: usize) -> fionn_core::Result<usize> {
         match self {
             Self::Owned(inner) => inner.skip_value(index),
             Self::Pooled(inner, _) => inner.skip_value(index),
@@ -99,9 +100,17 @@ impl TapeInner {
 }
 
 impl Tape {
-    const fn get_inner(&self) -> &TapeInner {
+    pub(super) const fn get_inner(&self) -> &TapeInner {
         self.inner.as_ref().expect("Tape has been dropped")
     }
+
+    /// Materialize the value at `index` (and everything below it).
+    pub(super) fn node_to_py(&self, py: Python<'_>, index: usize) -> PyResult<Py<PyAny>> {
+        match self.get_inner() {
+            TapeInner::Owned(inner) => tape_node_to_py(py, inner, index),
+            TapeInner::Pooled(inner, _) => tape_node_to_py(py, inner, index),
+        }
+    }
 }
 
 impl Drop for Tape {
@@ -195,10 +204,12 @@ impl Tape {
             Some(SimdValue::Null) => Ok(py.None()),
             Some(SimdValue::Bool(b)) => Ok(b.into_pyobject(py)?.to_owned().unbind().into_any()),
             Some(SimdValue::Number(n)) => {
-                // Try to parse as int first, then float
-                if let Ok(i) = n.parse::<i64>() {
+                // Integers keep their exact value, everything else is a float
+                if let Some(i) = n.as_i64() {
                     Ok(i.into_pyobject(py)?.unbind().into_any())
-                } else if let Ok(f) = n.parse::<f64>() {
+                } else if let Some(u) = n.as_u64() {
+                    Ok(u.into_pyobject(py)?.unbind().into_any())
+                } else if let Some(f) = n.as_f64() {
                     Ok(PyFloat::new(py, f).unbind().into_any())
                 } else {
                     // Return as string if can't parse
@@ -232,11 +243,47 @@ impl Tape {
     /// Note: This uses direct tape-to-Python conversion, bypassing JSON
     /// string serialization and simd-json intermediary for better performance.
     fn to_object(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let inner = self.get_inner();
-        match inner {
-            TapeInner::Owned(inner) => tape_node_to_py(py, inner, 0),
-            TapeInner::Pooled(inner, _) => tape_node_to_py(py, inner, 0),
-        }
+        self.node_to_py(py, 0)
+    }
+
+    /// Materialize the subtree at a path.
+    ///
+    /// Unlike `get()`, containers come back as full dicts/lists, and a
+    /// missing path raises instead of returning None.
+    ///
+    /// Args:
+    ///     path: JSON path (e.g., "users[0]"); None converts the whole document
+    ///
+    /// Returns:
+    ///     Python value at the path
+    ///
+    /// Raises:
+    ///     `KeyError`: If the path does not exist
+    #[pyo3(signature = (path=None))]
+    fn to_python(&self, py: Python<'_>, path: Option<&str>) -> PyResult<Py<PyAny>> {
+        let index = match path {
+            Some(path) => self
+                .resolve_path(path)?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>(path.to_string()))?,
+            None => 0,
+        };
+        self.node_to_py(py, index)
+    }
+
+    /// Lazy view over the document.
+    ///
+    /// Objects come back as read-only `Mapping` proxies and arrays as
+    /// `Sequence` proxies that walk the tape on access; only scalars that
+    /// are actually read get converted to Python objects. A scalar root is
+    /// returned as its plain value.
+    ///
+    /// Example:
+    /// ```python
+    /// root = tape.view()
+    /// root["users"][0]["name"]  # "Alice", without building the users list
+    /// ```
+    fn view(slf: &Bound<'_, Self>) -> PyResult<Py<PyAny>> {
+        tape_view::view_value(slf.py(), slf.as_unbound(), 0)
     }
 
     /// Convert tape back to JSON string.
@@ -0,0 +1,342 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Lazy views over a `Tape`
+//!
+//! `TapeMapping` and `TapeSequence` hold a reference to their tape plus the
+//! index of a container node. Every access walks the tape with the O(1)
+//! `skip_value`, so nothing below a node becomes a Python object until it is
+//! read. Nested containers come back as further views.
+
+use std::sync::OnceLock;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PySlice, PyString, PyTuple};
+use simd_json::value::tape::Node;
+
+use super::tape::Tape;
+use crate::exceptions::JSONDecodeError;
+
+/// Index of the node following the value at `index`.
+fn skip(tape: &Tape, index: usize) -> PyResult<usize> {
+    tape.get_inner()
+        .skip_value(index)
+        .map_err(|e| PyErr::new::<JSONDecodeError, _>(format!("Skip error: {e}")))
+}
+
+/// Wrap the value at `index`: containers become views, scalars are converted.
+fn wrap(py: Python<'_>, owner: &Py<Tape>, tape: &Tape, index: usize) -> PyResult<Py<PyAny>> {
+    match tape.get_inner().nodes().get(index) {
+        Some(Node::Object { len, .. }) => Ok(Py::new(
+            py,
+            TapeMapping {
+                tape: owner.clone_ref(py),
+                index,
+                len: *len,
+            },
+        )?
+        .into_any()),
+        Some(Node::Array { len, .. }) => Ok(Py::new(
+            py,
+            TapeSequence {
+                tape: owner.clone_ref(py),
+                index,
+                len: *len,
+                offsets: OnceLock::new(),
+            },
+        )?
+        .into_any()),
+        _ => tape.node_to_py(py, index),
+    }
+}
+
+/// View of the value at `index` in `tape`.
+pub(super) fn view_value(py: Python<'_>, tape: &Py<Tape>, index: usize) -> PyResult<Py<PyAny>> {
+    wrap(py, tape, &tape.borrow(py), index)
+}
+
+/// Register the views with `collections.abc` so `isinstance` checks pass.
+pub(super) fn register_abcs(py: Python<'_>) -> PyResult<()> {
+    let abc = py.import("collections.abc")?;
+    abc.getattr("Mapping")?
+        .call_method1("register", (py.get_type::<TapeMapping>(),))?;
+    abc.getattr("Sequence")?
+        .call_method1("register", (py.get_type::<TapeSequence>(),))?;
+    Ok(())
+}
+
+/// Read-only `Mapping` over a JSON object in a tape.
+///
+/// Key lookup scans the object's keys in document order without
+/// allocating; values are converted (or wrapped) only when read.
+#[pyclass(mapping, frozen, module = "fionn.ext")]
+pub struct TapeMapping {
+    tape: Py<Tape>,
+    index: usize,
+    len: usize,
+}
+
+impl TapeMapping {
+    /// Tape index of the value stored under `key`.
+    fn find(&self, tape: &Tape, key: &str) -> PyResult<Option<usize>> {
+        let nodes = tape.get_inner().nodes();
+        let mut current = self.index + 1;
+        for _ in 0..self.len {
+            let Some(Node::String(name)) = nodes.get(current) else {
+                break;
+            };
+            if *name == key {
+                return Ok(Some(current + 1));
+            }
+            current = skip(tape, current + 1)?;
+        }
+        Ok(None)
+    }
+
+    /// Iterator over this object's entries, yielding `kind` for each.
+    fn children(&self, py: Python<'_>, kind: IterKind) -> TapeIter {
+        TapeIter {
+            tape: self.tape.clone_ref(py),
+            cursor: self.index + 1,
+            remaining: self.len,
+            kind,
+        }
+    }
+
+    fn lookup(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<Option<Py<PyAny>>> {
+        let Ok(key) = key.cast::<PyString>() else {
+            return Ok(None);
+        };
+        let tape = self.tape.borrow(py);
+        self.find(&tape, &key.to_cow()?)?
+            .map(|index| wrap(py, &self.tape, &tape, index))
+            .transpose()
+    }
+}
+
+#[pymethods]
+impl TapeMapping {
+    const fn __len__(&self) -> usize {
+        self.len
+    }
+
+    fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.lookup(py, key)?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>(key.clone().unbind()))
+    }
+
+    fn __contains__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let Ok(key) = key.cast::<PyString>() else {
+            return Ok(false);
+        };
+        Ok(self.find(&self.tape.borrow(py), &key.to_cow()?)?.is_some())
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> TapeIter {
+        self.children(py, IterKind::Keys)
+    }
+
+    /// Value for `key`, or `default` if the key is missing.
+    #[pyo3(signature = (key, default=None))]
+    fn get(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        default: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        Ok(self
+            .lookup(py, key)?
+            .unwrap_or_else(|| default.unwrap_or_else(|| py.None())))
+    }
+
+    /// List of keys, in document order.
+    fn keys(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        self.children(py, IterKind::Keys).collect(py)
+    }
+
+    /// List of values; containers are returned as views.
+    fn values(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        self.children(py, IterKind::Values).collect(py)
+    }
+
+    /// List of `(key, value)` pairs; containers are returned as views.
+    fn items(&self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        self.children(py, IterKind::Items).collect(py)
+    }
+
+    /// Materialize this object as a dict.
+    fn to_python(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.tape.borrow(py).node_to_py(py, self.index)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TapeMapping(len={})", self.len)
+    }
+}
+
+/// Read-only `Sequence` over a JSON array in a tape.
+///
+/// The first indexed access records where each element starts, so later
+/// lookups (including negative indices and slices) are O(1).
+#[pyclass(sequence, frozen, module = "fionn.ext")]
+pub struct TapeSequence {
+    tape: Py<Tape>,
+    index: usize,
+    len: usize,
+    offsets: OnceLock<Vec<usize>>,
+}
+
+impl TapeSequence {
+    /// Tape index of every element, computed on first use.
+    fn offsets(&self, tape: &Tape) -> PyResult<&[usize]> {
+        if let Some(offsets) = self.offsets.get() {
+            return Ok(offsets);
+        }
+        let mut offsets = Vec::with_capacity(self.len);
+        let mut current = self.index + 1;
+        for _ in 0..self.len {
+            offsets.push(current);
+            current = skip(tape, current)?;
+        }
+        Ok(self.offsets.get_or_init(|| offsets))
+    }
+}
+
+#[pymethods]
+impl TapeSequence {
+    const fn __len__(&self) -> usize {
+        self.len
+    }
+
+    fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let tape = self.tape.borrow(py);
+        let offsets = self.offsets(&tape)?;
+
+        if let Ok(slice) = key.cast::<PySlice>() {
+            let len = isize::try_from(self.len)?;
+            let indices = slice.indices(len)?;
+            let list = PyList::empty(py);
+            let mut position = indices.start;
+            for _ in 0..indices.slicelength {
+                let offset = offsets[usize::try_from(position)?];
+                list.append(wrap(py, &self.tape, &tape, offset)?)?;
+                position += indices.step;
+            }
+            return Ok(list.into_any().unbind());
+        }
+
+        let position: isize = key.extract()?;
+        let resolved = if position < 0 {
+            self.len.checked_sub(position.unsigned_abs())
+        } else {
+            Some(position.unsigned_abs())
+        };
+        match resolved.and_then(|i| offsets.get(i)) {
+            Some(&offset) => wrap(py, &self.tape, &tape, offset),
+            None => Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(
+                "TapeSequence index out of range",
+            )),
+        }
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> TapeIter {
+        TapeIter {
+            tape: self.tape.clone_ref(py),
+            cursor: self.index + 1,
+            remaining: self.len,
+            kind: IterKind::Elements,
+        }
+    }
+
+    /// Materialize this array as a list.
+    fn to_python(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.tape.borrow(py).node_to_py(py, self.index)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TapeSequence(len={})", self.len)
+    }
+}
+
+/// What a `TapeIter` yields for each step.
+#[derive(Clone, Copy)]
+enum IterKind {
+    /// Object keys
+    Keys,
+    /// Object values
+    Values,
+    /// Object `(key, value)` tuples
+    Items,
+    /// Array elements
+    Elements,
+}
+
+/// Iterator over the children of a tape container.
+#[pyclass(module = "fionn.ext")]
+pub struct TapeIter {
+    tape: Py<Tape>,
+    cursor: usize,
+    remaining: usize,
+    kind: IterKind,
+}
+
+impl TapeIter {
+    fn advance(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let tape = self.tape.borrow(py);
+
+        let item = if matches!(self.kind, IterKind::Elements) {
+            let value = wrap(py, &self.tape, &tape, self.cursor)?;
+            self.cursor = skip(&tape, self.cursor)?;
+            value
+        } else {
+            let Some(Node::String(key)) = tape.get_inner().nodes().get(self.cursor) else {
+                self.remaining = 0;
+                return Ok(None);
+            };
+            let value_index = self.cursor + 1;
+            self.cursor = skip(&tape, value_index)?;
+            match self.kind {
+                IterKind::Keys => PyString::new(py, key).into_any().unbind(),
+                IterKind::Values => wrap(py, &self.tape, &tape, value_index)?,
+                _ => {
+                    let value = wrap(py, &self.tape, &tape, value_index)?;
+                    PyTuple::new(py, [PyString::new(py, key).into_any().unbind(), value])?
+                        .into_any()
+                        .unbind()
+                }
+            }
+        };
+
+        self.remaining -= 1;
+        Ok(Some(item))
+    }
+
+    fn collect(mut self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        let list = PyList::empty(py);
+        while let Some(item) = self.advance(py)? {
+            list.append(item)?;
+        }
+        Ok(list.unbind())
+    }
+}
+
+#[pymethods]
+impl TapeIter {
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        self.advance(py)
+    }
+
+    const fn __length_hint__(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Python-side tests in tests/test_tape.py
+}
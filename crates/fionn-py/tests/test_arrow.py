@@ -0,0 +1,43 @@
+# SPDX-License-Identifier: MIT OR Apache-2.0
+"""Tests for Arrow export (jsonl_to_arrow, records_to_arrow)."""
+
+from __future__ import annotations
+
+import pytest
+
+import fionn.ext as fx
+
+if not hasattr(fx, "jsonl_to_arrow"):
+    pytest.skip("fionn built without the arrow feature", allow_module_level=True)
+
+JSONL = b'{"id": 1, "big": 18446744073709551615, "tags": ["a"]}\n\n{"id": 2, "tags": []}\n'
+
+
+class TestArrowBatch:
+    """Test building batches from JSONL and dicts."""
+
+    def test_jsonl_shape(self) -> None:
+        """Test rows and inferred columns from JSONL bytes."""
+        batch = fx.jsonl_to_arrow(JSONL)
+        assert len(batch) == 2
+        assert batch.num_columns == 3
+        assert sorted(batch.column_names) == ["big", "id", "tags"]
+
+    def test_records(self) -> None:
+        """Test building a batch from a list of dicts."""
+        batch = fx.records_to_arrow([{"a": {"b": 1}}, {"a": None}])
+        assert batch.num_rows == 2
+        assert batch.column_names == ["a"]
+
+    def test_non_object_record_rejected(self) -> None:
+        """Test that a non-object line cannot become a row."""
+        with pytest.raises(ValueError):
+            fx.jsonl_to_arrow("[1, 2]")
+
+    def test_pyarrow_import(self) -> None:
+        """Test that pyarrow imports the stream, keeping big integers exact."""
+        pa = pytest.importorskip("pyarrow")
+        table = pa.table(fx.jsonl_to_arrow(JSONL))
+        assert table.column("big").type == pa.uint64()
+        assert table.column("big").to_pylist() == [18446744073709551615, None]
+        assert table.column("tags").to_pylist() == [["a"], []]
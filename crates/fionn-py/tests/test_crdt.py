@@ -0,0 +1,224 @@
+# SPDX-License-Identifier: MIT OR Apache-2.0
+"""Tests for CrdtDocument (causal merges, deltas, strategies)."""
+
+from __future__ import annotations
+
+import pytest
+
+
+class TestCrdtDocumentBasics:
+    """Test local reads and writes."""
+
+    def test_initial_value(self) -> None:
+        """Test the initial data is the document value."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"a": 1, "b": {"c": [1, 2]}}, replica_id="r1")
+        assert doc.value == {"a": 1, "b": {"c": [1, 2]}}
+
+    def test_initial_data_must_be_dict(self) -> None:
+        """Test non-dict initial data is rejected."""
+        import fionn.ext as fx
+
+        with pytest.raises(TypeError):
+            fx.CrdtDocument([1, 2], replica_id="r1")
+
+    def test_nested_set_and_delete(self) -> None:
+        """Test dotted paths reach into objects and arrays."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"user": {"name": "a"}, "tags": ["x"]}, replica_id="r1")
+        doc.set("user.name", "b")
+        doc.set("user.address.city", "Oslo")
+        doc.set("tags.1", "y")
+        doc.delete("tags.0")
+        assert doc.value == {
+            "user": {"name": "b", "address": {"city": "Oslo"}},
+            "tags": [None, "y"],
+        }
+
+        doc.delete("user.address")
+        assert doc.value["user"] == {"name": "b"}
+
+    def test_set_container_replaces(self) -> None:
+        """Test setting a dict replaces the old one rather than merging keys."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"cfg": {"a": 1}}, replica_id="r1")
+        doc.set("cfg", {"b": 2})
+        assert doc.value == {"cfg": {"b": 2}}
+
+    def test_invalid_paths(self) -> None:
+        """Test writes through scalars or with empty segments fail."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"n": 1}, replica_id="r1")
+        with pytest.raises(ValueError):
+            doc.set("n.x", 2)
+        with pytest.raises(ValueError):
+            doc.set("a..b", 2)
+        assert doc.value == {"n": 1}
+
+    def test_clock_counts_local_operations(self) -> None:
+        """Test every local write advances this replica's clock entry."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"a": 1}, replica_id="r1")
+        assert doc.clock == {"r1": 1}
+        doc.set("a", 2)
+        doc.delete("a")
+        assert doc.clock == {"r1": 3}
+
+
+class TestCrdtDocumentMerge:
+    """Test merging replicas."""
+
+    def test_causal_overwrite_has_no_conflict(self) -> None:
+        """Test a write made after seeing the other replica simply wins."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"x": 1}, replica_id="a")
+        b = fx.CrdtDocument({}, replica_id="b")
+        b.merge(a)
+        b.set("x", 2)
+
+        assert a.merge(b) == []
+        assert a.value == {"x": 2}
+
+    def test_concurrent_writes_converge(self) -> None:
+        """Test both replicas pick the same winner regardless of merge order."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"counter": 0}, replica_id="node-1")
+        b = fx.CrdtDocument({"counter": 0}, replica_id="node-2")
+        a.set("counter", 10)
+        b.set("counter", 5)
+
+        conflicts = a.merge(b)
+        b.merge(a)
+        assert a.value == b.value
+        assert any(c["path"] == "counter" for c in conflicts)
+        conflict = next(c for c in conflicts if c["path"] == "counter" and c["remote"] == 5)
+        assert set(conflict) >= {"local", "remote", "resolved", "local_ts", "remote_ts", "winner"}
+
+    def test_strategy_per_path(self) -> None:
+        """Test a path strategy resolves concurrent writes below it."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"stats": {"points": 7}, "low": 4, "name": "a"}, replica_id="A")
+        b = fx.CrdtDocument({"stats": {"points": 3}, "low": 2, "name": "b"}, replica_id="B")
+        for doc in (a, b):
+            doc.set_strategy("stats", fx.MergeStrategy.Max)
+            doc.set_strategy("low", fx.MergeStrategy.Min)
+
+        a.merge(b)
+        b.merge(a)
+        # Strings fall back to LWW, where the larger replica id breaks the tie
+        assert a.value == b.value == {"stats": {"points": 7}, "low": 2, "name": "b"}
+
+    def test_merge_is_idempotent(self) -> None:
+        """Test merging the same replica twice changes nothing."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"n": 1}, replica_id="a")
+        b = fx.CrdtDocument({"m": 2}, replica_id="b")
+        a.merge(b)
+        clock = a.clock
+        assert a.merge(b) == []
+        assert a.merge(a) == []
+        assert a.clock == clock
+        assert a.value == {"n": 1, "m": 2}
+
+
+class TestCrdtDocumentDeltas:
+    """Test delta exchange."""
+
+    def test_delta_roundtrip(self) -> None:
+        """Test applying a delta brings a peer up to date."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"items": [1, 2], "meta": {"v": 1}}, replica_id="a")
+        b = fx.CrdtDocument({}, replica_id="b")
+
+        payload = a.delta_since(b.clock)
+        assert isinstance(payload, bytes)
+        assert b.apply_delta(payload) == []
+        assert b.value == a.value
+        assert b.clock["a"] == a.clock["a"]
+
+    def test_delta_since_only_ships_new_operations(self) -> None:
+        """Test a peer's clock filters out what it already has."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({"x": 1}, replica_id="a")
+        b = fx.CrdtDocument({}, replica_id="b")
+        b.apply_delta(a.delta_since())
+
+        a.set("y", 2)
+        small = a.delta_since(b.clock)
+        assert b"\"y\"" in small
+        assert b"\"x\"" not in small
+
+        b.apply_delta(small)
+        assert b.value == {"x": 1, "y": 2}
+
+    def test_out_of_order_deltas(self) -> None:
+        """Test a delta arriving before its dependencies is held back."""
+        import fionn.ext as fx
+
+        a = fx.CrdtDocument({}, replica_id="a")
+        b = fx.CrdtDocument({}, replica_id="b")
+        a.set("x", 1)
+        first = a.delta_since()
+        clock = a.clock
+        a.set("x", 2)
+        second = a.delta_since(clock)
+
+        b.apply_delta(second)
+        assert b.value == {}
+        b.apply_delta(first)
+        assert b.value == {"x": 2}
+
+    def test_invalid_delta(self) -> None:
+        """Test garbage bytes raise a decode error."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({}, replica_id="a")
+        with pytest.raises(ValueError):
+            doc.apply_delta(b"not a delta")
+
+    def test_export_state(self) -> None:
+        """Test exported state carries the document and clock."""
+        import fionn.ext as fx
+
+        doc = fx.CrdtDocument({"a": 1}, replica_id="r1")
+        state = doc.export_state()
+        assert state == {"replica_id": "r1", "document": {"a": 1}, "clock": {"r1": 1}}
+
+
+class TestCrdtDocumentJsonl:
+    """Test bulk JSONL merges."""
+
+    def test_merge_jsonl_uses_strategies(self) -> None:
+        """Test lines resolve through path strategies and replicate."""
+        import tempfile
+        from pathlib import Path
+
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmp:
+            path = Path(tmp) / "updates.jsonl"
+            path.write_text(
+                '{"score": 5, "name": "x", "ts": 2}\n'
+                '{"score": 3, "name": "y", "ts": 1}\n'
+                '{"score": 9, "name": "z", "ts": 3}\n'
+            )
+            doc = fx.CrdtDocument({"score": 0, "name": "start"}, replica_id="leader")
+            doc.set_strategy("score", fx.MergeStrategy.Max)
+            doc.merge_jsonl(str(path))
+
+        assert doc.value == {"score": 9, "name": "z"}
+
+        peer = fx.CrdtDocument({}, replica_id="peer")
+        peer.merge(doc)
+        assert peer.value == doc.value
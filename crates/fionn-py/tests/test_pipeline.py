@@ -360,3 +360,220 @@ class TestPipelineErrorHandling:
             pipeline = fx.Pipeline()
             with pytest.raises(IOError):
                 pipeline.process_jsonl("/nonexistent/path/input.jsonl", str(output_path))
+
+
+def _write_jsonl(path: Path, records: list[dict]) -> None:
+    with open(path, "w") as f:
+        for record in records:
+            f.write(json.dumps(record) + "\n")
+
+
+def _read_jsonl(path: Path) -> list[dict]:
+    with open(path) as f:
+        return [json.loads(line) for line in f]
+
+
+class TestPipelineNativeStages:
+    """Test the declarative stages that run without the GIL."""
+
+    def test_native_stage_chaining(self) -> None:
+        """Test native stages chain and show up in repr."""
+        import fionn.ext as fx
+
+        pipeline = (
+            fx.Pipeline()
+            .where("active == true")
+            .select(["id"])
+            .rename({"id": "key"})
+            .drop(["debug"])
+            .apply([{"op": "field_delete", "path": "x"}])
+            .convert(to="jsonl")
+        )
+        assert len(pipeline) == 6
+        assert repr(pipeline) == (
+            "Pipeline(stages=[where, select, rename, drop, apply, convert])"
+        )
+
+    def test_where(self) -> None:
+        """Test where filters records, including nested paths and `in`."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.jsonl"
+            _write_jsonl(
+                input_path,
+                [
+                    {"id": 1, "user": {"role": "admin"}, "latency": 900},
+                    {"id": 2, "user": {"role": "guest"}, "latency": 950},
+                    {"id": 3, "user": {"role": "owner"}, "latency": 10},
+                    {"id": 4, "latency": 1000},
+                ],
+            )
+
+            pipeline = fx.Pipeline().where('user.role in ["admin", "owner"]').where("latency > 500")
+            count = pipeline.process_jsonl(str(input_path), str(output_path))
+            assert count == 1
+            assert [r["id"] for r in _read_jsonl(output_path)] == [1]
+
+    def test_where_rejects_wildcards(self) -> None:
+        """Test where refuses paths it cannot resolve to one value."""
+        import fionn.ext as fx
+
+        with pytest.raises(ValueError):
+            fx.Pipeline().where("items[*].id == 1")
+
+    def test_select(self) -> None:
+        """Test select keeps nested paths, array elements and wildcards."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.jsonl"
+            _write_jsonl(
+                input_path,
+                [
+                    {
+                        "id": 1,
+                        "secret": "x",
+                        "user": {"name": "Ann", "age": 40},
+                        "items": [{"sku": "a", "qty": 2}],
+                        "meta": {"a": 1, "b": 2},
+                    }
+                ],
+            )
+
+            pipeline = fx.Pipeline().select(["id", "user.name", "items.sku", "meta.*"])
+            pipeline.process_jsonl(str(input_path), str(output_path))
+            assert _read_jsonl(output_path) == [
+                {
+                    "id": 1,
+                    "user": {"name": "Ann"},
+                    "items": [{"sku": "a"}],
+                    "meta": {"a": 1, "b": 2},
+                }
+            ]
+
+    def test_rename_and_drop(self) -> None:
+        """Test rename moves dotted paths and drop removes them."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.jsonl"
+            _write_jsonl(input_path, [{"ts": 5, "user": {"name": "Ann", "password": "x"}}])
+
+            pipeline = (
+                fx.Pipeline()
+                .rename({"ts": "timestamp", "user.name": "name"})
+                .drop(["user.password", "missing"])
+            )
+            pipeline.process_jsonl(str(input_path), str(output_path))
+            assert _read_jsonl(output_path) == [{"timestamp": 5, "name": "Ann", "user": {}}]
+
+    def test_apply(self) -> None:
+        """Test apply runs DSON operations on every record."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.jsonl"
+            _write_jsonl(input_path, [{"id": 1, "debug": True}, {"id": 2, "debug": False}])
+
+            pipeline = fx.Pipeline().apply(
+                [
+                    {"op": "field_add", "path": "source", "value": "import"},
+                    {"op": "field_delete", "path": "debug"},
+                ]
+            )
+            count = pipeline.process_jsonl(str(input_path), str(output_path))
+            assert count == 2
+            assert _read_jsonl(output_path) == [
+                {"id": 1, "source": "import"},
+                {"id": 2, "source": "import"},
+            ]
+
+    def test_apply_rejects_bad_operations(self) -> None:
+        """Test apply validates operations when the stage is added."""
+        import fionn.ext as fx
+
+        with pytest.raises(ValueError):
+            fx.Pipeline().apply([{"op": "explode", "path": "x"}])
+        with pytest.raises(ValueError):
+            fx.Pipeline().apply([{"op": "field_add", "path": "x"}])
+        with pytest.raises(ValueError):
+            fx.Pipeline().apply([{"op": "field_add", "path": "x", "value": {"a": 1}}])
+
+    def test_convert(self) -> None:
+        """Test convert sets the output format of process_jsonl."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.isonl"
+            _write_jsonl(input_path, [{"id": 1, "name": "Ann"}, {"id": 2, "name": "Bob"}])
+
+            pipeline = fx.Pipeline().convert(to="isonl")
+            assert pipeline.process_jsonl(str(input_path), str(output_path)) == 2
+            with open(output_path) as f:
+                lines = f.read().splitlines()
+            assert lines == [
+                "table.data|id:int|name:string|1|Ann",
+                "table.data|id:int|name:string|2|Bob",
+            ]
+
+        with pytest.raises(ValueError):
+            fx.Pipeline().convert(to="xml")
+
+    def test_process_defaults_to_convert_format(self) -> None:
+        """Test process uses the convert stage when no output format is given."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.json"
+            _write_jsonl(input_path, [{"id": 1}, {"id": 2}])
+
+            pipeline = fx.Pipeline().convert(to="json")
+            assert pipeline.process(str(input_path), "jsonl", str(output_path)) == 2
+            with open(output_path) as f:
+                assert json.load(f) == [{"id": 1}, {"id": 2}]
+
+    def test_native_and_python_stages_mix(self) -> None:
+        """Test Python stages run between native ones in order."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.jsonl"
+            output_path = Path(tmpdir) / "output.jsonl"
+            _write_jsonl(input_path, [{"id": i, "score": i * 10} for i in range(2000)])
+
+            pipeline = (
+                fx.Pipeline()
+                .where("score >= 100")
+                .map(lambda x: {**x, "score": x["score"] * 2})
+                .filter(lambda x: x["id"] % 2 == 0)
+                .select(["id", "score"])
+                .rename({"score": "points"})
+            )
+            count = pipeline.process_jsonl(str(input_path), str(output_path))
+            output_data = _read_jsonl(output_path)
+            assert count == len(output_data) == 995
+            assert output_data[0] == {"id": 10, "points": 200}
+            assert output_data[-1] == {"id": 1998, "points": 39960}
+
+    def test_isonl_native_stages(self) -> None:
+        """Test native stages on ISONL keep the input table name."""
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            input_path = Path(tmpdir) / "input.isonl"
+            output_path = Path(tmpdir) / "output.isonl"
+            with open(input_path, "w") as f:
+                f.write("table.users|id:int|name:string|1|Alice\n")
+                f.write("table.users|id:int|name:string|2|Bob\n")
+
+            pipeline = fx.Pipeline().where("id > 1")
+            assert pipeline.process_isonl(str(input_path), str(output_path)) == 1
+            with open(output_path) as f:
+                assert f.read() == "table.users|id:int|name:string|2|Bob\n"
@@ -0,0 +1,230 @@
+# SPDX-License-Identifier: MIT OR Apache-2.0
+"""Tests for open_stream (incremental readers over files and file-like objects)."""
+
+from __future__ import annotations
+
+import io
+
+import pytest
+
+JSONL = b'{"id": 1, "name": "a", "x": 0}\n{"id": 2, "name": "b", "x": 0}\n\n{"id": 3, "name": "c"}'
+
+
+class TrickleReader:
+    """File-like object returning a few bytes per read()."""
+
+    def __init__(self, data: bytes, size: int = 3) -> None:
+        self.data = data
+        self.size = size
+
+    def read(self, _n: int = -1) -> bytes:
+        chunk, self.data = self.data[: self.size], self.data[self.size :]
+        return chunk
+
+
+class TestOpenStreamSources:
+    """Test the kinds of source open_stream accepts."""
+
+    def test_path(self) -> None:
+        """Test reading from a path (str or PathLike)."""
+        import tempfile
+        from pathlib import Path
+
+        import fionn.ext as fx
+
+        with tempfile.TemporaryDirectory() as tmp:
+            path = Path(tmp) / "data.jsonl"
+            path.write_bytes(JSONL)
+            assert [r["id"] for r in fx.open_stream(path)] == [1, 2, 3]
+            assert [r["id"] for r in fx.open_stream(str(path))] == [1, 2, 3]
+
+    def test_binary_and_text_file_objects(self) -> None:
+        """Test read() may return bytes or str."""
+        import fionn.ext as fx
+
+        assert len(list(fx.open_stream(io.BytesIO(JSONL)))) == 3
+        assert len(list(fx.open_stream(io.StringIO(JSONL.decode())))) == 3
+
+    def test_lines_split_across_reads(self) -> None:
+        """Test records spanning chunk boundaries are reassembled."""
+        import fionn.ext as fx
+
+        records = list(fx.open_stream(TrickleReader(JSONL)))
+        assert [r["name"] for r in records] == ["a", "b", "c"]
+
+    def test_gzip(self) -> None:
+        """Test gzip file objects stream transparently."""
+        import gzip
+
+        import fionn.ext as fx
+
+        with gzip.GzipFile(fileobj=io.BytesIO(gzip.compress((JSONL + b"\n") * 1000))) as f:
+            assert sum(1 for _ in fx.open_stream(f)) == 3000
+
+    def test_socket(self) -> None:
+        """Test sockets, both directly (recv) and through makefile()."""
+        import socket
+
+        import fionn.ext as fx
+
+        for wrap in (lambda s: s, lambda s: s.makefile("rb")):
+            left, right = socket.socketpair()
+            left.sendall(JSONL)
+            left.close()
+            with right:
+                assert [r["id"] for r in fx.open_stream(wrap(right))] == [1, 2, 3]
+
+    def test_invalid_source(self) -> None:
+        """Test unsupported sources and formats are rejected."""
+        import fionn.ext as fx
+
+        with pytest.raises(TypeError):
+            fx.open_stream(42)
+        with pytest.raises(OSError):
+            fx.open_stream("/nonexistent/data.jsonl")
+        with pytest.raises(ValueError):
+            fx.open_stream(io.BytesIO(JSONL), format="xml")
+        with pytest.raises(ValueError):
+            fx.open_stream(io.BytesIO(JSONL), batch_size=0)
+
+
+class TestOpenStreamOptions:
+    """Test fields, batching and error handling."""
+
+    def test_fields(self) -> None:
+        """Test only requested top-level fields are kept."""
+        import fionn.ext as fx
+
+        records = list(fx.open_stream(io.BytesIO(JSONL), fields=["id"]))
+        assert records == [{"id": 1}, {"id": 2}, {"id": 3}]
+
+    def test_nested_fields(self) -> None:
+        """Test fields are schema paths that reach into nested objects."""
+        import fionn.ext as fx
+
+        data = b'{"id": 1, "user": {"name": "a", "age": 2}, "x": 3}\n'
+        records = list(fx.open_stream(io.BytesIO(data), fields=["id", "user.name"]))
+        assert records == [{"id": 1, "user": {"name": "a"}}]
+
+    def test_batches(self) -> None:
+        """Test batch_size yields lists, with a short final batch."""
+        import fionn.ext as fx
+
+        batches = list(fx.open_stream(TrickleReader(JSONL), batch_size=2))
+        assert [[r["id"] for r in b] for b in batches] == [[1, 2], [3]]
+
+    def test_errors_do_not_abort(self) -> None:
+        """Test bad lines are reported with line numbers and skipped."""
+        import fionn.ext as fx
+
+        data = b'{"id": 1}\n{"id": \n\xff\xfe\n{"id": 4}\n'
+        stream = fx.open_stream(io.BytesIO(data))
+        assert [r["id"] for r in stream] == [1, 4]
+        assert [e["line"] for e in stream.errors] == [2, 3]
+        assert stream.errors[0]["text"] == '{"id": '
+        assert "JSON" in stream.errors[0]["message"]
+        assert "UTF-8" in stream.errors[1]["message"]
+
+    def test_strict(self) -> None:
+        """Test strict mode raises on the first bad record."""
+        import fionn.ext as fx
+
+        stream = fx.open_stream(io.BytesIO(b'{"id": 1}\nnope\n'), strict=True)
+        assert next(stream) == {"id": 1}
+        with pytest.raises(ValueError, match="line 2"):
+            next(stream)
+
+
+class TestOpenStreamFormats:
+    """Test the non-JSONL formats."""
+
+    def test_isonl(self) -> None:
+        """Test ISONL lines with field selection."""
+        import fionn.ext as fx
+
+        data = b"table.u|id:int|name:string|1|Alice\ntable.u|id:int|name:string|2|Bob\n"
+        assert list(fx.open_stream(io.BytesIO(data), format="isonl", fields=["name"])) == [
+            {"name": "Alice"},
+            {"name": "Bob"},
+        ]
+
+    def test_csv(self) -> None:
+        """Test CSV rows become dicts keyed by the header line."""
+        import fionn.ext as fx
+
+        data = b"name,age,ok\r\nAlice,30,true\r\nBob,2.5\r\n"
+        assert list(fx.open_stream(TrickleReader(data, 4), format="csv")) == [
+            {"name": "Alice", "age": 30, "ok": True},
+            {"name": "Bob", "age": 2.5, "ok": ""},
+        ]
+
+    def test_csv_quoted_fields(self) -> None:
+        """Test quoted CSV fields keep delimiters, quotes and newlines."""
+        import fionn.ext as fx
+
+        data = b'name,note,n\n"Smith, J","line 1\nline 2",007\nx,"say ""hi""",1\n'
+        stream = fx.open_stream(TrickleReader(data, 3), format="csv")
+        assert list(stream) == [
+            {"name": "Smith, J", "note": "line 1\nline 2", "n": 7},
+            {"name": "x", "note": 'say "hi"', "n": 1},
+        ]
+
+    def test_yaml_multi(self) -> None:
+        """Test YAML documents are split on --- and bad documents are reported."""
+        import fionn.ext as fx
+
+        data = b"# leading comment\n---\na: 1\nb: [1, 2]\n--- {c: 3}\n---\nbad: [\n...\nd: 4\n"
+        stream = fx.open_stream(TrickleReader(data, 5), format="yaml-multi")
+        assert list(stream) == [{"a": 1, "b": [1, 2]}, {"c": 3}, {"d": 4}]
+        assert [e["line"] for e in stream.errors] == [7]
+
+
+class TestOpenStreamAsync:
+    """Test async iteration over asyncio streams."""
+
+    def test_asyncio_stream_reader(self) -> None:
+        """Test records arrive as data is fed to an asyncio.StreamReader."""
+        import asyncio
+
+        import fionn.ext as fx
+
+        async def main() -> list:
+            reader = asyncio.StreamReader()
+
+            async def produce() -> None:
+                for i in range(0, len(JSONL), 7):
+                    reader.feed_data(JSONL[i : i + 7])
+                    await asyncio.sleep(0)
+                reader.feed_eof()
+
+            producer = asyncio.ensure_future(produce())
+            records = [r async for r in fx.open_stream(reader, fields=["name"])]
+            await producer
+            return records
+
+        assert asyncio.run(main()) == [{"name": "a"}, {"name": "b"}, {"name": "c"}]
+
+    def test_async_batches_over_sync_source(self) -> None:
+        """Test async for also works when read() is synchronous."""
+        import asyncio
+
+        import fionn.ext as fx
+
+        async def main() -> list:
+            return [b async for b in fx.open_stream(io.BytesIO(JSONL), batch_size=2)]
+
+        assert [len(b) for b in asyncio.run(main())] == [2, 1]
+
+    def test_sync_iteration_over_async_source(self) -> None:
+        """Test a plain for loop over an async source fails clearly."""
+        import asyncio
+
+        import fionn.ext as fx
+
+        async def main() -> None:
+            reader = asyncio.StreamReader()
+            reader.feed_eof()
+            with pytest.raises(TypeError, match="async for"):
+                list(fx.open_stream(reader))
+
+        asyncio.run(main())
@@ -153,6 +153,92 @@ class TestTapeConversion:
         assert obj == {"name": "Alice", "scores": [100, 95, 88]}
 
 
+class TestTapeView:
+    """Test lazy Tape.view() proxies and Tape.to_python()."""
+
+    DOC = '{"name": "Alice", "tags": ["a", "b", "c"], "meta": {"n": 1, "x": null}}'
+
+    def test_view_mapping(self) -> None:
+        """Test object views behave like read-only mappings."""
+        from collections.abc import Mapping
+
+        import fionn.ext as fx
+
+        root = fx.Tape.parse(self.DOC).view()
+        assert isinstance(root, Mapping)
+        assert len(root) == 3
+        assert list(root) == ["name", "tags", "meta"]
+        assert root.keys() == ["name", "tags", "meta"]
+        assert root["name"] == "Alice"
+        assert "meta" in root
+        assert "missing" not in root
+        assert 1 not in root
+        assert root.get("missing", 5) == 5
+        assert root["meta"]["x"] is None
+        assert dict(root["meta"].items()) == {"n": 1, "x": None}
+        with pytest.raises(KeyError):
+            root["missing"]
+        assert repr(root) == "TapeMapping(len=3)"
+
+    def test_view_sequence(self) -> None:
+        """Test array views support indexing, negative indices and slices."""
+        from collections.abc import Sequence
+
+        import fionn.ext as fx
+
+        tags = fx.Tape.parse(self.DOC).view()["tags"]
+        assert isinstance(tags, Sequence)
+        assert len(tags) == 3
+        assert tags[0] == "a"
+        assert tags[-1] == "c"
+        assert tags[1:] == ["b", "c"]
+        assert tags[::-2] == ["c", "a"]
+        assert list(tags) == ["a", "b", "c"]
+        with pytest.raises(IndexError):
+            tags[3]
+        with pytest.raises(IndexError):
+            tags[-4]
+
+    def test_view_nested_containers_stay_lazy(self) -> None:
+        """Test nested containers come back as views, not dicts/lists."""
+        import fionn.ext as fx
+
+        tape = fx.Tape.parse('[{"a": [1, [2, 3]]}, {"b": {}}, []]')
+        root = tape.view()
+        first = root[0]
+        assert isinstance(first, fx.TapeMapping)
+        assert isinstance(first["a"][1], fx.TapeSequence)
+        assert first["a"][1][1] == 3
+        assert root[1]["b"].to_python() == {}
+        assert root[2].to_python() == []
+        assert root.to_python() == tape.to_object()
+
+    def test_view_outlives_tape_reference(self) -> None:
+        """Test a view keeps its tape alive."""
+        import fionn.ext as fx
+
+        view = fx.TapePool().parse(b'{"k": {"v": [1, 2]}}').view()
+        assert view["k"]["v"][1] == 2
+
+    def test_view_scalar_root(self) -> None:
+        """Test a scalar document views as its plain value."""
+        import fionn.ext as fx
+
+        assert fx.Tape.parse("42").view() == 42
+        assert fx.Tape.parse('"s"').view() == "s"
+
+    def test_to_python_path(self) -> None:
+        """Test materializing subtrees by path."""
+        import fionn.ext as fx
+
+        tape = fx.Tape.parse(self.DOC)
+        assert tape.to_python() == tape.to_object()
+        assert tape.to_python("meta") == {"n": 1, "x": None}
+        assert tape.to_python("tags[1]") == "b"
+        with pytest.raises(KeyError):
+            tape.to_python("nope")
+
+
 class TestTapeFiltering:
     """Test Tape.filter() with schema."""
 
@@ -0,0 +1,1018 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! On-demand JSON cursor built on the skip engines
+//!
+//! [`JsonCursor`] navigates raw JSON bytes without building a tape. Only the
+//! values you ask for are decoded; every sibling you walk past is skipped
+//! with the selected [`SkipStrategy`].
+//!
+//! ```
+//! use fionn_simd::cursor::JsonCursor;
+//!
+//! let line = br#"{"meta": {"request_id": "r-42", "sizes": [1, 2]}, "body": {"big": [0]}}"#;
+//! let doc = JsonCursor::new(line);
+//! let id = doc.find_field("meta")?.find_field("request_id")?.as_str()?;
+//! assert_eq!(id, "r-42");
+//! # Ok::<(), fionn_simd::cursor::CursorError>(())
+//! ```
+//!
+//! Multi-field extraction is driven by a [`PathSelector`] (implemented for
+//! [`CompiledSchema`] and [`SchemaPattern`]) and runs as a single forward pass.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use fionn_core::{CompiledSchema, SchemaPattern};
+
+use crate::skip::{Skip, SkipStrategy};
+
+/// JSON value type as seen from its first byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonType {
+    /// `{ ... }`
+    Object,
+    /// `[ ... ]`
+    Array,
+    /// `"..."`
+    String,
+    /// Integer or floating point number
+    Number,
+    /// `true` or `false`
+    Bool,
+    /// `null`
+    Null,
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Bool => "bool",
+            Self::Null => "null",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Error raised while navigating or decoding with a [`JsonCursor`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorError {
+    /// Input ended before the value was complete
+    UnexpectedEnd {
+        /// Offset where more input was expected
+        offset: usize,
+    },
+    /// A byte that cannot appear at this position
+    UnexpectedByte {
+        /// The offending byte
+        byte: u8,
+        /// Offset of the byte
+        offset: usize,
+    },
+    /// The value has a different type than requested
+    TypeMismatch {
+        /// Requested type
+        expected: JsonType,
+        /// Actual type
+        found: JsonType,
+    },
+    /// The object has no field with this name
+    NoSuchField(String),
+    /// The array has fewer elements than the requested index
+    IndexOutOfBounds(usize),
+    /// A number that does not fit the requested type
+    InvalidNumber {
+        /// Offset of the number
+        offset: usize,
+    },
+    /// A string with invalid escapes or UTF-8
+    InvalidString {
+        /// Offset of the opening quote
+        offset: usize,
+    },
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd { offset } => write!(f, "unexpected end of input at {offset}"),
+            Self::UnexpectedByte { byte, offset } => {
+                write!(f, "unexpected byte {:?} at {offset}", char::from(*byte))
+            }
+            Self::TypeMismatch { expected, found } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            Self::NoSuchField(name) => write!(f, "no such field: {name}"),
+            Self::IndexOutOfBounds(index) => write!(f, "index {index} out of bounds"),
+            Self::InvalidNumber { offset } => write!(f, "invalid number at {offset}"),
+            Self::InvalidString { offset } => write!(f, "invalid string at {offset}"),
+        }
+    }
+}
+
+impl std::error::Error for CursorError {}
+
+/// Result type for cursor operations
+pub type CursorResult<T> = Result<T, CursorError>;
+
+/// Position of a JSON value inside a buffer
+///
+/// A cursor is a borrowed `(buffer, offset)` pair and is cheap to copy.
+/// Nothing past the value's first byte is examined until a navigation or
+/// decoding method asks for it.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonCursor<'a> {
+    input: &'a [u8],
+    start: usize,
+    strategy: SkipStrategy,
+}
+
+impl<'a> JsonCursor<'a> {
+    /// Create a cursor at the root value, skipping with the best SIMD strategy
+    #[must_use]
+    pub fn new(input: &'a [u8]) -> Self {
+        Self::with_strategy(input, SkipStrategy::best_simd())
+    }
+
+    /// Create a cursor at the root value using a specific skip strategy
+    #[must_use]
+    pub fn with_strategy(input: &'a [u8], strategy: SkipStrategy) -> Self {
+        Self {
+            input,
+            start: skip_whitespace(input, 0),
+            strategy,
+        }
+    }
+
+    /// Skip strategy used for unvisited values
+    #[must_use]
+    pub const fn strategy(&self) -> SkipStrategy {
+        self.strategy
+    }
+
+    /// Offset of the value's first byte in the input buffer
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.start
+    }
+
+    /// Type of the value under the cursor
+    ///
+    /// # Errors
+    /// Returns an error if the input is empty or the byte cannot start a value.
+    pub fn value_type(&self) -> CursorResult<JsonType> {
+        match self.first_byte()? {
+            b'{' => Ok(JsonType::Object),
+            b'[' => Ok(JsonType::Array),
+            b'"' => Ok(JsonType::String),
+            b'-' | b'0'..=b'9' => Ok(JsonType::Number),
+            b't' | b'f' => Ok(JsonType::Bool),
+            b'n' => Ok(JsonType::Null),
+            byte => Err(CursorError::UnexpectedByte {
+                byte,
+                offset: self.start,
+            }),
+        }
+    }
+
+    /// Raw bytes of the value, including quotes and brackets
+    ///
+    /// # Errors
+    /// Returns an error if the value is truncated or malformed.
+    pub fn raw(&self) -> CursorResult<&'a [u8]> {
+        Ok(&self.input[self.start..self.value_end()?])
+    }
+
+    /// Move to the value of an object field
+    ///
+    /// Fields before the match are skipped without being decoded.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::NoSuchField`] if the field is absent, or an error
+    /// if the value is not an object or is malformed.
+    pub fn find_field(&self, name: &str) -> CursorResult<Self> {
+        let mut fields = self.fields()?;
+        while let Some(key) = fields.next_key()? {
+            if key.matches(name) {
+                return Ok(fields.value());
+            }
+        }
+        Err(CursorError::NoSuchField(name.to_string()))
+    }
+
+    /// Move to the value of an object field, letting the last of duplicate
+    /// keys win as `serde_json` does
+    ///
+    /// Unlike [`find_field`](Self::find_field) this walks the whole object.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::NoSuchField`] if the field is absent, or an error
+    /// if the value is not an object or is malformed.
+    pub fn find_last_field(&self, name: &str) -> CursorResult<Self> {
+        let mut fields = self.fields()?;
+        let mut found = None;
+        while let Some(key) = fields.next_key()? {
+            if key.matches(name) {
+                found = Some(fields.value());
+            }
+        }
+        found.ok_or_else(|| CursorError::NoSuchField(name.to_string()))
+    }
+
+    /// Follow a sequence of field names from this value
+    ///
+    /// # Errors
+    /// Returns the first error hit by [`find_field`](Self::find_field).
+    pub fn find_path(&self, fields: &[&str]) -> CursorResult<Self> {
+        fields
+            .iter()
+            .try_fold(*self, |cursor, field| cursor.find_field(field))
+    }
+
+    /// Move to an array element
+    ///
+    /// # Errors
+    /// Returns [`CursorError::IndexOutOfBounds`] if the array is too short, or
+    /// an error if the value is not an array or is malformed.
+    pub fn at(&self, index: usize) -> CursorResult<Self> {
+        self.elements()?
+            .nth(index)
+            .unwrap_or(Err(CursorError::IndexOutOfBounds(index)))
+    }
+
+    /// Iterate over the fields of an object
+    ///
+    /// # Errors
+    /// Returns an error if the value is not an object.
+    pub fn fields(&self) -> CursorResult<Fields<'a>> {
+        self.expect(JsonType::Object)?;
+        Ok(Fields {
+            members: Members::new(self, b'}'),
+        })
+    }
+
+    /// Iterate over the elements of an array
+    ///
+    /// # Errors
+    /// Returns an error if the value is not an array.
+    pub fn elements(&self) -> CursorResult<Elements<'a>> {
+        self.expect(JsonType::Array)?;
+        Ok(Elements {
+            members: Members::new(self, b']'),
+        })
+    }
+
+    /// Decode a string value, borrowing when it contains no escapes
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a string or is malformed.
+    pub fn as_str(&self) -> CursorResult<Cow<'a, str>> {
+        self.expect(JsonType::String)?;
+        let end = self.string_end(self.start)?;
+        decode_string(self.input, self.start, end)
+    }
+
+    /// Decode a boolean value
+    ///
+    /// # Errors
+    /// Returns an error if the value is not `true` or `false`.
+    pub fn as_bool(&self) -> CursorResult<bool> {
+        match self.raw()? {
+            b"true" => Ok(true),
+            b"false" => Ok(false),
+            _ => Err(CursorError::TypeMismatch {
+                expected: JsonType::Bool,
+                found: self.value_type()?,
+            }),
+        }
+    }
+
+    /// Check whether the value is `null`
+    ///
+    /// # Errors
+    /// Returns an error if the input is empty or malformed at the cursor.
+    pub fn is_null(&self) -> CursorResult<bool> {
+        if self.value_type()? == JsonType::Null {
+            self.raw()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Parse the value as a signed integer
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a number or does not fit in `i64`.
+    pub fn as_i64(&self) -> CursorResult<i64> {
+        self.number_text()?
+            .parse()
+            .map_err(|_| CursorError::InvalidNumber { offset: self.start })
+    }
+
+    /// Parse the value as an unsigned integer
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a number or does not fit in `u64`.
+    pub fn as_u64(&self) -> CursorResult<u64> {
+        self.number_text()?
+            .parse()
+            .map_err(|_| CursorError::InvalidNumber { offset: self.start })
+    }
+
+    /// Parse the value as a floating point number
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a number.
+    pub fn as_f64(&self) -> CursorResult<f64> {
+        self.number_text()?
+            .parse()
+            .map_err(|_| CursorError::InvalidNumber { offset: self.start })
+    }
+
+    /// Extract every value chosen by `selector` in one forward pass
+    ///
+    /// Containers the selector rules out are skipped without being scanned
+    /// for keys. Returned paths use dotted field names with `[n]` for array
+    /// indices (`items[0].id`), in document order.
+    ///
+    /// # Errors
+    /// Returns an error if a visited part of the document is malformed.
+    pub fn extract<S: PathSelector + ?Sized>(
+        &self,
+        selector: &S,
+    ) -> CursorResult<Vec<(String, Self)>> {
+        let mut path = Vec::new();
+        let mut found = Vec::new();
+        self.walk(selector, &mut path, &mut found)?;
+        Ok(found)
+    }
+
+    /// Visit this value for [`extract`](Self::extract)
+    ///
+    /// Returns the end offset when the value was descended into, so the
+    /// parent does not need to skip it a second time.
+    fn walk<S: PathSelector + ?Sized>(
+        &self,
+        selector: &S,
+        path: &mut Vec<PathComponent<'a>>,
+        found: &mut Vec<(String, Self)>,
+    ) -> CursorResult<Option<usize>> {
+        match selector.select(path) {
+            Selection::Take => {
+                found.push((render_path(path), *self));
+                Ok(None)
+            }
+            Selection::Skip => Ok(None),
+            Selection::Descend => match self.value_type()? {
+                JsonType::Object => {
+                    let mut fields = self.fields()?;
+                    while let Some(key) = fields.next_key()? {
+                        path.push(PathComponent::Field(key.decode()?));
+                        let end = fields.value().walk(selector, path, found)?;
+                        path.pop();
+                        if let Some(end) = end {
+                            fields.members.resume_at(end);
+                        }
+                    }
+                    Ok(Some(fields.members.pos))
+                }
+                JsonType::Array => {
+                    let mut elements = self.elements()?;
+                    let mut index = 0;
+                    while let Some(element) = elements.next().transpose()? {
+                        path.push(PathComponent::Index(index));
+                        let end = element.walk(selector, path, found)?;
+                        path.pop();
+                        if let Some(end) = end {
+                            elements.members.resume_at(end);
+                        }
+                        index += 1;
+                    }
+                    Ok(Some(elements.members.pos))
+                }
+                _ => Ok(None),
+            },
+        }
+    }
+
+    fn first_byte(&self) -> CursorResult<u8> {
+        self.input
+            .get(self.start)
+            .copied()
+            .ok_or(CursorError::UnexpectedEnd { offset: self.start })
+    }
+
+    fn expect(&self, expected: JsonType) -> CursorResult<()> {
+        let found = self.value_type()?;
+        if found == expected {
+            Ok(())
+        } else {
+            Err(CursorError::TypeMismatch { expected, found })
+        }
+    }
+
+    fn number_text(&self) -> CursorResult<&'a str> {
+        self.expect(JsonType::Number)?;
+        // Number bytes are ASCII by construction of `scalar_end`
+        std::str::from_utf8(self.raw()?)
+            .map_err(|_| CursorError::InvalidNumber { offset: self.start })
+    }
+
+    /// End offset (exclusive) of a string whose opening quote is at `quote`
+    fn string_end(&self, quote: usize) -> CursorResult<usize> {
+        let result = self.strategy.skip_string(&self.input[quote + 1..]).ok_or(
+            CursorError::UnexpectedEnd {
+                offset: self.input.len(),
+            },
+        )?;
+        Ok(quote + 1 + result.consumed)
+    }
+
+    /// End offset (exclusive) of the value under the cursor
+    fn value_end(&self) -> CursorResult<usize> {
+        let body = self.start + 1;
+        let container = match self.first_byte()? {
+            b'{' => self.strategy.skip_object(&self.input[body..]),
+            b'[' => self.strategy.skip_array(&self.input[body..]),
+            b'"' => self.strategy.skip_string(&self.input[body..]),
+            _ => return scalar_end(self.input, self.start),
+        };
+        container
+            .map(|result| body + result.consumed)
+            .ok_or(CursorError::UnexpectedEnd {
+                offset: self.input.len(),
+            })
+    }
+}
+
+/// Step in the path from the extraction root to a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathComponent<'a> {
+    /// Object field name (unescaped)
+    Field(Cow<'a, str>),
+    /// Array index
+    Index(usize),
+}
+
+/// What [`JsonCursor::extract`] does with the value at a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    /// Return this value
+    Take,
+    /// Visit this value's children
+    Descend,
+    /// Skip this value and everything under it
+    Skip,
+}
+
+/// Chooses which values a single-pass extraction returns
+pub trait PathSelector {
+    /// Decide what to do with the value at `path` (empty for the root)
+    fn select(&self, path: &[PathComponent<'_>]) -> Selection;
+}
+
+/// Schema paths name fields only; array indices are transparent, as in
+/// skip-tape processing (`items.id` matches every element's `id`)
+fn schema_path(path: &[PathComponent<'_>]) -> String {
+    let mut joined = String::new();
+    for component in path {
+        if let PathComponent::Field(name) = component {
+            if !joined.is_empty() {
+                joined.push('.');
+            }
+            joined.push_str(name);
+        }
+    }
+    joined
+}
+
+impl PathSelector for CompiledSchema {
+    fn select(&self, path: &[PathComponent<'_>]) -> Selection {
+        if path.is_empty() {
+            return Selection::Descend;
+        }
+        if matches!(path.last(), Some(PathComponent::Index(_))) {
+            // Elements are addressed by their parent's path
+            return Selection::Descend;
+        }
+        let joined = schema_path(path);
+        if self.is_excluded(&joined) {
+            Selection::Skip
+        } else if self.include_patterns.iter().any(|p| p.matches(&joined)) {
+            Selection::Take
+        } else if self.should_include_object(&joined) {
+            Selection::Descend
+        } else {
+            Selection::Skip
+        }
+    }
+}
+
+impl PathSelector for SchemaPattern {
+    fn select(&self, path: &[PathComponent<'_>]) -> Selection {
+        if path.is_empty() || matches!(path.last(), Some(PathComponent::Index(_))) {
+            return Selection::Descend;
+        }
+        let joined = schema_path(path);
+        if self.matches(&joined) {
+            Selection::Take
+        } else if self.could_match_children(&joined) {
+            Selection::Descend
+        } else {
+            Selection::Skip
+        }
+    }
+}
+
+/// Render an extraction path as `a.b[0].c`
+fn render_path(path: &[PathComponent<'_>]) -> String {
+    use std::fmt::Write;
+    let mut rendered = String::new();
+    for component in path {
+        match component {
+            PathComponent::Field(name) => {
+                if !rendered.is_empty() {
+                    rendered.push('.');
+                }
+                rendered.push_str(name);
+            }
+            PathComponent::Index(index) => {
+                let _ = write!(rendered, "[{index}]");
+            }
+        }
+    }
+    rendered
+}
+
+/// Shared state for object and array iteration
+#[derive(Debug)]
+struct Members<'a> {
+    input: &'a [u8],
+    pos: usize,
+    close: u8,
+    first: bool,
+    done: bool,
+    strategy: SkipStrategy,
+    /// Previously yielded value, skipped lazily on the next step
+    pending: Option<JsonCursor<'a>>,
+}
+
+impl<'a> Members<'a> {
+    const fn new(container: &JsonCursor<'a>, close: u8) -> Self {
+        Self {
+            input: container.input,
+            pos: container.start + 1,
+            close,
+            first: true,
+            done: false,
+            strategy: container.strategy,
+            pending: None,
+        }
+    }
+
+    /// Continue after a value whose end is already known
+    const fn resume_at(&mut self, end: usize) {
+        self.pending = None;
+        self.pos = end;
+    }
+
+    /// Move to the start of the next member, or past the closing bracket
+    fn advance(&mut self) -> CursorResult<Option<usize>> {
+        if self.done {
+            return Ok(None);
+        }
+        if let Some(previous) = self.pending.take() {
+            self.pos = previous.value_end()?;
+        }
+        let mut pos = skip_whitespace(self.input, self.pos);
+        let byte = byte_at(self.input, pos)?;
+        if byte == self.close {
+            self.pos = pos + 1;
+            self.done = true;
+            return Ok(None);
+        }
+        if !self.first {
+            if byte != b',' {
+                return Err(CursorError::UnexpectedByte { byte, offset: pos });
+            }
+            pos = skip_whitespace(self.input, pos + 1);
+        }
+        self.first = false;
+        self.pos = pos;
+        Ok(Some(pos))
+    }
+
+    const fn cursor_at(&mut self, start: usize) -> JsonCursor<'a> {
+        let cursor = JsonCursor {
+            input: self.input,
+            start,
+            strategy: self.strategy,
+        };
+        self.pending = Some(cursor);
+        cursor
+    }
+
+    const fn fail<T>(&mut self, error: CursorError) -> CursorResult<T> {
+        self.done = true;
+        Err(error)
+    }
+}
+
+/// Undecoded object key
+#[derive(Debug, Clone, Copy)]
+struct Key<'a> {
+    input: &'a [u8],
+    quote: usize,
+    end: usize,
+}
+
+impl<'a> Key<'a> {
+    fn matches(&self, name: &str) -> bool {
+        let body = &self.input[self.quote + 1..self.end - 1];
+        if memchr::memchr(b'\\', body).is_none() {
+            body == name.as_bytes()
+        } else {
+            self.decode().is_ok_and(|key| key == name)
+        }
+    }
+
+    fn decode(&self) -> CursorResult<Cow<'a, str>> {
+        decode_string(self.input, self.quote, self.end)
+    }
+}
+
+/// Iterator over `(key, value)` pairs of an object
+///
+/// Values are skipped lazily: a value you never look at costs one call into
+/// the skip engine when the iterator moves past it.
+#[derive(Debug)]
+pub struct Fields<'a> {
+    members: Members<'a>,
+}
+
+impl<'a> Fields<'a> {
+    /// Offset just past the closing `}` once iteration has finished
+    #[must_use]
+    pub const fn end_offset(&self) -> Option<usize> {
+        if self.members.done {
+            Some(self.members.pos)
+        } else {
+            None
+        }
+    }
+
+    fn next_key(&mut self) -> CursorResult<Option<Key<'a>>> {
+        let Some(quote) = self.members.advance()? else {
+            return Ok(None);
+        };
+        let byte = byte_at(self.members.input, quote)?;
+        if byte != b'"' {
+            return self.members.fail(CursorError::UnexpectedByte {
+                byte,
+                offset: quote,
+            });
+        }
+        let key_cursor = JsonCursor {
+            input: self.members.input,
+            start: quote,
+            strategy: self.members.strategy,
+        };
+        let end = key_cursor.string_end(quote)?;
+        let colon = skip_whitespace(self.members.input, end);
+        let byte = byte_at(self.members.input, colon)?;
+        if byte != b':' {
+            return self.members.fail(CursorError::UnexpectedByte {
+                byte,
+                offset: colon,
+            });
+        }
+        let value = skip_whitespace(self.members.input, colon + 1);
+        byte_at(self.members.input, value)?;
+        self.members.cursor_at(value);
+        Ok(Some(Key {
+            input: self.members.input,
+            quote,
+            end,
+        }))
+    }
+
+    /// Next member with the offset of its key's opening quote
+    pub(crate) fn next_member(
+        &mut self,
+    ) -> Option<CursorResult<(usize, Cow<'a, str>, JsonCursor<'a>)>> {
+        match self.next_key() {
+            Ok(Some(key)) => Some(key.decode().map(|name| (key.quote, name, self.value()))),
+            Ok(None) => None,
+            Err(e) => {
+                self.members.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Cursor for the value of the key most recently returned by `next_key`
+    const fn value(&self) -> JsonCursor<'a> {
+        self.members
+            .pending
+            .expect("next_key sets the pending value")
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = CursorResult<(Cow<'a, str>, JsonCursor<'a>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_key() {
+            Ok(Some(key)) => Some(key.decode().map(|name| (name, self.value()))),
+            Ok(None) => None,
+            Err(e) => {
+                self.members.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterator over the elements of an array
+#[derive(Debug)]
+pub struct Elements<'a> {
+    members: Members<'a>,
+}
+
+impl Elements<'_> {
+    /// Offset just past the closing `]` once iteration has finished
+    #[must_use]
+    pub const fn end_offset(&self) -> Option<usize> {
+        if self.members.done {
+            Some(self.members.pos)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for Elements<'a> {
+    type Item = CursorResult<JsonCursor<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.members.advance() {
+            Ok(Some(start)) => Some(Ok(self.members.cursor_at(start))),
+            Ok(None) => None,
+            Err(e) => {
+                self.members.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[inline]
+fn skip_whitespace(input: &[u8], mut pos: usize) -> usize {
+    while pos < input.len() && matches!(input[pos], b' ' | b'\t' | b'\n' | b'\r') {
+        pos += 1;
+    }
+    pos
+}
+
+#[inline]
+fn byte_at(input: &[u8], pos: usize) -> CursorResult<u8> {
+    input
+        .get(pos)
+        .copied()
+        .ok_or(CursorError::UnexpectedEnd { offset: pos })
+}
+
+/// End offset of a literal or number starting at `start`
+fn scalar_end(input: &[u8], start: usize) -> CursorResult<usize> {
+    let literal = match input[start] {
+        b't' => Some(&b"true"[..]),
+        b'f' => Some(&b"false"[..]),
+        b'n' => Some(&b"null"[..]),
+        _ => None,
+    };
+    if let Some(literal) = literal {
+        return if input[start..].starts_with(literal) {
+            Ok(start + literal.len())
+        } else {
+            Err(CursorError::UnexpectedByte {
+                byte: input[start],
+                offset: start,
+            })
+        };
+    }
+    let mut end = start;
+    while end < input.len() && matches!(input[end], b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+    {
+        end += 1;
+    }
+    if end == start {
+        Err(CursorError::UnexpectedByte {
+            byte: input[start],
+            offset: start,
+        })
+    } else {
+        Ok(end)
+    }
+}
+
+/// Decode the string spanning `input[quote..end]` (quotes included)
+fn decode_string(input: &[u8], quote: usize, end: usize) -> CursorResult<Cow<'_, str>> {
+    let invalid = CursorError::InvalidString { offset: quote };
+    let body = &input[quote + 1..end - 1];
+    if memchr::memchr(b'\\', body).is_none() {
+        return std::str::from_utf8(body)
+            .map(Cow::Borrowed)
+            .map_err(|_| invalid);
+    }
+    serde_json::from_slice::<String>(&input[quote..end])
+        .map(Cow::Owned)
+        .map_err(|_| invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &[u8] = br#"{"id": 7, "meta": {"request_id": "r-1", "tags": ["a", "b"]}, "ok": true, "score": -1.5e2, "none": null}"#;
+
+    #[test]
+    fn test_find_field_chain() {
+        let doc = JsonCursor::new(DOC);
+        let id = doc
+            .find_field("meta")
+            .unwrap()
+            .find_field("request_id")
+            .unwrap();
+        assert_eq!(id.as_str().unwrap(), "r-1");
+        assert_eq!(doc.find_field("id").unwrap().as_i64().unwrap(), 7);
+        assert_eq!(doc.find_field("id").unwrap().as_u64().unwrap(), 7);
+        assert!(doc.find_field("ok").unwrap().as_bool().unwrap());
+        assert!((doc.find_field("score").unwrap().as_f64().unwrap() + 150.0).abs() < f64::EPSILON);
+        assert!(doc.find_field("none").unwrap().is_null().unwrap());
+    }
+
+    #[test]
+    fn test_find_last_field_with_duplicates() {
+        let doc = JsonCursor::new(br#"{"a": 1, "b": {"c": 0}, "a": 2}"#);
+        assert_eq!(doc.find_field("a").unwrap().as_i64().unwrap(), 1);
+        assert_eq!(doc.find_last_field("a").unwrap().as_i64().unwrap(), 2);
+        assert!(matches!(
+            doc.find_last_field("c"),
+            Err(CursorError::NoSuchField(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_path_and_missing_field() {
+        let doc = JsonCursor::new(DOC);
+        let tags = doc.find_path(&["meta", "tags"]).unwrap();
+        assert_eq!(tags.value_type().unwrap(), JsonType::Array);
+        assert_eq!(
+            doc.find_field("missing").unwrap_err(),
+            CursorError::NoSuchField("missing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_array_iteration_and_index() {
+        let doc = JsonCursor::new(br#"[1, {"x": [2, 3]}, "s", [], {}]"#);
+        let kinds: Vec<JsonType> = doc
+            .elements()
+            .unwrap()
+            .map(|e| e.unwrap().value_type().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                JsonType::Number,
+                JsonType::Object,
+                JsonType::String,
+                JsonType::Array,
+                JsonType::Object
+            ]
+        );
+        assert_eq!(doc.at(2).unwrap().as_str().unwrap(), "s");
+        assert_eq!(
+            doc.at(1)
+                .unwrap()
+                .find_field("x")
+                .unwrap()
+                .at(1)
+                .unwrap()
+                .as_i64()
+                .unwrap(),
+            3
+        );
+        assert_eq!(doc.at(9).unwrap_err(), CursorError::IndexOutOfBounds(9));
+    }
+
+    #[test]
+    fn test_fields_iteration() {
+        let doc = JsonCursor::new(br#"{"a": {"deep": [1, 2]}, "b!": 2}"#);
+        let mut fields = doc.fields().unwrap();
+        let names: Vec<String> = fields.by_ref().map(|f| f.unwrap().0.into_owned()).collect();
+        assert_eq!(names, vec!["a", "b!"]);
+        assert_eq!(fields.end_offset(), Some(doc.raw().unwrap().len()));
+        assert_eq!(doc.find_field("b!").unwrap().as_i64().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_raw_slice_and_escapes() {
+        let doc = JsonCursor::new(br#" {"s": "line\nbreak", "o": {"k": "}"}} "#);
+        assert_eq!(
+            doc.find_field("o").unwrap().raw().unwrap(),
+            br#"{"k": "}"}"#
+        );
+        let s = doc.find_field("s").unwrap().as_str().unwrap();
+        assert!(matches!(s, Cow::Owned(_)));
+        assert_eq!(s, "line\nbreak");
+    }
+
+    #[test]
+    fn test_type_mismatch_and_truncation() {
+        let doc = JsonCursor::new(br#"{"a": 1, "b": [1, 2"#);
+        assert_eq!(
+            doc.find_field("a").unwrap().as_str().unwrap_err(),
+            CursorError::TypeMismatch {
+                expected: JsonType::String,
+                found: JsonType::Number
+            }
+        );
+        assert!(matches!(
+            doc.find_field("b").unwrap().raw(),
+            Err(CursorError::UnexpectedEnd { .. })
+        ));
+        assert!(JsonCursor::new(b"").value_type().is_err());
+    }
+
+    #[test]
+    fn test_as_bool_rejects_non_booleans() {
+        assert!(!JsonCursor::new(b"false").as_bool().unwrap());
+        assert_eq!(
+            JsonCursor::new(b"null").as_bool().unwrap_err(),
+            CursorError::TypeMismatch {
+                expected: JsonType::Bool,
+                found: JsonType::Null
+            }
+        );
+        assert!(matches!(
+            JsonCursor::new(b"fals").as_bool(),
+            Err(CursorError::UnexpectedByte { .. })
+        ));
+        assert!(JsonCursor::new(b"tru").as_bool().is_err());
+    }
+
+    #[test]
+    fn test_strategies_agree() {
+        for strategy in SkipStrategy::all_strategies() {
+            let doc = JsonCursor::with_strategy(DOC, strategy);
+            assert_eq!(
+                doc.find_path(&["meta", "request_id"])
+                    .unwrap()
+                    .as_str()
+                    .unwrap(),
+                "r-1",
+                "strategy {strategy}"
+            );
+            assert!(doc.find_field("ok").unwrap().as_bool().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_extract_with_compiled_schema() {
+        let schema =
+            CompiledSchema::compile(&["meta.request_id".to_string(), "items.id".to_string()])
+                .unwrap();
+        let line = br#"{"skip": {"big": [1, 2, 3]}, "meta": {"request_id": "r-9", "other": 1}, "items": [{"id": 1}, {"id": 2, "x": 0}]}"#;
+        let found = JsonCursor::new(line).extract(&schema).unwrap();
+        let rendered: Vec<(String, String)> = found
+            .iter()
+            .map(|(path, value)| {
+                (
+                    path.clone(),
+                    String::from_utf8(value.raw().unwrap().to_vec()).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                ("meta.request_id".to_string(), "\"r-9\"".to_string()),
+                ("items[0].id".to_string(), "1".to_string()),
+                ("items[1].id".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_with_schema_pattern() {
+        let pattern = SchemaPattern::compile("user.*").unwrap();
+        let line = br#"{"user": {"name": "x", "age": 3}, "user_meta": 1}"#;
+        let found = JsonCursor::new(line).extract(&pattern).unwrap();
+        let paths: Vec<&str> = found.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paths, vec!["user.name", "user.age"]);
+    }
+}
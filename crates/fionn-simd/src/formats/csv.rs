@@ -6,6 +6,9 @@
 //! - Quoted field detection and handling
 //! - Newline detection (handling CRLF and LF)
 //! - Header row detection
+//! - Dialect configuration ([`CsvDialect`]): quote/escape style, comments, trimming, null tokens
+//! - RFC 4180 multi-line quoted fields via a resumable [`CsvRecordScanner`]
+//! - Sample-driven per-column type inference ([`CsvColumnType`])
 //!
 //! # SIMD Strategies
 //!
@@ -15,6 +18,8 @@
 
 use super::{ChunkMask, FormatParser, StructuralPositions};
 use fionn_core::format::FormatKind;
+use serde_json::Value;
+use std::borrow::Cow;
 
 /// CSV SIMD parser
 #[derive(Debug, Clone)]
@@ -122,6 +127,17 @@ impl CsvParser {
         self
     }
 
+    /// Create a parser for a dialect
+    #[must_use]
+    pub const fn with_dialect(dialect: &CsvDialect) -> Self {
+        Self {
+            delimiter: dialect.delimiter,
+            quote_char: dialect.quote,
+            has_header: dialect.has_header,
+            field_count: 0,
+        }
+    }
+
     /// Create a tab-separated parser
     #[must_use]
     pub const fn tsv() -> Self {
@@ -342,6 +358,666 @@ impl CsvParser {
     }
 }
 
+// =============================================================================
+// Dialect
+// =============================================================================
+
+/// How quote characters are escaped inside quoted fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvEscape {
+    /// RFC 4180 style: a quote inside a quoted field is written twice (`""`)
+    #[default]
+    Doubled,
+    /// Backslash style: `\"` for a quote, `\\` for a backslash
+    Backslash,
+}
+
+/// CSV dialect configuration
+///
+/// Describes how records and fields are laid out. Empty unquoted fields are
+/// always read as null; `null_tokens` adds further spellings such as `NULL`
+/// or `\N`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// Field delimiter
+    pub delimiter: u8,
+    /// Quote character
+    pub quote: u8,
+    /// Escape style inside quoted fields
+    pub escape: CsvEscape,
+    /// Whether the first record is a header
+    pub has_header: bool,
+    /// Lines starting with this byte are skipped
+    pub comment_prefix: Option<u8>,
+    /// Trim whitespace around unquoted fields
+    pub trim: bool,
+    /// Unquoted cell values that read as null
+    pub null_tokens: Vec<String>,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvDialect {
+    /// Create the RFC 4180 dialect (comma, double quote, doubled escapes, header)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: CsvEscape::Doubled,
+            has_header: true,
+            comment_prefix: None,
+            trim: false,
+            null_tokens: Vec::new(),
+        }
+    }
+
+    /// Create a tab-separated dialect
+    #[must_use]
+    pub const fn tsv() -> Self {
+        Self::new().with_delimiter(b'\t')
+    }
+
+    /// Set the field delimiter
+    #[must_use]
+    pub const fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character
+    #[must_use]
+    pub const fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Set the escape style
+    #[must_use]
+    pub const fn with_escape(mut self, escape: CsvEscape) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Set whether the first record is a header
+    #[must_use]
+    pub const fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Skip lines starting with the given byte
+    #[must_use]
+    pub const fn with_comment_prefix(mut self, prefix: u8) -> Self {
+        self.comment_prefix = Some(prefix);
+        self
+    }
+
+    /// Trim whitespace around unquoted fields
+    #[must_use]
+    pub const fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set the unquoted tokens that read as null
+    #[must_use]
+    pub fn with_null_tokens<I, S>(mut self, tokens: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_tokens = tokens.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check whether a parsed cell reads as null under this dialect
+    #[must_use]
+    pub fn is_null(&self, cell: &CsvCell) -> bool {
+        !cell.quoted && (cell.value.is_empty() || self.null_tokens.contains(&cell.value))
+    }
+
+    /// Check whether a record is a comment line
+    #[must_use]
+    pub fn is_comment(&self, record: &[u8]) -> bool {
+        self.comment_prefix
+            .is_some_and(|prefix| record.first() == Some(&prefix))
+    }
+
+    /// Split data into records, honouring quoted fields that span lines
+    ///
+    /// Blank lines and comment lines are dropped. Each record excludes its
+    /// line terminator.
+    #[must_use]
+    pub fn split_records<'a>(&self, data: &'a [u8]) -> Vec<&'a [u8]> {
+        let mut records = Vec::new();
+        self.for_each_record(data, &mut Vec::new(), |_, record| records.push(record));
+        records
+    }
+
+    /// Call `f` with the index and bytes of every record in `data`
+    ///
+    /// Same records as [`CsvDialect::split_records`], without collecting
+    /// them; `ends` is scratch space for record boundaries and is cleared
+    /// first, so a reused vector keeps this allocation-free.
+    pub fn for_each_record<'a>(
+        &self,
+        data: &'a [u8],
+        ends: &mut Vec<usize>,
+        mut f: impl FnMut(usize, &'a [u8]),
+    ) {
+        ends.clear();
+        CsvRecordScanner::new(self).scan(data, ends);
+        if ends.last() != Some(&data.len()) {
+            ends.push(data.len());
+        }
+
+        let mut start = 0;
+        let mut index = 0;
+        for &end in ends.iter() {
+            let record = trim_line_end(&data[start..end]);
+            start = end;
+            if record.iter().all(u8::is_ascii_whitespace) || self.is_comment(record) {
+                continue;
+            }
+            f(index, record);
+            index += 1;
+        }
+    }
+
+    /// Parse one record into cells, unquoting and unescaping as configured
+    #[must_use]
+    pub fn parse_record(&self, record: &[u8]) -> Vec<CsvCell> {
+        let mut text = Vec::new();
+        let mut fields = Vec::new();
+        self.parse_record_into(record, &mut text, &mut fields);
+        fields
+            .iter()
+            .map(|field| CsvCell {
+                value: self
+                    .cell_text(field.bytes(&text), field.quoted)
+                    .into_owned(),
+                quoted: field.quoted,
+            })
+            .collect()
+    }
+
+    /// Parse one record into reusable buffers
+    ///
+    /// Unescaped field bytes are written to `text` and each field's span to
+    /// `fields`; both are cleared first. Use [`CsvFieldSpan::bytes`] and
+    /// [`CsvDialect::cell_text`] to read a field back.
+    pub fn parse_record_into(
+        &self,
+        record: &[u8],
+        text: &mut Vec<u8>,
+        fields: &mut Vec<CsvFieldSpan>,
+    ) {
+        let record = trim_line_end(record);
+        text.clear();
+        fields.clear();
+        let mut field_start = 0;
+        let mut quoted = false;
+        let mut in_quote = false;
+        // End of the quoted text, once the closing quote is seen
+        let mut closed_at = None;
+        let mut i = 0;
+
+        while i < record.len() {
+            let byte = record[i];
+            if in_quote {
+                if self.escape == CsvEscape::Backslash && byte == b'\\' && i + 1 < record.len() {
+                    text.push(record[i + 1]);
+                    i += 2;
+                    continue;
+                }
+                if byte == self.quote {
+                    if self.escape == CsvEscape::Doubled && record.get(i + 1) == Some(&self.quote) {
+                        text.push(self.quote);
+                        i += 2;
+                        continue;
+                    }
+                    in_quote = false;
+                    closed_at = Some(text.len());
+                } else {
+                    text.push(byte);
+                }
+            } else if byte == self.delimiter {
+                fields.push(CsvFieldSpan {
+                    start: field_start,
+                    end: field_end(text, closed_at),
+                    quoted,
+                });
+                field_start = text.len();
+                quoted = false;
+                closed_at = None;
+            } else if byte == self.quote && text[field_start..].iter().all(u8::is_ascii_whitespace)
+            {
+                // Opening quote; whitespace before it is insignificant
+                text.truncate(field_start);
+                quoted = true;
+                in_quote = true;
+                closed_at = None;
+            } else {
+                text.push(byte);
+            }
+            i += 1;
+        }
+
+        fields.push(CsvFieldSpan {
+            start: field_start,
+            end: field_end(text, closed_at),
+            quoted,
+        });
+    }
+
+    /// Decode a field's unescaped bytes, trimming unquoted fields as
+    /// configured
+    ///
+    /// Quoted text is returned exactly as written. Borrows from `raw` unless
+    /// it is not valid UTF-8.
+    #[must_use]
+    pub fn cell_text<'a>(&self, raw: &'a [u8], quoted: bool) -> Cow<'a, str> {
+        if !self.trim || quoted {
+            return String::from_utf8_lossy(raw);
+        }
+        match String::from_utf8_lossy(raw) {
+            Cow::Borrowed(text) => Cow::Borrowed(text.trim()),
+            Cow::Owned(text) => Cow::Owned(text.trim().to_string()),
+        }
+    }
+}
+
+/// End of a field's text in the parse buffer
+///
+/// Whitespace spilled after a closing quote (`"a" ,`) is not part of the
+/// field; `closed_at` is where the quoted text ended.
+fn field_end(text: &[u8], closed_at: Option<usize>) -> usize {
+    closed_at.map_or(text.len(), |end| end + text[end..].trim_ascii_end().len())
+}
+
+/// Location of one field in a [`CsvDialect::parse_record_into`] buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvFieldSpan {
+    /// Start offset of the unescaped field bytes
+    pub start: usize,
+    /// End offset of the unescaped field bytes
+    pub end: usize,
+    /// Whether the field was quoted in the source
+    pub quoted: bool,
+}
+
+impl CsvFieldSpan {
+    /// Get the field's unescaped bytes from the parse buffer
+    #[must_use]
+    pub fn bytes(self, text: &[u8]) -> &[u8] {
+        &text[self.start..self.end]
+    }
+}
+
+/// Strip a trailing `\n` / `\r\n` / `\r`
+fn trim_line_end(record: &[u8]) -> &[u8] {
+    let record = record.strip_suffix(b"\n").unwrap_or(record);
+    record.strip_suffix(b"\r").unwrap_or(record)
+}
+
+/// A single parsed CSV cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvCell {
+    /// Unquoted, unescaped cell text
+    pub value: String,
+    /// Whether the cell was quoted in the source
+    pub quoted: bool,
+}
+
+// =============================================================================
+// Record Scanner
+// =============================================================================
+
+/// Resumable record boundary scanner
+///
+/// Carries quote and escape state between calls, so a quoted field holding
+/// newlines is kept in one record even when it straddles two input chunks.
+/// Quotes are read as [`CsvDialect::parse_record_into`] reads them: a quote
+/// opens a quoted field only when nothing but whitespace precedes it in the
+/// field, and is literal text anywhere else. Records end at `\n`, `\r\n` or
+/// a bare `\r`. Comment lines are skipped to their line end without touching
+/// quote state. Uses `memchr` to jump between quote, line end and escape
+/// bytes.
+#[derive(Debug, Clone)]
+pub struct CsvRecordScanner {
+    delimiter: u8,
+    quote: u8,
+    escape: CsvEscape,
+    comment_prefix: Option<u8>,
+    line: LineState,
+    in_quote: bool,
+    /// Whether the current field holds only whitespace so far
+    field_blank: bool,
+    pending: Pending,
+    offset: usize,
+}
+
+/// Where the scanner is relative to the current line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineState {
+    /// Next byte starts a line
+    Start,
+    /// Inside a record line
+    Record,
+    /// Inside a comment line
+    Comment,
+}
+
+/// A byte at the end of the previous chunk whose meaning depends on the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Pending {
+    #[default]
+    None,
+    /// A backslash in a quoted field, escaping the next byte
+    Escape,
+    /// A quote in a quoted field: doubled, or the closing quote
+    Quote,
+    /// A `\r` ending a line, unless a `\n` follows
+    CarriageReturn,
+}
+
+impl CsvRecordScanner {
+    /// Create a scanner for the given dialect
+    #[must_use]
+    pub const fn new(dialect: &CsvDialect) -> Self {
+        Self {
+            delimiter: dialect.delimiter,
+            quote: dialect.quote,
+            escape: dialect.escape,
+            comment_prefix: dialect.comment_prefix,
+            line: LineState::Start,
+            in_quote: false,
+            field_blank: true,
+            pending: Pending::None,
+            offset: 0,
+        }
+    }
+
+    /// Scan the next chunk of input
+    ///
+    /// Pushes the absolute offset just past every record terminator onto
+    /// `ends`. Offsets count from the first byte ever fed to the scanner.
+    /// A `\r` ending the chunk is reported once the next chunk shows no `\n`
+    /// follows it; at end of input, bytes past the last end are the final
+    /// record.
+    pub fn scan(&mut self, chunk: &[u8], ends: &mut Vec<usize>) {
+        let mut i = 0;
+        if let Some(&first) = chunk.first() {
+            match std::mem::take(&mut self.pending) {
+                Pending::None => {}
+                Pending::Escape => {
+                    self.note_quoted(&[first]);
+                    i = 1;
+                }
+                Pending::Quote if first == self.quote => {
+                    self.field_blank = false;
+                    i = 1;
+                }
+                Pending::Quote => self.in_quote = false,
+                // A following `\n` ends the line itself
+                Pending::CarriageReturn if first == b'\n' => {}
+                Pending::CarriageReturn => self.end_line(self.offset, ends),
+            }
+        }
+
+        while i < chunk.len() {
+            if self.line == LineState::Start {
+                self.line = if self.comment_prefix == Some(chunk[i]) {
+                    LineState::Comment
+                } else {
+                    LineState::Record
+                };
+            }
+            let rest = &chunk[i..];
+            let next = if self.line == LineState::Comment {
+                memchr::memchr2(b'\n', b'\r', rest)
+            } else if self.in_quote {
+                match self.escape {
+                    CsvEscape::Doubled => memchr::memchr(self.quote, rest),
+                    CsvEscape::Backslash => memchr::memchr2(self.quote, b'\\', rest),
+                }
+            } else {
+                memchr::memchr3(self.quote, b'\n', b'\r', rest)
+            };
+            let Some(pos) = next else {
+                self.note(rest);
+                break;
+            };
+            let at = i + pos;
+            self.note(&rest[..pos]);
+            i = at + 1;
+
+            match chunk[at] {
+                b'\\' if self.in_quote => match chunk.get(at + 1) {
+                    Some(&escaped) => {
+                        self.note_quoted(&[escaped]);
+                        i = at + 2;
+                    }
+                    None => self.pending = Pending::Escape,
+                },
+                _ if self.in_quote => {
+                    if self.escape == CsvEscape::Doubled {
+                        match chunk.get(at + 1) {
+                            Some(&next) if next == self.quote => {
+                                self.field_blank = false;
+                                i = at + 2;
+                                continue;
+                            }
+                            None => {
+                                self.pending = Pending::Quote;
+                                continue;
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                    self.in_quote = false;
+                }
+                b'\r' => match chunk.get(at + 1) {
+                    Some(b'\n') => {}
+                    Some(_) => self.end_line(self.offset + at + 1, ends),
+                    None => self.pending = Pending::CarriageReturn,
+                },
+                b'\n' => self.end_line(self.offset + at + 1, ends),
+                // A quote opens a quoted field only at its start
+                _ if self.field_blank => self.in_quote = true,
+                _ => {}
+            }
+        }
+
+        self.offset += chunk.len();
+    }
+
+    /// Record the end of a line just before `end`
+    fn end_line(&mut self, end: usize, ends: &mut Vec<usize>) {
+        ends.push(end);
+        self.line = LineState::Start;
+        self.field_blank = true;
+    }
+
+    /// Track field content skipped over in the current state
+    fn note(&mut self, bytes: &[u8]) {
+        match self.line {
+            LineState::Comment => {}
+            _ if self.in_quote => self.note_quoted(bytes),
+            _ => {
+                // Unquoted text: only the field after the last delimiter counts
+                let field = memchr::memrchr(self.delimiter, bytes).map_or(bytes, |d| {
+                    self.field_blank = true;
+                    &bytes[d + 1..]
+                });
+                self.note_quoted(field);
+            }
+        }
+    }
+
+    /// Track field text that is kept as written
+    fn note_quoted(&mut self, bytes: &[u8]) {
+        if self.field_blank {
+            self.field_blank = bytes.iter().all(u8::is_ascii_whitespace);
+        }
+    }
+
+    /// Whether the scanner is currently inside a quoted field
+    #[must_use]
+    pub const fn in_quoted_field(&self) -> bool {
+        self.in_quote
+    }
+
+    /// Reset to the start of a new input
+    pub const fn reset(&mut self) {
+        self.line = LineState::Start;
+        self.in_quote = false;
+        self.field_blank = true;
+        self.pending = Pending::None;
+        self.offset = 0;
+    }
+}
+
+// =============================================================================
+// Column Type Inference
+// =============================================================================
+
+/// Inferred type of a CSV column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CsvColumnType {
+    /// Only null cells seen
+    #[default]
+    Null,
+    /// `true` / `false`
+    Bool,
+    /// 64-bit integer
+    Int,
+    /// Floating point number
+    Float,
+    /// ISO 8601 date or date-time (kept as a string value)
+    Date,
+    /// Anything else
+    String,
+}
+
+impl CsvColumnType {
+    /// Classify a single non-null cell value
+    #[must_use]
+    pub fn of(value: &str) -> Self {
+        if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            Self::Bool
+        } else if is_integer(value) {
+            Self::Int
+        } else if is_float(value) {
+            Self::Float
+        } else if is_iso_date(value) {
+            Self::Date
+        } else {
+            Self::String
+        }
+    }
+
+    /// Combine two observations of the same column
+    #[must_use]
+    pub const fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Null, t) | (t, Self::Null) => t,
+            (Self::Int, Self::Float) | (Self::Float, Self::Int) => Self::Float,
+            (a, b) if a as u8 == b as u8 => a,
+            _ => Self::String,
+        }
+    }
+
+    /// Convert a cell to a JSON value of this column type
+    ///
+    /// Cells that do not fit the column type are kept as strings.
+    #[must_use]
+    pub fn to_value(self, cell: &CsvCell, dialect: &CsvDialect) -> Value {
+        if dialect.is_null(cell) {
+            return Value::Null;
+        }
+        let text = cell.value.as_str();
+        match self {
+            Self::Bool => match text.to_ascii_lowercase().as_str() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                _ => Value::String(text.to_string()),
+            },
+            Self::Int => text
+                .parse::<i64>()
+                .map_or_else(|_| Value::String(text.to_string()), Value::from),
+            Self::Float => text
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map_or_else(|| Value::String(text.to_string()), Value::Number),
+            Self::Null | Self::Date | Self::String => Value::String(text.to_string()),
+        }
+    }
+}
+
+/// Infer column types from a sample of parsed records
+///
+/// At most `sample_rows` records are inspected. Columns beyond the end of a
+/// short record are treated as null for that record.
+#[must_use]
+pub fn infer_column_types(
+    records: &[Vec<CsvCell>],
+    dialect: &CsvDialect,
+    sample_rows: usize,
+) -> Vec<CsvColumnType> {
+    let mut types: Vec<CsvColumnType> = Vec::new();
+    for record in records.iter().take(sample_rows) {
+        if types.len() < record.len() {
+            types.resize(record.len(), CsvColumnType::Null);
+        }
+        for (ty, cell) in types.iter_mut().zip(record) {
+            if !dialect.is_null(cell) {
+                *ty = ty.merge(CsvColumnType::of(&cell.value));
+            }
+        }
+    }
+    types
+}
+
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    // Leading zeros (zip codes, identifiers) stay strings
+    !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && (digits.len() == 1 || !digits.starts_with('0'))
+        && value.parse::<i64>().is_ok()
+}
+
+fn is_float(value: &str) -> bool {
+    let unsigned = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let leading_zero =
+        unsigned.len() > 1 && unsigned.starts_with('0') && unsigned.as_bytes()[1].is_ascii_digit();
+    !leading_zero
+        && value.bytes().any(|b| b.is_ascii_digit())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'))
+        && value.parse::<f64>().is_ok()
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() < 10 {
+        return false;
+    }
+    let date_ok = bytes[..10].iter().enumerate().all(|(i, b)| match i {
+        4 | 7 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    date_ok && (bytes.len() == 10 || matches!(bytes[10], b'T' | b' '))
+}
+
 impl FormatParser for CsvParser {
     type Error = CsvError;
 
@@ -444,4 +1120,197 @@ mod tests {
         let parser = CsvParser::tsv();
         assert_eq!(parser.count_fields(b"a\tb\tc"), 3);
     }
+    #[test]
+    fn test_dialect_parse_record_doubled_quotes() {
+        let dialect = CsvDialect::new();
+        let cells = dialect.parse_record(b"\"a \"\"b\"\"\",,c\r\n");
+        assert_eq!(cells[0].value, "a \"b\"");
+        assert!(cells[0].quoted);
+        assert!(dialect.is_null(&cells[1]));
+        assert_eq!(cells[2].value, "c");
+    }
+
+    #[test]
+    fn test_dialect_parse_record_backslash_and_trim() {
+        let dialect = CsvDialect::new()
+            .with_delimiter(b';')
+            .with_escape(CsvEscape::Backslash)
+            .with_trim(true)
+            .with_null_tokens(["NULL"]);
+        let cells = dialect.parse_record(b" x ;\"say \\\"hi\\\"\";NULL");
+        assert_eq!(cells[0].value, "x");
+        assert_eq!(cells[1].value, "say \"hi\"");
+        assert!(dialect.is_null(&cells[2]));
+    }
+
+    #[test]
+    fn test_split_records_multiline_and_comments() {
+        let dialect = CsvDialect::new().with_comment_prefix(b'#');
+        let data = b"# generated\nid,note\n1,\"line one\nline two\"\n\n2,plain\n";
+        let records = dialect.split_records(data);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], b"1,\"line one\nline two\"");
+    }
+
+    #[test]
+    fn test_parse_record_into_reuses_buffers() {
+        let dialect = CsvDialect::new().with_trim(true);
+        let mut text = Vec::new();
+        let mut fields = Vec::new();
+
+        dialect.parse_record_into(b" a , \"b,\"\"c\"\"\" ,", &mut text, &mut fields);
+        assert_eq!(fields.len(), 3);
+        assert_eq!(
+            dialect.cell_text(fields[0].bytes(&text), fields[0].quoted),
+            "a"
+        );
+        assert_eq!(
+            dialect.cell_text(fields[1].bytes(&text), fields[1].quoted),
+            "b,\"c\""
+        );
+        assert!(fields[1].quoted);
+        assert!(fields[2].bytes(&text).is_empty());
+
+        dialect.parse_record_into(b"x", &mut text, &mut fields);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].bytes(&text), b"x");
+    }
+
+    #[test]
+    fn test_split_records_quote_in_comment() {
+        let dialect = CsvDialect::new().with_comment_prefix(b'#');
+        let data = b"# don't \"x\nid,note\n1,a\n# another \" here\n2,b\n";
+        let records = dialect.split_records(data);
+        assert_eq!(records, vec![&b"id,note"[..], b"1,a", b"2,b"]);
+
+        // Comment state survives chunk boundaries
+        let mut whole = Vec::new();
+        CsvRecordScanner::new(&dialect).scan(data, &mut whole);
+        for chunk_size in 1..data.len() {
+            let mut scanner = CsvRecordScanner::new(&dialect);
+            let mut ends = Vec::new();
+            for chunk in data.chunks(chunk_size) {
+                scanner.scan(chunk, &mut ends);
+            }
+            assert_eq!(ends, whole, "chunk size {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn test_comment_prefix_only_at_line_start() {
+        let dialect = CsvDialect::new().with_comment_prefix(b'#');
+        let records = dialect.split_records(b"a,\"#x\ny\"\nb,c#d\n");
+        assert_eq!(records, vec![&b"a,\"#x\ny\""[..], b"b,c#d"]);
+    }
+
+    #[test]
+    fn test_record_scanner_across_chunks() {
+        let dialect = CsvDialect::new().with_escape(CsvEscape::Backslash);
+        let data = b"a,\"x\\\"\ny\"\nb,\"z\"\nc,d\n";
+        let mut whole = Vec::new();
+        CsvRecordScanner::new(&dialect).scan(data, &mut whole);
+
+        for chunk_size in 1..data.len() {
+            let mut scanner = CsvRecordScanner::new(&dialect);
+            let mut ends = Vec::new();
+            for chunk in data.chunks(chunk_size) {
+                scanner.scan(chunk, &mut ends);
+            }
+            assert_eq!(ends, whole, "chunk size {chunk_size}");
+        }
+        assert_eq!(whole.len(), 3);
+    }
+
+    #[test]
+    fn test_quoted_whitespace_is_kept() {
+        for dialect in [CsvDialect::new(), CsvDialect::new().with_trim(true)] {
+            let cells = dialect.parse_record(b"\"x  \",\"  y\" , \"a\"b ,\"\"");
+            let values: Vec<_> = cells.iter().map(|cell| cell.value.as_str()).collect();
+            assert_eq!(values, ["x  ", "  y", "ab", ""]);
+        }
+    }
+
+    /// Record ends from one scan, checked against every chunking
+    fn scan_in_chunks(dialect: &CsvDialect, data: &[u8]) -> Vec<usize> {
+        let mut whole = Vec::new();
+        CsvRecordScanner::new(dialect).scan(data, &mut whole);
+        for chunk_size in 1..data.len() {
+            let mut scanner = CsvRecordScanner::new(dialect);
+            let mut ends = Vec::new();
+            for chunk in data.chunks(chunk_size) {
+                scanner.scan(chunk, &mut ends);
+            }
+            assert_eq!(ends, whole, "chunk size {chunk_size}");
+        }
+        whole
+    }
+
+    #[test]
+    fn test_record_scanner_agrees_on_mid_field_quotes() {
+        let dialect = CsvDialect::new();
+        // Quotes inside unquoted text are literal and open nothing
+        let data = b"a\"b,c\nd,\"e\"\"\nf\"\n \"g\nh\",i\"j\n";
+        assert_eq!(scan_in_chunks(&dialect, data), [6, 16, 27]);
+        let records = dialect.split_records(data);
+        let cells: Vec<Vec<String>> = records
+            .iter()
+            .map(|record| {
+                dialect
+                    .parse_record(record)
+                    .into_iter()
+                    .map(|cell| cell.value)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            [vec!["a\"b", "c"], vec!["d", "e\"\nf"], vec!["g\nh", "i\"j"]]
+        );
+    }
+
+    #[test]
+    fn test_record_scanner_bare_carriage_returns() {
+        let dialect = CsvDialect::new().with_comment_prefix(b'#');
+        let data = b"a,b\r# note\rc,\"d\re\"\r\nf,g\r";
+        // The final `\r` waits for a possible `\n` in the next chunk
+        assert_eq!(scan_in_chunks(&dialect, data), [4, 11, 20]);
+        assert_eq!(
+            dialect.split_records(data),
+            [&b"a,b"[..], b"c,\"d\re\"", b"f,g"]
+        );
+    }
+
+    #[test]
+    fn test_column_type_of() {
+        assert_eq!(CsvColumnType::of("42"), CsvColumnType::Int);
+        assert_eq!(CsvColumnType::of("007"), CsvColumnType::String);
+        assert_eq!(CsvColumnType::of("-1.5e3"), CsvColumnType::Float);
+        assert_eq!(CsvColumnType::of("TRUE"), CsvColumnType::Bool);
+        assert_eq!(CsvColumnType::of("2024-01-31"), CsvColumnType::Date);
+        assert_eq!(
+            CsvColumnType::of("2024-01-31T10:00:00Z"),
+            CsvColumnType::Date
+        );
+        assert_eq!(CsvColumnType::of("inf"), CsvColumnType::String);
+    }
+
+    #[test]
+    fn test_infer_column_types() {
+        let dialect = CsvDialect::new();
+        let records: Vec<Vec<CsvCell>> = [&b"1,2.5,x"[..], b"2,3,", b",4,y"]
+            .iter()
+            .map(|r| dialect.parse_record(r))
+            .collect();
+        let types = infer_column_types(&records, &dialect, 100);
+        assert_eq!(
+            types,
+            vec![
+                CsvColumnType::Int,
+                CsvColumnType::Float,
+                CsvColumnType::String
+            ]
+        );
+        assert_eq!(types[0].to_value(&records[0][0], &dialect), Value::from(1));
+        assert_eq!(types[0].to_value(&records[2][0], &dialect), Value::Null);
+    }
 }
@@ -35,7 +35,10 @@ pub use yaml::YamlParser;
 pub use toml::TomlParser;
 
 #[cfg(feature = "csv")]
-pub use csv::CsvParser;
+pub use csv::{
+    CsvCell, CsvColumnType, CsvDialect, CsvEscape, CsvFieldSpan, CsvParser, CsvRecordScanner,
+    infer_column_types,
+};
 
 #[cfg(feature = "ison")]
 pub use ison::{IsonParser, IsonlParsedLine};
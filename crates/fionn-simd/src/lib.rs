@@ -6,6 +6,9 @@
 //! - Line boundary detection for JSONL files
 //! - Character classification
 //!
+//! Every SIMD kernel here is chosen through the `fionn_core::simd` kernel
+//! table, so setting `FIONN_SIMD` switches them all at once.
+//!
 //! # Skip Strategies
 //!
 //! The [`skip`] module provides multiple implementations for skipping JSON values:
@@ -14,10 +17,33 @@
 //! - [`LangdaleSkip`] - Langdale-Lemire XOR prefix algorithm
 //! - [`JsonSkiSkip`] - `JSONSki` bracket counting (default)
 //! - [`Avx2Skip`] - AVX2 SIMD acceleration (`x86_64`)
+//! - [`ValidatingSkip`] - Grammar and UTF-8 validation for untrusted input
+//! - [`ResumableSkip`] - Chunk-at-a-time skipping with a fixed buffer
 //!
 //! Use [`SkipStrategy`] for runtime selection of the best strategy.
+//!
+//! # String Unescaping
+//!
+//! [`unescape`] decodes JSON string escapes into a caller's buffer, so tape
+//! builders unescape strings in the same pass that finds them.
+//!
+//! # On-Demand Access
+//!
+//! [`JsonCursor`] reads individual fields straight from the raw bytes,
+//! skipping everything it does not visit with the chosen strategy.
+//! [`Prefilter`] builds on it to test filter predicates against raw JSONL
+//! records before they are parsed.
+//!
+//! # Sidecar Indexes
+//!
+//! [`sidecar`] saves value offsets of a JSON or JSONL file next to it, so
+//! later runs seek straight to the bytes a query needs.
 
+pub mod cursor;
+pub mod predicate;
+pub mod sidecar;
 pub mod skip;
+pub mod unescape;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod x86;
@@ -38,14 +64,26 @@ pub mod formats;
     feature = "toml",
     feature = "csv",
     feature = "ison",
-    feature = "toon"
+    feature = "toon",
+    feature = "hcl",
+    feature = "ini",
+    feature = "properties",
+    feature = "env"
 ))]
 pub mod transform;
 
+use fionn_core::simd::{SimdDispatch, SimdLevel};
+
+// Re-export the on-demand cursor
+pub use cursor::{CursorError, JsonCursor, JsonType, PathSelector};
+pub use predicate::{CompareOp, FilterExpr, Prefilter, Verdict};
+pub use unescape::{UnescapeError, unescape_json_string_into, unescape_json_string_simd};
+
 // Re-export key types from skip module
 pub use skip::{
-    JsonSkiSkip, LangdaleSkip, ParallelSkipper, ScalarSkip, Skip, SkipResult, SkipStrategy,
-    skip_arrays_parallel, skip_objects_parallel, skip_values_parallel,
+    JsonSkiSkip, LangdaleSkip, ParallelSkipper, Progress, ResumableSkip, ScalarSkip, Skip,
+    SkipError, SkipErrorKind, SkipResult, SkipStrategy, ValidatingSkip, skip_arrays_parallel,
+    skip_objects_parallel, skip_values_parallel,
 };
 
 // Re-export SIMD skip implementations
@@ -69,10 +107,30 @@ impl SimdLineSeparator {
     /// If the data doesn't end with a newline, the final position is the end of the data.
     #[must_use]
     pub fn find_line_boundaries(&self, data: &[u8]) -> Vec<usize> {
-        // Use memchr iterator which exploits SIMD for finding byte occurrences
-        let mut boundaries: Vec<usize> = memchr::memchr_iter(b'\n', data)
-            .map(|pos| pos + 1) // Position after the \n
-            .collect();
+        Self::boundaries_with(fionn_core::simd::dispatch().line_split, data)
+    }
+
+    /// Detect line boundaries with the kernel for `level`
+    ///
+    /// The level is lowered to what the CPU supports.
+    #[must_use]
+    pub fn find_line_boundaries_with_level(&self, level: SimdLevel, data: &[u8]) -> Vec<usize> {
+        Self::boundaries_with(SimdDispatch::for_level(level).line_split, data)
+    }
+
+    fn boundaries_with(kernel: SimdLevel, data: &[u8]) -> Vec<usize> {
+        let mut boundaries: Vec<usize> = if kernel == SimdLevel::Scalar {
+            data.iter()
+                .enumerate()
+                .filter(|&(_, &byte)| byte == b'\n')
+                .map(|(pos, _)| pos + 1)
+                .collect()
+        } else {
+            // memchr exploits SIMD for finding byte occurrences
+            memchr::memchr_iter(b'\n', data)
+                .map(|pos| pos + 1) // Position after the \n
+                .collect()
+        };
 
         // If data doesn't end with \n, add the end position
         if !data.is_empty() && data[data.len() - 1] != b'\n' {
@@ -10,9 +10,8 @@ pub mod x86;
 #[cfg(target_arch = "aarch64")]
 pub mod neon;
 
-pub mod dispatch;
 pub mod string_ops;
 
 // Re-exports
-pub use dispatch::SimdDispatch;
+pub use fionn_core::simd::SimdDispatch;
 pub use string_ops::SimdStringOps;
@@ -0,0 +1,410 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Filter predicates evaluated on raw JSON bytes
+//!
+//! A [`FilterExpr`] is the parsed form of a filter such as `status == "error"`,
+//! `latency > 500` or `user.id in [1, 2]`. When its path is a plain field
+//! chain it compiles to a [`Prefilter`], which locates the field with
+//! [`JsonCursor`] and compares the raw literal without building a DOM, so
+//! non-matching JSONL records are rejected at skip speed.
+//!
+//! [`Prefilter::check`] does not validate the bytes it skips, so a malformed
+//! record is only reported [`Verdict::Inconclusive`] when the cursor trips
+//! over it. [`Prefilter::check_validated`] runs a [`ValidatingSkip`] over
+//! every record it decides, so malformed records are never silently kept or
+//! dropped.
+
+use crate::cursor::{CursorResult, JsonCursor, JsonType};
+use crate::skip::{SkipError, ValidatingSkip};
+use serde_json::Value;
+
+/// Comparison operator of a filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `in [..]` - equal to any element of a list
+    In,
+}
+
+/// Infix operators in the order they are searched for; two-character
+/// operators first so `>=` is not read as `>`
+const INFIX_OPS: [(&str, CompareOp); 6] = [
+    (">=", CompareOp::Ge),
+    ("<=", CompareOp::Le),
+    ("!=", CompareOp::Ne),
+    ("==", CompareOp::Eq),
+    (">", CompareOp::Gt),
+    ("<", CompareOp::Lt),
+];
+
+impl CompareOp {
+    /// Apply the operator
+    ///
+    /// Ordering compares numbers numerically and strings lexicographically;
+    /// any other pairing is false.
+    #[must_use]
+    pub fn compare(self, lhs: &Value, rhs: &Value) -> bool {
+        match self {
+            Self::Eq => lhs == rhs,
+            Self::Ne => lhs != rhs,
+            Self::In => rhs.as_array().is_some_and(|items| items.contains(lhs)),
+            Self::Gt | Self::Ge | Self::Lt | Self::Le => {
+                let ordering = match (lhs, rhs) {
+                    (Value::Number(l), Value::Number(r)) => l
+                        .as_f64()
+                        .zip(r.as_f64())
+                        .and_then(|(l, r)| l.partial_cmp(&r)),
+                    (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+                    _ => None,
+                };
+                ordering.is_some_and(|ordering| match self {
+                    Self::Gt => ordering.is_gt(),
+                    Self::Ge => ordering.is_ge(),
+                    Self::Lt => ordering.is_lt(),
+                    _ => ordering.is_le(),
+                })
+            }
+        }
+    }
+}
+
+/// A parsed filter: a path, and optionally an operator and operand
+///
+/// Without an operator the filter tests that the path exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterExpr {
+    /// Query path of the left-hand side
+    pub path: String,
+    /// Operator, or `None` for an existence test
+    pub op: Option<CompareOp>,
+    /// Right-hand side (`Null` for existence tests, an array for `in`)
+    pub operand: Value,
+}
+
+impl FilterExpr {
+    /// Parse `path`, `path OP literal` or `path in [..]`
+    ///
+    /// Literals are `null`, `true`, `false`, numbers, or strings with
+    /// optional single or double quotes.
+    #[must_use]
+    pub fn parse(filter: &str) -> Self {
+        let filter = filter.trim();
+
+        if let Some(pos) = filter.find(" in ")
+            && let Ok(list @ Value::Array(_)) = serde_json::from_str(filter[pos + 4..].trim())
+        {
+            return Self {
+                path: filter[..pos].trim().to_string(),
+                op: Some(CompareOp::In),
+                operand: list,
+            };
+        }
+
+        for (token, op) in INFIX_OPS {
+            if let Some(pos) = filter.find(token) {
+                return Self {
+                    path: filter[..pos].trim().to_string(),
+                    op: Some(op),
+                    operand: parse_literal(filter[pos + token.len()..].trim()),
+                };
+            }
+        }
+
+        Self {
+            path: filter.to_string(),
+            op: None,
+            operand: Value::Null,
+        }
+    }
+
+    /// Evaluate against the value found at the path (`None` if absent)
+    ///
+    /// An absent path fails every filter, including `!=`.
+    #[must_use]
+    pub fn test(&self, found: Option<&Value>) -> bool {
+        match (found, self.op) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(value), Some(op)) => op.compare(value, &self.operand),
+        }
+    }
+}
+
+fn parse_literal(rhs: &str) -> Value {
+    match rhs {
+        "null" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => match (rhs.parse::<i64>(), rhs.parse::<f64>()) {
+            (Ok(n), _) => Value::Number(n.into()),
+            (_, Ok(n)) => serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number),
+            _ => Value::String(rhs.trim_matches('"').trim_matches('\'').to_string()),
+        },
+    }
+}
+
+/// Outcome of checking a record's raw bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The record satisfies the filter
+    Match,
+    /// The record fails the filter
+    Reject,
+    /// The bytes could not be decided; evaluate the parsed record
+    Inconclusive,
+}
+
+/// A [`FilterExpr`] over a plain field chain, compiled for raw-byte checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prefilter {
+    fields: Vec<String>,
+    expr: FilterExpr,
+}
+
+impl Prefilter {
+    /// Compile a filter whose path is a field chain such as `user.id` or
+    /// `$.status`
+    ///
+    /// Returns `None` for paths with indices, wildcards or recursive descent.
+    #[must_use]
+    pub fn compile(expr: &FilterExpr) -> Option<Self> {
+        let path = expr.path.trim_start_matches('$');
+        let path = path.strip_prefix('.').unwrap_or(path);
+        if path.is_empty() || path.contains(['[', ']', '*']) {
+            return None;
+        }
+        let fields: Vec<String> = path.split('.').map(str::to_string).collect();
+        if fields.iter().any(String::is_empty) {
+            return None;
+        }
+        Some(Self {
+            fields,
+            expr: expr.clone(),
+        })
+    }
+
+    /// Compile a filter string; see [`FilterExpr::parse`]
+    #[must_use]
+    pub fn parse(filter: &str) -> Option<Self> {
+        Self::compile(&FilterExpr::parse(filter))
+    }
+
+    /// Check one JSON record without parsing it
+    #[must_use]
+    pub fn check(&self, record: &[u8]) -> Verdict {
+        let raw = match self.locate(record) {
+            Ok(Some(raw)) => raw,
+            Ok(None) => return Verdict::Reject,
+            Err(_) => return Verdict::Inconclusive,
+        };
+        let Some(op) = self.expr.op else {
+            return Verdict::Match;
+        };
+        let decided = match raw.first() {
+            Some(b'{' | b'[') => self.compare_container(op, raw),
+            Some(b'"') if matches!(op, CompareOp::Eq | CompareOp::Ne) && !raw.contains(&b'\\') => {
+                std::str::from_utf8(&raw[1..raw.len() - 1])
+                    .ok()
+                    .map(|s| (self.expr.operand.as_str() == Some(s)) == (op == CompareOp::Eq))
+            }
+            _ => serde_json::from_slice(raw)
+                .ok()
+                .map(|value| op.compare(&value, &self.expr.operand)),
+        };
+        match decided {
+            Some(true) => Verdict::Match,
+            Some(false) => Verdict::Reject,
+            None => Verdict::Inconclusive,
+        }
+    }
+
+    /// Check one JSON record, validating its grammar when the raw check
+    /// decides it
+    ///
+    /// Inconclusive records are not validated; the caller parses them anyway.
+    ///
+    /// # Errors
+    /// Returns the validation error if a decided record is not valid JSON.
+    pub fn check_validated(&self, record: &[u8]) -> Result<Verdict, SkipError> {
+        let verdict = self.check(record);
+        if verdict != Verdict::Inconclusive {
+            ValidatingSkip::new().validate_document(record)?;
+        }
+        Ok(verdict)
+    }
+
+    /// Evaluate against a parsed record
+    #[must_use]
+    pub fn evaluate(&self, record: &Value) -> bool {
+        let found = self
+            .fields
+            .iter()
+            .try_fold(record, |value, field| value.as_object()?.get(field));
+        self.expr.test(found)
+    }
+
+    /// Raw bytes at the field chain; the last of duplicate keys wins, as when
+    /// the record is parsed
+    fn locate<'a>(&self, record: &'a [u8]) -> CursorResult<Option<&'a [u8]>> {
+        let mut cursor = JsonCursor::new(record);
+        for name in &self.fields {
+            if cursor.value_type()? != JsonType::Object {
+                return Ok(None);
+            }
+            let mut fields = cursor.fields()?;
+            let mut found = None;
+            while let Some(member) = fields.next_member() {
+                let (_, key, value) = member?;
+                if key == name.as_str() {
+                    found = Some(value);
+                }
+            }
+            match found {
+                Some(value) => cursor = value,
+                None => return Ok(None),
+            }
+        }
+        cursor.raw().map(Some)
+    }
+
+    /// A container never equals or orders against a scalar operand, so it
+    /// is only parsed when the operand holds a container
+    fn compare_container(&self, op: CompareOp, raw: &[u8]) -> Option<bool> {
+        let is_container = |v: &Value| v.is_object() || v.is_array();
+        let operand = &self.expr.operand;
+        let needs_value = match operand {
+            Value::Array(items) if op == CompareOp::In => items.iter().any(is_container),
+            operand => is_container(operand),
+        };
+        if needs_value {
+            serde_json::from_slice(raw)
+                .ok()
+                .map(|value| op.compare(&value, operand))
+        } else {
+            Some(op == CompareOp::Ne)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// The raw check must agree with evaluating the parsed record
+    fn assert_agrees(filter: &str, records: &[&str]) {
+        let prefilter = Prefilter::parse(filter).unwrap();
+        for record in records {
+            let expected = prefilter.evaluate(&serde_json::from_str(record).unwrap());
+            let verdict = prefilter.check(record.as_bytes());
+            assert_ne!(verdict, Verdict::Inconclusive, "{filter} on {record}");
+            assert_eq!(verdict == Verdict::Match, expected, "{filter} on {record}");
+        }
+    }
+
+    const RECORDS: &[&str] = &[
+        r#"{"status": "error", "latency": 750, "user": {"id": 7}}"#,
+        r#"{"status": "ok", "latency": 20.5, "user": {"id": "7"}}"#,
+        r#"{"status": "error", "latency": -3, "user": [7]}"#,
+        r#"{"status": "ok", "status": "error", "latency": 5e2}"#,
+        r#"{"status": null, "latency": "slow", "user": {"id": {"n": 7}}}"#,
+        r#"{"other": true}"#,
+        "[1, 2]",
+        "42",
+    ];
+
+    // =========================================================================
+    // Parsing
+    // =========================================================================
+
+    #[test]
+    fn test_parse_forms() {
+        let expr = FilterExpr::parse(r#" status == "error" "#);
+        assert_eq!(expr.path, "status");
+        assert_eq!(expr.op, Some(CompareOp::Eq));
+        assert_eq!(expr.operand, json!("error"));
+
+        let expr = FilterExpr::parse("latency >= 1.5");
+        assert_eq!((expr.op, expr.operand), (Some(CompareOp::Ge), json!(1.5)));
+
+        let expr = FilterExpr::parse("user.id in [1, \"two\"]");
+        assert_eq!(expr.path, "user.id");
+        assert_eq!(
+            (expr.op, expr.operand),
+            (Some(CompareOp::In), json!([1, "two"]))
+        );
+
+        let expr = FilterExpr::parse(".user.name");
+        assert_eq!((expr.op, expr.operand), (None, Value::Null));
+    }
+
+    #[test]
+    fn test_compile_only_field_chains() {
+        assert!(Prefilter::parse("$.user.id == 3").is_some());
+        assert!(Prefilter::parse("users[0].id == 3").is_none());
+        assert!(Prefilter::parse("..id == 3").is_none());
+        assert!(Prefilter::parse("user.*").is_none());
+    }
+
+    // =========================================================================
+    // Raw checks agree with full evaluation
+    // =========================================================================
+
+    #[test]
+    fn test_equality() {
+        assert_agrees(r#"status == "error""#, RECORDS);
+        assert_agrees("status != error", RECORDS);
+        assert_agrees("status == null", RECORDS);
+        assert_agrees("user.id == 7", RECORDS);
+        assert_agrees("latency == 500", RECORDS);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert_agrees("latency > 500", RECORDS);
+        assert_agrees("latency <= 20.5", RECORDS);
+        assert_agrees("status < p", RECORDS);
+    }
+
+    #[test]
+    fn test_membership_and_existence() {
+        assert_agrees(r#"user.id in [7, "8"]"#, RECORDS);
+        assert_agrees(r#"user.id in [{"n": 7}]"#, RECORDS);
+        assert_agrees("user.id", RECORDS);
+        assert_agrees("$.other", RECORDS);
+    }
+
+    #[test]
+    fn test_malformed_record_is_inconclusive() {
+        let prefilter = Prefilter::parse("status == ok").unwrap();
+        assert_eq!(prefilter.check(br#"{"status": "ok"#), Verdict::Inconclusive);
+        assert_eq!(prefilter.check(b""), Verdict::Inconclusive);
+    }
+
+    #[test]
+    fn test_check_validated_rejects_malformed_decided_records() {
+        let prefilter = Prefilter::parse("status == ok").unwrap();
+        let malformed = br#"{"status": "ok", "n": [1,, 2]}"#;
+        assert_eq!(prefilter.check(malformed), Verdict::Match);
+        assert!(prefilter.check_validated(malformed).is_err());
+        assert!(prefilter.check_validated(br#"{"x": [1,, 2]}"#).is_err());
+        assert_eq!(
+            prefilter.check_validated(br#"{"status": "ok"}"#),
+            Ok(Verdict::Match)
+        );
+        assert_eq!(
+            prefilter.check_validated(br#"{"status": "ok"#),
+            Ok(Verdict::Inconclusive)
+        );
+    }
+}
@@ -0,0 +1,1074 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Persistent structural index sidecar
+//!
+//! Re-scanning a multi-gigabyte archive from byte 0 on every query is wasted
+//! work when the file has not changed. A [`SidecarIndex`] records where
+//! values live so later runs can seek straight to the bytes they need:
+//!
+//! - [`JsonlIndex`] - byte range of every record in a JSONL file
+//! - [`JsonIndex`] - byte range, type and key hash of every value down to a
+//!   configurable depth of a JSON document
+//!
+//! The index is saved next to the data (`data.json.fidx`) together with a
+//! [`Fingerprint`] of the file, so a stale index is detected rather than
+//! trusted.
+
+use crate::cursor::{CursorError, JsonCursor, JsonType};
+use fionn_core::{PathComponent, parse_simd};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// File extension appended to the data path for the default sidecar location
+pub const SIDECAR_EXTENSION: &str = "fidx";
+
+const MAGIC: &[u8; 8] = b"FIONNIDX";
+const VERSION: u32 = 1;
+
+/// Bytes hashed from each sampled region of the data file
+const SAMPLE_BLOCK: u64 = 64 * 1024;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// Entry kind byte: low bits hold the value type, high bits the flags
+const TYPE_MASK: u8 = 0x0f;
+const FIELD: u8 = 0x10;
+const ELEMENT: u8 = 0x20;
+const EXPANDED: u8 = 0x40;
+
+/// Errors from building, saving or loading an index
+#[derive(Debug)]
+pub enum IndexError {
+    /// Reading or writing a file failed
+    Io(io::Error),
+    /// The data is not valid JSON
+    Parse(CursorError),
+    /// The index file is corrupt or from an incompatible version
+    Format(&'static str),
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Parse(e) => write!(f, "parse error: {e}"),
+            Self::Format(msg) => write!(f, "invalid index file: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for IndexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::Format(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for IndexError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<CursorError> for IndexError {
+    fn from(e: CursorError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Result type for index operations
+pub type IndexResult<T> = Result<T, IndexError>;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &b in bytes {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Hash of an object key as stored in a [`JsonIndex`]
+///
+/// FNV-1a over the decoded key, so it is stable across runs and platforms.
+#[must_use]
+pub fn key_hash(key: &str) -> u64 {
+    fnv1a(FNV_OFFSET, key.as_bytes())
+}
+
+// =============================================================================
+// Fingerprint
+// =============================================================================
+
+/// Identity of a data file at the time it was indexed
+///
+/// A different size or modification time means the file changed. When both
+/// match, a hash of sampled blocks from the start, middle and end is checked
+/// as well, catching rewrites within the timestamp resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// File size in bytes
+    pub size: u64,
+    /// Modification time, seconds since the Unix epoch (0 if unavailable)
+    pub modified_secs: u64,
+    /// Sub-second part of the modification time
+    pub modified_nanos: u32,
+    /// Hash of the sampled content blocks
+    pub hash: u64,
+}
+
+impl Fingerprint {
+    /// Fingerprint the file at `path`
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read.
+    pub fn of_file(path: &Path) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let (modified_secs, modified_nanos) = modified(&metadata);
+        Ok(Self {
+            size: metadata.len(),
+            modified_secs,
+            modified_nanos,
+            hash: sample_hash(&mut file, metadata.len())?,
+        })
+    }
+
+    /// Whether the file at `path` still matches this fingerprint
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read.
+    pub fn matches(&self, path: &Path) -> io::Result<bool> {
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        if metadata.len() != self.size
+            || modified(&metadata) != (self.modified_secs, self.modified_nanos)
+        {
+            return Ok(false);
+        }
+        Ok(sample_hash(&mut file, self.size)? == self.hash)
+    }
+}
+
+fn modified(metadata: &std::fs::Metadata) -> (u64, u32) {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or((0, 0), |d| (d.as_secs(), d.subsec_nanos()))
+}
+
+fn sample_hash<R: Read + Seek>(reader: &mut R, size: u64) -> io::Result<u64> {
+    let mut hash = fnv1a(FNV_OFFSET, &size.to_le_bytes());
+    let mut block = Vec::new();
+    let mut last_end = 0;
+    for offset in [
+        0,
+        (size / 2).saturating_sub(SAMPLE_BLOCK / 2),
+        size.saturating_sub(SAMPLE_BLOCK),
+    ] {
+        let offset = offset.max(last_end);
+        let len = SAMPLE_BLOCK.min(size - offset.min(size));
+        if len == 0 {
+            continue;
+        }
+        reader.seek(SeekFrom::Start(offset))?;
+        block.clear();
+        reader.by_ref().take(len).read_to_end(&mut block)?;
+        hash = fnv1a(hash, &block);
+        last_end = offset + len;
+    }
+    Ok(hash)
+}
+
+// =============================================================================
+// JSON index
+// =============================================================================
+
+/// Location of one value in an indexed JSON document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexEntry {
+    /// Offset of the value's first byte
+    pub start: u64,
+    /// Offset just past the value
+    pub end: u64,
+    /// Key hash for object members, element index for array members
+    key: u64,
+    /// Bytes from the key's opening quote to the value
+    key_len: u32,
+    /// Entries in this value's subtree, excluding itself
+    descendants: u64,
+    kind: u8,
+}
+
+impl IndexEntry {
+    /// Byte range of the value
+    #[must_use]
+    pub const fn range(&self) -> Range<u64> {
+        self.start..self.end
+    }
+
+    /// Type of the value
+    #[must_use]
+    pub const fn value_type(&self) -> JsonType {
+        match self.kind & TYPE_MASK {
+            0 => JsonType::Object,
+            1 => JsonType::Array,
+            2 => JsonType::String,
+            3 => JsonType::Number,
+            4 => JsonType::Bool,
+            _ => JsonType::Null,
+        }
+    }
+
+    /// Key hash if the value is an object member
+    #[must_use]
+    pub const fn key_hash(&self) -> Option<u64> {
+        if self.kind & FIELD != 0 {
+            Some(self.key)
+        } else {
+            None
+        }
+    }
+
+    /// Position in the parent array if the value is an array element
+    #[must_use]
+    pub const fn element_index(&self) -> Option<u64> {
+        if self.kind & ELEMENT != 0 {
+            Some(self.key)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the value's members have entries of their own
+    #[must_use]
+    pub const fn is_expanded(&self) -> bool {
+        self.kind & EXPANDED != 0
+    }
+}
+
+const fn type_code(ty: JsonType) -> u8 {
+    match ty {
+        JsonType::Object => 0,
+        JsonType::Array => 1,
+        JsonType::String => 2,
+        JsonType::Number => 3,
+        JsonType::Bool => 4,
+        JsonType::Null => 5,
+    }
+}
+
+/// Where a path lookup ended in a [`JsonIndex`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved {
+    /// Deepest indexed entry on the path
+    pub entry: usize,
+    /// Components below that entry, to be resolved inside its bytes
+    pub rest: Vec<PathComponent>,
+}
+
+/// Offsets of every value down to a fixed depth of a JSON document
+///
+/// Entries are stored in document order; each records how many entries its
+/// subtree holds, so siblings are found without visiting nested values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonIndex {
+    max_depth: u32,
+    entries: Vec<IndexEntry>,
+}
+
+impl JsonIndex {
+    /// Index `data`, recording values nested up to `max_depth` levels below
+    /// the root
+    ///
+    /// # Errors
+    /// Returns an error if the indexed part of the document is malformed.
+    pub fn build(data: &[u8], max_depth: u32) -> IndexResult<Self> {
+        let mut index = Self {
+            max_depth,
+            entries: Vec::new(),
+        };
+        index.visit(JsonCursor::new(data), 0, 0, 0, 0)?;
+        Ok(index)
+    }
+
+    fn visit(
+        &mut self,
+        cursor: JsonCursor<'_>,
+        depth: u32,
+        member: u8,
+        key: u64,
+        key_len: u32,
+    ) -> IndexResult<()> {
+        let slot = self.entries.len();
+        let ty = cursor.value_type()?;
+        let start = cursor.offset();
+        let mut kind = type_code(ty) | member;
+        self.entries.push(IndexEntry {
+            start: start as u64,
+            end: 0,
+            key,
+            key_len,
+            descendants: 0,
+            kind,
+        });
+
+        let end = match ty {
+            JsonType::Object if depth < self.max_depth => {
+                kind |= EXPANDED;
+                let mut fields = cursor.fields()?;
+                while let Some(member) = fields.next_member() {
+                    let (quote, name, value) = member?;
+                    let key_len = u32::try_from(value.offset() - quote)
+                        .map_err(|_| IndexError::Format("object key too long"))?;
+                    self.visit(value, depth + 1, FIELD, key_hash(&name), key_len)?;
+                }
+                fields.end_offset()
+            }
+            JsonType::Array if depth < self.max_depth => {
+                kind |= EXPANDED;
+                let mut elements = cursor.elements()?;
+                for (position, element) in (0..).zip(elements.by_ref()) {
+                    self.visit(element?, depth + 1, ELEMENT, position, 0)?;
+                }
+                elements.end_offset()
+            }
+            _ => Some(start + cursor.raw()?.len()),
+        };
+
+        let descendants = (self.entries.len() - slot - 1) as u64;
+        let entry = &mut self.entries[slot];
+        entry.end = end.ok_or(CursorError::UnexpectedEnd { offset: start })? as u64;
+        entry.descendants = descendants;
+        entry.kind = kind;
+        Ok(())
+    }
+
+    /// Depth the index was built with
+    #[must_use]
+    pub const fn max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
+    /// All entries in document order; the root is entry 0
+    #[must_use]
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Entry at `index`
+    #[must_use]
+    pub fn entry(&self, index: usize) -> Option<&IndexEntry> {
+        self.entries.get(index)
+    }
+
+    /// Entry numbers of the direct members of an expanded container
+    #[allow(clippy::cast_possible_truncation)] // descendants counts in-memory entries
+    pub fn children(&self, entry: usize) -> impl Iterator<Item = usize> + '_ {
+        let end = self
+            .entries
+            .get(entry)
+            .map_or(entry, |e| entry + 1 + e.descendants as usize);
+        let mut next = entry + 1;
+        std::iter::from_fn(move || {
+            if next >= end {
+                return None;
+            }
+            let current = next;
+            next += 1 + self.entries[current].descendants as usize;
+            Some(current)
+        })
+    }
+
+    /// Member of an expanded object named `name`
+    ///
+    /// Keys are compared by hash, then confirmed by reading them from `source`.
+    /// With duplicate keys the last one wins, as in `serde_json`.
+    ///
+    /// # Errors
+    /// Returns an error if `source` cannot be read.
+    pub fn field<R: Read + Seek>(
+        &self,
+        source: &mut R,
+        entry: usize,
+        name: &str,
+    ) -> io::Result<Option<usize>> {
+        let hash = key_hash(name);
+        let mut found = None;
+        for child in self.children(entry) {
+            let e = &self.entries[child];
+            if e.key_hash() == Some(hash) && read_key(source, e)?.as_deref() == Some(name) {
+                found = Some(child);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Decoded key of an object member entry
+    ///
+    /// # Errors
+    /// Returns an error if `source` cannot be read.
+    pub fn key<R: Read + Seek>(&self, source: &mut R, entry: usize) -> io::Result<Option<String>> {
+        let e = &self.entries[entry];
+        if e.key_hash().is_none() {
+            return Ok(None);
+        }
+        read_key(source, e)
+    }
+
+    /// Element `index` of an expanded array
+    #[must_use]
+    pub fn element(&self, entry: usize, index: usize) -> Option<usize> {
+        self.children(entry).nth(index)
+    }
+
+    /// Follow `path` (e.g. `users[3].name`) as far as the index reaches
+    ///
+    /// Returns `None` if an indexed container shows the path is absent.
+    ///
+    /// # Errors
+    /// Returns an error if `source` cannot be read.
+    pub fn resolve_path<R: Read + Seek>(
+        &self,
+        source: &mut R,
+        path: &str,
+    ) -> io::Result<Option<Resolved>> {
+        if self.entries.is_empty() {
+            return Ok(None);
+        }
+        let components = parse_simd(path);
+        let mut entry = 0;
+        for (i, component) in components.iter().enumerate() {
+            let e = &self.entries[entry];
+            if !e.is_expanded() {
+                return Ok(Some(Resolved {
+                    entry,
+                    rest: components[i..].to_vec(),
+                }));
+            }
+            let next = match (component, e.value_type()) {
+                (PathComponent::Field(name), JsonType::Object) => {
+                    self.field(source, entry, name)?
+                }
+                (PathComponent::ArrayIndex(index), JsonType::Array) => self.element(entry, *index),
+                _ => None,
+            };
+            match next {
+                Some(next) => entry = next,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(Resolved {
+            entry,
+            rest: Vec::new(),
+        }))
+    }
+
+    /// Raw bytes of the value at `path`, reading only the part of `source`
+    /// the index cannot rule out
+    ///
+    /// # Errors
+    /// Returns an error if `source` cannot be read or the bytes read are
+    /// malformed.
+    pub fn resolve_raw<R: Read + Seek>(
+        &self,
+        source: &mut R,
+        path: &str,
+    ) -> IndexResult<Option<Vec<u8>>> {
+        let Some(resolved) = self.resolve_path(source, path)? else {
+            return Ok(None);
+        };
+        let bytes = read_range(source, self.entries[resolved.entry].range())?;
+        let mut cursor = JsonCursor::new(&bytes);
+        for component in &resolved.rest {
+            let next = match component {
+                PathComponent::Field(name) => cursor.find_last_field(name),
+                PathComponent::ArrayIndex(index) => cursor.at(*index),
+            };
+            match next {
+                Ok(next) => cursor = next,
+                Err(
+                    CursorError::NoSuchField(_)
+                    | CursorError::IndexOutOfBounds(_)
+                    | CursorError::TypeMismatch { .. },
+                ) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(Some(cursor.raw()?.to_vec()))
+    }
+
+    /// Raw bytes of an entry's value
+    ///
+    /// # Errors
+    /// Returns an error if `source` cannot be read.
+    pub fn read_raw<R: Read + Seek>(&self, source: &mut R, entry: usize) -> io::Result<Vec<u8>> {
+        read_range(source, self.entries[entry].range())
+    }
+}
+
+/// Read `range` from `source`
+///
+/// # Errors
+/// Returns an error if the range cannot be read in full.
+pub fn read_range<R: Read + Seek>(source: &mut R, range: Range<u64>) -> io::Result<Vec<u8>> {
+    source.seek(SeekFrom::Start(range.start))?;
+    let len = usize::try_from(range.end - range.start)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "range too large"))?;
+    let mut bytes = vec![0; len];
+    source.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Decoded key of an object member
+fn read_key<R: Read + Seek>(source: &mut R, entry: &IndexEntry) -> io::Result<Option<String>> {
+    let bytes = read_range(source, entry.start - u64::from(entry.key_len)..entry.start)?;
+    let Some(close) = bytes.iter().rposition(|&b| b == b'"') else {
+        return Ok(None);
+    };
+    Ok(serde_json::from_slice(&bytes[..=close]).ok())
+}
+
+// =============================================================================
+// JSONL index
+// =============================================================================
+
+/// Byte ranges of the records of a JSONL file
+///
+/// Blank lines are not records; ranges exclude the line terminator.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonlIndex {
+    records: Vec<(u64, u64)>,
+}
+
+impl JsonlIndex {
+    /// Index the records read from `reader` with a fixed-size buffer
+    ///
+    /// # Errors
+    /// Returns an error if `reader` fails.
+    pub fn build<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut records = Vec::new();
+        let mut buf = vec![0u8; 256 * 1024];
+        let mut offset = 0u64;
+        // First and last non-whitespace offsets of the current line
+        let mut content: Option<(u64, u64)> = None;
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let mut pos = 0;
+            while pos < n {
+                let chunk = &buf[pos..n];
+                let newline = memchr::memchr(b'\n', chunk);
+                let line = &chunk[..newline.unwrap_or(chunk.len())];
+                let base = offset + pos as u64;
+                if let Some(first) = line.iter().position(|b| !b.is_ascii_whitespace()) {
+                    let last = line
+                        .iter()
+                        .rposition(|b| !b.is_ascii_whitespace())
+                        .unwrap_or(first);
+                    let (start, _) = content.unwrap_or((base + first as u64, 0));
+                    content = Some((start, base + last as u64 + 1));
+                }
+                match newline {
+                    Some(p) => {
+                        if let Some(record) = content.take() {
+                            records.push(record);
+                        }
+                        pos += p + 1;
+                    }
+                    None => pos = n,
+                }
+            }
+            offset += n as u64;
+        }
+        if let Some(record) = content {
+            records.push(record);
+        }
+        Ok(Self { records })
+    }
+
+    /// Number of records
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the file holds no records
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Byte range of record `index`
+    #[must_use]
+    pub fn record(&self, index: usize) -> Option<Range<u64>> {
+        self.records.get(index).map(|&(start, end)| start..end)
+    }
+
+    /// Byte ranges of all records
+    pub fn records(&self) -> impl Iterator<Item = Range<u64>> + '_ {
+        self.records.iter().map(|&(start, end)| start..end)
+    }
+}
+
+// =============================================================================
+// Sidecar file
+// =============================================================================
+
+/// Index payload for the supported layouts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexData {
+    /// A single JSON document
+    Json(JsonIndex),
+    /// One JSON document per line
+    Jsonl(JsonlIndex),
+}
+
+/// An index plus the fingerprint of the file it describes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarIndex {
+    /// Identity of the indexed file
+    pub fingerprint: Fingerprint,
+    /// The index itself
+    pub data: IndexData,
+}
+
+impl SidecarIndex {
+    /// Default sidecar location: the data path with `.fidx` appended
+    #[must_use]
+    pub fn default_path(data_path: &Path) -> PathBuf {
+        let mut path = data_path.as_os_str().to_owned();
+        path.push(".");
+        path.push(SIDECAR_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// Build a JSON index for the file at `path`
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is malformed.
+    pub fn build_json(path: &Path, max_depth: u32) -> IndexResult<Self> {
+        let fingerprint = Fingerprint::of_file(path)?;
+        let data = std::fs::read(path)?;
+        Ok(Self {
+            fingerprint,
+            data: IndexData::Json(JsonIndex::build(&data, max_depth)?),
+        })
+    }
+
+    /// Build a JSONL index for the file at `path`, streaming it
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read.
+    pub fn build_jsonl(path: &Path) -> IndexResult<Self> {
+        let fingerprint = Fingerprint::of_file(path)?;
+        Ok(Self {
+            fingerprint,
+            data: IndexData::Jsonl(JsonlIndex::build(File::open(path)?)?),
+        })
+    }
+
+    /// Whether the index still describes the file at `data_path`
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read.
+    pub fn is_fresh(&self, data_path: &Path) -> io::Result<bool> {
+        self.fingerprint.matches(data_path)
+    }
+
+    /// Save to `path`
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Load from `path`
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid index.
+    pub fn load(path: &Path) -> IndexResult<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Serialize in the sidecar format
+    ///
+    /// # Errors
+    /// Returns an error if `writer` fails.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let w = &mut writer;
+        w.write_all(MAGIC)?;
+        w.write_all(&VERSION.to_le_bytes())?;
+        let fp = &self.fingerprint;
+        for value in [fp.size, fp.modified_secs] {
+            w.write_all(&value.to_le_bytes())?;
+        }
+        w.write_all(&fp.modified_nanos.to_le_bytes())?;
+        w.write_all(&fp.hash.to_le_bytes())?;
+        match &self.data {
+            IndexData::Json(index) => {
+                w.write_all(&[0])?;
+                w.write_all(&index.max_depth.to_le_bytes())?;
+                w.write_all(&(index.entries.len() as u64).to_le_bytes())?;
+                for e in &index.entries {
+                    for value in [e.start, e.end, e.key, e.descendants] {
+                        w.write_all(&value.to_le_bytes())?;
+                    }
+                    w.write_all(&e.key_len.to_le_bytes())?;
+                    w.write_all(&[e.kind])?;
+                }
+            }
+            IndexData::Jsonl(index) => {
+                w.write_all(&[1])?;
+                w.write_all(&(index.records.len() as u64).to_le_bytes())?;
+                for &(start, end) in &index.records {
+                    w.write_all(&start.to_le_bytes())?;
+                    w.write_all(&end.to_le_bytes())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserialize from the sidecar format
+    ///
+    /// # Errors
+    /// Returns an error if `reader` fails or the data is not a valid index.
+    pub fn read_from<R: Read>(mut reader: R) -> IndexResult<Self> {
+        let r = &mut reader;
+        let mut magic = [0; 8];
+        read_exact(r, &mut magic)?;
+        if &magic != MAGIC {
+            return Err(IndexError::Format("not a fionn index"));
+        }
+        if read_u32(r)? != VERSION {
+            return Err(IndexError::Format("unsupported index version"));
+        }
+        let fingerprint = Fingerprint {
+            size: read_u64(r)?,
+            modified_secs: read_u64(r)?,
+            modified_nanos: read_u32(r)?,
+            hash: read_u64(r)?,
+        };
+        let data = match read_u8(r)? {
+            0 => {
+                let max_depth = read_u32(r)?;
+                let count = read_u64(r)?;
+                let mut entries = Vec::new();
+                for _ in 0..count {
+                    let (start, end, key, descendants) =
+                        (read_u64(r)?, read_u64(r)?, read_u64(r)?, read_u64(r)?);
+                    entries.push(IndexEntry {
+                        start,
+                        end,
+                        key,
+                        key_len: read_u32(r)?,
+                        descendants,
+                        kind: read_u8(r)?,
+                    });
+                }
+                // Ranges are read back with a buffer of their length, so
+                // none may reach past the indexed file
+                let size = fingerprint.size;
+                if entries.iter().enumerate().any(|(i, e)| {
+                    e.start > e.end
+                        || e.end > size
+                        || u64::from(e.key_len) > e.start
+                        || e.descendants >= (entries.len() - i) as u64
+                }) {
+                    return Err(IndexError::Format("inconsistent entries"));
+                }
+                IndexData::Json(JsonIndex { max_depth, entries })
+            }
+            1 => {
+                let count = read_u64(r)?;
+                let mut records = Vec::new();
+                for _ in 0..count {
+                    records.push((read_u64(r)?, read_u64(r)?));
+                }
+                if records
+                    .iter()
+                    .any(|&(start, end)| start > end || end > fingerprint.size)
+                {
+                    return Err(IndexError::Format("inconsistent records"));
+                }
+                IndexData::Jsonl(JsonlIndex { records })
+            }
+            _ => return Err(IndexError::Format("unknown index kind")),
+        };
+        Ok(Self { fingerprint, data })
+    }
+}
+
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> IndexResult<()> {
+    reader.read_exact(buf).map_err(|e| {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            IndexError::Format("truncated index")
+        } else {
+            IndexError::Io(e)
+        }
+    })
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> IndexResult<u8> {
+    let mut buf = [0; 1];
+    read_exact(reader, &mut buf)?;
+    Ok(buf[0])
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> IndexResult<u32> {
+    let mut buf = [0; 4];
+    read_exact(reader, &mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> IndexResult<u64> {
+    let mut buf = [0; 8];
+    read_exact(reader, &mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const DOC: &str = r#"{
+        "meta": {"version": 2, "tags": ["a", "b"]},
+        "users": [
+            {"id": 1, "name": "ada", "roles": ["admin"]},
+            {"id": 2, "n\u0061me": "bob", "name": "second"}
+        ],
+        "dup": 1, "dup": 2
+    }"#;
+
+    fn raw(index: &JsonIndex, path: &str) -> Option<String> {
+        index
+            .resolve_raw(&mut Cursor::new(DOC.as_bytes()), path)
+            .unwrap()
+            .map(|bytes| String::from_utf8(bytes).unwrap())
+    }
+
+    // =========================================================================
+    // JSON index
+    // =========================================================================
+
+    #[test]
+    fn test_entries_cover_values_to_depth() {
+        let index = JsonIndex::build(DOC.as_bytes(), 2).unwrap();
+        let root = &index.entries()[0];
+        assert_eq!(root.range(), 0..DOC.len() as u64);
+        assert!(root.is_expanded());
+        assert_eq!(index.children(0).count(), 4);
+
+        let users = index
+            .field(&mut Cursor::new(DOC), 0, "users")
+            .unwrap()
+            .unwrap();
+        assert_eq!(index.entries()[users].value_type(), JsonType::Array);
+        let second = index.element(users, 1).unwrap();
+        assert_eq!(index.entries()[second].element_index(), Some(1));
+        // Depth 2 values are recorded but not expanded
+        assert!(!index.entries()[second].is_expanded());
+        assert_eq!(index.children(second).count(), 0);
+    }
+
+    #[test]
+    fn test_resolve_within_and_beyond_depth() {
+        for depth in 0..4 {
+            let index = JsonIndex::build(DOC.as_bytes(), depth).unwrap();
+            assert_eq!(
+                raw(&index, "meta.version").as_deref(),
+                Some("2"),
+                "depth {depth}"
+            );
+            assert_eq!(raw(&index, "users[1].name").as_deref(), Some(r#""second""#));
+            assert_eq!(raw(&index, "dup").as_deref(), Some("2"));
+            assert_eq!(
+                raw(&index, "users[0].roles[0]").as_deref(),
+                Some(r#""admin""#)
+            );
+            assert_eq!(raw(&index, "users[5]"), None);
+            assert_eq!(raw(&index, "missing.field"), None);
+        }
+    }
+
+    #[test]
+    fn test_resolve_path_stops_at_index_depth() {
+        let index = JsonIndex::build(DOC.as_bytes(), 1).unwrap();
+        let resolved = index
+            .resolve_path(&mut Cursor::new(DOC), "users[1].name")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            index.entries()[resolved.entry].value_type(),
+            JsonType::Array
+        );
+        assert_eq!(
+            resolved.rest,
+            vec![
+                PathComponent::ArrayIndex(1),
+                PathComponent::Field("name".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_verifies_escaped_and_duplicate_keys() {
+        let index = JsonIndex::build(DOC.as_bytes(), 3).unwrap();
+        let mut source = Cursor::new(DOC);
+        let dup = index.field(&mut source, 0, "dup").unwrap().unwrap();
+        assert_eq!(index.read_raw(&mut source, dup).unwrap(), b"2");
+
+        let users = index.field(&mut source, 0, "users").unwrap().unwrap();
+        let bob = index.element(users, 1).unwrap();
+        // The escaped spelling of `name` matches, and the later duplicate wins
+        let name = index.field(&mut source, bob, "name").unwrap().unwrap();
+        assert_eq!(index.read_raw(&mut source, name).unwrap(), br#""second""#);
+    }
+
+    #[test]
+    fn test_scalar_root() {
+        let index = JsonIndex::build(b"  42 ", 3).unwrap();
+        assert_eq!(index.entries().len(), 1);
+        assert_eq!(index.entries()[0].range(), 2..4);
+    }
+
+    #[test]
+    fn test_malformed_document() {
+        assert!(matches!(
+            JsonIndex::build(br#"{"a": [1, 2"#, 3),
+            Err(IndexError::Parse(_))
+        ));
+    }
+
+    // =========================================================================
+    // JSONL index
+    // =========================================================================
+
+    #[test]
+    fn test_jsonl_records() {
+        let data = "{\"a\":1}\n\n  {\"a\":2}  \r\n{\"a\":3}";
+        let index = JsonlIndex::build(data.as_bytes()).unwrap();
+        assert_eq!(index.len(), 3);
+        let records: Vec<&str> = index
+            .records()
+            .map(|r| &data[usize::try_from(r.start).unwrap()..usize::try_from(r.end).unwrap()])
+            .collect();
+        assert_eq!(records, [r#"{"a":1}"#, r#"{"a":2}"#, r#"{"a":3}"#]);
+    }
+
+    #[test]
+    fn test_jsonl_lines_spanning_reads() {
+        let line = format!("{{\"pad\": \"{}\"}}", "x".repeat(300_000));
+        let data = format!("{line}\n{line}\n");
+        let index = JsonlIndex::build(data.as_bytes()).unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index.record(1),
+            Some(line.len() as u64 + 1..2 * line.len() as u64 + 1)
+        );
+    }
+
+    // =========================================================================
+    // Sidecar file
+    // =========================================================================
+
+    #[test]
+    fn test_round_trip() {
+        let fingerprint = Fingerprint {
+            size: DOC.len() as u64,
+            modified_secs: 20,
+            modified_nanos: 30,
+            hash: 40,
+        };
+        for data in [
+            IndexData::Json(JsonIndex::build(DOC.as_bytes(), 2).unwrap()),
+            IndexData::Jsonl(JsonlIndex::build(&b"1\n2\n"[..]).unwrap()),
+        ] {
+            let sidecar = SidecarIndex { fingerprint, data };
+            let mut bytes = Vec::new();
+            sidecar.write_to(&mut bytes).unwrap();
+            assert_eq!(SidecarIndex::read_from(&bytes[..]).unwrap(), sidecar);
+            assert!(matches!(
+                SidecarIndex::read_from(&bytes[..bytes.len() - 1]),
+                Err(IndexError::Format(_))
+            ));
+        }
+        assert!(matches!(
+            SidecarIndex::read_from(&b"NOTANIDX"[..]),
+            Err(IndexError::Format(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_ranges_past_the_indexed_file() {
+        let fingerprint = Fingerprint {
+            size: 3,
+            modified_secs: 0,
+            modified_nanos: 0,
+            hash: 0,
+        };
+        for data in [
+            IndexData::Json(JsonIndex::build(b"[1, 2]", 2).unwrap()),
+            IndexData::Jsonl(JsonlIndex::build(&b"1\n22\n"[..]).unwrap()),
+        ] {
+            let mut bytes = Vec::new();
+            SidecarIndex { fingerprint, data }
+                .write_to(&mut bytes)
+                .unwrap();
+            assert!(matches!(
+                SidecarIndex::read_from(&bytes[..]),
+                Err(IndexError::Format(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_staleness() {
+        let dir = std::env::temp_dir().join(format!("fionn-sidecar-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let data_path = dir.join("data.json");
+        std::fs::write(&data_path, DOC).unwrap();
+
+        let sidecar = SidecarIndex::build_json(&data_path, 2).unwrap();
+        let index_path = SidecarIndex::default_path(&data_path);
+        assert_eq!(index_path, dir.join("data.json.fidx"));
+        sidecar.save(&index_path).unwrap();
+        let loaded = SidecarIndex::load(&index_path).unwrap();
+        assert!(loaded.is_fresh(&data_path).unwrap());
+
+        // Same content, different timestamp
+        let mut touched = loaded.clone();
+        touched.fingerprint.modified_secs ^= 1;
+        assert!(!touched.is_fresh(&data_path).unwrap());
+
+        // Same size and timestamp, different sampled content
+        let mut rewritten = loaded.clone();
+        rewritten.fingerprint.hash ^= 1;
+        assert!(!rewritten.is_fresh(&data_path).unwrap());
+
+        // Same size, different content and timestamp
+        std::fs::write(&data_path, DOC.replace("ada", "eve")).unwrap();
+        assert!(!touched.is_fresh(&data_path).unwrap());
+
+        // Different size
+        std::fs::write(&data_path, "[]").unwrap();
+        assert!(!loaded.is_fresh(&data_path).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
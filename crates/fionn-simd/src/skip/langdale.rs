@@ -787,7 +787,7 @@ mod tests {
         let skip = LangdaleSkip::new();
         // Create object that is exactly 64 bytes
         let padding = "a".repeat(50);
-        let json = format!("\"{padding}\"}}",);
+        let json = format!("\"{padding}\"}}");
 
         let result = skip.skip_object(json.as_bytes());
         assert!(result.is_some());
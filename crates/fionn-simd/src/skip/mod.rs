@@ -7,6 +7,9 @@
 //! Scalar implementations are in this module. SIMD implementations (AVX2, NEON)
 //! are in the unified `simd` module for architecture-specific acceleration.
 //!
+//! All of these only balance brackets and strings. For untrusted input,
+//! [`ValidatingSkip`] also checks the JSON grammar and string UTF-8.
+//!
 //! # Parallel Processing
 //! For processing multiple documents, use `skip_batch_parallel` which distributes
 //! work across all available CPU cores using rayon.
@@ -14,14 +17,18 @@
 mod arena_jsonski;
 mod jsonski;
 mod langdale;
+mod resumable;
 mod scalar;
+mod validating;
 
 use rayon::prelude::*;
 
 pub use arena_jsonski::ArenaJsonSkiSkip;
 pub use jsonski::JsonSkiSkip;
 pub use langdale::LangdaleSkip;
+pub use resumable::{Progress, ResumableSkip};
 pub use scalar::ScalarSkip;
+pub use validating::{SkipError, SkipErrorKind, ValidatingSkip};
 
 // Re-export SIMD implementations from the x86 module
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -64,6 +71,8 @@ pub enum SkipStrategy {
     /// AVX2 SIMD (`x86_64` only)
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     Avx2,
+    /// Grammar and UTF-8 validation (opt-in, for untrusted input)
+    Validating,
 }
 
 impl std::fmt::Display for SkipStrategy {
@@ -74,11 +83,45 @@ impl std::fmt::Display for SkipStrategy {
             Self::JsonSki => "JsonSki",
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             Self::Avx2 => "Avx2",
+            Self::Validating => "Validating",
         };
         write!(f, "{name}")
     }
 }
 
+/// Forward a [`Skip`] call to the strategy's skipper without boxing it
+macro_rules! dispatch_skip {
+    ($strategy:expr, $method:ident, $input:expr) => {
+        match $strategy {
+            SkipStrategy::Scalar => ScalarSkip.$method($input),
+            SkipStrategy::Langdale => LangdaleSkip::new().$method($input),
+            SkipStrategy::JsonSki => JsonSkiSkip::new().$method($input),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SkipStrategy::Avx2 => Skip::$method(&Avx2Skip::new(), $input),
+            SkipStrategy::Validating => ValidatingSkip::new().$method($input),
+        }
+    };
+}
+
+/// Static dispatch for hot paths that skip many values with one strategy
+impl Skip for SkipStrategy {
+    fn skip_object(&self, input: &[u8]) -> Option<SkipResult> {
+        dispatch_skip!(self, skip_object, input)
+    }
+
+    fn skip_array(&self, input: &[u8]) -> Option<SkipResult> {
+        dispatch_skip!(self, skip_array, input)
+    }
+
+    fn skip_string(&self, input: &[u8]) -> Option<SkipResult> {
+        dispatch_skip!(self, skip_string, input)
+    }
+
+    fn skip_value(&self, input: &[u8]) -> Option<SkipResult> {
+        dispatch_skip!(self, skip_value, input)
+    }
+}
+
 impl SkipStrategy {
     /// Create a skipper for this strategy
     #[must_use]
@@ -89,10 +132,14 @@ impl SkipStrategy {
             Self::JsonSki => Box::new(JsonSkiSkip::new()),
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             Self::Avx2 => Box::new(Avx2Skip::new()),
+            Self::Validating => Box::new(ValidatingSkip::new()),
         }
     }
 
     /// Get the best available SIMD strategy for this platform
+    ///
+    /// Follows the process-wide kernel table, so `FIONN_SIMD=scalar`
+    /// selects [`SkipStrategy::JsonSki`].
     #[must_use]
     pub fn best_simd() -> Self {
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -105,6 +152,9 @@ impl SkipStrategy {
     }
 
     /// Get all available strategies for benchmarking
+    ///
+    /// [`SkipStrategy::Validating`] is excluded: it does more work than the
+    /// others and is not a drop-in speed comparison.
     #[must_use]
     pub fn all_strategies() -> Vec<Self> {
         // Mutable on x86/x86_64 where Avx2 may be pushed
@@ -387,6 +437,14 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_skip_strategy_skipper_validating() {
+        let skipper = SkipStrategy::Validating.skipper();
+        assert!(skipper.skip_object(br#""a": [1]}"#).is_some());
+        assert!(skipper.skip_object(br#""a": [1,]}"#).is_none());
+        assert_eq!(SkipStrategy::Validating.to_string(), "Validating");
+    }
+
     #[test]
     fn test_skip_strategy_best_simd() {
         let strategy = SkipStrategy::best_simd();
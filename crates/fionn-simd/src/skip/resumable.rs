@@ -0,0 +1,486 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Resumable skip across chunk boundaries
+//!
+//! The [`Skip`](super::Skip) implementations need the whole value in one
+//! slice. [`ResumableSkip`] instead carries in-string, escape and depth state
+//! between calls, so a value larger than any read buffer can be skipped by
+//! feeding it one chunk at a time. Scanning uses `memchr`'s SIMD searches.
+
+use std::io::{self, BufRead};
+
+use super::{SkipError, SkipErrorKind};
+
+/// Outcome of feeding one chunk to a [`ResumableSkip`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// The whole chunk belongs to the value; feed the next one
+    NeedMore,
+    /// The value ended after this many bytes of the chunk
+    Done(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Waiting for the first non-whitespace byte of a value
+    Start,
+    /// Inside an object or array, counting `open`/`close` brackets
+    Container { open: u8, close: u8 },
+    /// Inside a top-level string
+    String,
+    /// Inside a literal or number, which ends at the first delimiter
+    Scalar,
+    /// Inside a raw field, which ends before either byte
+    Delimited { first: u8, second: u8 },
+    /// The value has ended
+    Finished,
+}
+
+/// Skip state that survives chunk boundaries
+///
+/// ```
+/// use fionn_simd::skip::{Progress, ResumableSkip};
+///
+/// let mut skip = ResumableSkip::value();
+/// assert_eq!(skip.feed(br#"{"big": [1, 2, "]"#)?, Progress::NeedMore);
+/// assert_eq!(skip.feed(br#"}"]}, "next": 1}"#)?, Progress::Done(4));
+/// assert_eq!(skip.total_consumed(), 21);
+/// # Ok::<(), fionn_simd::skip::SkipError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResumableSkip {
+    mode: Mode,
+    depth: usize,
+    in_string: bool,
+    /// A backslash ended the previous chunk
+    escaped: bool,
+    has_escapes: bool,
+    consumed: u64,
+}
+
+impl Default for ResumableSkip {
+    fn default() -> Self {
+        Self::value()
+    }
+}
+
+impl ResumableSkip {
+    const fn with_mode(mode: Mode, depth: usize) -> Self {
+        Self {
+            mode,
+            depth,
+            in_string: false,
+            escaped: false,
+            has_escapes: false,
+            consumed: 0,
+        }
+    }
+
+    /// Skip any value, starting before optional leading whitespace
+    #[must_use]
+    pub const fn value() -> Self {
+        Self::with_mode(Mode::Start, 0)
+    }
+
+    /// Skip an object whose opening `{` was already consumed
+    #[must_use]
+    pub const fn object() -> Self {
+        Self::with_mode(
+            Mode::Container {
+                open: b'{',
+                close: b'}',
+            },
+            1,
+        )
+    }
+
+    /// Skip an array whose opening `[` was already consumed
+    #[must_use]
+    pub const fn array() -> Self {
+        Self::with_mode(
+            Mode::Container {
+                open: b'[',
+                close: b']',
+            },
+            1,
+        )
+    }
+
+    /// Skip a string whose opening `"` was already consumed
+    #[must_use]
+    pub const fn string() -> Self {
+        Self::with_mode(Mode::String, 0)
+    }
+
+    /// Skip raw bytes up to, not including, `first` or `second`
+    ///
+    /// For fields of delimiter-separated lines such as ISONL, e.g.
+    /// `ResumableSkip::until(b'|', b'\n')`.
+    #[must_use]
+    pub const fn until(first: u8, second: u8) -> Self {
+        Self::with_mode(Mode::Delimited { first, second }, 0)
+    }
+
+    /// Feed the next chunk of input
+    ///
+    /// After [`Progress::Done`], further calls return `Done(0)`.
+    ///
+    /// # Errors
+    /// Returns [`SkipErrorKind::ExpectedValue`] if a [`value`](Self::value)
+    /// skip meets a byte that cannot start a value; the offset counts from
+    /// the first byte fed.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Progress, SkipError> {
+        let mut i = 0;
+        loop {
+            let step = match self.mode {
+                Mode::Finished => return Ok(Progress::Done(0)),
+                Mode::Start => self.start(chunk, &mut i)?,
+                Mode::Container { open, close } => {
+                    if self.in_string {
+                        self.scan_string(chunk, &mut i);
+                        None
+                    } else {
+                        self.scan_container(chunk, &mut i, open, close)
+                    }
+                }
+                Mode::String => {
+                    self.scan_string(chunk, &mut i);
+                    (!self.in_string).then_some(i)
+                }
+                Mode::Scalar => chunk[i..]
+                    .iter()
+                    .position(|b| !is_scalar_byte(*b))
+                    .map(|p| i + p)
+                    .or_else(|| {
+                        i = chunk.len();
+                        None
+                    }),
+                Mode::Delimited { first, second } => memchr::memchr2(first, second, &chunk[i..])
+                    .map(|p| i + p)
+                    .or_else(|| {
+                        i = chunk.len();
+                        None
+                    }),
+            };
+            if let Some(end) = step {
+                self.mode = Mode::Finished;
+                self.consumed += end as u64;
+                return Ok(Progress::Done(end));
+            }
+            if i >= chunk.len() {
+                self.consumed += chunk.len() as u64;
+                return Ok(Progress::NeedMore);
+            }
+        }
+    }
+
+    /// Signal end of input
+    ///
+    /// A literal, number or raw field has no closing delimiter, so it only
+    /// completes when the input ends. Returns the total length if the value
+    /// is complete.
+    pub const fn finish(&mut self) -> Option<u64> {
+        match self.mode {
+            Mode::Scalar | Mode::Delimited { .. } => {
+                self.mode = Mode::Finished;
+                Some(self.consumed)
+            }
+            Mode::Finished => Some(self.consumed),
+            _ => None,
+        }
+    }
+
+    /// Skip a value from a buffered reader, consuming exactly its bytes
+    ///
+    /// Bytes after the value stay in the reader. Returns the value's length
+    /// (including leading whitespace for [`value`](Self::value)).
+    ///
+    /// # Errors
+    /// Returns `UnexpectedEof` if the input ends inside the value,
+    /// `InvalidData` if no value starts where one should, or any error from
+    /// the reader.
+    pub fn skip_reader<R: BufRead + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        self.drive(reader, None)
+    }
+
+    /// Like [`skip_reader`](Self::skip_reader), appending the value's bytes
+    /// to `out`
+    ///
+    /// # Errors
+    /// As for [`skip_reader`](Self::skip_reader).
+    pub fn copy_reader<R: BufRead + ?Sized>(
+        &mut self,
+        reader: &mut R,
+        out: &mut Vec<u8>,
+    ) -> io::Result<u64> {
+        self.drive(reader, Some(out))
+    }
+
+    fn drive<R: BufRead + ?Sized>(
+        &mut self,
+        reader: &mut R,
+        mut out: Option<&mut Vec<u8>>,
+    ) -> io::Result<u64> {
+        loop {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                return self.finish().ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "input ended inside a JSON value",
+                    )
+                });
+            }
+            let progress = self
+                .feed(chunk)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let used = match progress {
+                Progress::NeedMore => chunk.len(),
+                Progress::Done(used) => used,
+            };
+            if let Some(out) = out.as_deref_mut() {
+                out.extend_from_slice(&chunk[..used]);
+            }
+            reader.consume(used);
+            if let Progress::Done(_) = progress {
+                return Ok(self.consumed);
+            }
+        }
+    }
+
+    /// Total bytes consumed across all chunks so far
+    #[must_use]
+    pub const fn total_consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Whether escape sequences were encountered
+    #[must_use]
+    pub const fn has_escapes(&self) -> bool {
+        self.has_escapes
+    }
+
+    /// Current bracket depth (0 outside containers)
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Whether the value has ended
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.mode == Mode::Finished
+    }
+
+    /// Pick the mode from the value's first byte
+    fn start(&mut self, chunk: &[u8], i: &mut usize) -> Result<Option<usize>, SkipError> {
+        while *i < chunk.len() && matches!(chunk[*i], b' ' | b'\t' | b'\n' | b'\r') {
+            *i += 1;
+        }
+        let Some(&first) = chunk.get(*i) else {
+            return Ok(None);
+        };
+        match first {
+            b'{' | b'[' => {
+                let close = if first == b'{' { b'}' } else { b']' };
+                self.mode = Mode::Container { open: first, close };
+                self.depth = 1;
+                *i += 1;
+            }
+            b'"' => {
+                self.mode = Mode::String;
+                self.in_string = true;
+                *i += 1;
+            }
+            b if is_scalar_byte(b) => self.mode = Mode::Scalar,
+            _ => {
+                return Err(SkipError {
+                    kind: SkipErrorKind::ExpectedValue,
+                    offset: usize::try_from(self.consumed).unwrap_or(usize::MAX) + *i,
+                });
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advance through string contents, clearing `in_string` at the close
+    fn scan_string(&mut self, chunk: &[u8], i: &mut usize) {
+        self.in_string = true;
+        if self.escaped {
+            if *i >= chunk.len() {
+                return;
+            }
+            self.escaped = false;
+            *i += 1;
+        }
+        while let Some(p) = memchr::memchr2(b'"', b'\\', &chunk[*i..]) {
+            *i += p;
+            if chunk[*i] == b'"' {
+                *i += 1;
+                self.in_string = false;
+                return;
+            }
+            self.has_escapes = true;
+            if *i + 1 < chunk.len() {
+                *i += 2;
+            } else {
+                self.escaped = true;
+                *i = chunk.len();
+                return;
+            }
+        }
+        *i = chunk.len();
+    }
+
+    /// Advance through container bytes outside strings; returns the end
+    /// offset when the outermost bracket closes
+    fn scan_container(
+        &mut self,
+        chunk: &[u8],
+        i: &mut usize,
+        open: u8,
+        close: u8,
+    ) -> Option<usize> {
+        while let Some(p) = memchr::memchr3(b'"', open, close, &chunk[*i..]) {
+            *i += p;
+            let byte = chunk[*i];
+            *i += 1;
+            if byte == b'"' {
+                self.in_string = true;
+                return None;
+            } else if byte == open {
+                self.depth += 1;
+            } else {
+                self.depth -= 1;
+                if self.depth == 0 {
+                    return Some(*i);
+                }
+            }
+        }
+        *i = chunk.len();
+        None
+    }
+}
+
+/// Bytes that can appear in a literal or number
+const fn is_scalar_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'+' | b'.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skip::{JsonSkiSkip, Skip};
+    use std::fmt::Write;
+
+    /// Feed `input` in fixed-size chunks; returns the value's total length
+    fn feed_chunks(mut skip: ResumableSkip, input: &[u8], size: usize) -> Option<u64> {
+        for chunk in input.chunks(size) {
+            if let Progress::Done(_) = skip.feed(chunk).unwrap() {
+                return Some(skip.total_consumed());
+            }
+        }
+        skip.finish()
+    }
+
+    #[test]
+    fn test_every_chunk_size_matches_contiguous_skip() {
+        let body = br#""a": {"b": [1, 2, {"c": "}\"{"}]}, "d\\": "x\\\"y", "e": {}}, "tail": 1"#;
+        let expected = JsonSkiSkip::new().skip_object(body).unwrap().consumed as u64;
+        for size in 1..=body.len() {
+            assert_eq!(
+                feed_chunks(ResumableSkip::object(), body, size),
+                Some(expected),
+                "chunk size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_escape_split_across_chunks() {
+        let mut skip = ResumableSkip::string();
+        assert_eq!(skip.feed(br"abc\"), Ok(Progress::NeedMore));
+        assert_eq!(skip.feed(br#"""#), Ok(Progress::NeedMore));
+        assert_eq!(skip.feed(br#"def", 1"#), Ok(Progress::Done(4)));
+        assert!(skip.has_escapes());
+        assert_eq!(skip.total_consumed(), 9);
+    }
+
+    #[test]
+    fn test_value_mode_types() {
+        assert_eq!(
+            feed_chunks(ResumableSkip::value(), b"  [1, [2]] x", 3),
+            Some(10)
+        );
+        assert_eq!(
+            feed_chunks(ResumableSkip::value(), br#" "s\"" ,"#, 2),
+            Some(6)
+        );
+        assert_eq!(feed_chunks(ResumableSkip::value(), b"-12.5e3,", 2), Some(7));
+        // Number running to end of input completes on finish
+        assert_eq!(feed_chunks(ResumableSkip::value(), b"true", 3), Some(4));
+    }
+
+    #[test]
+    fn test_non_value_start_is_an_error() {
+        let mut skip = ResumableSkip::value();
+        assert_eq!(skip.feed(b"  "), Ok(Progress::NeedMore));
+        let err = skip.feed(b" }").unwrap_err();
+        assert_eq!(err.kind, SkipErrorKind::ExpectedValue);
+        assert_eq!(err.offset, 3);
+
+        let mut reader = io::BufReader::new(&b" ,1"[..]);
+        let err = ResumableSkip::value().skip_reader(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_until_stops_before_delimiter() {
+        assert_eq!(
+            feed_chunks(ResumableSkip::until(b'|', b'\n'), b"a b:c|d", 2),
+            Some(5)
+        );
+        assert_eq!(
+            feed_chunks(ResumableSkip::until(b'|', b'\n'), b"tail", 3),
+            Some(4)
+        );
+
+        let mut reader = io::BufReader::with_capacity(4, &b"long value\nnext"[..]);
+        let mut out = Vec::new();
+        let len = ResumableSkip::until(b'|', b'\n')
+            .copy_reader(&mut reader, &mut out)
+            .unwrap();
+        assert_eq!((len, out.as_slice()), (10, &b"long value"[..]));
+    }
+
+    #[test]
+    fn test_truncated_value_needs_more() {
+        let mut skip = ResumableSkip::array();
+        assert_eq!(skip.feed(b"1, [2, 3]"), Ok(Progress::NeedMore));
+        assert_eq!(skip.depth(), 1);
+        assert_eq!(skip.finish(), None);
+        assert!(!skip.is_done());
+    }
+
+    #[test]
+    fn test_skip_reader_leaves_remainder() {
+        let mut value = String::from(r#"{"huge": ["#);
+        for i in 0..5000 {
+            write!(value, r#"{{"i": {i}, "s": "a\"]}}"}},"#).unwrap();
+        }
+        value.push_str("0]}");
+        let input = format!("{value}\n{{\"next\": true}}\n");
+        let mut reader = io::BufReader::with_capacity(64, input.as_bytes());
+        let len = ResumableSkip::value().skip_reader(&mut reader).unwrap();
+        assert_eq!(len, value.len() as u64);
+        let mut rest = String::new();
+        io::Read::read_to_string(&mut reader, &mut rest).unwrap();
+        assert_eq!(rest, "\n{\"next\": true}\n");
+    }
+
+    #[test]
+    fn test_skip_reader_reports_eof() {
+        let mut reader = io::BufReader::new(&b"[1, 2"[..]);
+        let err = ResumableSkip::value().skip_reader(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,607 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Validating skip implementation
+//!
+//! The other skippers only balance brackets and strings, so `{"a": tru,,}`
+//! skips "successfully". [`ValidatingSkip`] checks the full RFC 8259 grammar
+//! (value/colon/comma ordering, literals, number syntax, escapes) in the same
+//! pass, and validates string contents as UTF-8 without control characters.
+//! String bodies are handed to the AVX2 Keiser–Lemire kernel when available.
+//!
+//! Failures carry a [`SkipErrorKind`] and the byte offset where they were
+//! detected, relative to the slice passed in.
+
+use std::fmt;
+
+use super::{Skip, SkipResult};
+
+/// What went wrong during a validating skip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipErrorKind {
+    /// Input ended inside a value
+    UnexpectedEnd,
+    /// A value was expected (after `[`, `:` or `,`)
+    ExpectedValue,
+    /// An object key string was expected
+    ExpectedKey,
+    /// `:` was expected after an object key
+    ExpectedColon,
+    /// `,` or the closing bracket was expected
+    ExpectedCommaOrClose,
+    /// Misspelled `true`, `false` or `null`
+    InvalidLiteral,
+    /// Number does not follow the JSON number grammar
+    InvalidNumber,
+    /// Unknown escape or malformed `\u` escape (including lone surrogates)
+    InvalidEscape,
+    /// Unescaped control character inside a string
+    ControlCharacter,
+    /// Invalid UTF-8 inside a string
+    InvalidUtf8,
+    /// Non-whitespace after the end of a document
+    TrailingCharacters,
+}
+
+impl fmt::Display for SkipErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::UnexpectedEnd => "unexpected end of input",
+            Self::ExpectedValue => "expected value",
+            Self::ExpectedKey => "expected object key",
+            Self::ExpectedColon => "expected ':'",
+            Self::ExpectedCommaOrClose => "expected ',' or closing bracket",
+            Self::InvalidLiteral => "invalid literal",
+            Self::InvalidNumber => "invalid number",
+            Self::InvalidEscape => "invalid escape sequence",
+            Self::ControlCharacter => "control character in string",
+            Self::InvalidUtf8 => "invalid UTF-8 in string",
+            Self::TrailingCharacters => "trailing characters",
+        };
+        write!(f, "{message}")
+    }
+}
+
+/// Typed validation error with the byte offset where it was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkipError {
+    /// Error category
+    pub kind: SkipErrorKind,
+    /// Byte offset into the input slice
+    pub offset: usize,
+}
+
+impl fmt::Display for SkipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for SkipError {}
+
+type ValidateResult<T> = Result<T, SkipError>;
+
+const fn fail<T>(kind: SkipErrorKind, offset: usize) -> ValidateResult<T> {
+    Err(SkipError { kind, offset })
+}
+
+/// Skip with full grammar and UTF-8 validation
+///
+/// Implements [`Skip`] (errors become `None`); use the `validate_*` methods
+/// to get the typed error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatingSkip;
+
+impl ValidatingSkip {
+    /// Create a new validating skipper
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Validate an object starting after the opening `{`
+    ///
+    /// # Errors
+    /// Returns the first grammar or encoding error in the object.
+    pub fn validate_object(&self, input: &[u8]) -> ValidateResult<SkipResult> {
+        validate_container(input, 0, true)
+    }
+
+    /// Validate an array starting after the opening `[`
+    ///
+    /// # Errors
+    /// Returns the first grammar or encoding error in the array.
+    pub fn validate_array(&self, input: &[u8]) -> ValidateResult<SkipResult> {
+        validate_container(input, 0, false)
+    }
+
+    /// Validate a string starting after the opening `"`
+    ///
+    /// # Errors
+    /// Returns the first escape or encoding error in the string.
+    pub fn validate_string(&self, input: &[u8]) -> ValidateResult<SkipResult> {
+        let mut has_escapes = false;
+        let consumed = validate_string_body(input, 0, &mut has_escapes)?;
+        Ok(SkipResult {
+            consumed,
+            has_escapes,
+        })
+    }
+
+    /// Validate one value after optional leading whitespace
+    ///
+    /// `consumed` is the offset just past the value.
+    ///
+    /// # Errors
+    /// Returns the first grammar or encoding error in the value.
+    pub fn validate_value(&self, input: &[u8]) -> ValidateResult<SkipResult> {
+        let start = skip_whitespace(input, 0);
+        let Some(&first) = input.get(start) else {
+            return fail(SkipErrorKind::UnexpectedEnd, start);
+        };
+        let mut has_escapes = false;
+        let consumed = match first {
+            b'{' | b'[' => {
+                let result = validate_container(input, start + 1, first == b'{')?;
+                has_escapes = result.has_escapes;
+                result.consumed
+            }
+            b'"' => validate_string_body(input, start + 1, &mut has_escapes)?,
+            _ => validate_scalar(input, start)?,
+        };
+        Ok(SkipResult {
+            consumed,
+            has_escapes,
+        })
+    }
+
+    /// Validate a complete document: one value surrounded by whitespace
+    ///
+    /// # Errors
+    /// Returns the first error, including [`SkipErrorKind::TrailingCharacters`].
+    pub fn validate_document(&self, input: &[u8]) -> ValidateResult<SkipResult> {
+        let result = self.validate_value(input)?;
+        let end = skip_whitespace(input, result.consumed);
+        if end < input.len() {
+            return fail(SkipErrorKind::TrailingCharacters, end);
+        }
+        Ok(result)
+    }
+}
+
+impl Skip for ValidatingSkip {
+    fn skip_object(&self, input: &[u8]) -> Option<SkipResult> {
+        self.validate_object(input).ok()
+    }
+
+    fn skip_array(&self, input: &[u8]) -> Option<SkipResult> {
+        self.validate_array(input).ok()
+    }
+
+    fn skip_string(&self, input: &[u8]) -> Option<SkipResult> {
+        self.validate_string(input).ok()
+    }
+
+    fn skip_value(&self, input: &[u8]) -> Option<SkipResult> {
+        self.validate_value(input).ok()
+    }
+}
+
+/// Container kinds for the open brackets, one bit per level
+///
+/// The first 64 levels live inline so typical documents never allocate.
+#[derive(Debug, Default)]
+struct NestingStack {
+    depth: usize,
+    inline: u64,
+    spill: Vec<u64>,
+}
+
+impl NestingStack {
+    fn push(&mut self, is_object: bool) {
+        let (word, bit) = (self.depth / 64, self.depth % 64);
+        if word == 0 {
+            self.inline = (self.inline & !(1 << bit)) | (u64::from(is_object) << bit);
+        } else {
+            if self.spill.len() < word {
+                self.spill.push(0);
+            }
+            let slot = &mut self.spill[word - 1];
+            *slot = (*slot & !(1 << bit)) | (u64::from(is_object) << bit);
+        }
+        self.depth += 1;
+    }
+
+    /// Pop a level; returns whether the stack is now empty
+    const fn pop(&mut self) -> bool {
+        self.depth -= 1;
+        self.depth == 0
+    }
+
+    fn top_is_object(&self) -> bool {
+        let level = self.depth - 1;
+        let (word, bit) = (level / 64, level % 64);
+        let bits = if word == 0 {
+            self.inline
+        } else {
+            self.spill[word - 1]
+        };
+        bits & (1 << bit) != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    /// After `{`
+    KeyOrClose,
+    /// After `,` in an object
+    Key,
+    /// After a key
+    Colon,
+    /// After `[`
+    ValueOrClose,
+    /// After `:` or `,` in an array
+    Value,
+    /// After a complete value
+    CommaOrClose,
+}
+
+/// Validate a container whose opening bracket precedes `pos`
+fn validate_container(input: &[u8], mut pos: usize, is_object: bool) -> ValidateResult<SkipResult> {
+    let mut stack = NestingStack::default();
+    stack.push(is_object);
+    let mut expect = if is_object {
+        Expect::KeyOrClose
+    } else {
+        Expect::ValueOrClose
+    };
+    let mut has_escapes = false;
+
+    loop {
+        pos = skip_whitespace(input, pos);
+        let Some(&byte) = input.get(pos) else {
+            return fail(SkipErrorKind::UnexpectedEnd, pos);
+        };
+
+        let closes = match expect {
+            Expect::KeyOrClose => byte == b'}',
+            Expect::ValueOrClose => byte == b']',
+            Expect::CommaOrClose => byte == if stack.top_is_object() { b'}' } else { b']' },
+            Expect::Key | Expect::Colon | Expect::Value => false,
+        };
+        if closes {
+            if stack.pop() {
+                return Ok(SkipResult {
+                    consumed: pos + 1,
+                    has_escapes,
+                });
+            }
+            pos += 1;
+            expect = Expect::CommaOrClose;
+            continue;
+        }
+
+        match expect {
+            Expect::KeyOrClose | Expect::Key => {
+                if byte != b'"' {
+                    return fail(SkipErrorKind::ExpectedKey, pos);
+                }
+                pos = validate_string_body(input, pos + 1, &mut has_escapes)?;
+                expect = Expect::Colon;
+            }
+            Expect::Colon => {
+                if byte != b':' {
+                    return fail(SkipErrorKind::ExpectedColon, pos);
+                }
+                pos += 1;
+                expect = Expect::Value;
+            }
+            Expect::CommaOrClose => {
+                if byte != b',' {
+                    return fail(SkipErrorKind::ExpectedCommaOrClose, pos);
+                }
+                pos += 1;
+                expect = if stack.top_is_object() {
+                    Expect::Key
+                } else {
+                    Expect::Value
+                };
+            }
+            Expect::ValueOrClose | Expect::Value => match byte {
+                b'{' => {
+                    stack.push(true);
+                    pos += 1;
+                    expect = Expect::KeyOrClose;
+                }
+                b'[' => {
+                    stack.push(false);
+                    pos += 1;
+                    expect = Expect::ValueOrClose;
+                }
+                b'"' => {
+                    pos = validate_string_body(input, pos + 1, &mut has_escapes)?;
+                    expect = Expect::CommaOrClose;
+                }
+                _ => {
+                    pos = validate_scalar(input, pos)?;
+                    expect = Expect::CommaOrClose;
+                }
+            },
+        }
+    }
+}
+
+#[inline]
+fn skip_whitespace(input: &[u8], mut pos: usize) -> usize {
+    while pos < input.len() && matches!(input[pos], b' ' | b'\t' | b'\n' | b'\r') {
+        pos += 1;
+    }
+    pos
+}
+
+/// Validate a literal or number at `pos`; returns the offset past it
+fn validate_scalar(input: &[u8], pos: usize) -> ValidateResult<usize> {
+    let literal: &[u8] = match input[pos] {
+        b't' => b"true",
+        b'f' => b"false",
+        b'n' => b"null",
+        b'-' | b'0'..=b'9' => return validate_number(input, pos),
+        _ => return fail(SkipErrorKind::ExpectedValue, pos),
+    };
+    let available = &input[pos..input.len().min(pos + literal.len())];
+    if available == literal {
+        return Ok(pos + literal.len());
+    }
+    // Report the first wrong byte, or the end if the literal is truncated
+    let mismatch = available
+        .iter()
+        .zip(literal)
+        .position(|(a, b)| a != b)
+        .unwrap_or(available.len());
+    if mismatch == available.len() {
+        fail(SkipErrorKind::UnexpectedEnd, pos + mismatch)
+    } else {
+        fail(SkipErrorKind::InvalidLiteral, pos + mismatch)
+    }
+}
+
+/// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`
+fn validate_number(input: &[u8], mut pos: usize) -> ValidateResult<usize> {
+    let digits = |input: &[u8], start: usize| -> ValidateResult<usize> {
+        let count = input[start.min(input.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if count == 0 {
+            let kind = if start >= input.len() {
+                SkipErrorKind::UnexpectedEnd
+            } else {
+                SkipErrorKind::InvalidNumber
+            };
+            return fail(kind, start);
+        }
+        Ok(start + count)
+    };
+
+    if input[pos] == b'-' {
+        pos += 1;
+    }
+    if input.get(pos) == Some(&b'0') {
+        pos += 1;
+    } else {
+        pos = digits(input, pos)?;
+    }
+    if input.get(pos) == Some(&b'.') {
+        pos = digits(input, pos + 1)?;
+    }
+    if matches!(input.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        if matches!(input.get(pos), Some(b'+' | b'-')) {
+            pos += 1;
+        }
+        pos = digits(input, pos)?;
+    }
+    Ok(pos)
+}
+
+/// Validate a string body starting after the opening quote; returns the
+/// offset past the closing quote
+fn validate_string_body(
+    input: &[u8],
+    mut pos: usize,
+    has_escapes: &mut bool,
+) -> ValidateResult<usize> {
+    loop {
+        let rest = &input[pos..];
+        let Some(special) = memchr::memchr2(b'"', b'\\', rest) else {
+            check_span(rest, pos)?;
+            return fail(SkipErrorKind::UnexpectedEnd, input.len());
+        };
+        // Multi-byte UTF-8 never contains ASCII, so spans split cleanly here
+        check_span(&rest[..special], pos)?;
+        pos += special;
+        if input[pos] == b'"' {
+            return Ok(pos + 1);
+        }
+        *has_escapes = true;
+        pos = validate_escape(input, pos)?;
+    }
+}
+
+/// Validate the escape whose backslash is at `pos`
+fn validate_escape(input: &[u8], pos: usize) -> ValidateResult<usize> {
+    match input.get(pos + 1) {
+        None => fail(SkipErrorKind::UnexpectedEnd, pos + 1),
+        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => Ok(pos + 2),
+        Some(b'u') => {
+            let unit = hex_escape(input, pos)?;
+            match unit {
+                0xD800..=0xDBFF => {
+                    // High surrogate must be followed by `\u` + low surrogate
+                    let next = pos + 6;
+                    if input.get(next) != Some(&b'\\') || input.get(next + 1) != Some(&b'u') {
+                        let kind = if next >= input.len() {
+                            SkipErrorKind::UnexpectedEnd
+                        } else {
+                            SkipErrorKind::InvalidEscape
+                        };
+                        return fail(kind, next);
+                    }
+                    if (0xDC00..=0xDFFF).contains(&hex_escape(input, next)?) {
+                        Ok(next + 6)
+                    } else {
+                        fail(SkipErrorKind::InvalidEscape, next)
+                    }
+                }
+                0xDC00..=0xDFFF => fail(SkipErrorKind::InvalidEscape, pos),
+                _ => Ok(pos + 6),
+            }
+        }
+        Some(_) => fail(SkipErrorKind::InvalidEscape, pos),
+    }
+}
+
+/// Decode the four hex digits of the `\u` escape at `pos`
+fn hex_escape(input: &[u8], pos: usize) -> ValidateResult<u16> {
+    let mut unit = 0u16;
+    for i in pos + 2..pos + 6 {
+        let Some(&byte) = input.get(i) else {
+            return fail(SkipErrorKind::UnexpectedEnd, i);
+        };
+        let Some(digit) = char::from(byte).to_digit(16) else {
+            return fail(SkipErrorKind::InvalidEscape, pos);
+        };
+        // Four hex digits always fit in u16
+        #[allow(clippy::cast_possible_truncation)] // digit < 16
+        let digit = digit as u16;
+        unit = (unit << 4) | digit;
+    }
+    Ok(unit)
+}
+
+/// Check raw string bytes (no quotes or backslashes) starting at `base`
+#[inline]
+fn check_span(span: &[u8], base: usize) -> ValidateResult<()> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if crate::x86::utf8::is_available() {
+            // SAFETY: AVX2 availability checked above
+            if unsafe { crate::x86::utf8::validate_string_bytes(span) } {
+                return Ok(());
+            }
+            return locate_span_error(span, base);
+        }
+    }
+    locate_span_error(span, base)
+}
+
+/// Scalar check that also pinpoints the first bad byte
+fn locate_span_error(span: &[u8], base: usize) -> ValidateResult<()> {
+    let control = span.iter().position(|&b| b < 0x20);
+    let utf8 = std::str::from_utf8(span).err().map(|e| e.valid_up_to());
+    match (control, utf8) {
+        (Some(c), Some(u)) if u < c => fail(SkipErrorKind::InvalidUtf8, base + u),
+        (Some(c), _) => fail(SkipErrorKind::ControlCharacter, base + c),
+        (None, Some(u)) => fail(SkipErrorKind::InvalidUtf8, base + u),
+        (None, None) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(input: &[u8]) -> ValidateResult<SkipResult> {
+        ValidatingSkip::new().validate_document(input)
+    }
+
+    fn error(input: &[u8]) -> (SkipErrorKind, usize) {
+        let e = document(input).unwrap_err();
+        (e.kind, e.offset)
+    }
+
+    #[test]
+    fn test_accepts_valid_documents() {
+        let docs: &[&[u8]] = &[
+            r#"{"a": [1, -2.5e+3, 0, true, false, null, "x\né😀"], "b": {}}"#.as_bytes(),
+            b"[]",
+            b" 42 ",
+            "\"caf\u{e9}\"".as_bytes(),
+            br#"[[[[{"deep": [[]]}]]]]"#,
+        ];
+        for doc in docs {
+            assert!(document(doc).is_ok(), "{}", String::from_utf8_lossy(doc));
+        }
+    }
+
+    #[test]
+    fn test_rejects_grammar_errors() {
+        assert_eq!(
+            error(br#"{"a": tru,,}"#),
+            (SkipErrorKind::InvalidLiteral, 9)
+        );
+        assert_eq!(error(br#"{"a" 1}"#), (SkipErrorKind::ExpectedColon, 5));
+        assert_eq!(error(br#"{"a": 1,}"#), (SkipErrorKind::ExpectedKey, 8));
+        assert_eq!(error(b"[1 2]"), (SkipErrorKind::ExpectedCommaOrClose, 3));
+        assert_eq!(error(b"[1,]"), (SkipErrorKind::ExpectedValue, 3));
+        assert_eq!(error(b"{1: 2}"), (SkipErrorKind::ExpectedKey, 1));
+        assert_eq!(error(b"[}"), (SkipErrorKind::ExpectedValue, 1));
+        assert_eq!(error(b"[1}"), (SkipErrorKind::ExpectedCommaOrClose, 2));
+        assert_eq!(error(b"{} x"), (SkipErrorKind::TrailingCharacters, 3));
+        assert_eq!(error(b"[1, 2"), (SkipErrorKind::UnexpectedEnd, 5));
+    }
+
+    #[test]
+    fn test_rejects_bad_numbers() {
+        assert_eq!(error(b"[01]"), (SkipErrorKind::ExpectedCommaOrClose, 2));
+        assert_eq!(error(b"[1.]"), (SkipErrorKind::InvalidNumber, 3));
+        assert_eq!(error(b"[-]"), (SkipErrorKind::InvalidNumber, 2));
+        assert_eq!(error(b"[1e+]"), (SkipErrorKind::InvalidNumber, 4));
+        assert_eq!(error(b"[.5]"), (SkipErrorKind::ExpectedValue, 1));
+    }
+
+    #[test]
+    fn test_rejects_bad_strings() {
+        assert_eq!(error(b"[\"a\xFFb\"]"), (SkipErrorKind::InvalidUtf8, 3));
+        assert_eq!(
+            error(b"[\"tab\there\"]"),
+            (SkipErrorKind::ControlCharacter, 5)
+        );
+        assert_eq!(error(br#"["\x"]"#), (SkipErrorKind::InvalidEscape, 2));
+        assert_eq!(error(br#"["\u12G4"]"#), (SkipErrorKind::InvalidEscape, 2));
+        assert_eq!(error(br#"["\udc00"]"#), (SkipErrorKind::InvalidEscape, 2));
+        assert_eq!(error(br#"["\ud800x"]"#), (SkipErrorKind::InvalidEscape, 8));
+        assert_eq!(error(b"[\"open"), (SkipErrorKind::UnexpectedEnd, 6));
+    }
+
+    #[test]
+    fn test_skip_trait_matches_other_skippers() {
+        let body = br#""a": [1, {"b": "}"}], "c": "\"q\""}"#;
+        let expected = super::super::ScalarSkip.skip_object(body);
+        assert_eq!(ValidatingSkip::new().skip_object(body), expected);
+        assert_eq!(ValidatingSkip::new().skip_object(br#""a": tru}"#), None);
+    }
+
+    #[test]
+    fn test_deep_nesting_spills_stack() {
+        let depth = 200;
+        let mut doc = "[".repeat(depth);
+        doc.push_str(&"]".repeat(depth));
+        assert_eq!(document(doc.as_bytes()).unwrap().consumed, depth * 2);
+        doc.pop();
+        assert_eq!(
+            error(doc.as_bytes()),
+            (SkipErrorKind::UnexpectedEnd, depth * 2 - 1)
+        );
+    }
+
+    #[test]
+    fn test_long_strings_cross_simd_blocks() {
+        let mut doc = String::from("[\"");
+        doc.push_str(&"\u{e9}x".repeat(40));
+        doc.push_str("\"]");
+        assert!(document(doc.as_bytes()).is_ok());
+        let mut bytes = doc.into_bytes();
+        let bad = bytes.len() - 10;
+        bytes[bad] = 0xC3; // lead byte followed by ASCII
+        assert_eq!(error(&bytes).0, SkipErrorKind::InvalidUtf8);
+    }
+}
@@ -3,10 +3,22 @@
 //!
 //! Each emitter converts a unified tape into a specific output format.
 
-use super::tape::{TapeNode, TapeValue, UnifiedTape};
+use super::tape::{TapeNode, TapeValue, UnifiedTape, section_key};
 use super::{TransformOptions, TransformResult};
 use std::io::Write;
 
+#[cfg(feature = "hcl")]
+use super::TransformError;
+#[cfg(any(feature = "ini", feature = "properties", feature = "env"))]
+use super::tape::PathEvent;
+#[cfg(any(
+    feature = "hcl",
+    feature = "ini",
+    feature = "properties",
+    feature = "env"
+))]
+use std::borrow::Cow;
+
 /// Trait for format emitters
 pub trait Emitter {
     /// Emit tape to new Vec
@@ -73,6 +85,31 @@ impl<'a> JsonEmitter<'a> {
             }
         }
     }
+
+    fn emit_key(
+        &self,
+        key: &str,
+        output: &mut Vec<u8>,
+        depth: usize,
+        first_in_container: &mut [bool],
+    ) {
+        if !first_in_container.last().copied().unwrap_or(true) {
+            output.push(b',');
+        }
+        if self.options.pretty {
+            self.emit_indent(output, depth);
+        }
+        output.push(b'"');
+        escape_json_string(key, output);
+        output.push(b'"');
+        output.push(b':');
+        if self.options.pretty {
+            output.push(b' ');
+        }
+        if let Some(first) = first_in_container.last_mut() {
+            *first = false;
+        }
+    }
 }
 
 impl Emitter for JsonEmitter<'_> {
@@ -142,23 +179,13 @@ impl Emitter for JsonEmitter<'_> {
                     current_fields = None;
                 }
                 TapeNode::Key(key) => {
-                    if !first_in_container.last().copied().unwrap_or(true) {
-                        output.push(b',');
-                    }
-                    if self.options.pretty {
-                        self.emit_indent(output, depth);
-                    }
-                    output.push(b'"');
-                    escape_json_string(key, output);
-                    output.push(b'"');
-                    output.push(b':');
-                    if self.options.pretty {
-                        output.push(b' ');
-                    }
+                    self.emit_key(key, output, depth, &mut first_in_container);
+                    expect_value = true;
+                }
+                TapeNode::Section { path } if depth > 0 => {
+                    // INI sections name their object in place of a key
+                    self.emit_key(&section_key(path), output, depth, &mut first_in_container);
                     expect_value = true;
-                    if let Some(first) = first_in_container.last_mut() {
-                        *first = false;
-                    }
                 }
                 TapeNode::Value(value) => {
                     if !expect_value && !first_in_container.last().copied().unwrap_or(true) {
@@ -283,6 +310,24 @@ impl<'a> YamlEmitter<'a> {
             output.extend_from_slice(self.options.indent.as_bytes());
         }
     }
+
+    fn emit_key(
+        &self,
+        key: &str,
+        output: &mut Vec<u8>,
+        depth: usize,
+        first_in_container: &mut [bool],
+    ) {
+        if !first_in_container.last().copied().unwrap_or(true) || depth > 1 {
+            output.push(b'\n');
+        }
+        self.emit_indent(output, depth.saturating_sub(1));
+        output.extend_from_slice(key.as_bytes());
+        output.extend_from_slice(b": ");
+        if let Some(first) = first_in_container.last_mut() {
+            *first = false;
+        }
+    }
 }
 
 #[cfg(feature = "yaml")]
@@ -324,15 +369,10 @@ impl Emitter for YamlEmitter<'_> {
                     first_in_container.push(true);
                 }
                 TapeNode::Key(key) => {
-                    if !first_in_container.last().copied().unwrap_or(true) || depth > 1 {
-                        output.push(b'\n');
-                    }
-                    self.emit_indent(output, depth.saturating_sub(1));
-                    output.extend_from_slice(key.as_bytes());
-                    output.extend_from_slice(b": ");
-                    if let Some(first) = first_in_container.last_mut() {
-                        *first = false;
-                    }
+                    self.emit_key(key, output, depth, &mut first_in_container);
+                }
+                TapeNode::Section { path } if depth > 0 => {
+                    self.emit_key(&section_key(path), output, depth, &mut first_in_container);
                 }
                 TapeNode::Value(value) => {
                     if *in_array.last().unwrap_or(&false) {
@@ -347,13 +387,11 @@ impl Emitter for YamlEmitter<'_> {
                         *first = false;
                     }
                 }
-                TapeNode::Comment(text) => {
-                    if self.options.preserve_comments {
-                        output.push(b'\n');
-                        self.emit_indent(output, depth);
-                        output.extend_from_slice(b"# ");
-                        output.extend_from_slice(text.as_bytes());
-                    }
+                TapeNode::Comment(text) if self.options.preserve_comments => {
+                    output.push(b'\n');
+                    self.emit_indent(output, depth);
+                    output.extend_from_slice(b"# ");
+                    output.extend_from_slice(text.as_bytes());
                 }
                 TapeNode::TabularRow { values } => {
                     output.push(b'\n');
@@ -437,6 +475,7 @@ impl Emitter for TomlEmitter<'_> {
     fn emit_into(&self, tape: &UnifiedTape<'_>, output: &mut Vec<u8>) -> TransformResult<()> {
         let mut path: Vec<String> = Vec::new();
         let mut in_inline = false;
+        let mut object_depth = 0usize;
 
         for node in &tape.nodes {
             match node {
@@ -448,15 +487,18 @@ impl Emitter for TomlEmitter<'_> {
                         output.extend_from_slice(path.join(".").as_bytes());
                         output.extend_from_slice(b"]\n");
                     }
+                    object_depth += 1;
                 }
                 TapeNode::ObjectEnd => {
-                    if !path.is_empty() {
-                        path.pop();
-                    }
+                    object_depth = object_depth.saturating_sub(1);
+                    path.pop();
                 }
                 TapeNode::Key(key) => {
                     path.push(key.to_string());
                 }
+                TapeNode::Section { path: section } if object_depth > 0 => {
+                    path.push(section_key(section));
+                }
                 TapeNode::Value(value) => {
                     if let Some(key) = path.last() {
                         output.extend_from_slice(key.as_bytes());
@@ -647,10 +689,8 @@ impl Emitter for IsonEmitter<'_> {
                 TapeNode::ObjectStart { .. } => {
                     // ISON uses flat key paths, no explicit object markers
                 }
-                TapeNode::ObjectEnd => {
-                    if !path.is_empty() {
-                        path.pop();
-                    }
+                TapeNode::ObjectEnd if !path.is_empty() => {
+                    path.pop();
                 }
                 TapeNode::ArrayStart { .. } => {
                     in_array = true;
@@ -865,6 +905,421 @@ impl Emitter for ToonEmitter<'_> {
     }
 }
 
+// =============================================================================
+// HCL Emitter
+// =============================================================================
+
+/// HCL format emitter
+///
+/// Objects in a body become blocks, everything below an attribute is written
+/// as tuple and object expressions, and `"${expr}"` strings are written back
+/// as bare expressions.
+#[cfg(feature = "hcl")]
+pub struct HclEmitter<'a> {
+    options: &'a TransformOptions,
+}
+
+/// Container being written by [`HclEmitter`]
+#[cfg(feature = "hcl")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HclScope {
+    /// Root or block body: attributes and nested blocks
+    Body,
+    /// Object expression
+    Object,
+    /// Tuple expression
+    List,
+}
+
+#[cfg(feature = "hcl")]
+impl<'a> HclEmitter<'a> {
+    /// Create a new HCL emitter
+    #[must_use]
+    pub const fn new(options: &'a TransformOptions) -> Self {
+        Self { options }
+    }
+
+    #[allow(clippy::unused_self)] // Method signature for API consistency; may use self.options in future
+    fn emit_value(&self, value: &TapeValue<'_>, output: &mut Vec<u8>) {
+        match value {
+            TapeValue::Null => output.extend_from_slice(b"null"),
+            TapeValue::Bool(true) => output.extend_from_slice(b"true"),
+            TapeValue::Bool(false) => output.extend_from_slice(b"false"),
+            TapeValue::Int(i) => {
+                let _ = write!(output, "{i}");
+            }
+            TapeValue::Float(f) => {
+                if f.is_finite() {
+                    let _ = write!(output, "{f}");
+                } else {
+                    output.extend_from_slice(b"null"); // HCL has no NaN/Infinity
+                }
+            }
+            TapeValue::RawNumber(s) => output.extend_from_slice(s.as_bytes()),
+            TapeValue::String(s) => {
+                if let Some(expr) = hcl_template_expression(s) {
+                    output.extend_from_slice(expr.as_bytes());
+                } else {
+                    output.push(b'"');
+                    escape_json_string(s, output);
+                    output.push(b'"');
+                }
+            }
+        }
+    }
+
+    fn emit_indent(&self, output: &mut Vec<u8>, depth: usize) {
+        for _ in 0..depth {
+            output.extend_from_slice(self.options.indent.as_bytes());
+        }
+    }
+
+    /// Write an attribute or object key, quoting non-identifiers
+    fn emit_key(key: &str, output: &mut Vec<u8>) {
+        let is_ident = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if is_ident {
+            output.extend_from_slice(key.as_bytes());
+        } else {
+            output.push(b'"');
+            escape_json_string(key, output);
+            output.push(b'"');
+        }
+    }
+}
+
+#[cfg(feature = "hcl")]
+impl Emitter for HclEmitter<'_> {
+    fn emit(&self, tape: &UnifiedTape<'_>) -> TransformResult<Vec<u8>> {
+        let mut output =
+            Vec::with_capacity(tape.stats.string_bytes * 2 + tape.stats.node_count * 4);
+        self.emit_into(tape, &mut output)?;
+        Ok(output)
+    }
+
+    #[allow(clippy::too_many_lines)] // Format emission handles many node types inline for performance
+    fn emit_into(&self, tape: &UnifiedTape<'_>, output: &mut Vec<u8>) -> TransformResult<()> {
+        let not_an_object = || TransformError::UnsupportedTransformation {
+            source: tape.source_format,
+            target: fionn_core::format::FormatKind::Hcl,
+            reason: "an HCL document must be an object".to_string(),
+        };
+        let mut scopes: Vec<HclScope> = Vec::new();
+        let mut key: Option<Cow<'_, str>> = None;
+        let mut current_fields: Option<Vec<&str>> = None;
+
+        for node in &tape.nodes {
+            match node {
+                TapeNode::Key(k) => key = Some(Cow::Borrowed(k.as_ref())),
+                TapeNode::Section { path } if !scopes.is_empty() => {
+                    key = Some(Cow::Owned(section_key(path)));
+                }
+                TapeNode::ObjectStart { .. } | TapeNode::ArrayStart { .. } => {
+                    let array = matches!(node, TapeNode::ArrayStart { .. });
+                    let Some(&parent) = scopes.last() else {
+                        if array {
+                            return Err(not_an_object());
+                        }
+                        scopes.push(HclScope::Body);
+                        continue;
+                    };
+                    self.emit_indent(output, scopes.len() - 1);
+                    if parent != HclScope::List {
+                        Self::emit_key(key.take().as_deref().unwrap_or_default(), output);
+                    }
+                    let (opener, scope): (&[u8], _) = match (parent, array) {
+                        (HclScope::List, true) => (b"[\n", HclScope::List),
+                        (HclScope::List, false) => (b"{\n", HclScope::Object),
+                        (HclScope::Body, false) => (b" {\n", HclScope::Body),
+                        (HclScope::Body | HclScope::Object, true) => (b" = [\n", HclScope::List),
+                        (HclScope::Object, false) => (b" = {\n", HclScope::Object),
+                    };
+                    output.extend_from_slice(opener);
+                    scopes.push(scope);
+                }
+                TapeNode::ObjectEnd | TapeNode::ArrayEnd => {
+                    let Some(scope) = scopes.pop() else {
+                        continue;
+                    };
+                    if matches!(node, TapeNode::ArrayEnd) {
+                        current_fields = None;
+                    }
+                    let Some(&parent) = scopes.last() else {
+                        continue;
+                    };
+                    self.emit_indent(output, scopes.len() - 1);
+                    output.push(if scope == HclScope::List { b']' } else { b'}' });
+                    if parent == HclScope::List {
+                        output.push(b',');
+                    }
+                    output.push(b'\n');
+                }
+                TapeNode::Value(value) => {
+                    let Some(&scope) = scopes.last() else {
+                        return Err(not_an_object());
+                    };
+                    self.emit_indent(output, scopes.len() - 1);
+                    if scope == HclScope::List {
+                        self.emit_value(value, output);
+                        output.push(b',');
+                    } else {
+                        Self::emit_key(key.take().as_deref().unwrap_or_default(), output);
+                        output.extend_from_slice(b" = ");
+                        self.emit_value(value, output);
+                    }
+                    output.push(b'\n');
+                }
+                TapeNode::TabularHeader { fields, .. } => {
+                    current_fields = Some(fields.iter().map(AsRef::as_ref).collect());
+                }
+                TapeNode::TabularRow { values } if scopes.last() == Some(&HclScope::List) => {
+                    self.emit_indent(output, scopes.len() - 1);
+                    output.push(if current_fields.is_some() { b'{' } else { b'[' });
+                    for (i, val) in values.iter().enumerate() {
+                        if i > 0 {
+                            output.extend_from_slice(b", ");
+                        }
+                        if let Some(ref fields) = current_fields {
+                            Self::emit_key(fields.get(i).copied().unwrap_or("_"), output);
+                            output.extend_from_slice(b" = ");
+                        }
+                        self.emit_value(val, output);
+                    }
+                    output.extend_from_slice(if current_fields.is_some() {
+                        b"},\n"
+                    } else {
+                        b"],\n"
+                    });
+                }
+                TapeNode::Comment(text) if self.options.preserve_comments => {
+                    self.emit_indent(output, scopes.len().saturating_sub(1));
+                    output.extend_from_slice(b"# ");
+                    output.extend_from_slice(text.as_bytes());
+                    output.push(b'\n');
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// INI Emitter
+// =============================================================================
+
+/// INI format emitter
+///
+/// Top-level scalars are written before the first section and each nested
+/// object becomes a `[section]`; deeper paths are flattened into dotted keys.
+#[cfg(feature = "ini")]
+pub struct IniEmitter<'a> {
+    options: &'a TransformOptions,
+}
+
+#[cfg(feature = "ini")]
+impl<'a> IniEmitter<'a> {
+    /// Create a new INI emitter
+    #[must_use]
+    pub const fn new(options: &'a TransformOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg(feature = "ini")]
+impl Emitter for IniEmitter<'_> {
+    fn emit(&self, tape: &UnifiedTape<'_>) -> TransformResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(tape.stats.string_bytes + tape.stats.node_count * 2);
+        self.emit_into(tape, &mut output)?;
+        Ok(output)
+    }
+
+    fn emit_into(&self, tape: &UnifiedTape<'_>, output: &mut Vec<u8>) -> TransformResult<()> {
+        let mut sections: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut current: Option<usize> = None;
+
+        for entry in flat_entries(tape)? {
+            match entry {
+                FlatEntry::Comment(text) if self.options.preserve_comments => {
+                    let buf = current.map_or(&mut *output, |i| &mut sections[i].1);
+                    buf.extend_from_slice(b"; ");
+                    buf.extend_from_slice(text.as_bytes());
+                    buf.push(b'\n');
+                }
+                FlatEntry::Comment(_) => {}
+                FlatEntry::Scalar(path, value) => {
+                    let (buf, key) = match path.split_first() {
+                        Some((section, rest)) if !rest.is_empty() => {
+                            let index = sections
+                                .iter()
+                                .position(|(name, _)| name == section)
+                                .unwrap_or_else(|| {
+                                    sections.push((section.clone(), Vec::new()));
+                                    sections.len() - 1
+                                });
+                            current = Some(index);
+                            (&mut sections[index].1, rest.join("."))
+                        }
+                        _ => {
+                            current = None;
+                            (&mut *output, path.join("."))
+                        }
+                    };
+                    buf.extend_from_slice(key.as_bytes());
+                    if !value.is_null() {
+                        let text = scalar_text(value);
+                        buf.extend_from_slice(b" = ");
+                        if text.trim() == text {
+                            buf.extend_from_slice(text.as_bytes());
+                        } else {
+                            buf.push(b'"');
+                            buf.extend_from_slice(text.as_bytes());
+                            buf.push(b'"');
+                        }
+                    }
+                    buf.push(b'\n');
+                }
+            }
+        }
+
+        for (name, body) in sections {
+            if !output.is_empty() {
+                output.push(b'\n');
+            }
+            output.push(b'[');
+            output.extend_from_slice(name.as_bytes());
+            output.extend_from_slice(b"]\n");
+            output.extend_from_slice(&body);
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Properties Emitter
+// =============================================================================
+
+/// Java `.properties` format emitter
+///
+/// Nested paths are flattened into dotted keys.
+#[cfg(feature = "properties")]
+pub struct PropertiesEmitter<'a> {
+    options: &'a TransformOptions,
+}
+
+#[cfg(feature = "properties")]
+impl<'a> PropertiesEmitter<'a> {
+    /// Create a new `.properties` emitter
+    #[must_use]
+    pub const fn new(options: &'a TransformOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg(feature = "properties")]
+impl Emitter for PropertiesEmitter<'_> {
+    fn emit(&self, tape: &UnifiedTape<'_>) -> TransformResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(tape.stats.string_bytes + tape.stats.node_count * 2);
+        self.emit_into(tape, &mut output)?;
+        Ok(output)
+    }
+
+    fn emit_into(&self, tape: &UnifiedTape<'_>, output: &mut Vec<u8>) -> TransformResult<()> {
+        for entry in flat_entries(tape)? {
+            match entry {
+                FlatEntry::Comment(text) if self.options.preserve_comments => {
+                    output.extend_from_slice(b"# ");
+                    output.extend_from_slice(text.as_bytes());
+                    output.push(b'\n');
+                }
+                FlatEntry::Comment(_) => {}
+                FlatEntry::Scalar(path, value) => {
+                    escape_property(&path.join("."), true, output);
+                    output.push(b'=');
+                    escape_property(&scalar_text(value), false, output);
+                    output.push(b'\n');
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Dotenv Emitter
+// =============================================================================
+
+/// Dotenv (`.env`) format emitter
+///
+/// Nested paths are joined with `_` into variable names, and values that
+/// are not plain words are double-quoted.
+#[cfg(feature = "env")]
+pub struct EnvEmitter<'a> {
+    options: &'a TransformOptions,
+}
+
+#[cfg(feature = "env")]
+impl<'a> EnvEmitter<'a> {
+    /// Create a new dotenv emitter
+    #[must_use]
+    pub const fn new(options: &'a TransformOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg(feature = "env")]
+impl Emitter for EnvEmitter<'_> {
+    fn emit(&self, tape: &UnifiedTape<'_>) -> TransformResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(tape.stats.string_bytes + tape.stats.node_count * 2);
+        self.emit_into(tape, &mut output)?;
+        Ok(output)
+    }
+
+    fn emit_into(&self, tape: &UnifiedTape<'_>, output: &mut Vec<u8>) -> TransformResult<()> {
+        for entry in flat_entries(tape)? {
+            match entry {
+                FlatEntry::Comment(text) if self.options.preserve_comments => {
+                    output.extend_from_slice(b"# ");
+                    output.extend_from_slice(text.as_bytes());
+                    output.push(b'\n');
+                }
+                FlatEntry::Comment(_) => {}
+                FlatEntry::Scalar(path, value) => {
+                    let name: String = path
+                        .join("_")
+                        .chars()
+                        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                        .collect();
+                    if name.starts_with(|c: char| c.is_ascii_digit()) {
+                        output.push(b'_');
+                    }
+                    output.extend_from_slice(name.as_bytes());
+                    output.push(b'=');
+                    let text = scalar_text(value);
+                    let bare = text
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "_-./:@%+,${}=".contains(c));
+                    if bare {
+                        output.extend_from_slice(text.as_bytes());
+                    } else {
+                        output.push(b'"');
+                        escape_json_string(&text, output);
+                        output.push(b'"');
+                    }
+                    output.push(b'\n');
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -945,3 +1400,75 @@ fn needs_toon_quoting(s: &str) -> bool {
         || s.contains('\n')
         || s.starts_with('-')
 }
+
+/// Bare expression held by an HCL `"${expr}"` string, if it is exactly one
+#[cfg(feature = "hcl")]
+fn hcl_template_expression(s: &str) -> Option<&str> {
+    let inner = s.strip_prefix("${")?.strip_suffix('}')?;
+    let mut depth = 0usize;
+    for c in inner.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.checked_sub(1)?,
+            _ => {}
+        }
+    }
+    (depth == 0 && !inner.trim().is_empty()).then_some(inner)
+}
+
+/// Scalar or comment of a tape flattened to key paths
+#[cfg(any(feature = "ini", feature = "properties", feature = "env"))]
+enum FlatEntry<'t> {
+    Scalar(Vec<String>, &'t TapeValue<'t>),
+    Comment(&'t str),
+}
+
+/// Flatten a tape into scalars with their key paths, for the flat formats
+#[cfg(any(feature = "ini", feature = "properties", feature = "env"))]
+fn flat_entries<'t>(tape: &'t UnifiedTape<'_>) -> TransformResult<Vec<FlatEntry<'t>>> {
+    let mut entries = Vec::new();
+    tape.walk_paths(|path, event| {
+        match event {
+            PathEvent::Scalar(value) => entries.push(FlatEntry::Scalar(path.to_vec(), value)),
+            PathEvent::Comment(text) => entries.push(FlatEntry::Comment(text)),
+            PathEvent::Container { .. } => {}
+        }
+        Ok(())
+    })?;
+    Ok(entries)
+}
+
+/// Text of a scalar for formats whose values are untyped strings
+#[cfg(any(feature = "ini", feature = "properties", feature = "env"))]
+fn scalar_text<'t>(value: &'t TapeValue<'_>) -> Cow<'t, str> {
+    match value {
+        TapeValue::Null => Cow::Borrowed(""),
+        TapeValue::Bool(b) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        TapeValue::Int(i) => Cow::Owned(i.to_string()),
+        TapeValue::Float(f) => Cow::Owned(f.to_string()),
+        TapeValue::String(s) | TapeValue::RawNumber(s) => Cow::Borrowed(s.as_ref()),
+    }
+}
+
+/// Escape a `.properties` key or value
+#[cfg(feature = "properties")]
+fn escape_property(s: &str, is_key: bool, output: &mut Vec<u8>) {
+    for (i, c) in s.chars().enumerate() {
+        match c {
+            '\\' => output.extend_from_slice(b"\\\\"),
+            '\n' => output.extend_from_slice(b"\\n"),
+            '\r' => output.extend_from_slice(b"\\r"),
+            '\t' => output.extend_from_slice(b"\\t"),
+            '\x0c' => output.extend_from_slice(b"\\f"),
+            '=' | ':' | '#' | '!' | ' ' if is_key => {
+                output.push(b'\\');
+                output.push(c as u8);
+            }
+            ' ' if i == 0 => output.extend_from_slice(b"\\ "),
+            c => {
+                let mut buf = [0u8; 4];
+                output.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+}
@@ -44,7 +44,20 @@ pub use emitter::IsonEmitter;
 #[cfg(feature = "toon")]
 pub use emitter::ToonEmitter;
 
+#[cfg(feature = "hcl")]
+pub use emitter::HclEmitter;
+
+#[cfg(feature = "ini")]
+pub use emitter::IniEmitter;
+
+#[cfg(feature = "properties")]
+pub use emitter::PropertiesEmitter;
+
+#[cfg(feature = "env")]
+pub use emitter::EnvEmitter;
+
 use fionn_core::format::FormatKind;
+use tape::PathEvent;
 
 /// Transformation fidelity modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -215,6 +228,10 @@ pub fn transform(
         return Ok((input.to_vec(), metrics));
     }
 
+    if options.fidelity == TransformFidelity::Strict {
+        check_fidelity(&tape, target_format)?;
+    }
+
     // Create appropriate emitter and emit
     let output = match target_format {
         FormatKind::Json => {
@@ -246,6 +263,26 @@ pub fn transform(
             let emitter = ToonEmitter::new(options);
             emitter.emit(&tape)?
         }
+        #[cfg(feature = "hcl")]
+        FormatKind::Hcl => {
+            let emitter = HclEmitter::new(options);
+            emitter.emit(&tape)?
+        }
+        #[cfg(feature = "ini")]
+        FormatKind::Ini => {
+            let emitter = IniEmitter::new(options);
+            emitter.emit(&tape)?
+        }
+        #[cfg(feature = "properties")]
+        FormatKind::Properties => {
+            let emitter = PropertiesEmitter::new(options);
+            emitter.emit(&tape)?
+        }
+        #[cfg(feature = "env")]
+        FormatKind::Env => {
+            let emitter = EnvEmitter::new(options);
+            emitter.emit(&tape)?
+        }
     };
 
     metrics.record_emit(output.len());
@@ -272,6 +309,10 @@ pub fn transform_into(
     let tape = UnifiedTape::parse(input, source_format)?;
     metrics.record_parse(input.len());
 
+    if options.fidelity == TransformFidelity::Strict && source_format != target_format {
+        check_fidelity(&tape, target_format)?;
+    }
+
     output.clear();
 
     match target_format {
@@ -304,6 +345,26 @@ pub fn transform_into(
             let emitter = ToonEmitter::new(options);
             emitter.emit_into(&tape, output)?;
         }
+        #[cfg(feature = "hcl")]
+        FormatKind::Hcl => {
+            let emitter = HclEmitter::new(options);
+            emitter.emit_into(&tape, output)?;
+        }
+        #[cfg(feature = "ini")]
+        FormatKind::Ini => {
+            let emitter = IniEmitter::new(options);
+            emitter.emit_into(&tape, output)?;
+        }
+        #[cfg(feature = "properties")]
+        FormatKind::Properties => {
+            let emitter = PropertiesEmitter::new(options);
+            emitter.emit_into(&tape, output)?;
+        }
+        #[cfg(feature = "env")]
+        FormatKind::Env => {
+            let emitter = EnvEmitter::new(options);
+            emitter.emit_into(&tape, output)?;
+        }
     }
 
     metrics.record_emit(output.len());
@@ -311,3 +372,150 @@ pub fn transform_into(
 
     Ok(metrics)
 }
+
+/// Report the first element `target` cannot represent
+///
+/// Used in [`TransformFidelity::Strict`] mode: comments, arrays, nesting
+/// deeper than the target allows and typed scalars bound for string-only
+/// formats are reported as [`TransformError::InformationLoss`].
+fn check_fidelity(tape: &UnifiedTape<'_>, target: FormatKind) -> TransformResult<()> {
+    let loss = |path: &[String], lost_element: &str| {
+        let mut display = String::from("$");
+        for segment in path {
+            display.push('.');
+            display.push_str(segment);
+        }
+        Err(TransformError::InformationLoss {
+            path: display,
+            lost_element: lost_element.to_string(),
+            source_format: tape.source_format,
+            target_format: target,
+        })
+    };
+
+    tape.walk_paths(|path, event| match event {
+        PathEvent::Comment(_) if !target.supports_comments() => loss(path, "comment"),
+        PathEvent::Container { array: true, .. } if !target.supports_arrays() => {
+            loss(path, "array")
+        }
+        PathEvent::Container { depth, .. }
+            if target.max_nesting().is_some_and(|max| depth > max) =>
+        {
+            loss(path, "nested object")
+        }
+        PathEvent::Scalar(value)
+            if !target.supports_typed_scalars() && !matches!(value, TapeValue::String(_)) =>
+        {
+            loss(path, "typed value")
+        }
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(
+        input: &str,
+        from: FormatKind,
+        to: FormatKind,
+        options: &TransformOptions,
+    ) -> String {
+        let (output, _) = transform(input.as_bytes(), from, to, options).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[cfg(feature = "hcl")]
+    #[test]
+    fn test_hcl_round_trip_through_json() {
+        let options = TransformOptions::new();
+        let hcl = "service \"api\" {\n  port = 8080\n  hosts = [\"a\", \"b\"]\n  region = var.region\n}\n";
+        let json = convert(hcl, FormatKind::Hcl, FormatKind::Json, &options);
+        let back = convert(&json, FormatKind::Json, FormatKind::Hcl, &options);
+        assert!(back.contains("service {\n"));
+        assert!(back.contains("region = var.region\n"));
+        let reparsed = convert(&back, FormatKind::Hcl, FormatKind::Json, &options);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&reparsed).unwrap()
+        );
+    }
+
+    #[cfg(feature = "ini")]
+    #[test]
+    fn test_emit_ini_sections() {
+        let options = TransformOptions::new();
+        let json = r#"{"server": {"host": "example.com", "tls": {"port": 443}}, "name": "demo"}"#;
+        let ini = convert(json, FormatKind::Json, FormatKind::Ini, &options);
+        assert_eq!(
+            ini,
+            "name = demo\n\n[server]\nhost = example.com\ntls.port = 443\n"
+        );
+    }
+
+    #[cfg(feature = "properties")]
+    #[test]
+    fn test_emit_properties_flattens_keys() {
+        let options = TransformOptions::new();
+        let json = r#"{"server": {"port": 8080, "name": "my app"}, "list": ["a", "b"]}"#;
+        let props = convert(json, FormatKind::Json, FormatKind::Properties, &options);
+        assert_eq!(
+            props,
+            "list.0=a\nlist.1=b\nserver.name=my app\nserver.port=8080\n"
+        );
+    }
+
+    #[cfg(feature = "env")]
+    #[test]
+    fn test_emit_env_quotes_values() {
+        let options = TransformOptions::new();
+        let json = r#"{"db": {"url": "postgres://h/db"}, "GREETING": "hello world"}"#;
+        let env = convert(json, FormatKind::Json, FormatKind::Env, &options);
+        assert_eq!(env, "GREETING=\"hello world\"\ndb_url=postgres://h/db\n");
+    }
+
+    #[cfg(feature = "env")]
+    #[test]
+    fn test_strict_reports_loss_for_env() {
+        let strict = TransformOptions::new().with_fidelity(TransformFidelity::Strict);
+        let nested = br#"{"db": {"port": "5432"}}"#;
+        let err = transform(nested, FormatKind::Json, FormatKind::Env, &strict).unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::InformationLoss { ref path, ref lost_element, .. }
+                if path == "$.db" && lost_element == "nested object"
+        ));
+
+        let typed = br#"{"PORT": 5432}"#;
+        let err = transform(typed, FormatKind::Json, FormatKind::Env, &strict).unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::InformationLoss { ref lost_element, .. } if lost_element == "typed value"
+        ));
+
+        let flat = br#"{"PORT": "5432"}"#;
+        assert!(transform(flat, FormatKind::Json, FormatKind::Env, &strict).is_ok());
+    }
+
+    #[cfg(feature = "ini")]
+    #[test]
+    fn test_strict_reports_dropped_comments() {
+        let strict = TransformOptions::new().with_fidelity(TransformFidelity::Strict);
+        let ini = b"; owner: ops\n[server]\nhost = a\n";
+        let err = transform(ini, FormatKind::Ini, FormatKind::Json, &strict).unwrap_err();
+        assert!(matches!(
+            err,
+            TransformError::InformationLoss { ref lost_element, .. } if lost_element == "comment"
+        ));
+        assert!(
+            transform(
+                ini,
+                FormatKind::Ini,
+                FormatKind::Json,
+                &TransformOptions::new()
+            )
+            .is_ok()
+        );
+    }
+}
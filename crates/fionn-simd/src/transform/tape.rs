@@ -7,7 +7,18 @@
 
 use super::{TransformError, TransformResult};
 use fionn_core::format::{FormatKind, NodeKind};
+use fionn_core::limits::{LimitViolation, ParseLimits, check_json, locate_keys};
+use fionn_core::value_builder::PathSegment;
+use fionn_tape::StructuralIndex;
+use rayon::prelude::*;
 use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Inputs below this size are parsed serially by [`UnifiedTape::parse_json_parallel`]
+const PARALLEL_MIN_LEN: usize = 1 << 20;
+
+/// Nesting depth at which `serde_json` stops with a recursion error
+const SERDE_RECURSION_LIMIT: usize = 128;
 
 /// A node in the unified tape
 #[derive(Debug, Clone)]
@@ -140,6 +151,23 @@ pub enum DefKind {
     Generic,
 }
 
+/// Node reached by [`UnifiedTape::walk_paths`]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PathEvent<'t> {
+    /// Start of an object, array or tabular row
+    Container {
+        /// Whether the container is an array
+        array: bool,
+        /// Nesting depth, 1 for the root container
+        depth: usize,
+    },
+    /// Scalar value
+    Scalar(&'t TapeValue<'t>),
+    /// Comment text
+    #[allow(dead_code)] // Text is only read by the flat-format emitters
+    Comment(&'t str),
+}
+
 /// Unified tape structure
 #[derive(Debug)]
 pub struct UnifiedTape<'a> {
@@ -206,7 +234,7 @@ impl<'a> UnifiedTape<'a> {
     /// Returns an error if the input cannot be parsed as the specified format.
     pub fn parse(input: &'a [u8], format: FormatKind) -> TransformResult<Self> {
         match format {
-            FormatKind::Json => Self::parse_json(input),
+            FormatKind::Json => Self::parse_json(input, &HashMap::new()),
             #[cfg(feature = "yaml")]
             FormatKind::Yaml => Self::parse_yaml(input),
             #[cfg(feature = "toml")]
@@ -217,13 +245,115 @@ impl<'a> UnifiedTape<'a> {
             FormatKind::Ison => Self::parse_ison(input),
             #[cfg(feature = "toon")]
             FormatKind::Toon => Self::parse_toon(input),
+            #[cfg(feature = "hcl")]
+            FormatKind::Hcl => Self::parse_hcl(input),
+            #[cfg(feature = "ini")]
+            FormatKind::Ini => Self::parse_ini(input),
+            #[cfg(feature = "properties")]
+            FormatKind::Properties => Self::parse_properties(input),
+            #[cfg(feature = "env")]
+            FormatKind::Env => Self::parse_env(input),
+        }
+    }
+
+    /// Parse input into unified tape, enforcing `limits`
+    ///
+    /// JSON is checked in full before parsing, and out-of-range integers
+    /// kept under [`BigIntegers::Preserve`](fionn_core::limits::BigIntegers::Preserve)
+    /// become [`TapeValue::RawNumber`]. Other formats are checked after
+    /// parsing for document size, nesting depth and string length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be parsed as the specified
+    /// format or breaks a limit.
+    pub fn parse_with_limits(
+        input: &'a [u8],
+        format: FormatKind,
+        limits: &ParseLimits,
+    ) -> TransformResult<Self> {
+        let limit_error = |violation: LimitViolation| TransformError::ParseError {
+            format,
+            message: violation.to_string(),
+        };
+        if format == FormatKind::Json {
+            let report = check_json(input, limits).map_err(limit_error)?;
+            let raw_numbers = report
+                .raw_numbers
+                .into_iter()
+                .map(|raw| (raw.path, raw.lexeme))
+                .collect();
+            return Self::parse_json(input, &raw_numbers);
+        }
+        let tape = Self::parse(input, format)?;
+        tape.check_limits(input, limits).map_err(limit_error)?;
+        Ok(tape)
+    }
+
+    /// Check a parsed tape's size, depth and string lengths
+    ///
+    /// Offsets are found by searching `input` for the text of the keys and
+    /// strings involved, as the parsers keep no positions.
+    fn check_limits(&self, input: &[u8], limits: &ParseLimits) -> Result<(), LimitViolation> {
+        limits.check_shape(input.len(), self.stats.max_depth, |limit| {
+            locate_keys(input, self.keys_to_depth(limit + 1))
+        })?;
+        let Some(limit) = limits.max_string_len else {
+            return Ok(());
+        };
+        let too_long = |s: &str| {
+            (s.len() > limit).then(|| LimitViolation::StringTooLong {
+                offset: locate_keys(input, [s]),
+                len: s.len(),
+                limit,
+            })
+        };
+        let string_value = |value: &TapeValue<'_>| match value {
+            TapeValue::String(s) => too_long(s),
+            _ => None,
+        };
+        let violation = self.nodes.iter().find_map(|node| match node {
+            TapeNode::Key(s) => too_long(s),
+            TapeNode::Value(value) => string_value(value),
+            TapeNode::TabularRow { values } => values.iter().find_map(string_value),
+            _ => None,
+        });
+        violation.map_or(Ok(()), Err)
+    }
+
+    /// Keys on the way to the first container opened `depth` levels deep
+    fn keys_to_depth(&self, depth: usize) -> Vec<&str> {
+        // Key of each open container, `None` for array elements and the root
+        let mut open = Vec::new();
+        let mut key = None;
+        for node in &self.nodes {
+            match node {
+                TapeNode::Key(k) => key = Some(k.as_ref()),
+                TapeNode::ObjectStart { .. } | TapeNode::ArrayStart { .. } => {
+                    open.push(key.take());
+                    if open.len() >= depth {
+                        break;
+                    }
+                }
+                TapeNode::ObjectEnd | TapeNode::ArrayEnd => {
+                    open.pop();
+                }
+                TapeNode::Value(_) => key = None,
+                _ => {}
+            }
         }
+        open.into_iter().flatten().collect()
     }
 
     /// Parse JSON into unified tape
+    ///
+    /// Numbers at a path in `raw_numbers` are stored as written.
     #[allow(clippy::items_after_statements)] // Nested helper functions for recursive descent parsing
     #[allow(clippy::missing_transmute_annotations)] // serde_json internal transmutes
-    fn parse_json(input: &'a [u8]) -> TransformResult<Self> {
+    fn parse_json(
+        input: &'a [u8],
+        raw_numbers: &HashMap<Vec<PathSegment>, String>,
+    ) -> TransformResult<Self> {
         let input_str = std::str::from_utf8(input).map_err(|e| TransformError::ParseError {
             format: FormatKind::Json,
             message: e.to_string(),
@@ -240,12 +370,35 @@ impl<'a> UnifiedTape<'a> {
                 message: e.to_string(),
             })?;
 
+        /// Output tape plus the state threaded through the recursion
+        struct Emitter<'e, 'a> {
+            tape: &'e mut UnifiedTape<'a>,
+            raw_numbers: &'e HashMap<Vec<PathSegment>, String>,
+            path: Vec<PathSegment>,
+        }
+
+        impl Emitter<'_, '_> {
+            /// Descend into a child when raw numbers need its path
+            fn enter(&mut self, segment: impl FnOnce() -> PathSegment) {
+                if !self.raw_numbers.is_empty() {
+                    self.path.push(segment());
+                }
+            }
+
+            fn leave(&mut self) {
+                if !self.raw_numbers.is_empty() {
+                    self.path.pop();
+                }
+            }
+        }
+
         fn emit_value<'a>(
-            tape: &mut UnifiedTape<'a>,
+            emitter: &mut Emitter<'_, 'a>,
             value: &'a serde_json::Value,
             depth: &mut usize,
             max_depth: &mut usize,
         ) {
+            let tape = &mut *emitter.tape;
             match value {
                 serde_json::Value::Null => {
                     tape.push_value(TapeValue::Null);
@@ -256,6 +409,8 @@ impl<'a> UnifiedTape<'a> {
                 serde_json::Value::Number(n) => {
                     if let Some(i) = n.as_i64() {
                         tape.push_value(TapeValue::Int(i));
+                    } else if let Some(raw) = emitter.raw_numbers.get(&emitter.path) {
+                        tape.push_value(TapeValue::RawNumber(Cow::Owned(raw.clone())));
                     } else if let Some(f) = n.as_f64() {
                         tape.push_value(TapeValue::Float(f));
                     } else {
@@ -270,10 +425,12 @@ impl<'a> UnifiedTape<'a> {
                     *depth += 1;
                     *max_depth = (*max_depth).max(*depth);
                     tape.push_array_start(arr.len());
-                    for item in arr {
-                        emit_value(tape, item, depth, max_depth);
+                    for (index, item) in arr.iter().enumerate() {
+                        emitter.enter(|| PathSegment::Index(index));
+                        emit_value(emitter, item, depth, max_depth);
+                        emitter.leave();
                     }
-                    tape.push_array_end();
+                    emitter.tape.push_array_end();
                     *depth -= 1;
                 }
                 serde_json::Value::Object(obj) => {
@@ -281,10 +438,12 @@ impl<'a> UnifiedTape<'a> {
                     *max_depth = (*max_depth).max(*depth);
                     tape.push_object_start(obj.len());
                     for (key, val) in obj {
-                        tape.push_key(Cow::Owned(key.clone()));
-                        emit_value(tape, val, depth, max_depth);
+                        emitter.tape.push_key(Cow::Owned(key.clone()));
+                        emitter.enter(|| PathSegment::Field(key.clone()));
+                        emit_value(emitter, val, depth, max_depth);
+                        emitter.leave();
                     }
-                    tape.push_object_end();
+                    emitter.tape.push_object_end();
                     *depth -= 1;
                 }
             }
@@ -294,8 +453,13 @@ impl<'a> UnifiedTape<'a> {
         // This is a temporary solution - a proper implementation would use simd-json
         let value_ref: &serde_json::Value = &value;
         // SAFETY: We're using Cow::Owned for all strings, so lifetime is fine
+        let mut emitter = Emitter {
+            tape: unsafe { std::mem::transmute(&mut tape) },
+            raw_numbers,
+            path: Vec::new(),
+        };
         emit_value(
-            unsafe { std::mem::transmute(&mut tape) },
+            &mut emitter,
             unsafe { std::mem::transmute::<&serde_json::Value, &'a serde_json::Value>(value_ref) },
             &mut depth,
             &mut max_depth,
@@ -306,6 +470,139 @@ impl<'a> UnifiedTape<'a> {
         Ok(tape)
     }
 
+    /// Parse JSON into unified tape, parsing the root's members in parallel
+    ///
+    /// Produces exactly the tape [`UnifiedTape::parse`] would. The members of
+    /// a root array or object are located with a parallel
+    /// [`StructuralIndex`] and parsed on the thread pool, then joined in
+    /// order, with duplicate keys resolved as the serial parser does. Scalar
+    /// roots, inputs under 1 MiB, a single-threaded pool and anything the
+    /// split does not parse cleanly go to the serial parser, which also
+    /// reports errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid JSON.
+    pub fn parse_json_parallel(input: &'a [u8]) -> TransformResult<Self> {
+        if rayon::current_num_threads() == 1 || input.len() < PARALLEL_MIN_LEN {
+            return Self::parse_json(input, &HashMap::new());
+        }
+        Self::parse_members(input, &StructuralIndex::build(input))
+            .map_or_else(|| Self::parse_json(input, &HashMap::new()), Ok)
+    }
+
+    /// Parse JSON into unified tape in parallel, indexing in chunks of
+    /// `chunk_size` bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid JSON.
+    pub fn parse_json_parallel_with_chunk_size(
+        input: &'a [u8],
+        chunk_size: usize,
+    ) -> TransformResult<Self> {
+        Self::parse_members(input, &StructuralIndex::with_chunk_size(input, chunk_size))
+            .map_or_else(|| Self::parse_json(input, &HashMap::new()), Ok)
+    }
+
+    /// Build the tape from the root container's members, or `None` to defer
+    /// to the serial parser
+    fn parse_members(input: &'a [u8], index: &StructuralIndex) -> Option<Self> {
+        let [open, close] = index.tokens_at_depth(input, 0)[..] else {
+            return None;
+        };
+        let is_object = match (input[open], input[close]) {
+            (b'{', b'}') => true,
+            (b'[', b']') => false,
+            _ => return None,
+        };
+        let separators: Vec<usize> = index
+            .tokens_at_depth(input, 1)
+            .into_iter()
+            .filter(|&pos| matches!(input[pos], b',' | b':'))
+            .collect();
+        let bounds: Vec<usize> = std::iter::once(open)
+            .chain(separators.iter().copied())
+            .chain(std::iter::once(close))
+            .collect();
+        let segments: Vec<&'a [u8]> = bounds
+            .windows(2)
+            .map(|pair| &input[pair[0] + 1..pair[1]])
+            .collect();
+
+        let empty = separators.is_empty() && segments[0].iter().all(u8::is_ascii_whitespace);
+        let member = |segment: &'a [u8]| Self::parse_json(segment, &HashMap::new()).ok();
+        let entries: Vec<(Option<String>, Self)> = if empty {
+            Vec::new()
+        } else if is_object {
+            // Object members alternate `key: value` and `,`
+            let paired = separators
+                .iter()
+                .enumerate()
+                .all(|(i, &pos)| input[pos] == if i % 2 == 0 { b':' } else { b',' });
+            if !paired || !segments.len().is_multiple_of(2) {
+                return None;
+            }
+            let pairs: Vec<(String, Self)> = segments
+                .par_chunks(2)
+                .map(|pair| Some((serde_json::from_slice(pair[0]).ok()?, member(pair[1])?)))
+                .collect::<Option<_>>()?;
+            // Replay serde_json's inserts for its key order and last-wins
+            let mut order = serde_json::Map::new();
+            for (i, (key, _)) in pairs.iter().enumerate() {
+                order.insert(key.clone(), serde_json::Value::from(i));
+            }
+            let mut pairs: Vec<Option<(String, Self)>> = pairs.into_iter().map(Some).collect();
+            order
+                .values()
+                .filter_map(|i| pairs[usize::try_from(i.as_u64()?).ok()?].take())
+                .map(|(key, value)| (Some(key), value))
+                .collect()
+        } else {
+            if separators.iter().any(|&pos| input[pos] != b',') {
+                return None;
+            }
+            segments
+                .par_iter()
+                .map(|segment| Some((None, member(segment)?)))
+                .collect::<Option<_>>()?
+        };
+
+        let len: usize = entries.iter().map(|(_, value)| value.nodes.len() + 1).sum();
+        let mut tape = Self::with_capacity(FormatKind::Json, len + 2);
+        if is_object {
+            tape.push_object_start(entries.len());
+        } else {
+            tape.push_array_start(entries.len());
+        }
+        let mut depth = 0;
+        for (key, value) in entries {
+            if let Some(key) = key {
+                tape.push_key(Cow::Owned(key));
+            }
+            depth = depth.max(value.stats.max_depth);
+            let stats = &mut tape.stats;
+            stats.object_count += value.stats.object_count;
+            stats.array_count += value.stats.array_count;
+            stats.string_count += value.stats.string_count;
+            stats.string_bytes += value.stats.string_bytes;
+            stats.number_count += value.stats.number_count;
+            tape.nodes.extend(value.nodes);
+        }
+        if is_object {
+            tape.push_object_end();
+        } else {
+            tape.push_array_end();
+        }
+        // Past serde_json's recursion limit the serial parser reports an error
+        if depth + 1 >= SERDE_RECURSION_LIMIT {
+            return None;
+        }
+        tape.stats.max_depth = depth + 1;
+        tape.finalize_stats();
+        Some(tape)
+    }
+
     /// Parse YAML into unified tape
     #[cfg(feature = "yaml")]
     fn parse_yaml(input: &'a [u8]) -> TransformResult<Self> {
@@ -808,6 +1105,233 @@ impl<'a> UnifiedTape<'a> {
         Ok(tape)
     }
 
+    /// Parse HCL into unified tape
+    ///
+    /// Blocks become nested objects keyed by block type and labels, the way
+    /// Terraform's JSON syntax spells them; repeated blocks at the same path
+    /// collect into an array. Anything other than a literal is kept as a
+    /// `"${...}"` template string.
+    #[cfg(feature = "hcl")]
+    fn parse_hcl(input: &'a [u8]) -> TransformResult<Self> {
+        let input_str = std::str::from_utf8(input).map_err(|e| TransformError::ParseError {
+            format: FormatKind::Hcl,
+            message: e.to_string(),
+        })?;
+
+        let mut reader = HclReader::new(input_str);
+        let body = reader.parse_body(false)?;
+
+        let mut tape = Self::with_capacity(FormatKind::Hcl, input.len() / 10);
+        for comment in reader.comments {
+            tape.nodes.push(TapeNode::Comment(Cow::Owned(comment)));
+            tape.stats.comment_count += 1;
+        }
+
+        let mut depth = 0;
+        let mut max_depth = 0;
+        emit_hcl_node(&mut tape, HclNode::Object(body), &mut depth, &mut max_depth);
+
+        tape.stats.max_depth = max_depth;
+        tape.finalize_stats();
+        Ok(tape)
+    }
+
+    /// Parse INI into unified tape
+    ///
+    /// Keys before the first `[section]` stay at the root, and each section
+    /// is an object introduced by a [`TapeNode::Section`]. Values are kept
+    /// as strings; a key without `=` reads as null.
+    #[cfg(feature = "ini")]
+    fn parse_ini(input: &'a [u8]) -> TransformResult<Self> {
+        let input_str = std::str::from_utf8(input).map_err(|e| TransformError::ParseError {
+            format: FormatKind::Ini,
+            message: e.to_string(),
+        })?;
+
+        let mut tape = Self::with_capacity(FormatKind::Ini, input.len() / 10);
+        let mut in_section = false;
+        tape.push_object_start(0);
+
+        for (line_no, line) in input_str.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some(comment_text) = trimmed.strip_prefix([';', '#']) {
+                tape.nodes.push(TapeNode::Comment(Cow::Owned(
+                    comment_text.trim().to_string(),
+                )));
+                tape.stats.comment_count += 1;
+                continue;
+            }
+
+            if let Some(header) = trimmed.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| TransformError::ParseError {
+                        format: FormatKind::Ini,
+                        message: format!("line {}: unterminated section header", line_no + 1),
+                    })?;
+                if in_section {
+                    tape.push_object_end();
+                }
+                tape.nodes.push(TapeNode::Section {
+                    path: vec![Cow::Owned(name.trim().to_string())],
+                });
+                tape.push_object_start(0);
+                in_section = true;
+                continue;
+            }
+
+            if let Some(sep) = trimmed.find(['=', ':']) {
+                let value = strip_matching_quotes(trimmed[sep + 1..].trim_start());
+                tape.push_key(Cow::Owned(trimmed[..sep].trim_end().to_string()));
+                tape.push_value(TapeValue::String(Cow::Owned(value.to_string())));
+            } else {
+                tape.push_key(Cow::Owned(trimmed.to_string()));
+                tape.push_value(TapeValue::Null);
+            }
+        }
+
+        if in_section {
+            tape.push_object_end();
+        }
+        tape.push_object_end();
+
+        tape.stats.max_depth = if in_section { 2 } else { 1 };
+        tape.finalize_stats();
+        Ok(tape)
+    }
+
+    /// Parse Java `.properties` into unified tape
+    ///
+    /// Keys are kept flat (`server.port` stays one key) so the document
+    /// round-trips; values are unescaped strings.
+    #[cfg(feature = "properties")]
+    fn parse_properties(input: &'a [u8]) -> TransformResult<Self> {
+        let input_str = std::str::from_utf8(input).map_err(|e| TransformError::ParseError {
+            format: FormatKind::Properties,
+            message: e.to_string(),
+        })?;
+
+        let mut tape = Self::with_capacity(FormatKind::Properties, input.len() / 10);
+        tape.push_object_start(0);
+
+        let mut lines = input_str.lines();
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some(comment_text) = trimmed.strip_prefix(['#', '!']) {
+                tape.nodes.push(TapeNode::Comment(Cow::Owned(
+                    comment_text.trim().to_string(),
+                )));
+                tape.stats.comment_count += 1;
+                continue;
+            }
+
+            // Join continuation lines (odd number of trailing backslashes)
+            let mut logical = trimmed.to_string();
+            while (logical.len() - logical.trim_end_matches('\\').len()) % 2 == 1 {
+                logical.pop();
+                match lines.next() {
+                    Some(next) => logical.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+
+            let (key, value) = split_property(&logical);
+            tape.push_key(Cow::Owned(unescape_property(key)));
+            tape.push_value(TapeValue::String(Cow::Owned(unescape_property(value))));
+        }
+
+        tape.push_object_end();
+        tape.stats.max_depth = 1;
+        tape.finalize_stats();
+        Ok(tape)
+    }
+
+    /// Parse a dotenv file into unified tape
+    ///
+    /// Accepts `NAME=value` and `export NAME=value`. Double-quoted values are
+    /// unescaped and may span lines, single-quoted values are literal, and
+    /// `${VAR}` interpolations are kept verbatim.
+    #[cfg(feature = "env")]
+    fn parse_env(input: &'a [u8]) -> TransformResult<Self> {
+        let input_str = std::str::from_utf8(input).map_err(|e| TransformError::ParseError {
+            format: FormatKind::Env,
+            message: e.to_string(),
+        })?;
+
+        let mut tape = Self::with_capacity(FormatKind::Env, input.len() / 10);
+        tape.push_object_start(0);
+
+        let mut lines = input_str.lines().enumerate();
+        while let Some((line_no, line)) = lines.next() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some(comment_text) = trimmed.strip_prefix('#') {
+                tape.nodes.push(TapeNode::Comment(Cow::Owned(
+                    comment_text.trim().to_string(),
+                )));
+                tape.stats.comment_count += 1;
+                continue;
+            }
+
+            let assignment = trimmed
+                .strip_prefix("export ")
+                .map_or(trimmed, str::trim_start);
+            let Some((name, raw)) = assignment.split_once('=') else {
+                return Err(TransformError::ParseError {
+                    format: FormatKind::Env,
+                    message: format!("line {}: expected NAME=value", line_no + 1),
+                });
+            };
+
+            let raw = raw.trim_start();
+            let value = match raw.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let mut text = raw[1..].to_string();
+                    // Quoted values run until the closing quote, across lines
+                    loop {
+                        if let Some(end) = find_closing_quote(&text, quote) {
+                            text.truncate(end);
+                            break;
+                        }
+                        let Some((_, next)) = lines.next() else {
+                            return Err(TransformError::ParseError {
+                                format: FormatKind::Env,
+                                message: format!("line {}: unterminated quoted value", line_no + 1),
+                            });
+                        };
+                        text.push('\n');
+                        text.push_str(next);
+                    }
+                    if quote == '"' {
+                        unescape_env(&text)
+                    } else {
+                        text
+                    }
+                }
+                _ => strip_env_comment(raw).to_string(),
+            };
+
+            tape.push_key(Cow::Owned(name.trim_end().to_string()));
+            tape.push_value(TapeValue::String(Cow::Owned(value)));
+        }
+
+        tape.push_object_end();
+        tape.stats.max_depth = 1;
+        tape.finalize_stats();
+        Ok(tape)
+    }
+
     /// Push object start
     pub fn push_object_start(&mut self, count: usize) {
         self.nodes.push(TapeNode::ObjectStart { count });
@@ -851,6 +1375,107 @@ impl<'a> UnifiedTape<'a> {
         self.nodes.push(TapeNode::Value(value));
     }
 
+    /// Walk the tape, reporting containers, scalars and comments with their key path
+    ///
+    /// Array elements are addressed by index, and tabular rows are expanded
+    /// under the preceding header's field names.
+    pub(crate) fn walk_paths<'t, F>(&'t self, mut visit: F) -> TransformResult<()>
+    where
+        F: FnMut(&[String], PathEvent<'t>) -> TransformResult<()>,
+    {
+        struct Frame {
+            array: bool,
+            next_index: usize,
+            named: bool,
+        }
+
+        /// Name the next child: a pending key, or the next index in an array
+        fn child_segment(frames: &mut [Frame], pending: &mut Option<String>) -> Option<String> {
+            if let Some(key) = pending.take() {
+                return Some(key);
+            }
+            let frame = frames.last_mut().filter(|f| f.array)?;
+            frame.next_index += 1;
+            Some((frame.next_index - 1).to_string())
+        }
+
+        let mut path: Vec<String> = Vec::new();
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut pending: Option<String> = None;
+        let mut fields: Option<&'t [Cow<'a, str>]> = None;
+
+        for node in &self.nodes {
+            match node {
+                TapeNode::Key(key) => pending = Some(key.to_string()),
+                TapeNode::Section { path: section } if !frames.is_empty() => {
+                    pending = Some(section_key(section));
+                }
+                TapeNode::ObjectStart { .. } | TapeNode::ArrayStart { .. } => {
+                    let array = matches!(node, TapeNode::ArrayStart { .. });
+                    let segment = child_segment(&mut frames, &mut pending);
+                    let named = segment.is_some();
+                    path.extend(segment);
+                    frames.push(Frame {
+                        array,
+                        next_index: 0,
+                        named,
+                    });
+                    visit(
+                        &path,
+                        PathEvent::Container {
+                            array,
+                            depth: frames.len(),
+                        },
+                    )?;
+                }
+                TapeNode::ObjectEnd | TapeNode::ArrayEnd => {
+                    let frame = frames.pop();
+                    if frame.is_some_and(|frame| frame.named) {
+                        path.pop();
+                    }
+                }
+                TapeNode::Value(value) => {
+                    let segment = child_segment(&mut frames, &mut pending);
+                    let named = segment.is_some();
+                    path.extend(segment);
+                    visit(&path, PathEvent::Scalar(value))?;
+                    if named {
+                        path.pop();
+                    }
+                }
+                TapeNode::TabularHeader { fields: names, .. } => fields = Some(names),
+                TapeNode::TabularRow { values } => {
+                    let segment = child_segment(&mut frames, &mut pending);
+                    let named = segment.is_some();
+                    path.extend(segment);
+                    visit(
+                        &path,
+                        PathEvent::Container {
+                            array: fields.is_none(),
+                            depth: frames.len() + 1,
+                        },
+                    )?;
+                    for (i, value) in values.iter().enumerate() {
+                        path.push(
+                            fields
+                                .and_then(|names| names.get(i))
+                                .map_or_else(|| i.to_string(), ToString::to_string),
+                        );
+                        visit(&path, PathEvent::Scalar(value))?;
+                        path.pop();
+                    }
+                    if named {
+                        path.pop();
+                    }
+                }
+                TapeNode::Comment(text) => visit(&path, PathEvent::Comment(text))?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
     /// Finalize statistics
     #[allow(clippy::missing_const_for_fn)] // Vec::len() is not const
     fn finalize_stats(&mut self) {
@@ -873,6 +1498,7 @@ impl<'a> UnifiedTape<'a> {
 }
 
 /// Parse a simple YAML-like value
+#[cfg(any(feature = "yaml", feature = "toon"))]
 fn parse_yaml_value(s: &str) -> TapeValue<'_> {
     let trimmed = s.trim();
 
@@ -948,6 +1574,640 @@ fn parse_ison_value(s: &str) -> TapeValue<'static> {
     TapeValue::String(Cow::Owned(unquoted.to_string()))
 }
 
+/// Key under which a section's object sits, its path joined with `.`
+pub(crate) fn section_key(path: &[Cow<'_, str>]) -> String {
+    path.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(".")
+}
+
+/// Remove one pair of matching surrounding quotes
+#[cfg(feature = "ini")]
+fn strip_matching_quotes(s: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = s
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    s
+}
+
+/// Split a logical `.properties` line into raw key and value
+///
+/// The key ends at the first unescaped `=`, `:` or whitespace; whitespace
+/// around a single separator is dropped.
+#[cfg(feature = "properties")]
+fn split_property(line: &str) -> (&str, &str) {
+    const BLANK: [char; 3] = [' ', '\t', '\x0c'];
+    let bytes = line.as_bytes();
+    let mut end = 0;
+    while end < bytes.len() {
+        match bytes[end] {
+            b'\\' => end += 2,
+            b'=' | b':' | b' ' | b'\t' | b'\x0c' => break,
+            _ => end += 1,
+        }
+    }
+    let end = end.min(bytes.len());
+    let rest = line[end..].trim_start_matches(BLANK);
+    let value = rest
+        .strip_prefix(['=', ':'])
+        .map_or(rest, |v| v.trim_start_matches(BLANK));
+    (&line[..end], value)
+}
+
+/// Resolve `.properties` escapes (`\t`, `\n`, `\uXXXX`, `\=` ...)
+#[cfg(feature = "properties")]
+fn unescape_property(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\x0c'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                if let Some(ch) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    out.push(ch);
+                } else {
+                    out.push_str("\\u");
+                    out.push_str(&hex);
+                }
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Byte offset of the quote closing a dotenv value
+#[cfg(feature = "env")]
+fn find_closing_quote(text: &str, quote: char) -> Option<usize> {
+    if quote == '\'' {
+        return text.find('\'');
+    }
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Resolve escapes in a double-quoted dotenv value
+#[cfg(feature = "env")]
+fn unescape_env(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(ch @ ('\\' | '"' | '$')) => out.push(ch),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Drop a trailing ` # comment` from an unquoted dotenv value
+#[cfg(feature = "env")]
+fn strip_env_comment(raw: &str) -> &str {
+    let end = raw
+        .char_indices()
+        .find(|&(i, c)| c == '#' && raw[..i].ends_with([' ', '\t']))
+        .map_or(raw.len(), |(i, _)| i);
+    raw[..end].trim_end()
+}
+
+/// HCL value tree, built before emission so repeated blocks can be merged
+#[cfg(feature = "hcl")]
+enum HclNode {
+    Value(TapeValue<'static>),
+    List(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+#[cfg(feature = "hcl")]
+impl HclNode {
+    /// Insert a block body at its type/label path
+    ///
+    /// Blocks sharing a parent path merge into one object; a block repeated
+    /// at the same full path turns into an array of bodies.
+    fn insert_block(entries: &mut Vec<(String, Self)>, path: &[String], body: Vec<(String, Self)>) {
+        let Some((last, parents)) = path.split_last() else {
+            return;
+        };
+        let mut current = entries;
+        for key in parents {
+            let index = current
+                .iter()
+                .position(|(k, v)| k == key && matches!(v, Self::Object(_)))
+                .unwrap_or_else(|| {
+                    current.push((key.clone(), Self::Object(Vec::new())));
+                    current.len() - 1
+                });
+            let Self::Object(inner) = &mut current[index].1 else {
+                return;
+            };
+            current = inner;
+        }
+        match current.iter_mut().find(|(k, _)| k == last) {
+            Some((_, Self::List(items))) => items.push(Self::Object(body)),
+            Some((_, existing)) => {
+                let first = std::mem::replace(existing, Self::List(Vec::new()));
+                *existing = Self::List(vec![first, Self::Object(body)]);
+            }
+            None => current.push((last.clone(), Self::Object(body))),
+        }
+    }
+}
+
+/// Emit an HCL value tree onto the tape
+#[cfg(feature = "hcl")]
+fn emit_hcl_node(
+    tape: &mut UnifiedTape<'_>,
+    node: HclNode,
+    depth: &mut usize,
+    max_depth: &mut usize,
+) {
+    match node {
+        HclNode::Value(value) => tape.push_value(value),
+        HclNode::List(items) => {
+            *depth += 1;
+            *max_depth = (*max_depth).max(*depth);
+            tape.push_array_start(items.len());
+            for item in items {
+                emit_hcl_node(tape, item, depth, max_depth);
+            }
+            tape.push_array_end();
+            *depth -= 1;
+        }
+        HclNode::Object(entries) => {
+            *depth += 1;
+            *max_depth = (*max_depth).max(*depth);
+            tape.push_object_start(entries.len());
+            for (key, value) in entries {
+                tape.push_key(Cow::Owned(key));
+                emit_hcl_node(tape, value, depth, max_depth);
+            }
+            tape.push_object_end();
+            *depth -= 1;
+        }
+    }
+}
+
+/// Recursive-descent reader for HCL native syntax
+#[cfg(feature = "hcl")]
+struct HclReader<'s> {
+    src: &'s str,
+    pos: usize,
+    comments: Vec<String>,
+}
+
+#[cfg(feature = "hcl")]
+impl<'s> HclReader<'s> {
+    const fn new(src: &'s str) -> Self {
+        Self {
+            src,
+            pos: 0,
+            comments: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.src.as_bytes().get(self.pos + offset).copied()
+    }
+
+    fn error(&self, message: &str) -> TransformError {
+        let end = self.pos.min(self.src.len());
+        let line = memchr::memchr_iter(b'\n', &self.src.as_bytes()[..end]).count() + 1;
+        TransformError::ParseError {
+            format: FormatKind::Hcl,
+            message: format!("line {line}: {message}"),
+        }
+    }
+
+    /// Skip blanks and comments, and newlines too when `newlines` is set
+    fn skip_space(&mut self, newlines: bool) {
+        while let Some(b) = self.peek() {
+            match b {
+                b' ' | b'\t' | b'\r' => self.pos += 1,
+                b'\n' if newlines => self.pos += 1,
+                b'#' => self.line_comment(1),
+                b'/' if self.peek_at(1) == Some(b'/') => self.line_comment(2),
+                b'/' if self.peek_at(1) == Some(b'*') => {
+                    let start = self.pos + 2;
+                    let end = self.src[start..]
+                        .find("*/")
+                        .map_or(self.src.len(), |i| start + i);
+                    self.comments.push(self.src[start..end].trim().to_string());
+                    self.pos = (end + 2).min(self.src.len());
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn line_comment(&mut self, marker_len: usize) {
+        let start = self.pos + marker_len;
+        let end = self.src[start..]
+            .find('\n')
+            .map_or(self.src.len(), |i| start + i);
+        self.comments.push(self.src[start..end].trim().to_string());
+        self.pos = end;
+    }
+
+    /// Check that the keyword `word` follows, as a whole word
+    fn at_keyword(&self, word: &str) -> bool {
+        self.src[self.pos..].starts_with(word)
+            && self
+                .src
+                .as_bytes()
+                .get(self.pos + word.len())
+                .is_some_and(u8::is_ascii_whitespace)
+    }
+
+    /// Check that an expression ends here (end of line, separator or closer)
+    fn at_expr_end(&mut self) -> bool {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\r')) {
+            self.pos += 1;
+        }
+        match self.peek() {
+            None | Some(b'\n' | b',' | b']' | b'}' | b')' | b'#') => true,
+            Some(b'/') => matches!(self.peek_at(1), Some(b'/' | b'*')),
+            _ => false,
+        }
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        let start = self.pos;
+        if !self
+            .peek()
+            .is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+        {
+            return None;
+        }
+        while self
+            .peek()
+            .is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        {
+            self.pos += 1;
+        }
+        Some(self.src[start..self.pos].to_string())
+    }
+
+    /// Parse a body of attributes and blocks, up to `}` when `nested`
+    fn parse_body(&mut self, nested: bool) -> TransformResult<Vec<(String, HclNode)>> {
+        let mut entries = Vec::new();
+        loop {
+            self.skip_space(true);
+            match self.peek() {
+                None if nested => return Err(self.error("unterminated block")),
+                None => return Ok(entries),
+                Some(b'}') if nested => {
+                    self.pos += 1;
+                    return Ok(entries);
+                }
+                _ => {}
+            }
+
+            let name = self
+                .ident()
+                .ok_or_else(|| self.error("expected attribute or block name"))?;
+            self.skip_space(false);
+
+            if self.peek() == Some(b'=') && self.peek_at(1) != Some(b'=') {
+                self.pos += 1;
+                let value = self.parse_expr()?;
+                entries.push((name, value));
+                self.skip_space(false);
+                if !matches!(self.peek(), None | Some(b'\n' | b'}')) {
+                    return Err(self.error("expected newline after attribute"));
+                }
+                continue;
+            }
+
+            let mut path = vec![name];
+            while self.peek() != Some(b'{') {
+                let label = if self.peek() == Some(b'"') {
+                    self.string()?
+                } else {
+                    self.ident()
+                        .ok_or_else(|| self.error("expected block label or '{'"))?
+                };
+                path.push(label);
+                self.skip_space(false);
+            }
+            self.pos += 1;
+            let body = self.parse_body(true)?;
+            HclNode::insert_block(&mut entries, &path, body);
+        }
+    }
+
+    /// Parse an expression, keeping non-literals as template strings
+    fn parse_expr(&mut self) -> TransformResult<HclNode> {
+        self.skip_space(false);
+        let start = self.pos;
+        let comments = self.comments.len();
+
+        let literal = match self.peek() {
+            Some(b'"') => Some(HclNode::Value(TapeValue::String(Cow::Owned(
+                self.string()?,
+            )))),
+            Some(b'[') => self.list()?,
+            Some(b'{') => self.object()?,
+            Some(b'<') if self.src[self.pos..].starts_with("<<") => Some(self.heredoc()?),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => match self.ident().as_deref() {
+                Some("true") => Some(HclNode::Value(TapeValue::Bool(true))),
+                Some("false") => Some(HclNode::Value(TapeValue::Bool(false))),
+                Some("null") => Some(HclNode::Value(TapeValue::Null)),
+                _ => None,
+            },
+            None => None,
+        };
+
+        if let Some(node) = literal
+            && self.at_expr_end()
+        {
+            return Ok(node);
+        }
+
+        self.pos = start;
+        self.comments.truncate(comments);
+        self.raw_expr()
+    }
+
+    /// Read an expression verbatim up to the end of the line or a closer
+    fn raw_expr(&mut self) -> TransformResult<HclNode> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(b) = self.peek() {
+            let at_comment =
+                b == b'#' || (b == b'/' && matches!(self.peek_at(1), Some(b'/' | b'*')));
+            match b {
+                _ if depth == 0
+                    && (at_comment || matches!(b, b'\n' | b',' | b')' | b']' | b'}')) =>
+                {
+                    break;
+                }
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth -= 1,
+                b'"' => {
+                    self.string()?;
+                    continue;
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+
+        let expr = self.src[start..self.pos].trim();
+        if expr.is_empty() {
+            return Err(self.error("expected expression"));
+        }
+        Ok(HclNode::Value(TapeValue::String(Cow::Owned(format!(
+            "${{{expr}}}"
+        )))))
+    }
+
+    /// Parse a quoted string; template sequences are kept verbatim
+    fn string(&mut self) -> TransformResult<String> {
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let Some(c) = self.src[self.pos..].chars().next() else {
+                return Err(self.error("unterminated string"));
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                '\n' => return Err(self.error("unterminated string")),
+                '\\' => {
+                    let Some(esc) = self.src[self.pos + 1..].chars().next() else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.pos += 1 + esc.len_utf8();
+                    match esc {
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        '"' | '\\' => out.push(esc),
+                        'u' | 'U' => {
+                            let len = if esc == 'u' { 4 } else { 8 };
+                            let ch = self
+                                .src
+                                .get(self.pos..self.pos + len)
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            out.push(ch);
+                            self.pos += len;
+                        }
+                        other => {
+                            out.push('\\');
+                            out.push(other);
+                        }
+                    }
+                }
+                // `$${` and `%%{` escape a literal template opener
+                '$' | '%' if self.peek_at(1) == Some(c as u8) && self.peek_at(2) == Some(b'{') => {
+                    out.push_str(&self.src[self.pos..self.pos + 3]);
+                    self.pos += 3;
+                }
+                '$' | '%' if self.peek_at(1) == Some(b'{') => {
+                    let start = self.pos;
+                    self.pos += 2;
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match self.peek() {
+                            None => return Err(self.error("unterminated template")),
+                            Some(b'{') => depth += 1,
+                            Some(b'}') => depth -= 1,
+                            Some(b'"') => {
+                                self.string()?;
+                                continue;
+                            }
+                            _ => {}
+                        }
+                        self.pos += 1;
+                    }
+                    out.push_str(&self.src[start..self.pos]);
+                }
+                _ => {
+                    out.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+    }
+
+    fn number(&mut self) -> Option<HclNode> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        while matches!(
+            self.peek(),
+            Some(b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-')
+        ) {
+            self.pos += 1;
+        }
+        let token = &self.src[start..self.pos];
+        token.parse::<i64>().map_or_else(
+            |_| {
+                token
+                    .parse::<f64>()
+                    .ok()
+                    .map(|f| HclNode::Value(TapeValue::Float(f)))
+            },
+            |i| Some(HclNode::Value(TapeValue::Int(i))),
+        )
+    }
+
+    /// Parse a tuple literal; `None` for `[for ...]` expressions
+    fn list(&mut self) -> TransformResult<Option<HclNode>> {
+        self.pos += 1;
+        self.skip_space(true);
+        if self.at_keyword("for") {
+            return Ok(None);
+        }
+        let mut items = Vec::new();
+        loop {
+            self.skip_space(true);
+            match self.peek() {
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Some(HclNode::List(items)));
+                }
+                None => return Err(self.error("unterminated list")),
+                _ => {}
+            }
+            items.push(self.parse_expr()?);
+            self.skip_space(true);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {}
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    /// Parse an object literal; `None` for `{for ...}` or computed keys
+    fn object(&mut self) -> TransformResult<Option<HclNode>> {
+        self.pos += 1;
+        self.skip_space(true);
+        if self.at_keyword("for") {
+            return Ok(None);
+        }
+        let mut entries = Vec::new();
+        loop {
+            self.skip_space(true);
+            let key = match self.peek() {
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Some(HclNode::Object(entries)));
+                }
+                None => return Err(self.error("unterminated object")),
+                Some(b'"') => self.string()?,
+                _ => match self.ident() {
+                    Some(key) => key,
+                    None => return Ok(None),
+                },
+            };
+            self.skip_space(false);
+            if !matches!(self.peek(), Some(b'=' | b':')) {
+                return Ok(None);
+            }
+            self.pos += 1;
+            let value = self.parse_expr()?;
+            entries.push((key, value));
+            self.skip_space(false);
+            if self.peek() == Some(b',') {
+                self.pos += 1;
+            }
+        }
+    }
+
+    /// Parse `<<MARKER` / `<<-MARKER` heredocs; `-` strips common indentation
+    fn heredoc(&mut self) -> TransformResult<HclNode> {
+        self.pos += 2;
+        let indented = self.peek() == Some(b'-');
+        if indented {
+            self.pos += 1;
+        }
+        let marker = self
+            .ident()
+            .ok_or_else(|| self.error("expected heredoc marker"))?;
+        let mut cursor = self.src[self.pos..]
+            .find('\n')
+            .map(|i| self.pos + i + 1)
+            .ok_or_else(|| self.error("unterminated heredoc"))?;
+
+        let mut lines = Vec::new();
+        loop {
+            if cursor >= self.src.len() {
+                return Err(self.error("unterminated heredoc"));
+            }
+            let end = self.src[cursor..]
+                .find('\n')
+                .map_or(self.src.len(), |i| cursor + i);
+            let line = self.src[cursor..end].trim_end_matches('\r');
+            if line.trim() == marker {
+                self.pos = end;
+                break;
+            }
+            lines.push(line);
+            cursor = end + 1;
+        }
+
+        let strip = if indented {
+            lines
+                .iter()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.len() - line.trim_start().len())
+                .min()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let mut text = String::new();
+        for line in lines {
+            text.push_str(line.get(strip..).unwrap_or(""));
+            text.push('\n');
+        }
+        Ok(HclNode::Value(TapeValue::String(Cow::Owned(text))))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -968,6 +2228,130 @@ mod tests {
         assert_eq!(tape.stats.max_depth, 2);
     }
 
+    #[test]
+    fn test_parse_json_with_limits() {
+        use fionn_core::limits::DuplicateKeys;
+
+        let strict = ParseLimits::strict();
+        let input = br#"{"a": 1, "a": 2}"#;
+        // serde_json keeps the last value without complaint
+        assert!(UnifiedTape::parse(input, FormatKind::Json).is_ok());
+        let err = UnifiedTape::parse_with_limits(input, FormatKind::Json, &strict).unwrap_err();
+        assert!(err.to_string().contains("duplicate key"));
+
+        let report = strict.with_duplicate_keys(DuplicateKeys::Report);
+        assert!(UnifiedTape::parse_with_limits(input, FormatKind::Json, &report).is_ok());
+
+        let deep = b"[[[[1]]]]";
+        let shallow = ParseLimits::permissive().with_max_depth(3);
+        assert!(UnifiedTape::parse_with_limits(deep, FormatKind::Json, &shallow).is_err());
+    }
+
+    fn assert_parallel_same(json: &str) {
+        let serial = UnifiedTape::parse(json.as_bytes(), FormatKind::Json);
+        for size in [1, 3, 16, json.len().max(1)] {
+            let parallel = UnifiedTape::parse_json_parallel_with_chunk_size(json.as_bytes(), size);
+            match (&serial, parallel) {
+                (Ok(s), Ok(p)) => assert_eq!(format!("{s:?}"), format!("{p:?}"), "{json}"),
+                (Err(s), Err(p)) => assert_eq!(s.to_string(), p.to_string()),
+                (s, p) => panic!(
+                    "chunk size {size}: serial {:?} vs parallel {:?}",
+                    s.is_ok(),
+                    p.is_ok()
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_json_parallel_matches_serial() {
+        for json in [
+            r#"{"b": [1, {"c": "x,y:z"}], "a": -2.5e3, "d": {}, "e": []}"#,
+            r#"[ "s\"q", true, null, 18446744073709551615, {"k": [[], {}]} ]"#,
+            r#"{"dup": 1, "z": 0, "dup": [2]}"#,
+            " [ ] ",
+            "{}",
+            "42",
+            "[1,]",
+            "[,1]",
+            r#"{"a" 1}"#,
+            r#"{"a":1,}"#,
+            r#"{"a":1:2}"#,
+            "[1}",
+            "[1][2]",
+            "[1] x",
+        ] {
+            assert_parallel_same(json);
+        }
+    }
+
+    #[test]
+    fn test_parse_json_parallel_splits_root_members() {
+        let json = br#"{"a": [1, 2], "b": {"c": "d"}, "a": 3}"#;
+        let index = StructuralIndex::with_chunk_size(json, 5);
+        let tape = UnifiedTape::parse_members(json, &index).expect("members parse cleanly");
+        let serial = UnifiedTape::parse(json, FormatKind::Json).unwrap();
+        assert_eq!(format!("{tape:?}"), format!("{serial:?}"));
+    }
+
+    #[test]
+    fn test_parse_json_parallel_recursion_limit() {
+        for depth in 125..=130 {
+            assert_parallel_same(&format!("{}{}", "[".repeat(depth), "]".repeat(depth)));
+        }
+    }
+
+    #[test]
+    fn test_parse_json_preserves_big_integers() {
+        use fionn_core::limits::BigIntegers;
+
+        let input = br#"{"id": [7, 123456789012345678901234567890], "f": 1.5}"#;
+        let limits = ParseLimits::permissive().with_big_integers(BigIntegers::Preserve);
+        let tape = UnifiedTape::parse_with_limits(input, FormatKind::Json, &limits).unwrap();
+        let values: Vec<_> = tape
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                TapeNode::Value(value) => Some(value.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                TapeValue::Float(1.5),
+                TapeValue::Int(7),
+                TapeValue::RawNumber(Cow::Borrowed("123456789012345678901234567890")),
+            ]
+        );
+
+        // Without the policy the integer is rounded
+        let lossy = UnifiedTape::parse(input, FormatKind::Json).unwrap();
+        assert!(
+            lossy
+                .nodes
+                .iter()
+                .all(|node| !matches!(node, TapeNode::Value(TapeValue::RawNumber(_))))
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_parse_toml_with_limits() {
+        let input = b"name = \"a long string value\"\n[a.b.c]\nx = 1\n";
+        let limits = ParseLimits::permissive().with_max_string_len(8);
+        assert!(UnifiedTape::parse_with_limits(input, FormatKind::Toml, &limits).is_err());
+        let limits = ParseLimits::permissive().with_max_depth(2);
+        let err = UnifiedTape::parse_with_limits(input, FormatKind::Toml, &limits).unwrap_err();
+        // Table `b` of `[a.b.c]` is the first one nested past the limit
+        assert!(
+            err.to_string().contains("nesting deeper than 2 at byte 32"),
+            "{err}"
+        );
+        let limits = ParseLimits::permissive().with_max_document_size(input.len());
+        assert!(UnifiedTape::parse_with_limits(input, FormatKind::Toml, &limits).is_ok());
+    }
+
     #[test]
     fn test_tape_value_kind() {
         assert_eq!(TapeValue::Null.kind(), NodeKind::Null);
@@ -978,4 +2362,116 @@ mod tests {
             NodeKind::String
         );
     }
+
+    /// Render a tape as JSON for structural assertions
+    #[cfg(any(
+        feature = "hcl",
+        feature = "ini",
+        feature = "properties",
+        feature = "env"
+    ))]
+    fn to_json(tape: &UnifiedTape<'_>) -> serde_json::Value {
+        use super::super::{Emitter, JsonEmitter, TransformOptions};
+        let options = TransformOptions::new();
+        let bytes = JsonEmitter::new(&options).emit(tape).unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[cfg(feature = "hcl")]
+    #[test]
+    fn test_parse_hcl_blocks() {
+        let input = br#"
+# network
+resource "aws_instance" "web" {
+  ami           = "ami-123"
+  count         = 2
+  tags = {
+    Name = "web"
+  }
+  subnet_id = var.subnet
+  user_data = <<-EOT
+    #!/bin/sh
+    echo hi
+  EOT
+}
+
+resource "aws_instance" "db" {
+  ports = [5432, 5433]
+}
+
+ingress { port = 80 }
+ingress { port = 443 }
+"#;
+        let tape = UnifiedTape::parse(input, FormatKind::Hcl).unwrap();
+        assert_eq!(tape.stats.comment_count, 1);
+        let json = to_json(&tape);
+        let web = &json["resource"]["aws_instance"]["web"];
+        assert_eq!(web["ami"], "ami-123");
+        assert_eq!(web["count"], 2);
+        assert_eq!(web["tags"]["Name"], "web");
+        assert_eq!(web["subnet_id"], "${var.subnet}");
+        assert_eq!(web["user_data"], "#!/bin/sh\necho hi\n");
+        assert_eq!(
+            json["resource"]["aws_instance"]["db"]["ports"],
+            serde_json::json!([5432, 5433])
+        );
+        assert_eq!(json["ingress"][1]["port"], 443);
+    }
+
+    #[cfg(feature = "hcl")]
+    #[test]
+    fn test_parse_hcl_expressions() {
+        let input = b"a = \"${var.x}-suffix\"\nb = [for s in var.list : upper(s)]\nc = x > 1 ? \"y\" : \"z\" // pick\n";
+        let json = to_json(&UnifiedTape::parse(input, FormatKind::Hcl).unwrap());
+        assert_eq!(json["a"], "${var.x}-suffix");
+        assert_eq!(json["b"], "${[for s in var.list : upper(s)]}");
+        assert_eq!(json["c"], "${x > 1 ? \"y\" : \"z\"}");
+
+        assert!(UnifiedTape::parse(b"block {\n  a = 1\n", FormatKind::Hcl).is_err());
+    }
+
+    #[cfg(feature = "ini")]
+    #[test]
+    fn test_parse_ini_sections() {
+        let input = b"; global\nname = demo\n\n[server]\nhost = example.com\nport: 8080\ntitle = \" padded \"\nverbose\n";
+        let tape = UnifiedTape::parse(input, FormatKind::Ini).unwrap();
+        assert_eq!(tape.stats.max_depth, 2);
+        assert!(tape.nodes.iter().any(
+            |n| matches!(n, TapeNode::Section { path } if path.len() == 1 && path[0] == "server")
+        ));
+        let json = to_json(&tape);
+        assert_eq!(json["name"], "demo");
+        assert_eq!(json["server"]["port"], "8080");
+        assert_eq!(json["server"]["title"], " padded ");
+        assert!(json["server"]["verbose"].is_null());
+    }
+
+    #[cfg(feature = "properties")]
+    #[test]
+    fn test_parse_properties() {
+        let input = b"! comment\nserver.port=8080\nspring.name : demo app\nkey\\ with\\ spaces value\nmulti = one, \\\n    two\nunicode=caf\\u00e9\n";
+        let tape = UnifiedTape::parse(input, FormatKind::Properties).unwrap();
+        assert_eq!(tape.stats.comment_count, 1);
+        let json = to_json(&tape);
+        assert_eq!(json["server.port"], "8080");
+        assert_eq!(json["spring.name"], "demo app");
+        assert_eq!(json["key with spaces"], "value");
+        assert_eq!(json["multi"], "one, two");
+        assert_eq!(json["unicode"], "café");
+    }
+
+    #[cfg(feature = "env")]
+    #[test]
+    fn test_parse_env() {
+        let input = b"# db\nexport DATABASE_URL=postgres://localhost/db # inline\nGREETING=\"hello\\nworld\"\nRAW='${HOME}/x'\nURL=${BASE}/api\nCERT=\"line1\nline2\"\n";
+        let tape = UnifiedTape::parse(input, FormatKind::Env).unwrap();
+        let json = to_json(&tape);
+        assert_eq!(json["DATABASE_URL"], "postgres://localhost/db");
+        assert_eq!(json["GREETING"], "hello\nworld");
+        assert_eq!(json["RAW"], "${HOME}/x");
+        assert_eq!(json["URL"], "${BASE}/api");
+        assert_eq!(json["CERT"], "line1\nline2");
+
+        assert!(UnifiedTape::parse(b"NOT AN ASSIGNMENT\n", FormatKind::Env).is_err());
+    }
 }
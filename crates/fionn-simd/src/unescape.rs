@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
-//! SIMD-accelerated string unescaping for ungron operations.
+//! SIMD-accelerated JSON string unescaping.
 //!
 //! This module provides optimized JSON string unescaping using SIMD instructions
-//! to find backslash positions and bulk-copy clean segments. This is the inverse
-//! operation of `simd_escape`.
+//! to find backslash positions and bulk-copy clean segments. Tape builders use
+//! [`unescape_json_string_into`] to decode strings straight into their arena;
+//! ungron uses the allocating wrappers.
 //!
 //! Supported escape sequences:
 //! - `\"` → `"`
@@ -25,8 +26,7 @@ use std::arch::x86_64::{
 #[cfg(target_arch = "aarch64")]
 use std::arch::aarch64::{uint8x16_t, vceqq_u8, vdupq_n_u8, vld1q_u8, vmaxvq_u8};
 
-#[allow(unused_imports)] // OnceLock used conditionally per target_arch
-use std::sync::OnceLock;
+use fionn_core::simd::{SimdDispatch, SimdLevel};
 
 /// Error type for unescape operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -54,65 +54,75 @@ impl std::fmt::Display for UnescapeError {
 
 impl std::error::Error for UnescapeError {}
 
-/// Cached SIMD feature detection.
-#[cfg(target_arch = "x86_64")]
-static HAS_SSE2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
-static HAS_AVX2: OnceLock<bool> = OnceLock::new();
-
-#[cfg(target_arch = "x86_64")]
+/// SIMD-accelerated JSON string unescaping.
+///
+/// Takes a JSON string value (without surrounding quotes) and returns
+/// the unescaped bytes. Uses SIMD to find backslash positions and
+/// bulk-copies clean segments. The kernel comes from the
+/// `fionn_core::simd` table.
+///
+/// # Errors
+///
+/// Returns an error if the string contains invalid escape sequences.
 #[inline]
-fn has_sse2() -> bool {
-    *HAS_SSE2.get_or_init(|| is_x86_feature_detected!("sse2"))
+pub fn unescape_json_string_simd(s: &[u8]) -> Result<Vec<u8>, UnescapeError> {
+    unescape_with(fionn_core::simd::dispatch().unescape, s)
 }
 
-#[cfg(target_arch = "x86_64")]
-#[inline]
-fn has_avx2() -> bool {
-    *HAS_AVX2.get_or_init(|| is_x86_feature_detected!("avx2"))
+/// JSON string unescaping with the kernel for `level`.
+///
+/// The level is lowered to what the CPU supports.
+///
+/// # Errors
+///
+/// Returns an error if the string contains invalid escape sequences.
+pub fn unescape_json_string_with_level(
+    level: SimdLevel,
+    s: &[u8],
+) -> Result<Vec<u8>, UnescapeError> {
+    unescape_with(SimdDispatch::for_level(level).unescape, s)
 }
 
-/// SIMD-accelerated JSON string unescaping.
+/// JSON string unescaping appended to `out`.
 ///
-/// Takes a JSON string value (without surrounding quotes) and returns
-/// the unescaped bytes. Uses SIMD to find backslash positions and
-/// bulk-copies clean segments.
+/// Clean input is copied with one `memcpy`, so callers can pass a reused
+/// scratch buffer or an arena-backed vector without a per-string allocation.
+/// On error `out` may hold a partially decoded prefix.
 ///
 /// # Errors
 ///
 /// Returns an error if the string contains invalid escape sequences.
 #[inline]
-pub fn unescape_json_string_simd(s: &[u8]) -> Result<Vec<u8>, UnescapeError> {
-    // Quick path: no backslashes means no escaping needed
-    if !s.contains(&b'\\') {
-        return Ok(s.to_vec());
-    }
+pub fn unescape_json_string_into(s: &[u8], out: &mut Vec<u8>) -> Result<(), UnescapeError> {
+    unescape_into_with(fionn_core::simd::dispatch().unescape, s, out)
+}
 
+#[inline]
+fn unescape_with(kernel: SimdLevel, s: &[u8]) -> Result<Vec<u8>, UnescapeError> {
     let mut out = Vec::with_capacity(s.len());
+    unescape_into_with(kernel, s, &mut out)?;
+    Ok(out)
+}
 
-    #[cfg(target_arch = "x86_64")]
-    {
-        // Prefer AVX2 for strings >= 32 bytes
-        if s.len() >= 32 && has_avx2() {
-            return unsafe { unescape_json_string_avx2(s, &mut out) }.map(|()| out);
-        }
-        if has_sse2() {
-            return unsafe { unescape_json_string_sse2(s, &mut out) }.map(|()| out);
-        }
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    {
-        // NEON is always available on aarch64
-        return unsafe { unescape_json_string_neon(s, &mut out) }.map(|()| out);
+#[inline]
+fn unescape_into_with(kernel: SimdLevel, s: &[u8], out: &mut Vec<u8>) -> Result<(), UnescapeError> {
+    // Quick path: no backslashes means no escaping needed
+    if memchr::memchr(b'\\', s).is_none() {
+        out.extend_from_slice(s);
+        return Ok(());
     }
+    out.reserve(s.len());
 
-    // Fallback to scalar
-    #[allow(unreachable_code)] // Reachable only when no SIMD available (non-x86/aarch64)
-    {
-        unescape_json_string_scalar(s, &mut out)?;
-        Ok(out)
+    // SAFETY: The kernel table only selects instruction sets the CPU has
+    match kernel {
+        // Prefer AVX2 for strings >= 32 bytes
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2 if s.len() >= 32 => unsafe { unescape_json_string_avx2(s, out) },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Sse2 | SimdLevel::Avx2 => unsafe { unescape_json_string_sse2(s, out) },
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon => unsafe { unescape_json_string_neon(s, out) },
+        _ => unescape_json_string_scalar(s, out),
     }
 }
 
@@ -270,7 +280,7 @@ unsafe fn find_next_backslash_avx2(
         }
 
         // Handle remaining bytes with SSE2 or scalar
-        if i + 16 <= len && has_sse2() {
+        if i + 16 <= len {
             #[allow(clippy::cast_possible_wrap)] // Safe: ASCII byte fits in i8
             let sse_backslash = _mm_set1_epi8(b'\\' as i8);
             return find_next_backslash_sse2(bytes, i, sse_backslash);
@@ -515,6 +525,15 @@ mod tests {
         assert_eq!(result, b"hello");
     }
 
+    #[test]
+    fn test_unescape_into_appends() {
+        let mut out = b"prefix:".to_vec();
+        unescape_json_string_into(br"a\tb", &mut out).unwrap();
+        unescape_json_string_into(b"clean", &mut out).unwrap();
+        assert_eq!(out, b"prefix:a\tbclean");
+        assert!(unescape_json_string_into(br"\q", &mut out).is_err());
+    }
+
     #[test]
     fn test_unescape_quotes() {
         let result = unescape_json_string_simd(br#"say \"hello\""#).unwrap();
@@ -646,7 +665,7 @@ mod tests {
 
     #[test]
     fn test_roundtrip() {
-        use crate::simd_escape::escape_json_string_simd;
+        use fionn_gron::escape_json_string_simd;
 
         let test_cases = [
             "hello world",
@@ -939,6 +958,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_unescape_every_level_agrees() {
+        let long = format!("{}\\n\\u00e9\\\"{}", "clean ".repeat(10), "x".repeat(40));
+        let inputs: [&[u8]; 3] = [
+            long.as_bytes(),
+            b"short\\t",
+            b"bad \\q escape in a long enough string",
+        ];
+        for input in inputs {
+            let expected = unescape_json_string_with_level(SimdLevel::Scalar, input);
+            for level in fionn_core::simd::available_levels() {
+                assert_eq!(
+                    unescape_json_string_with_level(level, input),
+                    expected,
+                    "{level}"
+                );
+            }
+        }
+    }
+
     // =========================================================================
     // decode_escape Direct Tests
     // =========================================================================
@@ -6,6 +6,7 @@
 pub mod classify;
 pub mod path;
 pub mod skip;
+pub mod utf8;
 
 pub use classify::{CharacterClasses, SimdCharClassifier};
 pub use skip::{Avx2Skip, SkipResult};
@@ -6,8 +6,9 @@
 //!
 //! # Runtime Feature Detection
 //! This module uses `#[target_feature(enable = "avx2")]` to compile AVX2 code
-//! even without `-C target-feature=+avx2`. The caller must check `is_available()`
-//! before calling AVX2-accelerated functions.
+//! even without `-C target-feature=+avx2`. Each skipper picks its chunk
+//! classifier from the `fionn_core::simd` kernel table when it is built, so
+//! `FIONN_SIMD` overrides apply here too.
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use std::arch::x86_64::{
@@ -24,6 +25,8 @@ use std::arch::x86_64::{__m512i, _mm512_cmpeq_epi8_mask, _mm512_loadu_si512, _mm
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use std::arch::x86_64::_bzhi_u64;
 
+use fionn_core::simd::{SimdDispatch, SimdLevel};
+
 /// Result of a skip operation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SkipResult {
@@ -34,28 +37,55 @@ pub struct SkipResult {
 }
 
 /// AVX2 SIMD skip using actual SIMD intrinsics
-#[derive(Debug, Clone, Copy, Default)]
-pub struct Avx2Skip;
+///
+/// Uses the AVX-512, AVX2 or scalar chunk classifier chosen by the
+/// process-wide [`SimdDispatch`] table, or by the level passed to
+/// [`Avx2Skip::with_level`].
+#[derive(Debug, Clone, Copy)]
+pub struct Avx2Skip {
+    kernel: SimdLevel,
+    bmi2: bool,
+    pclmulqdq: bool,
+}
 
 impl Avx2Skip {
-    /// Create a new AVX2 skipper
+    /// Create a new AVX2 skipper using the process-wide kernel table
     #[must_use]
-    pub const fn new() -> Self {
-        Self
+    pub fn new() -> Self {
+        Self::from_dispatch(fionn_core::simd::dispatch())
     }
 
-    /// Check if AVX2 is available at runtime
+    /// Create a skipper pinned to `level`, lowered to what the CPU supports
     #[must_use]
-    pub fn is_available() -> bool {
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
-            std::arch::is_x86_feature_detected!("avx2")
-        }
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        {
-            false
+    pub fn with_level(level: SimdLevel) -> Self {
+        Self::from_dispatch(&SimdDispatch::for_level(level))
+    }
+
+    const fn from_dispatch(table: &SimdDispatch) -> Self {
+        Self {
+            kernel: table.skip,
+            bmi2: table.bmi2,
+            pclmulqdq: table.pclmulqdq,
         }
     }
+
+    /// Chunk classifier this skipper runs
+    #[must_use]
+    pub const fn kernel(&self) -> SimdLevel {
+        self.kernel
+    }
+
+    /// Check if the process-wide kernel table enables the SIMD skip kernels
+    #[must_use]
+    pub fn is_available() -> bool {
+        fionn_core::simd::dispatch().skip != SimdLevel::Scalar
+    }
+}
+
+impl Default for Avx2Skip {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Import Skip trait from the skip module for trait implementation
@@ -135,15 +165,13 @@ unsafe fn prefix_xor_clmul(bitmask: u64) -> u64 {
 }
 
 /// XOR prefix computation for in-string detection
-/// Uses PCLMUL when available, falls back to scalar otherwise
+/// Uses PCLMUL when the kernel table enables it, falls back to scalar otherwise
 #[inline]
-fn prefix_xor(bitmask: u64) -> u64 {
+fn prefix_xor(bitmask: u64, use_clmul: bool) -> u64 {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        // is_x86_feature_detected! uses internal caching (AtomicU32)
-        // so this check is very fast after first call
-        if std::arch::is_x86_feature_detected!("pclmulqdq") {
-            // SAFETY: We just verified PCLMULQDQ is available
+        if use_clmul {
+            // SAFETY: The kernel table only enables PCLMULQDQ when detected
             return unsafe { prefix_xor_clmul(bitmask) };
         }
     }
@@ -307,6 +335,7 @@ fn classify_partial_scalar(data: &[u8], open: u8, close: u8) -> (u64, u64, u64,
 #[inline]
 #[allow(clippy::too_many_arguments)] // SIMD state machine requires all parameters
 fn skip_container_loop(
+    skip: Avx2Skip,
     quote_bits: u64,
     bs_bits: u64,
     mut open_bits: u64,
@@ -327,7 +356,7 @@ fn skip_container_loop(
 
     // Compute in-string mask using prefix-xor
     let unescaped_quotes = quote_bits & !escaped;
-    let in_string = prefix_xor(unescaped_quotes) ^ *prev_instring;
+    let in_string = prefix_xor(unescaped_quotes, skip.pclmulqdq) ^ *prev_instring;
     *prev_instring = 0u64.wrapping_sub(in_string >> 63);
 
     // Exclude brackets inside strings
@@ -336,11 +365,9 @@ fn skip_container_loop(
 
     let last_lbrace_num = *lbrace_num;
 
-    // Check BMI2 availability for optimized mask operations
+    // BMI2 for optimized mask operations, as enabled by the kernel table
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let use_bmi2 = std::arch::is_x86_feature_detected!("bmi2");
-    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-    let use_bmi2 = false;
+    let use_bmi2 = skip.bmi2;
 
     // Process each closing bracket - early exit when match found
     while close_bits != 0 {
@@ -351,7 +378,7 @@ fn skip_container_loop(
         // BMI2 bzhi: single instruction vs shift+subtract+and
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         let opens_before = if use_bmi2 {
-            // SAFETY: We checked BMI2 is available
+            // SAFETY: The kernel table only enables BMI2 when detected
             unsafe { _bzhi_u64(open_bits, close_pos) }.count_ones() as usize
         } else {
             (open_bits & ((1u64 << close_pos) - 1)).count_ones() as usize
@@ -388,16 +415,9 @@ impl Avx2Skip {
         let mut offset: usize = 0;
         let mut has_escapes = false;
 
-        // Check SIMD availability once at the start for runtime dispatch
-        // Prefer AVX-512 > AVX2 > scalar
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        let use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        let use_avx2 = !use_avx512 && std::arch::is_x86_feature_detected!("avx2");
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        let use_avx512 = false;
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        let use_avx2 = false;
+        // Kernel chosen once, when the skipper was built
+        let use_avx512 = self.kernel == SimdLevel::Avx512;
+        let use_avx2 = self.kernel == SimdLevel::Avx2;
 
         // Process 64-byte chunks
         while offset + 64 <= input.len() {
@@ -414,10 +434,10 @@ impl Avx2Skip {
 
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             let (quote_bits, bs_bits, open_bits, close_bits) = if use_avx512 {
-                // SAFETY: We checked AVX-512BW is available via is_x86_feature_detected
+                // SAFETY: The kernel table only selects AVX-512BW when detected
                 unsafe { classify_chunk_avx512(chunk, open, close) }
             } else if use_avx2 {
-                // SAFETY: We checked AVX2 is available via is_x86_feature_detected
+                // SAFETY: The kernel table only selects AVX2 when detected
                 unsafe { classify_chunk_avx2(chunk, open, close) }
             } else {
                 classify_chunk_scalar(chunk, open, close)
@@ -430,6 +450,7 @@ impl Avx2Skip {
             has_escapes = has_escapes || bs_bits != 0;
 
             if let Some(pos) = skip_container_loop(
+                *self,
                 quote_bits,
                 bs_bits,
                 open_bits,
@@ -458,6 +479,7 @@ impl Avx2Skip {
             has_escapes = has_escapes || bs_bits != 0;
 
             if let Some(pos) = skip_container_loop(
+                *self,
                 quote_bits,
                 bs_bits,
                 open_bits,
@@ -503,15 +525,9 @@ impl Avx2Skip {
         let mut offset: usize = 0;
         let mut has_escapes = false;
 
-        // Check SIMD availability once at the start for runtime dispatch
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        let use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        let use_avx2 = !use_avx512 && std::arch::is_x86_feature_detected!("avx2");
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        let use_avx512 = false;
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        let use_avx2 = false;
+        // Kernel chosen once, when the skipper was built
+        let use_avx512 = self.kernel == SimdLevel::Avx512;
+        let use_avx2 = self.kernel == SimdLevel::Avx2;
 
         while offset + 64 <= input.len() {
             // Prefetch next chunk (2 cache lines ahead = 128 bytes)
@@ -526,13 +542,13 @@ impl Avx2Skip {
 
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             let (quote_bits, bs_bits) = if use_avx512 {
-                // SAFETY: We checked AVX-512BW is available via is_x86_feature_detected
+                // SAFETY: The kernel table only selects AVX-512BW when detected
                 unsafe {
                     let (q, b, _, _) = classify_chunk_avx512(chunk, b'{', b'}');
                     (q, b)
                 }
             } else if use_avx2 {
-                // SAFETY: We checked AVX2 is available via is_x86_feature_detected
+                // SAFETY: The kernel table only selects AVX2 when detected
                 unsafe {
                     let (q, b, _, _) = classify_chunk_avx2(chunk, b'{', b'}');
                     (q, b)
@@ -681,8 +697,8 @@ mod tests {
 
     #[test]
     fn test_avx2_skip_default() {
-        let skip = Avx2Skip;
-        let _ = skip;
+        let skip = Avx2Skip::default();
+        assert_eq!(skip.kernel(), Avx2Skip::new().kernel());
     }
 
     #[test]
@@ -1102,9 +1118,11 @@ mod tests {
 
     #[test]
     fn test_prefix_xor_uses_clmul_or_scalar() {
-        // Just verify it works (may use CLMUL if available)
-        assert_eq!(prefix_xor(0), 0);
-        assert_eq!(prefix_xor(1), u64::MAX);
+        let use_clmul = Avx2Skip::new().pclmulqdq;
+        for clmul in [false, use_clmul] {
+            assert_eq!(prefix_xor(0, clmul), 0);
+            assert_eq!(prefix_xor(1, clmul), u64::MAX);
+        }
     }
 
     #[test]
@@ -1550,7 +1568,7 @@ mod tests {
         // The actual values are implementation specific, just verify consistency
         let test_values = [0b1010u64, 0b0100, 0b1111, 0xFFFF, 0x5555_5555_5555_5555];
         for val in test_values {
-            let result = prefix_xor(val);
+            let result = prefix_xor(val, Avx2Skip::new().pclmulqdq);
             // Verify it matches scalar implementation
             assert_eq!(result, prefix_xor_scalar(val));
         }
@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! AVX2 UTF-8 validation for JSON string contents
+//!
+//! Implements the Keiser–Lemire lookup algorithm ("Validating UTF-8 In Less
+//! Than One Instruction Per Byte", 2021): three nibble lookups classify every
+//! byte pair, and two saturating subtractions catch the third and fourth
+//! bytes of long sequences. Control characters (`< 0x20`), which JSON forbids
+//! inside strings, are rejected in the same pass.
+//!
+//! The kernel only answers "valid or not"; callers locate the exact offset
+//! with a scalar pass when it reports an error.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use std::arch::x86_64::{
+    __m256i, _mm256_alignr_epi8, _mm256_and_si256, _mm256_cmpeq_epi8, _mm256_loadu_si256,
+    _mm256_min_epu8, _mm256_movemask_epi8, _mm256_or_si256, _mm256_permute2x128_si256,
+    _mm256_set1_epi8, _mm256_setr_epi8, _mm256_setzero_si256, _mm256_shuffle_epi8,
+    _mm256_srli_epi16, _mm256_subs_epu8, _mm256_testz_si256, _mm256_xor_si256,
+};
+
+use fionn_core::simd::{SimdDispatch, SimdLevel};
+
+// Error bits from the paper's lookup tables
+const TOO_SHORT: u8 = 1 << 0;
+const TOO_LONG: u8 = 1 << 1;
+const OVERLONG_3: u8 = 1 << 2;
+const TOO_LARGE: u8 = 1 << 3;
+const SURROGATE: u8 = 1 << 4;
+const OVERLONG_2: u8 = 1 << 5;
+const TOO_LARGE_1000: u8 = 1 << 6;
+const OVERLONG_4: u8 = 1 << 6;
+const TWO_CONTS: u8 = 1 << 7;
+const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTS;
+
+/// Indexed by the high nibble of the first byte of a pair
+const BYTE_1_HIGH: [u8; 16] = [
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    TOO_SHORT | OVERLONG_2,
+    TOO_SHORT,
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+];
+
+/// Indexed by the low nibble of the first byte of a pair
+const BYTE_1_LOW: [u8; 16] = [
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    CARRY | OVERLONG_2,
+    CARRY,
+    CARRY,
+    CARRY | TOO_LARGE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+];
+
+/// Indexed by the high nibble of the second byte of a pair
+const BYTE_2_HIGH: [u8; 16] = [
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+];
+
+/// Largest byte value that can end a block without leaving a sequence open
+///
+/// Only the last three bytes of a block may start an unfinished sequence.
+const INCOMPLETE_MAX: [u8; 32] = {
+    let mut max = [0xFF; 32];
+    max[29] = 0xF0 - 1;
+    max[30] = 0xE0 - 1;
+    max[31] = 0xC0 - 1;
+    max
+};
+
+/// Check if the process-wide kernel table enables AVX2 UTF-8 validation
+#[must_use]
+pub fn is_available() -> bool {
+    fionn_core::simd::dispatch().utf8 == SimdLevel::Avx2
+}
+
+/// Broadcast a 16-entry table to both 128-bit lanes for `vpshufb`
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+#[inline]
+fn lane_table(t: &[u8; 16]) -> __m256i {
+    let b = t.map(|v| i8::from_ne_bytes([v]));
+    _mm256_setr_epi8(
+        b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11], b[12], b[13],
+        b[14], b[15], b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11],
+        b[12], b[13], b[14], b[15],
+    )
+}
+
+/// Per-block state carried across 32-byte blocks
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+struct Utf8Checker {
+    error: __m256i,
+    prev_input: __m256i,
+    prev_incomplete: __m256i,
+    byte_1_high: __m256i,
+    byte_1_low: __m256i,
+    byte_2_high: __m256i,
+    nibble_mask: __m256i,
+    control_max: __m256i,
+    incomplete_max: __m256i,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Utf8Checker {
+    #[target_feature(enable = "avx2")]
+    fn new() -> Self {
+        // SAFETY: `INCOMPLETE_MAX` is 32 bytes and loadu has no alignment requirement
+        let incomplete_max = unsafe { _mm256_loadu_si256(INCOMPLETE_MAX.as_ptr().cast()) };
+        Self {
+            error: _mm256_setzero_si256(),
+            prev_input: _mm256_setzero_si256(),
+            prev_incomplete: _mm256_setzero_si256(),
+            byte_1_high: lane_table(&BYTE_1_HIGH),
+            byte_1_low: lane_table(&BYTE_1_LOW),
+            byte_2_high: lane_table(&BYTE_2_HIGH),
+            nibble_mask: _mm256_set1_epi8(0x0F),
+            control_max: _mm256_set1_epi8(0x1F),
+            incomplete_max,
+        }
+    }
+
+    /// Bytes `n` positions back, spanning the previous block
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    fn prev<const SHIFT: i32>(input: __m256i, prev_input: __m256i) -> __m256i {
+        _mm256_alignr_epi8::<SHIFT>(input, _mm256_permute2x128_si256::<0x21>(prev_input, input))
+    }
+
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    fn high_nibble(&self, v: __m256i) -> __m256i {
+        _mm256_and_si256(_mm256_srli_epi16::<4>(v), self.nibble_mask)
+    }
+
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    fn check_block(&mut self, input: __m256i) {
+        // Control characters: min(x, 0x1F) == x  <=>  x <= 0x1F
+        let control = _mm256_cmpeq_epi8(_mm256_min_epu8(input, self.control_max), input);
+        self.error = _mm256_or_si256(self.error, control);
+
+        if _mm256_movemask_epi8(input) == 0 {
+            // Pure ASCII: only an unfinished sequence from the last block can fail
+            self.error = _mm256_or_si256(self.error, self.prev_incomplete);
+            self.prev_incomplete = _mm256_setzero_si256();
+            self.prev_input = input;
+            return;
+        }
+
+        // Alignment 15 = one byte back, 14 = two, 13 = three
+        let prev1 = Self::prev::<15>(input, self.prev_input);
+        let special_cases = _mm256_and_si256(
+            _mm256_and_si256(
+                _mm256_shuffle_epi8(self.byte_1_high, self.high_nibble(prev1)),
+                _mm256_shuffle_epi8(self.byte_1_low, _mm256_and_si256(prev1, self.nibble_mask)),
+            ),
+            _mm256_shuffle_epi8(self.byte_2_high, self.high_nibble(input)),
+        );
+
+        let prev2 = Self::prev::<14>(input, self.prev_input);
+        let prev3 = Self::prev::<13>(input, self.prev_input);
+        // Subtracting 0xE0 - 0x80 (0xF0 - 0x80) leaves the high bit set only
+        // for three-byte (four-byte) leads two (three) positions back
+        let third = _mm256_subs_epu8(prev2, _mm256_set1_epi8(0x60));
+        let fourth = _mm256_subs_epu8(prev3, _mm256_set1_epi8(0x70));
+        let must23_80 = _mm256_and_si256(
+            _mm256_or_si256(third, fourth),
+            _mm256_set1_epi8(i8::from_ne_bytes([0x80])),
+        );
+        self.error = _mm256_or_si256(self.error, _mm256_xor_si256(must23_80, special_cases));
+
+        self.prev_incomplete = _mm256_subs_epu8(input, self.incomplete_max);
+        self.prev_input = input;
+    }
+
+    #[target_feature(enable = "avx2")]
+    fn finish(&self) -> bool {
+        let error = _mm256_or_si256(self.error, self.prev_incomplete);
+        _mm256_testz_si256(error, error) == 1
+    }
+}
+
+/// Validate the bytes between a JSON string's quotes
+///
+/// Returns `true` when `bytes` is valid UTF-8 and contains no control
+/// characters. Quotes and backslashes are not treated specially.
+///
+/// # Safety
+/// Caller must ensure AVX2 is available (see [`is_available`]).
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+#[must_use]
+#[allow(clippy::cast_ptr_alignment)] // Intentional unaligned load via _mm256_loadu_si256
+pub unsafe fn validate_string_bytes(bytes: &[u8]) -> bool {
+    let mut checker = Utf8Checker::new();
+    let mut chunks = bytes.chunks_exact(32);
+    for chunk in &mut chunks {
+        // SAFETY: `chunk` is exactly 32 bytes and loadu has no alignment requirement
+        let input = unsafe { _mm256_loadu_si256(chunk.as_ptr().cast::<__m256i>()) };
+        checker.check_block(input);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        // Pad with spaces: ASCII, and not a control character
+        let mut block = [b' '; 32];
+        block[..tail.len()].copy_from_slice(tail);
+        // SAFETY: `block` is 32 bytes and loadu has no alignment requirement
+        let input = unsafe { _mm256_loadu_si256(block.as_ptr().cast::<__m256i>()) };
+        checker.check_block(input);
+    }
+    checker.finish()
+}
+
+/// Validate string bytes with the kernel for `level`
+///
+/// Safe counterpart of [`validate_string_bytes`]: the level is lowered to
+/// what the CPU supports, and levels without the AVX2 kernel use `std`.
+#[must_use]
+pub fn validate_string_bytes_with_level(level: SimdLevel, bytes: &[u8]) -> bool {
+    if SimdDispatch::for_level(level).utf8 == SimdLevel::Avx2 {
+        // SAFETY: The kernel table only selects AVX2 when detected
+        return unsafe { validate_string_bytes(bytes) };
+    }
+    bytes.iter().all(|&b| b >= 0x20) && std::str::from_utf8(bytes).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(bytes: &[u8]) -> bool {
+        // SAFETY: guarded by `is_available`
+        unsafe { validate_string_bytes(bytes) }
+    }
+
+    #[test]
+    fn test_matches_std_on_samples() {
+        if !is_available() {
+            return;
+        }
+        let samples: &[&[u8]] = &[
+            b"",
+            b"plain ascii that is longer than one thirty-two byte block",
+            "h\u{e9}llo w\u{f6}rld \u{1f600} \u{4e2d}\u{6587}".as_bytes(),
+            b"\xC0\x80",
+            b"\xED\xA0\x80",
+            b"\xF4\x90\x80\x80",
+            b"\xE2\x82",
+            b"abc\xE2\x82\xAC",
+            b"tab\tinside",
+            b"\x80 stray continuation",
+        ];
+        for sample in samples {
+            let expected = std::str::from_utf8(sample).is_ok() && sample.iter().all(|&b| b >= 0x20);
+            assert_eq!(check(sample), expected, "{sample:?}");
+        }
+    }
+
+    #[test]
+    fn test_sequence_split_across_blocks() {
+        if !is_available() {
+            return;
+        }
+        for offset in 28..34 {
+            let mut bytes = vec![b'a'; offset];
+            bytes.extend_from_slice("\u{1f600}".as_bytes());
+            bytes.extend_from_slice(b"tail");
+            assert!(check(&bytes), "offset {offset}");
+            // Truncate the four-byte sequence at the block boundary
+            bytes.truncate(offset + 2);
+            assert!(!check(&bytes), "truncated at {offset}");
+        }
+    }
+}
@@ -0,0 +1,356 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Cross-kernel differential tests
+//!
+//! Runs every SIMD kernel the CPU supports over the same corpus and checks
+//! that each one agrees with the scalar kernel. A kernel bug then shows up
+//! here before it reaches a user who happens to have that instruction set.
+
+use fionn_core::simd::{SimdLevel, available_levels};
+use proptest::prelude::*;
+use serde_json::Value;
+
+/// Assert that `run` gives the same answer at every available level
+fn assert_levels_agree<T, F>(family: &str, input: &[u8], run: F)
+where
+    T: PartialEq + std::fmt::Debug,
+    F: Fn(SimdLevel) -> T,
+{
+    let expected = run(SimdLevel::Scalar);
+    for level in available_levels() {
+        assert_eq!(
+            run(level),
+            expected,
+            "{family} kernel {level} disagrees with scalar on {:?}",
+            String::from_utf8_lossy(input)
+        );
+    }
+}
+
+/// JSON documents chosen to straddle 16-, 32- and 64-byte boundaries
+fn corpus() -> Vec<Vec<u8>> {
+    let mut docs: Vec<Vec<u8>> = vec![
+        br"{}".to_vec(),
+        br"[]".to_vec(),
+        br#"{"a":1,"b":[true,false,null],"c":{"d":"e"}}"#.to_vec(),
+        br#"["}", "]", "{", "[", "\"", "\\"]"#.to_vec(),
+        br#"{"escaped \"key\"":"value with \\ and \" and \n","t":"\t"}"#.to_vec(),
+        "{\"unicode\":\"h\u{e9}llo w\u{f6}rld \u{1f600} \u{4e2d}\u{6587}\",\"esc\":\"\\u00e9\\ud83d\\ude00\"}"
+            .as_bytes()
+            .to_vec(),
+        br#"{"unterminated": [1, 2, 3"#.to_vec(),
+        br#""just a string with a trailing backslash \\""#.to_vec(),
+        br"-12.5e+3".to_vec(),
+    ];
+
+    // Quotes and backslash runs landing on every offset around a chunk edge
+    for offset in 58..70 {
+        for run in 1..=4 {
+            let mut doc = b"{\"k\":\"".to_vec();
+            doc.extend(std::iter::repeat_n(b'x', offset));
+            doc.extend(std::iter::repeat_n(b'\\', run * 2));
+            doc.extend_from_slice(b"\\\"}]\",\"n\":[1,{\"m\":\"]\"}]}");
+            docs.push(doc);
+        }
+    }
+
+    // Deep nesting and long arrays cross many chunks
+    let depth = 100;
+    let mut nested = "[".repeat(depth).into_bytes();
+    nested.extend_from_slice(b"\"deep\"");
+    nested.extend("]".repeat(depth).into_bytes());
+    docs.push(nested);
+    let items: Vec<String> = (0..200)
+        .map(|i| format!("{{\"id\":{i},\"s\":\"v{i}\"}}"))
+        .collect();
+    docs.push(format!("[{}]", items.join(",")).into_bytes());
+
+    // Shift everything so kernels see every alignment
+    let shifted: Vec<Vec<u8>> = docs
+        .iter()
+        .flat_map(|doc| {
+            [1usize, 15, 31, 63].map(|pad| {
+                let mut padded = vec![b' '; pad];
+                padded.extend_from_slice(doc);
+                padded
+            })
+        })
+        .collect();
+    docs.extend(shifted);
+    docs
+}
+
+/// Every string and key in the valid corpus documents
+fn corpus_strings() -> Vec<String> {
+    fn collect(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::String(s) => out.push(s.clone()),
+            Value::Array(items) => items.iter().for_each(|item| collect(item, out)),
+            Value::Object(map) => {
+                for (key, item) in map {
+                    out.push(key.clone());
+                    collect(item, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut strings = vec![
+        String::new(),
+        "\u{1}\u{1f} control".to_string(),
+        "clean ".repeat(20),
+        format!("{}\"{}", "q".repeat(31), "q".repeat(33)),
+    ];
+    for doc in corpus() {
+        if let Ok(value) = serde_json::from_slice::<Value>(&doc) {
+            collect(&value, &mut strings);
+        }
+    }
+    strings
+}
+
+// =============================================================================
+// Skip and Classify
+// =============================================================================
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_skip_kernels_agree() {
+    use fionn_simd::Avx2Skip;
+
+    for doc in corpus() {
+        assert_levels_agree("skip", &doc, |level| {
+            Avx2Skip::with_level(level).skip_value(&doc)
+        });
+        let body = doc.iter().position(|&b| b == b'{' || b == b'[');
+        if let Some(start) = body {
+            assert_levels_agree("skip_container", &doc, |level| {
+                let (open, close) = if doc[start] == b'{' {
+                    (b'{', b'}')
+                } else {
+                    (b'[', b']')
+                };
+                Avx2Skip::with_level(level).skip_container(&doc[start + 1..], open, close)
+            });
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_skip_kernels_match_scalar_strategy() {
+    use fionn_simd::{Avx2Skip, ScalarSkip, Skip};
+
+    for doc in corpus() {
+        let expected = ScalarSkip.skip_value(&doc).map(|r| r.consumed);
+        for level in available_levels() {
+            let got = Avx2Skip::with_level(level)
+                .skip_value(&doc)
+                .map(|r| r.consumed);
+            assert_eq!(got, expected, "skip {level} vs ScalarSkip");
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_classify_kernels_agree() {
+    use fionn_simd::x86::SimdCharClassifier;
+
+    let classifier = SimdCharClassifier::new();
+    for doc in corpus() {
+        for window in doc.chunks(64) {
+            let mut chunk = [0u8; 64];
+            chunk[..window.len()].copy_from_slice(window);
+            assert_levels_agree("classify", window, |level| {
+                classifier.classify_chunk_with_level(level, &chunk)
+            });
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_utf8_kernels_agree() {
+    use fionn_simd::x86::utf8::validate_string_bytes_with_level;
+
+    let mut samples = corpus();
+    samples.extend([
+        b"\xC0\x80".to_vec(),
+        b"\xED\xA0\x80 surrogate".to_vec(),
+        b"\xF4\x90\x80\x80".to_vec(),
+        [b"x".repeat(31), b"\xE2\x82".to_vec()].concat(),
+        [
+            b"x".repeat(30),
+            "\u{20ac}".as_bytes().to_vec(),
+            b"y".repeat(40),
+        ]
+        .concat(),
+    ]);
+    for sample in samples {
+        assert_levels_agree("utf8", &sample, |level| {
+            validate_string_bytes_with_level(level, &sample)
+        });
+    }
+}
+
+// =============================================================================
+// Line Split, Path Parse and Compare
+// =============================================================================
+
+#[test]
+fn test_line_split_kernels_agree() {
+    let separator = fionn_simd::SimdLineSeparator::new();
+    let docs = corpus();
+    let jsonl = docs.join(&b'\n');
+    let trailing = [jsonl.clone(), b"\n".to_vec()].concat();
+    for data in [jsonl, trailing, Vec::new(), b"\n\n\n".to_vec()] {
+        assert_levels_agree("line_split", &data, |level| {
+            separator.find_line_boundaries_with_level(level, &data)
+        });
+    }
+}
+
+#[test]
+fn test_path_kernels_agree() {
+    use fionn_core::path::{parse_baseline, parse_simd_with_level};
+
+    let long_field = "field".repeat(30);
+    let paths = [
+        String::new(),
+        "a".to_string(),
+        "user.name".to_string(),
+        "users[0].emails[12].domain".to_string(),
+        "a..b[3]".to_string(),
+        format!("{long_field}.{long_field}[7].{long_field}"),
+        format!("{}[{}]", "x".repeat(63), "9".repeat(3)),
+        format!("{}.y", "x".repeat(64)),
+    ];
+    for path in &paths {
+        let expected = parse_baseline(path);
+        assert_levels_agree("path", path.as_bytes(), |level| {
+            parse_simd_with_level(path, level)
+        });
+        assert_eq!(parse_simd_with_level(path, SimdLevel::Scalar), expected);
+    }
+}
+
+#[test]
+fn test_compare_kernels_agree() {
+    use fionn_diff::{simd_bytes_equal_with_level, simd_find_first_difference_with_level};
+
+    let docs = corpus();
+    for a in &docs {
+        for b in &docs {
+            assert_levels_agree("compare", a, |level| {
+                (
+                    simd_bytes_equal_with_level(level, a, b),
+                    simd_find_first_difference_with_level(level, a, b),
+                )
+            });
+        }
+    }
+}
+
+// =============================================================================
+// Escape and Unescape
+// =============================================================================
+
+#[test]
+fn test_escape_kernels_agree() {
+    use fionn_gron::escape_json_string_with_level;
+    use fionn_simd::unescape::unescape_json_string_with_level;
+
+    for s in corpus_strings() {
+        assert_levels_agree("escape", s.as_bytes(), |level| {
+            let mut out = Vec::new();
+            escape_json_string_with_level(level, &s, &mut out);
+            out
+        });
+
+        let mut escaped = Vec::new();
+        escape_json_string_with_level(SimdLevel::Scalar, &s, &mut escaped);
+        let inner = &escaped[1..escaped.len() - 1];
+        assert_levels_agree("unescape", inner, |level| {
+            unescape_json_string_with_level(level, inner)
+        });
+        assert_eq!(
+            unescape_json_string_with_level(SimdLevel::Scalar, inner).as_deref(),
+            Ok(s.as_bytes())
+        );
+    }
+}
+
+#[test]
+fn test_unescape_kernels_agree_on_raw_strings() {
+    use fionn_simd::unescape::unescape_json_string_with_level;
+
+    let raw: [&[u8]; 5] = [
+        br"plain",
+        br#"\n\t\"\\\/\b\f\r"#,
+        "\u{e9}\u{1f600} and a long tail to cross the vector width".as_bytes(),
+        br"bad \x escape after thirty-two bytes of padding",
+        br"truncated unicode at the very end of a long string \u00",
+    ];
+    for input in raw {
+        assert_levels_agree("unescape", input, |level| {
+            unescape_json_string_with_level(level, input)
+        });
+    }
+}
+
+// =============================================================================
+// Random Inputs
+// =============================================================================
+
+/// Bytes biased towards JSON structure, escapes and multi-byte UTF-8
+fn json_ish_bytes() -> impl Strategy<Value = Vec<u8>> {
+    let alphabet = b"{}[]\":,\\ \nabc019-.e\t\xC3\xA9\x80\xE2\x82\xAC".to_vec();
+    prop::collection::vec(prop::sample::select(alphabet), 0..300)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn prop_kernels_agree_on_random_bytes(data in json_ish_bytes()) {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            use fionn_simd::Avx2Skip;
+            use fionn_simd::x86::utf8::validate_string_bytes_with_level;
+
+            for start in [0usize, 1] {
+                let input = data.get(start..).unwrap_or_default();
+                assert_levels_agree("skip", input, |level| {
+                    let skip = Avx2Skip::with_level(level);
+                    (
+                        skip.skip_object(input),
+                        skip.skip_array(input),
+                        skip.skip_string(input),
+                    )
+                });
+            }
+            assert_levels_agree("utf8", &data, |level| {
+                validate_string_bytes_with_level(level, &data)
+            });
+        }
+
+        let separator = fionn_simd::SimdLineSeparator::new();
+        assert_levels_agree("line_split", &data, |level| {
+            separator.find_line_boundaries_with_level(level, &data)
+        });
+
+        let half = data.len() / 2;
+        let (a, b) = data.split_at(half);
+        assert_levels_agree("compare", &data, |level| {
+            (
+                fionn_diff::simd_bytes_equal_with_level(level, a, &b[..a.len().min(b.len())]),
+                fionn_diff::simd_find_first_difference_with_level(level, a, b),
+            )
+        });
+
+        assert_levels_agree("unescape", &data, |level| {
+            fionn_simd::unescape::unescape_json_string_with_level(level, &data)
+        });
+    }
+}
@@ -0,0 +1,815 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Apache Arrow / Parquet bridge
+//!
+//! Converts batches of normalized documents (as produced by the JSONL, CSV and
+//! ISONL batch processors) into Arrow [`RecordBatch`]es, so fionn can act as the
+//! ingest stage in front of Arrow-native engines such as `DataFusion` or Polars.
+//!
+//! - Column schemas are inferred from the batch or supplied by the caller
+//! - Nested objects become `Struct` columns, arrays become `List` columns
+//! - Integers and floats widen to `Float64`, conflicting scalars fall back to `Utf8`
+//! - Integers above `i64::MAX` become `UInt64`, or `Decimal128(20, 0)` when the
+//!   column also holds negative integers
+//!
+//! With the `parquet` feature, record batches can be written to Parquet and
+//! Parquet files read back through [`ParquetBatchProcessor`], which plugs into
+//! [`FormatDsonProcessor`](crate::format_dson::FormatDsonProcessor) like any other
+//! batch source.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, BooleanArray, Decimal128Array, Float64Array, Int64Array, ListArray, NullArray,
+    RecordBatch, RecordBatchOptions, StringArray, StructArray, UInt64Array,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef};
+use fionn_core::{DsonError, Result};
+use serde_json::Value;
+
+use crate::format_dson::FormatBatchResult;
+
+// =============================================================================
+// Schema Inference
+// =============================================================================
+
+/// Column type lattice used while inferring a schema from sample records
+#[derive(Debug, Clone, PartialEq)]
+enum InferredType {
+    Null,
+    Bool,
+    /// Integers that fit `i64`, noting whether any is negative
+    Int {
+        negative: bool,
+    },
+    /// Non-negative integers, some above `i64::MAX`
+    UInt,
+    /// Negative integers mixed with ones above `i64::MAX`
+    Decimal,
+    Float,
+    Utf8,
+    List(Box<Self>),
+    Struct(Vec<(String, Self)>),
+}
+
+impl InferredType {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(_) => Self::Bool,
+            Value::Number(n) => match (n.as_i64(), n.is_u64()) {
+                (Some(i), _) => Self::Int { negative: i < 0 },
+                (None, true) => Self::UInt,
+                (None, false) => Self::Float,
+            },
+            Value::String(_) => Self::Utf8,
+            Value::Array(items) => Self::List(Box::new(
+                items.iter().map(Self::of).fold(Self::Null, Self::merge),
+            )),
+            Value::Object(map) => Self::Struct(
+                map.iter()
+                    .map(|(key, val)| (key.clone(), Self::of(val)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Null, t) | (t, Self::Null) => t,
+            (Self::Int { .. } | Self::UInt | Self::Decimal, Self::Float)
+            | (Self::Float, Self::Int { .. } | Self::UInt | Self::Decimal) => Self::Float,
+            (Self::Int { negative: a }, Self::Int { negative: b }) => {
+                Self::Int { negative: a || b }
+            }
+            (Self::Int { negative: false }, Self::UInt)
+            | (Self::UInt, Self::Int { negative: false }) => Self::UInt,
+            (Self::Int { .. } | Self::UInt | Self::Decimal, Self::UInt | Self::Decimal)
+            | (Self::UInt | Self::Decimal, Self::Int { .. }) => Self::Decimal,
+            (Self::List(a), Self::List(b)) => Self::List(Box::new(a.merge(*b))),
+            (Self::Struct(mut fields), Self::Struct(other_fields)) => {
+                for (name, ty) in other_fields {
+                    if let Some(slot) = fields.iter_mut().find(|(n, _)| *n == name) {
+                        let current = std::mem::replace(&mut slot.1, Self::Null);
+                        slot.1 = current.merge(ty);
+                    } else {
+                        fields.push((name, ty));
+                    }
+                }
+                Self::Struct(fields)
+            }
+            (a, b) if a == b => a,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn into_data_type(self) -> DataType {
+        match self {
+            Self::Null => DataType::Null,
+            Self::Bool => DataType::Boolean,
+            Self::Int { .. } => DataType::Int64,
+            Self::UInt => DataType::UInt64,
+            Self::Decimal => DataType::Decimal128(20, 0),
+            Self::Float => DataType::Float64,
+            Self::Utf8 => DataType::Utf8,
+            Self::List(item) => {
+                DataType::List(Arc::new(Field::new("item", item.into_data_type(), true)))
+            }
+            Self::Struct(fields) => DataType::Struct(into_fields(fields)),
+        }
+    }
+}
+
+fn into_fields(fields: Vec<(String, InferredType)>) -> Fields {
+    fields
+        .into_iter()
+        .map(|(name, ty)| Field::new(name, ty.into_data_type(), true))
+        .collect()
+}
+
+/// Infer an Arrow schema from a sample of records
+///
+/// Every record must be a JSON object; top-level keys become columns in order
+/// of first appearance. All columns are nullable.
+///
+/// # Errors
+/// Returns an error if a record is not an object
+pub fn infer_arrow_schema<'a, I>(records: I) -> Result<Schema>
+where
+    I: IntoIterator<Item = &'a Value>,
+{
+    let mut merged = InferredType::Struct(Vec::new());
+    for (idx, record) in records.into_iter().enumerate() {
+        if !record.is_object() {
+            return Err(DsonError::SchemaError(format!(
+                "record {idx} is not an object and cannot become an Arrow row"
+            )));
+        }
+        merged = merged.merge(InferredType::of(record));
+    }
+
+    let InferredType::Struct(fields) = merged else {
+        unreachable!("merging objects always yields a struct")
+    };
+    Ok(Schema::new(into_fields(fields)))
+}
+
+// =============================================================================
+// RecordBatch Construction
+// =============================================================================
+
+/// Build a [`RecordBatch`] from JSON object records
+///
+/// When `schema` is `None` it is inferred from the records with
+/// [`infer_arrow_schema`]. With a supplied schema, string cells are coerced to
+/// numeric and boolean columns when they parse cleanly, which lets CSV rows
+/// land in typed columns.
+///
+/// # Errors
+/// Returns an error if a record is not an object, a value does not fit its
+/// column type, or the schema uses an unsupported Arrow type
+pub fn records_to_record_batch(
+    records: &[Value],
+    schema: Option<SchemaRef>,
+) -> Result<RecordBatch> {
+    let schema = match schema {
+        Some(schema) => schema,
+        None => Arc::new(infer_arrow_schema(records)?),
+    };
+
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    for field in schema.fields() {
+        let cells: Vec<Option<&Value>> = records
+            .iter()
+            .map(|record| record.get(field.name()))
+            .collect();
+        columns.push(build_array(field.data_type(), &cells, field.name())?);
+    }
+
+    let options = RecordBatchOptions::new().with_row_count(Some(records.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+        .map_err(|e| DsonError::SchemaError(e.to_string()))
+}
+
+/// Build a [`RecordBatch`] from the documents of a batch processor result
+///
+/// # Errors
+/// Returns an error if a document is not valid JSON or does not fit the schema
+pub fn format_batch_to_record_batch(
+    batch: &FormatBatchResult,
+    schema: Option<SchemaRef>,
+) -> Result<RecordBatch> {
+    let records = batch
+        .documents
+        .iter()
+        .map(|doc| serde_json::from_str(doc).map_err(|e| DsonError::ParseError(e.to_string())))
+        .collect::<Result<Vec<Value>>>()?;
+    records_to_record_batch(&records, schema)
+}
+
+/// Build a [`RecordBatch`] from a tabular unified tape (CSV, ISON tables, TOON tabular blocks)
+///
+/// Each `TabularRow` becomes one row, keyed by the fields of the preceding
+/// `TabularHeader`.
+///
+/// # Errors
+/// Returns an error if a row appears before any header or the rows do not fit the schema
+#[cfg(any(feature = "csv", feature = "ison", feature = "toon"))]
+pub fn tabular_tape_to_record_batch(
+    tape: &fionn_simd::transform::UnifiedTape<'_>,
+    schema: Option<SchemaRef>,
+) -> Result<RecordBatch> {
+    use fionn_simd::transform::{TapeNode, TapeValue};
+
+    let mut header: Option<&[std::borrow::Cow<'_, str>]> = None;
+    let mut records = Vec::new();
+
+    for node in &tape.nodes {
+        match node {
+            TapeNode::TabularHeader { fields, .. } => header = Some(fields),
+            TapeNode::TabularRow { values } => {
+                let fields = header.ok_or_else(|| {
+                    DsonError::SchemaError("tabular row without a header".to_string())
+                })?;
+                let mut row = serde_json::Map::with_capacity(fields.len());
+                for (name, value) in fields.iter().zip(values) {
+                    let cell = match value {
+                        TapeValue::Null => Value::Null,
+                        TapeValue::Bool(b) => Value::Bool(*b),
+                        TapeValue::Int(i) => Value::from(*i),
+                        TapeValue::Float(f) => Value::from(*f),
+                        TapeValue::String(s) | TapeValue::RawNumber(s) => {
+                            Value::String(s.to_string())
+                        }
+                    };
+                    row.insert(name.to_string(), cell);
+                }
+                records.push(Value::Object(row));
+            }
+            _ => {}
+        }
+    }
+
+    records_to_record_batch(&records, schema)
+}
+
+fn type_error(column: &str, expected: &str, value: &Value) -> DsonError {
+    DsonError::SchemaError(format!(
+        "column '{column}': expected {expected}, found {value}"
+    ))
+}
+
+fn null_buffer(cells: &[Option<&Value>]) -> Option<NullBuffer> {
+    let validity: NullBuffer = cells
+        .iter()
+        .map(|cell| !matches!(cell, None | Some(Value::Null)))
+        .collect();
+    (validity.null_count() > 0).then_some(validity)
+}
+
+/// Build one Arrow array for `data_type` from a column of optional JSON cells
+fn build_array(data_type: &DataType, cells: &[Option<&Value>], column: &str) -> Result<ArrayRef> {
+    let array: ArrayRef = match data_type {
+        DataType::Null => Arc::new(NullArray::new(cells.len())),
+        DataType::Boolean => {
+            let values = cells
+                .iter()
+                .map(|cell| match cell {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(Value::Bool(b)) => Ok(Some(*b)),
+                    Some(v @ Value::String(s)) => s
+                        .parse::<bool>()
+                        .map(Some)
+                        .map_err(|_| type_error(column, "boolean", v)),
+                    Some(v) => Err(type_error(column, "boolean", v)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(BooleanArray::from(values))
+        }
+        DataType::Int64 => {
+            let values = cells
+                .iter()
+                .map(|cell| match cell {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(v @ Value::Number(n)) => n
+                        .as_i64()
+                        .map(Some)
+                        .ok_or_else(|| type_error(column, "int64", v)),
+                    Some(v @ Value::String(s)) => s
+                        .trim()
+                        .parse::<i64>()
+                        .map(Some)
+                        .map_err(|_| type_error(column, "int64", v)),
+                    Some(v) => Err(type_error(column, "int64", v)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(Int64Array::from(values))
+        }
+        DataType::UInt64 => {
+            let values = cells
+                .iter()
+                .map(|cell| match cell {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(v @ Value::Number(n)) => n
+                        .as_u64()
+                        .map(Some)
+                        .ok_or_else(|| type_error(column, "uint64", v)),
+                    Some(v @ Value::String(s)) => s
+                        .trim()
+                        .parse::<u64>()
+                        .map(Some)
+                        .map_err(|_| type_error(column, "uint64", v)),
+                    Some(v) => Err(type_error(column, "uint64", v)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(UInt64Array::from(values))
+        }
+        DataType::Decimal128(precision, 0) => build_decimal_array(*precision, cells, column)?,
+        DataType::Float64 => {
+            let values = cells
+                .iter()
+                .map(|cell| match cell {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(v @ Value::Number(n)) => n
+                        .as_f64()
+                        .map(Some)
+                        .ok_or_else(|| type_error(column, "float64", v)),
+                    Some(v @ Value::String(s)) => s
+                        .trim()
+                        .parse::<f64>()
+                        .map(Some)
+                        .map_err(|_| type_error(column, "float64", v)),
+                    Some(v) => Err(type_error(column, "float64", v)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(Float64Array::from(values))
+        }
+        DataType::Utf8 => {
+            let values: Vec<Option<String>> = cells
+                .iter()
+                .map(|cell| match cell {
+                    None | Some(Value::Null) => None,
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(other) => Some(other.to_string()),
+                })
+                .collect();
+            Arc::new(StringArray::from(values))
+        }
+        DataType::Struct(fields) => build_struct_array(fields, cells, column)?,
+        DataType::List(item) => build_list_array(item, cells, column)?,
+        other => {
+            return Err(DsonError::SchemaError(format!(
+                "column '{column}': unsupported Arrow type {other}"
+            )));
+        }
+    };
+    Ok(array)
+}
+
+/// Build a scale-0 decimal array, which holds both `i64` and `u64` integers
+fn build_decimal_array(precision: u8, cells: &[Option<&Value>], column: &str) -> Result<ArrayRef> {
+    let expected = format!("decimal({precision}, 0)");
+    let values = cells
+        .iter()
+        .map(|cell| match cell {
+            None | Some(Value::Null) => Ok(None),
+            Some(v @ Value::Number(n)) => n
+                .as_i64()
+                .map(i128::from)
+                .or_else(|| n.as_u64().map(i128::from))
+                .map(Some)
+                .ok_or_else(|| type_error(column, &expected, v)),
+            Some(v @ Value::String(s)) => s
+                .trim()
+                .parse::<i128>()
+                .map(Some)
+                .map_err(|_| type_error(column, &expected, v)),
+            Some(v) => Err(type_error(column, &expected, v)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let array = Decimal128Array::from(values)
+        .with_precision_and_scale(precision, 0)
+        .map_err(|e| DsonError::SchemaError(format!("column '{column}': {e}")))?;
+    array
+        .validate_decimal_precision(precision)
+        .map_err(|e| DsonError::SchemaError(format!("column '{column}': {e}")))?;
+    Ok(Arc::new(array))
+}
+
+fn build_struct_array(fields: &Fields, cells: &[Option<&Value>], column: &str) -> Result<ArrayRef> {
+    let mut children = Vec::with_capacity(fields.len());
+    for field in fields {
+        let child_cells = cells
+            .iter()
+            .map(|cell| match cell {
+                None | Some(Value::Null) => Ok(None),
+                Some(Value::Object(map)) => Ok(map.get(field.name())),
+                Some(v) => Err(type_error(column, "object", v)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let path = format!("{column}.{}", field.name());
+        children.push(build_array(field.data_type(), &child_cells, &path)?);
+    }
+
+    let nulls = null_buffer(cells);
+    if fields.is_empty() {
+        return Ok(Arc::new(StructArray::new_empty_fields(cells.len(), nulls)));
+    }
+    let array = StructArray::try_new(fields.clone(), children, nulls)
+        .map_err(|e| DsonError::SchemaError(e.to_string()))?;
+    Ok(Arc::new(array))
+}
+
+fn build_list_array(item: &FieldRef, cells: &[Option<&Value>], column: &str) -> Result<ArrayRef> {
+    let mut lengths = Vec::with_capacity(cells.len());
+    let mut items = Vec::new();
+    for cell in cells {
+        match cell {
+            None | Some(Value::Null) => lengths.push(0),
+            Some(Value::Array(values)) => {
+                lengths.push(values.len());
+                items.extend(values.iter().map(Some));
+            }
+            Some(v) => return Err(type_error(column, "array", v)),
+        }
+    }
+
+    let path = format!("{column}[]");
+    let values = build_array(item.data_type(), &items, &path)?;
+    let array = ListArray::try_new(
+        item.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        null_buffer(cells),
+    )
+    .map_err(|e| DsonError::SchemaError(e.to_string()))?;
+    Ok(Arc::new(array))
+}
+
+// =============================================================================
+// RecordBatch -> Documents
+// =============================================================================
+
+/// Convert a [`RecordBatch`] back into one JSON document per row
+///
+/// Null cells are omitted from the emitted objects.
+///
+/// # Errors
+/// Returns an error if a column type cannot be encoded as JSON
+pub fn record_batch_to_documents(batch: &RecordBatch) -> Result<Vec<String>> {
+    let mut writer = arrow_json::LineDelimitedWriter::new(Vec::new());
+    writer
+        .write(batch)
+        .and_then(|()| writer.finish())
+        .map_err(|e| DsonError::SerializationError(e.to_string()))?;
+    let buffer = writer.into_inner();
+
+    let text =
+        std::str::from_utf8(&buffer).map_err(|e| DsonError::SerializationError(e.to_string()))?;
+    Ok(text.lines().map(str::to_string).collect())
+}
+
+// =============================================================================
+// Parquet
+// =============================================================================
+
+#[cfg(feature = "parquet")]
+pub use parquet_io::{ParquetBatchProcessor, read_parquet, write_parquet};
+
+#[cfg(feature = "parquet")]
+mod parquet_io {
+    use std::io::Write;
+    use std::time::Instant;
+
+    use arrow_array::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use parquet::arrow::ProjectionMask;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::basic::{Compression, ZstdLevel};
+    use parquet::file::properties::WriterProperties;
+
+    use super::record_batch_to_documents;
+    use crate::format_dson::{BatchStatistics, FormatBatchProcessor, FormatBatchResult, LineError};
+    use crate::skiptape::CompiledSchema;
+    use fionn_core::format::FormatKind;
+    use fionn_core::{DsonError, Result};
+
+    fn parquet_error(e: impl std::fmt::Display) -> DsonError {
+        DsonError::SerializationError(format!("parquet: {e}"))
+    }
+
+    /// Write record batches to a zstd-compressed Parquet stream
+    ///
+    /// All batches must share the schema of the first one. Writing an empty
+    /// slice is a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if the batches cannot be encoded or written
+    pub fn write_parquet<W: Write + Send>(writer: W, batches: &[RecordBatch]) -> Result<()> {
+        let Some(first) = batches.first() else {
+            return Ok(());
+        };
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(writer, first.schema(), Some(props)).map_err(parquet_error)?;
+        for batch in batches {
+            writer.write(batch).map_err(parquet_error)?;
+        }
+        writer.close().map_err(parquet_error)?;
+        Ok(())
+    }
+
+    /// Read all record batches from in-memory Parquet data
+    ///
+    /// # Errors
+    /// Returns an error if the data is not a valid Parquet file
+    pub fn read_parquet(data: &[u8], batch_size: usize) -> Result<Vec<RecordBatch>> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::copy_from_slice(data))
+            .map_err(parquet_error)?;
+        builder
+            .with_batch_size(batch_size.max(1))
+            .build()
+            .map_err(parquet_error)?
+            .map(|batch| batch.map_err(parquet_error))
+            .collect()
+    }
+
+    /// Parquet tape source
+    ///
+    /// Reads Parquet files and yields one normalized JSON document per row.
+    /// Schema filtering is pushed down as a column projection, so unselected
+    /// top-level columns are never decoded.
+    #[derive(Debug, Clone)]
+    pub struct ParquetBatchProcessor {
+        /// Rows decoded per Arrow batch
+        batch_size: usize,
+    }
+
+    impl Default for ParquetBatchProcessor {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl ParquetBatchProcessor {
+        /// Create a new Parquet batch processor
+        #[must_use]
+        pub const fn new() -> Self {
+            Self { batch_size: 8192 }
+        }
+
+        /// Set the number of rows decoded per Arrow batch
+        #[must_use]
+        pub const fn with_batch_size(mut self, batch_size: usize) -> Self {
+            self.batch_size = batch_size;
+            self
+        }
+
+        fn process(
+            &self,
+            data: &[u8],
+            schema: Option<&CompiledSchema>,
+        ) -> Result<FormatBatchResult> {
+            let start = Instant::now();
+            let mut builder =
+                ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::copy_from_slice(data))
+                    .map_err(parquet_error)?
+                    .with_batch_size(self.batch_size.max(1));
+
+            if let Some(schema) = schema.filter(|s| !s.include_patterns.is_empty()) {
+                let selected: Vec<usize> = builder
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| {
+                        schema.matches_path(field.name())
+                            || schema.should_include_object(field.name())
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect();
+                let mask = ProjectionMask::roots(builder.parquet_schema(), selected);
+                builder = builder.with_projection(mask);
+            }
+
+            let reader = builder.build().map_err(parquet_error)?;
+            let mut result = FormatBatchResult::new();
+            // Statistics count rows; a failed batch fails all of its rows and
+            // is reported at its first row
+            let mut row_index = 0;
+            let mut failed_rows = 0;
+            for batch in reader {
+                let batch = batch.map_err(parquet_error);
+                let batch_rows = batch
+                    .as_ref()
+                    .map_or_else(|_| self.batch_size.max(1), RecordBatch::num_rows);
+                match batch.and_then(|b| record_batch_to_documents(&b)) {
+                    Ok(documents) => result.documents.extend(documents),
+                    Err(e) => {
+                        failed_rows += batch_rows;
+                        result.errors.push(LineError {
+                            line_index: row_index,
+                            error_message: e.to_string(),
+                            raw_line: String::new(),
+                        });
+                    }
+                }
+                row_index += batch_rows;
+            }
+
+            let rows = result.documents.len();
+            result.statistics = BatchStatistics {
+                total_lines: rows + failed_rows,
+                successful_lines: rows,
+                failed_lines: failed_rows,
+                processing_time_ms: start.elapsed().as_secs_f64() * 1000.0,
+                avg_memory_per_line: data.len().checked_div(rows).unwrap_or(0),
+                overall_schema_match_ratio: 1.0,
+            };
+            Ok(result)
+        }
+    }
+
+    impl FormatBatchProcessor for ParquetBatchProcessor {
+        fn format_kind(&self) -> FormatKind {
+            // Rows are normalized to JSON documents
+            FormatKind::Json
+        }
+
+        fn process_batch(
+            &mut self,
+            data: &[u8],
+            schema: &CompiledSchema,
+        ) -> Result<FormatBatchResult> {
+            self.process(data, Some(schema))
+        }
+
+        fn process_batch_unfiltered(&mut self, data: &[u8]) -> Result<FormatBatchResult> {
+            self.process(data, None)
+        }
+
+        fn reset(&mut self) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Decimal128Type, Float64Type, Int64Type, UInt64Type};
+    use serde_json::json;
+
+    #[test]
+    fn test_infer_schema_widens_numbers() {
+        let records = vec![json!({"a": 1, "b": "x"}), json!({"a": 2.5, "c": true})];
+        let schema = infer_arrow_schema(&records).unwrap();
+        assert_eq!(
+            schema.field_with_name("a").unwrap().data_type(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            schema.field_with_name("b").unwrap().data_type(),
+            &DataType::Utf8
+        );
+        assert_eq!(
+            schema.field_with_name("c").unwrap().data_type(),
+            &DataType::Boolean
+        );
+    }
+
+    #[test]
+    fn test_large_unsigned_integers_keep_precision() {
+        let records = vec![
+            json!({"a": u64::MAX, "b": 1}),
+            json!({"a": 7, "b": u64::MAX}),
+        ];
+        let batch = records_to_record_batch(&records, None).unwrap();
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::UInt64);
+        assert_eq!(
+            batch.column(0).as_primitive::<UInt64Type>().value(0),
+            u64::MAX
+        );
+
+        let mixed = vec![json!({"a": -1}), json!({"a": u64::MAX})];
+        let batch = records_to_record_batch(&mixed, None).unwrap();
+        assert_eq!(
+            batch.schema().field(0).data_type(),
+            &DataType::Decimal128(20, 0)
+        );
+        let column = batch.column(0).as_primitive::<Decimal128Type>();
+        assert_eq!(column.value(0), -1);
+        assert_eq!(column.value(1), i128::from(u64::MAX));
+        assert_eq!(
+            record_batch_to_documents(&batch).unwrap()[1],
+            format!("{{\"a\":{}}}", u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_conflict_falls_back_to_utf8() {
+        let records = vec![json!({"a": 1}), json!({"a": "one"})];
+        let schema = infer_arrow_schema(&records).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn test_infer_schema_rejects_non_object() {
+        assert!(infer_arrow_schema(&[json!([1, 2])]).is_err());
+    }
+
+    #[test]
+    fn test_nested_struct_and_list_columns() {
+        let records = vec![
+            json!({"user": {"id": 1, "name": "a"}, "tags": ["x", "y"]}),
+            json!({"user": null, "tags": []}),
+            json!({"user": {"id": 3}}),
+        ];
+        let batch = records_to_record_batch(&records, None).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+
+        let user = batch.column_by_name("user").unwrap().as_struct();
+        assert!(user.is_null(1));
+        let ids = user
+            .column_by_name("id")
+            .unwrap()
+            .as_primitive::<Int64Type>();
+        assert_eq!(ids.value(0), 1);
+        assert_eq!(ids.value(2), 3);
+
+        let tags = batch.column_by_name("tags").unwrap().as_list::<i32>();
+        assert_eq!(tags.value_length(0), 2);
+        assert_eq!(tags.value_length(1), 0);
+        assert!(tags.is_null(2));
+    }
+
+    #[test]
+    fn test_supplied_schema_coerces_strings() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("score", DataType::Float64, true),
+        ]));
+        let records = vec![json!({"id": "42", "score": "1.5"})];
+        let batch = records_to_record_batch(&records, Some(schema)).unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().value(0), 42);
+        assert!(
+            (batch.column(1).as_primitive::<Float64Type>().value(0) - 1.5).abs() < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_supplied_schema_type_mismatch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)]));
+        let records = vec![json!({"id": "abc"})];
+        let err = records_to_record_batch(&records, Some(schema)).unwrap_err();
+        assert!(err.to_string().contains("id"));
+    }
+
+    #[test]
+    fn test_format_batch_round_trip() {
+        let mut batch = FormatBatchResult::new();
+        batch.documents.push(r#"{"a":1,"b":"x"}"#.to_string());
+        batch.documents.push(r#"{"a":2}"#.to_string());
+        let record_batch = format_batch_to_record_batch(&batch, None).unwrap();
+        let docs = record_batch_to_documents(&record_batch).unwrap();
+        assert_eq!(docs, vec![r#"{"a":1,"b":"x"}"#, r#"{"a":2}"#]);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_tabular_tape_to_record_batch() {
+        use fionn_core::format::FormatKind;
+        use fionn_simd::transform::UnifiedTape;
+
+        let tape = UnifiedTape::parse(b"id,name\n1,alice\n2,bob\n", FormatKind::Csv).unwrap();
+        let batch = tabular_tape_to_record_batch(&tape, None).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+        assert_eq!(batch.column(1).as_string::<i32>().value(1), "bob");
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_round_trip_with_projection() {
+        use crate::format_dson::FormatBatchProcessor;
+        use crate::skiptape::CompiledSchema;
+
+        let records = vec![json!({"id": 1, "name": "a"}), json!({"id": 2, "name": "b"})];
+        let batch = records_to_record_batch(&records, None).unwrap();
+        let mut buffer = Vec::new();
+        write_parquet(&mut buffer, &[batch]).unwrap();
+
+        let batches = read_parquet(&buffer, 1024).unwrap();
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 2);
+
+        let schema = CompiledSchema::compile(&["id".to_string()]).unwrap();
+        let result = ParquetBatchProcessor::new()
+            .process_batch(&buffer, &schema)
+            .unwrap();
+        assert_eq!(result.documents, vec![r#"{"id":1}"#, r#"{"id":2}"#]);
+        assert_eq!(result.statistics.total_lines, 2);
+        assert_eq!(result.statistics.failed_lines, 0);
+    }
+}
@@ -0,0 +1,508 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Transparent compressed input/output
+//!
+//! Detects gzip, zstd and bzip2 streams by their magic bytes or by a trailing
+//! compression extension (`events.jsonl.gz`, `archive.ndjson.zst`) and wraps
+//! them in streaming decoders and encoders. Decoded readers are plain
+//! [`BufRead`]s, so they feed [`line_batches`] (and from there the JSONL and
+//! ISONL batch processors) or `gron_jsonl_streaming` without buffering the
+//! whole file.
+//!
+//! Each codec sits behind its own cargo feature (`gzip`, `zstd`, `bzip2`;
+//! `compression` enables all three). Concatenated gzip members, zstd frames
+//! and bzip2 streams decode as a single stream.
+//!
+//! ```no_run
+//! use fionn_stream::compression::open_source;
+//!
+//! let source = open_source("events.jsonl.gz")?;
+//! println!("{:?} {:?}", source.compression, source.extension);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use fionn_core::FormatKind;
+
+/// Buffer size used for file readers and decoders
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Compression codec of a byte stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compression {
+    /// Uncompressed
+    #[default]
+    None,
+    /// gzip (RFC 1952), including multi-member files
+    Gzip,
+    /// Zstandard, including multi-frame files
+    Zstd,
+    /// bzip2
+    Bzip2,
+}
+
+impl Compression {
+    /// Codec name, which is also the cargo feature enabling it
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Bzip2 => "bzip2",
+        }
+    }
+
+    /// Canonical file extension, without the leading dot
+    #[must_use]
+    pub const fn extension(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Gzip => Some("gz"),
+            Self::Zstd => Some("zst"),
+            Self::Bzip2 => Some("bz2"),
+        }
+    }
+
+    /// Codec for a file extension (`gz`, `zst`, `bz2`, ...)
+    #[must_use]
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "gz" | "gzip" => Some(Self::Gzip),
+            "zst" | "zstd" => Some(Self::Zstd),
+            "bz2" | "bzip2" => Some(Self::Bzip2),
+            _ => None,
+        }
+    }
+
+    /// Codec named by the last extension of `path`
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+            .unwrap_or_default()
+    }
+
+    /// Codec identified by the leading magic bytes of a stream
+    #[must_use]
+    pub const fn from_magic(bytes: &[u8]) -> Self {
+        match bytes {
+            [0x1f, 0x8b, ..] => Self::Gzip,
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Self::Zstd,
+            [b'B', b'Z', b'h', b'1'..=b'9', ..] => Self::Bzip2,
+            _ => Self::None,
+        }
+    }
+
+    /// Whether this build can encode and decode the codec
+    #[must_use]
+    pub const fn is_available(self) -> bool {
+        match self {
+            Self::None => true,
+            Self::Gzip => cfg!(feature = "gzip"),
+            Self::Zstd => cfg!(feature = "zstd"),
+            Self::Bzip2 => cfg!(feature = "bzip2"),
+        }
+    }
+}
+
+/// Split a path into its compression codec and the extension underneath it
+///
+/// `events.jsonl.gz` yields `(Gzip, Some("jsonl"))`, `data.json` yields
+/// `(None, Some("json"))`.
+#[must_use]
+pub fn split_extension(path: &Path) -> (Compression, Option<String>) {
+    let compression = Compression::from_path(path);
+    let inner = if compression == Compression::None {
+        path.extension()
+    } else {
+        path.file_stem().map(Path::new).and_then(Path::extension)
+    };
+    (
+        compression,
+        inner
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase),
+    )
+}
+
+fn unavailable(compression: Compression) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "{} support not enabled (build with the `{}` feature)",
+            compression.name(),
+            compression.name()
+        ),
+    )
+}
+
+/// Peek at the magic bytes of a buffered reader without consuming them
+///
+/// # Errors
+/// Returns an error if the underlying reader fails.
+pub fn detect<R: BufRead>(reader: &mut R) -> io::Result<Compression> {
+    Ok(Compression::from_magic(reader.fill_buf()?))
+}
+
+/// Wrap a reader in a streaming decoder for `compression`
+///
+/// # Errors
+/// Returns an error if the codec is not enabled in this build or the decoder
+/// cannot be initialised.
+pub fn decoder<'a, R: BufRead + Send + 'a>(
+    reader: R,
+    compression: Compression,
+) -> io::Result<Box<dyn BufRead + Send + 'a>> {
+    match compression {
+        Compression::None => Ok(Box::new(reader)),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => Ok(Box::new(BufReader::with_capacity(
+            BUFFER_SIZE,
+            flate2::bufread::MultiGzDecoder::new(reader),
+        ))),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => Ok(Box::new(BufReader::with_capacity(
+            BUFFER_SIZE,
+            zstd::stream::read::Decoder::with_buffer(reader)?,
+        ))),
+        #[cfg(feature = "bzip2")]
+        Compression::Bzip2 => Ok(Box::new(BufReader::with_capacity(
+            BUFFER_SIZE,
+            bzip2::bufread::MultiBzDecoder::new(reader),
+        ))),
+        #[allow(unreachable_patterns)] // Matches feature-gated codecs
+        other => Err(unavailable(other)),
+    }
+}
+
+/// Detect the codec from magic bytes and wrap the reader in its decoder
+///
+/// # Errors
+/// Returns an error if reading fails or the detected codec is not enabled.
+pub fn auto_decoder<'a, R: BufRead + Send + 'a>(
+    mut reader: R,
+) -> io::Result<(Compression, Box<dyn BufRead + Send + 'a>)> {
+    let compression = detect(&mut reader)?;
+    Ok((compression, decoder(reader, compression)?))
+}
+
+/// An opened, decompressed input
+pub struct Source {
+    /// Data format from the extension, or sniffed from the decoded content
+    pub format: Option<FormatKind>,
+    /// Extension underneath any compression suffix (`jsonl` for `a.jsonl.gz`)
+    pub extension: Option<String>,
+    /// Compression codec of the file
+    pub compression: Compression,
+    /// Decompressed content
+    pub reader: Box<dyn BufRead + Send>,
+}
+
+impl Source {
+    /// Read the whole decompressed content into a byte vector
+    ///
+    /// # Errors
+    /// Returns an error if reading or decompression fails.
+    pub fn read_to_end(mut self) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Open a possibly compressed file for streaming
+///
+/// The codec is taken from the magic bytes, so mislabelled files still decode;
+/// the extension underneath a compression suffix decides the format, falling
+/// back to content detection on the first decoded block.
+///
+/// # Errors
+/// Returns an error if the file cannot be opened or read, or its codec is not
+/// enabled in this build.
+pub fn open_source(path: impl AsRef<Path>) -> io::Result<Source> {
+    let path = path.as_ref();
+    let file = BufReader::with_capacity(BUFFER_SIZE, File::open(path)?);
+    let (compression, mut reader) = auto_decoder(file)?;
+    let (_, extension) = split_extension(path);
+
+    let format = if let Some(detected) = extension
+        .as_deref()
+        .and_then(FormatKind::detect_from_extension)
+    {
+        Some(detected.format)
+    } else {
+        let head = reader.fill_buf()?;
+        (!head.is_empty()).then(|| FormatKind::detect_from_content(head).format)
+    };
+
+    Ok(Source {
+        format,
+        extension,
+        compression,
+        reader,
+    })
+}
+
+/// Writer that compresses into an inner writer
+///
+/// Call [`finish`](Self::finish) to write the trailer; dropping an encoder
+/// without finishing may leave a truncated stream.
+pub enum CompressedWriter<W: Write> {
+    /// Pass-through
+    Plain(W),
+    /// gzip encoder
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+    /// Zstandard encoder
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, W>),
+    /// bzip2 encoder
+    #[cfg(feature = "bzip2")]
+    Bzip2(bzip2::write::BzEncoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    /// Wrap `writer` in an encoder for `compression` at the default level
+    ///
+    /// # Errors
+    /// Returns an error if the codec is not enabled in this build or the
+    /// encoder cannot be initialised.
+    pub fn new(writer: W, compression: Compression) -> io::Result<Self> {
+        match compression {
+            Compression::None => Ok(Self::Plain(writer)),
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Ok(Self::Gzip(flate2::write::GzEncoder::new(
+                writer,
+                flate2::Compression::default(),
+            ))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(Self::Zstd(zstd::stream::write::Encoder::new(
+                writer,
+                zstd::DEFAULT_COMPRESSION_LEVEL,
+            )?)),
+            #[cfg(feature = "bzip2")]
+            Compression::Bzip2 => Ok(Self::Bzip2(bzip2::write::BzEncoder::new(
+                writer,
+                bzip2::Compression::default(),
+            ))),
+            #[allow(unreachable_patterns)] // Matches feature-gated codecs
+            other => Err(unavailable(other)),
+        }
+    }
+
+    /// Flush the encoder, write its trailer and return the inner writer
+    ///
+    /// # Errors
+    /// Returns an error if writing the trailer fails.
+    pub fn finish(self) -> io::Result<W> {
+        let mut writer = match self {
+            Self::Plain(writer) => writer,
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.finish()?,
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.finish()?,
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(encoder) => encoder.finish()?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.write(buf),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(encoder) => encoder.flush(),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// Create a file, compressing according to its extension
+///
+/// # Errors
+/// Returns an error if the file cannot be created or its codec is not enabled.
+pub fn create_sink(path: impl AsRef<Path>) -> io::Result<CompressedWriter<BufWriter<File>>> {
+    let path = path.as_ref();
+    let compression = Compression::from_path(path);
+    if !compression.is_available() {
+        return Err(unavailable(compression));
+    }
+    CompressedWriter::new(BufWriter::new(File::create(path)?), compression)
+}
+
+/// Iterator over batches of whole lines read from a [`BufRead`]
+///
+/// Each batch holds complete newline-terminated records (the final one may
+/// lack its newline), so it can be handed straight to a batch processor.
+pub struct LineBatches<R> {
+    reader: R,
+    batch_bytes: usize,
+}
+
+/// Split a reader into line-aligned batches of roughly `batch_bytes` bytes
+#[must_use]
+pub const fn line_batches<R: BufRead>(reader: R, batch_bytes: usize) -> LineBatches<R> {
+    LineBatches {
+        reader,
+        batch_bytes,
+    }
+}
+
+impl<R: BufRead> Iterator for LineBatches<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut batch = Vec::with_capacity(self.batch_bytes.min(BUFFER_SIZE));
+        while batch.len() < self.batch_bytes {
+            match self.reader.read_until(b'\n', &mut batch) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        (!batch.is_empty()).then_some(Ok(batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
+        let mut writer = CompressedWriter::new(Vec::new(), compression).unwrap();
+        writer.write_all(data).unwrap();
+        writer.finish().unwrap()
+    }
+
+    fn decompress(data: &[u8]) -> (Compression, Vec<u8>) {
+        let (compression, mut reader) = auto_decoder(Cursor::new(data.to_vec())).unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        (compression, out)
+    }
+
+    #[test]
+    fn test_magic_and_extension_detection() {
+        assert_eq!(Compression::from_magic(&[0x1f, 0x8b, 8]), Compression::Gzip);
+        assert_eq!(
+            Compression::from_magic(&[0x28, 0xb5, 0x2f, 0xfd]),
+            Compression::Zstd
+        );
+        assert_eq!(Compression::from_magic(b"BZh9"), Compression::Bzip2);
+        assert_eq!(Compression::from_magic(b"BZh0"), Compression::None);
+        assert_eq!(Compression::from_magic(b"{\"a\""), Compression::None);
+
+        assert_eq!(
+            split_extension(Path::new("events.jsonl.gz")),
+            (Compression::Gzip, Some("jsonl".to_string()))
+        );
+        assert_eq!(
+            split_extension(Path::new("archive.NDJSON.zst")),
+            (Compression::Zstd, Some("ndjson".to_string()))
+        );
+        assert_eq!(
+            split_extension(Path::new("data.json")),
+            (Compression::None, Some("json".to_string()))
+        );
+        assert_eq!(
+            split_extension(Path::new("dump.bz2")),
+            (Compression::Bzip2, None)
+        );
+    }
+
+    #[test]
+    fn test_plain_passthrough_and_line_batches() {
+        let data = b"{\"a\":1}\n{\"a\":2}\n{\"a\":3}";
+        let (compression, out) = decompress(&compress(data, Compression::None));
+        assert_eq!(compression, Compression::None);
+        assert_eq!(out, data);
+
+        let batches: Vec<Vec<u8>> = line_batches(Cursor::new(data), 10)
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0], b"{\"a\":1}\n{\"a\":2}\n");
+        assert_eq!(batches[1], b"{\"a\":3}");
+    }
+
+    #[test]
+    fn test_unavailable_codec_reports_feature() {
+        for compression in [Compression::Gzip, Compression::Zstd, Compression::Bzip2] {
+            if !compression.is_available() {
+                let err = decoder(Cursor::new(Vec::new()), compression).err().unwrap();
+                assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+                assert!(err.to_string().contains(compression.name()));
+            }
+        }
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip_multi_member() {
+        let mut data = compress(b"{\"a\":1}\n", Compression::Gzip);
+        data.extend(compress(b"{\"a\":2}\n", Compression::Gzip));
+        let (compression, out) = decompress(&data);
+        assert_eq!(compression, Compression::Gzip);
+        assert_eq!(out, b"{\"a\":1}\n{\"a\":2}\n");
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_multi_frame() {
+        let mut data = compress(b"line one\n", Compression::Zstd);
+        data.extend(compress(b"line two\n", Compression::Zstd));
+        let (compression, out) = decompress(&data);
+        assert_eq!(compression, Compression::Zstd);
+        assert_eq!(out, b"line one\nline two\n");
+    }
+
+    #[cfg(feature = "bzip2")]
+    #[test]
+    fn test_bzip2_roundtrip() {
+        let payload = b"{\"k\":\"v\"}\n".repeat(100);
+        let (compression, out) = decompress(&compress(&payload, Compression::Bzip2));
+        assert_eq!(compression, Compression::Bzip2);
+        assert_eq!(out, payload);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_open_source_and_create_sink() {
+        let path =
+            std::env::temp_dir().join(format!("fionn-compression-{}.jsonl.gz", std::process::id()));
+        let mut sink = create_sink(&path).unwrap();
+        sink.write_all(b"{\"a\":1}\n{\"a\":2}\n").unwrap();
+        sink.finish().unwrap();
+
+        let source = open_source(&path).unwrap();
+        assert_eq!(source.compression, Compression::Gzip);
+        assert_eq!(source.extension.as_deref(), Some("jsonl"));
+        assert_eq!(source.format, Some(FormatKind::Json));
+        let lines: Vec<String> = source.reader.lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["{\"a\":1}", "{\"a\":2}"]);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -11,6 +11,7 @@ use crate::skiptape::csv_batch::SimdCsvBatchProcessor;
 use fionn_core::Result;
 use fionn_core::format::FormatKind;
 use fionn_ops::DsonOperation;
+use fionn_simd::formats::CsvDialect;
 
 // =============================================================================
 // CsvDsonProcessor
@@ -31,7 +32,7 @@ impl Default for CsvDsonProcessor {
 impl CsvDsonProcessor {
     /// Create a new CSV-DSON processor
     #[must_use]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             inner: FormatDsonProcessor::new(SimdCsvBatchProcessor::new()),
         }
@@ -39,12 +40,28 @@ impl CsvDsonProcessor {
 
     /// Create a processor with custom delimiter
     #[must_use]
-    pub fn with_delimiter(delimiter: u8) -> Self {
+    pub const fn with_delimiter(delimiter: u8) -> Self {
         Self {
             inner: FormatDsonProcessor::new(SimdCsvBatchProcessor::with_delimiter(delimiter)),
         }
     }
 
+    /// Create a processor for an explicit CSV dialect
+    #[must_use]
+    pub const fn with_dialect(dialect: CsvDialect) -> Self {
+        Self {
+            inner: FormatDsonProcessor::new(SimdCsvBatchProcessor::with_dialect(dialect)),
+        }
+    }
+
+    /// Infer column types from the first `sample_rows` data rows
+    #[must_use]
+    pub fn with_type_inference(mut self, sample_rows: usize) -> Self {
+        let processor = std::mem::take(self.inner.batch_processor_mut());
+        *self.inner.batch_processor_mut() = processor.with_type_inference(sample_rows);
+        self
+    }
+
     /// Process CSV data with schema filtering and DSON operations
     ///
     /// # Errors
@@ -146,6 +163,16 @@ mod tests {
         assert_eq!(processor.format_kind(), FormatKind::Csv);
     }
 
+    #[test]
+    fn test_with_dialect_and_type_inference() {
+        let dialect = CsvDialect::tsv().with_trim(true);
+        let mut processor = CsvDsonProcessor::with_dialect(dialect).with_type_inference(10);
+        let result = processor
+            .process_unfiltered(b"id\tname\n 7 \t Alice \n")
+            .unwrap();
+        assert_eq!(result.documents[0], r#"{"id":7,"name":"Alice"}"#);
+    }
+
     #[test]
     fn test_process_unfiltered() {
         let mut processor = CsvDsonProcessor::new();
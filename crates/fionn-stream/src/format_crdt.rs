@@ -6,14 +6,15 @@
 
 use crate::format_dson::{FormatBatchProcessor, FormatBatchResult, FormatDsonProcessor};
 use crate::skiptape::CompiledSchema;
-use fionn_core::Result;
 use fionn_core::format::FormatKind;
+use fionn_core::{DsonError, Result};
 use fionn_ops::dson_traits::{
     CrdtMerge, CrdtOperation, DeltaCrdt, MergeConflict, OpBasedCrdt, VectorClock,
 };
 use fionn_ops::{DsonOperation, MergeStrategy, OperationValue};
+use serde_json::{Map, Value};
 use smallvec::SmallVec;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // =============================================================================
 // CRDT Delta Types
@@ -66,6 +67,137 @@ impl FormatDelta {
     pub const fn len(&self) -> usize {
         self.operations.len()
     }
+
+    /// Encode the delta for shipping to another process
+    ///
+    /// The encoding is JSON with replica ids spelled out, so a replica that has
+    /// never heard of the sender can still rebuild its vector clocks.
+    ///
+    /// # Errors
+    /// Returns an error if the delta carries operations other than field
+    /// add/modify/delete, which have no portable encoding.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let operations = self
+            .operations
+            .iter()
+            .map(encode_operation)
+            .collect::<Result<Vec<_>>>()?;
+        let envelope = serde_json::json!({
+            "format": self.format_kind.name(),
+            "clock": self.clock.clocks(),
+            "operations": operations,
+        });
+        serde_json::to_vec(&envelope).map_err(|e| DsonError::SerializationError(e.to_string()))
+    }
+
+    /// Decode a delta produced by [`Self::to_bytes`]
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a well-formed delta
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let envelope: Value = serde_json::from_slice(bytes)
+            .map_err(|e| DsonError::ParseError(format!("Invalid delta: {e}")))?;
+        let format_kind = envelope
+            .get("format")
+            .and_then(Value::as_str)
+            .and_then(FormatKind::detect_from_extension)
+            .map(|detected| detected.format)
+            .ok_or_else(|| invalid_delta("missing or unknown format"))?;
+        let clock = decode_clock(envelope.get("clock"))?;
+        let operations = envelope
+            .get("operations")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid_delta("missing operations"))?
+            .iter()
+            .map(decode_operation)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::with_operations(operations, clock, format_kind))
+    }
+}
+
+fn invalid_delta(reason: &str) -> DsonError {
+    DsonError::ParseError(format!("Invalid delta: {reason}"))
+}
+
+/// Encode one field operation with its causal metadata
+fn encode_operation(op: &CrdtOperation) -> Result<Value> {
+    let (kind, path, value) = match &op.operation {
+        DsonOperation::FieldAdd { path, value } => ("add", path, Some(value)),
+        DsonOperation::FieldModify { path, value } => ("modify", path, Some(value)),
+        DsonOperation::FieldDelete { path } => ("delete", path, None),
+        other => {
+            return Err(DsonError::InvalidOperation(format!(
+                "{other:?} cannot be encoded in a delta"
+            )));
+        }
+    };
+
+    let mut entry = serde_json::json!({
+        "op": kind,
+        "path": path,
+        "timestamp": op.timestamp,
+        "replica": op.replica_id,
+        "clock": op.vector_clock.clocks(),
+    });
+    match value {
+        Some(OperationValue::ObjectRef { .. }) => entry["container"] = "object".into(),
+        Some(OperationValue::ArrayRef { .. }) => entry["container"] = "array".into(),
+        Some(value) => entry["value"] = operation_value_to_json(value),
+        None => {}
+    }
+    Ok(entry)
+}
+
+/// Decode one operation written by [`encode_operation`]
+fn decode_operation(entry: &Value) -> Result<CrdtOperation> {
+    let path = entry
+        .get("path")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid_delta("operation without a path"))?
+        .to_string();
+    let value = match entry.get("container").and_then(Value::as_str) {
+        Some("object") => OperationValue::ObjectRef { start: 0, end: 0 },
+        Some("array") => OperationValue::ArrayRef { start: 0, end: 0 },
+        Some(other) => return Err(invalid_delta(&format!("unknown container {other}"))),
+        None => entry
+            .get("value")
+            .map_or(OperationValue::Null, json_to_operation_value),
+    };
+    let operation = match entry.get("op").and_then(Value::as_str) {
+        Some("add") => DsonOperation::FieldAdd { path, value },
+        Some("modify") => DsonOperation::FieldModify { path, value },
+        Some("delete") => DsonOperation::FieldDelete { path },
+        _ => return Err(invalid_delta("unknown operation kind")),
+    };
+
+    Ok(CrdtOperation {
+        operation,
+        timestamp: entry
+            .get("timestamp")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| invalid_delta("operation without a timestamp"))?,
+        replica_id: entry
+            .get("replica")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid_delta("operation without a replica"))?
+            .to_string(),
+        vector_clock: decode_clock(entry.get("clock"))?,
+    })
+}
+
+fn decode_clock(clock: Option<&Value>) -> Result<VectorClock> {
+    let entries = clock
+        .and_then(Value::as_object)
+        .ok_or_else(|| invalid_delta("missing vector clock"))?;
+    let clocks = entries
+        .iter()
+        .map(|(replica, time)| {
+            time.as_u64()
+                .map(|time| (replica.clone(), time))
+                .ok_or_else(|| invalid_delta("vector clock entries must be counters"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(VectorClock::from_clocks(clocks))
 }
 
 // =============================================================================
@@ -79,6 +211,9 @@ struct DocumentState {
     content: String,
     /// Last write timestamp for each field path
     field_timestamps: HashMap<String, u64>,
+    /// Causal metadata of the value held at each written path, ordered so a
+    /// subtree is a contiguous range
+    field_writes: BTreeMap<String, FieldWrite>,
 }
 
 impl DocumentState {
@@ -86,7 +221,359 @@ impl DocumentState {
         Self {
             content,
             field_timestamps: HashMap::new(),
+            field_writes: BTreeMap::new(),
+        }
+    }
+
+    /// Store new content and the write that produced the value at `path`
+    fn commit(&mut self, json: &Value, path: &str, write: FieldWrite) {
+        if let Ok(content) = serde_json::to_string(json) {
+            self.content = content;
+        }
+        if write.shadows {
+            // The subtree is gone, so writes recorded below it are too
+            let below: Vec<String> = self
+                .field_writes
+                .range(format!("{path}.")..format!("{path}/"))
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in below {
+                self.field_writes.remove(&key);
+            }
+        }
+        self.field_timestamps
+            .insert(path.to_string(), write.timestamp);
+        self.field_writes.insert(path.to_string(), write);
+    }
+
+    /// Whether an ancestor of `path` holds a scalar or delete that `clock` has not seen
+    ///
+    /// A nested write racing a concurrent overwrite of its parent is dropped on
+    /// every replica, whichever order the two arrive in.
+    fn shadowed(&self, path: &str, clock: &VectorClock) -> bool {
+        path.match_indices('.').any(|(end, _)| {
+            self.field_writes
+                .get(&path[..end])
+                .is_some_and(|write| write.shadows && !write.seen_by(clock))
+        })
+    }
+
+    /// Merge a remote write (`None` for a delete) into the value at `path`
+    ///
+    /// A write that has seen every write merged into the current value replaces
+    /// it. Concurrent writes are ordered by `(Lamport timestamp, replica id)` and
+    /// handed to `strategy`, so every replica picks the same value.
+    fn merge_write(
+        &mut self,
+        op: &CrdtOperation,
+        path: &str,
+        value: Option<&OperationValue>,
+        strategy: &MergeStrategy,
+    ) -> Option<MergeConflict> {
+        if self.shadowed(path, &op.vector_clock) {
+            return None;
+        }
+        let mut json: Value = serde_json::from_str(&self.content).ok()?;
+
+        let Some(local) = self
+            .field_writes
+            .get(path)
+            .filter(|write| !write.seen_by(&op.vector_clock))
+        else {
+            if write_json_path(&mut json, path, value) {
+                self.commit(&json, path, FieldWrite::new(op, shadows(value)));
+            }
+            return None;
+        };
+        let local_timestamp = local.timestamp;
+        let remote_wins = local.precedes(op);
+        let mut write = self.field_writes.remove(path)?;
+        write.absorb(op);
+
+        let current_json = lookup_json_path(&json, path);
+        let current = current_json.map(json_to_operation_value);
+
+        let agrees = match (current_json, value) {
+            (None, None)
+            | (Some(Value::Object(_)), Some(OperationValue::ObjectRef { .. }))
+            | (Some(Value::Array(_)), Some(OperationValue::ArrayRef { .. })) => true,
+            (Some(json), Some(value)) => !is_container(json) && current.as_ref() == Some(value),
+            _ => false,
+        };
+        if agrees {
+            self.field_writes.insert(path.to_string(), write);
+            return None;
+        }
+
+        let resolved = match (current_json, value) {
+            (Some(json), Some(remote)) if !is_container(json) && !is_container_ref(remote) => {
+                // Rank the writes rather than pass raw timestamps, so strategies
+                // that fall back to LWW honour the replica tie-break
+                let (local_rank, remote_rank) = if remote_wins { (0, 1) } else { (1, 0) };
+                current
+                    .as_ref()
+                    .map(|local| strategy.resolve(local, remote, local_rank, remote_rank))
+            }
+            _ if remote_wins => value.cloned(),
+            _ => current.clone(),
+        };
+
+        let conflict = MergeConflict {
+            path: path.to_string(),
+            local_value: current.clone().unwrap_or(OperationValue::Null),
+            remote_value: value.cloned().unwrap_or(OperationValue::Null),
+            local_timestamp,
+            remote_timestamp: op.timestamp,
+            resolved_value: resolved.clone(),
+        };
+
+        if resolved == current {
+            self.field_writes.insert(path.to_string(), write);
+        } else if write_json_path(&mut json, path, resolved.as_ref()) {
+            write.shadows = shadows(resolved.as_ref());
+            self.commit(&json, path, write);
+        }
+        Some(conflict)
+    }
+}
+
+/// Causal metadata for the value currently held at a field path
+#[derive(Debug, Clone)]
+struct FieldWrite {
+    /// Lamport timestamp of the winning write
+    timestamp: u64,
+    /// Replica of the winning write, breaks Lamport ties
+    replica_id: String,
+    /// Writes merged into the value, as replica -> clock entry of the write
+    dots: HashMap<String, u64>,
+    /// Whether the value hides its subtree (a scalar or a delete)
+    shadows: bool,
+}
+
+impl FieldWrite {
+    fn new(op: &CrdtOperation, shadows: bool) -> Self {
+        let mut dots = HashMap::with_capacity(1);
+        dots.insert(op.replica_id.clone(), op.vector_clock.get(&op.replica_id));
+        Self {
+            timestamp: op.timestamp,
+            replica_id: op.replica_id.clone(),
+            dots,
+            shadows,
+        }
+    }
+
+    /// Whether every write merged into this value is in the causal past of `clock`
+    fn seen_by(&self, clock: &VectorClock) -> bool {
+        let covered = clock
+            .iter()
+            .filter(|(replica, time)| self.dots.get(*replica).is_some_and(|dot| dot <= time))
+            .count();
+        covered == self.dots.len()
+    }
+
+    /// Whether `op` orders after the winning write under LWW
+    fn precedes(&self, op: &CrdtOperation) -> bool {
+        (self.timestamp, self.replica_id.as_str()) < (op.timestamp, op.replica_id.as_str())
+    }
+
+    /// Fold a concurrent write into this value's causal metadata
+    fn absorb(&mut self, op: &CrdtOperation) {
+        for (replica, time) in op.vector_clock.iter() {
+            if self.dots.get(replica).is_some_and(|dot| *dot <= time) {
+                self.dots.remove(replica);
+            }
+        }
+        self.dots
+            .insert(op.replica_id.clone(), op.vector_clock.get(&op.replica_id));
+        if self.precedes(op) {
+            self.timestamp = op.timestamp;
+            self.replica_id.clone_from(&op.replica_id);
+        }
+    }
+}
+
+/// Whether writing `value` (`None` for a delete) hides everything below the path
+const fn shadows(value: Option<&OperationValue>) -> bool {
+    !matches!(value, Some(value) if is_container_ref(value))
+}
+
+const fn is_container_ref(value: &OperationValue) -> bool {
+    matches!(
+        value,
+        OperationValue::ObjectRef { .. } | OperationValue::ArrayRef { .. }
+    )
+}
+
+const fn is_container(json: &Value) -> bool {
+    matches!(json, Value::Object(_) | Value::Array(_))
+}
+
+/// The path and value (`None` for a delete) a field operation writes
+fn field_target(op: &DsonOperation) -> Option<(&str, Option<&OperationValue>)> {
+    match op {
+        DsonOperation::FieldAdd { path, value } | DsonOperation::FieldModify { path, value } => {
+            Some((path, Some(value)))
+        }
+        DsonOperation::FieldDelete { path } => Some((path, None)),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// JSON Path Helpers
+// =============================================================================
+//
+// Paths are dot-separated; a numeric segment indexes into an array. Arrays
+// only grow by appending at their length, and deleting an element nulls it
+// rather than shifting its neighbours, so positions stay stable across
+// replicas.
+
+/// Step into `part` of a container, creating a missing intermediate object
+fn child_mut<'a>(current: &'a mut Value, part: &str) -> Option<&'a mut Value> {
+    let child = match current {
+        Value::Object(obj) => obj.entry(part.to_string()).or_insert(Value::Null),
+        Value::Array(items) => {
+            let index = part.parse::<usize>().ok()?;
+            if index == items.len() {
+                items.push(Value::Null);
+            }
+            items.get_mut(index)?
+        }
+        _ => return None,
+    };
+    if child.is_null() {
+        *child = Value::Object(Map::new());
+    }
+    Some(child)
+}
+
+/// Set a value at a JSON path, returning false if the path cannot hold it
+///
+/// A container marker leaves an existing container of the same kind in place,
+/// so concurrent creations of the same object merge instead of clobbering.
+fn set_json_path(json: &mut Value, path: &str, value: &OperationValue) -> bool {
+    let parts: Vec<&str> = path.split('.').collect();
+    let Some((last, parents)) = parts.split_last() else {
+        return false;
+    };
+
+    let mut current = json;
+    for part in parents {
+        match child_mut(current, part) {
+            Some(child) => current = child,
+            None => return false,
+        }
+    }
+
+    let slot = match current {
+        Value::Object(obj) => obj.entry((*last).to_string()).or_insert(Value::Null),
+        Value::Array(items) => {
+            let Ok(index) = last.parse::<usize>() else {
+                return false;
+            };
+            if index == items.len() {
+                items.push(Value::Null);
+            }
+            match items.get_mut(index) {
+                Some(slot) => slot,
+                None => return false,
+            }
+        }
+        _ => return false,
+    };
+
+    match (value, &*slot) {
+        (OperationValue::ObjectRef { .. }, Value::Object(_))
+        | (OperationValue::ArrayRef { .. }, Value::Array(_)) => {}
+        _ => *slot = operation_value_to_json(value),
+    }
+    true
+}
+
+/// Delete a value at a JSON path; array elements are nulled in place
+fn delete_json_path(json: &mut Value, path: &str) {
+    let parts: Vec<&str> = path.split('.').collect();
+    let Some((last, parents)) = parts.split_last() else {
+        return;
+    };
+
+    let mut current = json;
+    for part in parents {
+        let child = match current {
+            Value::Object(obj) => obj.get_mut(*part),
+            Value::Array(items) => part.parse::<usize>().ok().and_then(|i| items.get_mut(i)),
+            _ => None,
+        };
+        match child {
+            Some(child) => current = child,
+            None => return,
+        }
+    }
+
+    match current {
+        Value::Object(obj) => {
+            obj.remove(*last);
+        }
+        Value::Array(items) => {
+            if let Some(item) = last.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                *item = Value::Null;
+            }
         }
+        _ => {}
+    }
+}
+
+/// Set (`Some`) or delete (`None`) a value at a JSON path
+fn write_json_path(json: &mut Value, path: &str, value: Option<&OperationValue>) -> bool {
+    if let Some(value) = value {
+        set_json_path(json, path, value)
+    } else {
+        delete_json_path(json, path);
+        true
+    }
+}
+
+/// Look up a value at a JSON path
+fn lookup_json_path<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(json, |current, part| match current {
+            Value::Object(obj) => obj.get(part),
+            Value::Array(items) => items.get(part.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+/// Convert `OperationValue` to JSON
+///
+/// Note: `ArrayRef` and `ObjectRef` are tape position ranges in the actual implementation,
+/// so we represent them as placeholder values. In practice, these should be resolved
+/// from the tape before calling this function.
+fn operation_value_to_json(value: &OperationValue) -> Value {
+    match value {
+        OperationValue::Null => Value::Null,
+        OperationValue::BoolRef(b) => Value::Bool(*b),
+        OperationValue::NumberRef(n) => n.to_json_value(),
+        OperationValue::StringRef(s) => Value::String(s.clone()),
+        // ArrayRef and ObjectRef are tape position ranges - represent as empty
+        // containers; nested values arrive as separate operations
+        OperationValue::ArrayRef { .. } => Value::Array(Vec::new()),
+        OperationValue::ObjectRef { .. } => Value::Object(Map::new()),
+    }
+}
+
+/// Convert JSON to `OperationValue`
+///
+/// Note: Arrays and objects are represented as JSON strings in `StringRef`,
+/// since ArrayRef/ObjectRef require tape positions which we don't have here.
+fn json_to_operation_value(json: &Value) -> OperationValue {
+    match json {
+        Value::Null => OperationValue::Null,
+        Value::Bool(b) => OperationValue::BoolRef(*b),
+        Value::Number(n) => OperationValue::NumberRef(n.into()),
+        Value::String(s) => OperationValue::StringRef(s.clone()),
+        // Arrays and objects are serialized to JSON strings
+        // since ArrayRef/ObjectRef use tape positions
+        Value::Array(_) | Value::Object(_) => OperationValue::StringRef(json.to_string()),
     }
 }
 
@@ -98,7 +585,8 @@ impl DocumentState {
 ///
 /// This wraps a `FormatDsonProcessor` and adds CRDT semantics:
 /// - Vector clock tracking for causality
-/// - Last-Writer-Wins (LWW) register semantics per field
+/// - Last-Writer-Wins (LWW) register semantics per field, with per-path
+///   strategies for concurrent writes
 /// - Delta-state synchronization
 /// - Operation buffering for causal ordering
 pub struct FormatCrdtProcessor<P: FormatBatchProcessor> {
@@ -112,6 +600,8 @@ pub struct FormatCrdtProcessor<P: FormatBatchProcessor> {
     lamport_timestamp: u64,
     /// Default merge strategy
     default_strategy: MergeStrategy,
+    /// Strategy overrides for concurrent writes at a path and below
+    path_strategies: HashMap<String, MergeStrategy>,
     /// Document states indexed by document ID or index
     document_states: HashMap<String, DocumentState>,
     /// Operation history for delta generation
@@ -130,6 +620,7 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
             vector_clock: VectorClock::new(),
             lamport_timestamp: 0,
             default_strategy: MergeStrategy::LastWriteWins,
+            path_strategies: HashMap::new(),
             document_states: HashMap::new(),
             operation_history: Vec::new(),
             operation_buffer: SmallVec::new(),
@@ -143,6 +634,31 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
         self
     }
 
+    /// Resolve concurrent writes at `path`, and anything below it, with `strategy`
+    ///
+    /// A trailing `.*` is accepted and means the same as the bare path.
+    pub fn set_path_strategy(&mut self, path: &str, strategy: MergeStrategy) {
+        let path = path.strip_suffix(".*").unwrap_or(path);
+        self.path_strategies.insert(path.to_string(), strategy);
+    }
+
+    /// Get the strategy for concurrent writes at `path`
+    ///
+    /// The closest ancestor with an override wins; otherwise the default applies.
+    #[must_use]
+    pub fn strategy_for(&self, path: &str) -> &MergeStrategy {
+        let mut candidate = path;
+        loop {
+            if let Some(strategy) = self.path_strategies.get(candidate) {
+                return strategy;
+            }
+            match candidate.rsplit_once('.') {
+                Some((parent, _)) => candidate = parent,
+                None => return &self.default_strategy,
+            }
+        }
+    }
+
     /// Get the format kind
     #[must_use]
     pub fn format_kind(&self) -> FormatKind {
@@ -244,6 +760,58 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
         Ok(result)
     }
 
+    /// Track a document without advancing the clock
+    ///
+    /// Use this to seed replicas; content that peers should receive has to be
+    /// written through [`Self::apply_local`].
+    pub fn insert_document(&mut self, doc_id: impl Into<String>, content: String) {
+        self.document_states
+            .insert(doc_id.into(), DocumentState::new(content));
+    }
+
+    /// Apply a local operation to every tracked document and record it for replication
+    ///
+    /// Returns the operation stamped with this replica's Lamport timestamp and
+    /// vector clock. Peers receive it through [`DeltaCrdt::generate_delta`].
+    ///
+    /// # Errors
+    /// Returns an error if a document cannot hold the write, e.g. the path
+    /// runs through a scalar or skips past the end of an array.
+    pub fn apply_local(&mut self, op: &DsonOperation) -> Result<CrdtOperation> {
+        let target = field_target(op);
+
+        let mut updated = Vec::new();
+        if let Some((path, value)) = target {
+            for (doc_id, state) in &self.document_states {
+                let mut json: Value = serde_json::from_str(&state.content)
+                    .map_err(|e| DsonError::ParseError(format!("{doc_id}: {e}")))?;
+                if !write_json_path(&mut json, path, value) {
+                    return Err(DsonError::InvalidOperation(format!(
+                        "cannot write {path} in {doc_id}: parent is not a container"
+                    )));
+                }
+                updated.push((doc_id.clone(), json));
+            }
+        }
+
+        let crdt_op = self.prepare_operation(op);
+        if let Some((path, value)) = target {
+            for (doc_id, json) in updated {
+                if let Some(state) = self.document_states.get_mut(&doc_id) {
+                    state.commit(&json, path, FieldWrite::new(&crdt_op, shadows(value)));
+                }
+            }
+        }
+        self.operation_history.push(crdt_op.clone());
+        Ok(crdt_op)
+    }
+
+    /// Whether `op` is already reflected in the local vector clock
+    fn has_seen(&self, op: &CrdtOperation) -> bool {
+        let time = op.vector_clock.get(&op.replica_id);
+        time > 0 && self.vector_clock.get(&op.replica_id) >= time
+    }
+
     /// Apply a local operation and prepare it for replication
     fn prepare_operation(&mut self, op: &DsonOperation) -> CrdtOperation {
         self.lamport_timestamp += 1;
@@ -295,11 +863,10 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
                 // Apply the update to the document content
                 if let Ok(mut json_value) =
                     serde_json::from_str::<serde_json::Value>(&state.content)
+                    && set_json_path(&mut json_value, path, value)
+                    && let Ok(new_content) = serde_json::to_string(&json_value)
                 {
-                    Self::set_json_path(&mut json_value, path, value);
-                    if let Ok(new_content) = serde_json::to_string(&json_value) {
-                        state.content = new_content;
-                    }
+                    state.content = new_content;
                 }
 
                 None
@@ -323,56 +890,6 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
         }
     }
 
-    /// Set a value at a JSON path
-    fn set_json_path(json: &mut serde_json::Value, path: &str, value: &OperationValue) {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = json;
-
-        for (i, part) in parts.iter().enumerate() {
-            if i == parts.len() - 1 {
-                // Final part - set the value
-                if let serde_json::Value::Object(obj) = current {
-                    obj.insert((*part).to_string(), Self::operation_value_to_json(value));
-                }
-            } else {
-                // Navigate down
-                if let serde_json::Value::Object(obj) = current {
-                    current = obj
-                        .entry((*part).to_string())
-                        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
-                }
-            }
-        }
-    }
-
-    /// Convert `OperationValue` to JSON
-    ///
-    /// Note: `ArrayRef` and `ObjectRef` are tape position ranges in the actual implementation,
-    /// so we represent them as placeholder values. In practice, these should be resolved
-    /// from the tape before calling this function.
-    fn operation_value_to_json(value: &OperationValue) -> serde_json::Value {
-        match value {
-            OperationValue::Null => serde_json::Value::Null,
-            OperationValue::BoolRef(b) => serde_json::Value::Bool(*b),
-            OperationValue::NumberRef(n) => n
-                .parse::<i64>()
-                .map(|i| serde_json::Value::Number(i.into()))
-                .or_else(|_| {
-                    n.parse::<f64>().map(|f| {
-                        serde_json::Value::Number(
-                            serde_json::Number::from_f64(f).unwrap_or_else(|| 0.into()),
-                        )
-                    })
-                })
-                .unwrap_or_else(|_| serde_json::Value::String(n.clone())),
-            OperationValue::StringRef(s) => serde_json::Value::String(s.clone()),
-            // ArrayRef and ObjectRef are tape position ranges - represent as empty
-            // In practice, CRDT operations work on scalar values
-            OperationValue::ArrayRef { .. } => serde_json::Value::Array(Vec::new()),
-            OperationValue::ObjectRef { .. } => serde_json::Value::Object(serde_json::Map::new()),
-        }
-    }
-
     /// Reset the processor
     pub fn reset(&mut self) {
         self.dson_processor.reset();
@@ -400,49 +917,32 @@ impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
 
 impl<P: FormatBatchProcessor> CrdtMerge for FormatCrdtProcessor<P> {
     fn merge_operation(&mut self, op: CrdtOperation) -> Result<Option<MergeConflict>> {
+        // Redelivered operations (e.g. relayed back by a third replica) are no-ops
+        if self.has_seen(&op) {
+            return Ok(None);
+        }
+
         // Update Lamport timestamp
         self.lamport_timestamp = self.lamport_timestamp.max(op.timestamp) + 1;
 
-        // Merge vector clocks
+        // Merge vector clocks; receiving is not a local event, so our own entry
+        // only counts operations peers can ask us for
         self.vector_clock.merge(&op.vector_clock);
-        self.vector_clock.increment(&self.replica_id);
 
-        // Apply the operation based on type
-        match &op.operation {
-            DsonOperation::FieldAdd { path, value }
-            | DsonOperation::FieldModify { path, value } => {
-                // Apply to all documents (or specific document if path indicates)
-                let mut conflict = None;
-                let doc_ids: Vec<_> = self.document_states.keys().cloned().collect();
-                for doc_id in doc_ids {
-                    if let Some(c) = self.apply_field_value(&doc_id, path, value, op.timestamp) {
-                        conflict = Some(c);
-                    }
-                }
-                Ok(conflict)
-            }
-            DsonOperation::FieldDelete { path } => {
-                // Remove field from all documents
-                let doc_ids: Vec<_> = self.document_states.keys().cloned().collect();
-                for doc_id in doc_ids {
-                    if let Some(state) = self.document_states.get_mut(&doc_id)
-                        && let Ok(mut json_value) =
-                            serde_json::from_str::<serde_json::Value>(&state.content)
-                    {
-                        Self::delete_json_path(&mut json_value, path);
-                        if let Ok(new_content) = serde_json::to_string(&json_value) {
-                            state.content = new_content;
-                        }
-                    }
+        // Apply field writes to all documents, resolving concurrent ones
+        let mut conflict = None;
+        if let Some((path, value)) = field_target(&op.operation) {
+            let strategy = self.strategy_for(path).clone();
+            for state in self.document_states.values_mut() {
+                if let Some(c) = state.merge_write(&op, path, value, &strategy) {
+                    conflict = Some(c);
                 }
-                Ok(None)
-            }
-            _ => {
-                // Other operations - record in history
-                self.operation_history.push(op);
-                Ok(None)
             }
         }
+
+        // Keep the operation so it can be relayed to other replicas
+        self.operation_history.push(op);
+        Ok(conflict)
     }
 
     fn merge_field(
@@ -523,67 +1023,11 @@ impl<P: FormatBatchProcessor> CrdtMerge for FormatCrdtProcessor<P> {
 }
 
 impl<P: FormatBatchProcessor> FormatCrdtProcessor<P> {
-    /// Delete a value at a JSON path
-    fn delete_json_path(json: &mut serde_json::Value, path: &str) {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = json;
-
-        for (i, part) in parts.iter().enumerate() {
-            if i == parts.len() - 1 {
-                // Final part - remove the value
-                if let serde_json::Value::Object(obj) = current {
-                    obj.remove(*part);
-                }
-            } else {
-                // Navigate down
-                if let serde_json::Value::Object(obj) = current {
-                    match obj.get_mut(*part) {
-                        Some(v) => current = v,
-                        None => return,
-                    }
-                } else {
-                    return;
-                }
-            }
-        }
-    }
-
     /// Get field value from a document
     fn get_field_value(&self, doc_id: &str, path: &str) -> Option<OperationValue> {
         let state = self.document_states.get(doc_id)?;
-        let json_value: serde_json::Value = serde_json::from_str(&state.content).ok()?;
-
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = &json_value;
-
-        for part in &parts {
-            match current {
-                serde_json::Value::Object(obj) => {
-                    current = obj.get(*part)?;
-                }
-                _ => return None,
-            }
-        }
-
-        Some(Self::json_to_operation_value(current))
-    }
-
-    /// Convert JSON to `OperationValue`
-    ///
-    /// Note: Arrays and objects are represented as JSON strings in `StringRef`,
-    /// since ArrayRef/ObjectRef require tape positions which we don't have here.
-    fn json_to_operation_value(json: &serde_json::Value) -> OperationValue {
-        match json {
-            serde_json::Value::Null => OperationValue::Null,
-            serde_json::Value::Bool(b) => OperationValue::BoolRef(*b),
-            serde_json::Value::Number(n) => OperationValue::NumberRef(n.to_string()),
-            serde_json::Value::String(s) => OperationValue::StringRef(s.clone()),
-            // Arrays and objects are serialized to JSON strings
-            // since ArrayRef/ObjectRef use tape positions
-            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-                OperationValue::StringRef(json.to_string())
-            }
-        }
+        let json_value: Value = serde_json::from_str(&state.content).ok()?;
+        lookup_json_path(&json_value, path).map(json_to_operation_value)
     }
 }
 
@@ -595,11 +1039,11 @@ impl<P: FormatBatchProcessor> DeltaCrdt for FormatCrdtProcessor<P> {
     type Delta = FormatDelta;
 
     fn generate_delta(&self, since: &VectorClock) -> Self::Delta {
-        // Collect operations that happened after the given vector clock
+        // Collect operations the holder of `since` has not seen
         let ops: Vec<CrdtOperation> = self
             .operation_history
             .iter()
-            .filter(|op| !op.vector_clock.happened_before(since))
+            .filter(|op| op.vector_clock.get(&op.replica_id) > since.get(&op.replica_id))
             .cloned()
             .collect();
 
@@ -621,8 +1065,11 @@ impl<P: FormatBatchProcessor> DeltaCrdt for FormatCrdtProcessor<P> {
         // Process any buffered operations that are now ready
         conflicts.extend(self.process_buffered()?);
 
-        // Merge the delta clock
-        self.vector_clock.merge(&delta.clock);
+        // Adopt the sender's clock only once every operation was delivered;
+        // claiming buffered operations as seen would make us skip them
+        if self.operation_buffer.is_empty() {
+            self.vector_clock.merge(&delta.clock);
+        }
 
         Ok(conflicts)
     }
@@ -984,8 +1431,8 @@ mod tests {
 
         let conflict = MergeConflict {
             path: "test".to_string(),
-            local_value: OperationValue::NumberRef("10".to_string()),
-            remote_value: OperationValue::NumberRef("20".to_string()),
+            local_value: OperationValue::NumberRef("10".into()),
+            remote_value: OperationValue::NumberRef("20".into()),
             local_timestamp: 1,
             remote_timestamp: 2,
             resolved_value: None,
@@ -1005,74 +1452,48 @@ mod tests {
     #[test]
     fn test_operation_value_to_json_all_types() {
         // Test all OperationValue variants
-        let null_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::Null,
-        );
+        let null_json = operation_value_to_json(&OperationValue::Null);
         assert!(null_json.is_null());
 
-        let bool_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::BoolRef(true),
-        );
+        let bool_json = operation_value_to_json(&OperationValue::BoolRef(true));
         assert_eq!(bool_json, serde_json::Value::Bool(true));
 
-        let int_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::NumberRef("42".to_string()),
-        );
+        let int_json = operation_value_to_json(&OperationValue::NumberRef("42".into()));
         assert_eq!(int_json, serde_json::json!(42));
 
-        let float_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::NumberRef("3.14".to_string()),
-        );
+        let float_json = operation_value_to_json(&OperationValue::NumberRef("3.14".into()));
         assert!(float_json.is_number());
 
-        let string_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::StringRef("hello".to_string()),
-        );
+        let string_json = operation_value_to_json(&OperationValue::StringRef("hello".to_string()));
         assert_eq!(string_json, serde_json::Value::String("hello".to_string()));
 
         // ArrayRef and ObjectRef use tape positions, so they return empty containers
-        let array_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::ArrayRef { start: 0, end: 0 },
-        );
+        let array_json = operation_value_to_json(&OperationValue::ArrayRef { start: 0, end: 0 });
         assert!(array_json.is_array());
 
-        let obj_json = FormatCrdtProcessor::<MockBatchProcessor>::operation_value_to_json(
-            &OperationValue::ObjectRef { start: 0, end: 0 },
-        );
+        let obj_json = operation_value_to_json(&OperationValue::ObjectRef { start: 0, end: 0 });
         assert!(obj_json.is_object());
     }
 
     #[test]
     fn test_json_to_operation_value_all_types() {
-        let null_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::Value::Null,
-        );
+        let null_val = json_to_operation_value(&serde_json::Value::Null);
         assert!(matches!(null_val, OperationValue::Null));
 
-        let bool_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::Value::Bool(false),
-        );
+        let bool_val = json_to_operation_value(&serde_json::Value::Bool(false));
         assert!(matches!(bool_val, OperationValue::BoolRef(false)));
 
-        let num_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::json!(123),
-        );
+        let num_val = json_to_operation_value(&serde_json::json!(123));
         assert!(matches!(num_val, OperationValue::NumberRef(_)));
 
-        let str_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::Value::String("test".to_string()),
-        );
+        let str_val = json_to_operation_value(&serde_json::Value::String("test".to_string()));
         assert!(matches!(str_val, OperationValue::StringRef(_)));
 
         // Arrays and objects are serialized to JSON strings in StringRef
-        let arr_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::json!([1, 2]),
-        );
+        let arr_val = json_to_operation_value(&serde_json::json!([1, 2]));
         assert!(matches!(arr_val, OperationValue::StringRef(_)));
 
-        let obj_val = FormatCrdtProcessor::<MockBatchProcessor>::json_to_operation_value(
-            &serde_json::json!({"a": 1}),
-        );
+        let obj_val = json_to_operation_value(&serde_json::json!({"a": 1}));
         assert!(matches!(obj_val, OperationValue::StringRef(_)));
     }
 
@@ -1101,4 +1522,224 @@ mod tests {
         let conflict = processor.merge_operation(op).unwrap();
         assert!(conflict.is_none());
     }
+
+    // =========================================================================
+    // Causal replication
+    // =========================================================================
+
+    fn replica(id: &str) -> FormatCrdtProcessor<MockBatchProcessor> {
+        let mut processor = FormatCrdtProcessor::new(MockBatchProcessor, id);
+        processor.insert_document("doc_0", "{}".to_string());
+        processor
+    }
+
+    fn set(processor: &mut FormatCrdtProcessor<MockBatchProcessor>, path: &str, value: i64) {
+        processor
+            .apply_local(&DsonOperation::FieldModify {
+                path: path.to_string(),
+                value: OperationValue::NumberRef(value.into()),
+            })
+            .unwrap();
+    }
+
+    fn sync(
+        from: &FormatCrdtProcessor<MockBatchProcessor>,
+        to: &mut FormatCrdtProcessor<MockBatchProcessor>,
+    ) -> Vec<MergeConflict> {
+        to.apply_delta(from.generate_delta(to.vector_clock()))
+            .unwrap()
+    }
+
+    fn document(processor: &FormatCrdtProcessor<MockBatchProcessor>) -> Value {
+        serde_json::from_str(processor.get_document("doc_0").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_apply_local_stamps_and_records() {
+        let mut processor = replica("a");
+        set(&mut processor, "x", 1);
+        set(&mut processor, "x", 2);
+
+        assert_eq!(processor.vector_clock().get("a"), 2);
+        assert_eq!(document(&processor), serde_json::json!({"x": 2}));
+        assert_eq!(processor.generate_delta(&VectorClock::new()).len(), 2);
+    }
+
+    #[test]
+    fn test_causal_overwrite_is_not_a_conflict() {
+        let mut a = replica("a");
+        let mut b = replica("b");
+        set(&mut a, "x", 1);
+        assert!(sync(&a, &mut b).is_empty());
+
+        set(&mut b, "x", 2);
+        assert!(sync(&b, &mut a).is_empty());
+        assert_eq!(document(&a), serde_json::json!({"x": 2}));
+    }
+
+    #[test]
+    fn test_concurrent_writes_converge_with_replica_tiebreak() {
+        let mut a = replica("a");
+        let mut b = replica("b");
+        set(&mut a, "x", 1);
+        set(&mut b, "x", 2);
+
+        let on_a = sync(&b, &mut a);
+        let on_b = sync(&a, &mut b);
+
+        assert_eq!(on_a.len(), 1);
+        assert!(on_b.is_empty() || on_b[0].resolved_value == on_a[0].resolved_value);
+        // Equal Lamport timestamps: the larger replica id wins on both sides
+        assert_eq!(document(&a), serde_json::json!({"x": 2}));
+        assert_eq!(document(&b), document(&a));
+    }
+
+    #[test]
+    fn test_path_strategy_resolves_concurrent_writes() {
+        let mut a = replica("b");
+        let mut b = replica("a");
+        a.set_path_strategy("stats", MergeStrategy::Max);
+        b.set_path_strategy("stats.*", MergeStrategy::Max);
+        set(&mut a, "stats.points", 10);
+        set(&mut b, "stats.points", 3);
+
+        sync(&b, &mut a);
+        sync(&a, &mut b);
+
+        assert!(matches!(a.strategy_for("stats.points"), MergeStrategy::Max));
+        assert!(matches!(
+            a.strategy_for("other"),
+            MergeStrategy::LastWriteWins
+        ));
+        assert_eq!(document(&a), serde_json::json!({"stats": {"points": 10}}));
+        assert_eq!(document(&b), document(&a));
+    }
+
+    #[test]
+    fn test_out_of_order_delta_is_buffered() {
+        let mut a = replica("a");
+        let mut b = replica("b");
+        set(&mut a, "x", 1);
+        let first = a.generate_delta(&VectorClock::new());
+        set(&mut a, "x", 2);
+        let second = a.generate_delta(&first.operations[0].vector_clock);
+
+        b.apply_delta(second).unwrap();
+        assert_eq!(b.vector_clock().get("a"), 0);
+        assert_eq!(document(&b), serde_json::json!({}));
+
+        b.apply_delta(first).unwrap();
+        assert_eq!(b.vector_clock().get("a"), 2);
+        assert_eq!(document(&b), serde_json::json!({"x": 2}));
+    }
+
+    #[test]
+    fn test_relayed_operations_apply_once() {
+        let mut a = replica("a");
+        let mut b = replica("b");
+        let mut c = replica("c");
+        a.set_path_strategy("n", MergeStrategy::Additive);
+        c.set_path_strategy("n", MergeStrategy::Additive);
+        set(&mut a, "n", 1);
+        sync(&a, &mut b);
+        set(&mut c, "n", 5);
+
+        sync(&a, &mut c);
+        sync(&b, &mut c);
+        assert_eq!(document(&c), serde_json::json!({"n": 6.0}));
+    }
+
+    #[test]
+    fn test_nested_and_array_paths() {
+        let mut processor = replica("a");
+        for (path, value) in [
+            ("items", OperationValue::ArrayRef { start: 0, end: 0 }),
+            ("items.0", OperationValue::NumberRef(1_i64.into())),
+            ("items.1", OperationValue::ObjectRef { start: 0, end: 0 }),
+            ("items.1.name", OperationValue::StringRef("x".to_string())),
+        ] {
+            processor
+                .apply_local(&DsonOperation::FieldAdd {
+                    path: path.to_string(),
+                    value,
+                })
+                .unwrap();
+        }
+        assert_eq!(
+            document(&processor),
+            serde_json::json!({"items": [1, {"name": "x"}]})
+        );
+
+        processor
+            .apply_local(&DsonOperation::FieldDelete {
+                path: "items.0".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            document(&processor),
+            serde_json::json!({"items": [null, {"name": "x"}]})
+        );
+
+        let past_end = processor.apply_local(&DsonOperation::FieldAdd {
+            path: "items.5".to_string(),
+            value: OperationValue::Null,
+        });
+        assert!(past_end.is_err());
+        let through_scalar = processor.apply_local(&DsonOperation::FieldAdd {
+            path: "items.1.name.first".to_string(),
+            value: OperationValue::Null,
+        });
+        assert!(through_scalar.is_err());
+        assert_eq!(processor.vector_clock().get("a"), 5);
+    }
+
+    #[test]
+    fn test_concurrent_parent_delete_drops_nested_write() {
+        let mut a = replica("a");
+        let mut b = replica("b");
+        a.apply_local(&DsonOperation::FieldAdd {
+            path: "obj".to_string(),
+            value: OperationValue::ObjectRef { start: 0, end: 0 },
+        })
+        .unwrap();
+        sync(&a, &mut b);
+
+        set(&mut b, "obj.x", 1);
+        a.apply_local(&DsonOperation::FieldDelete {
+            path: "obj".to_string(),
+        })
+        .unwrap();
+
+        sync(&b, &mut a);
+        sync(&a, &mut b);
+        assert_eq!(document(&a), serde_json::json!({}));
+        assert_eq!(document(&b), document(&a));
+    }
+
+    #[test]
+    fn test_delta_bytes_roundtrip() {
+        let mut a = replica("a");
+        set(&mut a, "x", 1);
+        a.apply_local(&DsonOperation::FieldAdd {
+            path: "obj".to_string(),
+            value: OperationValue::ObjectRef { start: 0, end: 0 },
+        })
+        .unwrap();
+        a.apply_local(&DsonOperation::FieldDelete {
+            path: "x".to_string(),
+        })
+        .unwrap();
+
+        let bytes = a.generate_delta(&VectorClock::new()).to_bytes().unwrap();
+        let delta = FormatDelta::from_bytes(&bytes).unwrap();
+        assert_eq!(delta.len(), 3);
+        assert_eq!(delta.clock.get("a"), 3);
+
+        let mut b = replica("b");
+        b.apply_delta(delta).unwrap();
+        assert_eq!(document(&b), serde_json::json!({"obj": {}}));
+
+        assert!(FormatDelta::from_bytes(b"{}").is_err());
+        assert!(FormatDelta::from_bytes(b"not json").is_err());
+    }
 }
@@ -20,9 +20,11 @@
 //! available. New code should prefer `TapeBatchResult` for full fidelity.
 
 use crate::skiptape::CompiledSchema;
+use crate::skiptape::pool::TapeBuilderPool;
 use fionn_core::Result;
 use fionn_core::format::FormatKind;
 use fionn_ops::DsonOperation;
+use std::sync::Arc;
 
 use crate::skiptape::unified_tape::{TapeSegment, UnifiedTape};
 
@@ -261,6 +263,24 @@ pub trait TapeBatchProcessor<'arena> {
         arena: &'arena bumpalo::Bump,
     ) -> Result<TapeBatchResult<'arena>>;
 
+    /// Process a batch to unified tape, drawing tape storage from `pool`
+    ///
+    /// Hand the result back with [`TapeBuilderPool::recycle`] once consumed.
+    /// Pass an empty schema to keep every field. Processors without pooled
+    /// support fall back to [`TapeBatchProcessor::process_to_tape`].
+    ///
+    /// # Errors
+    /// Returns an error if batch processing fundamentally fails
+    fn process_to_tape_pooled(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+        _pool: &TapeBuilderPool,
+    ) -> Result<TapeBatchResult<'arena>> {
+        self.process_to_tape(data, schema, arena)
+    }
+
     /// Reset processor state for a new batch
     fn reset(&mut self);
 }
@@ -276,13 +296,31 @@ pub trait TapeBatchProcessor<'arena> {
 pub struct FormatDsonProcessor<P: FormatBatchProcessor> {
     /// The underlying format-specific batch processor
     batch_processor: P,
+    /// Arenas and tape storage for tape processing (created on first use)
+    pool: Option<Arc<TapeBuilderPool>>,
 }
 
 impl<P: FormatBatchProcessor> FormatDsonProcessor<P> {
     /// Create a new format DSON processor
     #[must_use]
     pub const fn new(batch_processor: P) -> Self {
-        Self { batch_processor }
+        Self {
+            batch_processor,
+            pool: None,
+        }
+    }
+
+    /// Draw arenas and tape storage from a shared pool
+    #[must_use]
+    pub fn with_pool(mut self, pool: Arc<TapeBuilderPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Get the tape builder pool, if one has been set or created
+    #[must_use]
+    pub const fn pool(&self) -> Option<&Arc<TapeBuilderPool>> {
+        self.pool.as_ref()
     }
 
     /// Get the format kind
@@ -416,6 +454,43 @@ impl<P: FormatBatchProcessor> FormatDsonProcessor<P> {
     }
 }
 
+impl<P> FormatDsonProcessor<P>
+where
+    P: FormatBatchProcessor + for<'arena> TapeBatchProcessor<'arena>,
+{
+    /// Process a batch to unified tape and pass the result to `f`
+    ///
+    /// The arena, tape and segment storage come from the processor's pool
+    /// and go back to it once `f` returns, so repeated batches of similar
+    /// size reuse the previous batch's allocations.
+    ///
+    /// # Errors
+    /// Returns an error if batch processing fails
+    pub fn process_tape_with<R>(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        f: impl FnOnce(&TapeBatchResult<'_>) -> R,
+    ) -> Result<R> {
+        let pool = Arc::clone(self.pool.get_or_insert_with(Default::default));
+        let arena = pool.acquire_arena();
+        let output = TapeBatchProcessor::process_to_tape_pooled(
+            &mut self.batch_processor,
+            data,
+            schema,
+            &arena,
+            &pool,
+        )
+        .map(|result| {
+            let output = f(&result);
+            pool.recycle(result);
+            output
+        });
+        pool.release_arena(arena);
+        output
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -542,6 +617,28 @@ mod tests {
         // Reset should not panic
     }
 
+    #[test]
+    fn test_process_tape_with_reuses_pool() {
+        use crate::skiptape::SimdJsonlBatchProcessor;
+
+        let pool = Arc::new(TapeBuilderPool::default());
+        let mut processor =
+            FormatDsonProcessor::new(SimdJsonlBatchProcessor::new()).with_pool(Arc::clone(&pool));
+        let schema = CompiledSchema::compile(&[]).unwrap();
+
+        for _ in 0..3 {
+            let segments = processor
+                .process_tape_with(b"{\"a\": 1}\n{\"b\": [true]}\n", &schema, |result| {
+                    result.segment_count()
+                })
+                .unwrap();
+            assert_eq!(segments, 2);
+        }
+
+        assert_eq!(pool.arena_stats().reuses, 2);
+        assert_eq!(pool.unified_stats().reuses, 2);
+    }
+
     #[test]
     fn test_batch_processor_mut_access() {
         let mut processor = FormatDsonProcessor::new(MockBatchProcessor::new(FormatKind::Json));
@@ -283,7 +283,7 @@ mod tests {
         let data = b"table.users|id:int|name:string|1|Alice";
         let operations = vec![DsonOperation::FieldAdd {
             path: "added".to_string(),
-            value: OperationValue::NumberRef("42".to_string()),
+            value: OperationValue::NumberRef("42".into()),
         }];
 
         let result = process_isonl_with_ops(data, &operations).unwrap();
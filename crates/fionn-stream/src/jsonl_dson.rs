@@ -171,6 +171,8 @@ mod tests {
                 processing_time_ms: 0.1,
                 avg_memory_per_line: 10,
                 overall_schema_match_ratio: 1.0,
+                prefilter_hits: 0,
+                prefilter_misses: 0,
             },
         };
         let debug = format!("{batch:?}");
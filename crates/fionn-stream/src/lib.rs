@@ -7,6 +7,8 @@
 //! - [`jsonl_dson`] - JSONL-DSON integration
 //! - [`format_dson`] - Format-agnostic DSON processor
 //! - [`format_crdt`] - Format-aware CRDT processor
+//! - [`arrow_bridge`] - Apache Arrow / Parquet bridge (requires `arrow` feature)
+//! - [`compression`] - Transparent gzip / zstd / bzip2 input and output
 
 #![deny(missing_docs)]
 #![deny(rust_2018_idioms)]
@@ -31,6 +33,13 @@ pub mod format_dson;
 /// Format-aware CRDT processor
 pub mod format_crdt;
 
+/// Apache Arrow / Parquet bridge
+#[cfg(feature = "arrow")]
+pub mod arrow_bridge;
+
+/// Transparent compressed input/output
+pub mod compression;
+
 // Format-specific DSON processors (feature-gated by format)
 
 /// ISONL-DSON Integration
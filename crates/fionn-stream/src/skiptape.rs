@@ -7,6 +7,7 @@
 
 pub mod error;
 pub mod jsonl;
+pub mod pool;
 pub mod processor;
 pub mod schema;
 pub mod simd_ops;
@@ -37,10 +38,11 @@ pub mod toon_batch;
 
 /// Re-export main types for convenience
 pub use error::SkipTapeError;
-pub use jsonl::{PreScanMode, SimdJsonlProcessor};
+pub use jsonl::{PreScanMode, SimdJsonlBatchProcessor, SimdJsonlProcessor};
+pub use pool::TapeBuilderPool;
 pub use processor::SkipTapeProcessor;
 pub use schema::CompiledSchema;
-pub use tape::SkipTape;
+pub use tape::{SkipTape, SkipTapeStorage};
 
 /// Re-export ISONL processor
 #[cfg(feature = "ison")]
@@ -66,4 +68,5 @@ pub use toon_batch::SimdToonBatchProcessor;
 pub use unified_tape::{
     ExtendedNodeType, IsonFieldType, IsonRefKind, NewlineStyle, NodeFlags, OriginalSyntax,
     TapeSegment, UnifiedNode, UnifiedStringArena, UnifiedTape, UnifiedTapeMetadata,
+    UnifiedTapeStorage,
 };
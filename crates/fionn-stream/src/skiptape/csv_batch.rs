@@ -3,11 +3,18 @@
 //!
 //! High-performance CSV processing with schema-aware filtering.
 //! Converts CSV rows to JSON documents for DSON operations.
+//!
+//! Records are split according to a [`CsvDialect`], so quoted fields may span
+//! lines. Cell values are typed per cell by default; with
+//! [`SimdCsvBatchProcessor::with_type_inference`] a column type is inferred
+//! from a sample of rows and applied to the whole column.
 
 use crate::skiptape::error::Result;
+use crate::skiptape::pool::TapeBuilderPool;
 use crate::skiptape::schema::CompiledSchema;
-use crate::skiptape::simd_ops::SimdLineSeparator;
-use fionn_simd::formats::CsvParser;
+use fionn_simd::formats::{
+    CsvCell, CsvColumnType, CsvDialect, CsvFieldSpan, CsvParser, infer_column_types,
+};
 use std::time::Instant;
 
 use crate::format_dson::{BatchStatistics, FormatBatchProcessor, FormatBatchResult, LineError};
@@ -71,12 +78,38 @@ pub struct CsvBatchStatistics {
 /// output suitable for DSON operations.
 #[derive(Debug)]
 pub struct SimdCsvBatchProcessor {
-    /// The underlying CSV parser (reserved for future SIMD-optimized parsing)
-    _parser: CsvParser,
-    /// Detected delimiter
-    delimiter: u8,
-    /// Whether first row is a header
-    has_header: bool,
+    /// Dialect used to split records and fields
+    dialect: CsvDialect,
+    /// Whether to detect the delimiter from each batch
+    detect_delimiter: bool,
+    /// Number of rows sampled for per-column type inference
+    type_sample_rows: Option<usize>,
+    /// Scratch buffers reused by tape processing
+    scratch: CsvScratch,
+}
+
+/// Buffers reused across batches so tape processing does not allocate
+#[derive(Debug, Default)]
+struct CsvScratch {
+    /// Record end offsets
+    record_ends: Vec<usize>,
+    /// Unescaped field bytes of the current record
+    text: Vec<u8>,
+    /// Field spans into `text`
+    fields: Vec<CsvFieldSpan>,
+    /// Whether each header column matches the schema
+    header_matches: Vec<bool>,
+}
+
+impl CsvScratch {
+    const fn new() -> Self {
+        Self {
+            record_ends: Vec::new(),
+            text: Vec::new(),
+            fields: Vec::new(),
+            header_matches: Vec::new(),
+        }
+    }
 }
 
 impl Default for SimdCsvBatchProcessor {
@@ -87,34 +120,62 @@ impl Default for SimdCsvBatchProcessor {
 
 impl SimdCsvBatchProcessor {
     /// Create a new CSV batch processor
+    ///
+    /// The delimiter is detected from each batch.
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)] // CsvParser::new() is not const
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
-            _parser: CsvParser::new(),
-            delimiter: b',',
-            has_header: true,
+            dialect: CsvDialect::new(),
+            detect_delimiter: true,
+            type_sample_rows: None,
+            scratch: CsvScratch::new(),
         }
     }
 
     /// Create a CSV batch processor with custom delimiter
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)] // CsvParser methods are not const
-    pub fn with_delimiter(delimiter: u8) -> Self {
+    pub const fn with_delimiter(delimiter: u8) -> Self {
+        Self {
+            dialect: CsvDialect::new().with_delimiter(delimiter),
+            detect_delimiter: false,
+            type_sample_rows: None,
+            scratch: CsvScratch::new(),
+        }
+    }
+
+    /// Create a CSV batch processor for an explicit dialect
+    #[must_use]
+    pub const fn with_dialect(dialect: CsvDialect) -> Self {
         Self {
-            _parser: CsvParser::new().with_delimiter(delimiter),
-            delimiter,
-            has_header: true,
+            dialect,
+            detect_delimiter: false,
+            type_sample_rows: None,
+            scratch: CsvScratch::new(),
         }
     }
 
     /// Set whether the first row is a header
     #[must_use]
     pub const fn with_header(mut self, has_header: bool) -> Self {
-        self.has_header = has_header;
+        self.dialect.has_header = has_header;
+        self
+    }
+
+    /// Infer one type per column from the first `sample_rows` data rows
+    ///
+    /// Cells that do not fit their column's type are emitted as strings.
+    #[must_use]
+    pub const fn with_type_inference(mut self, sample_rows: usize) -> Self {
+        self.type_sample_rows = Some(sample_rows);
         self
     }
 
+    /// Get the dialect in use
+    #[must_use]
+    pub const fn dialect(&self) -> &CsvDialect {
+        &self.dialect
+    }
+
     /// Process a CSV batch with schema filtering
     ///
     /// # Errors
@@ -126,48 +187,18 @@ impl SimdCsvBatchProcessor {
     ) -> Result<CsvBatchResult> {
         let start = Instant::now();
 
-        // Detect delimiter if not set
-        let lines: Vec<&[u8]> = csv_data.split(|&b| b == b'\n').take(5).collect();
-        self.delimiter = CsvParser::detect_delimiter(&lines);
-
-        // Use SIMD line boundary detection
-        let line_separator = SimdLineSeparator::new();
-        let line_boundaries = line_separator.find_line_boundaries(csv_data);
+        let records = self.split_records(csv_data);
+        let (headers, rows, types) = self.parse_rows(&records);
 
-        let mut documents = Vec::with_capacity(line_boundaries.len());
+        let mut documents = Vec::with_capacity(rows.len());
         let mut errors = Vec::new();
         let mut successful_rows = 0;
         let mut failed_rows = 0;
         let mut schema_filtered = 0;
-        let mut headers: Vec<String> = Vec::new();
 
         // Process rows
-        let mut line_start = 0;
-        for (row_index, &line_end) in line_boundaries.iter().enumerate() {
-            let row = &csv_data[line_start..line_end];
-
-            // Skip empty rows
-            if row.iter().all(|&b| b.is_ascii_whitespace()) {
-                line_start = line_end;
-                continue;
-            }
-
-            // Trim trailing newline/carriage return
-            let row = row
-                .strip_suffix(b"\n")
-                .unwrap_or(row)
-                .strip_suffix(b"\r")
-                .unwrap_or(row);
-
-            // Parse header row
-            if row_index == 0 && self.has_header {
-                headers = self.parse_csv_row(row);
-                line_start = line_end;
-                continue;
-            }
-
-            // Parse data row
-            let values = self.parse_csv_row(row);
+        for (row_index, row, values) in &rows {
+            let row_index = *row_index;
 
             if values.len() != headers.len() && !headers.is_empty() {
                 failed_rows += 1;
@@ -180,7 +211,6 @@ impl SimdCsvBatchProcessor {
                     ),
                     raw_row: String::from_utf8_lossy(row).to_string(),
                 });
-                line_start = line_end;
                 continue;
             }
 
@@ -188,16 +218,13 @@ impl SimdCsvBatchProcessor {
             let matches = self.matches_schema(&headers, schema);
             if !matches {
                 schema_filtered += 1;
-                line_start = line_end;
                 continue;
             }
 
             // Convert to JSON
-            let json = self.row_to_json(&headers, &values);
+            let json = self.row_to_json(&headers, values, &types);
             documents.push(json);
             successful_rows += 1;
-
-            line_start = line_end;
         }
 
         let elapsed = start.elapsed();
@@ -223,40 +250,16 @@ impl SimdCsvBatchProcessor {
     pub fn process_batch_unfiltered(&mut self, csv_data: &[u8]) -> Result<CsvBatchResult> {
         let start = Instant::now();
 
-        let lines: Vec<&[u8]> = csv_data.split(|&b| b == b'\n').take(5).collect();
-        self.delimiter = CsvParser::detect_delimiter(&lines);
-
-        let line_separator = SimdLineSeparator::new();
-        let line_boundaries = line_separator.find_line_boundaries(csv_data);
+        let records = self.split_records(csv_data);
+        let (headers, rows, types) = self.parse_rows(&records);
 
-        let mut documents = Vec::with_capacity(line_boundaries.len());
+        let mut documents = Vec::with_capacity(rows.len());
         let mut errors = Vec::new();
         let mut successful_rows = 0;
         let mut failed_rows = 0;
-        let mut headers: Vec<String> = Vec::new();
 
-        let mut line_start = 0;
-        for (row_index, &line_end) in line_boundaries.iter().enumerate() {
-            let row = &csv_data[line_start..line_end];
-
-            if row.iter().all(|&b| b.is_ascii_whitespace()) {
-                line_start = line_end;
-                continue;
-            }
-
-            let row = row
-                .strip_suffix(b"\n")
-                .unwrap_or(row)
-                .strip_suffix(b"\r")
-                .unwrap_or(row);
-
-            if row_index == 0 && self.has_header {
-                headers = self.parse_csv_row(row);
-                line_start = line_end;
-                continue;
-            }
-
-            let values = self.parse_csv_row(row);
+        for (row_index, row, values) in &rows {
+            let row_index = *row_index;
 
             if values.len() != headers.len() && !headers.is_empty() {
                 failed_rows += 1;
@@ -269,15 +272,12 @@ impl SimdCsvBatchProcessor {
                     ),
                     raw_row: String::from_utf8_lossy(row).to_string(),
                 });
-                line_start = line_end;
                 continue;
             }
 
-            let json = self.row_to_json(&headers, &values);
+            let json = self.row_to_json(&headers, values, &types);
             documents.push(json);
             successful_rows += 1;
-
-            line_start = line_end;
         }
 
         let elapsed = start.elapsed();
@@ -296,20 +296,58 @@ impl SimdCsvBatchProcessor {
         })
     }
 
-    /// Parse a CSV row into field values
-    fn parse_csv_row(&self, row: &[u8]) -> Vec<String> {
-        CsvParser::split_fields(row, self.delimiter)
-            .iter()
-            .map(|field| {
-                let s = String::from_utf8_lossy(field);
-                // Unquote if quoted
-                if s.starts_with('"') && s.ends_with('"') && s.len() >= 2 {
-                    s[1..s.len() - 1].replace("\"\"", "\"")
-                } else {
-                    s.to_string()
-                }
-            })
-            .collect()
+    /// Split a batch into records, detecting the delimiter if not configured
+    fn split_records<'a>(&mut self, data: &'a [u8]) -> Vec<&'a [u8]> {
+        self.detect_batch_delimiter(data);
+        self.dialect.split_records(data)
+    }
+
+    /// Detect the delimiter from the first lines of a batch if not configured
+    fn detect_batch_delimiter(&mut self, data: &[u8]) {
+        if self.detect_delimiter {
+            let mut lines: [&[u8]; 5] = [&[]; 5];
+            let mut count = 0;
+            for (slot, line) in lines.iter_mut().zip(data.split(|&b| b == b'\n')) {
+                *slot = line;
+                count += 1;
+            }
+            self.dialect.delimiter = CsvParser::detect_delimiter(&lines[..count]);
+        }
+    }
+
+    /// Parse records into headers, data rows and inferred column types
+    ///
+    /// Column types are empty unless type inference is enabled.
+    #[allow(clippy::type_complexity)] // (headers, (index, raw, cells), types)
+    fn parse_rows<'a>(
+        &self,
+        records: &[&'a [u8]],
+    ) -> (
+        Vec<String>,
+        Vec<(usize, &'a [u8], Vec<CsvCell>)>,
+        Vec<CsvColumnType>,
+    ) {
+        let mut headers = Vec::new();
+        let mut rows = Vec::with_capacity(records.len());
+        for (row_index, &record) in records.iter().enumerate() {
+            let cells = self.dialect.parse_record(record);
+            if row_index == 0 && self.dialect.has_header {
+                headers = cells.into_iter().map(|cell| cell.value).collect();
+            } else {
+                rows.push((row_index, record, cells));
+            }
+        }
+
+        let types = self.type_sample_rows.map_or_else(Vec::new, |sample| {
+            let sampled: Vec<Vec<CsvCell>> = rows
+                .iter()
+                .take(sample)
+                .map(|(_, _, cells)| cells.clone())
+                .collect();
+            infer_column_types(&sampled, &self.dialect, sample)
+        });
+
+        (headers, rows, types)
     }
 
     /// Check if headers match the schema
@@ -329,11 +367,18 @@ impl SimdCsvBatchProcessor {
     }
 
     /// Convert a CSV row to JSON
-    #[allow(clippy::unused_self)] // Method signature for API consistency
-    fn row_to_json(&self, headers: &[String], values: &[String]) -> String {
+    ///
+    /// Uses the inferred column types when present, otherwise types each cell
+    /// on its own.
+    fn row_to_json(
+        &self,
+        headers: &[String],
+        cells: &[CsvCell],
+        types: &[CsvColumnType],
+    ) -> String {
         let mut json = String::from("{");
 
-        for (i, (header, value)) in headers.iter().zip(values.iter()).enumerate() {
+        for (i, (header, cell)) in headers.iter().zip(cells.iter()).enumerate() {
             if i > 0 {
                 json.push(',');
             }
@@ -341,9 +386,15 @@ impl SimdCsvBatchProcessor {
             json.push_str(&Self::escape_json_string(header));
             json.push_str("\":");
 
-            // Try to detect type - booleans, integers, and floats are written verbatim
-            if value.is_empty() {
+            let value = cell.value.as_str();
+            if let Some(ty) = types.get(i) {
+                json.push_str(&ty.to_value(cell, &self.dialect).to_string());
+            } else if self.dialect.is_null(cell) {
                 json.push_str("null");
+            } else if cell.quoted {
+                json.push('"');
+                json.push_str(&Self::escape_json_string(value));
+                json.push('"');
             } else if value == "true"
                 || value == "false"
                 || value.parse::<i64>().is_ok()
@@ -372,13 +423,15 @@ impl SimdCsvBatchProcessor {
 
     /// Reset processor state
     pub const fn reset(&mut self) {
-        self.delimiter = b',';
+        if self.detect_delimiter {
+            self.dialect.delimiter = b',';
+        }
     }
 
-    /// Get the detected delimiter
+    /// Get the delimiter in use (detected or configured)
     #[must_use]
     pub const fn delimiter(&self) -> u8 {
-        self.delimiter
+        self.dialect.delimiter
     }
 }
 
@@ -458,107 +511,86 @@ impl FormatBatchProcessor for SimdCsvBatchProcessor {
     }
 }
 
-// =============================================================================
-// TapeBatchProcessor Implementation
-// =============================================================================
-
-impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
-    fn format_kind(&self) -> fionn_core::format::FormatKind {
-        fionn_core::format::FormatKind::Csv
-    }
-
+impl SimdCsvBatchProcessor {
+    /// Build unified tape for a batch into the given tape and segment storage
+    ///
+    /// Records and fields are parsed into the processor's scratch buffers,
+    /// so with pooled tape storage a warmed-up batch does not allocate.
     #[allow(clippy::too_many_lines)] // Complex tape construction with schema filtering
-    fn process_to_tape(
+    fn schema_filtered_tape<'arena>(
         &mut self,
         data: &[u8],
         schema: &CompiledSchema,
-        arena: &'arena bumpalo::Bump,
-    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        mut tape: UnifiedTape<'arena>,
+        mut segments: Vec<SegmentBoundary>,
+    ) -> TapeBatchResult<'arena> {
         let start = Instant::now();
 
-        // Detect delimiter
-        let lines: Vec<&[u8]> = data.split(|&b| b == b'\n').take(5).collect();
-        self.delimiter = CsvParser::detect_delimiter(&lines);
-
-        // Create unified tape
-        let mut tape = UnifiedTape::new(arena, fionn_core::format::FormatKind::Csv);
+        self.detect_batch_delimiter(data);
         tape.metadata.original_size = data.len();
 
         // Detect newline style
         let newline_style = Self::detect_newline_style(data);
         let _delimiter_idx = tape.add_original_syntax(OriginalSyntax::CsvDelimiter {
-            delimiter: self.delimiter as char,
+            delimiter: self.dialect.delimiter as char,
         });
         let _newline_idx = tape.add_original_syntax(OriginalSyntax::CsvNewlineStyle {
             style: newline_style,
         });
 
-        // Use SIMD line boundary detection
-        let line_separator = SimdLineSeparator::new();
-        let line_boundaries = line_separator.find_line_boundaries(data);
+        let Self {
+            dialect, scratch, ..
+        } = self;
+        let CsvScratch {
+            record_ends,
+            text,
+            fields,
+            header_matches,
+        } = scratch;
+        header_matches.clear();
 
-        let mut segments: Vec<SegmentBoundary> = Vec::with_capacity(line_boundaries.len());
         let mut errors = Vec::new();
         let mut successful_rows = 0;
         let mut failed_rows = 0;
         let mut schema_filtered = 0;
-        let mut headers: Vec<String> = Vec::new();
+        let mut header_count = 0;
+        let mut row_matches = schema.include_patterns.is_empty();
 
-        // Process rows
-        let mut line_start = 0;
-        for (row_index, &line_end) in line_boundaries.iter().enumerate() {
-            let row = &data[line_start..line_end];
+        dialect.for_each_record(data, record_ends, |row_index, record| {
+            dialect.parse_record_into(record, text, fields);
 
-            // Skip empty rows
-            if row.iter().all(|&b| b.is_ascii_whitespace()) {
-                line_start = line_end;
-                continue;
-            }
-
-            // Trim trailing newline/carriage return
-            let row = row
-                .strip_suffix(b"\n")
-                .unwrap_or(row)
-                .strip_suffix(b"\r")
-                .unwrap_or(row);
-
-            // Parse header row
-            if row_index == 0 && self.has_header {
-                headers = self.parse_csv_row(row);
-                // Add header row marker
+            if row_index == 0 && dialect.has_header {
+                // Add header row marker and store header strings in arena
                 tape.add_node(UnifiedNode::new(
                     ExtendedNodeType::CsvHeaderRow,
                     fionn_core::format::FormatKind::Csv,
                 ));
-                // Store header strings in arena
-                for header in &headers {
-                    tape.add_string(header);
+                for field in fields.iter() {
+                    let header = dialect.cell_text(field.bytes(text), field.quoted);
+                    tape.add_string(&header);
+                    header_matches.push(schema.matches_path(&header));
                 }
-                line_start = line_end;
-                continue;
+                header_count = fields.len();
+                row_matches |= header_matches.contains(&true);
+                return;
             }
 
-            // Parse data row
-            let values = self.parse_csv_row(row);
-
-            if values.len() != headers.len() && !headers.is_empty() {
+            if fields.len() != header_count && header_count > 0 {
                 failed_rows += 1;
-                errors.push(CsvLineError {
-                    row_index,
+                errors.push(LineError {
+                    line_index: row_index,
                     error_message: format!(
                         "Column count mismatch: expected {}, got {}",
-                        headers.len(),
-                        values.len()
+                        header_count,
+                        fields.len()
                     ),
-                    raw_row: String::from_utf8_lossy(row).to_string(),
+                    raw_line: String::from_utf8_lossy(record).to_string(),
                 });
-                line_start = line_end;
-                continue;
+                return;
             }
 
             // Check schema match
-            let matches = self.matches_schema(&headers, schema);
-            if !matches {
+            if !row_matches {
                 schema_filtered += 1;
                 // Add skip marker for unmatched rows
                 tape.add_node(UnifiedNode::new(
@@ -566,8 +598,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
                     fionn_core::format::FormatKind::Csv,
                 ));
                 tape.metadata.skipped_count += 1;
-                line_start = line_end;
-                continue;
+                return;
             }
 
             // Record segment start for tracking
@@ -582,13 +613,12 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
             ));
 
             // Add fields using CSV-specific markers
-            for (col_idx, value) in values.iter().enumerate() {
-                // Determine if quoted
-                let has_quotes = value.starts_with('"') && value.ends_with('"');
-                let value_idx = tape.add_string(value);
+            for (col_idx, field) in fields.iter().enumerate() {
+                let value = dialect.cell_text(field.bytes(text), field.quoted);
+                let value_idx = tape.add_string(&value);
 
                 // Check if this column matches the schema
-                let col_matches = headers.get(col_idx).is_some_and(|h| schema.matches_path(h))
+                let col_matches = header_matches.get(col_idx).copied().unwrap_or(false)
                     || schema.include_patterns.is_empty();
 
                 if col_matches {
@@ -603,7 +633,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
                     );
 
                     // Preserve quote information
-                    if has_quotes {
+                    if field.quoted {
                         let syntax_idx = tape.add_original_syntax(OriginalSyntax::CsvQuotedValue {
                             has_quotes: true,
                         });
@@ -635,8 +665,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
             });
 
             successful_rows += 1;
-            line_start = line_end;
-        }
+        });
 
         let elapsed = start.elapsed();
 
@@ -654,30 +683,50 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
         let original_size = tape.metadata.original_size;
         let schema_match_ratio = tape.metadata.schema_match_ratio;
 
-        Ok(TapeBatchResult {
+        TapeBatchResult {
             tape,
             segments,
-            errors: errors
-                .into_iter()
-                .map(|e| LineError {
-                    line_index: e.row_index,
-                    error_message: e.error_message,
-                    raw_line: e.raw_row,
-                })
-                .collect(),
+            errors,
             statistics: BatchStatistics {
                 total_lines: total_rows,
                 successful_lines: successful_rows,
                 failed_lines: failed_rows,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful_rows > 0 {
-                    original_size / successful_rows
-                } else {
-                    0
-                },
+                avg_memory_per_line: original_size.checked_div(successful_rows).unwrap_or(0),
                 overall_schema_match_ratio: schema_match_ratio,
             },
-        })
+        }
+    }
+}
+
+// =============================================================================
+// TapeBatchProcessor Implementation
+// =============================================================================
+
+impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
+    fn format_kind(&self) -> fionn_core::format::FormatKind {
+        fionn_core::format::FormatKind::Csv
+    }
+
+    fn process_to_tape(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = UnifiedTape::new(arena, fionn_core::format::FormatKind::Csv);
+        Ok(self.schema_filtered_tape(data, schema, tape, Vec::new()))
+    }
+
+    fn process_to_tape_pooled(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+        pool: &TapeBuilderPool,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = pool.unified_tape(arena, fionn_core::format::FormatKind::Csv);
+        Ok(self.schema_filtered_tape(data, schema, tape, pool.segments()))
     }
 
     #[allow(clippy::too_many_lines)] // Complex tape construction without filtering
@@ -688,9 +737,8 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
     ) -> fionn_core::Result<TapeBatchResult<'arena>> {
         let start = Instant::now();
 
-        // Detect delimiter
-        let lines: Vec<&[u8]> = data.split(|&b| b == b'\n').take(5).collect();
-        self.delimiter = CsvParser::detect_delimiter(&lines);
+        let records = self.split_records(data);
+        let (headers, rows, _types) = self.parse_rows(&records);
 
         // Create unified tape
         let mut tape = UnifiedTape::new(arena, fionn_core::format::FormatKind::Csv);
@@ -699,58 +747,32 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
         // Detect newline style
         let newline_style = Self::detect_newline_style(data);
         let _delimiter_idx = tape.add_original_syntax(OriginalSyntax::CsvDelimiter {
-            delimiter: self.delimiter as char,
+            delimiter: self.dialect.delimiter as char,
         });
         let _newline_idx = tape.add_original_syntax(OriginalSyntax::CsvNewlineStyle {
             style: newline_style,
         });
 
-        // Use SIMD line boundary detection
-        let line_separator = SimdLineSeparator::new();
-        let line_boundaries = line_separator.find_line_boundaries(data);
-
-        let mut segments: Vec<SegmentBoundary> = Vec::with_capacity(line_boundaries.len());
+        let mut segments: Vec<SegmentBoundary> = Vec::with_capacity(rows.len());
         let mut errors = Vec::new();
         let mut successful_rows = 0;
         let mut failed_rows = 0;
-        let mut headers: Vec<String> = Vec::new();
 
-        // Process rows
-        let mut line_start = 0;
-        for (row_index, &line_end) in line_boundaries.iter().enumerate() {
-            let row = &data[line_start..line_end];
-
-            // Skip empty rows
-            if row.iter().all(|&b| b.is_ascii_whitespace()) {
-                line_start = line_end;
-                continue;
-            }
-
-            // Trim trailing newline/carriage return
-            let row = row
-                .strip_suffix(b"\n")
-                .unwrap_or(row)
-                .strip_suffix(b"\r")
-                .unwrap_or(row);
-
-            // Parse header row
-            if row_index == 0 && self.has_header {
-                headers = self.parse_csv_row(row);
-                // Add header row marker
-                tape.add_node(UnifiedNode::new(
-                    ExtendedNodeType::CsvHeaderRow,
-                    fionn_core::format::FormatKind::Csv,
-                ));
-                // Store header strings in arena
-                for header in &headers {
-                    tape.add_string(header);
-                }
-                line_start = line_end;
-                continue;
+        if self.dialect.has_header && !records.is_empty() {
+            // Add header row marker
+            tape.add_node(UnifiedNode::new(
+                ExtendedNodeType::CsvHeaderRow,
+                fionn_core::format::FormatKind::Csv,
+            ));
+            // Store header strings in arena
+            for header in &headers {
+                tape.add_string(header);
             }
+        }
 
-            // Parse data row
-            let values = self.parse_csv_row(row);
+        // Process rows
+        for (row_index, row, values) in &rows {
+            let row_index = *row_index;
 
             if values.len() != headers.len() && !headers.is_empty() {
                 failed_rows += 1;
@@ -763,7 +785,6 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
                     ),
                     raw_row: String::from_utf8_lossy(row).to_string(),
                 });
-                line_start = line_end;
                 continue;
             }
 
@@ -780,8 +801,8 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
 
             // Add all fields
             for (col_idx, value) in values.iter().enumerate() {
-                let has_quotes = value.starts_with('"') && value.ends_with('"');
-                let value_idx = tape.add_string(value);
+                let has_quotes = value.quoted;
+                let value_idx = tape.add_string(&value.value);
 
                 #[allow(clippy::cast_possible_truncation)] // Column index won't exceed u32::MAX
                 let col_idx_u32 = col_idx as u32;
@@ -816,7 +837,6 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
             });
 
             successful_rows += 1;
-            line_start = line_end;
         }
 
         let elapsed = start.elapsed();
@@ -840,11 +860,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdCsvBatchProcessor {
                 successful_lines: successful_rows,
                 failed_lines: failed_rows,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful_rows > 0 {
-                    original_size / successful_rows
-                } else {
-                    0
-                },
+                avg_memory_per_line: original_size.checked_div(successful_rows).unwrap_or(0),
                 overall_schema_match_ratio: 1.0,
             },
         })
@@ -976,6 +992,62 @@ mod tests {
         assert_eq!(processor.delimiter(), b',');
     }
 
+    #[test]
+    fn test_explicit_delimiter_not_overridden() {
+        // Auto-detection would pick ',' from these rows
+        let mut processor = SimdCsvBatchProcessor::with_delimiter(b';');
+        let data = b"a;b\n1,5;x,y\n";
+
+        let result = processor.process_batch_unfiltered(data).unwrap();
+        assert_eq!(processor.delimiter(), b';');
+        assert_eq!(result.documents[0], r#"{"a":"1,5","b":"x,y"}"#);
+    }
+
+    #[test]
+    fn test_multiline_quoted_field() {
+        let mut processor = SimdCsvBatchProcessor::new();
+        let data = b"id,note\n1,\"first line\nsecond line\"\n2,plain\n";
+
+        let result = processor.process_batch_unfiltered(data).unwrap();
+        assert!(result.errors.is_empty());
+        assert_eq!(result.documents.len(), 2);
+        assert_eq!(
+            result.documents[0],
+            r#"{"id":1,"note":"first line\nsecond line"}"#
+        );
+    }
+
+    #[test]
+    fn test_dialect_comments_and_null_tokens() {
+        let dialect = CsvDialect::new()
+            .with_comment_prefix(b'#')
+            .with_null_tokens(["NA"]);
+        let mut processor = SimdCsvBatchProcessor::with_dialect(dialect);
+        let data = b"# exported\nname,score\nAlice,NA\n\"NA\",3\n";
+
+        let result = processor.process_batch_unfiltered(data).unwrap();
+        assert_eq!(result.documents[0], r#"{"name":"Alice","score":null}"#);
+        // Quoted tokens are literal strings
+        assert_eq!(result.documents[1], r#"{"name":"NA","score":3}"#);
+    }
+
+    #[test]
+    fn test_type_inference_per_column() {
+        let mut processor = SimdCsvBatchProcessor::new().with_type_inference(100);
+        let data = b"zip,qty,price\n02134,1,2\n90210,2,2.5\n";
+
+        let result = processor.process_batch_unfiltered(data).unwrap();
+        // Leading zeros keep the column a string; ints widen to floats
+        assert_eq!(
+            result.documents[0],
+            r#"{"zip":"02134","qty":1,"price":2.0}"#
+        );
+        assert_eq!(
+            result.documents[1],
+            r#"{"zip":"90210","qty":2,"price":2.5}"#
+        );
+    }
+
     // =========================================================================
     // Tape-based processing tests (requires dson-multi-format feature)
     // =========================================================================
@@ -1000,6 +1072,26 @@ mod tests {
             assert_eq!(result.statistics.successful_lines, 2);
         }
 
+        #[test]
+        fn test_tape_pooled_processing_reuses_storage() {
+            let pool = TapeBuilderPool::default();
+            let mut processor = SimdCsvBatchProcessor::new();
+            let data = b"name,age,city\nAlice,30,Boston\nBob,25,Seattle";
+            let schema = CompiledSchema::compile(&[]).unwrap();
+
+            for _ in 0..2 {
+                pool.with_arena(|arena| {
+                    let result = processor
+                        .process_to_tape_pooled(data, &schema, arena, &pool)
+                        .unwrap();
+                    assert_eq!(result.segments.len(), 2);
+                    assert_eq!(result.statistics.successful_lines, 2);
+                    pool.recycle(result);
+                });
+            }
+            assert_eq!(pool.unified_stats().reuses, 1);
+        }
+
         #[test]
         fn test_tape_csv_header_preservation() {
             let arena = bumpalo::Bump::new();
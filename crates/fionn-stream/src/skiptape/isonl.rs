@@ -9,10 +9,15 @@
 //! table.events|id:int|type:string|2|view
 //! ```
 
-use crate::skiptape::error::Result;
+use crate::skiptape::error::{Result, SkipTapeError};
+use crate::skiptape::pool::TapeBuilderPool;
 use crate::skiptape::schema::CompiledSchema;
 use crate::skiptape::simd_ops::SimdLineSeparator;
+use fionn_core::limits::{DuplicateKeys, LimitViolation, ParseLimits};
+use fionn_simd::ResumableSkip;
 use fionn_simd::formats::{IsonParser, IsonlParsedLine};
+use std::collections::HashSet;
+use std::io::{self, BufRead};
 use std::time::Instant;
 
 use crate::format_dson::{BatchStatistics, FormatBatchProcessor, FormatBatchResult, LineError};
@@ -82,6 +87,10 @@ pub struct SimdIsonlBatchProcessor {
     parser: IsonParser,
     /// Reusable buffer for line data
     line_buffer: Vec<u8>,
+    /// Reusable buffer for `table.field` schema paths
+    path_buffer: String,
+    /// Limits every line is checked against
+    limits: ParseLimits,
 }
 
 impl Default for SimdIsonlBatchProcessor {
@@ -97,9 +106,22 @@ impl SimdIsonlBatchProcessor {
         Self {
             parser: IsonParser::streaming(),
             line_buffer: Vec::with_capacity(4096),
+            path_buffer: String::new(),
+            limits: ParseLimits::permissive(),
         }
     }
 
+    /// Check every line against `limits`, failing the lines that break one
+    ///
+    /// A line is one object, so it nests one deep; the size limit applies
+    /// to the trimmed line, the string limit to names and values, and
+    /// duplicate field names follow the duplicate key policy.
+    #[must_use]
+    pub const fn with_limits(mut self, limits: ParseLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Process an ISONL batch with schema filtering
     ///
     /// # Arguments
@@ -136,6 +158,16 @@ impl SimdIsonlBatchProcessor {
                 line_start = line_end;
                 continue;
             }
+            if let Err(violation) = check_line_limits(line, &self.limits) {
+                failed_lines += 1;
+                errors.push(IsonlLineError {
+                    line_index,
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
+                line_start = line_end;
+                continue;
+            }
 
             // Parse the ISONL line
             if let Some(parsed) = IsonParser::parse_isonl_line(line) {
@@ -168,7 +200,14 @@ impl SimdIsonlBatchProcessor {
         // Handle last line if no trailing newline
         if line_start < isonl_data.len() {
             let line = &isonl_data[line_start..];
-            if let Some(parsed) = IsonParser::parse_isonl_line(line) {
+            if let Err(violation) = check_line_limits(line, &self.limits) {
+                failed_lines += 1;
+                errors.push(IsonlLineError {
+                    line_index: line_boundaries.len(),
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
+            } else if let Some(parsed) = IsonParser::parse_isonl_line(line) {
                 if self.matches_schema(&parsed, schema) {
                     let json = IsonParser::isonl_to_json(&parsed);
                     documents.push(json);
@@ -191,11 +230,7 @@ impl SimdIsonlBatchProcessor {
                 failed_lines,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
                 schema_filtered_lines: schema_filtered,
-                avg_bytes_per_line: if total_lines > 0 {
-                    isonl_data.len() / total_lines
-                } else {
-                    0
-                },
+                avg_bytes_per_line: isonl_data.len().checked_div(total_lines).unwrap_or(0),
             },
         })
     }
@@ -224,6 +259,16 @@ impl SimdIsonlBatchProcessor {
                 line_start = line_end;
                 continue;
             }
+            if let Err(violation) = check_line_limits(line, &self.limits) {
+                failed_lines += 1;
+                errors.push(IsonlLineError {
+                    line_index,
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
+                line_start = line_end;
+                continue;
+            }
 
             if let Some(parsed) = IsonParser::parse_isonl_line(line) {
                 let json = IsonParser::isonl_to_json(&parsed);
@@ -247,7 +292,14 @@ impl SimdIsonlBatchProcessor {
         // Handle last line
         if line_start < isonl_data.len() {
             let line = &isonl_data[line_start..];
-            if let Some(parsed) = IsonParser::parse_isonl_line(line) {
+            if let Err(violation) = check_line_limits(line, &self.limits) {
+                failed_lines += 1;
+                errors.push(IsonlLineError {
+                    line_index: line_boundaries.len(),
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
+            } else if let Some(parsed) = IsonParser::parse_isonl_line(line) {
                 let json = IsonParser::isonl_to_json(&parsed);
                 documents.push(json);
                 successful_lines += 1;
@@ -266,15 +318,54 @@ impl SimdIsonlBatchProcessor {
                 failed_lines,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
                 schema_filtered_lines: 0,
-                avg_bytes_per_line: if total_lines > 0 {
-                    isonl_data.len() / total_lines
-                } else {
-                    0
-                },
+                avg_bytes_per_line: isonl_data.len().checked_div(total_lines).unwrap_or(0),
             },
         })
     }
 
+    /// Read and process the next batch of lines from `reader`
+    ///
+    /// Lines are read until about `batch_bytes` of them are buffered. Values
+    /// of fields `schema` does not select are skipped with a
+    /// [`ResumableSkip`] through the reader's own buffer, so a line carrying
+    /// a huge unwanted value never has to fit in memory; the first value of
+    /// a line is always read, since it is only told apart from a field
+    /// declaration by its contents. Returned documents hold only the
+    /// selected fields. Returns `None` once the reader is exhausted.
+    ///
+    /// # Errors
+    /// Returns an error if the reader fails
+    pub fn read_batch<R: BufRead>(
+        &mut self,
+        reader: &mut R,
+        schema: &CompiledSchema,
+        batch_bytes: usize,
+    ) -> Result<Option<IsonlBatchResult>> {
+        let mut batch = Vec::new();
+        let mut part = Vec::new();
+        let mut keep = Vec::new();
+        let mut lines = 0;
+        while batch.len() < batch_bytes {
+            let more = read_line(
+                reader,
+                schema,
+                &mut batch,
+                &mut part,
+                &mut keep,
+                &mut self.path_buffer,
+            )
+            .map_err(|e| SkipTapeError::IoError(e.to_string()))?;
+            if !more {
+                break;
+            }
+            lines += 1;
+        }
+        if lines == 0 {
+            return Ok(None);
+        }
+        self.process_batch_optimized(&batch, schema).map(Some)
+    }
+
     /// Check if a parsed ISONL line matches the schema
     #[allow(clippy::unused_self)] // Method signature for API consistency
     fn matches_schema(&self, parsed: &IsonlParsedLine, schema: &CompiledSchema) -> bool {
@@ -388,205 +479,367 @@ impl FormatBatchProcessor for SimdIsonlBatchProcessor {
     }
 }
 
-// =============================================================================
-// TapeBatchProcessor Implementation
-// =============================================================================
+/// An ISONL line borrowed from the batch
+///
+/// Accepts exactly the lines [`IsonParser::parse_isonl_line`] accepts, without
+/// copying names and values into owned strings.
+struct IsonlLine<'a> {
+    name: &'a str,
+    body: &'a str,
+    field_count: usize,
+}
 
-impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
-    fn format_kind(&self) -> fionn_core::format::FormatKind {
-        fionn_core::format::FormatKind::Ison
+impl<'a> IsonlLine<'a> {
+    fn parse(line: &'a [u8]) -> Option<Self> {
+        let trimmed = std::str::from_utf8(line).ok()?.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return None;
+        }
+
+        let (header, body) = trimmed.split_once('|')?;
+        let name = header
+            .strip_prefix("table.")
+            .or_else(|| header.strip_prefix("object."))?;
+
+        // Field declarations carry a type annotation, values follow them
+        let field_count = body
+            .split('|')
+            .take_while(|part| part.contains(':'))
+            .count();
+        let value_count = body.split('|').count() - field_count;
+        if field_count != value_count && field_count != 0 {
+            return None;
+        }
+
+        Some(Self {
+            name,
+            body,
+            field_count,
+        })
+    }
+
+    fn fields(&self) -> impl Iterator<Item = (&'a str, IsonFieldType)> + use<'a> {
+        self.body.split('|').take(self.field_count).map(|spec| {
+            let (name, field_type) = spec
+                .split_once(':')
+                .map_or((spec, None), |(name, ty)| (name, IsonType::parse(ty)));
+            (name, field_type_of(field_type))
+        })
+    }
+
+    fn values(&self) -> impl Iterator<Item = &'a str> + use<'a> {
+        self.body.split('|').skip(self.field_count)
+    }
+
+    /// Whether `syntax` is this line's schema header
+    fn is_header(&self, syntax: &OriginalSyntax) -> bool {
+        matches!(syntax, OriginalSyntax::IsonSchemaHeader { fields }
+        if fields.len() == self.field_count
+            && fields.iter().zip(self.fields()).all(|((name, ty), (line_name, line_ty))| {
+                name == line_name && *ty == line_ty
+            }))
+    }
+
+    fn header(&self) -> OriginalSyntax {
+        OriginalSyntax::IsonSchemaHeader {
+            fields: self
+                .fields()
+                .map(|(name, field_type)| (name.to_string(), field_type))
+                .collect(),
+        }
+    }
+}
+
+const fn field_type_of(field_type: Option<IsonType>) -> IsonFieldType {
+    match field_type {
+        Some(IsonType::Int) => IsonFieldType::Int,
+        Some(IsonType::Float) => IsonFieldType::Float,
+        Some(IsonType::Bool) => IsonFieldType::Bool,
+        Some(IsonType::String | IsonType::Computed | IsonType::Reference) | None => {
+            IsonFieldType::String
+        }
+    }
+}
+
+/// Check a line against `limits`, leaving lines that do not parse to the
+/// parser's own error
+fn check_line_limits(line: &[u8], limits: &ParseLimits) -> std::result::Result<(), LimitViolation> {
+    if limits.is_permissive() {
+        return Ok(());
+    }
+    let Some(parsed) = IsonlLine::parse(line) else {
+        return Ok(());
+    };
+    // The record is the one container, opening where its text starts
+    limits.check_shape(line.trim_ascii().len(), 1, |_| {
+        line.len() - line.trim_ascii_start().len()
+    })?;
+    if let Some(limit) = limits.max_string_len {
+        let names = parsed.fields().map(|(name, _)| name);
+        let mut offset = 0;
+        for text in names.chain(parsed.values()) {
+            if text.len() > limit {
+                return Err(LimitViolation::StringTooLong {
+                    offset,
+                    len: text.len(),
+                    limit,
+                });
+            }
+            offset += text.len() + 1;
+        }
+    }
+    if limits.duplicate_keys == DuplicateKeys::Reject {
+        let mut seen = HashSet::new();
+        for (name, _) in parsed.fields() {
+            if !seen.insert(name) {
+                return Err(LimitViolation::DuplicateKey {
+                    offset: 0,
+                    key: name.to_string(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read one line into `out`, dropping the fields `schema` does not select
+///
+/// Lines that are blank, comments or not ISONL are copied whole for the
+/// batch processor to judge. Returns `false` at the end of input.
+fn read_line<R: BufRead>(
+    reader: &mut R,
+    schema: &CompiledSchema,
+    out: &mut Vec<u8>,
+    part: &mut Vec<u8>,
+    keep: &mut Vec<bool>,
+    path: &mut String,
+) -> io::Result<bool> {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(false);
+    }
+    let field = || ResumableSkip::until(b'|', b'\n');
+    let header_start = out.len();
+    field().copy_reader(reader, out)?;
+    let header = std::str::from_utf8(&out[header_start..]).unwrap_or_default();
+    let table = header
+        .trim()
+        .strip_prefix("table.")
+        .or_else(|| header.trim().strip_prefix("object."))
+        .map(str::to_string);
+    let Some(table) =
+        table.filter(|t| !schema.include_patterns.is_empty() && !schema.matches_path(t))
+    else {
+        // Nothing to project: copy the rest of the line
+        ResumableSkip::until(b'\n', b'\n').copy_reader(reader, out)?;
+        return finish_line(reader, out);
+    };
+
+    keep.clear();
+    let mut values = 0;
+    while next_field(reader)? {
+        if values > 0 {
+            // Past the declarations, each value's field is known up front
+            let selected = keep.get(values).copied().unwrap_or(true);
+            values += 1;
+            if selected {
+                out.push(b'|');
+                field().copy_reader(reader, out)?;
+            } else {
+                field().skip_reader(reader)?;
+            }
+            continue;
+        }
+
+        part.clear();
+        field().copy_reader(reader, part)?;
+        let text = String::from_utf8_lossy(part);
+        let selected = if text.contains(':') {
+            let name = text.split_once(':').map_or("", |(name, _)| name);
+            let selected = matches_field(schema, &table, name, path);
+            keep.push(selected);
+            selected
+        } else {
+            values = 1;
+            keep.first().copied().unwrap_or(true)
+        };
+        if selected {
+            out.push(b'|');
+            out.extend_from_slice(part);
+        }
+    }
+    finish_line(reader, out)
+}
+
+/// Consume a `|` separator; `false` at the end of the line
+fn next_field<R: BufRead>(reader: &mut R) -> io::Result<bool> {
+    if reader.fill_buf()?.first() == Some(&b'|') {
+        reader.consume(1);
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+/// Consume the line's newline, if any, and end the copied line
+fn finish_line<R: BufRead>(reader: &mut R, out: &mut Vec<u8>) -> io::Result<bool> {
+    if reader.fill_buf()?.first() == Some(&b'\n') {
+        reader.consume(1);
+    }
+    out.push(b'\n');
+    Ok(true)
+}
+
+/// Check `name` and `table.name` against the schema
+fn matches_field(schema: &CompiledSchema, table: &str, name: &str, path: &mut String) -> bool {
+    if schema.matches_path(name) {
+        return true;
     }
+    path.clear();
+    path.push_str(table);
+    path.push('.');
+    path.push_str(name);
+    schema.matches_path(path)
+}
 
+impl SimdIsonlBatchProcessor {
+    /// Build unified tape for a batch into the given tape and segment storage
+    ///
+    /// Lines are parsed in place and repeated schema headers share one
+    /// original syntax entry, so with pooled tape storage a warmed-up batch
+    /// does not allocate.
     #[allow(clippy::too_many_lines)] // Complex tape construction with schema filtering
-    fn process_to_tape(
-        &mut self,
+    fn schema_filtered_tape<'arena>(
         data: &[u8],
         schema: &CompiledSchema,
-        arena: &'arena bumpalo::Bump,
-    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        mut tape: UnifiedTape<'arena>,
+        mut segments: Vec<SegmentBoundary>,
+        path: &mut String,
+        limits: &ParseLimits,
+    ) -> TapeBatchResult<'arena> {
         let start = Instant::now();
 
-        let mut tape = UnifiedTape::new(arena, fionn_core::format::FormatKind::Ison);
         tape.metadata.original_size = data.len();
 
-        let line_separator = SimdLineSeparator::new();
-        let line_boundaries = line_separator.find_line_boundaries(data);
-
-        let mut segments: Vec<SegmentBoundary> = Vec::with_capacity(line_boundaries.len());
         let mut errors = Vec::new();
         let mut successful = 0;
         let mut failed = 0;
         let mut filtered = 0;
 
-        let mut line_start = 0;
-        for (line_index, &line_end) in line_boundaries.iter().enumerate() {
-            let line = &data[line_start..line_end];
-
+        for (line_index, line) in data.split_inclusive(|&b| b == b'\n').enumerate() {
             // Skip empty lines
             if line.iter().all(|&b| b.is_ascii_whitespace()) {
-                line_start = line_end;
+                continue;
+            }
+            if let Err(violation) = check_line_limits(line, limits) {
+                failed += 1;
+                errors.push(LineError {
+                    line_index,
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
                 continue;
             }
 
             // Parse ISONL line
-            if let Some(parsed) = IsonParser::parse_isonl_line(line) {
-                // Check schema match
-                if !self.matches_schema(&parsed, schema) {
-                    filtered += 1;
+            let Some(parsed) = IsonlLine::parse(line) else {
+                // Check if it's a comment
+                if !line.contains(&b'#') {
+                    failed += 1;
+                    errors.push(LineError {
+                        line_index,
+                        error_message: "Failed to parse ISONL line".to_string(),
+                        raw_line: String::from_utf8_lossy(line).to_string(),
+                    });
+                }
+                continue;
+            };
+
+            // Check schema match: any field, or the table name itself
+            let matches = schema.include_patterns.is_empty()
+                || parsed
+                    .fields()
+                    .any(|(name, _)| matches_field(schema, parsed.name, name, path))
+                || schema.matches_path(parsed.name);
+            if !matches {
+                filtered += 1;
+                tape.add_node(UnifiedNode::new(
+                    ExtendedNodeType::SkipMarker,
+                    fionn_core::format::FormatKind::Ison,
+                ));
+                tape.metadata.skipped_count += 1;
+                continue;
+            }
+
+            let segment_start = tape.nodes().len();
+
+            // Add ISON schema header, shared by lines with the same fields
+            let syntax_idx =
+                tape.intern_original_syntax(|syntax| parsed.is_header(syntax), || parsed.header());
+
+            // Add table block with name
+            let name_idx = tape.add_string(parsed.name);
+            tape.add_node(
+                UnifiedNode::new(
+                    ExtendedNodeType::IsonTableBlock { name_idx },
+                    fionn_core::format::FormatKind::Ison,
+                )
+                .with_original_syntax(syntax_idx),
+            );
+
+            // Add each field with its value
+            let mut values = parsed.values();
+            for (name, field_type) in parsed.fields() {
+                let value = values.next().unwrap_or("");
+
+                // Check if this field matches schema (for column-level filtering)
+                if !schema.include_patterns.is_empty()
+                    && !matches_field(schema, parsed.name, name, path)
+                {
                     tape.add_node(UnifiedNode::new(
                         ExtendedNodeType::SkipMarker,
                         fionn_core::format::FormatKind::Ison,
                     ));
                     tape.metadata.skipped_count += 1;
-                    line_start = line_end;
                     continue;
                 }
 
-                let segment_start = tape.nodes().len();
-
-                // Add ISON schema header - convert IsonType to IsonFieldType
-                let field_types: Vec<(String, IsonFieldType)> = parsed
-                    .fields
-                    .iter()
-                    .map(|f| {
-                        let ftype = match f.field_type {
-                            Some(IsonType::Int) => IsonFieldType::Int,
-                            Some(IsonType::Float) => IsonFieldType::Float,
-                            Some(IsonType::Bool) => IsonFieldType::Bool,
-                            Some(IsonType::String | IsonType::Computed | IsonType::Reference)
-                            | None => IsonFieldType::String,
-                        };
-                        (f.name.clone(), ftype)
-                    })
-                    .collect();
-
-                let syntax_idx = tape.add_original_syntax(OriginalSyntax::IsonSchemaHeader {
-                    fields: field_types,
-                });
-
-                // Add table block with name
-                let name_idx = tape.add_string(&parsed.name);
-                tape.add_node(
-                    UnifiedNode::new(
-                        ExtendedNodeType::IsonTableBlock { name_idx },
-                        fionn_core::format::FormatKind::Ison,
-                    )
-                    .with_original_syntax(syntax_idx),
-                );
-
-                // Add each field with its value
-                for (i, field) in parsed.fields.iter().enumerate() {
-                    // Check if this field matches schema (for column-level filtering)
-                    let field_matches = schema.include_patterns.is_empty()
-                        || schema.matches_path(&field.name)
-                        || schema.matches_path(&format!("{}.{}", parsed.name, field.name));
-
-                    if !field_matches {
-                        tape.add_node(UnifiedNode::new(
-                            ExtendedNodeType::SkipMarker,
-                            fionn_core::format::FormatKind::Ison,
-                        ));
-                        tape.metadata.skipped_count += 1;
-                        continue;
-                    }
-
-                    let field_type = match field.field_type {
-                        Some(IsonType::Int) => IsonFieldType::Int,
-                        Some(IsonType::Float) => IsonFieldType::Float,
-                        Some(IsonType::Bool) => IsonFieldType::Bool,
-                        Some(IsonType::String | IsonType::Computed | IsonType::Reference)
-                        | None => IsonFieldType::String,
-                    };
-
-                    // Get the value from the values vector
-                    let value = parsed.values.get(i).map_or("", String::as_str);
-
-                    let name_idx = tape.add_string(&field.name);
-
-                    // Add key node
-                    tape.add_node(UnifiedNode::new(
-                        ExtendedNodeType::Key(name_idx),
-                        fionn_core::format::FormatKind::Ison,
-                    ));
-
-                    // Add typed field marker
-                    tape.add_node(UnifiedNode::new(
-                        ExtendedNodeType::IsonTypedField { field_type },
-                        fionn_core::format::FormatKind::Ison,
-                    ));
-
-                    // Add field value based on type
-                    match field_type {
-                        IsonFieldType::Int => {
-                            if let Ok(n) = value.parse::<i64>() {
-                                tape.add_node(UnifiedNode::new(
-                                    #[allow(clippy::cast_precision_loss)]
-                                    // Acceptable for JSON number representation
-                                    ExtendedNodeType::Number(n as f64),
-                                    fionn_core::format::FormatKind::Ison,
-                                ));
-                            } else {
-                                // Fallback to string
-                                let value_idx = tape.add_string(value);
-                                tape.add_node(UnifiedNode::new(
-                                    ExtendedNodeType::String(value_idx),
-                                    fionn_core::format::FormatKind::Ison,
-                                ));
-                            }
-                        }
-                        IsonFieldType::Float => {
-                            if let Ok(n) = value.parse::<f64>() {
-                                tape.add_node(UnifiedNode::new(
-                                    ExtendedNodeType::Number(n),
-                                    fionn_core::format::FormatKind::Ison,
-                                ));
-                            } else {
-                                // Fallback to string
-                                let value_idx = tape.add_string(value);
-                                tape.add_node(UnifiedNode::new(
-                                    ExtendedNodeType::String(value_idx),
-                                    fionn_core::format::FormatKind::Ison,
-                                ));
-                            }
-                        }
-                        IsonFieldType::Bool => {
-                            let b = value == "true" || value == "1";
-                            tape.add_node(UnifiedNode::new(
-                                ExtendedNodeType::Bool(b),
-                                fionn_core::format::FormatKind::Ison,
-                            ));
-                        }
-                        _ => {
-                            let value_idx = tape.add_string(value);
-                            tape.add_node(UnifiedNode::new(
-                                ExtendedNodeType::String(value_idx),
-                                fionn_core::format::FormatKind::Ison,
-                            ));
-                        }
+                let name_idx = tape.add_string(name);
+
+                // Add key node
+                tape.add_node(UnifiedNode::new(
+                    ExtendedNodeType::Key(name_idx),
+                    fionn_core::format::FormatKind::Ison,
+                ));
+
+                // Add typed field marker
+                tape.add_node(UnifiedNode::new(
+                    ExtendedNodeType::IsonTypedField { field_type },
+                    fionn_core::format::FormatKind::Ison,
+                ));
+
+                // Add field value based on type, falling back to a string
+                #[allow(clippy::cast_precision_loss)] // Acceptable for JSON number representation
+                let node = match field_type {
+                    IsonFieldType::Int => value
+                        .parse::<i64>()
+                        .ok()
+                        .map(|n| ExtendedNodeType::Number(n as f64)),
+                    IsonFieldType::Float => value.parse::<f64>().ok().map(ExtendedNodeType::Number),
+                    IsonFieldType::Bool => {
+                        Some(ExtendedNodeType::Bool(value == "true" || value == "1"))
                     }
+                    _ => None,
                 }
-
-                segments.push(SegmentBoundary {
-                    start_idx: segment_start,
-                    end_idx: tape.nodes().len(),
-                    source_idx: line_index,
-                });
-
-                successful += 1;
-            } else {
-                // Check if it's a comment
-                let trimmed = line.iter().filter(|&&b| !b.is_ascii_whitespace()).count();
-                if trimmed > 0 && !line.contains(&b'#') {
-                    failed += 1;
-                    errors.push(LineError {
-                        line_index,
-                        error_message: "Failed to parse ISONL line".to_string(),
-                        raw_line: String::from_utf8_lossy(line).to_string(),
-                    });
-                }
+                .unwrap_or_else(|| ExtendedNodeType::String(tape.add_string(value)));
+                tape.add_node(UnifiedNode::new(node, fionn_core::format::FormatKind::Ison));
             }
 
-            line_start = line_end;
+            segments.push(SegmentBoundary {
+                start_idx: segment_start,
+                end_idx: tape.nodes().len(),
+                source_idx: line_index,
+            });
+
+            successful += 1;
         }
 
         let elapsed = start.elapsed();
@@ -600,7 +853,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
             };
         }
 
-        Ok(TapeBatchResult {
+        TapeBatchResult {
             tape,
             segments,
             errors,
@@ -609,11 +862,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
                 successful_lines: successful,
                 failed_lines: failed,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful > 0 {
-                    data.len() / successful
-                } else {
-                    0
-                },
+                avg_memory_per_line: data.len().checked_div(successful).unwrap_or(0),
                 #[allow(clippy::cast_precision_loss)] // Acceptable for ratio calculation
                 overall_schema_match_ratio: if total > 0 {
                     successful as f64 / total as f64
@@ -621,7 +870,52 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
                     0.0
                 },
             },
-        })
+        }
+    }
+}
+
+// =============================================================================
+// TapeBatchProcessor Implementation
+// =============================================================================
+
+impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
+    fn format_kind(&self) -> fionn_core::format::FormatKind {
+        fionn_core::format::FormatKind::Ison
+    }
+
+    fn process_to_tape(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = UnifiedTape::new(arena, fionn_core::format::FormatKind::Ison);
+        Ok(Self::schema_filtered_tape(
+            data,
+            schema,
+            tape,
+            Vec::new(),
+            &mut self.path_buffer,
+            &self.limits,
+        ))
+    }
+
+    fn process_to_tape_pooled(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+        pool: &TapeBuilderPool,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = pool.unified_tape(arena, fionn_core::format::FormatKind::Ison);
+        Ok(Self::schema_filtered_tape(
+            data,
+            schema,
+            tape,
+            pool.segments(),
+            &mut self.path_buffer,
+            &self.limits,
+        ))
     }
 
     #[allow(clippy::too_many_lines)] // Complex tape construction for all fields
@@ -652,6 +946,16 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
                 line_start = line_end;
                 continue;
             }
+            if let Err(violation) = check_line_limits(line, &self.limits) {
+                failed += 1;
+                errors.push(LineError {
+                    line_index,
+                    error_message: violation.to_string(),
+                    raw_line: String::from_utf8_lossy(line).to_string(),
+                });
+                line_start = line_end;
+                continue;
+            }
 
             // Parse ISONL line
             if let Some(parsed) = IsonParser::parse_isonl_line(line) {
@@ -800,11 +1104,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdIsonlBatchProcessor {
                 successful_lines: successful,
                 failed_lines: failed,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful > 0 {
-                    data.len() / successful
-                } else {
-                    0
-                },
+                avg_memory_per_line: data.len().checked_div(successful).unwrap_or(0),
                 overall_schema_match_ratio: 1.0,
             },
         })
@@ -929,6 +1229,59 @@ mod tests {
         assert!(result.statistics.processing_time_ms >= 0.0);
     }
 
+    #[test]
+    fn test_limits_fail_offending_lines() {
+        let limits = ParseLimits::strict().with_max_string_len(8);
+        let mut processor = SimdIsonlBatchProcessor::new().with_limits(limits);
+        let data = b"table.users|id:int|name:string|1|Alice\n\
+            table.users|id:int|name:string|2|Bartholomew\n\
+            table.users|id:int|id:int|3|4";
+
+        let result = processor.process_batch_unfiltered(data).unwrap();
+        assert_eq!(result.documents.len(), 1);
+        assert_eq!(result.statistics.failed_lines, 2);
+        assert!(
+            result.errors[0]
+                .error_message
+                .contains("exceeds limit of 8")
+        );
+        assert!(result.errors[1].error_message.contains("duplicate key"));
+
+        let arena = bumpalo::Bump::new();
+        let result = processor.process_to_tape_unfiltered(data, &arena).unwrap();
+        assert_eq!(result.segment_count(), 1);
+        assert_eq!(result.statistics.failed_lines, 2);
+    }
+
+    #[test]
+    fn test_read_batch_skips_unselected_values() {
+        let huge = "x".repeat(100_000);
+        let input = format!(
+            "table.users|id:int|bio:string|name:string|1|{huge}|Alice\n\
+             # comment\n\
+             table.users|id:int|bio:string|name:string|2|short|Bob"
+        );
+        let mut reader = io::BufReader::with_capacity(256, input.as_bytes());
+        let schema = CompiledSchema::compile(&["id".to_string(), "name".to_string()]).unwrap();
+        let mut processor = SimdIsonlBatchProcessor::new();
+
+        let result = processor
+            .read_batch(&mut reader, &schema, 1 << 20)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            result.documents,
+            [r#"{"id":1,"name":"Alice"}"#, r#"{"id":2,"name":"Bob"}"#]
+        );
+        assert_eq!(result.statistics.failed_lines, 0);
+        assert!(
+            processor
+                .read_batch(&mut reader, &schema, 1 << 20)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut processor = SimdIsonlBatchProcessor::new();
@@ -969,6 +1322,25 @@ mod tape_tests {
         assert!(!result.tape.nodes().is_empty());
     }
 
+    #[test]
+    fn test_tape_pooled_processing_reuses_storage() {
+        let pool = TapeBuilderPool::default();
+        let mut processor = SimdIsonlBatchProcessor::new();
+        let data = b"table.users|id:int|name:string|1|Alice\ntable.users|id:int|name:string|2|Bob";
+        let schema = CompiledSchema::compile(&[]).unwrap();
+
+        for _ in 0..2 {
+            pool.with_arena(|arena| {
+                let result = processor
+                    .process_to_tape_pooled(data, &schema, arena, &pool)
+                    .unwrap();
+                assert_eq!(result.segments.len(), 2);
+                pool.recycle(result);
+            });
+        }
+        assert_eq!(pool.unified_stats().reuses, 1);
+    }
+
     #[test]
     fn test_tape_unfiltered_processing() {
         let mut processor = SimdIsonlBatchProcessor::new();
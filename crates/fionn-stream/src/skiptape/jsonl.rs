@@ -2,14 +2,32 @@
 //! JSONL (JSON Lines) processing for SIMD-JSONL Skip Tape
 //!
 //! This module provides SIMD-accelerated JSON Lines processing with schema-aware filtering.
-
+//!
+//! [`SimdJsonlBatchProcessor`] also emits unified tape. Its pooled tape path
+//! reuses arenas, tape nodes and simd-json buffers from a
+//! [`TapeBuilderPool`], so steady-state batches make no heap allocations.
+//! [`SimdJsonlBatchProcessor::read_batch`] reads records straight from a
+//! [`BufRead`], skipping unselected members with a [`ResumableSkip`].
+
+use crate::format_dson::{
+    FormatBatchProcessor, FormatBatchResult, SegmentBoundary, TapeBatchProcessor, TapeBatchResult,
+};
 use crate::skiptape::SkipTapeProcessor;
 use crate::skiptape::error::{Result, SkipTapeError};
+use crate::skiptape::pool::TapeBuilderPool;
 use crate::skiptape::schema::CompiledSchema;
 use crate::skiptape::simd_ops::SimdJsonStructuralDetector;
+use crate::skiptape::unified_tape::{ExtendedNodeType, UnifiedNode, UnifiedTape};
+use fionn_core::format::FormatKind;
+use fionn_core::limits::{ParseLimits, check_json};
+use fionn_pool::TapeStorage;
 use fionn_simd::SimdLineSeparator;
 use fionn_simd::SimdStructuralFilter;
+use fionn_simd::{Prefilter, ResumableSkip, Verdict};
 use rayon::prelude::*;
+use simd_json::StaticNode;
+use simd_json::value::tape::Node;
+use std::io::{self, BufRead};
 
 const GPU_MIN_BYTES: usize = 16 * 1024;
 
@@ -125,6 +143,24 @@ pub struct BatchStatistics {
     pub avg_memory_per_line: usize,
     /// Overall schema match ratio
     pub overall_schema_match_ratio: f64,
+    /// Lines a predicate prefilter decided from raw bytes
+    pub prefilter_hits: usize,
+    /// Lines the prefilter left to full evaluation
+    pub prefilter_misses: usize,
+}
+
+impl BatchStatistics {
+    /// Fraction of prefiltered lines decided without parsing (0 when no
+    /// prefilter ran)
+    #[must_use]
+    pub fn prefilter_hit_rate(&self) -> f64 {
+        let checked = self.prefilter_hits + self.prefilter_misses;
+        if checked == 0 {
+            return 0.0;
+        }
+        let hits = f64::from(u32::try_from(self.prefilter_hits).unwrap_or(u32::MAX));
+        hits / f64::from(u32::try_from(checked).unwrap_or(u32::MAX))
+    }
 }
 
 /// Result of batch processing
@@ -159,6 +195,8 @@ pub struct SimdJsonlBatchProcessor {
     structural_detector: SimdJsonStructuralDetector,
     /// Skip tape processor for individual lines
     skip_processor: SkipTapeProcessor,
+    /// Limits every line is checked against
+    limits: ParseLimits,
     /// GPU pre-scan enabled flag
     gpu_enabled: bool,
     /// Pre-scan mode (CPU vs GPU)
@@ -169,6 +207,8 @@ pub struct SimdJsonlBatchProcessor {
     // gpu_scanner: Option<GpuSimdScanner>,
     /// Processing statistics
     stats: BatchStatistics,
+    /// Dotted path of the member being emitted to unified tape
+    path_buffer: String,
 }
 
 impl Default for SimdJsonlBatchProcessor {
@@ -187,11 +227,13 @@ impl SimdJsonlBatchProcessor {
             structural_filter: SimdStructuralFilter::new(),
             structural_detector: SimdJsonStructuralDetector::new(),
             skip_processor: SkipTapeProcessor::new(),
+            limits: ParseLimits::permissive(),
             gpu_enabled: false,
             prescan_mode: PreScanMode::Auto,
             gpu_min_bytes: GPU_MIN_BYTES,
             // gpu_scanner: None,
             stats: BatchStatistics::default(),
+            path_buffer: String::new(),
         }
     }
 
@@ -203,6 +245,23 @@ impl SimdJsonlBatchProcessor {
         processor
     }
 
+    /// Check every line against `limits`, failing the lines that break one
+    #[must_use]
+    pub const fn with_limits(mut self, limits: ParseLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Check a trimmed line against the configured limits
+    fn check_limits(&self, line: &[u8]) -> std::result::Result<(), SkipTapeError> {
+        if self.limits.is_permissive() {
+            return Ok(());
+        }
+        check_json(line, &self.limits)
+            .map(drop)
+            .map_err(|violation| SkipTapeError::ValidationError(violation.to_string()))
+    }
+
     /// Process a batch of JSONL data with schema filtering using optimized approach
     ///
     /// Key optimizations:
@@ -226,6 +285,8 @@ impl SimdJsonlBatchProcessor {
             processing_time_ms: 0.0,
             avg_memory_per_line: 0,
             overall_schema_match_ratio: 0.0,
+            prefilter_hits: 0,
+            prefilter_misses: 0,
         };
 
         // SIMD-accelerated line separation
@@ -248,7 +309,17 @@ impl SimdJsonlBatchProcessor {
             // Convert bytes to string (assuming valid UTF-8 for JSON)
             if let Ok(s) = std::str::from_utf8(line_data) {
                 let trimmed = s.trim();
-                if !trimmed.is_empty() {
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Err(error) = self.check_limits(trimmed.as_bytes()) {
+                    errors.push(LineError {
+                        line_index,
+                        error,
+                        raw_line: trimmed.to_string(),
+                    });
+                    self.stats.failed_lines += 1;
+                } else {
                     valid_lines.push((line_index, trimmed.to_string()));
                 }
             } else {
@@ -287,8 +358,8 @@ impl SimdJsonlBatchProcessor {
         let processing_time = start_time.elapsed().as_secs_f64() * 1000.0;
         self.stats.processing_time_ms = processing_time;
 
-        if self.stats.successful_lines > 0 {
-            self.stats.avg_memory_per_line = total_memory / self.stats.successful_lines;
+        if let Some(avg) = total_memory.checked_div(self.stats.successful_lines) {
+            self.stats.avg_memory_per_line = avg;
             let successful_f64 =
                 f64::from(u32::try_from(self.stats.successful_lines).unwrap_or(u32::MAX));
             self.stats.overall_schema_match_ratio = total_schema_match_ratio / successful_f64;
@@ -324,6 +395,8 @@ impl SimdJsonlBatchProcessor {
             processing_time_ms: 0.0,
             avg_memory_per_line: 0,
             overall_schema_match_ratio: 1.0,
+            prefilter_hits: 0,
+            prefilter_misses: 0,
         };
 
         // Step 1: SIMD-JSONL parsing (fast batch processing)
@@ -364,8 +437,8 @@ impl SimdJsonlBatchProcessor {
         self.stats.processing_time_ms = processing_time;
         self.stats.total_lines = parse_result.documents.len();
 
-        if self.stats.successful_lines > 0 {
-            self.stats.avg_memory_per_line = total_memory / self.stats.successful_lines;
+        if let Some(avg) = total_memory.checked_div(self.stats.successful_lines) {
+            self.stats.avg_memory_per_line = avg;
         }
 
         Ok(BatchResult {
@@ -391,6 +464,8 @@ impl SimdJsonlBatchProcessor {
             processing_time_ms: 0.0,
             avg_memory_per_line: 0,
             overall_schema_match_ratio: 1.0, // All data included
+            prefilter_hits: 0,
+            prefilter_misses: 0,
         };
 
         // SIMD-accelerated line separation
@@ -412,7 +487,17 @@ impl SimdJsonlBatchProcessor {
             // Convert bytes to string (assuming valid UTF-8 for JSON)
             if let Ok(s) = std::str::from_utf8(line_data) {
                 let trimmed = s.trim();
-                if !trimmed.is_empty() {
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Err(error) = self.check_limits(trimmed.as_bytes()) {
+                    errors.push(LineError {
+                        line_index,
+                        error,
+                        raw_line: trimmed.to_string(),
+                    });
+                    self.stats.failed_lines += 1;
+                } else {
                     valid_lines.push((line_index, trimmed.to_string()));
                 }
             } else {
@@ -462,8 +547,8 @@ impl SimdJsonlBatchProcessor {
         let processing_time = start_time.elapsed().as_secs_f64() * 1000.0;
         self.stats.processing_time_ms = processing_time;
 
-        if self.stats.successful_lines > 0 {
-            self.stats.avg_memory_per_line = total_memory / self.stats.successful_lines;
+        if let Some(avg) = total_memory.checked_div(self.stats.successful_lines) {
+            self.stats.avg_memory_per_line = avg;
         }
 
         Ok(BatchResult {
@@ -473,6 +558,177 @@ impl SimdJsonlBatchProcessor {
         })
     }
 
+    /// Process a batch keeping only lines that satisfy a filter predicate
+    ///
+    /// Each line is first checked on its raw bytes; only lines the
+    /// [`Prefilter`] cannot decide are parsed and evaluated in full. Decided
+    /// lines are grammar-validated, so malformed lines are reported in
+    /// `errors` whatever the verdict.
+    /// `statistics.prefilter_hits`/`prefilter_misses` record how often the
+    /// raw check sufficed.
+    ///
+    /// # Errors
+    /// Returns an error if batch processing fails
+    pub fn process_batch_prefiltered(
+        &mut self,
+        jsonl_data: &[u8],
+        prefilter: &Prefilter,
+    ) -> Result<BatchResult> {
+        let start_time = std::time::Instant::now();
+
+        // Reset statistics
+        self.stats = BatchStatistics {
+            overall_schema_match_ratio: 1.0,
+            ..BatchStatistics::default()
+        };
+
+        let line_boundaries = self.line_boundaries(jsonl_data);
+        self.stats.total_lines = line_boundaries.len();
+
+        let mut documents = Vec::new();
+        let mut errors = Vec::new();
+        let mut total_memory = 0;
+        let mut line_start = 0;
+        for (line_index, &line_end) in line_boundaries.iter().enumerate() {
+            let line_data = jsonl_data[line_start..line_end].trim_ascii();
+            line_start = line_end;
+            if line_data.is_empty() {
+                continue;
+            }
+            if let Err(error) = self.check_limits(line_data) {
+                errors.push(LineError {
+                    line_index,
+                    error,
+                    raw_line: String::from_utf8_lossy(line_data).to_string(),
+                });
+                self.stats.failed_lines += 1;
+                continue;
+            }
+
+            let keep = match prefilter.check_validated(line_data) {
+                Ok(Verdict::Match) => {
+                    self.stats.prefilter_hits += 1;
+                    true
+                }
+                Ok(Verdict::Reject) => {
+                    self.stats.prefilter_hits += 1;
+                    false
+                }
+                Err(e) => {
+                    errors.push(LineError {
+                        line_index,
+                        error: SkipTapeError::ParseError(e.to_string()),
+                        raw_line: String::from_utf8_lossy(line_data).to_string(),
+                    });
+                    self.stats.prefilter_hits += 1;
+                    self.stats.failed_lines += 1;
+                    continue;
+                }
+                Ok(Verdict::Inconclusive) => {
+                    self.stats.prefilter_misses += 1;
+                    match serde_json::from_slice(line_data) {
+                        Ok(value) => prefilter.evaluate(&value),
+                        Err(e) => {
+                            errors.push(LineError {
+                                line_index,
+                                error: SkipTapeError::ParseError(e.to_string()),
+                                raw_line: String::from_utf8_lossy(line_data).to_string(),
+                            });
+                            self.stats.failed_lines += 1;
+                            continue;
+                        }
+                    }
+                }
+            };
+            if !keep {
+                continue;
+            }
+
+            if let Ok(line) = std::str::from_utf8(line_data) {
+                total_memory += line.len();
+                documents.push(line.to_string());
+                self.stats.successful_lines += 1;
+            } else {
+                errors.push(LineError {
+                    line_index,
+                    error: SkipTapeError::ParseError("Invalid UTF-8".to_string()),
+                    raw_line: String::from_utf8_lossy(line_data).to_string(),
+                });
+                self.stats.failed_lines += 1;
+            }
+        }
+
+        self.stats.processing_time_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+        if let Some(avg) = total_memory.checked_div(self.stats.successful_lines) {
+            self.stats.avg_memory_per_line = avg;
+        }
+
+        Ok(BatchResult {
+            documents,
+            errors,
+            statistics: self.stats.clone(),
+        })
+    }
+
+    /// Read and process the next batch of records from `reader`
+    ///
+    /// Records are read until about `batch_bytes` of them are buffered. Top
+    /// level members of object records that `schema` does not select are
+    /// skipped with a [`ResumableSkip`] through the reader's own buffer, so
+    /// a record carrying a huge unwanted field never has to fit in memory.
+    /// Returned documents hold only the selected members. Returns `None`
+    /// once the reader is exhausted.
+    ///
+    /// # Errors
+    /// Returns an error if the reader fails; malformed records are reported
+    /// as line errors.
+    pub fn read_batch<R: BufRead>(
+        &mut self,
+        reader: &mut R,
+        schema: &CompiledSchema,
+        batch_bytes: usize,
+    ) -> Result<Option<BatchResult>> {
+        let mut batch = Vec::new();
+        let mut key = Vec::new();
+        let mut read_errors = Vec::new();
+        let mut lines = 0;
+        while batch.len() < batch_bytes {
+            let start = batch.len();
+            match read_record(reader, schema, &mut batch, &mut key) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof
+                    ) =>
+                {
+                    read_errors.push(LineError {
+                        line_index: lines,
+                        error: SkipTapeError::ParseError(e.to_string()),
+                        raw_line: String::from_utf8_lossy(&batch[start..]).to_string(),
+                    });
+                    batch.truncate(start);
+                    discard_line(reader).map_err(|e| SkipTapeError::IoError(e.to_string()))?;
+                }
+                Err(e) => return Err(SkipTapeError::IoError(e.to_string())),
+            }
+            // Failed records stay as blank lines to keep line indexes aligned
+            batch.push(b'\n');
+            lines += 1;
+        }
+        if lines == 0 {
+            return Ok(None);
+        }
+
+        let mut result = self.process_batch_optimized(&batch, schema)?;
+        self.stats.failed_lines += read_errors.len();
+        result.statistics.failed_lines += read_errors.len();
+        result.errors.extend(read_errors);
+        result.errors.sort_by_key(|e| e.line_index);
+        Ok(Some(result))
+    }
+
     /// Apply DSON operations to a single JSON document
     fn apply_dson_operations_to_document(
         doc_json: &str,
@@ -610,6 +866,8 @@ impl SimdJsonlBatchProcessor {
             processing_time_ms: 0.0,
             avg_memory_per_line: 0,
             overall_schema_match_ratio: 0.0,
+            prefilter_hits: 0,
+            prefilter_misses: 0,
         };
 
         // SIMD-accelerated line separation
@@ -655,11 +913,21 @@ impl SimdJsonlBatchProcessor {
             // Convert bytes to string (assuming valid UTF-8 for JSON)
             if let Ok(s) = std::str::from_utf8(line_data) {
                 let trimmed = s.trim();
-                if !trimmed.is_empty() {
-                    valid_lines.push((line_index, trimmed.to_string()));
-                    if gpu_prefilter_flags.is_none() {
-                        prefiltered_count += 1;
-                    }
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Err(error) = self.check_limits(trimmed.as_bytes()) {
+                    errors.push(LineError {
+                        line_index,
+                        error,
+                        raw_line: trimmed.to_string(),
+                    });
+                    self.stats.failed_lines += 1;
+                    continue;
+                }
+                valid_lines.push((line_index, trimmed.to_string()));
+                if gpu_prefilter_flags.is_none() {
+                    prefiltered_count += 1;
                 }
             } else {
                 errors.push(LineError {
@@ -710,8 +978,8 @@ impl SimdJsonlBatchProcessor {
         let processing_time = start_time.elapsed().as_secs_f64() * 1000.0;
         self.stats.processing_time_ms = processing_time;
 
-        if self.stats.successful_lines > 0 {
-            self.stats.avg_memory_per_line = total_memory / self.stats.successful_lines;
+        if let Some(avg) = total_memory.checked_div(self.stats.successful_lines) {
+            self.stats.avg_memory_per_line = avg;
             let successful_f64 =
                 f64::from(u32::try_from(self.stats.successful_lines).unwrap_or(u32::MAX));
             self.stats.overall_schema_match_ratio = total_schema_match_ratio / successful_f64;
@@ -749,6 +1017,8 @@ impl SimdJsonlBatchProcessor {
             processing_time_ms: 0.0,
             avg_memory_per_line: 0,
             overall_schema_match_ratio: 0.0,
+            prefilter_hits: 0,
+            prefilter_misses: 0,
         };
     }
 
@@ -794,6 +1064,254 @@ impl SimdJsonlBatchProcessor {
         self.gpu_min_bytes = min_bytes;
     }
 
+    /// Parse each line into `tape`, using `json_tape` and `scratch` per line
+    ///
+    /// Lines are copied into `scratch` for simd-json, so none of the storage
+    /// passed in is allocated again once it has grown to the batch's size.
+    fn fill_unified_tape<'arena>(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        mut tape: UnifiedTape<'arena>,
+        mut segments: Vec<SegmentBoundary>,
+        json_tape: TapeStorage,
+        scratch: &mut Vec<u8>,
+    ) -> (TapeBatchResult<'arena>, TapeStorage) {
+        let start = std::time::Instant::now();
+        let filter = !schema.include_patterns.is_empty();
+        tape.metadata.original_size = data.len();
+
+        let mut storage = json_tape;
+        let mut errors = Vec::new();
+        let mut successful = 0;
+        let mut failed = 0;
+        let mut filtered = 0;
+
+        let mut line_start = 0;
+        let mut line_index = 0;
+        while line_start < data.len() {
+            let line_end = memchr::memchr(b'\n', &data[line_start..])
+                .map_or(data.len(), |offset| line_start + offset);
+            let line = data[line_start..line_end].trim_ascii();
+            line_start = line_end + 1;
+            line_index += 1;
+
+            if line.is_empty() {
+                continue;
+            }
+            if let Err(e) = self.check_limits(line) {
+                failed += 1;
+                errors.push(crate::format_dson::LineError {
+                    line_index: line_index - 1,
+                    error_message: e.to_string(),
+                    raw_line: String::from_utf8_lossy(line).into_owned(),
+                });
+                continue;
+            }
+
+            scratch.clear();
+            scratch.extend_from_slice(line);
+            let (buffers, parsed) = storage.fill(scratch);
+            match parsed {
+                Ok(json) => {
+                    if filter && !Self::record_matches(&json.0, schema) {
+                        filtered += 1;
+                        tape.add_node(UnifiedNode::new(
+                            ExtendedNodeType::SkipMarker,
+                            FormatKind::Json,
+                        ));
+                        tape.metadata.skipped_count += 1;
+                    } else {
+                        let segment_start = tape.nodes().len();
+                        self.path_buffer.clear();
+                        Self::emit_json_value(
+                            &json.0,
+                            0,
+                            0,
+                            &mut tape,
+                            schema,
+                            &mut self.path_buffer,
+                            filter,
+                        );
+                        segments.push(SegmentBoundary {
+                            start_idx: segment_start,
+                            end_idx: tape.nodes().len(),
+                            source_idx: line_index - 1,
+                        });
+                        successful += 1;
+                    }
+                    storage = TapeStorage::restore(json, buffers);
+                }
+                Err(e) => {
+                    failed += 1;
+                    errors.push(crate::format_dson::LineError {
+                        line_index: line_index - 1,
+                        error_message: e.to_string(),
+                        raw_line: String::from_utf8_lossy(line).into_owned(),
+                    });
+                    storage = TapeStorage::from_buffers(buffers);
+                }
+            }
+        }
+
+        let total = successful + failed + filtered;
+        let ratio = if total > 0 {
+            f64::from(u32::try_from(successful).unwrap_or(u32::MAX))
+                / f64::from(u32::try_from(total).unwrap_or(u32::MAX))
+        } else {
+            0.0
+        };
+        tape.metadata.schema_match_ratio = ratio;
+
+        let result = TapeBatchResult {
+            tape,
+            segments,
+            errors,
+            statistics: crate::format_dson::BatchStatistics {
+                total_lines: total,
+                successful_lines: successful,
+                failed_lines: failed,
+                processing_time_ms: start.elapsed().as_secs_f64() * 1000.0,
+                avg_memory_per_line: data.len().checked_div(successful).unwrap_or(0),
+                overall_schema_match_ratio: ratio,
+            },
+        };
+        (result, storage)
+    }
+
+    /// Whether any top-level member of a record is selected by `schema`
+    fn record_matches(nodes: &[Node<'_>], schema: &CompiledSchema) -> bool {
+        let Some(Node::Object { len, .. }) = nodes.first() else {
+            return false;
+        };
+        let mut idx = 1;
+        for _ in 0..*len {
+            let Node::String(key) = nodes[idx] else {
+                return false;
+            };
+            let value = &nodes[idx + 1];
+            if schema.matches_path(key)
+                || (matches!(value, Node::Object { .. }) && schema.should_include_object(key))
+            {
+                return true;
+            }
+            idx += 1 + Self::node_span(value);
+        }
+        false
+    }
+
+    /// Number of tape nodes taken by a value, including nested ones
+    const fn node_span(node: &Node<'_>) -> usize {
+        match node {
+            Node::Object { count, .. } | Node::Array { count, .. } => *count + 1,
+            Node::String(_) | Node::Static(_) => 1,
+        }
+    }
+
+    /// Emit the simd-json value at `idx` onto `tape`, returning the index past it
+    ///
+    /// With `filter` set, object members are kept only when their dotted path
+    /// (accumulated in `path`) matches `schema` or leads to a matching child.
+    fn emit_json_value(
+        nodes: &[Node<'_>],
+        idx: usize,
+        depth: u8,
+        tape: &mut UnifiedTape<'_>,
+        schema: &CompiledSchema,
+        path: &mut String,
+        filter: bool,
+    ) -> usize {
+        let node = |node_type| UnifiedNode::new(node_type, FormatKind::Json).with_depth(depth);
+        match nodes[idx] {
+            Node::String(s) => {
+                let value_idx = tape.add_string(s);
+                tape.add_node(node(ExtendedNodeType::String(value_idx)));
+                idx + 1
+            }
+            Node::Static(value) => {
+                #[allow(clippy::cast_precision_loss)] // Unified tape stores numbers as f64
+                let node_type = match value {
+                    StaticNode::Null => ExtendedNodeType::Null,
+                    StaticNode::Bool(b) => ExtendedNodeType::Bool(b),
+                    StaticNode::I64(n) => ExtendedNodeType::Number(n as f64),
+                    StaticNode::U64(n) => ExtendedNodeType::Number(n as f64),
+                    StaticNode::F64(f) => ExtendedNodeType::Number(f),
+                };
+                tape.add_node(node(node_type));
+                idx + 1
+            }
+            Node::Array { len, .. } => {
+                tape.add_node(node(ExtendedNodeType::ArrayStart));
+                let mut next = idx + 1;
+                for _ in 0..len {
+                    next = Self::emit_json_value(
+                        nodes,
+                        next,
+                        depth.saturating_add(1),
+                        tape,
+                        schema,
+                        path,
+                        filter,
+                    );
+                }
+                tape.add_node(node(ExtendedNodeType::ArrayEnd));
+                next
+            }
+            Node::Object { len, .. } => {
+                tape.add_node(node(ExtendedNodeType::ObjectStart));
+                let child_depth = depth.saturating_add(1);
+                let mut next = idx + 1;
+                for _ in 0..len {
+                    let key = if let Node::String(key) = nodes[next] {
+                        key
+                    } else {
+                        ""
+                    };
+                    let value_idx = next + 1;
+
+                    let prefix_len = path.len();
+                    if filter {
+                        if prefix_len > 0 {
+                            path.push('.');
+                        }
+                        path.push_str(key);
+                    }
+                    let keep_all = !filter || schema.matches_path(path);
+                    let descend = !keep_all
+                        && matches!(nodes[value_idx], Node::Object { .. })
+                        && schema.should_include_object(path);
+
+                    if keep_all || descend {
+                        let key_idx = tape.add_string(key);
+                        tape.add_node(
+                            UnifiedNode::new(ExtendedNodeType::Key(key_idx), FormatKind::Json)
+                                .with_depth(child_depth),
+                        );
+                        next = Self::emit_json_value(
+                            nodes,
+                            value_idx,
+                            child_depth,
+                            tape,
+                            schema,
+                            path,
+                            !keep_all,
+                        );
+                    } else {
+                        tape.add_node(
+                            UnifiedNode::new(ExtendedNodeType::SkipMarker, FormatKind::Json)
+                                .with_depth(child_depth),
+                        );
+                        tape.metadata.skipped_count += 1;
+                        next = value_idx + Self::node_span(&nodes[value_idx]);
+                    }
+                    path.truncate(prefix_len);
+                }
+                tape.add_node(node(ExtendedNodeType::ObjectEnd));
+                next
+            }
+        }
+    }
+
     #[allow(clippy::needless_pass_by_ref_mut)] // Will mutate GPU state in future
     fn line_boundaries(&mut self, data: &[u8]) -> Vec<usize> {
         if let Some(boundaries) = self.gpu_line_boundaries(data) {
@@ -837,3 +1355,415 @@ impl SimdJsonlBatchProcessor {
         None
     }
 }
+
+// =============================================================================
+// Streaming record reader
+// =============================================================================
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Skip spaces, tabs and carriage returns; returns the next byte, if any
+fn peek_byte<R: BufRead>(reader: &mut R) -> io::Result<Option<u8>> {
+    loop {
+        let chunk = reader.fill_buf()?;
+        let Some(&byte) = chunk.first() else {
+            return Ok(None);
+        };
+        if !matches!(byte, b' ' | b'\t' | b'\r') {
+            return Ok(Some(byte));
+        }
+        reader.consume(1);
+    }
+}
+
+/// Consume the rest of the current line, newline included
+fn discard_line<R: BufRead>(reader: &mut R) -> io::Result<()> {
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        if let Some(pos) = memchr::memchr(b'\n', chunk) {
+            reader.consume(pos + 1);
+            return Ok(());
+        }
+        let len = chunk.len();
+        reader.consume(len);
+    }
+}
+
+/// Read one line into `out`, dropping object members `schema` does not select
+///
+/// Blank lines add nothing. Returns `false` at the end of input.
+fn read_record<R: BufRead>(
+    reader: &mut R,
+    schema: &CompiledSchema,
+    out: &mut Vec<u8>,
+    key: &mut Vec<u8>,
+) -> io::Result<bool> {
+    match peek_byte(reader)? {
+        None => return Ok(false),
+        Some(b'\n') => {
+            reader.consume(1);
+            return Ok(true);
+        }
+        Some(b'{') if !schema.include_patterns.is_empty() => {
+            reader.consume(1);
+            read_object(reader, schema, out, key)?;
+        }
+        Some(_) => {
+            ResumableSkip::value().copy_reader(reader, out)?;
+        }
+    }
+    match peek_byte(reader)? {
+        None => {}
+        Some(b'\n') => reader.consume(1),
+        Some(_) => return Err(invalid("trailing characters after record")),
+    }
+    Ok(true)
+}
+
+/// Read an object whose `{` was consumed, copying the selected members
+fn read_object<R: BufRead>(
+    reader: &mut R,
+    schema: &CompiledSchema,
+    out: &mut Vec<u8>,
+    key: &mut Vec<u8>,
+) -> io::Result<()> {
+    out.push(b'{');
+    let mut kept = 0;
+    if peek_byte(reader)? == Some(b'}') {
+        reader.consume(1);
+        out.push(b'}');
+        return Ok(());
+    }
+    loop {
+        if peek_byte(reader)? != Some(b'"') {
+            return Err(invalid("expected object key"));
+        }
+        reader.consume(1);
+        key.clear();
+        key.push(b'"');
+        ResumableSkip::string().copy_reader(reader, key)?;
+        if peek_byte(reader)? != Some(b':') {
+            return Err(invalid("expected ':' after object key"));
+        }
+        reader.consume(1);
+
+        let name: String = serde_json::from_slice(key).map_err(|e| invalid(&e.to_string()))?;
+        if schema.matches_path(&name) || schema.should_include_object(&name) {
+            if kept > 0 {
+                out.push(b',');
+            }
+            kept += 1;
+            out.extend_from_slice(key);
+            out.push(b':');
+            peek_byte(reader)?;
+            ResumableSkip::value().copy_reader(reader, out)?;
+        } else {
+            ResumableSkip::value().skip_reader(reader)?;
+        }
+
+        match peek_byte(reader)? {
+            Some(b',') => reader.consume(1),
+            Some(b'}') => {
+                reader.consume(1);
+                out.push(b'}');
+                return Ok(());
+            }
+            _ => return Err(invalid("expected ',' or '}' in object")),
+        }
+    }
+}
+
+// =============================================================================
+// FormatBatchProcessor Implementation
+// =============================================================================
+
+impl FormatBatchProcessor for SimdJsonlBatchProcessor {
+    fn format_kind(&self) -> FormatKind {
+        FormatKind::Json
+    }
+
+    fn process_batch(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+    ) -> fionn_core::Result<FormatBatchResult> {
+        let result = self
+            .process_batch_optimized(data, schema)
+            .map_err(|e| fionn_core::DsonError::ParseError(e.to_string()))?;
+
+        Ok(FormatBatchResult {
+            documents: result.documents,
+            errors: result
+                .errors
+                .into_iter()
+                .map(|e| crate::format_dson::LineError {
+                    line_index: e.line_index,
+                    error_message: e.error.to_string(),
+                    raw_line: e.raw_line,
+                })
+                .collect(),
+            statistics: crate::format_dson::BatchStatistics {
+                total_lines: result.statistics.total_lines,
+                successful_lines: result.statistics.successful_lines,
+                failed_lines: result.statistics.failed_lines,
+                processing_time_ms: result.statistics.processing_time_ms,
+                avg_memory_per_line: result.statistics.avg_memory_per_line,
+                overall_schema_match_ratio: result.statistics.overall_schema_match_ratio,
+            },
+        })
+    }
+
+    fn process_batch_unfiltered(&mut self, data: &[u8]) -> fionn_core::Result<FormatBatchResult> {
+        let schema = CompiledSchema::compile(&[])
+            .map_err(|e| fionn_core::DsonError::ParseError(e.to_string()))?;
+        FormatBatchProcessor::process_batch(self, data, &schema)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self);
+    }
+}
+
+// =============================================================================
+// TapeBatchProcessor Implementation
+// =============================================================================
+
+impl<'arena> TapeBatchProcessor<'arena> for SimdJsonlBatchProcessor {
+    fn format_kind(&self) -> FormatKind {
+        FormatKind::Json
+    }
+
+    fn process_to_tape(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = UnifiedTape::new(arena, FormatKind::Json);
+        let mut scratch = Vec::new();
+        let (result, _) = self.fill_unified_tape(
+            data,
+            schema,
+            tape,
+            Vec::new(),
+            TapeStorage::new(),
+            &mut scratch,
+        );
+        Ok(result)
+    }
+
+    fn process_to_tape_unfiltered(
+        &mut self,
+        data: &[u8],
+        arena: &'arena bumpalo::Bump,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let schema = CompiledSchema::compile(&[])
+            .map_err(|e| fionn_core::DsonError::ParseError(e.to_string()))?;
+        self.process_to_tape(data, &schema, arena)
+    }
+
+    fn process_to_tape_pooled(
+        &mut self,
+        data: &[u8],
+        schema: &CompiledSchema,
+        arena: &'arena bumpalo::Bump,
+        pool: &TapeBuilderPool,
+    ) -> fionn_core::Result<TapeBatchResult<'arena>> {
+        let tape = pool.unified_tape(arena, FormatKind::Json);
+        let mut scratch = pool.scratch();
+        let (result, json_tape) = self.fill_unified_tape(
+            data,
+            schema,
+            tape,
+            pool.segments(),
+            pool.json_tape(),
+            &mut scratch,
+        );
+        pool.release_json_tape(json_tape);
+        pool.release_scratch(scratch);
+        Ok(result)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENTS: &[u8] = b"{\"status\": \"error\", \"latency\": 900}\n\
+        {\"status\": \"ok\", \"latency\": 12}\n\
+        \n\
+        {\"status\": \"error\", \"latency\": 40}\n\
+        {\"status\": \"error\", \"latency\": 800\n";
+
+    #[test]
+    fn test_prefiltered_batch_keeps_matching_lines() {
+        let prefilter = Prefilter::parse("latency > 500").unwrap();
+        let mut processor = SimdJsonlBatchProcessor::new();
+        let result = processor
+            .process_batch_prefiltered(EVENTS, &prefilter)
+            .unwrap();
+        assert_eq!(result.documents, [r#"{"status": "error", "latency": 900}"#]);
+        // The truncated last line cannot be decided from bytes and fails to parse
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].line_index, 4);
+        assert_eq!(result.statistics.prefilter_hits, 3);
+        assert_eq!(result.statistics.prefilter_misses, 1);
+        assert!((result.statistics.prefilter_hit_rate() - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_prefiltered_batch_reports_malformed_decided_lines() {
+        let prefilter = Prefilter::parse("latency > 500").unwrap();
+        let mut processor = SimdJsonlBatchProcessor::new();
+        let data = b"{\"latency\": 900, \"tags\": [1,, 2]}\n\
+            {\"latency\": 1, \"tags\": [1,, 2]}\n\
+            {\"latency\": 700}\n";
+        let result = processor
+            .process_batch_prefiltered(data, &prefilter)
+            .unwrap();
+        assert_eq!(result.documents, [r#"{"latency": 700}"#]);
+        let failed: Vec<_> = result.errors.iter().map(|e| e.line_index).collect();
+        assert_eq!(failed, [0, 1]);
+        assert_eq!(result.statistics.failed_lines, 2);
+        assert_eq!(result.statistics.prefilter_hits, 3);
+    }
+
+    #[test]
+    fn test_limits_fail_offending_lines() {
+        let limits = ParseLimits::strict().with_max_string_len(8);
+        let mut processor = SimdJsonlBatchProcessor::new().with_limits(limits);
+        let data = b"{\"a\": 1}\n{\"a\": 1, \"a\": 2}\n[\"much too long\"]\n[[1]]\n";
+        let schema = CompiledSchema::compile(&[]).unwrap();
+        let result = processor.process_batch_optimized(data, &schema).unwrap();
+        assert_eq!(result.documents, [r#"{"a": 1}"#, "[[1]]"]);
+        let failed: Vec<_> = result.errors.iter().map(|e| e.line_index).collect();
+        assert_eq!(failed, [1, 2]);
+        assert!(matches!(
+            result.errors[0].error,
+            SkipTapeError::ValidationError(_)
+        ));
+        assert_eq!(result.statistics.failed_lines, 2);
+
+        let arena = bumpalo::Bump::new();
+        let result = processor.process_to_tape_unfiltered(data, &arena).unwrap();
+        assert_eq!(result.segment_count(), 2);
+        assert_eq!(result.statistics.failed_lines, 2);
+    }
+
+    #[test]
+    fn test_read_batch_skips_unselected_members() {
+        let huge = "x".repeat(100_000);
+        let input = format!(
+            "{{\"id\": 1, \"blob\": {{\"data\": [\"{huge}\", {{\"k\": \"}}\"}}]}}, \"age\": 30}}\n\
+             \n\
+             {{\"id\": 2, \"blob\": }}\n\
+             {{\"id\": 3}}\n"
+        );
+        let mut reader = io::BufReader::with_capacity(256, input.as_bytes());
+        let schema = CompiledSchema::compile(&["id".to_string(), "age".to_string()]).unwrap();
+        let mut processor = SimdJsonlBatchProcessor::new();
+
+        let result = processor
+            .read_batch(&mut reader, &schema, 1 << 20)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.documents, [r#"{"id":1,"age":30}"#, r#"{"id":3}"#]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].line_index, 2);
+        assert_eq!(result.statistics.failed_lines, 1);
+        assert!(
+            processor
+                .read_batch(&mut reader, &schema, 1 << 20)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_process_to_tape_emits_records() {
+        let arena = bumpalo::Bump::new();
+        let mut processor = SimdJsonlBatchProcessor::new();
+        let data = b"{\"a\": [1, {\"b\": null}], \"c\": \"x\"}\n\n[true]\n{bad\n";
+        let result = processor.process_to_tape_unfiltered(data, &arena).unwrap();
+
+        assert_eq!(result.segment_count(), 2);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].line_index, 3);
+
+        let first: Vec<_> = result
+            .segment(0)
+            .unwrap()
+            .nodes()
+            .iter()
+            .map(|n| n.node_type.clone())
+            .collect();
+        assert_eq!(first.len(), 12);
+        assert_eq!(first[0], ExtendedNodeType::ObjectStart);
+        assert_eq!(first[3], ExtendedNodeType::Number(1.0));
+        assert_eq!(first[6], ExtendedNodeType::Null);
+        assert_eq!(first[11], ExtendedNodeType::ObjectEnd);
+        assert_eq!(result.tape.get_string(1), Some("b"));
+        assert_eq!(result.segments[1].source_idx, 2);
+    }
+
+    #[test]
+    fn test_process_to_tape_filters_members() {
+        let arena = bumpalo::Bump::new();
+        let mut processor = SimdJsonlBatchProcessor::new();
+        let schema = CompiledSchema::compile(&["user.name".to_string()]).unwrap();
+        let data = b"{\"user\": {\"name\": \"n\", \"age\": 3}, \"x\": [1, 2]}\n{\"y\": 1}\n";
+        let result = processor.process_to_tape(data, &schema, &arena).unwrap();
+
+        assert_eq!(result.segment_count(), 1);
+        assert_eq!(result.statistics.total_lines, 2);
+        // Within the record only `age` and `x` are skipped
+        let skipped = result
+            .segment(0)
+            .unwrap()
+            .nodes()
+            .iter()
+            .filter(|n| n.node_type == ExtendedNodeType::SkipMarker)
+            .count();
+        assert_eq!(skipped, 2);
+        assert_eq!(result.tape.metadata.skipped_count, 3);
+    }
+
+    #[test]
+    fn test_pooled_tape_matches_unpooled() {
+        let pool = TapeBuilderPool::default();
+        let mut processor = SimdJsonlBatchProcessor::new();
+        let schema = CompiledSchema::compile(&[]).unwrap();
+
+        for _ in 0..3 {
+            let arena = pool.acquire_arena();
+            let pooled = processor
+                .process_to_tape_pooled(EVENTS, &schema, &arena, &pool)
+                .unwrap();
+            let fresh_arena = bumpalo::Bump::new();
+            let fresh = processor
+                .process_to_tape(EVENTS, &schema, &fresh_arena)
+                .unwrap();
+
+            assert_eq!(pooled.tape.nodes().len(), fresh.tape.nodes().len());
+            assert_eq!(pooled.segment_count(), 3);
+            assert_eq!(pooled.errors.len(), 1);
+            pool.recycle(pooled);
+            pool.release_arena(arena);
+        }
+        assert_eq!(pool.unified_stats().reuses, 2);
+    }
+
+    #[test]
+    fn test_hit_rate_without_prefilter() {
+        assert!(BatchStatistics::default().prefilter_hit_rate().abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,300 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Pooled tape builders and arenas for steady-state batch processing
+//!
+//! A [`TapeBuilderPool`] hands out bump arenas, [`UnifiedTape`] and
+//! [`SkipTape`] storage, segment vectors and simd-json tape storage. Batch
+//! processors draw from it in
+//! [`TapeBatchProcessor::process_to_tape_pooled`](crate::format_dson::TapeBatchProcessor::process_to_tape_pooled)
+//! and the caller hands the result back with [`TapeBuilderPool::recycle`],
+//! so once the pool has warmed up a batch of similar size reuses every
+//! allocation made by the previous one.
+
+use crate::format_dson::{SegmentBoundary, TapeBatchResult};
+use crate::skiptape::tape::{SkipTape, SkipTapeStorage};
+use crate::skiptape::unified_tape::{UnifiedTape, UnifiedTapeStorage};
+use bumpalo::Bump;
+use fionn_core::format::FormatKind;
+use fionn_pool::{ArenaPool, ObjectPool, PoolStats, PoolStrategy, Reusable, TapeStorage};
+use std::collections::HashMap;
+
+/// Clear string storage and detach it from its arena lifetime
+pub(crate) fn detach_strings(
+    mut strings: Vec<&str>,
+    mut dedup_map: HashMap<&str, u32>,
+) -> (Vec<&'static str>, HashMap<&'static str, u32>) {
+    strings.clear();
+    dedup_map.clear();
+    // SAFETY: both collections are empty, so no borrowed data outlives the
+    // arena; only the lifetime parameter changes, which does not affect layout
+    unsafe {
+        (
+            std::mem::transmute::<Vec<&str>, Vec<&'static str>>(strings),
+            std::mem::transmute::<HashMap<&str, u32>, HashMap<&'static str, u32>>(dedup_map),
+        )
+    }
+}
+
+impl Reusable for UnifiedTapeStorage {
+    fn reset(&mut self) {
+        self.nodes.clear();
+        self.strings.clear();
+        self.dedup_map.clear();
+        // `syntax_cache` is kept for the next tape to intern from
+        self.original_syntax.clear();
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.nodes.retained_bytes() + self.strings.retained_bytes()
+    }
+}
+
+impl Reusable for SkipTapeStorage {
+    fn reset(&mut self) {
+        self.nodes.clear();
+        self.strings.clear();
+        self.dedup_map.clear();
+    }
+
+    fn retained_bytes(&self) -> usize {
+        self.nodes.retained_bytes() + self.strings.retained_bytes()
+    }
+}
+
+/// Pool of arenas and tape builder storage for batch processors
+#[derive(Debug)]
+pub struct TapeBuilderPool {
+    arenas: ArenaPool,
+    unified: ObjectPool<UnifiedTapeStorage>,
+    skip: ObjectPool<SkipTapeStorage>,
+    segments: ObjectPool<Vec<SegmentBoundary>>,
+    json_tapes: ObjectPool<TapeStorage>,
+    scratch: ObjectPool<Vec<u8>>,
+}
+
+impl TapeBuilderPool {
+    /// Create a pool whose free lists all follow `strategy`
+    #[must_use]
+    pub fn new(strategy: PoolStrategy) -> Self {
+        Self {
+            arenas: ObjectPool::new(strategy.clone()),
+            unified: ObjectPool::new(strategy.clone()),
+            skip: ObjectPool::new(strategy.clone()),
+            segments: ObjectPool::new(strategy.clone()),
+            json_tapes: ObjectPool::new(strategy.clone()),
+            scratch: ObjectPool::new(strategy),
+        }
+    }
+
+    /// Take a reset arena from the pool
+    pub fn acquire_arena(&self) -> Bump {
+        self.arenas.acquire()
+    }
+
+    /// Reset an arena and return it to the pool
+    ///
+    /// Everything allocated in the arena must be dropped first, including
+    /// tapes built on it (recycle them with [`TapeBuilderPool::recycle`]).
+    pub fn release_arena(&self, arena: Bump) {
+        self.arenas.release(arena);
+    }
+
+    /// Run `f` with a pooled arena, returning the arena afterwards
+    pub fn with_arena<R>(&self, f: impl FnOnce(&Bump) -> R) -> R {
+        let arena = self.acquire_arena();
+        let result = f(&arena);
+        self.release_arena(arena);
+        result
+    }
+
+    /// Build a unified tape on pooled storage
+    #[must_use]
+    pub fn unified_tape<'arena>(
+        &self,
+        arena: &'arena Bump,
+        format: FormatKind,
+    ) -> UnifiedTape<'arena> {
+        UnifiedTape::from_storage(arena, format, self.unified.acquire())
+    }
+
+    /// Build a skip tape on pooled storage
+    #[must_use]
+    pub fn skip_tape<'arena>(&self, arena: &'arena Bump) -> SkipTape<'arena> {
+        SkipTape::from_storage(arena, self.skip.acquire())
+    }
+
+    /// Take an empty segment vector from the pool
+    #[must_use]
+    pub fn segments(&self) -> Vec<SegmentBoundary> {
+        self.segments.acquire()
+    }
+
+    /// Take simd-json tape storage from the pool
+    #[must_use]
+    pub fn json_tape(&self) -> TapeStorage {
+        self.json_tapes.acquire()
+    }
+
+    /// Return simd-json tape storage to the pool
+    pub fn release_json_tape(&self, storage: TapeStorage) {
+        self.json_tapes.release(storage);
+    }
+
+    /// Take an empty byte scratch buffer from the pool
+    #[must_use]
+    pub fn scratch(&self) -> Vec<u8> {
+        self.scratch.acquire()
+    }
+
+    /// Return a byte scratch buffer to the pool
+    pub fn release_scratch(&self, scratch: Vec<u8>) {
+        self.scratch.release(scratch);
+    }
+
+    /// Return a unified tape's storage to the pool
+    pub fn recycle_unified(&self, tape: UnifiedTape<'_>) {
+        self.unified.release(tape.into_storage());
+    }
+
+    /// Return a skip tape's storage to the pool
+    pub fn recycle_skip(&self, tape: SkipTape<'_>) {
+        self.skip.release(tape.into_storage());
+    }
+
+    /// Return a batch result's tape and segment storage to the pool
+    pub fn recycle(&self, result: TapeBatchResult<'_>) {
+        self.recycle_unified(result.tape);
+        self.segments.release(result.segments);
+    }
+
+    /// Statistics for the arena free list
+    #[must_use]
+    pub fn arena_stats(&self) -> PoolStats {
+        self.arenas.stats()
+    }
+
+    /// Statistics for the unified tape storage free list
+    #[must_use]
+    pub fn unified_stats(&self) -> PoolStats {
+        self.unified.stats()
+    }
+
+    /// Statistics for the skip tape storage free list
+    #[must_use]
+    pub fn skip_stats(&self) -> PoolStats {
+        self.skip.stats()
+    }
+
+    /// Drop every pooled arena and builder
+    pub fn clear(&self) {
+        self.arenas.clear();
+        self.unified.clear();
+        self.skip.clear();
+        self.segments.clear();
+        self.json_tapes.clear();
+        self.scratch.clear();
+    }
+}
+
+impl Default for TapeBuilderPool {
+    fn default() -> Self {
+        Self::new(PoolStrategy::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skiptape::tape::SkipNode;
+    use crate::skiptape::unified_tape::{ExtendedNodeType, UnifiedNode};
+
+    #[test]
+    fn test_unified_tape_storage_round_trip() {
+        let pool = TapeBuilderPool::default();
+
+        pool.with_arena(|arena| {
+            let mut tape = pool.unified_tape(arena, FormatKind::Json);
+            let idx = tape.add_string("name");
+            tape.add_node(UnifiedNode::new(
+                ExtendedNodeType::Key(idx),
+                FormatKind::Json,
+            ));
+            assert_eq!(tape.add_string("name"), idx);
+            pool.recycle_unified(tape);
+        });
+
+        pool.with_arena(|arena| {
+            let mut tape = pool.unified_tape(arena, FormatKind::Json);
+            assert!(tape.nodes().is_empty());
+            assert_eq!(tape.metadata.source_format, FormatKind::Json);
+            assert_eq!(tape.add_string("other"), 0);
+            assert_eq!(tape.get_string(0), Some("other"));
+            pool.recycle_unified(tape);
+        });
+
+        let stats = pool.unified_stats();
+        assert_eq!(stats.reuses, 1);
+        assert_eq!(pool.arena_stats().reuses, 1);
+    }
+
+    #[test]
+    fn test_interned_syntax_reused_across_tapes() {
+        use crate::skiptape::unified_tape::OriginalSyntax;
+
+        let pool = TapeBuilderPool::default();
+        let is_tag = |syntax: &OriginalSyntax| matches!(syntax, OriginalSyntax::YamlTag { tag } if tag == "!x");
+        let make = || OriginalSyntax::YamlTag {
+            tag: "!x".to_string(),
+        };
+
+        for _ in 0..2 {
+            pool.with_arena(|arena| {
+                let mut tape = pool.unified_tape(arena, FormatKind::Json);
+                let first = tape.intern_original_syntax(is_tag, make);
+                let second = tape.intern_original_syntax(is_tag, || unreachable!());
+                assert_eq!(first, second);
+                assert_eq!(tape.original_syntax.len(), 1);
+                assert_eq!(tape.metadata.preserved_syntax_count, 2);
+                pool.recycle_unified(tape);
+            });
+        }
+
+        // The second tape took the entry from the first tape's storage
+        pool.with_arena(|arena| {
+            let mut tape = pool.unified_tape(arena, FormatKind::Json);
+            tape.intern_original_syntax(is_tag, || unreachable!());
+            pool.recycle_unified(tape);
+        });
+    }
+
+    #[test]
+    fn test_skip_tape_storage_round_trip() {
+        let pool = TapeBuilderPool::default();
+
+        pool.with_arena(|arena| {
+            let mut tape = pool.skip_tape(arena);
+            tape.add_node(SkipNode::object_start());
+            tape.add_string("key");
+            pool.recycle_skip(tape);
+        });
+
+        pool.with_arena(|arena| {
+            let mut tape = pool.skip_tape(arena);
+            assert!(tape.nodes().is_empty());
+            assert_eq!(tape.metadata().node_count, 0);
+            assert_eq!(tape.add_string("fresh"), 0);
+            pool.recycle_skip(tape);
+        });
+
+        assert_eq!(pool.skip_stats().reuses, 1);
+    }
+
+    #[test]
+    fn test_clear_drops_pooled_storage() {
+        let pool = TapeBuilderPool::new(PoolStrategy::Unbounded);
+        pool.with_arena(|arena| pool.recycle_unified(pool.unified_tape(arena, FormatKind::Json)));
+        pool.clear();
+
+        assert_eq!(pool.unified_stats().buffers_in_pool, 0);
+        assert_eq!(pool.arena_stats().buffers_in_pool, 0);
+    }
+}
@@ -8,6 +8,9 @@ use crate::skiptape::error::{Result, SkipTapeError};
 use crate::skiptape::schema::CompiledSchema;
 use crate::skiptape::tape::{SkipNode, SkipTape};
 use bumpalo::Bump;
+use fionn_core::number::{self, NumberValue};
+use fionn_simd::unescape::unescape_json_string_into;
+use std::borrow::Cow;
 
 /// Main processor for SIMD-JSONL skip tape generation
 pub struct SkipTapeProcessor {
@@ -147,7 +150,7 @@ impl SkipTapeProcessor {
                     // String value - check if it's a field name or value
                     if Self::is_field_name(bytes, index) {
                         let field_name = Self::parse_string(bytes, &mut index)?;
-                        path_stack.push(field_name.clone());
+                        path_stack.push(field_name.into_owned());
 
                         // Check if this field should be included
                         let current_path = Self::build_path_string(&path_stack);
@@ -179,7 +182,7 @@ impl SkipTapeProcessor {
                 b'0'..=b'9' | b'-' => {
                     // Number value
                     let number_value = Self::parse_number(bytes, &mut index)?;
-                    skip_tape.add_node(SkipNode::number(number_value).with_depth(depth));
+                    skip_tape.add_node(SkipNode::from_number(number_value).with_depth(depth));
                 }
                 b',' | b':' | b' ' | b'\t' | b'\n' | b'\r' => {
                     // Structural or whitespace - skip
@@ -210,9 +213,11 @@ impl SkipTapeProcessor {
         false
     }
 
-    /// Parse a JSON string and return the string value
-    fn parse_string(bytes: &[u8], index: &mut usize) -> Result<String> {
-        // Simple string parsing - in a real implementation this would handle escapes
+    /// Parse a JSON string and return its unescaped value
+    ///
+    /// Strings without escapes are borrowed from the input; the caller copies
+    /// the result into the tape's arena either way.
+    fn parse_string<'a>(bytes: &'a [u8], index: &mut usize) -> Result<Cow<'a, str>> {
         let start = *index + 1; // Skip opening quote
         let mut end = start;
 
@@ -227,10 +232,20 @@ impl SkipTapeProcessor {
             return Err(SkipTapeError::ParseError("Unterminated string".to_string()));
         }
 
-        let string_bytes = &bytes[start..end];
-        let string_value = std::str::from_utf8(string_bytes)
-            .map_err(|e| SkipTapeError::ParseError(format!("Invalid UTF-8 in string: {e}")))?
-            .to_string();
+        let raw = &bytes[start..end];
+        let string_value =
+            if memchr::memchr(b'\\', raw).is_none() {
+                Cow::Borrowed(std::str::from_utf8(raw).map_err(|e| {
+                    SkipTapeError::ParseError(format!("Invalid UTF-8 in string: {e}"))
+                })?)
+            } else {
+                let mut unescaped = Vec::with_capacity(raw.len());
+                unescape_json_string_into(raw, &mut unescaped)
+                    .map_err(|e| SkipTapeError::ParseError(format!("Invalid string: {e}")))?;
+                Cow::Owned(String::from_utf8(unescaped).map_err(|e| {
+                    SkipTapeError::ParseError(format!("Invalid UTF-8 in string: {e}"))
+                })?)
+            };
 
         *index = end; // Update index to after closing quote
         Ok(string_value)
@@ -249,8 +264,8 @@ impl SkipTapeProcessor {
         }
     }
 
-    /// Parse a number value
-    fn parse_number(bytes: &[u8], index: &mut usize) -> Result<f64> {
+    /// Parse a number value once into its typed form
+    fn parse_number(bytes: &[u8], index: &mut usize) -> Result<NumberValue> {
         let start = *index;
         while *index < bytes.len()
             && (bytes[*index].is_ascii_digit()
@@ -263,12 +278,13 @@ impl SkipTapeProcessor {
             *index += 1;
         }
 
-        let number_str = std::str::from_utf8(&bytes[start..*index])
-            .map_err(|e| SkipTapeError::ParseError(format!("Invalid UTF-8 in number: {e}")))?;
-
-        number_str
-            .parse()
-            .map_err(|e| SkipTapeError::ParseError(format!("Invalid number: {e}")))
+        let lexeme = &bytes[start..*index];
+        number::parse_number(lexeme).ok_or_else(|| {
+            SkipTapeError::ParseError(format!(
+                "Invalid number: {}",
+                String::from_utf8_lossy(lexeme)
+            ))
+        })
     }
 
     /// Skip a JSON value
@@ -531,6 +547,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_process_line_unescapes_into_arena() {
+        let mut processor = SkipTapeProcessor::new();
+        let schema = CompiledSchema::compile(&["*".to_string()]).unwrap();
+        let tape = processor
+            .process_line(r#"["tab\tand \u00e9"]"#, &schema)
+            .unwrap();
+        assert_eq!(tape.strings.strings, ["tab\tand \u{e9}"]);
+    }
+
+    #[test]
+    fn test_process_line_typed_numbers() {
+        let mut processor = SkipTapeProcessor::new();
+        let schema = CompiledSchema::compile(&["*".to_string()]).unwrap();
+        let tape = processor
+            .process_line("[-3, 18446744073709551615, 0.5]", &schema)
+            .unwrap();
+        let numbers: Vec<_> = tape
+            .nodes()
+            .iter()
+            .filter_map(SkipNode::number_value)
+            .collect();
+        assert_eq!(
+            numbers,
+            [
+                NumberValue::I64(-3),
+                NumberValue::U64(u64::MAX),
+                NumberValue::F64(0.5)
+            ]
+        );
+    }
+
     #[test]
     fn test_process_line_scientific_notation() {
         let mut processor = SkipTapeProcessor::new();
@@ -696,7 +744,14 @@ mod tests {
         let bytes = b"\"he\\\"llo\"";
         let mut index = 0;
         let result = SkipTapeProcessor::parse_string(bytes, &mut index);
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "he\"llo");
+    }
+
+    #[test]
+    fn test_parse_string_invalid_escape() {
+        let bytes = b"\"bad \\x\"";
+        let mut index = 0;
+        assert!(SkipTapeProcessor::parse_string(bytes, &mut index).is_err());
     }
 
     #[test]
@@ -744,7 +799,7 @@ mod tests {
         let mut index = 0;
         let result = SkipTapeProcessor::parse_number(bytes, &mut index);
         assert!(result.is_ok());
-        assert!((result.unwrap() - 123.0).abs() < f64::EPSILON);
+        assert_eq!(result.unwrap(), NumberValue::U64(123));
     }
 
     #[test]
@@ -754,7 +809,7 @@ mod tests {
         let mut index = 0;
         let result = SkipTapeProcessor::parse_number(bytes, &mut index);
         assert!(result.is_ok());
-        assert!((result.unwrap() - (-456.0)).abs() < f64::EPSILON);
+        assert_eq!(result.unwrap(), NumberValue::I64(-456));
     }
 
     #[test]
@@ -764,7 +819,7 @@ mod tests {
         let mut index = 0;
         let result = SkipTapeProcessor::parse_number(bytes, &mut index);
         assert!(result.is_ok());
-        assert!((result.unwrap() - 1.23456).abs() < 0.001);
+        assert!((result.unwrap().as_f64() - 1.23456).abs() < 0.001);
     }
 
     #[test]
@@ -774,7 +829,7 @@ mod tests {
         let mut index = 0;
         let result = SkipTapeProcessor::parse_number(bytes, &mut index);
         assert!(result.is_ok());
-        assert!((result.unwrap() - 1.5e10).abs() < 1.0);
+        assert!((result.unwrap().as_f64() - 1.5e10).abs() < 1.0);
     }
 
     #[test]
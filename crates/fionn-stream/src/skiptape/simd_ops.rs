@@ -151,19 +151,11 @@ impl SimdLineSeparator {
     }
 
     /// Detect line boundaries in a data chunk using SIMD
+    ///
+    /// Uses the line-split kernel chosen by the `fionn_core::simd` table.
     #[must_use]
     pub fn find_line_boundaries(&self, data: &[u8]) -> Vec<usize> {
-        // Use memchr iterator which exploits SIMD for finding byte occurrences
-        let mut boundaries: Vec<usize> = memchr::memchr_iter(b'\n', data)
-            .map(|pos| pos + 1) // Position after the \n
-            .collect();
-
-        // If data doesn't end with \n, add the end position
-        if !data.is_empty() && data[data.len() - 1] != b'\n' {
-            boundaries.push(data.len());
-        }
-
-        boundaries
+        fionn_simd::SimdLineSeparator::new().find_line_boundaries(data)
     }
 }
 
@@ -201,165 +193,21 @@ impl SimdStructuralFilter {
     }
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-use std::arch::x86_64::{
-    __m256i, _mm256_and_si256, _mm256_andnot_si256, _mm256_cmpeq_epi8, _mm256_cmpgt_epi8,
-    _mm256_movemask_epi8, _mm256_or_si256, _mm256_set1_epi8,
-};
-
 impl SimdCharClassifier {
-    /// Classify characters in a 64-byte chunk using SIMD (AVX2 optimized)
+    /// Classify characters in a 64-byte chunk
     ///
-    /// # Safety
-    /// Uses SIMD intrinsics that are safe on `x86`/`x86_64` with AVX2 support.
-    /// The `_mm256_loadu_si256` intrinsic handles unaligned loads safely.
-    ///
-    /// # Panics
-    /// This function does not panic. The `try_into().unwrap()` calls are
-    /// guaranteed to succeed because we split a 64-byte array at index 32,
-    /// producing exactly two 32-byte slices.
+    /// Runs the AVX2 or scalar kernel from `fionn_simd`, as chosen by the
+    /// `fionn_core::simd` kernel table.
     #[inline]
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[must_use]
-    #[allow(clippy::too_many_lines)] // SIMD code benefits from inline processing
     pub fn classify_chunk(&self, chunk: &[u8; 64]) -> CharacterClasses {
-        // Helper to convert u8 to i8 for SIMD constants (wrapping is intentional for byte values)
-        const fn to_i8(b: u8) -> i8 {
-            // Use from_ne_bytes for const-safe conversion that avoids the wrap warning
-            i8::from_ne_bytes([b])
-        }
-
-        // Helper to convert i32 movemask result to u32 (reinterpret bits)
-        const fn mask_to_u32(mask: i32) -> u32 {
-            // Use from_ne_bytes to reinterpret bits without sign-related warnings
-            u32::from_ne_bytes(mask.to_ne_bytes())
-        }
-
-        unsafe {
-            // Load 64 bytes into two 256-bit AVX2 registers
-            // We use std::mem::transmute to load the bytes, which is safe because
-            // _mm256_loadu_si256 is designed for unaligned loads.
-            // We avoid direct pointer cast to satisfy clippy's alignment checks.
-            let (first_half, second_half) = chunk.split_at(32);
-            let first_array: &[u8; 32] = first_half.try_into().unwrap();
-            let second_array: &[u8; 32] = second_half.try_into().unwrap();
-
-            // Use transmute to reinterpret the bytes as __m256i
-            // This is safe because we're just reinterpreting bytes
-            let v0: __m256i = std::mem::transmute::<[u8; 32], __m256i>(*first_array);
-            let v1: __m256i = std::mem::transmute::<[u8; 32], __m256i>(*second_array);
-
-            // 1. Whitespace: \t (09), \n (0A), \r (0D), space (20)
-            let space = _mm256_set1_epi8(to_i8(b' '));
-            let tab = _mm256_set1_epi8(to_i8(b'\t'));
-            let lf = _mm256_set1_epi8(to_i8(b'\n'));
-            let cr = _mm256_set1_epi8(to_i8(b'\r'));
-
-            let ws0 = _mm256_or_si256(
-                _mm256_or_si256(_mm256_cmpeq_epi8(v0, space), _mm256_cmpeq_epi8(v0, tab)),
-                _mm256_or_si256(_mm256_cmpeq_epi8(v0, lf), _mm256_cmpeq_epi8(v0, cr)),
-            );
-            let ws1 = _mm256_or_si256(
-                _mm256_or_si256(_mm256_cmpeq_epi8(v1, space), _mm256_cmpeq_epi8(v1, tab)),
-                _mm256_or_si256(_mm256_cmpeq_epi8(v1, lf), _mm256_cmpeq_epi8(v1, cr)),
-            );
-
-            let whitespace_mask = (u64::from(mask_to_u32(_mm256_movemask_epi8(ws1))) << 32)
-                | u64::from(mask_to_u32(_mm256_movemask_epi8(ws0)));
-
-            // 2. Structural: { } [ ] : ,
-            let brace_o = _mm256_set1_epi8(to_i8(b'{'));
-            let brace_c = _mm256_set1_epi8(to_i8(b'}'));
-            let bracket_o = _mm256_set1_epi8(to_i8(b'['));
-            let bracket_c = _mm256_set1_epi8(to_i8(b']'));
-            let colon = _mm256_set1_epi8(to_i8(b':'));
-            let comma = _mm256_set1_epi8(to_i8(b','));
-
-            let struct0 = _mm256_or_si256(
-                _mm256_or_si256(
-                    _mm256_cmpeq_epi8(v0, brace_o),
-                    _mm256_cmpeq_epi8(v0, brace_c),
-                ),
-                _mm256_or_si256(
-                    _mm256_or_si256(
-                        _mm256_cmpeq_epi8(v0, bracket_o),
-                        _mm256_cmpeq_epi8(v0, bracket_c),
-                    ),
-                    _mm256_or_si256(_mm256_cmpeq_epi8(v0, colon), _mm256_cmpeq_epi8(v0, comma)),
-                ),
-            );
-            let struct1 = _mm256_or_si256(
-                _mm256_or_si256(
-                    _mm256_cmpeq_epi8(v1, brace_o),
-                    _mm256_cmpeq_epi8(v1, brace_c),
-                ),
-                _mm256_or_si256(
-                    _mm256_or_si256(
-                        _mm256_cmpeq_epi8(v1, bracket_o),
-                        _mm256_cmpeq_epi8(v1, bracket_c),
-                    ),
-                    _mm256_or_si256(_mm256_cmpeq_epi8(v1, colon), _mm256_cmpeq_epi8(v1, comma)),
-                ),
-            );
-            let structural_mask = (u64::from(mask_to_u32(_mm256_movemask_epi8(struct1))) << 32)
-                | u64::from(mask_to_u32(_mm256_movemask_epi8(struct0)));
-
-            // 3. String: " and \
-            let quote = _mm256_set1_epi8(to_i8(b'"'));
-            let backslash = _mm256_set1_epi8(to_i8(b'\\'));
-
-            let str0 = _mm256_or_si256(
-                _mm256_cmpeq_epi8(v0, quote),
-                _mm256_cmpeq_epi8(v0, backslash),
-            );
-            let str1 = _mm256_or_si256(
-                _mm256_cmpeq_epi8(v1, quote),
-                _mm256_cmpeq_epi8(v1, backslash),
-            );
-            let string_mask = (u64::from(mask_to_u32(_mm256_movemask_epi8(str1))) << 32)
-                | u64::from(mask_to_u32(_mm256_movemask_epi8(str0)));
-
-            // 4. Numbers: 0-9, -, +, .
-            let dot = _mm256_set1_epi8(to_i8(b'.'));
-            let minus = _mm256_set1_epi8(to_i8(b'-'));
-            let plus = _mm256_set1_epi8(to_i8(b'+'));
-
-            // Range check for digits: x >= '0' && x <= '9'
-            // val >= 48 <=> val > 47. val <= 57 <=> !(val > 57)
-            let lower_bound = _mm256_set1_epi8(47);
-            let fifty_seven = _mm256_set1_epi8(57);
-            let all_ones = _mm256_set1_epi8(-1);
-
-            let is_digit0 = _mm256_and_si256(
-                _mm256_cmpgt_epi8(v0, lower_bound),
-                _mm256_andnot_si256(_mm256_cmpgt_epi8(v0, fifty_seven), all_ones),
-            );
-            let is_digit1 = _mm256_and_si256(
-                _mm256_cmpgt_epi8(v1, lower_bound),
-                _mm256_andnot_si256(_mm256_cmpgt_epi8(v1, fifty_seven), all_ones),
-            );
-
-            let num_markers0 = _mm256_or_si256(
-                _mm256_or_si256(_mm256_cmpeq_epi8(v0, dot), _mm256_cmpeq_epi8(v0, minus)),
-                _mm256_cmpeq_epi8(v0, plus),
-            );
-            let num_markers1 = _mm256_or_si256(
-                _mm256_or_si256(_mm256_cmpeq_epi8(v1, dot), _mm256_cmpeq_epi8(v1, minus)),
-                _mm256_cmpeq_epi8(v1, plus),
-            );
-
-            let num0 = _mm256_or_si256(is_digit0, num_markers0);
-            let num1 = _mm256_or_si256(is_digit1, num_markers1);
-
-            let number_mask = (u64::from(mask_to_u32(_mm256_movemask_epi8(num1))) << 32)
-                | u64::from(mask_to_u32(_mm256_movemask_epi8(num0)));
-
-            CharacterClasses {
-                whitespace: whitespace_mask,
-                structural: structural_mask,
-                string_chars: string_mask,
-                numbers: number_mask,
-            }
+        let classes = fionn_simd::x86::SimdCharClassifier::new().classify_chunk(chunk);
+        CharacterClasses {
+            whitespace: classes.whitespace,
+            structural: classes.structural,
+            string_chars: classes.string_chars,
+            numbers: classes.numbers,
         }
     }
 
@@ -377,6 +225,10 @@ impl SimdCharClassifier {
             vreinterpretq_s8_u8,
         };
 
+        if fionn_core::simd::dispatch().classify != fionn_core::simd::SimdLevel::Neon {
+            return classify_chunk_scalar(chunk);
+        }
+
         unsafe {
             // Load 64 bytes as 4x 128-bit NEON vectors
             let v0 = vld1q_u8(chunk.as_ptr());
@@ -530,29 +382,35 @@ impl SimdCharClassifier {
     #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
     #[must_use]
     pub fn classify_chunk(&self, chunk: &[u8; 64]) -> CharacterClasses {
-        let mut whitespace: u64 = 0;
-        let mut structural: u64 = 0;
-        let mut string_chars: u64 = 0;
-        let mut numbers: u64 = 0;
-
-        for (i, &byte) in chunk.iter().enumerate() {
-            let bit = 1u64 << i;
-            match byte {
-                b' ' | b'\t' | b'\n' | b'\r' => whitespace |= bit,
-                b'{' | b'}' | b'[' | b']' | b':' | b',' => structural |= bit,
-                b'"' | b'\\' => string_chars |= bit,
-                b'0'..=b'9' | b'-' | b'+' | b'.' => numbers |= bit,
-                _ => {}
-            }
-        }
+        classify_chunk_scalar(chunk)
+    }
+}
 
-        CharacterClasses {
-            whitespace,
-            structural,
-            string_chars,
-            numbers,
+/// Scalar character classification
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn classify_chunk_scalar(chunk: &[u8; 64]) -> CharacterClasses {
+    let mut whitespace: u64 = 0;
+    let mut structural: u64 = 0;
+    let mut string_chars: u64 = 0;
+    let mut numbers: u64 = 0;
+
+    for (i, &byte) in chunk.iter().enumerate() {
+        let bit = 1u64 << i;
+        match byte {
+            b' ' | b'\t' | b'\n' | b'\r' => whitespace |= bit,
+            b'{' | b'}' | b'[' | b']' | b':' | b',' => structural |= bit,
+            b'"' | b'\\' => string_chars |= bit,
+            b'0'..=b'9' | b'-' | b'+' | b'.' => numbers |= bit,
+            _ => {}
         }
     }
+
+    CharacterClasses {
+        whitespace,
+        structural,
+        string_chars,
+        numbers,
+    }
 }
 
 /// Convert NEON comparison results to a 16-bit bitmask
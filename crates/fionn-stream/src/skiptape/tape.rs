@@ -5,8 +5,11 @@
 //! into the parsing phase, producing compact representations containing only
 //! schema-matching data.
 
+use crate::skiptape::pool::detach_strings;
 use bumpalo::Bump;
 use fionn_core::format::FormatKind;
+use fionn_core::number::NumberValue;
+use std::collections::HashMap;
 
 /// SIMD-aligned skip node (64 bytes for cache efficiency)
 #[repr(C, align(64))]
@@ -40,7 +43,7 @@ pub enum NodeType {
     ArrayEnd = 3,
     /// String value
     String = 4,
-    /// Numeric value
+    /// Floating-point value
     Number = 5,
     /// Boolean value
     Bool = 6,
@@ -48,6 +51,10 @@ pub enum NodeType {
     Null = 7,
     /// Marker for skipped content
     SkipMarker = 8,
+    /// Negative integer value
+    Integer = 9,
+    /// Non-negative integer value
+    Unsigned = 10,
 }
 
 /// Zero-allocation skip tape using arena memory management
@@ -61,6 +68,17 @@ pub struct SkipTape<'arena> {
     pub metadata: SkipMetadata,
 }
 
+/// Recyclable storage behind a [`SkipTape`]
+///
+/// Obtained from [`SkipTape::into_storage`] and turned back into a tape with
+/// [`SkipTape::from_storage`]; every vector keeps its capacity.
+#[derive(Debug, Default)]
+pub struct SkipTapeStorage {
+    pub(crate) nodes: Vec<SkipNode>,
+    pub(crate) strings: Vec<&'static str>,
+    pub(crate) dedup_map: HashMap<&'static str, u32>,
+}
+
 /// Metadata for skip tape statistics and optimization
 #[derive(Debug, Clone)]
 pub struct SkipMetadata {
@@ -84,7 +102,7 @@ pub struct StringArena<'arena> {
     /// Stored strings with their offsets
     pub strings: Vec<&'arena str>,
     /// Map for string deduplication: string content -> offset
-    dedup_map: std::collections::HashMap<&'arena str, u32>,
+    dedup_map: HashMap<&'arena str, u32>,
     /// Total bytes used
     pub total_bytes: usize,
 }
@@ -106,6 +124,46 @@ impl<'arena> SkipTape<'arena> {
         }
     }
 
+    /// Create a skip tape on top of recycled storage
+    #[must_use]
+    pub fn from_storage(arena: &'arena Bump, storage: SkipTapeStorage) -> Self {
+        let SkipTapeStorage {
+            mut nodes,
+            strings,
+            dedup_map,
+        } = storage;
+        nodes.clear();
+        Self {
+            nodes,
+            strings: StringArena {
+                arena,
+                strings,
+                dedup_map,
+                total_bytes: 0,
+            },
+            metadata: SkipMetadata {
+                node_count: 0,
+                total_size: 0,
+                schema_match_ratio: 0.0,
+                original_size: 0,
+                skipped_count: 0,
+            },
+        }
+    }
+
+    /// Clear the tape and return its storage for reuse
+    #[must_use]
+    pub fn into_storage(self) -> SkipTapeStorage {
+        let (strings, dedup_map) = detach_strings(self.strings.strings, self.strings.dedup_map);
+        let mut nodes = self.nodes;
+        nodes.clear();
+        SkipTapeStorage {
+            nodes,
+            strings,
+            dedup_map,
+        }
+    }
+
     /// Add a node to the skip tape
     pub fn add_node(&mut self, node: SkipNode) {
         self.nodes.push(node);
@@ -258,6 +316,53 @@ impl SkipNode {
         }
     }
 
+    /// Create a signed integer node
+    #[must_use]
+    pub const fn integer(value: i64) -> Self {
+        Self {
+            node_type: NodeType::Integer,
+            data: value.cast_unsigned(),
+            depth: 0,
+            flags: 0,
+            format_kind: FormatKind::Json,
+            _padding: [0; 5],
+        }
+    }
+
+    /// Create an unsigned integer node
+    #[must_use]
+    pub const fn unsigned(value: u64) -> Self {
+        Self {
+            node_type: NodeType::Unsigned,
+            data: value,
+            depth: 0,
+            flags: 0,
+            format_kind: FormatKind::Json,
+            _padding: [0; 5],
+        }
+    }
+
+    /// Create a node for a parsed number, keeping integers exact
+    #[must_use]
+    pub const fn from_number(value: NumberValue) -> Self {
+        match value {
+            NumberValue::I64(i) => Self::integer(i),
+            NumberValue::U64(u) => Self::unsigned(u),
+            NumberValue::F64(f) => Self::number(f),
+        }
+    }
+
+    /// The typed value of a number node
+    #[must_use]
+    pub const fn number_value(&self) -> Option<NumberValue> {
+        match self.node_type {
+            NodeType::Integer => Some(NumberValue::I64(self.data.cast_signed())),
+            NodeType::Unsigned => Some(NumberValue::U64(self.data)),
+            NodeType::Number => Some(NumberValue::F64(f64::from_bits(self.data))),
+            _ => None,
+        }
+    }
+
     /// Create a boolean node
     #[must_use]
     pub const fn bool(value: bool) -> Self {
@@ -326,7 +431,7 @@ impl<'arena> StringArena<'arena> {
         Self {
             arena,
             strings: Vec::new(),
-            dedup_map: std::collections::HashMap::new(),
+            dedup_map: HashMap::new(),
             total_bytes: 0,
         }
     }
@@ -393,6 +498,21 @@ mod tests {
         assert_eq!(node.node_type, NodeType::String);
     }
 
+    #[test]
+    fn test_skip_node_typed_numbers() {
+        let node = SkipNode::from_number(NumberValue::I64(-7));
+        assert_eq!(node.node_type, NodeType::Integer);
+        assert_eq!(node.number_value(), Some(NumberValue::I64(-7)));
+
+        let node = SkipNode::from_number(NumberValue::U64(u64::MAX));
+        assert_eq!(node.node_type, NodeType::Unsigned);
+        assert_eq!(node.number_value(), Some(NumberValue::U64(u64::MAX)));
+
+        let node = SkipNode::from_number(NumberValue::F64(1.5));
+        assert_eq!(node.number_value(), Some(NumberValue::F64(1.5)));
+        assert_eq!(SkipNode::null().number_value(), None);
+    }
+
     #[test]
     fn test_skip_node_number() {
         let node = SkipNode::number(42.0);
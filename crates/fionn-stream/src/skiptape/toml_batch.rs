@@ -485,11 +485,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdTomlBatchProcessor {
                 successful_lines: successful,
                 failed_lines: failed,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful > 0 {
-                    data.len() / successful
-                } else {
-                    0
-                },
+                avg_memory_per_line: data.len().checked_div(successful).unwrap_or(0),
                 overall_schema_match_ratio: if total > 0 {
                     successful as f64 / total as f64
                 } else {
@@ -765,11 +761,7 @@ impl<'arena> TapeBatchProcessor<'arena> for SimdTomlBatchProcessor {
                 successful_lines: successful,
                 failed_lines: failed,
                 processing_time_ms: elapsed.as_secs_f64() * 1000.0,
-                avg_memory_per_line: if successful > 0 {
-                    data.len() / successful
-                } else {
-                    0
-                },
+                avg_memory_per_line: data.len().checked_div(successful).unwrap_or(0),
                 overall_schema_match_ratio: 1.0,
             },
         })
@@ -12,6 +12,7 @@
 //! 3. **Cross-Format CRDT**: Delta-CRDT operations across format boundaries
 //! 4. **Schema-Guided Skip Parsing**: Efficient filtering during parsing
 
+use crate::skiptape::pool::detach_strings;
 use bumpalo::Bump;
 use fionn_core::format::FormatKind;
 use std::collections::HashMap;
@@ -429,6 +430,8 @@ pub struct UnifiedTape<'arena> {
     pub strings: UnifiedStringArena<'arena>,
     /// Original syntax entries
     pub original_syntax: Vec<OriginalSyntax>,
+    /// Entries from the previous batch on recycled storage
+    syntax_cache: Vec<OriginalSyntax>,
     /// Tape metadata
     pub metadata: UnifiedTapeMetadata,
 }
@@ -446,6 +449,20 @@ pub struct UnifiedStringArena<'arena> {
     pub total_bytes: usize,
 }
 
+/// Recyclable storage behind a [`UnifiedTape`]
+///
+/// Obtained from [`UnifiedTape::into_storage`] and turned back into a tape
+/// with [`UnifiedTape::from_storage`]; every vector keeps its capacity.
+#[derive(Debug, Default)]
+pub struct UnifiedTapeStorage {
+    pub(crate) nodes: Vec<UnifiedNode>,
+    pub(crate) strings: Vec<&'static str>,
+    pub(crate) dedup_map: HashMap<&'static str, u32>,
+    pub(crate) original_syntax: Vec<OriginalSyntax>,
+    /// Entries kept for [`UnifiedTape::intern_original_syntax`]
+    pub(crate) syntax_cache: Vec<OriginalSyntax>,
+}
+
 /// Metadata for unified tape
 #[derive(Debug, Clone, Default)]
 pub struct UnifiedTapeMetadata {
@@ -477,6 +494,40 @@ impl<'arena> UnifiedTape<'arena> {
             nodes: Vec::with_capacity(capacity),
             strings: UnifiedStringArena::new(arena),
             original_syntax: Vec::new(),
+            syntax_cache: Vec::new(),
+            metadata: UnifiedTapeMetadata {
+                source_format: format,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Create a unified tape on top of recycled storage
+    #[must_use]
+    pub fn from_storage(
+        arena: &'arena Bump,
+        format: FormatKind,
+        storage: UnifiedTapeStorage,
+    ) -> Self {
+        let UnifiedTapeStorage {
+            mut nodes,
+            strings,
+            dedup_map,
+            mut original_syntax,
+            syntax_cache,
+        } = storage;
+        nodes.clear();
+        original_syntax.clear();
+        Self {
+            nodes,
+            strings: UnifiedStringArena {
+                arena,
+                strings,
+                dedup_map,
+                total_bytes: 0,
+            },
+            original_syntax,
+            syntax_cache,
             metadata: UnifiedTapeMetadata {
                 source_format: format,
                 ..Default::default()
@@ -484,6 +535,26 @@ impl<'arena> UnifiedTape<'arena> {
         }
     }
 
+    /// Clear the tape and return its storage for reuse
+    ///
+    /// Original syntax entries are kept so the next tape built on this
+    /// storage can reuse them through [`UnifiedTape::intern_original_syntax`].
+    #[must_use]
+    pub fn into_storage(self) -> UnifiedTapeStorage {
+        let (strings, dedup_map) = detach_strings(self.strings.strings, self.strings.dedup_map);
+        let mut nodes = self.nodes;
+        let mut spare = self.syntax_cache;
+        nodes.clear();
+        spare.clear();
+        UnifiedTapeStorage {
+            nodes,
+            strings,
+            dedup_map,
+            original_syntax: spare,
+            syntax_cache: self.original_syntax,
+        }
+    }
+
     /// Add a node to the tape
     pub fn add_node(&mut self, node: UnifiedNode) {
         self.nodes.push(node);
@@ -503,6 +574,31 @@ impl<'arena> UnifiedTape<'arena> {
         idx
     }
 
+    /// Add original syntax, sharing an equal entry when one exists
+    ///
+    /// Returns the index of an entry in this tape for which `matches`
+    /// holds. Otherwise adds one, reusing a matching entry left by the
+    /// previous tape on recycled storage before building it with `make`,
+    /// so syntax repeated on every line costs no allocation per line.
+    pub fn intern_original_syntax(
+        &mut self,
+        matches: impl Fn(&OriginalSyntax) -> bool,
+        make: impl FnOnce() -> OriginalSyntax,
+    ) -> u32 {
+        self.metadata.preserved_syntax_count += 1;
+        if let Some(idx) = self.original_syntax.iter().position(&matches) {
+            return u32::try_from(idx).unwrap_or(u32::MAX);
+        }
+        let syntax = self
+            .syntax_cache
+            .iter()
+            .position(&matches)
+            .map_or_else(make, |idx| self.syntax_cache.swap_remove(idx));
+        let idx = u32::try_from(self.original_syntax.len()).unwrap_or(u32::MAX);
+        self.original_syntax.push(syntax);
+        idx
+    }
+
     /// Get the nodes slice
     #[must_use]
     pub fn nodes(&self) -> &[UnifiedNode] {
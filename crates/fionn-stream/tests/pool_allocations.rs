@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Steady-state allocation checks for pooled tape processing
+//!
+//! A counting global allocator records heap allocations made by the test
+//! thread; once the pool has warmed up, pooled batches must make none.
+
+use fionn_stream::format_dson::{
+    FormatBatchProcessor, FormatDsonProcessor, TapeBatchProcessor, TapeBatchResult,
+};
+#[cfg(feature = "csv")]
+use fionn_stream::skiptape::SimdCsvBatchProcessor;
+#[cfg(feature = "ison")]
+use fionn_stream::skiptape::SimdIsonlBatchProcessor;
+use fionn_stream::skiptape::{CompiledSchema, SimdJsonlBatchProcessor, TapeBuilderPool};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::Arc;
+
+struct CountingAllocator;
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation() {
+    // `try_with` so allocations during thread teardown are ignored
+    let _ = COUNTING.try_with(|counting| {
+        if counting.get() {
+            let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        }
+    });
+}
+
+// SAFETY: delegates to the system allocator and only adds bookkeeping
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Count heap allocations made by `f` on this thread
+fn count_allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.with(|n| n.set(0));
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn jsonl_batch(records: usize) -> Vec<u8> {
+    let mut data = Vec::new();
+    for i in 0..records {
+        data.extend_from_slice(
+            format!(
+                "{{\"id\": {i}, \"name\": \"user{i}\", \"score\": {}.5, \"active\": {}, \
+                 \"tags\": [\"a\", \"b\"], \"meta\": {{\"region\": \"eu\", \"tier\": null}}}}\n",
+                i % 100,
+                i % 2 == 0
+            )
+            .as_bytes(),
+        );
+    }
+    data
+}
+
+#[cfg(feature = "ison")]
+fn isonl_batch(records: usize) -> Vec<u8> {
+    let mut data = Vec::new();
+    for i in 0..records {
+        data.extend_from_slice(
+            format!(
+                "table.users|id:int|name:string|score:float|active:bool|{i}|user{i}|{}.5|{}\n",
+                i % 100,
+                i % 2 == 0
+            )
+            .as_bytes(),
+        );
+    }
+    data
+}
+
+#[cfg(feature = "csv")]
+fn csv_batch(records: usize) -> Vec<u8> {
+    let mut data = b"id,name,score,note\n".to_vec();
+    for i in 0..records {
+        data.extend_from_slice(
+            format!("{i},user{i},{}.5,\"quoted, \"\"note\"\" {i}\"\n", i % 100).as_bytes(),
+        );
+    }
+    data
+}
+
+/// Process `data` repeatedly through a pooled `processor` and check that
+/// warmed-up batches of `records` segments make no heap allocations
+fn assert_steady_state_allocation_free<P>(
+    processor: P,
+    data: &[u8],
+    records: usize,
+    schema: &CompiledSchema,
+) where
+    P: FormatBatchProcessor + for<'arena> TapeBatchProcessor<'arena>,
+{
+    let pool = Arc::new(TapeBuilderPool::default());
+    let mut processor = FormatDsonProcessor::new(processor).with_pool(Arc::clone(&pool));
+
+    let mut run = || {
+        processor
+            .process_tape_with(data, schema, |result: &TapeBatchResult<'_>| {
+                result.segment_count()
+            })
+            .unwrap()
+    };
+
+    // Warm up: grow arenas, node vectors and parser buffers to size
+    for _ in 0..4 {
+        assert_eq!(run(), records);
+    }
+
+    let allocations = count_allocations(|| {
+        for _ in 0..16 {
+            assert_eq!(run(), records);
+        }
+    });
+    assert_eq!(allocations, 0, "pooled batches should not allocate");
+    assert_eq!(pool.arena_stats().reuses, 19);
+}
+
+#[test]
+fn test_pooled_jsonl_unfiltered_batches_do_not_allocate() {
+    assert_steady_state_allocation_free(
+        SimdJsonlBatchProcessor::new(),
+        &jsonl_batch(500),
+        500,
+        &CompiledSchema::compile(&[]).unwrap(),
+    );
+}
+
+#[test]
+fn test_pooled_jsonl_filtered_batches_do_not_allocate() {
+    let schema = CompiledSchema::compile(&[
+        "id".to_string(),
+        "name".to_string(),
+        "tags".to_string(),
+        "meta".to_string(),
+    ])
+    .unwrap();
+    assert_steady_state_allocation_free(
+        SimdJsonlBatchProcessor::new(),
+        &jsonl_batch(500),
+        500,
+        &schema,
+    );
+}
+
+#[cfg(feature = "ison")]
+#[test]
+fn test_pooled_isonl_unfiltered_batches_do_not_allocate() {
+    assert_steady_state_allocation_free(
+        SimdIsonlBatchProcessor::new(),
+        &isonl_batch(500),
+        500,
+        &CompiledSchema::compile(&[]).unwrap(),
+    );
+}
+
+#[cfg(feature = "ison")]
+#[test]
+fn test_pooled_isonl_filtered_batches_do_not_allocate() {
+    let schema = CompiledSchema::compile(&["users.id".to_string(), "name".to_string()]).unwrap();
+    assert_steady_state_allocation_free(
+        SimdIsonlBatchProcessor::new(),
+        &isonl_batch(500),
+        500,
+        &schema,
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_pooled_csv_unfiltered_batches_do_not_allocate() {
+    assert_steady_state_allocation_free(
+        SimdCsvBatchProcessor::new(),
+        &csv_batch(500),
+        500,
+        &CompiledSchema::compile(&[]).unwrap(),
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_pooled_csv_filtered_batches_do_not_allocate() {
+    let schema = CompiledSchema::compile(&["id".to_string(), "note".to_string()]).unwrap();
+    assert_steady_state_allocation_free(
+        SimdCsvBatchProcessor::new(),
+        &csv_batch(500),
+        500,
+        &schema,
+    );
+}
+
+#[test]
+fn test_unpooled_batches_allocate() {
+    // Sanity check that the counter sees allocations at all
+    let data = jsonl_batch(10);
+    let schema = CompiledSchema::compile(&[]).unwrap();
+    let mut processor = FormatDsonProcessor::new(SimdJsonlBatchProcessor::new());
+    let allocations = count_allocations(|| {
+        processor
+            .process_tape_with(&data, &schema, |result: &TapeBatchResult<'_>| {
+                result.segment_count()
+            })
+            .unwrap();
+    });
+    assert!(allocations > 0);
+}
@@ -5,7 +5,8 @@
 //! for efficient zero-allocation JSON parsing with skip optimization.
 
 use ahash::{AHashMap, AHashSet};
-use fionn_core::{DsonError, Result};
+use fionn_core::limits::{BigIntegers, ParseLimits, check_json};
+use fionn_core::{DsonError, JsonNumber, Result};
 pub use fionn_core::{ParsedPath, PathComponent, PathComponentRef};
 use simd_json::value::tape::{Node, Tape};
 
@@ -13,6 +14,10 @@ use simd_json::value::tape::{Node, Tape};
 mod tape_source_impl;
 pub use tape_source_impl::{ArrayElementIterator, ObjectFieldIterator};
 
+// Parallel structural indexing and tape construction
+mod parallel;
+pub use parallel::StructuralIndex;
+
 // Type aliases for performance-optimized collections
 type FastHashMap<K, V> = AHashMap<K, V>;
 type FastHashSet<T> = AHashSet<T>;
@@ -51,8 +56,8 @@ pub enum SimdValue {
     Null,
     /// A boolean value
     Bool(bool),
-    /// A number value stored as string for precision
-    Number(String),
+    /// A number value, typed and kept with its lexeme
+    Number(JsonNumber),
 }
 
 /// SIMD-DSON tape wrapper
@@ -79,6 +84,33 @@ impl DsonTape {
 
         Ok(Self { tape, data: bytes })
     }
+
+    /// Create a new DSON tape from JSON input, enforcing `limits`
+    ///
+    /// The tape keeps every key as written, so reported duplicates stay in
+    /// it. simd-json has no raw-number node, so [`BigIntegers::Preserve`]
+    /// is not supported.
+    ///
+    /// # Errors
+    /// Returns a validation error if the input breaks a limit, an invalid
+    /// operation error if `limits` asks for [`BigIntegers::Preserve`], or a
+    /// parse error if the JSON is malformed
+    pub fn parse_with_limits(json: &str, limits: &ParseLimits) -> Result<Self> {
+        enforce_limits(json.as_bytes(), limits)?;
+        Self::parse(json)
+    }
+}
+
+/// Run the limit checks a simd-json tape can honour
+fn enforce_limits(json: &[u8], limits: &ParseLimits) -> Result<()> {
+    if limits.big_integers == BigIntegers::Preserve {
+        return Err(DsonError::InvalidOperation(
+            "BigIntegers::Preserve is unsupported: simd-json tapes have no raw-number node"
+                .to_string(),
+        ));
+    }
+    check_json(json, limits)?;
+    Ok(())
 }
 
 impl<S: AsRef<[u8]>> DsonTape<S> {
@@ -238,9 +270,9 @@ impl<S: AsRef<[u8]>> DsonTape<S> {
             Node::Static(static_node) => match static_node {
                 simd_json::StaticNode::Null => Some(SimdValue::Null),
                 simd_json::StaticNode::Bool(b) => Some(SimdValue::Bool(*b)),
-                simd_json::StaticNode::I64(n) => Some(SimdValue::Number(n.to_string())),
-                simd_json::StaticNode::U64(n) => Some(SimdValue::Number(n.to_string())),
-                simd_json::StaticNode::F64(n) => Some(SimdValue::Number(n.to_string())),
+                simd_json::StaticNode::I64(n) => Some(SimdValue::Number((*n).into())),
+                simd_json::StaticNode::U64(n) => Some(SimdValue::Number((*n).into())),
+                simd_json::StaticNode::F64(n) => Some(SimdValue::Number((*n).into())),
             },
             _ => None,
         }
@@ -1173,6 +1205,31 @@ mod tests {
         assert!(tape.is_err());
     }
 
+    #[test]
+    fn test_dson_tape_parse_with_limits() {
+        let strict = ParseLimits::strict();
+        assert!(DsonTape::parse_with_limits(r#"{"a":[1,"\ud83d\ude00"]}"#, &strict).is_ok());
+        for input in [
+            r#"{"a":1,"a":2}"#,
+            r#"["\ud800"]"#,
+            "[18446744073709551616]",
+        ] {
+            // The plain parser accepts the first two
+            let err = DsonTape::parse_with_limits(input, &strict).err().unwrap();
+            assert!(matches!(err, DsonError::ValidationError(_)), "{input}");
+        }
+
+        let shallow = ParseLimits::permissive().with_max_depth(2);
+        assert!(DsonTape::parse_with_limits("[[1]]", &shallow).is_ok());
+        assert!(DsonTape::parse_with_limits("[[[1]]]", &shallow).is_err());
+
+        // Out-of-range integers cannot be preserved in a simd-json tape
+        let preserve = ParseLimits::permissive().with_big_integers(BigIntegers::Preserve);
+        let err = DsonTape::parse_with_limits("[1]", &preserve).err().unwrap();
+        assert!(matches!(err, DsonError::InvalidOperation(_)));
+        assert!(err.to_string().contains("unsupported"));
+    }
+
     #[test]
     fn test_dson_tape_nodes() {
         let tape = DsonTape::parse(r#"{"name":"test"}"#).unwrap();
@@ -1341,7 +1398,7 @@ mod tests {
     #[test]
     fn test_simd_value_variants() {
         let s = SimdValue::String("test".to_string());
-        let n = SimdValue::Number("42".to_string());
+        let n = SimdValue::Number("42".into());
         let b = SimdValue::Bool(true);
         let null = SimdValue::Null;
 
@@ -1889,7 +1946,7 @@ mod tests {
         let mut modifications = FastHashMap::default();
         modifications.insert(
             "value".to_string(),
-            fionn_core::OperationValue::NumberRef("100".to_string()),
+            fionn_core::OperationValue::NumberRef("100".into()),
         );
         let deletions = FastHashSet::default();
         let result = tape.serialize_with_modifications(&modifications, &deletions);
@@ -1971,7 +2028,7 @@ mod tests {
 
     #[test]
     fn test_simd_value_number_display() {
-        let value = SimdValue::Number("42".to_string());
+        let value = SimdValue::Number("42".into());
         let debug_str = format!("{value:?}");
         assert!(debug_str.contains("42"));
     }
@@ -2092,7 +2149,7 @@ mod tests {
         let mut modifications = FastHashMap::default();
         modifications.insert(
             "data[0][0]".to_string(),
-            fionn_core::OperationValue::NumberRef("42".to_string()),
+            fionn_core::OperationValue::NumberRef("42".into()),
         );
         let deletions = FastHashSet::default();
         let result = tape.serialize_with_modifications(&modifications, &deletions);
@@ -2160,7 +2217,7 @@ mod tests {
 
     #[test]
     fn test_simd_value_number_variant() {
-        let value = SimdValue::Number("3.14".to_string());
+        let value = SimdValue::Number("3.14".into());
         let debug_str = format!("{value:?}");
         assert!(debug_str.contains("Number"));
     }
@@ -0,0 +1,979 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Parallel structural indexing and tape construction
+//!
+//! [`DsonTape::parse`] runs both simd-json stages on a single core. For large
+//! documents [`DsonTape::parse_parallel`] splits the input into fixed-size
+//! chunks and makes three parallel passes, each followed by a short serial
+//! pass over per-chunk summaries:
+//!
+//! 1. **Quote scan** - count unescaped quotes per chunk so a prefix pass
+//!    knows whether each chunk starts inside a string
+//! 2. **Structural scan** - record structural characters, string starts and
+//!    scalar starts with local bracket matching; a prefix pass yields each
+//!    chunk's node offset and the containers still open at its start
+//! 3. **Tape build** - validate the grammar, parse scalars, unescape strings
+//!    in place and write each chunk's nodes into its own slice of the tape;
+//!    containers spanning chunks are patched afterwards
+//!
+//! The parallel passes accept strict RFC 8259 JSON only. Numbers simd-json
+//! treats specially (19+ character integers, exponents over 4 digits) are
+//! handed to simd-json one lexeme at a time inside their chunk. Anything
+//! else, including strings where simd-json has its own edge-case behaviour
+//! (lone surrogates, raw control characters), is handed to the serial
+//! parser. The result is therefore always identical to [`DsonTape::parse`],
+//! errors included, and independent of chunk size.
+
+use crate::DsonTape;
+use fionn_core::Result;
+use fionn_core::limits::ParseLimits;
+use fionn_core::number::{self, NumberValue};
+use rayon::prelude::*;
+use simd_json::StaticNode;
+use simd_json::value::tape::{Node, Tape};
+
+/// Smallest chunk worth scanning on its own thread
+const MIN_CHUNK_SIZE: usize = 1 << 20;
+
+/// Token offsets are stored relative to their chunk as `u32`
+const MAX_CHUNK_SIZE: usize = u32::MAX as usize;
+
+/// Token kind used for numbers and literals
+const SCALAR: u8 = b'0';
+
+/// Pick a chunk size giving each thread a few chunks to balance load
+fn default_chunk_size(len: usize) -> usize {
+    (len / (rayon::current_num_threads() * 4)).clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE)
+}
+
+#[inline]
+const fn is_whitespace(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Bytes that continue a number or literal
+#[inline]
+const fn is_scalar_byte(b: u8) -> bool {
+    !is_whitespace(b) && !matches!(b, b'{' | b'}' | b'[' | b']' | b':' | b',' | b'"')
+}
+
+#[inline]
+const fn token_kind(b: u8) -> u8 {
+    match b {
+        b'{' | b'}' | b'[' | b']' | b':' | b',' | b'"' => b,
+        _ => SCALAR,
+    }
+}
+
+#[inline]
+const fn closer(open: u8) -> u8 {
+    if open == b'{' { b'}' } else { b']' }
+}
+
+// =============================================================================
+// Pass 1: quote scan
+// =============================================================================
+
+#[derive(Debug, Clone, Copy, Default)]
+struct QuoteScan {
+    odd_quotes: bool,
+    /// The chunk ends with an unpaired backslash
+    escape_out: bool,
+}
+
+/// Count unescaped quotes, assuming the first byte is not escaped
+fn quote_scan(bytes: &[u8]) -> QuoteScan {
+    let mut odd_quotes = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(p) = memchr::memchr2(b'"', b'\\', &bytes[i..]) else {
+            break;
+        };
+        i += p;
+        if bytes[i] == b'"' {
+            odd_quotes = !odd_quotes;
+            i += 1;
+        } else {
+            i += 2;
+        }
+    }
+    QuoteScan {
+        odd_quotes,
+        escape_out: i > bytes.len(),
+    }
+}
+
+// =============================================================================
+// Pass 2: structural scan
+// =============================================================================
+
+/// Lexer state at the first byte of a chunk
+#[derive(Debug, Clone, Copy, Default)]
+struct ChunkStart {
+    in_string: bool,
+    escaped: bool,
+    /// The previous chunk ended in the middle of a number or literal
+    in_scalar: bool,
+}
+
+#[derive(Debug, Default)]
+struct ChunkScan {
+    /// Absolute offset of the chunk
+    start: usize,
+    /// Token offsets relative to `start`
+    tokens: Vec<u32>,
+    /// Tape nodes produced by the chunk's tokens
+    nodes: usize,
+    /// Closing brackets of containers opened in earlier chunks
+    closes: Vec<u8>,
+    /// Containers left open: opening byte and chunk-local node ordinal
+    opens: Vec<(u8, usize)>,
+    /// Brackets that cannot pair up
+    mismatched: bool,
+}
+
+/// Position after the closing quote, or `None` if the string runs past the chunk
+fn string_end(bytes: &[u8], mut i: usize, escaped: bool) -> Option<usize> {
+    if escaped {
+        i += 1;
+    }
+    while i < bytes.len() {
+        i += memchr::memchr2(b'"', b'\\', &bytes[i..])?;
+        if bytes[i] == b'"' {
+            return Some(i + 1);
+        }
+        i += 2;
+    }
+    None
+}
+
+#[allow(clippy::cast_possible_truncation)] // Chunks are at most MAX_CHUNK_SIZE bytes
+fn structural_scan(chunk: &[u8], start: usize, state: ChunkStart) -> ChunkScan {
+    let mut scan = ChunkScan {
+        start,
+        tokens: Vec::with_capacity(chunk.len() / 8),
+        ..ChunkScan::default()
+    };
+    // A stray backslash outside any string is invalid JSON
+    scan.mismatched = state.escaped && !state.in_string;
+
+    let mut i = 0;
+    if state.in_string {
+        match string_end(chunk, 0, state.escaped) {
+            Some(end) => i = end,
+            None => return scan,
+        }
+    } else if state.in_scalar {
+        while i < chunk.len() && is_scalar_byte(chunk[i]) {
+            i += 1;
+        }
+    }
+
+    let mut stack: Vec<(u8, usize)> = Vec::new();
+    while i < chunk.len() {
+        let b = chunk[i];
+        if is_whitespace(b) {
+            i += 1;
+            continue;
+        }
+        scan.tokens.push(i as u32);
+        match b {
+            b'{' | b'[' => {
+                stack.push((b, scan.nodes));
+                scan.nodes += 1;
+                i += 1;
+            }
+            b'}' | b']' => {
+                match stack.pop() {
+                    Some((open, _)) if closer(open) == b => {}
+                    Some(_) => scan.mismatched = true,
+                    None => scan.closes.push(b),
+                }
+                i += 1;
+            }
+            b':' | b',' => i += 1,
+            b'"' => {
+                scan.nodes += 1;
+                match string_end(chunk, i + 1, false) {
+                    Some(end) => i = end,
+                    None => break,
+                }
+            }
+            _ => {
+                scan.nodes += 1;
+                i += 1;
+                while i < chunk.len() && is_scalar_byte(chunk[i]) {
+                    i += 1;
+                }
+            }
+        }
+    }
+    scan.opens = stack;
+    scan
+}
+
+/// Positions of JSON tokens, found in parallel
+///
+/// Records every structural character outside strings, the opening quote of
+/// every string and the first byte of every number or literal. Chunks are
+/// scanned speculatively in parallel and reconciled with a prefix pass over
+/// quote parity, so the index is identical for any chunk size.
+///
+/// ```
+/// use fionn_tape::StructuralIndex;
+///
+/// let json = br#"{"a": [1, "x,y"]}"#;
+/// let index = StructuralIndex::with_chunk_size(json, 4);
+/// let positions: Vec<usize> = index.positions().collect();
+/// assert_eq!(positions, vec![0, 1, 4, 6, 7, 8, 10, 15, 16]);
+/// ```
+#[derive(Debug)]
+pub struct StructuralIndex {
+    chunks: Vec<ChunkScan>,
+}
+
+impl StructuralIndex {
+    /// Index `input` using a chunk size suited to the thread pool
+    #[must_use]
+    pub fn build(input: &[u8]) -> Self {
+        Self::with_chunk_size(input, default_chunk_size(input.len()))
+    }
+
+    /// Index `input` in chunks of `chunk_size` bytes
+    #[must_use]
+    pub fn with_chunk_size(input: &[u8], chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.clamp(1, MAX_CHUNK_SIZE);
+
+        // Pass 1, plus the alternative for chunks whose first byte may be escaped
+        let quotes: Vec<(QuoteScan, Option<QuoteScan>)> = input
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let shifted = matches!(chunk[0], b'"' | b'\\').then(|| quote_scan(&chunk[1..]));
+                (quote_scan(chunk), shifted)
+            })
+            .collect();
+
+        let mut starts = Vec::with_capacity(quotes.len());
+        let mut state = ChunkStart::default();
+        for (i, (plain, shifted)) in quotes.iter().enumerate() {
+            if i > 0 {
+                state.in_scalar = !state.in_string && is_scalar_byte(input[i * chunk_size - 1]);
+            }
+            starts.push(state);
+            let scan = if state.escaped {
+                shifted.unwrap_or(*plain)
+            } else {
+                *plain
+            };
+            state.in_string ^= scan.odd_quotes;
+            state.escaped = scan.escape_out;
+        }
+
+        // Pass 2
+        let chunks = input
+            .par_chunks(chunk_size)
+            .zip(starts.par_iter())
+            .enumerate()
+            .map(|(i, (chunk, state))| structural_scan(chunk, i * chunk_size, *state))
+            .collect();
+
+        Self { chunks }
+    }
+
+    /// Number of indexed tokens
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.tokens.len()).sum()
+    }
+
+    /// Whether the input holds no tokens
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.iter().all(|c| c.tokens.is_empty())
+    }
+
+    /// Number of chunks the input was split into
+    #[must_use]
+    pub const fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Absolute byte offsets of all tokens, in order
+    pub fn positions(&self) -> impl Iterator<Item = usize> + '_ {
+        self.chunks
+            .iter()
+            .flat_map(|c| c.tokens.iter().map(move |&t| c.start + t as usize))
+    }
+
+    /// Absolute byte offsets of the tokens directly inside containers opened
+    /// `depth` levels deep, in order
+    ///
+    /// The root's own brackets are at depth 0; its members and the commas and
+    /// colons between them are at depth 1. Chunks are walked in parallel from
+    /// depths found by a prefix pass. `input` must be the indexed input, and
+    /// brackets that do not pair up make the depths unreliable.
+    ///
+    /// ```
+    /// use fionn_tape::StructuralIndex;
+    ///
+    /// let json = br#"{"a": [1, 2], "b": 3}"#;
+    /// let index = StructuralIndex::with_chunk_size(json, 4);
+    /// assert_eq!(index.tokens_at_depth(json, 0), vec![0, 20]);
+    /// assert_eq!(index.tokens_at_depth(json, 1), vec![1, 4, 6, 11, 12, 14, 17, 19]);
+    /// ```
+    #[must_use]
+    pub fn tokens_at_depth(&self, input: &[u8], depth: usize) -> Vec<usize> {
+        let mut starts = Vec::with_capacity(self.chunks.len());
+        let mut level = 0usize;
+        for chunk in &self.chunks {
+            starts.push(level);
+            level = level.saturating_sub(chunk.closes.len()) + chunk.opens.len();
+        }
+        self.chunks
+            .par_iter()
+            .zip(starts)
+            .flat_map_iter(|(chunk, mut level)| {
+                chunk.tokens.iter().filter_map(move |&token| {
+                    let pos = chunk.start + token as usize;
+                    let at = match input[pos] {
+                        b'{' | b'[' => {
+                            level += 1;
+                            level - 1
+                        }
+                        b'}' | b']' => {
+                            level = level.saturating_sub(1);
+                            level
+                        }
+                        _ => level,
+                    };
+                    (at == depth).then_some(pos)
+                })
+            })
+            .collect()
+    }
+}
+
+// =============================================================================
+// Pass 3: tape build
+// =============================================================================
+
+/// Everything a chunk needs to know about the tokens before it
+#[derive(Debug)]
+struct ChunkContext {
+    node_base: usize,
+    /// Enclosing open containers, innermost first: opening byte and node index
+    outer: Vec<(u8, usize)>,
+    /// Kind of the previous token, 0 at document start
+    prev: u8,
+    prev_is_key: bool,
+}
+
+/// Effects of a chunk on containers it did not open
+#[derive(Debug, Default)]
+struct ChunkFix {
+    /// Children added to each enclosing container, innermost first
+    outer_children: Vec<usize>,
+    /// Node index at which each closed enclosing container ended
+    outer_closes: Vec<usize>,
+    /// Containers left open: node index and children so far
+    opens: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    Value,
+    ValueOrClose,
+    Key,
+    KeyOrClose,
+    Colon,
+    CommaOrClose,
+    End,
+}
+
+const fn expect(prev: u8, prev_is_key: bool, context: Option<u8>) -> Expect {
+    match prev {
+        b',' if matches!(context, Some(b'{')) => Expect::Key,
+        0 | b':' | b',' => Expect::Value,
+        b'{' => Expect::KeyOrClose,
+        b'[' => Expect::ValueOrClose,
+        b'"' if prev_is_key => Expect::Colon,
+        _ if context.is_some() => Expect::CommaOrClose,
+        _ => Expect::End,
+    }
+}
+
+const fn container<'s>(open: u8, len: usize, count: usize) -> Node<'s> {
+    if open == b'{' {
+        Node::Object { len, count }
+    } else {
+        Node::Array { len, count }
+    }
+}
+
+/// A run of raw string bytes simd-json and RFC 8259 agree on
+fn plain_run(run: &[u8]) -> bool {
+    !run.iter().any(|&b| b < 0x20) && std::str::from_utf8(run).is_ok()
+}
+
+fn hex4(bytes: &[u8]) -> Option<u32> {
+    let digits = std::str::from_utf8(bytes.get(..4)?).ok()?;
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Unescape the string starting after its opening quote at `buf[0]`, in place
+///
+/// Returns the unescaped length (content starts at `buf[1]`) and the offset
+/// after the closing quote.
+fn unescape_in_place(buf: &mut [u8]) -> Option<(usize, usize)> {
+    let mut read = 1;
+    let mut write = 1;
+    loop {
+        let run = memchr::memchr2(b'"', b'\\', buf.get(read..)?)?;
+        if !plain_run(&buf[read..read + run]) {
+            return None;
+        }
+        buf.copy_within(read..read + run, write);
+        read += run;
+        write += run;
+        if buf[read] == b'"' {
+            return Some((write - 1, read + 1));
+        }
+        let byte = match *buf.get(read + 1)? {
+            b'"' => b'"',
+            b'\\' => b'\\',
+            b'/' => b'/',
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'u' => {
+                let high = hex4(buf.get(read + 2..)?)?;
+                let (code_point, width) = match high {
+                    0xd800..=0xdbff => {
+                        if buf.get(read + 6..read + 8)? != b"\\u" {
+                            return None;
+                        }
+                        let low = hex4(buf.get(read + 8..)?)?;
+                        if !(0xdc00..=0xdfff).contains(&low) {
+                            return None;
+                        }
+                        (0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00), 12)
+                    }
+                    0xdc00..=0xdfff => return None,
+                    _ => (high, 6),
+                };
+                let encoded = char::from_u32(code_point)?;
+                write += encoded.encode_utf8(&mut buf[write..]).len();
+                read += width;
+                continue;
+            }
+            _ => return None,
+        };
+        buf[write] = byte;
+        write += 1;
+        read += 2;
+    }
+}
+
+/// Parse a number the way simd-json does, or `None` to defer to it
+fn parse_number(lexeme: &[u8]) -> Option<StaticNode> {
+    let exponent = lexeme.iter().position(|&b| b == b'e' || b == b'E');
+    if let Some(e) = exponent {
+        let digits = &lexeme[e + 1..];
+        let digits = digits
+            .strip_prefix(b"+")
+            .or_else(|| digits.strip_prefix(b"-"))
+            .unwrap_or(digits);
+        // Long exponents hit simd-json's own overflow handling
+        if digits.len() > 4 {
+            return simd_number(lexeme);
+        }
+    }
+    // Integers of 19+ characters take simd-json's wide-integer path
+    if exponent.is_none() && !lexeme.contains(&b'.') && lexeme.len() >= 19 {
+        return simd_number(lexeme);
+    }
+    Some(match number::parse_number(lexeme)? {
+        NumberValue::I64(value) => StaticNode::I64(value),
+        NumberValue::U64(value) => StaticNode::U64(value),
+        NumberValue::F64(value) => StaticNode::F64(value),
+    })
+}
+
+/// Let simd-json parse a lone number, which it reads as it would in place
+fn simd_number(lexeme: &[u8]) -> Option<StaticNode> {
+    let mut bytes = lexeme.to_vec();
+    match simd_json::to_tape(&mut bytes).ok()?.0.as_slice() {
+        [Node::Static(value)] => Some(*value),
+        _ => None,
+    }
+}
+
+fn parse_scalar(lexeme: &[u8]) -> Option<StaticNode> {
+    match lexeme {
+        b"true" => Some(StaticNode::Bool(true)),
+        b"false" => Some(StaticNode::Bool(false)),
+        b"null" => Some(StaticNode::Null),
+        _ => parse_number(lexeme),
+    }
+}
+
+/// Build one chunk's nodes from its segment of the input
+///
+/// `segment` starts at the chunk's first token and runs to the next chunk's
+/// first token, so every string and scalar starting in the chunk lies inside.
+fn build_chunk<'s>(
+    segment: &'s mut [u8],
+    tokens: &[u32],
+    nodes: &mut [Node<'s>],
+    ctx: &ChunkContext,
+) -> Option<ChunkFix> {
+    let first = tokens[0] as usize;
+    let mut fix = ChunkFix {
+        outer_children: vec![0; ctx.outer.len()],
+        ..ChunkFix::default()
+    };
+    let mut local: Vec<(u8, usize, usize)> = Vec::new();
+    let mut popped = 0;
+    let mut prev = ctx.prev;
+    let mut prev_is_key = ctx.prev_is_key;
+    let mut n = 0;
+
+    // Strings are carved off the front as they are unescaped
+    let mut rest = segment;
+    let mut rest_offset = 0;
+
+    for &token in tokens {
+        let pos = token as usize - first;
+        let kind = token_kind(rest[pos - rest_offset]);
+        let context = local
+            .last()
+            .map(|entry| entry.0)
+            .or_else(|| ctx.outer.get(popped).map(|entry| entry.0));
+        let is_value = matches!(kind, b'{' | b'[' | b'"' | SCALAR);
+        let is_key = match expect(prev, prev_is_key, context) {
+            Expect::Key | Expect::KeyOrClose if kind == b'"' => true,
+            Expect::KeyOrClose if kind == b'}' => false,
+            Expect::Value if is_value => false,
+            Expect::ValueOrClose if is_value || kind == b']' => false,
+            Expect::Colon if kind == b':' => false,
+            Expect::CommaOrClose
+                if kind == b',' || context.is_some_and(|open| closer(open) == kind) =>
+            {
+                false
+            }
+            _ => return None,
+        };
+
+        if is_key || (is_value && context == Some(b'[')) {
+            match local.last_mut() {
+                Some(entry) => entry.2 += 1,
+                None => fix.outer_children[popped] += 1,
+            }
+        }
+
+        match kind {
+            b'{' | b'[' => {
+                local.push((kind, n, 0));
+                n += 1;
+            }
+            b'}' | b']' => {
+                if let Some((open, index, children)) = local.pop() {
+                    nodes[index] = container(open, children, n - index - 1);
+                } else {
+                    fix.outer_closes.push(ctx.node_base + n);
+                    popped += 1;
+                }
+            }
+            b'"' => {
+                let tail = std::mem::take(&mut rest).split_at_mut(pos - rest_offset).1;
+                let (len, end) = unescape_in_place(tail)?;
+                let (string, after) = tail.split_at_mut(end);
+                rest = after;
+                rest_offset = pos + end;
+                let string: &'s [u8] = string;
+                // SAFETY: every run was UTF-8 validated and escapes decode to chars
+                nodes[n] = Node::String(unsafe { std::str::from_utf8_unchecked(&string[1..=len]) });
+                n += 1;
+            }
+            SCALAR => {
+                let bytes = &rest[pos - rest_offset..];
+                let len = bytes
+                    .iter()
+                    .position(|&b| !is_scalar_byte(b))
+                    .unwrap_or(bytes.len());
+                nodes[n] = Node::Static(parse_scalar(&bytes[..len])?);
+                n += 1;
+            }
+            _ => {}
+        }
+        prev = kind;
+        prev_is_key = is_key;
+    }
+
+    fix.opens = local
+        .into_iter()
+        .map(|(_, index, children)| (ctx.node_base + index, children))
+        .collect();
+    Some(fix)
+}
+
+/// Build tape nodes for `data`, or `None` to defer to the serial parser
+fn build_tape<'s>(data: &'s mut [u8], index: &StructuralIndex) -> Option<Vec<Node<'s>>> {
+    // Serial pass: node offsets, enclosing containers and grammar state per chunk
+    let mut contexts = Vec::with_capacity(index.chunks.len());
+    let mut stack: Vec<(u8, usize)> = Vec::new();
+    let mut node_base = 0;
+    let (mut last, mut before_last) = (0u8, 0u8);
+    for chunk in &index.chunks {
+        if chunk.mismatched {
+            return None;
+        }
+        let keep = (chunk.closes.len() + 1).min(stack.len());
+        let prev_is_key = last == b'"'
+            && stack.last().map(|entry| entry.0) == Some(b'{')
+            && matches!(before_last, b'{' | b',');
+        contexts.push(ChunkContext {
+            node_base,
+            outer: stack[stack.len() - keep..].iter().rev().copied().collect(),
+            prev: last,
+            prev_is_key,
+        });
+        for &close in &chunk.closes {
+            let (open, _) = stack.pop()?;
+            if closer(open) != close {
+                return None;
+            }
+        }
+        stack.extend(chunk.opens.iter().map(|&(open, n)| (open, node_base + n)));
+        node_base += chunk.nodes;
+        let tail = chunk.tokens.len().saturating_sub(2);
+        for &token in &chunk.tokens[tail..] {
+            before_last = last;
+            last = token_kind(data[chunk.start + token as usize]);
+        }
+    }
+    if !stack.is_empty() || last == 0 {
+        return None;
+    }
+
+    // Hand each chunk with tokens a disjoint slice of the input and the tape
+    let mut nodes = vec![Node::Static(StaticNode::Null); node_base];
+    let mut work = Vec::with_capacity(index.chunks.len());
+    let starts: Vec<usize> = index
+        .chunks
+        .iter()
+        .filter_map(|c| c.tokens.first().map(|&t| c.start + t as usize))
+        .collect();
+    let data_len = data.len();
+    let mut rest_data = data;
+    let mut rest_nodes = nodes.as_mut_slice();
+    let mut offset = 0;
+    let mut next_start = starts.iter().skip(1);
+    for (chunk, ctx) in index.chunks.iter().zip(&contexts) {
+        let Some(&first) = chunk.tokens.first() else {
+            continue;
+        };
+        let start = chunk.start + first as usize;
+        let end = next_start.next().copied().unwrap_or(data_len);
+        let (segment, after) =
+            std::mem::take(&mut rest_data)[start - offset..].split_at_mut(end - start);
+        rest_data = after;
+        offset = end;
+        let (chunk_nodes, after) = std::mem::take(&mut rest_nodes).split_at_mut(chunk.nodes);
+        rest_nodes = after;
+        work.push((segment, chunk_nodes, chunk, ctx));
+    }
+
+    let fixes: Vec<(ChunkFix, &ChunkScan)> = work
+        .into_par_iter()
+        .map(|(segment, chunk_nodes, chunk, ctx)| {
+            build_chunk(segment, &chunk.tokens, chunk_nodes, ctx).map(|fix| (fix, chunk))
+        })
+        .collect::<Option<_>>()?;
+
+    // Serial pass: finish containers that span chunks
+    let mut open: Vec<(u8, usize, usize)> = Vec::new();
+    for (fix, chunk) in fixes {
+        let top = open.len();
+        for (depth, children) in fix.outer_children.iter().enumerate() {
+            open[top - 1 - depth].2 += children;
+        }
+        for close_at in fix.outer_closes {
+            let (kind, index, children) = open.pop()?;
+            nodes[index] = container(kind, children, close_at - index - 1);
+        }
+        open.extend(
+            chunk
+                .opens
+                .iter()
+                .zip(fix.opens)
+                .map(|(&(kind, _), (index, children))| (kind, index, children)),
+        );
+    }
+    Some(nodes)
+}
+
+impl DsonTape {
+    /// Create a DSON tape from JSON input, indexing and building in parallel
+    ///
+    /// Produces exactly the tape [`DsonTape::parse`] would. Inputs too small
+    /// to split, or a single-threaded pool, fall back to the serial parser.
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is malformed
+    pub fn parse_parallel(json: &str) -> Result<Self> {
+        if rayon::current_num_threads() == 1 {
+            return Self::parse(json);
+        }
+        Self::parse_parallel_with_chunk_size(json, default_chunk_size(json.len()))
+    }
+
+    /// Create a DSON tape in parallel, enforcing `limits`
+    ///
+    /// Applies the same checks as [`DsonTape::parse_with_limits`].
+    ///
+    /// # Errors
+    /// Returns a validation error if the input breaks a limit, or a parse
+    /// error if the JSON is malformed
+    pub fn parse_parallel_with_limits(json: &str, limits: &ParseLimits) -> Result<Self> {
+        crate::enforce_limits(json.as_bytes(), limits)?;
+        Self::parse_parallel(json)
+    }
+
+    /// Create a DSON tape in parallel using chunks of `chunk_size` bytes
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is malformed
+    pub fn parse_parallel_with_chunk_size(json: &str, chunk_size: usize) -> Result<Self> {
+        if json.len() <= chunk_size {
+            return Self::parse(json);
+        }
+        let mut data = json.as_bytes().to_vec();
+        let index = StructuralIndex::with_chunk_size(&data, chunk_size);
+        let Some(nodes) = build_tape(&mut data, &index) else {
+            return Self::parse(json);
+        };
+        // SAFETY: the nodes borrow from `data`, whose heap buffer moves into the tape
+        let tape = unsafe { std::mem::transmute::<Tape<'_>, Tape<'static>>(Tape(nodes)) };
+        Ok(Self { tape, data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    fn assert_same(json: &str, chunk_size: usize) {
+        let serial = DsonTape::parse(json);
+        let parallel = DsonTape::parse_parallel_with_chunk_size(json, chunk_size);
+        match (serial, parallel) {
+            (Ok(s), Ok(p)) => assert_eq!(s.nodes(), p.nodes(), "chunk size {chunk_size}: {json}"),
+            (Err(s), Err(p)) => assert_eq!(s.to_string(), p.to_string()),
+            (s, p) => panic!(
+                "chunk size {chunk_size}: serial {:?} vs parallel {:?}",
+                s.is_ok(),
+                p.is_ok()
+            ),
+        }
+    }
+
+    fn sample() -> String {
+        let mut json =
+            String::from(r#"{"meta": {"name": "t\"e\\st", "tags": [], "empty": {}}, "items": ["#);
+        for i in 0..40 {
+            if i > 0 {
+                json.push_str(", ");
+            }
+            write!(
+                json,
+                r#"{{"id": {i}, "neg": -{i}, "f": {i}.5e-3, "s": "aé😀 {{[,:]}}", "ok": true, "n": null, "xs": [[{i}], {{}}]}}"#
+            )
+            .unwrap();
+        }
+        json.push_str("], \"last\": \"caf\u{e9}\"}");
+        json
+    }
+
+    // =========================================================================
+    // Structural index
+    // =========================================================================
+
+    #[test]
+    fn test_index_independent_of_chunk_size() {
+        let json = sample();
+        let serial: Vec<usize> = StructuralIndex::with_chunk_size(json.as_bytes(), json.len())
+            .positions()
+            .collect();
+        for size in [1, 2, 3, 7, 16, 61, 256] {
+            let index = StructuralIndex::with_chunk_size(json.as_bytes(), size);
+            assert_eq!(
+                index.positions().collect::<Vec<_>>(),
+                serial,
+                "chunk size {size}"
+            );
+            assert_eq!(index.len(), serial.len());
+        }
+    }
+
+    #[test]
+    fn test_index_chunk_starting_with_escaped_quote() {
+        let json = br#"["ab\"c", 1]"#;
+        let expected: Vec<usize> = vec![0, 1, 8, 10, 11];
+        for size in 1..json.len() {
+            let index = StructuralIndex::with_chunk_size(json, size);
+            assert_eq!(
+                index.positions().collect::<Vec<_>>(),
+                expected,
+                "chunk size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tokens_at_depth_independent_of_chunk_size() {
+        let json = sample();
+        let whole = StructuralIndex::with_chunk_size(json.as_bytes(), json.len());
+        for depth in 0..4 {
+            let expected = whole.tokens_at_depth(json.as_bytes(), depth);
+            for size in [1, 7, 61] {
+                let index = StructuralIndex::with_chunk_size(json.as_bytes(), size);
+                assert_eq!(
+                    index.tokens_at_depth(json.as_bytes(), depth),
+                    expected,
+                    "depth {depth}, chunk size {size}"
+                );
+            }
+        }
+        // The root's brackets, then for `meta`, `items` and `last`: key, colon,
+        // value (both brackets for containers) and the commas between them
+        assert_eq!(whole.tokens_at_depth(json.as_bytes(), 0).len(), 2);
+        assert_eq!(whole.tokens_at_depth(json.as_bytes(), 1).len(), 13);
+    }
+
+    #[test]
+    fn test_index_empty() {
+        let index = StructuralIndex::with_chunk_size(b"   ", 1);
+        assert!(index.is_empty());
+        assert_eq!(index.chunk_count(), 3);
+    }
+
+    // =========================================================================
+    // Tape construction
+    // =========================================================================
+
+    #[test]
+    fn test_parallel_tape_matches_serial() {
+        let json = sample();
+        for size in [1, 2, 5, 13, 64, 500, 4096] {
+            assert_same(&json, size);
+        }
+    }
+
+    #[test]
+    fn test_valid_input_stays_on_parallel_path() {
+        let json = sample();
+        let mut data = json.clone().into_bytes();
+        let index = StructuralIndex::with_chunk_size(&data, 97);
+        let nodes = build_tape(&mut data, &index).expect("strict JSON is built in parallel");
+        assert_eq!(DsonTape::parse(&json).unwrap().nodes(), &nodes[..]);
+    }
+
+    #[test]
+    fn test_wide_numbers_stay_on_parallel_path() {
+        let json = format!(
+            "[{}, 1e-00001, {}]",
+            sample(),
+            r#"{"big": 18446744073709551615, "neg": -9223372036854775808, "e": 2.5E+00012}"#
+        );
+        let mut data = json.clone().into_bytes();
+        let index = StructuralIndex::with_chunk_size(&data, 97);
+        let nodes = build_tape(&mut data, &index).expect("wide numbers are parsed in place");
+        assert_eq!(DsonTape::parse(&json).unwrap().nodes(), &nodes[..]);
+    }
+
+    #[test]
+    fn test_parallel_tape_scalars_and_roots() {
+        for json in [
+            "0",
+            "-0",
+            "123456789012345678",
+            "-123456789012345678",
+            "18446744073709551615",
+            "-9223372036854775808",
+            "1e00005",
+            "1.5",
+            "-0.0",
+            "1e308",
+            "2.2250738585072014e-308",
+            "true",
+            " null ",
+            r#""\/\b\f\n\r\t""#,
+            "[1,2,3]",
+            r#"{"a":{"b":{"c":[]}}}"#,
+            "[[[[[]]]]]",
+        ] {
+            for size in 1..=json.len() {
+                assert_same(json, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_tape_defers_edge_cases() {
+        for json in [
+            "",
+            "[",
+            "]",
+            "[1,]",
+            r#"{"a" 1}"#,
+            r#"{"a":1,}"#,
+            "[1 2]",
+            "01",
+            "1.",
+            "-",
+            "1e400",
+            "12345678901234567890",
+            "-9223372036854775808",
+            "tru",
+            "[true false]",
+            r#""\ud800""#,
+            r#""\udc00x""#,
+            "\"a\u{1}b\"",
+            r#"["a\x"]"#,
+            "[1]]",
+            "{]",
+            "1 2",
+            r#"{"a":1}{"#,
+            r#"["\"]"#,
+            "[\\1]",
+        ] {
+            for size in 1..=json.len().max(1) {
+                assert_same(json, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_tape_with_limits() {
+        let strict = ParseLimits::strict();
+        let json = format!("[{}]", vec![r#"{"a":1}"#; 200].join(","));
+        let tape = DsonTape::parse_parallel_with_limits(&json, &strict).unwrap();
+        assert_eq!(tape.nodes(), DsonTape::parse(&json).unwrap().nodes());
+        let duplicated = json.replace(r#"{"a":1}]"#, r#"{"a":1,"a":2}]"#);
+        assert!(DsonTape::parse_parallel_with_limits(&duplicated, &strict).is_err());
+    }
+
+    #[test]
+    fn test_parallel_tape_string_spanning_chunks() {
+        let long = "x\\\"".repeat(1000);
+        let json = format!(r#"{{"k": "{long}", "v": ["{long}", 1]}}"#);
+        for size in [1, 3, 100, 1000] {
+            assert_same(&json, size);
+        }
+    }
+}
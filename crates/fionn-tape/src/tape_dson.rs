@@ -79,9 +79,9 @@ impl TapeDsonProcessor {
                     let value = match static_val {
                         simd_json::StaticNode::Null => OperationValue::Null,
                         simd_json::StaticNode::Bool(b) => OperationValue::BoolRef(*b),
-                        simd_json::StaticNode::I64(n) => OperationValue::NumberRef(n.to_string()),
-                        simd_json::StaticNode::U64(n) => OperationValue::NumberRef(n.to_string()),
-                        simd_json::StaticNode::F64(n) => OperationValue::NumberRef(n.to_string()),
+                        simd_json::StaticNode::I64(n) => OperationValue::NumberRef((*n).into()),
+                        simd_json::StaticNode::U64(n) => OperationValue::NumberRef((*n).into()),
+                        simd_json::StaticNode::F64(n) => OperationValue::NumberRef((*n).into()),
                     };
                     self.field_values
                         .insert(current_path.clone(), value.clone());
@@ -238,19 +238,7 @@ impl TapeDsonProcessor {
     fn operation_value_to_json(value: &OperationValue) -> serde_json::Value {
         match value {
             OperationValue::StringRef(s) => serde_json::Value::String(s.clone()),
-            OperationValue::NumberRef(n) => n.parse::<i64>().map_or_else(
-                |_| {
-                    n.parse::<f64>().map_or_else(
-                        |_| serde_json::Value::String(n.clone()),
-                        |num| {
-                            serde_json::Number::from_f64(num).map_or_else(
-                                || serde_json::Value::String(n.clone()),
-                                serde_json::Value::Number,
-                            )
-                        },
-                    )
-                },
-                |num| serde_json::Value::Number(num.into()),
+            OperationValue::NumberRef(n) => n.to_json_value(),
             ),
             OperationValue::BoolRef(b) => serde_json::Value::Bool(*b),
             OperationValue::Null => serde_json::Value::Null,
@@ -554,7 +542,7 @@ mod tests {
     #[test]
     fn test_operation_value_to_json_invalid_float() {
         // NaN or infinity that can't be represented in JSON
-        let value = OperationValue::NumberRef("NaN".to_string());
+        let value = OperationValue::NumberRef("NaN".into());
         let json = TapeDsonProcessor::operation_value_to_json(&value);
         // Should fall back to string representation
         assert!(json.is_string());
@@ -602,7 +590,7 @@ mod tests {
         // Manually add an operation with array notation in path
         processor.operations.push(DsonOperation::FieldAdd {
             path: "items[0]".to_string(),
-            value: OperationValue::NumberRef("1".to_string()),
+            value: OperationValue::NumberRef("1".into()),
         });
 
         let result = processor.serialize_to_json().unwrap();
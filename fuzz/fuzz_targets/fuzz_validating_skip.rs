@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! libFuzzer differential target for the validating skipper.
+//!
+//! `ValidatingSkip::validate_document` must accept exactly the documents
+//! `serde_json` accepts, report UTF-8 errors at the first invalid byte, and
+//! agree with the bracket-counting skippers on where a valid object ends.
+//! Run with: cargo +nightly fuzz run fuzz_validating_skip
+
+#![no_main]
+
+use fionn_simd::{JsonSkiSkip, Skip, SkipErrorKind, ValidatingSkip};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > 100_000 {
+        return;
+    }
+
+    let ours = ValidatingSkip::new().validate_document(data);
+    let theirs = serde_json::from_slice::<serde_json::Value>(data);
+
+    // serde_json stops at 128 levels of nesting; the skipper has no limit
+    if let Err(e) = &theirs
+        && e.to_string().contains("recursion limit exceeded")
+    {
+        return;
+    }
+
+    assert_eq!(
+        ours.is_ok(),
+        theirs.is_ok(),
+        "validating skip disagrees with serde_json: ours={ours:?} theirs={theirs:?}"
+    );
+
+    match ours {
+        Ok(result) => {
+            // Valid objects end where the bracket counter says they do
+            let start = data
+                .iter()
+                .position(|b| !b.is_ascii_whitespace())
+                .expect("valid document is not blank");
+            if data[start] == b'{' {
+                let counted = JsonSkiSkip::new()
+                    .skip_object(&data[start + 1..])
+                    .expect("valid object must skip");
+                assert_eq!(start + 1 + counted.consumed, result.consumed);
+            }
+        }
+        Err(e) => {
+            assert!(e.offset <= data.len());
+            if e.kind == SkipErrorKind::InvalidUtf8 {
+                // Everything before the reported byte is still valid UTF-8
+                assert!(std::str::from_utf8(&data[..e.offset]).is_ok());
+            }
+        }
+    }
+});